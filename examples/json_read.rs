@@ -19,7 +19,7 @@ fn read_path(path: &str) -> Result<Arc<dyn Array>> {
 
     let data_type = read::infer_rows(&values)?;
 
-    Ok(read::deserialize_json(&values, data_type))
+    read::deserialize_json(&values, data_type)
 }
 
 fn main() -> Result<()> {
@@ -6,76 +6,27 @@ use std::time::SystemTime;
 
 use rayon::prelude::*;
 
-use arrow2::{
-    array::Array, chunk::Chunk, error::Result, io::parquet::read,
-    io::parquet::read::MutStreamingIterator,
-};
-
-fn parallel_read(path: &str, row_group: usize) -> Result<Chunk<Arc<dyn Array>>> {
-    let mut file = BufReader::new(File::open(path)?);
-    let file_metadata = read::read_metadata(&mut file)?;
-    let schema = read::get_schema(&file_metadata)?;
-
-    // IO-bounded
-    let columns = file_metadata
-        .schema()
-        .fields()
-        .iter()
-        .enumerate()
-        .map(|(field_i, field)| {
-            let start = SystemTime::now();
-            println!("read start - field: {}", field_i);
-            let mut columns = read::get_column_iterator(
-                &mut file,
-                &file_metadata,
-                row_group,
-                field_i,
-                None,
-                vec![],
-            );
-
-            let mut column_chunks = vec![];
-            while let read::State::Some(mut new_iter) = columns.advance().unwrap() {
-                if let Some((pages, metadata)) = new_iter.get() {
-                    let pages = pages.collect::<Vec<_>>();
-
-                    column_chunks.push((pages, metadata.clone()));
-                }
-                columns = new_iter;
-            }
-            println!(
-                "read end - {:?}: {} {}",
-                start.elapsed().unwrap(),
-                field_i,
-                row_group
-            );
-            (field_i, field.clone(), column_chunks)
-        })
-        .collect::<Vec<_>>();
-
-    // CPU-bounded
-    let columns = columns
-        .into_par_iter()
-        .map(|(field_i, parquet_field, column_chunks)| {
-            let columns = read::ReadColumnIterator::new(parquet_field, column_chunks);
-            let field = &schema.fields()[field_i];
-
-            read::column_iter_to_array(columns, field, vec![]).map(|x| x.0.into())
-        })
-        .collect::<Result<Vec<_>>>()?;
-
-    Chunk::try_new(columns)
-}
+use arrow2::{error::Result, io::parquet::read};
 
 fn main() -> Result<()> {
     use std::env;
     let args: Vec<String> = env::args().collect();
     let file_path = &args[1];
-    let row_group = args[2].parse::<usize>().unwrap();
 
     let start = SystemTime::now();
-    let batch = parallel_read(file_path, row_group)?;
-    assert!(!batch.is_empty());
+    let file = BufReader::new(File::open(file_path)?);
+    let mut reader = read::RecordReader::try_new(file, None, None, None, None)?;
+
+    // the column chunks of each row group are read (IO-bounded) by this thread, and
+    // decompressed and deserialized (CPU-bounded) by rayon's thread pool
+    reader.set_decoder(Arc::new(|tasks| {
+        tasks.into_par_iter().map(|task| task()).collect()
+    }));
+
+    for maybe_chunk in reader {
+        let chunk = maybe_chunk?;
+        assert!(!chunk.is_empty());
+    }
     println!("took: {} ms", start.elapsed().unwrap().as_millis());
 
     Ok(())
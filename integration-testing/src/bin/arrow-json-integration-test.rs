@@ -100,14 +100,22 @@ fn arrow_to_json(arrow_name: &str, json_name: &str, verbose: bool) -> Result<()>
 
     let schema = json_write::serialize_schema(&metadata.schema, &metadata.ipc_schema.fields);
 
-    let batches = reader
-        .map(|batch| Ok(json_write::serialize_chunk(&batch?, &names)))
-        .collect::<Result<Vec<_>>>()?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+
+    let dictionaries = batches
+        .first()
+        .map(|batch| json_write::serialize_dictionaries(batch, &metadata.ipc_schema.fields))
+        .filter(|dictionaries| !dictionaries.is_empty());
+
+    let batches = batches
+        .iter()
+        .map(|batch| json_write::serialize_chunk(batch, &names))
+        .collect();
 
     let arrow_json = ArrowJson {
         schema,
         batches,
-        dictionaries: None,
+        dictionaries,
     };
 
     let json_file = File::create(json_name)?;
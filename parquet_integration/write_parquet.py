@@ -209,9 +209,32 @@ def case_struct(size):
                     ]
                 ),
             ),
+            pa.field("struct_nullable", pa.struct(struct_fields)),
+            pa.field("list_struct", pa.list_(pa.struct(struct_fields))),
         ]
     )
 
+    struct_nullable = [
+        {"f1": s, "f2": b} if i not in [1, 6] else None
+        for i, (s, b) in enumerate(zip(string, boolean))
+    ]
+    list_struct = [
+        [{"f1": "a", "f2": True}, None],
+        None,
+        [],
+        [{"f1": "b", "f2": None}],
+        [
+            {"f1": None, "f2": False},
+            {"f1": "d", "f2": True},
+            {"f1": "e", "f2": False},
+        ],
+        None,
+        [],
+        [None],
+        [{"f1": "f", "f2": None}],
+        [{"f1": "g", "f2": True}],
+    ]
+
     struct = pa.StructArray.from_arrays(
         [pa.array(string * size), pa.array(boolean * size)],
         fields=struct_fields,
@@ -223,6 +246,8 @@ def case_struct(size):
                 [struct, pa.array(boolean * size)],
                 names=["f1", "f2"],
             ),
+            "struct_nullable": struct_nullable * size,
+            "list_struct": list_struct * size,
         },
         schema,
         f"struct_nullable_{size*10}.parquet",
@@ -1,7 +1,7 @@
-use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType};
+use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType, error::Result};
 
 use super::{
-    display_fmt, display_helper,
+    check_slice, display_fmt, display_helper,
     specification::{check_offsets, check_offsets_minimal},
     Array, GenericBinaryArray, Offset,
 };
@@ -133,6 +133,15 @@ impl<O: Offset> BinaryArray<O> {
         unsafe { self.slice_unchecked(offset, length) }
     }
 
+    /// Returns a slice of this [`BinaryArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Creates a new [`BinaryArray`] by slicing this [`BinaryArray`].
     /// # Implementation
     /// This function is `O(1)`: all data will be shared between both arrays.
@@ -1,7 +1,10 @@
 use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
-    array::{specification::check_offsets, Array, MutableArray, Offset, TryExtend, TryPush},
+    array::{
+        downcast_range, extend_validity, specification::check_offsets, Array, MutableArray, Offset,
+        TryExtend, TryPush,
+    },
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -167,6 +170,30 @@ impl<O: Offset> MutableBinaryArray<O> {
     pub fn offsets(&self) -> &Vec<O> {
         &self.offsets
     }
+
+    /// Extends the [`MutableBinaryArray`] with the `len` slots of `array` starting at `start`,
+    /// including their validity.
+    /// # Panics
+    /// This function panics iff `start + len > array.len()` or the length of all values
+    /// (in bytes) exceeds `O` maximum value.
+    pub fn extend_from_range(&mut self, array: &BinaryArray<O>, start: usize, len: usize) {
+        assert!(start + len <= array.len());
+        let length = self.len();
+        let offsets = &array.offsets()[start..=start + len];
+        let first = offsets[0];
+        let last = offsets[len];
+        let values = &array.values()[first.to_usize()..last.to_usize()];
+
+        let last_offset = *self.offsets.last().unwrap();
+        O::from_usize(last_offset.to_usize() + values.len()).expect("offsets overflow");
+        self.offsets.extend(
+            offsets[1..]
+                .iter()
+                .map(|offset| last_offset + (*offset - first)),
+        );
+        self.values.extend_from_slice(values);
+        extend_validity(length, &mut self.validity, array.validity(), start, len);
+    }
 }
 
 impl<O: Offset> MutableArray for MutableBinaryArray<O> {
@@ -216,6 +243,12 @@ impl<O: Offset> MutableArray for MutableBinaryArray<O> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let array = downcast_range(&self.data_type, array, start, len)?;
+        self.extend_from_range(array, start, len);
+        Ok(())
+    }
 }
 
 impl<O: Offset, P: AsRef<[u8]>> FromIterator<Option<P>> for MutableBinaryArray<O> {
@@ -1,9 +1,10 @@
 use crate::{
     bitmap::Bitmap,
     datatypes::{DataType, PhysicalType},
+    error::Result,
 };
 
-use super::{display_fmt, Array};
+use super::{check_slice, display_fmt, Array};
 
 mod ffi;
 mod from;
@@ -66,6 +67,15 @@ impl BooleanArray {
         unsafe { self.slice_unchecked(offset, length) }
     }
 
+    /// Returns a slice of this [`BooleanArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Returns a slice of this [`BooleanArray`].
     /// # Implementation
     /// This operation is `O(1)` as it amounts to increase two ref counts.
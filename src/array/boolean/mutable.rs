@@ -2,7 +2,7 @@ use std::iter::FromIterator;
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
+    array::{downcast_range, extend_validity, Array, MutableArray, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::{DataType, PhysicalType},
     error::Result,
@@ -189,6 +189,18 @@ impl MutableBooleanArray {
     pub fn values(&self) -> &MutableBitmap {
         &self.values
     }
+
+    /// Extends the [`MutableBooleanArray`] with the `len` slots of `array` starting at `start`,
+    /// including their validity.
+    /// # Panics
+    /// This function panics iff `start + len > array.len()`.
+    pub fn extend_from_range(&mut self, array: &BooleanArray, start: usize, len: usize) {
+        assert!(start + len <= array.len());
+        let length = self.values.len();
+        let (slice, offset, _) = array.values().as_slice();
+        self.values.extend_from_slice(slice, offset + start, len);
+        extend_validity(length, &mut self.validity, array.validity(), start, len);
+    }
 }
 
 /// Setters
@@ -229,7 +241,7 @@ impl MutableBooleanArray {
     /// Creates a new [`MutableBooleanArray`] from a slice of `bool`.
     #[inline]
     pub fn from_slice<P: AsRef<[bool]>>(slice: P) -> Self {
-        Self::from_trusted_len_values_iter(slice.as_ref().iter().copied())
+        Self::from_data(DataType::Boolean, MutableBitmap::from(slice), None)
     }
 
     /// Creates a [`BooleanArray`] from an iterator of trusted length.
@@ -473,6 +485,12 @@ impl MutableArray for MutableBooleanArray {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let array = downcast_range(&self.data_type, array, start, len)?;
+        self.extend_from_range(array, start, len);
+        Ok(())
+    }
 }
 
 impl Extend<Option<bool>> for MutableBooleanArray {
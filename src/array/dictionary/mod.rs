@@ -3,6 +3,7 @@ use std::sync::Arc;
 use crate::{
     bitmap::Bitmap,
     datatypes::{DataType, IntegerType},
+    error::Result,
     scalar::{new_scalar, Scalar},
     types::NativeType,
 };
@@ -14,7 +15,7 @@ pub use iterator::*;
 pub use mutable::*;
 
 use super::display::get_value_display;
-use super::{display_fmt, new_empty_array, primitive::PrimitiveArray, Array};
+use super::{check_slice, display_fmt, new_empty_array, primitive::PrimitiveArray, Array};
 use crate::scalar::NullScalar;
 
 /// Trait denoting [`NativeType`]s that can be used as keys of a dictionary.
@@ -89,6 +90,25 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         }
     }
 
+    /// Creates a new [`DictionaryArray`] of `data_type` (e.g. the [`DataType`] of an existing
+    /// array, so that whether it is ordered is preserved).
+    #[cfg(any(
+        feature = "compute_take",
+        feature = "compute_filter",
+        feature = "io_feather"
+    ))]
+    pub(crate) fn from_data_type(
+        data_type: DataType,
+        keys: PrimitiveArray<K>,
+        values: Arc<dyn Array>,
+    ) -> Self {
+        Self {
+            data_type,
+            keys,
+            values,
+        }
+    }
+
     /// Creates a new [`DictionaryArray`] by slicing the existing [`DictionaryArray`].
     /// # Panics
     /// iff `offset + length > self.len()`.
@@ -100,6 +120,15 @@ impl<K: DictionaryKey> DictionaryArray<K> {
         }
     }
 
+    /// Returns a slice of this [`DictionaryArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Creates a new [`DictionaryArray`] by slicing the existing [`DictionaryArray`].
     /// # Safety
     /// Safe iff `offset + length <= self.len()`.
@@ -2,6 +2,7 @@ use crate::{
     array::*,
     datatypes::{DataType, IntervalUnit, TimeUnit},
     temporal_conversions,
+    util::decimal::format_decimal,
 };
 
 macro_rules! dyn_display {
@@ -126,13 +127,8 @@ pub fn get_value_display<'a>(array: &'a dyn Array) -> Box<dyn Fn(usize) -> Strin
         LargeUtf8 => dyn_display!(array, Utf8Array<i64>, |x| x),
         Decimal(_, scale) => {
             // The number 999.99 has a precision of 5 and scale of 2
-            let scale = *scale as u32;
-            let display = move |x| {
-                let base = x / 10i128.pow(scale);
-                let decimals = x - base * 10i128.pow(scale);
-                format!("{}.{}", base, decimals)
-            };
-            dyn_primitive!(array, i128, display)
+            let scale = *scale;
+            dyn_primitive!(array, i128, move |x| format_decimal(x, scale))
         }
         List(_) => {
             let f = |x: Box<dyn Array>| {
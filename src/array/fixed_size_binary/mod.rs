@@ -1,6 +1,6 @@
 use crate::{bitmap::Bitmap, buffer::Buffer, datatypes::DataType, error::Result};
 
-use super::{display_fmt, Array};
+use super::{check_slice, display_fmt, Array};
 
 mod ffi;
 mod iterator;
@@ -63,6 +63,15 @@ impl FixedSizeBinaryArray {
         unsafe { self.slice_unchecked(offset, length) }
     }
 
+    /// Returns a slice of this [`FixedSizeBinaryArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Returns a slice of this [`FixedSizeBinaryArray`].
     /// # Implementation
     /// This operation is `O(1)` as it amounts to increase 3 ref counts.
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
-    array::{Array, MutableArray},
+    array::{downcast_range, extend_validity, Array, MutableArray},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -155,6 +155,20 @@ impl MutableFixedSizeBinaryArray {
         std::slice::from_raw_parts(self.values.as_ptr().add(i * self.size), self.size)
     }
 
+    /// Extends the [`MutableFixedSizeBinaryArray`] with the `len` slots of `array` starting at
+    /// `start`, including their validity.
+    /// # Panics
+    /// This function panics iff the size of `array` differs from this array's or
+    /// `start + len > array.len()`.
+    pub fn extend_from_range(&mut self, array: &FixedSizeBinaryArray, start: usize, len: usize) {
+        assert_eq!(array.size(), self.size);
+        assert!(start + len <= array.len());
+        let length = self.len();
+        self.values
+            .extend_from_slice(&array.values()[start * self.size..(start + len) * self.size]);
+        extend_validity(length, &mut self.validity, array.validity(), start, len);
+    }
+
     /// Shrinks the capacity of the [`MutableFixedSizeBinaryArray`] to fit its current length.
     pub fn shrink_to_fit(&mut self) {
         self.values.shrink_to_fit();
@@ -215,12 +229,18 @@ impl MutableArray for MutableFixedSizeBinaryArray {
     }
 
     fn push_null(&mut self) {
-        self.values.resize(self.values.len() + self.size, 0);
+        self.push::<&[u8]>(None);
     }
 
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let array = downcast_range(&self.data_type, array, start, len)?;
+        self.extend_from_range(array, start, len);
+        Ok(())
+    }
 }
 
 impl FixedSizeBinaryValues for MutableFixedSizeBinaryArray {
@@ -3,9 +3,10 @@ use std::sync::Arc;
 use crate::{
     bitmap::Bitmap,
     datatypes::{DataType, Field},
+    error::Result,
 };
 
-use super::{debug_fmt, new_empty_array, new_null_array, Array};
+use super::{check_slice, debug_fmt, new_empty_array, new_null_array, Array};
 
 mod ffi;
 mod iterator;
@@ -76,6 +77,15 @@ impl FixedSizeListArray {
         unsafe { self.slice_unchecked(offset, length) }
     }
 
+    /// Returns a slice of this [`FixedSizeListArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Returns a slice of this [`FixedSizeListArray`].
     /// # Implementation
     /// This operation is `O(1)`.
@@ -4,9 +4,10 @@ use crate::{
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::{DataType, Field},
+    error::Result,
 };
 
-use super::{debug_fmt, new_empty_array, specification::check_offsets, Array, Offset};
+use super::{check_slice, debug_fmt, new_empty_array, specification::check_offsets, Array, Offset};
 
 mod ffi;
 mod iterator;
@@ -87,6 +88,15 @@ impl<O: Offset> ListArray<O> {
         unsafe { self.slice_unchecked(offset, length) }
     }
 
+    /// Returns a slice of this [`ListArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Returns a slice of this [`ListArray`].
     /// # Safety
     /// The caller must ensure that `offset + length < self.len()`.
@@ -4,9 +4,10 @@ use crate::{
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::{DataType, Field},
+    error::Result,
 };
 
-use super::{new_empty_array, specification::check_offsets, Array};
+use super::{check_slice, new_empty_array, specification::check_offsets, Array};
 
 mod ffi;
 mod iterator;
@@ -94,6 +95,15 @@ impl MapArray {
         unsafe { self.slice_unchecked(offset, length) }
     }
 
+    /// Returns a slice of this [`MapArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Returns a slice of this [`MapArray`].
     /// # Safety
     /// The caller must ensure that `offset + length < self.len()`.
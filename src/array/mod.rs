@@ -19,10 +19,10 @@
 //! can be operated in-place.
 use std::any::Any;
 
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::types::{days_ms, months_days_ns};
 use crate::{
-    bitmap::{Bitmap, MutableBitmap},
+    bitmap::{utils::count_zeros, Bitmap, MutableBitmap},
     datatypes::DataType,
 };
 
@@ -93,6 +93,18 @@ pub trait Array: Send + Sync {
     /// This function panics iff `offset + length >= self.len()`.
     fn slice(&self, offset: usize, length: usize) -> Box<dyn Array>;
 
+    /// Slices the [`Array`], returning a new `Box<dyn Array>`.
+    /// # Implementation
+    /// This operation is `O(1)` over `len`, as it amounts to increase two ref counts
+    /// and moving the struct to the heap.
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    fn try_slice(&self, offset: usize, length: usize) -> Result<Box<dyn Array>> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Slices the [`Array`], returning a new `Box<dyn Array>`.
     /// # Implementation
     /// This operation is `O(1)` over `len`, as it amounts to increase two ref counts
@@ -159,6 +171,78 @@ pub trait MutableArray: std::fmt::Debug + Send + Sync {
 
     /// Shrink the array to fit its length.
     fn shrink_to_fit(&mut self);
+
+    /// Extends the array with the `len` slots of `array` starting at `start`, including their
+    /// validity.
+    /// # Errors
+    /// This function errors iff the [`DataType`] of `array` differs from the array's,
+    /// `start + len > array.len()`, or the array does not support this operation.
+    fn extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let _ = (array, start, len);
+        Err(ArrowError::NotYetImplemented(format!(
+            "Extending a mutable array of type {:?} from an array",
+            self.data_type()
+        )))
+    }
+}
+
+/// Downcasts `array` to `A` after checking that it has `data_type` and that the range
+/// `start..start + len` is within its bounds, as required by [`MutableArray::extend_from_array`].
+fn downcast_range<'a, A: Array + 'static>(
+    data_type: &DataType,
+    array: &'a dyn Array,
+    start: usize,
+    len: usize,
+) -> Result<&'a A> {
+    if array.data_type() != data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot extend a mutable array of type {:?} from an array of type {:?}",
+            data_type,
+            array.data_type()
+        )));
+    }
+    check_slice(start, len, array.len())?;
+    Ok(array.as_any().downcast_ref::<A>().unwrap())
+}
+
+/// Errors iff the range `offset..offset + length` is out of bounds for an array of `len` slots.
+#[inline]
+pub(crate) fn check_slice(offset: usize, length: usize, len: usize) -> Result<()> {
+    match offset.checked_add(length) {
+        Some(end) if end <= len => Ok(()),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "The range {}..{} is out of bounds for an array of length {}",
+            offset,
+            offset as u128 + length as u128,
+            len
+        ))),
+    }
+}
+
+/// Extends `validity`, the validity of a mutable array of `length` slots, with the slots
+/// `start..start + len` of `other`. The validity is only materialized when these slots have nulls.
+fn extend_validity(
+    length: usize,
+    validity: &mut Option<MutableBitmap>,
+    other: Option<&Bitmap>,
+    start: usize,
+    len: usize,
+) {
+    let other = other
+        .map(|other| other.as_slice())
+        .filter(|(slice, offset, _)| count_zeros(slice, offset + start, len) > 0);
+    match (validity.as_mut(), other) {
+        (_, Some((slice, offset, _))) => {
+            let validity = validity.get_or_insert_with(|| {
+                let mut validity = MutableBitmap::with_capacity(length + len);
+                validity.extend_constant(length, true);
+                validity
+            });
+            validity.extend_from_slice(slice, offset + start, len);
+        }
+        (Some(validity), None) => validity.extend_constant(len, true),
+        (None, None) => {}
+    }
 }
 
 macro_rules! general_dyn {
@@ -340,6 +424,54 @@ pub fn clone(array: &dyn Array) -> Box<dyn Array> {
     }
 }
 
+/// Returns a deep copy of `array` whose buffers only contain the data referenced by it.
+///
+/// Slicing an [`Array`] is `O(1)` and keeps the buffers of the original array alive, even the
+/// regions no longer referenced by any slot. This function copies the referenced regions to new
+/// buffers so that the original ones can be released, e.g. after slicing or filtering a large
+/// string column.
+///
+/// The values of a [`DictionaryArray`] are copied as is (its unreferenced values can be dropped
+/// with `compute::take::compact_dictionary`), and a [`UnionArray`] is cloned without copying.
+/// # Example
+/// ```
+/// use arrow2::array::{deep_clone_compact, Array, Utf8Array};
+///
+/// let array = Utf8Array::<i32>::from_slice(["a".repeat(1000), "b".to_string()]).slice(1, 1);
+/// assert_eq!(array.values().len(), 1001);
+///
+/// let compacted = deep_clone_compact(&array);
+/// let compacted = compacted.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+/// assert_eq!(compacted, &array);
+/// assert_eq!(compacted.values().len(), 1);
+/// ```
+/// # Implementation
+/// This operation is `O(N)` over the referenced data.
+pub fn deep_clone_compact(array: &dyn Array) -> Box<dyn Array> {
+    use crate::datatypes::PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Union => clone(array),
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = array.offsets();
+            let start = offsets[0];
+            let length = (offsets[offsets.len() - 1] - start) as usize;
+            let field = array.field().slice(start as usize, length);
+            Box::new(MapArray::from_data(
+                array.data_type().clone(),
+                offsets.iter().map(|offset| offset - start).collect(),
+                deep_clone_compact(field.as_ref()).into(),
+                array.validity().map(|x| x.iter().collect()),
+            ))
+        }
+        _ => {
+            let mut growable = growable::make_growable(&[array], false, array.len());
+            growable.extend(0, 0, array.len());
+            growable.as_box()
+        }
+    }
+}
+
 // see https://users.rust-lang.org/t/generic-for-dyn-a-or-box-dyn-a-or-arc-dyn-a/69430/3
 // for details
 impl<'a> AsRef<(dyn Array + 'a)> for dyn Array {
@@ -1,7 +1,7 @@
 use crate::{bitmap::Bitmap, datatypes::DataType};
 
 use crate::{
-    array::{Array, FromFfi, ToFfi},
+    array::{check_slice, Array, FromFfi, ToFfi},
     error::Result,
     ffi,
 };
@@ -37,6 +37,15 @@ impl NullArray {
         }
     }
 
+    /// Returns a slice of this [`NullArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(self.slice(offset, length))
+    }
+
     #[inline]
     fn len(&self) -> usize {
         self.length
@@ -3,10 +3,11 @@ use crate::{
     buffer::Buffer,
     datatypes::*,
     error::ArrowError,
+    error::Result,
     types::{days_ms, months_days_ns, NativeType},
 };
 
-use super::Array;
+use super::{check_slice, Array};
 
 mod display;
 mod ffi;
@@ -91,6 +92,15 @@ impl<T: NativeType> PrimitiveArray<T> {
         unsafe { self.slice_unchecked(offset, length) }
     }
 
+    /// Returns a slice of this [`PrimitiveArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Returns a slice of this [`PrimitiveArray`].
     /// # Implementation
     /// This operation is `O(1)` as it amounts to increase two ref counts.
@@ -164,6 +174,46 @@ impl<T: NativeType> PrimitiveArray<T> {
         *self.values.get_unchecked(i)
     }
 
+    /// Returns the values of this array split in a prefix, a middle of lanes of `N` values
+    /// and a suffix, such that the middle starts at an address aligned to the size of a lane
+    /// whenever that size is a power of two (e.g. 64 bytes for `[f32; 16]`).
+    ///
+    /// This is useful to write SIMD kernels: the middle can be loaded in registers of `N` lanes
+    /// while the prefix and suffix (each with less than `N` values) are handled individually.
+    /// Like the values, the lanes do not take the validity into account; the validity of the
+    /// middle can be obtained via `validity.clone().slice(prefix.len(), middle.len() * N)`
+    /// and [`Bitmap::chunks`].
+    /// # Panics
+    /// Panics iff `N` is zero.
+    /// # Example
+    /// ```
+    /// use arrow2::array::PrimitiveArray;
+    ///
+    /// let array = PrimitiveArray::<f32>::from_vec((0..100).map(|x| x as f32).collect());
+    /// let (prefix, middle, suffix) = array.aligned_chunks::<16>();
+    /// assert_eq!(prefix.len() + middle.len() * 16 + suffix.len(), 100);
+    /// assert_eq!(middle.as_ptr() as usize % 64, 0);
+    /// ```
+    pub fn aligned_chunks<const N: usize>(&self) -> (&[T], &[[T; N]], &[T]) {
+        assert!(N > 0, "The number of lanes must be positive");
+        let values = self.values.as_slice();
+
+        let lane_size = N * std::mem::size_of::<T>();
+        let prefix_len = if lane_size.is_power_of_two() {
+            values.as_ptr().align_offset(lane_size).min(values.len())
+        } else {
+            0
+        };
+        let (prefix, remaining) = values.split_at(prefix_len);
+        let (middle, suffix) = remaining.split_at(remaining.len() - remaining.len() % N);
+
+        // Safety: `[T; N]` has the same alignment as `T` and `middle.len()` is a multiple of `N`
+        let middle = unsafe {
+            std::slice::from_raw_parts(middle.as_ptr() as *const [T; N], middle.len() / N)
+        };
+        (prefix, middle, suffix)
+    }
+
     /// Returns a new [`PrimitiveArray`] with a different logical type.
     /// This is `O(1)`.
     /// # Panics
@@ -1,7 +1,7 @@
 use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
-    array::{Array, MutableArray, TryExtend, TryPush},
+    array::{downcast_range, extend_validity, Array, MutableArray, TryExtend, TryPush},
     bitmap::MutableBitmap,
     datatypes::DataType,
     error::{ArrowError, Result},
@@ -219,6 +219,17 @@ impl<T: NativeType> MutablePrimitiveArray<T> {
         self.update_all_valid();
     }
 
+    /// Extends the [`MutablePrimitiveArray`] with the `len` slots of `array` starting at `start`,
+    /// including their validity.
+    /// # Panics
+    /// This function panics iff `start + len > array.len()`.
+    pub fn extend_from_range(&mut self, array: &PrimitiveArray<T>, start: usize, len: usize) {
+        let length = self.values.len();
+        self.values
+            .extend_from_slice(&array.values()[start..start + len]);
+        extend_validity(length, &mut self.validity, array.validity(), start, len);
+    }
+
     fn update_all_valid(&mut self) {
         // get len before mutable borrow
         let len = self.len();
@@ -386,6 +397,12 @@ impl<T: NativeType> MutableArray for MutablePrimitiveArray<T> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let array = downcast_range(&self.data_type, array, start, len)?;
+        self.extend_from_range(array, start, len);
+        Ok(())
+    }
 }
 
 impl<T: NativeType> MutablePrimitiveArray<T> {
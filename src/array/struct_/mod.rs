@@ -3,9 +3,10 @@ use std::sync::Arc;
 use crate::{
     bitmap::Bitmap,
     datatypes::{DataType, Field},
+    error::Result,
 };
 
-use super::{new_empty_array, new_null_array, Array};
+use super::{check_slice, new_empty_array, new_null_array, Array};
 
 mod ffi;
 mod iterator;
@@ -113,6 +114,15 @@ impl StructArray {
         unsafe { self.slice_unchecked(offset, length) }
     }
 
+    /// Returns a slice of this [`StructArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Creates a new [`StructArray`] that is a slice of `self`.
     /// # Implementation
     /// This operation is `O(F)` where `F` is the number of fields.
@@ -1,10 +1,14 @@
 use std::{collections::HashMap, sync::Arc};
 
 use crate::{
-    array::{display::get_value_display, display_fmt, new_empty_array, new_null_array, Array},
+    array::{
+        check_slice, display::get_value_display, display_fmt, new_empty_array, new_null_array,
+        Array,
+    },
     bitmap::Bitmap,
     buffer::Buffer,
     datatypes::{DataType, Field, UnionMode},
+    error::Result,
     scalar::{new_scalar, Scalar},
 };
 
@@ -146,6 +150,15 @@ impl UnionArray {
         }
     }
 
+    /// Returns a slice of this [`UnionArray`].
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        Ok(unsafe { self.slice_unchecked(offset, length) })
+    }
+
     /// Returns a slice of this [`UnionArray`].
     /// # Implementation
     /// This operation is `O(F)` where `F` is the number of fields.
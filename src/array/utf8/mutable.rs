@@ -2,6 +2,7 @@ use std::{iter::FromIterator, sync::Arc};
 
 use crate::{
     array::{
+        downcast_range, extend_validity,
         specification::{check_offsets_and_utf8, check_offsets_minimal},
         Array, MutableArray, Offset, TryExtend, TryPush,
     },
@@ -196,6 +197,30 @@ impl<O: Offset> MutableUtf8Array<O> {
     pub fn offsets(&self) -> &Vec<O> {
         &self.offsets
     }
+
+    /// Extends the [`MutableUtf8Array`] with the `len` slots of `array` starting at `start`,
+    /// including their validity.
+    /// # Panics
+    /// This function panics iff `start + len > array.len()` or the length of all values
+    /// (in bytes) exceeds `O` maximum value.
+    pub fn extend_from_range(&mut self, array: &Utf8Array<O>, start: usize, len: usize) {
+        assert!(start + len <= array.len());
+        let length = self.len();
+        let offsets = &array.offsets()[start..=start + len];
+        let first = offsets[0];
+        let last = offsets[len];
+        let values = &array.values()[first.to_usize()..last.to_usize()];
+
+        let last_offset = *self.offsets.last().unwrap();
+        O::from_usize(last_offset.to_usize() + values.len()).expect("offsets overflow");
+        self.offsets.extend(
+            offsets[1..]
+                .iter()
+                .map(|offset| last_offset + (*offset - first)),
+        );
+        self.values.extend_from_slice(values);
+        extend_validity(length, &mut self.validity, array.validity(), start, len);
+    }
 }
 
 impl<O: Offset> MutableArray for MutableUtf8Array<O> {
@@ -258,6 +283,12 @@ impl<O: Offset> MutableArray for MutableUtf8Array<O> {
     fn shrink_to_fit(&mut self) {
         self.shrink_to_fit()
     }
+
+    fn extend_from_array(&mut self, array: &dyn Array, start: usize, len: usize) -> Result<()> {
+        let array = downcast_range(&self.data_type, array, start, len)?;
+        self.extend_from_range(array, start, len);
+        Ok(())
+    }
 }
 
 impl<O: Offset, P: AsRef<str>> FromIterator<Option<P>> for MutableUtf8Array<O> {
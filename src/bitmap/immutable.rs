@@ -4,7 +4,9 @@ use std::sync::Arc;
 use crate::{buffer::bytes::Bytes, trusted_len::TrustedLen};
 
 use super::{
-    utils::{count_zeros, fmt, get_bit, get_bit_unchecked, BitChunk, BitChunks, BitmapIter},
+    utils::{
+        count_zeros, fmt, get_bit, get_bit_unchecked, unpack_bits, BitChunk, BitChunks, BitmapIter,
+    },
     MutableBitmap,
 };
 
@@ -179,7 +181,7 @@ impl Bitmap {
 
 impl<P: AsRef<[bool]>> From<P> for Bitmap {
     fn from(slice: P) -> Self {
-        Self::from_trusted_len_iter(slice.as_ref().iter().copied())
+        MutableBitmap::from(slice).into()
     }
 }
 
@@ -257,6 +259,23 @@ impl Bitmap {
     }
 }
 
+impl Bitmap {
+    /// Returns the bits of this [`Bitmap`] as a [`Vec`] of `bool`, e.g. for APIs that require
+    /// one byte per boolean.
+    /// # Example
+    /// ```
+    /// use arrow2::bitmap::Bitmap;
+    ///
+    /// let bitmap = Bitmap::from([true, false, true]);
+    /// assert_eq!(bitmap.to_bools(), vec![true, false, true]);
+    /// ```
+    pub fn to_bools(&self) -> Vec<bool> {
+        let mut values = vec![false; self.length];
+        unpack_bits(&self.bytes, self.offset, &mut values);
+        values
+    }
+}
+
 impl<'a> IntoIterator for &'a Bitmap {
     type Item = bool;
     type IntoIter = BitmapIter<'a>;
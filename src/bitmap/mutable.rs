@@ -4,7 +4,7 @@ use std::iter::FromIterator;
 use crate::bitmap::utils::merge_reversed;
 use crate::trusted_len::TrustedLen;
 
-use super::utils::{count_zeros, fmt, get_bit, set, set_bit, BitmapIter};
+use super::utils::{count_zeros, fmt, get_bit, pack_bools, set, set_bit, BitmapIter};
 use super::Bitmap;
 
 /// A container to store booleans. [`MutableBitmap`] is semantically equivalent
@@ -248,7 +248,8 @@ impl From<MutableBitmap> for Option<Bitmap> {
 impl<P: AsRef<[bool]>> From<P> for MutableBitmap {
     #[inline]
     fn from(slice: P) -> Self {
-        MutableBitmap::from_trusted_len_iter(slice.as_ref().iter().copied())
+        let slice = slice.as_ref();
+        MutableBitmap::from_vec(pack_bools(slice), slice.len())
     }
 }
 
@@ -2,6 +2,7 @@
 mod chunk_iterator;
 mod fmt;
 mod iterator;
+mod pack;
 mod slice_iterator;
 mod zip_validity;
 
@@ -11,6 +12,7 @@ pub(crate) use chunk_iterator::merge_reversed;
 pub use chunk_iterator::{BitChunk, BitChunkIterExact, BitChunks, BitChunksExact};
 pub use fmt::fmt;
 pub use iterator::BitmapIter;
+pub use pack::{pack_bools, unpack_bits};
 pub use slice_iterator::SlicesIterator;
 pub use zip_validity::{zip_validity, ZipValidity};
 
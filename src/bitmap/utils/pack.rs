@@ -0,0 +1,60 @@
+/// Returns the bits of `values` packed into bytes, LSB first, e.g. `[true, false, true]` as
+/// `[0b101]`. This is the representation of the bits of a [`crate::bitmap::Bitmap`].
+pub fn pack_bools(values: &[bool]) -> Vec<u8> {
+    // Safety: a `bool` has the size and alignment of a `u8` and is either 0 or 1.
+    let bytes = unsafe { std::slice::from_raw_parts(values.as_ptr() as *const u8, values.len()) };
+
+    let mut buffer = Vec::with_capacity(super::bytes_for(values.len()));
+    let chunks = bytes.chunks_exact(8);
+    let remainder = chunks.remainder();
+    // each byte of a chunk is 0 or 1, which the multiplication gathers in its most significant byte
+    buffer.extend(chunks.map(|chunk| {
+        let chunk = u64::from_le_bytes(chunk.try_into().unwrap());
+        (chunk.wrapping_mul(0x0102040810204080) >> 56) as u8
+    }));
+    if !remainder.is_empty() {
+        let byte = remainder
+            .iter()
+            .enumerate()
+            .fold(0u8, |byte, (i, value)| byte | (value << i));
+        buffer.push(byte);
+    }
+    buffer
+}
+
+/// Writes to `values` the `values.len()` bits of `bytes` starting at bit `offset`, LSB first.
+/// This is the inverse of [`pack_bools`].
+/// # Panics
+/// This function panics iff `bytes` has less than `offset + values.len()` bits.
+pub fn unpack_bits(bytes: &[u8], offset: usize, values: &mut [bool]) {
+    assert!(offset + values.len() <= bytes.len() * 8);
+    let bit_offset = offset % 8;
+    let bytes = &bytes[offset / 8..];
+    let byte_at = |i: usize| {
+        if bit_offset == 0 {
+            bytes[i]
+        } else {
+            let next = bytes.get(i + 1).copied().unwrap_or(0);
+            ((bytes[i] as u16 | (next as u16) << 8) >> bit_offset) as u8
+        }
+    };
+
+    // Safety: a `bool` has the size and alignment of a `u8` and only 0 or 1 are written.
+    let out =
+        unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u8, values.len()) };
+    let mut chunks = out.chunks_exact_mut(8);
+    for (i, chunk) in (&mut chunks).enumerate() {
+        // spreads bit `j` of the byte to the least significant bit of byte `j`
+        let spread = (byte_at(i) as u64).wrapping_mul(0x0101010101010101) & 0x8040201008040201;
+        let spread = (spread.wrapping_add(0x7f7f7f7f7f7f7f7f) >> 7) & 0x0101010101010101;
+        chunk.copy_from_slice(&spread.to_le_bytes());
+    }
+    let remainder = chunks.into_remainder();
+    if !remainder.is_empty() {
+        let byte = byte_at(values.len() / 8);
+        remainder
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, value)| *value = (byte >> i) & 1);
+    }
+}
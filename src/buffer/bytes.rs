@@ -1,19 +1,20 @@
 //! This module contains an implementation of a contiguous immutable memory region that knows
 //! how to de-allocate itself, [`Bytes`].
 
+use std::any::Any;
 use std::slice;
 use std::{fmt::Debug, fmt::Formatter};
 use std::{ptr::NonNull, sync::Arc};
 
-use crate::ffi;
 use crate::types::NativeType;
 
 /// Mode of deallocating memory regions
 pub enum Deallocation {
     /// Native deallocation, using Rust deallocator with Arrow-specific memory aligment
     Native(usize),
-    // Foreign interface, via a callback
-    Foreign(Arc<ffi::ArrowArray>),
+    /// Foreign memory (e.g. from the C data interface or a memory-mapped file), deallocated
+    /// when the last reference to its owner is dropped
+    Foreign(Arc<dyn Any + Send + Sync>),
 }
 
 impl Debug for Deallocation {
@@ -68,6 +69,31 @@ impl<T: NativeType> Bytes<T> {
         }
     }
 
+    /// Returns the `length` slots of the bytes of `owner` starting at byte `offset`, without
+    /// copying them, keeping `owner` alive for as long as the [`Bytes`] are.
+    /// Returns `None` iff the bytes are out of bounds of `owner` or are not aligned to `T`.
+    pub fn from_owner<O: AsRef<[u8]> + Send + Sync + 'static>(
+        owner: Arc<O>,
+        offset: usize,
+        length: usize,
+    ) -> Option<Self> {
+        if length == 0 {
+            return Some(Vec::new().into());
+        }
+        let bytes: &[u8] = (*owner).as_ref();
+        let end = length
+            .checked_mul(std::mem::size_of::<T>())
+            .and_then(|size| offset.checked_add(size))?;
+        let ptr = bytes.get(offset..end)?.as_ptr();
+        if ptr.align_offset(std::mem::align_of::<T>()) != 0 {
+            return None;
+        }
+        let ptr = NonNull::new(ptr as *mut T)?;
+        // Safety: the region is in bounds and aligned, and it is immutable for as long as
+        // `owner` (which the `Bytes` hold) is alive
+        Some(unsafe { Self::new(ptr, length, Deallocation::Foreign(owner)) })
+    }
+
     #[inline]
     fn as_slice(&self) -> &[T] {
         self
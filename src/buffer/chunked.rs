@@ -0,0 +1,166 @@
+use crate::types::NativeType;
+
+use super::Buffer;
+
+/// The default size of a segment of a [`ChunkedBuffer`], in bytes (1 MiB)
+const DEFAULT_SEGMENT_BYTES: usize = 1024 * 1024;
+
+/// A growable container of [`NativeType`]s composed of fixed-capacity segments.
+///
+/// Contrarily to [`Vec`], it never reallocates: when a segment is full, a new one is allocated.
+/// This avoids the copies (and the peak memory) of the repeated reallocations of a [`Vec`]
+/// whose final length is unknown and large.
+/// Use [`ChunkedBuffer::into_buffer`] to consolidate it into a contiguous [`Buffer`], which
+/// copies every value exactly once.
+/// # Example
+/// ```
+/// use arrow2::buffer::{Buffer, ChunkedBuffer};
+///
+/// let mut values = ChunkedBuffer::<i32>::with_segment_capacity(2);
+/// values.extend_from_slice(&[1, 2, 3]);
+/// values.push(4);
+/// values.push(5);
+/// assert_eq!(values.len(), 5);
+/// assert_eq!(values.segments().count(), 3);
+///
+/// let buffer: Buffer<i32> = values.into_buffer();
+/// assert_eq!(buffer.as_slice(), &[1, 2, 3, 4, 5]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkedBuffer<T: NativeType> {
+    segments: Vec<Vec<T>>,
+    segment_capacity: usize,
+    length: usize,
+}
+
+impl<T: NativeType> Default for ChunkedBuffer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: NativeType> ChunkedBuffer<T> {
+    /// Creates a new empty [`ChunkedBuffer`] with segments of 1 MiB.
+    pub fn new() -> Self {
+        Self::with_segment_capacity((DEFAULT_SEGMENT_BYTES / std::mem::size_of::<T>()).max(1))
+    }
+
+    /// Creates a new empty [`ChunkedBuffer`] whose segments hold `capacity` values.
+    /// No segment is allocated until a value is pushed.
+    /// # Panics
+    /// This function panics iff `capacity` is zero.
+    pub fn with_segment_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "The capacity of segments must be positive");
+        Self {
+            segments: vec![],
+            segment_capacity: capacity,
+            length: 0,
+        }
+    }
+
+    /// Returns the number of values in the [`ChunkedBuffer`].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns whether the [`ChunkedBuffer`] is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the number of values each segment holds.
+    #[inline]
+    pub fn segment_capacity(&self) -> usize {
+        self.segment_capacity
+    }
+
+    /// Returns the last segment, allocating a new one when it is full.
+    #[inline]
+    fn last_segment(&mut self) -> &mut Vec<T> {
+        let is_full = self
+            .segments
+            .last()
+            .map(|segment| segment.len() == self.segment_capacity)
+            .unwrap_or(true);
+        if is_full {
+            self.segments
+                .push(Vec::with_capacity(self.segment_capacity));
+        }
+        self.segments.last_mut().unwrap()
+    }
+
+    /// Appends a value to the [`ChunkedBuffer`].
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.last_segment().push(value);
+        self.length += 1;
+    }
+
+    /// Appends a slice of values to the [`ChunkedBuffer`], filling segments as needed.
+    pub fn extend_from_slice(&mut self, mut values: &[T]) {
+        self.length += values.len();
+        while !values.is_empty() {
+            let segment_capacity = self.segment_capacity;
+            let segment = self.last_segment();
+            let additional = (segment_capacity - segment.len()).min(values.len());
+            segment.extend_from_slice(&values[..additional]);
+            values = &values[additional..];
+        }
+    }
+
+    /// Returns the value at `index`, if in bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index < self.length {
+            Some(self.segments[index / self.segment_capacity][index % self.segment_capacity])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the (contiguous) segments of the [`ChunkedBuffer`], in order.
+    pub fn segments(&self) -> impl Iterator<Item = &[T]> {
+        self.segments.iter().map(|segment| segment.as_slice())
+    }
+
+    /// Returns an iterator over the values of the [`ChunkedBuffer`].
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.segments.iter().flatten()
+    }
+
+    /// Consolidates the [`ChunkedBuffer`] into a contiguous [`Buffer`].
+    /// This is `O(N)`; when there is at most one segment, it does not copy.
+    pub fn into_buffer(mut self) -> Buffer<T> {
+        if self.segments.len() <= 1 {
+            return self.segments.pop().unwrap_or_default().into();
+        }
+        let mut values = Vec::with_capacity(self.length);
+        self.segments
+            .iter()
+            .for_each(|segment| values.extend_from_slice(segment));
+        values.into()
+    }
+}
+
+impl<T: NativeType> Extend<T> for ChunkedBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value))
+    }
+}
+
+impl<T: NativeType> FromIterator<T> for ChunkedBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut buffer = Self::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
+
+impl<T: NativeType> From<ChunkedBuffer<T>> for Buffer<T> {
+    #[inline]
+    fn from(buffer: ChunkedBuffer<T>) -> Self {
+        buffer.into_buffer()
+    }
+}
@@ -59,6 +59,20 @@ impl<T: NativeType> Buffer<T> {
         data.as_ref().to_vec().into()
     }
 
+    /// Creates a [`Buffer`] of `length` slots of the bytes of `owner` starting at byte `offset`,
+    /// without copying them. `owner` is kept alive for as long as the buffer (or any of its
+    /// clones or slices) is, e.g. to expose a memory-mapped file.
+    ///
+    /// The bytes are interpreted in the native endianness.
+    /// Returns `None` iff the bytes are out of bounds of `owner` or are not aligned to `T`.
+    pub fn from_owner<O: AsRef<[u8]> + Send + Sync + 'static>(
+        owner: Arc<O>,
+        offset: usize,
+        length: usize,
+    ) -> Option<Self> {
+        Bytes::from_owner(owner, offset, length).map(Self::from_bytes)
+    }
+
     /// Auxiliary method to create a new Buffer
     pub(crate) fn from_bytes(bytes: Bytes<T>) -> Self {
         let length = bytes.len();
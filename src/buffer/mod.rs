@@ -1,8 +1,14 @@
 #![deny(missing_docs)]
-//! Contains [`Buffer`], an immutable container for all Arrow physical types (e.g. i32, f64).
+//! Contains [`Buffer`], an immutable container for all Arrow physical types (e.g. i32, f64),
+//! [`MutableBuffer`], its mutable counterpart that can be written out of order, and
+//! [`ChunkedBuffer`], a segmented container to build large [`Buffer`]s.
 
+mod chunked;
 mod immutable;
+mod mutable;
 
 pub(crate) mod bytes;
 
+pub use chunked::ChunkedBuffer;
 pub use immutable::Buffer;
+pub use mutable::MutableBuffer;
@@ -0,0 +1,151 @@
+use crate::types::NativeType;
+
+use super::Buffer;
+
+/// A growable container of [`NativeType`]s that can be written at any position, backed by a
+/// [`Vec`] that converts to a [`Buffer`] without copying.
+///
+/// Decoders that produce values out of order (e.g. when reconstructing nested values from
+/// parquet's levels or when partitioning) can pre-size it with [`MutableBuffer::extend_zeros`]
+/// and fill it with [`MutableBuffer::set`] and [`MutableBuffer::write_slice_at`], which are
+/// bounds-checked.
+/// # Example
+/// ```
+/// use arrow2::buffer::{Buffer, MutableBuffer};
+///
+/// let mut values = MutableBuffer::<i32>::with_capacity(5);
+/// values.extend_zeros(5);
+/// values.set(4, 5);
+/// values.write_slice_at(1, &[2, 3]);
+///
+/// let buffer: Buffer<i32> = values.into();
+/// assert_eq!(buffer.as_slice(), &[0, 2, 3, 0, 5]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MutableBuffer<T: NativeType> {
+    values: Vec<T>,
+}
+
+impl<T: NativeType> MutableBuffer<T> {
+    /// Creates a new empty [`MutableBuffer`].
+    #[inline]
+    pub fn new() -> Self {
+        Self { values: vec![] }
+    }
+
+    /// Creates a new empty [`MutableBuffer`] with capacity for `capacity` values.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of values in the [`MutableBuffer`].
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether the [`MutableBuffer`] is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the values of the [`MutableBuffer`].
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Returns the values of the [`MutableBuffer`] as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+
+    /// Appends a value to the [`MutableBuffer`].
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.values.push(value)
+    }
+
+    /// Appends a slice of values to the [`MutableBuffer`].
+    #[inline]
+    pub fn extend_from_slice(&mut self, values: &[T]) {
+        self.values.extend_from_slice(values)
+    }
+
+    /// Appends `additional` zeros (the [`Default`] of `T`) to the [`MutableBuffer`].
+    #[inline]
+    pub fn extend_zeros(&mut self, additional: usize) {
+        self.values
+            .resize(self.values.len() + additional, T::default())
+    }
+
+    /// Sets the value at `index` to `value`.
+    /// # Panics
+    /// This function panics iff `index` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, index: usize, value: T) {
+        assert!(
+            index < self.values.len(),
+            "The index {} is out of bounds of a buffer of length {}",
+            index,
+            self.values.len()
+        );
+        self.values[index] = value
+    }
+
+    /// Writes `values` to the positions starting at `offset`.
+    /// # Panics
+    /// This function panics iff `offset + values.len()` is larger than the length of the
+    /// [`MutableBuffer`].
+    #[inline]
+    pub fn write_slice_at(&mut self, offset: usize, values: &[T]) {
+        let end = offset.checked_add(values.len());
+        assert!(
+            matches!(end, Some(end) if end <= self.values.len()),
+            "Writing {} values at offset {} is out of bounds of a buffer of length {}",
+            values.len(),
+            offset,
+            self.values.len()
+        );
+        self.values[offset..offset + values.len()].copy_from_slice(values)
+    }
+
+    /// Returns the underlying [`Vec`] of the [`MutableBuffer`].
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
+    }
+}
+
+impl<T: NativeType> Extend<T> for MutableBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.values.extend(iter)
+    }
+}
+
+impl<T: NativeType> FromIterator<T> for MutableBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            values: Vec::from_iter(iter),
+        }
+    }
+}
+
+impl<T: NativeType> From<Vec<T>> for MutableBuffer<T> {
+    #[inline]
+    fn from(values: Vec<T>) -> Self {
+        Self { values }
+    }
+}
+
+impl<T: NativeType> From<MutableBuffer<T>> for Buffer<T> {
+    #[inline]
+    fn from(buffer: MutableBuffer<T>) -> Self {
+        buffer.values.into()
+    }
+}
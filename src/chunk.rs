@@ -1,8 +1,13 @@
 //! Contains [`Chunk`], a container of [`Array`] where every array has the
 //! same length.
 
-use crate::array::Array;
+use std::sync::Arc;
+
+use crate::array::{check_slice, Array, FixedSizeListArray, ListArray, StructArray};
+use crate::datatypes::{child_field, DataType, Field, Schema};
 use crate::error::{ArrowError, Result};
+#[cfg(feature = "compute_concatenate")]
+use crate::{array::new_empty_array, compute::concatenate::concatenate};
 
 /// A vector of trait objects of [`Array`] where every item has
 /// the same length, [`Chunk::len`].
@@ -68,6 +73,202 @@ impl<A: AsRef<dyn Array>> Chunk<A> {
     }
 }
 
+impl Chunk<Arc<dyn Array>> {
+    /// Returns the rows `offset..offset + length` of this [`Chunk`], slicing every array
+    /// without copying.
+    /// # Panics
+    /// This function panics iff `offset + length > self.len()`.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        self.try_slice(offset, length).unwrap()
+    }
+
+    /// Returns the rows `offset..offset + length` of this [`Chunk`], slicing every array
+    /// without copying.
+    /// # Errors
+    /// This function errors iff `offset + length > self.len()`, e.g. when they are provided
+    /// by untrusted input.
+    pub fn try_slice(&self, offset: usize, length: usize) -> Result<Self> {
+        check_slice(offset, length, self.len())?;
+        let arrays = self
+            .arrays
+            .iter()
+            .map(|array| unsafe { array.slice_unchecked(offset, length) }.into())
+            .collect();
+        Ok(Self { arrays })
+    }
+
+    /// Returns the (possibly nested) column at the dotted `path` (e.g. `a.b.c`) of this [`Chunk`],
+    /// whose fields are described by `schema`.
+    ///
+    /// The path is resolved as in [`Schema::field_at_path`], and the returned array has the
+    /// data type of the field returned by it: children of a `Struct` inherit its nulls and
+    /// lists wrapping a `Struct` are preserved with the child as their values.
+    /// # Errors
+    /// This function errors iff the path does not exist in `schema`.
+    pub fn column_at_path(&self, schema: &Schema, path: &str) -> Result<Arc<dyn Array>> {
+        schema.field_at_path(path)?;
+
+        let mut components = path.split('.');
+        let first = components.next().unwrap();
+        let index = schema
+            .fields
+            .iter()
+            .position(|field| field.name == first)
+            .unwrap();
+        let mut array = self
+            .arrays
+            .get(index)
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(
+                    "The chunk has less columns than the schema".to_string(),
+                )
+            })?
+            .clone();
+        for name in components {
+            array = child_array(array.as_ref(), name)?;
+        }
+        Ok(array)
+    }
+
+    /// Concatenates `chunks`, whose columns are described by `schema`, into a single [`Chunk`].
+    ///
+    /// Every chunk is verified against `schema` before any column is concatenated, so that
+    /// chunks of different schemas are not concatenated by position.
+    /// # Errors
+    /// This function errors iff a chunk does not match `schema`, i.e. it has a different number
+    /// of columns than `schema` has fields, a column has a different [`DataType`] than its
+    /// field or a column of a non-nullable field has nulls. The error names the offending chunk
+    /// and column.
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow2::array::{Array, Int32Array};
+    /// use arrow2::chunk::Chunk;
+    /// use arrow2::datatypes::{DataType, Field, Schema};
+    ///
+    /// let schema = Schema::from(vec![Field::new("a", DataType::Int32, false)]);
+    /// let chunk1 = Chunk::new(vec![Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>]);
+    /// let chunk2 = Chunk::new(vec![Arc::new(Int32Array::from_slice([3])) as Arc<dyn Array>]);
+    ///
+    /// let chunk = Chunk::concat(&[chunk1, chunk2.clone()], &schema).unwrap();
+    /// assert_eq!(chunk.len(), 3);
+    ///
+    /// let chunk3 = Chunk::new(vec![Arc::new(Int32Array::from([None])) as Arc<dyn Array>]);
+    /// assert!(Chunk::concat(&[chunk2, chunk3], &schema).is_err());
+    /// ```
+    #[cfg(feature = "compute_concatenate")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "compute_concatenate")))]
+    pub fn concat<B: AsRef<dyn Array>>(chunks: &[Chunk<B>], schema: &Schema) -> Result<Self> {
+        chunks
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, chunk)| check_schema(chunk, index, schema))?;
+
+        let arrays = (0..schema.fields.len())
+            .map(|column| {
+                let arrays = chunks
+                    .iter()
+                    .map(|chunk| chunk.arrays()[column].as_ref())
+                    .collect::<Vec<_>>();
+                if arrays.is_empty() {
+                    Ok(new_empty_array(schema.fields[column].data_type().clone()).into())
+                } else {
+                    concatenate(&arrays).map(|array| array.into())
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Chunk::try_new(arrays)
+    }
+}
+
+/// Errors iff the columns of `chunk`, the `index`th chunk, do not match the fields of `schema`.
+#[cfg(feature = "compute_concatenate")]
+fn check_schema<A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    index: usize,
+    schema: &Schema,
+) -> Result<()> {
+    if chunk.arrays().len() != schema.fields.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Chunk {} has {} columns but the schema has {} fields",
+            index,
+            chunk.arrays().len(),
+            schema.fields.len()
+        )));
+    }
+    chunk
+        .arrays()
+        .iter()
+        .zip(schema.fields.iter())
+        .try_for_each(|(array, field)| {
+            let array = array.as_ref();
+            if array.data_type() != field.data_type() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Column \"{}\" of chunk {} has data type {:?} but its field has data type {:?}",
+                    field.name,
+                    index,
+                    array.data_type(),
+                    field.data_type()
+                )));
+            }
+            if !field.is_nullable && array.null_count() > 0 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Column \"{}\" of chunk {} has nulls but its field is not nullable",
+                    field.name, index
+                )));
+            }
+            Ok(())
+        })
+}
+
+/// Returns the child `name` of a `Struct` array, or of the `Struct` wrapped by list arrays.
+fn child_array(array: &dyn Array, name: &str) -> Result<Arc<dyn Array>> {
+    let data_type = child_field(&Field::new("", array.data_type().clone(), true), name)?
+        .data_type()
+        .clone();
+    Ok(match array.data_type() {
+        DataType::Struct(fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let index = fields.iter().position(|field| field.name == name).unwrap();
+            let child = &array.values()[index];
+            match (array.validity(), child.validity()) {
+                (None, _) => child.clone(),
+                (Some(validity), None) => child.with_validity(Some(validity.clone())).into(),
+                (Some(validity), Some(child_validity)) => {
+                    child.with_validity(Some(validity & child_validity)).into()
+                }
+            }
+        }
+        DataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            Arc::new(ListArray::<i32>::from_data(
+                data_type,
+                array.offsets().clone(),
+                child_array(array.values().as_ref(), name)?,
+                array.validity().cloned(),
+            ))
+        }
+        DataType::LargeList(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            Arc::new(ListArray::<i64>::from_data(
+                data_type,
+                array.offsets().clone(),
+                child_array(array.values().as_ref(), name)?,
+                array.validity().cloned(),
+            ))
+        }
+        DataType::FixedSizeList(_, _) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            Arc::new(FixedSizeListArray::from_data(
+                data_type,
+                child_array(array.values().as_ref(), name)?,
+                array.validity().cloned(),
+            ))
+        }
+        _ => unreachable!(),
+    })
+}
+
 impl<A: AsRef<dyn Array>> From<Chunk<A>> for Vec<A> {
     fn from(c: Chunk<A>) -> Self {
         c.into_arrays()
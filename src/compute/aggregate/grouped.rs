@@ -0,0 +1,180 @@
+use std::ops::Add;
+
+use crate::array::{PrimitiveArray, UInt64Array};
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::super::utils::check_same_len;
+
+/// Calls `op` with the group and the value of every slot of `values` whose value and group are
+/// not null, in a single pass.
+fn for_each_grouped<T, F>(
+    values: &PrimitiveArray<T>,
+    groups: &PrimitiveArray<u32>,
+    num_groups: usize,
+    mut op: F,
+) -> Result<()>
+where
+    T: NativeType,
+    F: FnMut(usize, T),
+{
+    check_same_len(values, groups)?;
+    values
+        .iter()
+        .zip(groups.iter())
+        .try_for_each(|(value, group)| {
+            if let (Some(value), Some(group)) = (value, group) {
+                let group = *group as usize;
+                if group >= num_groups {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "The group id {} is out of bounds for {} groups",
+                        group, num_groups
+                    )));
+                }
+                op(group, *value);
+            }
+            Ok(())
+        })
+}
+
+/// Reduces the values of each group via `op`, whose result is null for groups without
+/// (non-null) values.
+fn reduce_grouped<T, F>(
+    values: &PrimitiveArray<T>,
+    groups: &PrimitiveArray<u32>,
+    num_groups: usize,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType,
+    F: Fn(T, T) -> T,
+{
+    let mut result = vec![T::default(); num_groups];
+    let mut validity = MutableBitmap::from_len_zeroed(num_groups);
+    for_each_grouped(values, groups, num_groups, |group, value| {
+        result[group] = if validity.get(group) {
+            op(result[group], value)
+        } else {
+            validity.set(group, true);
+            value
+        };
+    })?;
+
+    Ok(PrimitiveArray::<T>::from_data(
+        values.data_type().clone(),
+        result.into(),
+        validity.into(),
+    ))
+}
+
+/// Returns the sum of the values of each of the `num_groups` groups, where the group of
+/// slot `i` of `values` is slot `i` of `groups`.
+///
+/// Null values and values whose group is null are ignored. The sum of a group without
+/// values is null.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::aggregate::grouped_sum;
+///
+/// let values = Int32Array::from([Some(1), Some(2), None, Some(4)]);
+/// let groups = UInt32Array::from_slice([0, 2, 1, 0]);
+/// let result = grouped_sum(&values, &groups, 3).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(5), None, Some(2)]));
+/// ```
+/// # Errors
+/// This function errors iff the arrays have a different length or a group id is not
+/// smaller than `num_groups`.
+pub fn grouped_sum<T>(
+    values: &PrimitiveArray<T>,
+    groups: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Add<Output = T>,
+{
+    reduce_grouped(values, groups, num_groups, |acc, value| acc + value)
+}
+
+/// Returns the minimum of the values of each of the `num_groups` groups, where the group of
+/// slot `i` of `values` is slot `i` of `groups` (see [`grouped_sum`]).
+///
+/// Values are compared with [`PartialOrd`], so that NaN values are only the minimum of a
+/// group whose first value is NaN.
+/// # Errors
+/// This function errors iff the arrays have a different length or a group id is not
+/// smaller than `num_groups`.
+pub fn grouped_min<T>(
+    values: &PrimitiveArray<T>,
+    groups: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    reduce_grouped(values, groups, num_groups, |acc, value| {
+        if value < acc {
+            value
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns the maximum of the values of each of the `num_groups` groups, where the group of
+/// slot `i` of `values` is slot `i` of `groups` (see [`grouped_sum`]).
+///
+/// Values are compared with [`PartialOrd`], so that NaN values are only the maximum of a
+/// group whose first value is NaN.
+/// # Errors
+/// This function errors iff the arrays have a different length or a group id is not
+/// smaller than `num_groups`.
+pub fn grouped_max<T>(
+    values: &PrimitiveArray<T>,
+    groups: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + PartialOrd,
+{
+    reduce_grouped(values, groups, num_groups, |acc, value| {
+        if value > acc {
+            value
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns the number of (non-null) values of each of the `num_groups` groups, where the
+/// group of slot `i` of `values` is slot `i` of `groups` (see [`grouped_sum`]).
+///
+/// The count of a group without values is zero.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array, UInt64Array};
+/// use arrow2::compute::aggregate::grouped_count;
+///
+/// let values = Int32Array::from([Some(1), Some(2), None, Some(4)]);
+/// let groups = UInt32Array::from([Some(0), Some(2), Some(1), None]);
+/// let result = grouped_count(&values, &groups, 3).unwrap();
+/// assert_eq!(result, UInt64Array::from_slice([1, 0, 1]));
+/// ```
+/// # Errors
+/// This function errors iff the arrays have a different length or a group id is not
+/// smaller than `num_groups`.
+pub fn grouped_count<T: NativeType>(
+    values: &PrimitiveArray<T>,
+    groups: &PrimitiveArray<u32>,
+    num_groups: usize,
+) -> Result<UInt64Array> {
+    let mut counts = vec![0u64; num_groups];
+    for_each_grouped(values, groups, num_groups, |group, _| counts[group] += 1)?;
+    Ok(UInt64Array::from_data(
+        DataType::UInt64,
+        counts.into(),
+        None,
+    ))
+}
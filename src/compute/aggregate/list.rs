@@ -0,0 +1,361 @@
+use std::ops::Add;
+
+use num_traits::AsPrimitive;
+
+use crate::array::{Array, ListArray, Offset, PrimitiveArray};
+use crate::bitmap::utils::{count_zeros, BitChunks};
+use crate::bitmap::MutableBitmap;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::simd::*;
+use crate::types::NativeType;
+
+use super::min_max::{
+    nonnull_max_primitive, nonnull_min_primitive, null_max_primitive_impl, null_min_primitive_impl,
+};
+use super::sum::{nonnull_sum, null_sum_impl};
+use super::{SimdOrd, Sum};
+
+/// The validity of the values of a sublist: the bytes of the validity of the values of the
+/// list and the offset, in bits, of the first value of the sublist. `None` when the values of
+/// the list have no validity.
+type Validity<'a> = Option<(&'a [u8], usize)>;
+
+/// Reduces each sublist of `array` to a value of type `R` via `op`, which receives the values
+/// of the sublist, a slice of the values of the list, and their [`Validity`]. The result is
+/// null whenever the sublist is null or `op` returns `None`.
+fn reduce<O, T, R, F>(array: &ListArray<O>, data_type: DataType, op: F) -> Result<PrimitiveArray<R>>
+where
+    O: Offset,
+    T: NativeType,
+    R: NativeType,
+    F: Fn(&[T], Validity) -> Option<R>,
+{
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T>>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The values of the list must be a primitive array of type {:?}",
+                T::PRIMITIVE
+            ))
+        })?;
+    let validity = values.validity().map(|validity| {
+        let (bytes, offset, _) = validity.as_slice();
+        (bytes, offset)
+    });
+    let values = values.values().as_slice();
+
+    let mut result = Vec::<R>::with_capacity(array.len());
+    let mut result_validity = MutableBitmap::with_capacity(array.len());
+    for (i, window) in array.offsets().windows(2).enumerate() {
+        let start = window[0].to_usize();
+        let end = window[1].to_usize();
+        let value = if array.is_valid(i) {
+            let validity = validity.map(|(bytes, offset)| (bytes, offset + start));
+            op(&values[start..end], validity)
+        } else {
+            None
+        };
+        result.push(value.unwrap_or_default());
+        result_validity.push(value.is_some());
+    }
+
+    Ok(PrimitiveArray::<R>::from_data(
+        data_type,
+        result.into(),
+        result_validity.into(),
+    ))
+}
+
+/// Returns the number of null values of a sublist of `length` values.
+#[inline]
+fn null_count(validity: Validity, length: usize) -> usize {
+    validity.map_or(0, |(bytes, offset)| count_zeros(bytes, offset, length))
+}
+
+/// Returns the validity of a sublist of `length` values as chunks of bits, when it has nulls.
+#[inline]
+fn validity_chunks<'a, T: Simd>(
+    validity: Validity<'a>,
+    length: usize,
+    null_count: usize,
+) -> Option<BitChunks<'a, <T::Simd as NativeSimd>::Chunk>> {
+    validity
+        .filter(|_| null_count > 0)
+        .map(|(bytes, offset)| BitChunks::new(bytes, offset, length))
+}
+
+/// Returns the sum of the non-null `values`, or `None` if they are all null.
+fn sum_values<T>(values: &[T], validity: Validity) -> Option<T>
+where
+    T: NativeType + Simd + Add<Output = T> + std::iter::Sum<T>,
+    T::Simd: Add<Output = T::Simd> + Sum<T>,
+{
+    let null_count = null_count(validity, values.len());
+    if null_count == values.len() {
+        return None;
+    }
+    Some(
+        match validity_chunks::<T>(validity, values.len(), null_count) {
+            Some(chunks) => null_sum_impl(values, chunks),
+            None => nonnull_sum(values),
+        },
+    )
+}
+
+/// Returns the minimum of the non-null `values`, or `None` if they are all null.
+fn min_values<T>(values: &[T], validity: Validity) -> Option<T>
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let null_count = null_count(validity, values.len());
+    if null_count == values.len() {
+        return None;
+    }
+    Some(
+        match validity_chunks::<T>(validity, values.len(), null_count) {
+            Some(chunks) => null_min_primitive_impl(values, chunks),
+            None => nonnull_min_primitive(values),
+        },
+    )
+}
+
+/// Returns the maximum of the non-null `values`, or `None` if they are all null.
+fn max_values<T>(values: &[T], validity: Validity) -> Option<T>
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let null_count = null_count(validity, values.len());
+    if null_count == values.len() {
+        return None;
+    }
+    Some(
+        match validity_chunks::<T>(validity, values.len(), null_count) {
+            Some(chunks) => null_max_primitive_impl(values, chunks),
+            None => nonnull_max_primitive(values),
+        },
+    )
+}
+
+/// Returns the sum of the values of each sublist of `array`, whose values must be a
+/// [`PrimitiveArray<T>`].
+///
+/// The sum of a sublist is null if the sublist is null, empty or only contains nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::aggregate::list_sum_primitive;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array
+///     .try_extend(vec![Some(vec![Some(1), Some(2)]), None, Some(vec![None, Some(3)]), Some(vec![])])
+///     .unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_sum_primitive::<i32, i32>(&array).unwrap();
+/// assert_eq!(result, Int32Array::from([Some(3), None, Some(3), None]));
+/// ```
+/// # Errors
+/// This function errors iff the values of `array` are not a [`PrimitiveArray<T>`].
+pub fn list_sum_primitive<O, T>(array: &ListArray<O>) -> Result<PrimitiveArray<T>>
+where
+    O: Offset,
+    T: NativeType + Simd + Add<Output = T> + std::iter::Sum<T>,
+    T::Simd: Add<Output = T::Simd> + Sum<T>,
+{
+    let data_type = ListArray::<O>::get_child_type(array.data_type()).clone();
+    reduce(array, data_type, sum_values)
+}
+
+/// Returns the minimum of the values of each sublist of `array`, whose values must be a
+/// [`PrimitiveArray<T>`], according to the natural order (see [`min_primitive`](super::min_primitive)).
+///
+/// The minimum of a sublist is null if the sublist is null, empty or only contains nulls.
+/// # Errors
+/// This function errors iff the values of `array` are not a [`PrimitiveArray<T>`].
+pub fn list_min_primitive<O, T>(array: &ListArray<O>) -> Result<PrimitiveArray<T>>
+where
+    O: Offset,
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let data_type = ListArray::<O>::get_child_type(array.data_type()).clone();
+    reduce(array, data_type, min_values)
+}
+
+/// Returns the maximum of the values of each sublist of `array`, whose values must be a
+/// [`PrimitiveArray<T>`], according to the natural order (see [`max_primitive`](super::max_primitive)).
+///
+/// The maximum of a sublist is null if the sublist is null, empty or only contains nulls.
+/// # Errors
+/// This function errors iff the values of `array` are not a [`PrimitiveArray<T>`].
+pub fn list_max_primitive<O, T>(array: &ListArray<O>) -> Result<PrimitiveArray<T>>
+where
+    O: Offset,
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let data_type = ListArray::<O>::get_child_type(array.data_type()).clone();
+    reduce(array, data_type, max_values)
+}
+
+/// Returns the arithmetic mean of the values of each sublist of `array`, whose values must be
+/// a [`PrimitiveArray<T>`]. Null values are not counted, i.e. the sum of each sublist is
+/// divided by its number of non-null values.
+///
+/// The mean of a sublist is null if the sublist is null, empty or only contains nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, ListArray, MutableListArray, MutablePrimitiveArray, TryExtend};
+/// use arrow2::compute::aggregate::list_mean_primitive;
+///
+/// let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+/// array
+///     .try_extend(vec![Some(vec![Some(1), Some(2)]), Some(vec![None, Some(3)]), Some(vec![])])
+///     .unwrap();
+/// let array: ListArray<i32> = array.into();
+///
+/// let result = list_mean_primitive::<i32, i32>(&array).unwrap();
+/// assert_eq!(result, Float64Array::from([Some(1.5), Some(3.0), None]));
+/// ```
+/// # Errors
+/// This function errors iff the values of `array` are not a [`PrimitiveArray<T>`].
+pub fn list_mean_primitive<O, T>(array: &ListArray<O>) -> Result<PrimitiveArray<f64>>
+where
+    O: Offset,
+    T: NativeType + Simd + Add<Output = T> + std::iter::Sum<T> + AsPrimitive<f64>,
+    T::Simd: Add<Output = T::Simd> + Sum<T>,
+{
+    reduce(array, DataType::Float64, |values: &[T], validity| {
+        let count = values.len() - null_count(validity, values.len());
+        sum_values(values, validity).map(|sum| sum.as_() / count as f64)
+    })
+}
+
+macro_rules! dyn_list {
+    ($array:expr, $op:ident, $name:expr, $is_supported:ident) => {{
+        fn dispatch<O: Offset>(array: &ListArray<O>) -> Result<Box<dyn Array>> {
+            let data_type = ListArray::<O>::get_child_type(array.data_type());
+            if !$is_supported(data_type) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The `{}` operator does not support lists of type `{:?}`",
+                    $name, data_type,
+                )));
+            }
+            Ok(match data_type.to_logical_type() {
+                DataType::Int8 => Box::new($op::<O, i8>(array)?),
+                DataType::Int16 => Box::new($op::<O, i16>(array)?),
+                DataType::Int32
+                | DataType::Date32
+                | DataType::Time32(_)
+                | DataType::Interval(IntervalUnit::YearMonth) => Box::new($op::<O, i32>(array)?),
+                DataType::Int64
+                | DataType::Date64
+                | DataType::Time64(_)
+                | DataType::Timestamp(_, _)
+                | DataType::Duration(_) => Box::new($op::<O, i64>(array)?),
+                DataType::UInt8 => Box::new($op::<O, u8>(array)?),
+                DataType::UInt16 => Box::new($op::<O, u16>(array)?),
+                DataType::UInt32 => Box::new($op::<O, u32>(array)?),
+                DataType::UInt64 => Box::new($op::<O, u64>(array)?),
+                DataType::Float32 => Box::new($op::<O, f32>(array)?),
+                DataType::Float64 => Box::new($op::<O, f64>(array)?),
+                _ => unreachable!(),
+            })
+        }
+
+        match $array.data_type().to_logical_type() {
+            DataType::List(_) => dispatch::<i32>($array.as_any().downcast_ref().unwrap()),
+            DataType::LargeList(_) => dispatch::<i64>($array.as_any().downcast_ref().unwrap()),
+            other => Err(ArrowError::InvalidArgumentError(format!(
+                "The `{}` operator does not support type `{:?}`",
+                $name, other,
+            ))),
+        }
+    }};
+}
+
+/// Whether the values of a list of `data_type` can be aggregated by [`list_sum`],
+/// [`list_min`] and [`list_max`].
+fn is_temporal_or_numeric(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type.to_logical_type(),
+        Int8 | Int16
+            | Int32
+            | Date32
+            | Time32(_)
+            | Interval(IntervalUnit::YearMonth)
+            | Int64
+            | Date64
+            | Time64(_)
+            | Timestamp(_, _)
+            | Duration(_)
+            | UInt8
+            | UInt16
+            | UInt32
+            | UInt64
+            | Float32
+            | Float64
+    )
+}
+
+/// Whether the values of a list of `data_type` can be aggregated by [`list_mean`].
+fn is_numeric(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type.to_logical_type(),
+        Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64
+    )
+}
+
+/// Returns the sum of the values of each sublist of a \[Large\]List `array` of primitive values,
+/// as an array of the logical type of the values (see [`list_sum_primitive`]).
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]List of a numeric or temporal type.
+pub fn list_sum(array: &dyn Array) -> Result<Box<dyn Array>> {
+    dyn_list!(
+        array,
+        list_sum_primitive,
+        "list_sum",
+        is_temporal_or_numeric
+    )
+}
+
+/// Returns the minimum of the values of each sublist of a \[Large\]List `array` of primitive
+/// values, as an array of the logical type of the values (see [`list_min_primitive`]).
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]List of a numeric or temporal type.
+pub fn list_min(array: &dyn Array) -> Result<Box<dyn Array>> {
+    dyn_list!(
+        array,
+        list_min_primitive,
+        "list_min",
+        is_temporal_or_numeric
+    )
+}
+
+/// Returns the maximum of the values of each sublist of a \[Large\]List `array` of primitive
+/// values, as an array of the logical type of the values (see [`list_max_primitive`]).
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]List of a numeric or temporal type.
+pub fn list_max(array: &dyn Array) -> Result<Box<dyn Array>> {
+    dyn_list!(
+        array,
+        list_max_primitive,
+        "list_max",
+        is_temporal_or_numeric
+    )
+}
+
+/// Returns the mean of the values of each sublist of a \[Large\]List `array` of numeric
+/// values, as a `Float64` array (see [`list_mean_primitive`]).
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]List of a numeric type.
+pub fn list_mean(array: &dyn Array) -> Result<Box<dyn Array>> {
+    dyn_list!(array, list_mean_primitive, "list_mean", is_numeric)
+}
@@ -1,3 +1,5 @@
+use multiversion::multiversion;
+
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::error::{ArrowError, Result};
@@ -31,7 +33,7 @@ pub trait SimdOrd<T> {
 }
 
 /// Helper to compute min/max of [`BinaryArray`]
-fn min_max_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
+fn reduce_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
     array: &BinaryArray<O>,
     cmp: F,
 ) -> Option<&[u8]> {
@@ -71,7 +73,7 @@ fn min_max_binary<O: Offset, F: Fn(&[u8], &[u8]) -> bool>(
 }
 
 /// Helper to compute min/max of [`Utf8Array`]
-fn min_max_string<O: Offset, F: Fn(&str, &str) -> bool>(
+fn reduce_string<O: Offset, F: Fn(&str, &str) -> bool>(
     array: &Utf8Array<O>,
     cmp: F,
 ) -> Option<&str> {
@@ -110,7 +112,9 @@ fn min_max_string<O: Offset, F: Fn(&str, &str) -> bool>(
     value
 }
 
-fn nonnull_min_primitive<T>(values: &[T]) -> T
+#[multiversion]
+#[clone(target = "x86_64+avx")]
+pub(super) fn nonnull_min_primitive<T>(values: &[T]) -> T
 where
     T: NativeType + Simd,
     T::Simd: SimdOrd<T>,
@@ -129,7 +133,9 @@ where
     reduced.min_element()
 }
 
-fn null_min_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> T
+#[multiversion]
+#[clone(target = "x86_64+avx")]
+pub(super) fn null_min_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> T
 where
     T: NativeType + Simd,
     T::Simd: SimdOrd<T>,
@@ -189,7 +195,9 @@ where
     }
 }
 
-fn nonnull_max_primitive<T>(values: &[T]) -> T
+#[multiversion]
+#[clone(target = "x86_64+avx")]
+pub(super) fn nonnull_max_primitive<T>(values: &[T]) -> T
 where
     T: NativeType + Simd,
     T::Simd: SimdOrd<T>,
@@ -208,7 +216,9 @@ where
     reduced.max_element()
 }
 
-fn null_max_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> T
+#[multiversion]
+#[clone(target = "x86_64+avx")]
+pub(super) fn null_max_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> T
 where
     T: NativeType + Simd,
     T::Simd: SimdOrd<T>,
@@ -249,10 +259,9 @@ where
     }
     let values = array.values();
 
-    Some(if let Some(validity) = array.validity() {
-        null_min_primitive(values, validity)
-    } else {
-        nonnull_min_primitive(values)
+    Some(match array.validity() {
+        Some(validity) if null_count > 0 => null_min_primitive(values, validity),
+        _ => nonnull_min_primitive(values),
     })
 }
 
@@ -271,31 +280,153 @@ where
     }
     let values = array.values();
 
-    Some(if let Some(validity) = array.validity() {
-        null_max_primitive(values, validity)
+    Some(match array.validity() {
+        Some(validity) if null_count > 0 => null_max_primitive(values, validity),
+        _ => nonnull_max_primitive(values),
+    })
+}
+
+#[multiversion]
+#[clone(target = "x86_64+avx")]
+fn nonnull_min_max_primitive<T>(values: &[T]) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let chunks = values.chunks_exact(T::Simd::LANES);
+    let remainder = chunks.remainder();
+
+    let (min, max) = chunks.fold(
+        (T::Simd::new_min(), T::Simd::new_max()),
+        |(min, max), chunk| {
+            let chunk = T::Simd::from_chunk(chunk);
+            (min.min(chunk), max.max(chunk))
+        },
+    );
+
+    let min = min.min(T::Simd::from_incomplete_chunk(remainder, T::Simd::MAX));
+    let max = max.max(T::Simd::from_incomplete_chunk(remainder, T::Simd::MIN));
+
+    (min.min_element(), max.max_element())
+}
+
+#[multiversion]
+#[clone(target = "x86_64+avx")]
+fn null_min_max_primitive_impl<T, I>(values: &[T], mut validity_masks: I) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+    I: BitChunkIterExact<<<T as Simd>::Simd as NativeSimd>::Chunk>,
+{
+    let mut chunks = values.chunks_exact(T::Simd::LANES);
+
+    let (min, max) = chunks.by_ref().zip(validity_masks.by_ref()).fold(
+        (T::Simd::new_min(), T::Simd::new_max()),
+        |(min, max), (chunk, validity_chunk)| {
+            let chunk = T::Simd::from_chunk(chunk);
+            let mask = || <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+            (
+                min.min(chunk.select(mask(), T::Simd::new_min())),
+                max.max(chunk.select(mask(), T::Simd::new_max())),
+            )
+        },
+    );
+
+    let validity_chunk = validity_masks.remainder();
+    let mask = || <T::Simd as NativeSimd>::Mask::from_chunk(validity_chunk);
+    let remainder = T::Simd::from_incomplete_chunk(chunks.remainder(), T::Simd::MAX);
+    let min = min.min(remainder.select(mask(), T::Simd::new_min()));
+    let remainder = T::Simd::from_incomplete_chunk(chunks.remainder(), T::Simd::MIN);
+    let max = max.max(remainder.select(mask(), T::Simd::new_max()));
+
+    (min.min_element(), max.max_element())
+}
+
+/// # Panics
+/// iff `values.len() != bitmap.len()`.
+fn null_min_max_primitive<T>(values: &[T], bitmap: &Bitmap) -> (T, T)
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let (slice, offset, length) = bitmap.as_slice();
+    if offset == 0 {
+        let validity_masks = BitChunksExact::<<T::Simd as NativeSimd>::Chunk>::new(slice, length);
+        null_min_max_primitive_impl(values, validity_masks)
     } else {
-        nonnull_max_primitive(values)
+        let validity_masks = bitmap.chunks::<<T::Simd as NativeSimd>::Chunk>();
+        null_min_max_primitive_impl(values, validity_masks)
+    }
+}
+
+/// Returns the minimum and maximum values in the array in a single pass over its values, i.e.
+/// `(min_primitive(array), max_primitive(array))` in about half the time.
+/// For floating point arrays any NaN values are considered to be greater than any other non-null value
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::aggregate::min_max_primitive;
+///
+/// let a = Int32Array::from([Some(5), None, Some(-1), Some(3)]);
+/// assert_eq!(min_max_primitive(&a), Some((-1, 5)));
+/// ```
+pub fn min_max_primitive<T>(array: &PrimitiveArray<T>) -> Option<(T, T)>
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let null_count = array.null_count();
+
+    // Includes case array.len() == 0
+    if null_count == array.len() {
+        return None;
+    }
+    let values = array.values();
+
+    Some(match array.validity() {
+        Some(validity) if null_count > 0 => null_min_max_primitive(values, validity),
+        _ => nonnull_min_max_primitive(values),
     })
 }
 
+/// Returns the minimum and maximum values over all `arrays`, e.g. the chunks of a column,
+/// according to the same order as [`min_max_primitive`].
+pub fn min_max_primitive_chunked<T>(arrays: &[&PrimitiveArray<T>]) -> Option<(T, T)>
+where
+    T: NativeType + Simd,
+    T::Simd: SimdOrd<T>,
+{
+    let (mins, maxs): (Vec<T>, Vec<T>) = arrays
+        .iter()
+        .filter_map(|array| min_max_primitive(array))
+        .unzip();
+    if mins.is_empty() {
+        return None;
+    }
+    // reduce the bounds of each array with the same order used to compute them
+    Some((
+        nonnull_min_max_primitive(&mins).0,
+        nonnull_min_max_primitive(&maxs).1,
+    ))
+}
+
 /// Returns the maximum value in the binary array, according to the natural order.
 pub fn max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
-    min_max_binary(array, |a, b| a < b)
+    reduce_binary(array, |a, b| a < b)
 }
 
 /// Returns the minimum value in the binary array, according to the natural order.
 pub fn min_binary<O: Offset>(array: &BinaryArray<O>) -> Option<&[u8]> {
-    min_max_binary(array, |a, b| a > b)
+    reduce_binary(array, |a, b| a > b)
 }
 
 /// Returns the maximum value in the string array, according to the natural order.
 pub fn max_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
-    min_max_string(array, |a, b| a < b)
+    reduce_string(array, |a, b| a < b)
 }
 
 /// Returns the minimum value in the string array, according to the natural order.
 pub fn min_string<O: Offset>(array: &Utf8Array<O>) -> Option<&str> {
-    min_max_string(array, |a, b| a > b)
+    reduce_string(array, |a, b| a > b)
 }
 
 /// Returns the minimum value in the boolean array.
@@ -348,6 +479,56 @@ pub fn max_boolean(array: &BooleanArray) -> Option<bool> {
         .or(Some(false))
 }
 
+/// Returns the minimum and maximum values in the binary array in a single pass, according to
+/// the natural order.
+pub fn min_max_binary<O: Offset>(array: &BinaryArray<O>) -> Option<(&[u8], &[u8])> {
+    reduce_bounds(array.iter().flatten().map(|x| (x, x)))
+}
+
+/// Returns the minimum and maximum values in the string array in a single pass, according to
+/// the natural order.
+pub fn min_max_string<O: Offset>(array: &Utf8Array<O>) -> Option<(&str, &str)> {
+    reduce_bounds(array.iter().flatten().map(|x| (x, x)))
+}
+
+/// Returns the minimum and maximum values in the boolean array in a single pass.
+///
+/// ```
+/// use arrow2::{
+///   array::BooleanArray,
+///   compute::aggregate::min_max_boolean,
+/// };
+///
+/// let a = BooleanArray::from(vec![Some(true), None, Some(false)]);
+/// assert_eq!(min_max_boolean(&a), Some((false, true)))
+/// ```
+pub fn min_max_boolean(array: &BooleanArray) -> Option<(bool, bool)> {
+    // short circuit if all nulls / zero length array
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    if array.validity().is_none() {
+        let false_count = array.values().null_count();
+        return Some((false_count == 0, false_count < array.len()));
+    }
+
+    // short circuit as soon as both values are seen
+    let mut has_false = false;
+    let mut has_true = false;
+    for value in array.iter().flatten() {
+        if value {
+            has_true = true;
+        } else {
+            has_false = true;
+        }
+        if has_true && has_false {
+            break;
+        }
+    }
+    Some((!has_false, has_true))
+}
+
 macro_rules! dyn_primitive {
     ($ty:ty, $array:expr, $f:ident) => {{
         let array = $array
@@ -449,3 +630,137 @@ pub fn min(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         }
     })
 }
+
+/// Reduces `(min, max)` bounds, e.g. of each array, to the bounds over all of them.
+fn reduce_bounds<T: PartialOrd + Copy, I: Iterator<Item = (T, T)>>(bounds: I) -> Option<(T, T)> {
+    bounds.fold(None, |acc, (min, max)| match acc {
+        None => Some((min, max)),
+        Some((acc_min, acc_max)) => Some((
+            if min < acc_min { min } else { acc_min },
+            if max > acc_max { max } else { acc_max },
+        )),
+    })
+}
+
+fn unzip<T>(bounds: Option<(T, T)>) -> (Option<T>, Option<T>) {
+    match bounds {
+        Some((min, max)) => (Some(min), Some(max)),
+        None => (None, None),
+    }
+}
+
+macro_rules! dyn_min_max_primitive {
+    ($ty:ty, $data_type:expr, $arrays:expr) => {{
+        let arrays = $arrays
+            .iter()
+            .map(|array| {
+                array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<$ty>>()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let (min, max) = unzip(min_max_primitive_chunked::<$ty>(&arrays));
+        (
+            Box::new(PrimitiveScalar::<$ty>::new($data_type.clone(), min)) as Box<dyn Scalar>,
+            Box::new(PrimitiveScalar::<$ty>::new($data_type.clone(), max)) as Box<dyn Scalar>,
+        )
+    }};
+}
+
+macro_rules! dyn_min_max_generic {
+    ($array_ty:ty, $scalar_ty:ty, $arrays:expr, $f:ident) => {{
+        let bounds = $arrays
+            .iter()
+            .filter_map(|array| $f(array.as_any().downcast_ref::<$array_ty>().unwrap()));
+        let (min, max) = unzip(reduce_bounds(bounds));
+        (
+            Box::new(<$scalar_ty>::new(min)) as Box<dyn Scalar>,
+            Box::new(<$scalar_ty>::new(max)) as Box<dyn Scalar>,
+        )
+    }};
+}
+
+/// Returns the minimum and maximum of [`Array`] in a single pass over it, i.e. the same as
+/// `(min(array)?, max(array)?)`. The scalars are null when all elements are null.
+/// # Error
+/// Errors iff the type does not support this operation.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::aggregate::min_max;
+/// use arrow2::scalar::Utf8Scalar;
+///
+/// let array = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("c")]);
+/// let (min, max) = min_max(&array).unwrap();
+/// assert_eq!(min.as_ref(), &Utf8Scalar::<i32>::new(Some("a")) as &dyn arrow2::scalar::Scalar);
+/// assert_eq!(max.as_ref(), &Utf8Scalar::<i32>::new(Some("c")) as &dyn arrow2::scalar::Scalar);
+/// ```
+pub fn min_max(array: &dyn Array) -> Result<(Box<dyn Scalar>, Box<dyn Scalar>)> {
+    min_max_chunked(&[array])
+}
+
+/// Returns the minimum and maximum over all `arrays`, e.g. the chunks of a column, in a single
+/// pass over them. The scalars are null when all elements are null or `arrays` is empty.
+/// # Error
+/// Errors iff `arrays` is empty, the arrays have different data types or the type does not
+/// support this operation.
+pub fn min_max_chunked(arrays: &[&dyn Array]) -> Result<(Box<dyn Scalar>, Box<dyn Scalar>)> {
+    let data_type = arrays
+        .first()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(
+                "The `min_max` operator requires at least one array".to_string(),
+            )
+        })?
+        .data_type();
+    if arrays.iter().any(|array| array.data_type() != data_type) {
+        return Err(ArrowError::InvalidArgumentError(
+            "The `min_max` operator requires all arrays to have the same data type".to_string(),
+        ));
+    }
+
+    Ok(match data_type {
+        DataType::Boolean => {
+            dyn_min_max_generic!(BooleanArray, BooleanScalar, arrays, min_max_boolean)
+        }
+        DataType::Int8 => dyn_min_max_primitive!(i8, data_type, arrays),
+        DataType::Int16 => dyn_min_max_primitive!(i16, data_type, arrays),
+        DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => {
+            dyn_min_max_primitive!(i32, data_type, arrays)
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => dyn_min_max_primitive!(i64, data_type, arrays),
+        DataType::UInt8 => dyn_min_max_primitive!(u8, data_type, arrays),
+        DataType::UInt16 => dyn_min_max_primitive!(u16, data_type, arrays),
+        DataType::UInt32 => dyn_min_max_primitive!(u32, data_type, arrays),
+        DataType::UInt64 => dyn_min_max_primitive!(u64, data_type, arrays),
+        DataType::Float16 => unreachable!(),
+        DataType::Float32 => dyn_min_max_primitive!(f32, data_type, arrays),
+        DataType::Float64 => dyn_min_max_primitive!(f64, data_type, arrays),
+        DataType::Utf8 => {
+            dyn_min_max_generic!(Utf8Array<i32>, Utf8Scalar<i32>, arrays, min_max_string)
+        }
+        DataType::LargeUtf8 => {
+            dyn_min_max_generic!(Utf8Array<i64>, Utf8Scalar<i64>, arrays, min_max_string)
+        }
+        DataType::Binary => {
+            dyn_min_max_generic!(BinaryArray<i32>, BinaryScalar<i32>, arrays, min_max_binary)
+        }
+        DataType::LargeBinary => {
+            dyn_min_max_generic!(BinaryArray<i64>, BinaryScalar<i64>, arrays, min_max_binary)
+        }
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The `min_max` operator does not support type `{:?}`",
+                data_type,
+            )))
+        }
+    })
+}
@@ -1,4 +1,11 @@
 //! Contains different aggregation functions
+//!
+//! The aggregations of [`PrimitiveArray`](crate::array::PrimitiveArray)s (e.g. [`sum_primitive`]
+//! and [`min_max_primitive`]) reduce values in lanes of the [`Simd`](crate::types::simd::Simd)
+//! type of each [`NativeType`](crate::types::NativeType), where null slots are masked out via
+//! the chunks of their validity and arrays without nulls skip the masking altogether.
+//! With the feature `simd`, lanes are explicit SIMD types of `packed_simd`; otherwise,
+//! they are arrays that the compiler auto-vectorizes.
 mod sum;
 pub use sum::*;
 
@@ -7,4 +14,10 @@ pub use min_max::*;
 
 mod memory;
 pub use memory::*;
+
+mod grouped;
+pub use grouped::*;
+
+mod list;
+pub use list::*;
 mod simd;
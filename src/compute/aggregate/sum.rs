@@ -1,6 +1,7 @@
 use std::ops::Add;
 
 use multiversion::multiversion;
+use num_traits::Float;
 
 use crate::bitmap::utils::{BitChunkIterExact, BitChunksExact};
 use crate::datatypes::{DataType, IntervalUnit};
@@ -22,7 +23,7 @@ pub trait Sum<T> {
 
 #[multiversion]
 #[clone(target = "x86_64+avx")]
-fn nonnull_sum<T>(values: &[T]) -> T
+pub(super) fn nonnull_sum<T>(values: &[T]) -> T
 where
     T: NativeType + Simd + Add<Output = T> + std::iter::Sum<T>,
     T::Simd: Sum<T> + Add<Output = T::Simd>,
@@ -41,7 +42,7 @@ where
 /// iff `values.len() != bitmap.len()` or the operation overflows.
 #[multiversion]
 #[clone(target = "x86_64+avx")]
-fn null_sum_impl<T, I>(values: &[T], mut validity_masks: I) -> T
+pub(super) fn null_sum_impl<T, I>(values: &[T], mut validity_masks: I) -> T
 where
     T: NativeType + Simd,
     T::Simd: Add<Output = T::Simd> + Sum<T>,
@@ -99,8 +100,8 @@ where
     }
 
     match array.validity() {
-        None => Some(nonnull_sum(array.values())),
-        Some(bitmap) => Some(null_sum(array.values(), bitmap)),
+        Some(bitmap) if null_count > 0 => Some(null_sum(array.values(), bitmap)),
+        _ => Some(nonnull_sum(array.values())),
     }
 }
 
@@ -174,3 +175,99 @@ pub fn sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
         }
     })
 }
+
+/// Returns `sum + value` and the low-order bits lost by it (Kahan-Babuška-Neumaier).
+#[inline]
+fn two_sum<T: Float>(sum: T, value: T) -> (T, T) {
+    let new_sum = sum + value;
+    let lost = if sum.abs() >= value.abs() {
+        (sum - new_sum) + value
+    } else {
+        (value - new_sum) + sum
+    };
+    (new_sum, lost)
+}
+
+/// Adds `value` to the second-order compensated sum `(sum, compensation, compensation2)`
+/// (Klein), where `compensation` is itself compensated so that it can grow with the sum.
+#[inline]
+fn compensated_add<T: Float>((sum, compensation, compensation2): (T, T, T), value: T) -> (T, T, T) {
+    let (sum, lost) = two_sum(sum, value);
+    let (compensation, lost) = two_sum(compensation, lost);
+    (sum, compensation, compensation2 + lost)
+}
+
+/// Returns the sum of values in a floating point array using (second-order) compensated
+/// summation, whose error does not grow with the number of values, contrarily to
+/// [`sum_primitive`]. It is slower than [`sum_primitive`], which is vectorized.
+///
+/// Returns `None` if the array is empty or only contains null values.
+/// # Example
+/// ```
+/// use arrow2::array::Float32Array;
+/// use arrow2::compute::aggregate::{compensated_sum_primitive, sum_primitive};
+///
+/// let array = Float32Array::from_vec(vec![0.1; 1_000_000]);
+/// assert!((compensated_sum_primitive(&array).unwrap() - 100_000.0).abs() < 0.1);
+/// assert!((sum_primitive(&array).unwrap() - 100_000.0).abs() > 1.0);
+/// ```
+pub fn compensated_sum_primitive<T>(array: &PrimitiveArray<T>) -> Option<T>
+where
+    T: NativeType + Float,
+{
+    let null_count = array.null_count();
+
+    if null_count == array.len() {
+        return None;
+    }
+
+    let initial = (T::zero(), T::zero(), T::zero());
+    let (sum, compensation, compensation2) = match array.validity() {
+        Some(bitmap) if null_count > 0 => array
+            .values()
+            .iter()
+            .zip(bitmap.iter())
+            .filter(|(_, is_valid)| *is_valid)
+            .fold(initial, |acc, (value, _)| compensated_add(acc, *value)),
+        _ => array
+            .values()
+            .iter()
+            .fold(initial, |acc, value| compensated_add(acc, *value)),
+    };
+    Some(sum + (compensation + compensation2))
+}
+
+/// Whether [`compensated_sum`] is valid for `data_type`
+pub fn can_compensated_sum(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Float32 | DataType::Float64)
+}
+
+/// Returns the sum of all elements in a floating point `array` using compensated summation
+/// (see [`compensated_sum_primitive`]), as a [`Scalar`] of the same data type as `array`.
+/// # Error
+/// Errors iff the operation is not supported.
+pub fn compensated_sum(array: &dyn Array) -> Result<Box<dyn Scalar>> {
+    macro_rules! dyn_compensated_sum {
+        ($ty:ty) => {{
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<$ty>>()
+                .unwrap();
+            Box::new(PrimitiveScalar::<$ty>::new(
+                array.data_type().clone(),
+                compensated_sum_primitive(array),
+            ))
+        }};
+    }
+
+    Ok(match array.data_type() {
+        DataType::Float32 => dyn_compensated_sum!(f32),
+        DataType::Float64 => dyn_compensated_sum!(f64),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The `compensated_sum` operator does not support type `{:?}`",
+                array.data_type(),
+            )))
+        }
+    })
+}
@@ -0,0 +1,215 @@
+//! Defines [`approx_distinct`], that estimates the number of distinct values of an array, and
+//! [`HyperLogLog`], the mergeable sketch behind it, e.g. to estimate the cardinality of a column
+//! split across many chunks or files (for profiling and query planning) in constant memory.
+use crate::array::{Array, Float32Array, Float64Array};
+use crate::compute::arity::unary;
+use crate::compute::hash::{can_hash, hash, hash_primitive};
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+
+/// The smallest precision of a [`HyperLogLog`].
+pub const MIN_PRECISION: u8 = 4;
+/// The largest precision of a [`HyperLogLog`].
+pub const MAX_PRECISION: u8 = 18;
+/// The precision of [`HyperLogLog::default`] and [`approx_distinct`], whose standard error
+/// is about 1.6%.
+pub const DEFAULT_PRECISION: u8 = 12;
+
+/// A HyperLogLog sketch, that estimates the number of distinct (non-null) values inserted into
+/// it in a fixed amount of memory (`2^precision` bytes) with a standard error of about
+/// `1.04 / sqrt(2^precision)`.
+///
+/// Sketches of the same precision can be [merged](HyperLogLog::merge), so that the number of
+/// distinct values of many arrays (e.g. the chunks of a column) can be estimated from a sketch
+/// per array. Values are hashed via [`hash`](crate::compute::hash::hash), which is
+/// deterministic, so that sketches built from different arrays of the same type can be merged
+/// and their [registers](HyperLogLog::registers) can be persisted.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::approx_distinct::HyperLogLog;
+///
+/// let mut sketch = HyperLogLog::default();
+/// sketch.update(&Int32Array::from_slice([1, 2, 3])).unwrap();
+///
+/// let mut other = HyperLogLog::default();
+/// other.update(&Int32Array::from([Some(3), None, Some(4)])).unwrap();
+///
+/// sketch.merge(&other).unwrap();
+/// assert_eq!(sketch.count(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new(DEFAULT_PRECISION).unwrap()
+    }
+}
+
+fn check_precision(precision: u8) -> Result<()> {
+    if !(MIN_PRECISION..=MAX_PRECISION).contains(&precision) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The precision of a HyperLogLog must be between {} and {}, got {}",
+            MIN_PRECISION, MAX_PRECISION, precision
+        )));
+    }
+    Ok(())
+}
+
+impl HyperLogLog {
+    /// Returns an empty [`HyperLogLog`] with `2^precision` registers.
+    /// # Errors
+    /// Errors iff `precision` is not between [`MIN_PRECISION`] and [`MAX_PRECISION`].
+    pub fn new(precision: u8) -> Result<Self> {
+        check_precision(precision)?;
+        Ok(Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    /// Returns a [`HyperLogLog`] from its `registers`, e.g. previously persisted from
+    /// [`HyperLogLog::registers`].
+    /// # Errors
+    /// Errors iff the number of registers is not a power of two between `2^MIN_PRECISION` and
+    /// `2^MAX_PRECISION` or a register is larger than possible.
+    pub fn try_from_registers(registers: Vec<u8>) -> Result<Self> {
+        if !registers.len().is_power_of_two() {
+            return Err(ArrowError::InvalidArgumentError(
+                "The number of registers of a HyperLogLog must be a power of two".to_string(),
+            ));
+        }
+        let precision = registers.len().trailing_zeros() as u8;
+        check_precision(precision)?;
+        if registers.iter().any(|x| *x > 65 - precision) {
+            return Err(ArrowError::InvalidArgumentError(
+                "The registers of a HyperLogLog are out of bounds".to_string(),
+            ));
+        }
+        Ok(Self {
+            precision,
+            registers,
+        })
+    }
+
+    /// The precision of this sketch, the base 2 logarithm of its number of registers.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// The registers of this sketch.
+    pub fn registers(&self) -> &[u8] {
+        &self.registers
+    }
+
+    /// Inserts a value into this sketch via its (64 bit) hash.
+    #[inline]
+    pub fn insert_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - self.precision)) as usize;
+        // the remaining bits, with a sentinel so that the rank is bounded by `65 - precision`
+        let remaining = (hash << self.precision) | (1 << (self.precision - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    /// Inserts the non-null values of `array` into this sketch.
+    /// Floats are inserted by their bit patterns (e.g. `0.0` and `-0.0` are distinct).
+    /// # Errors
+    /// Errors iff the type of `array` is not supported, see [`can_approx_distinct`].
+    pub fn update(&mut self, array: &dyn Array) -> Result<()> {
+        let hashes = match array.data_type().to_physical_type() {
+            PhysicalType::Primitive(PrimitiveType::Float32) => {
+                let array = array.as_any().downcast_ref::<Float32Array>().unwrap();
+                hash_primitive(&unary(array, |x| x.to_bits(), DataType::UInt32))
+            }
+            PhysicalType::Primitive(PrimitiveType::Float64) => {
+                let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+                hash_primitive(&unary(array, |x| x.to_bits(), DataType::UInt64))
+            }
+            _ => hash(array)?,
+        };
+        hashes
+            .iter()
+            .flatten()
+            .for_each(|hash| self.insert_hash(*hash));
+        Ok(())
+    }
+
+    /// Merges `other` into this sketch, so that it estimates the number of distinct values
+    /// inserted into either of them.
+    /// # Errors
+    /// Errors iff the sketches have a different precision.
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        if self.precision != other.precision {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot merge HyperLogLogs of precisions {} and {}",
+                self.precision, other.precision
+            )));
+        }
+        self.registers
+            .iter_mut()
+            .zip(other.registers.iter())
+            .for_each(|(lhs, rhs)| *lhs = (*lhs).max(*rhs));
+        Ok(())
+    }
+
+    /// Returns the estimated number of distinct values inserted into this sketch.
+    pub fn count(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum = self
+            .registers
+            .iter()
+            .map(|x| 2f64.powi(-(*x as i32)))
+            .sum::<f64>();
+        let estimate = alpha * m * m / sum;
+
+        // small cardinalities are better estimated via linear counting of empty registers
+        let zeros = self.registers.iter().filter(|x| **x == 0).count();
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+}
+
+/// Returns an estimate of the number of distinct non-null values of `array`, via a
+/// [`HyperLogLog`] of [`DEFAULT_PRECISION`].
+/// Use [`HyperLogLog`] to estimate the number of distinct values across arrays.
+/// # Errors
+/// Errors iff the type of `array` is not supported, see [`can_approx_distinct`].
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::approx_distinct::approx_distinct;
+///
+/// let array = Utf8Array::<i32>::from([Some("a"), None, Some("b"), Some("a")]);
+/// assert_eq!(approx_distinct(&array).unwrap(), 2);
+/// ```
+pub fn approx_distinct(array: &dyn Array) -> Result<u64> {
+    let mut sketch = HyperLogLog::default();
+    sketch.update(array)?;
+    Ok(sketch.count())
+}
+
+/// Returns whether [`approx_distinct`] and [`HyperLogLog::update`] support arrays of
+/// `data_type`: the types supported by [`can_hash`](crate::compute::hash::can_hash) and floats.
+pub fn can_approx_distinct(data_type: &DataType) -> bool {
+    can_hash(data_type)
+        || matches!(
+            data_type.to_physical_type(),
+            PhysicalType::Primitive(PrimitiveType::Float32)
+                | PhysicalType::Primitive(PrimitiveType::Float64)
+        )
+}
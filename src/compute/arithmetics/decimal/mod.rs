@@ -12,6 +12,7 @@ pub use mul::*;
 mod sub;
 pub use sub::*;
 
+use crate::array::PrimitiveArray;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 
@@ -86,6 +87,29 @@ fn adjusted_precision_scale(
     (res_p, res_s, diff)
 }
 
+// Macro to declare the kernels used by the dynamically dispatched arithmetics: arrays with the
+// same precision and scale use `$op`, while arrays with different ones use `$op_adaptive`, that
+// infers the precision and scale of the result.
+macro_rules! same_or_adaptive {
+    ($name:ident, $op:ident, $op_adaptive:ident) => {
+        pub(super) fn $name(
+            lhs: &PrimitiveArray<i128>,
+            rhs: &PrimitiveArray<i128>,
+        ) -> PrimitiveArray<i128> {
+            if lhs.data_type() == rhs.data_type() {
+                $op(lhs, rhs)
+            } else {
+                $op_adaptive(lhs, rhs).unwrap()
+            }
+        }
+    };
+}
+
+same_or_adaptive!(dyn_add, add, adaptive_add);
+same_or_adaptive!(dyn_sub, sub, adaptive_sub);
+same_or_adaptive!(dyn_mul, mul, adaptive_mul);
+same_or_adaptive!(dyn_div, div, adaptive_div);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -14,12 +14,16 @@
 
 pub mod basic;
 pub mod decimal;
+#[cfg(feature = "compute_cast")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_cast")))]
+pub mod promote;
 pub mod time;
 
 use crate::{
     array::{Array, DictionaryArray, PrimitiveArray},
     bitmap::Bitmap,
     datatypes::{DataType, IntervalUnit, TimeUnit},
+    error::{ArrowError, Result},
     scalar::{PrimitiveScalar, Scalar},
 };
 
@@ -195,6 +199,9 @@ macro_rules! arith_scalar {
 }
 
 /// Adds two [`Array`]s.
+///
+/// Decimal arrays with a different precision or scale are added with
+/// [`decimal::adaptive_add`], that infers the precision and scale of the result.
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_add`] to check)
@@ -205,6 +212,7 @@ pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         lhs,
         rhs,
         add,
+        decimal = dyn_add,
         duration = add_duration,
         interval = add_interval
     )
@@ -255,6 +263,9 @@ pub fn can_add(lhs: &DataType, rhs: &DataType) -> bool {
 }
 
 /// Subtracts two [`Array`]s.
+///
+/// Decimal arrays with a different precision or scale are subtracted with
+/// [`decimal::adaptive_sub`], that infers the precision and scale of the result.
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_sub`] to check)
@@ -265,7 +276,7 @@ pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
         lhs,
         rhs,
         sub,
-        decimal = sub,
+        decimal = dyn_sub,
         duration = subtract_duration,
         timestamp = subtract_timestamps
     )
@@ -316,12 +327,15 @@ pub fn can_sub(lhs: &DataType, rhs: &DataType) -> bool {
 }
 
 /// Multiply two [`Array`]s.
+///
+/// Decimal arrays with a different precision or scale are multiplied with
+/// [`decimal::adaptive_mul`], that infers the precision and scale of the result.
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_mul`] to check)
 /// * the arrays have a different length
 pub fn mul(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    arith!(lhs, rhs, mul, decimal = mul)
+    arith!(lhs, rhs, mul, decimal = dyn_mul)
 }
 
 /// Multiply an [`Array`] with a [`Scalar`].
@@ -352,12 +366,15 @@ pub fn can_mul(lhs: &DataType, rhs: &DataType) -> bool {
 }
 
 /// Divide of two [`Array`]s.
+///
+/// Decimal arrays with a different precision or scale are divided with
+/// [`decimal::adaptive_div`], that infers the precision and scale of the result.
 /// # Panic
 /// This function panics iff
 /// * the opertion is not supported for the logical types (use [`can_div`] to check)
 /// * the arrays have a different length
 pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Box<dyn Array> {
-    arith!(lhs, rhs, div, decimal = div)
+    arith!(lhs, rhs, div, decimal = dyn_div)
 }
 
 /// Divide an [`Array`] with a [`Scalar`].
@@ -400,6 +417,232 @@ pub fn can_rem(lhs: &DataType, rhs: &DataType) -> bool {
     )
 }
 
+// Macro to create a `match` statement with dynamic dispatch to integer functions of `basic`
+// (and, optionally, to functions of `decimal`) based on the array's logical types
+macro_rules! integer_arith {
+    ($lhs:expr, $rhs:expr, $op:tt $(, decimal = $op_decimal:tt )?) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        use DataType::*;
+        Ok(match (lhs.data_type(), rhs.data_type()) {
+            (Int8, Int8) => primitive!(lhs, rhs, $op, i8),
+            (Int16, Int16) => primitive!(lhs, rhs, $op, i16),
+            (Int32, Int32) => primitive!(lhs, rhs, $op, i32),
+            (Int64, Int64) | (Duration(_), Duration(_)) => primitive!(lhs, rhs, $op, i64),
+            (UInt8, UInt8) => primitive!(lhs, rhs, $op, u8),
+            (UInt16, UInt16) => primitive!(lhs, rhs, $op, u16),
+            (UInt32, UInt32) => primitive!(lhs, rhs, $op, u32),
+            (UInt64, UInt64) => primitive!(lhs, rhs, $op, u64),
+            $ (
+            (Decimal(lhs_p, lhs_s), Decimal(rhs_p, rhs_s)) if lhs_p == rhs_p && lhs_s == rhs_s => {
+                let lhs = lhs.as_any().downcast_ref().unwrap();
+                let rhs = rhs.as_any().downcast_ref().unwrap();
+                Box::new(decimal::$op_decimal(lhs, rhs)) as Box<dyn Array>
+            }
+            )?
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "{} of {:?} with {:?}",
+                    stringify!($op),
+                    lhs.data_type(),
+                    rhs.data_type()
+                )))
+            }
+        })
+    }};
+}
+
+// Macro to evaluate match branch in overflowing arithmetic function.
+macro_rules! primitive_overflowing {
+    ($lhs:expr, $rhs:expr, $op:tt, $type:ty) => {{
+        let lhs = $lhs.as_any().downcast_ref().unwrap();
+        let rhs = $rhs.as_any().downcast_ref().unwrap();
+
+        let (result, overflow) = basic::$op::<$type>(lhs, rhs);
+        (Box::new(result) as Box<dyn Array>, overflow)
+    }};
+}
+
+// Macro to create a `match` statement with dynamic dispatch to overflowing integer functions
+// of `basic` based on the array's logical types
+macro_rules! integer_arith_overflowing {
+    ($lhs:expr, $rhs:expr, $op:tt) => {{
+        let lhs = $lhs;
+        let rhs = $rhs;
+        use DataType::*;
+        Ok(match (lhs.data_type(), rhs.data_type()) {
+            (Int8, Int8) => primitive_overflowing!(lhs, rhs, $op, i8),
+            (Int16, Int16) => primitive_overflowing!(lhs, rhs, $op, i16),
+            (Int32, Int32) => primitive_overflowing!(lhs, rhs, $op, i32),
+            (Int64, Int64) | (Duration(_), Duration(_)) => {
+                primitive_overflowing!(lhs, rhs, $op, i64)
+            }
+            (UInt8, UInt8) => primitive_overflowing!(lhs, rhs, $op, u8),
+            (UInt16, UInt16) => primitive_overflowing!(lhs, rhs, $op, u16),
+            (UInt32, UInt32) => primitive_overflowing!(lhs, rhs, $op, u32),
+            (UInt64, UInt64) => primitive_overflowing!(lhs, rhs, $op, u64),
+            _ => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "{} of {:?} with {:?}",
+                    stringify!($op),
+                    lhs.data_type(),
+                    rhs.data_type()
+                )))
+            }
+        })
+    }};
+}
+
+/// Returns whether two [`DataType`]s are supported by the overflowing kernels
+/// (e.g. [`overflowing_add`]) and by [`checked_rem`].
+pub fn can_integer_arithmetics(lhs: &DataType, rhs: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        (lhs, rhs),
+        (Int8, Int8)
+            | (Int16, Int16)
+            | (Int32, Int32)
+            | (Int64, Int64)
+            | (UInt8, UInt8)
+            | (UInt16, UInt16)
+            | (UInt32, UInt32)
+            | (UInt64, UInt64)
+            | (Duration(_), Duration(_))
+    )
+}
+
+/// Returns whether two [`DataType`]s are supported by the checked (e.g. [`checked_add`])
+/// and saturating (e.g. [`saturating_add`]) kernels: the types supported by
+/// [`can_integer_arithmetics`] and decimals with the same precision and scale.
+pub fn can_checked_arithmetics(lhs: &DataType, rhs: &DataType) -> bool {
+    match (lhs, rhs) {
+        (DataType::Decimal(lhs_p, lhs_s), DataType::Decimal(rhs_p, rhs_s)) => {
+            lhs_p == rhs_p && lhs_s == rhs_s
+        }
+        _ => can_integer_arithmetics(lhs, rhs),
+    }
+}
+
+/// Adds two integer or decimal [`Array`]s, where values that overflow the type (or the
+/// precision of decimals) are null.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_checked_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn checked_add(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    integer_arith!(lhs, rhs, checked_add, decimal = checked_add)
+}
+
+/// Subtracts two integer or decimal [`Array`]s, where values that overflow the type (or the
+/// precision of decimals) are null.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_checked_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn checked_sub(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    integer_arith!(lhs, rhs, checked_sub, decimal = checked_sub)
+}
+
+/// Multiplies two integer or decimal [`Array`]s, where values that overflow the type (or the
+/// precision of decimals) are null.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_checked_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn checked_mul(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    integer_arith!(lhs, rhs, checked_mul, decimal = checked_mul)
+}
+
+/// Divides two integer or decimal [`Array`]s, where values that overflow the type (or the
+/// precision of decimals) and divisions by zero are null.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_checked_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn checked_div(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    integer_arith!(lhs, rhs, checked_div, decimal = checked_div)
+}
+
+/// Remainder of two integer [`Array`]s, where overflowing values and divisions by zero are null.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_integer_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn checked_rem(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    integer_arith!(lhs, rhs, checked_rem)
+}
+
+/// Adds two integer or decimal [`Array`]s, where values that overflow the type (or the
+/// precision of decimals) saturate at its bounds.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_checked_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn saturating_add(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    integer_arith!(lhs, rhs, saturating_add, decimal = saturating_add)
+}
+
+/// Subtracts two integer or decimal [`Array`]s, where values that overflow the type (or the
+/// precision of decimals) saturate at its bounds.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_checked_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn saturating_sub(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    integer_arith!(lhs, rhs, saturating_sub, decimal = saturating_sub)
+}
+
+/// Multiplies two integer or decimal [`Array`]s, where values that overflow the type (or the
+/// precision of decimals) saturate at its bounds.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_checked_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn saturating_mul(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    integer_arith!(lhs, rhs, saturating_mul, decimal = saturating_mul)
+}
+
+/// Adds two integer [`Array`]s, returning the wrapped values and a [`Bitmap`] that is set
+/// where the operation overflowed.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_integer_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn overflowing_add(lhs: &dyn Array, rhs: &dyn Array) -> Result<(Box<dyn Array>, Bitmap)> {
+    integer_arith_overflowing!(lhs, rhs, overflowing_add)
+}
+
+/// Subtracts two integer [`Array`]s, returning the wrapped values and a [`Bitmap`] that is set
+/// where the operation overflowed.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_integer_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn overflowing_sub(lhs: &dyn Array, rhs: &dyn Array) -> Result<(Box<dyn Array>, Bitmap)> {
+    integer_arith_overflowing!(lhs, rhs, overflowing_sub)
+}
+
+/// Multiplies two integer [`Array`]s, returning the wrapped values and a [`Bitmap`] that is set
+/// where the operation overflowed.
+/// # Errors
+/// This function errors iff the operation is not supported for the logical types
+/// (use [`can_integer_arithmetics`] to check).
+/// # Panic
+/// This function panics iff the arrays have a different length.
+pub fn overflowing_mul(lhs: &dyn Array, rhs: &dyn Array) -> Result<(Box<dyn Array>, Bitmap)> {
+    integer_arith_overflowing!(lhs, rhs, overflowing_mul)
+}
+
 macro_rules! with_match_negatable {(
     $key_type:expr, | $_:tt $T:ident | $($body:tt)*
 ) => ({
@@ -0,0 +1,202 @@
+//! Arithmetics of arrays of different numeric types, whose values are first cast to a
+//! common numeric type ("supertype") following SQL's rules of numeric coercion.
+//!
+//! For example, adding an `Int32` array to a `Float32` array casts both to `Float64` and
+//! returns a `Float64` array. See [`numeric_supertype`] for the rules.
+use crate::{
+    array::Array,
+    compute::cast::{cast, CastOptions},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+/// The maximum precision of a [`DataType::Decimal`].
+const MAX_DECIMAL_PRECISION: usize = 38;
+
+/// Returns whether `data_type` is a signed integer, and its number of bits, if it is an integer.
+fn integer(data_type: &DataType) -> Option<(bool, usize)> {
+    use DataType::*;
+    match data_type {
+        Int8 => Some((true, 8)),
+        Int16 => Some((true, 16)),
+        Int32 => Some((true, 32)),
+        Int64 => Some((true, 64)),
+        UInt8 => Some((false, 8)),
+        UInt16 => Some((false, 16)),
+        UInt32 => Some((false, 32)),
+        UInt64 => Some((false, 64)),
+        _ => None,
+    }
+}
+
+/// Returns the integer type with sign `is_signed` and `bits` bits.
+fn integer_type(is_signed: bool, bits: usize) -> DataType {
+    use DataType::*;
+    match (is_signed, bits) {
+        (true, 8) => Int8,
+        (true, 16) => Int16,
+        (true, 32) => Int32,
+        (true, _) => Int64,
+        (false, 8) => UInt8,
+        (false, 16) => UInt16,
+        (false, 32) => UInt32,
+        (false, _) => UInt64,
+    }
+}
+
+/// Returns the `(precision, scale)` of the smallest decimal that can represent every value of
+/// `data_type`, if it is an integer or a decimal.
+fn decimal(data_type: &DataType) -> Option<(usize, usize)> {
+    use DataType::*;
+    match data_type {
+        Int8 | UInt8 => Some((3, 0)),
+        Int16 | UInt16 => Some((5, 0)),
+        Int32 | UInt32 => Some((10, 0)),
+        Int64 => Some((19, 0)),
+        UInt64 => Some((20, 0)),
+        Decimal(precision, scale) => Some((*precision, *scale)),
+        _ => None,
+    }
+}
+
+/// Returns the numeric type that both `lhs` and `rhs` are cast to before being operated on,
+/// or `None` if one of them is not numeric. The rules are:
+/// * two integers of the same sign are promoted to the widest of the two, e.g.
+///   `Int8` and `Int32` to `Int32`;
+/// * a signed and an unsigned integer are promoted to the smallest signed integer that
+///   represents both, e.g. `Int8` and `UInt16` to `Int32`. `UInt64` and signed integers are
+///   promoted to `Int64`, whose cast turns values larger than `i64::MAX` into nulls;
+/// * `Float64` and any number, and `Float32` and integers of 32 or 64 bits, are promoted to
+///   `Float64`. `Float32` and smaller integers are promoted to `Float32`;
+/// * a decimal and an integer or a decimal are promoted to the decimal with the largest scale
+///   that represents the integral digits of both (with a precision of at most 38);
+/// * a decimal and a float are promoted to `Float64`.
+pub fn numeric_supertype(lhs: &DataType, rhs: &DataType) -> Option<DataType> {
+    use DataType::*;
+    match (lhs, rhs) {
+        (Float64, Float64) => Some(Float64),
+        (Float32, Float32) => Some(Float32),
+        (Float64, other) | (other, Float64) => {
+            if integer(other).is_some() || decimal(other).is_some() || other == &Float32 {
+                Some(Float64)
+            } else {
+                None
+            }
+        }
+        (Float32, other) | (other, Float32) => match (integer(other), other) {
+            (Some((_, bits)), _) if bits <= 16 => Some(Float32),
+            (Some(_), _) | (None, Decimal(_, _)) => Some(Float64),
+            _ => None,
+        },
+        (Decimal(_, _), _) | (_, Decimal(_, _)) => {
+            let (lhs_precision, lhs_scale) = decimal(lhs)?;
+            let (rhs_precision, rhs_scale) = decimal(rhs)?;
+            let scale = lhs_scale.max(rhs_scale);
+            let digits = (lhs_precision - lhs_scale).max(rhs_precision - rhs_scale);
+            Some(Decimal((digits + scale).min(MAX_DECIMAL_PRECISION), scale))
+        }
+        _ => {
+            let (lhs_signed, lhs_bits) = integer(lhs)?;
+            let (rhs_signed, rhs_bits) = integer(rhs)?;
+            Some(match (lhs_signed, rhs_signed) {
+                (true, true) | (false, false) => integer_type(lhs_signed, lhs_bits.max(rhs_bits)),
+                (true, false) => integer_type(true, lhs_bits.max(2 * rhs_bits)),
+                (false, true) => integer_type(true, rhs_bits.max(2 * lhs_bits)),
+            })
+        }
+    }
+}
+
+/// Casts `lhs` and `rhs` to their [`numeric_supertype`] and applies `op` to them.
+fn promoted(
+    lhs: &dyn Array,
+    rhs: &dyn Array,
+    op: fn(&dyn Array, &dyn Array) -> Box<dyn Array>,
+    can_op: fn(&DataType, &DataType) -> bool,
+) -> Result<Box<dyn Array>> {
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Arrays must have the same length".to_string(),
+        ));
+    }
+    let data_type = numeric_supertype(lhs.data_type(), rhs.data_type()).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "{:?} and {:?} have no common numeric type",
+            lhs.data_type(),
+            rhs.data_type()
+        ))
+    })?;
+    if !can_op(&data_type, &data_type) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "Arithmetics of {:?} are not supported",
+            data_type
+        )));
+    }
+
+    let lhs_cast;
+    let lhs = if lhs.data_type() == &data_type {
+        lhs
+    } else {
+        lhs_cast = cast(lhs, &data_type, CastOptions::default())?;
+        lhs_cast.as_ref()
+    };
+    let rhs_cast;
+    let rhs = if rhs.data_type() == &data_type {
+        rhs
+    } else {
+        rhs_cast = cast(rhs, &data_type, CastOptions::default())?;
+        rhs_cast.as_ref()
+    };
+    Ok(op(lhs, rhs))
+}
+
+/// Adds two numeric [`Array`]s of (possibly) different types, returning an array of their
+/// [`numeric_supertype`].
+/// # Errors
+/// This function errors iff the arrays have different lengths or no common numeric type.
+/// # Panic
+/// This function panics iff the addition overflows.
+pub fn add(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    promoted(lhs, rhs, super::add, super::can_add)
+}
+
+/// Subtracts two numeric [`Array`]s of (possibly) different types, returning an array of their
+/// [`numeric_supertype`].
+/// # Errors
+/// This function errors iff the arrays have different lengths or no common numeric type.
+/// # Panic
+/// This function panics iff the subtraction overflows.
+pub fn sub(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    promoted(lhs, rhs, super::sub, super::can_sub)
+}
+
+/// Multiplies two numeric [`Array`]s of (possibly) different types, returning an array of their
+/// [`numeric_supertype`].
+/// # Errors
+/// This function errors iff the arrays have different lengths or no common numeric type.
+/// # Panic
+/// This function panics iff the multiplication overflows.
+pub fn mul(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    promoted(lhs, rhs, super::mul, super::can_mul)
+}
+
+/// Divides two numeric [`Array`]s of (possibly) different types, returning an array of their
+/// [`numeric_supertype`].
+/// # Errors
+/// This function errors iff the arrays have different lengths or no common numeric type.
+/// # Panic
+/// This function panics iff an integer is divided by zero.
+pub fn div(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    promoted(lhs, rhs, super::div, super::can_div)
+}
+
+/// Returns the remainder of two numeric [`Array`]s of (possibly) different types, as an array of
+/// their [`numeric_supertype`].
+/// # Errors
+/// This function errors iff the arrays have different lengths or no common numeric type, or
+/// their common type is a decimal.
+/// # Panic
+/// This function panics iff an integer is divided by zero.
+pub fn rem(lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+    promoted(lhs, rhs, super::rem, super::can_rem)
+}
@@ -0,0 +1,257 @@
+//! Defines `between` kernels, that return whether each value of an array is within two bounds
+//! (e.g. SQL's `x BETWEEN low AND high`) in a single pass, instead of two comparisons and a
+//! conjunction.
+//!
+//! The bounds are either scalars (e.g. [`between_scalar`]) or arrays (e.g. [`between`]), and
+//! are inclusive or exclusive as declared by [`BetweenOptions`].
+//!
+//! # Example
+//! ```
+//! use arrow2::array::{BooleanArray, Int32Array};
+//! use arrow2::compute::between::{between_scalar, BetweenOptions};
+//!
+//! let array = Int32Array::from([Some(1), None, Some(3), Some(5)]);
+//! let result = between_scalar(&array, 2, 5, BetweenOptions::default());
+//! assert_eq!(result, BooleanArray::from([Some(false), None, Some(true), Some(true)]));
+//!
+//! let options = BetweenOptions { low_inclusive: true, high_inclusive: false };
+//! let result = between_scalar(&array, 2, 5, options);
+//! assert_eq!(result, BooleanArray::from([Some(false), None, Some(true), Some(false)]));
+//! ```
+use crate::array::{Array, BooleanArray, Offset, PrimitiveArray, Utf8Array};
+use crate::bitmap::Bitmap;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::scalar::{PrimitiveScalar, Scalar, Utf8Scalar};
+use crate::types::NativeType;
+
+use super::utils::{check_same_len, combine_validities};
+
+/// Whether the bounds of the `between` kernels are inclusive (`<=`) or exclusive (`<`).
+/// Both are inclusive by default, as in SQL's `BETWEEN`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BetweenOptions {
+    /// Whether values equal to the lower bound are within the bounds.
+    pub low_inclusive: bool,
+    /// Whether values equal to the upper bound are within the bounds.
+    pub high_inclusive: bool,
+}
+
+impl Default for BetweenOptions {
+    fn default() -> Self {
+        Self {
+            low_inclusive: true,
+            high_inclusive: true,
+        }
+    }
+}
+
+/// Returns whether `value` is within `low` and `high`. Inlined in the loops of the kernels
+/// so that `options` is hoisted out of them.
+#[inline(always)]
+fn is_between<T: PartialOrd + ?Sized>(
+    value: &T,
+    low: &T,
+    high: &T,
+    options: BetweenOptions,
+) -> bool {
+    let above = if options.low_inclusive {
+        value >= low
+    } else {
+        value > low
+    };
+    let below = if options.high_inclusive {
+        value <= high
+    } else {
+        value < high
+    };
+    above & below
+}
+
+/// Returns the validity of the result of a `between` kernel with array bounds.
+fn validity(array: &dyn Array, low: &dyn Array, high: &dyn Array) -> Option<Bitmap> {
+    let validity = combine_validities(array.validity(), low.validity());
+    combine_validities(validity.as_ref(), high.validity())
+}
+
+/// Returns whether each value of `array` is within `low` and `high`, as [`PartialOrd`]
+/// (e.g. `NaN` is never within bounds). Null values are null.
+pub fn between_scalar<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    low: T,
+    high: T,
+    options: BetweenOptions,
+) -> BooleanArray {
+    let values = Bitmap::from_trusted_len_iter(
+        array
+            .values()
+            .iter()
+            .map(|x| is_between(x, &low, &high, options)),
+    );
+    BooleanArray::from_data(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Returns whether each value of `array` is within the values of `low` and `high` at the same
+/// slot, as [`PartialOrd`]. Slots where either of them is null are null.
+/// # Errors
+/// Errors iff the arrays have a different length.
+pub fn between<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    low: &PrimitiveArray<T>,
+    high: &PrimitiveArray<T>,
+    options: BetweenOptions,
+) -> Result<BooleanArray> {
+    check_same_len(array, low)?;
+    check_same_len(array, high)?;
+    let values = Bitmap::from_trusted_len_iter(
+        array
+            .values()
+            .iter()
+            .zip(low.values().iter())
+            .zip(high.values().iter())
+            .map(|((x, low), high)| is_between(x, low, high, options)),
+    );
+    let validity = validity(array, low, high);
+    Ok(BooleanArray::from_data(DataType::Boolean, values, validity))
+}
+
+/// Returns whether each value of `array` is within `low` and `high`, compared
+/// lexicographically by their bytes. Null values are null.
+pub fn between_utf8_scalar<O: Offset>(
+    array: &Utf8Array<O>,
+    low: &str,
+    high: &str,
+    options: BetweenOptions,
+) -> BooleanArray {
+    let values = Bitmap::from_trusted_len_iter(
+        array
+            .values_iter()
+            .map(|x| is_between(x, low, high, options)),
+    );
+    BooleanArray::from_data(DataType::Boolean, values, array.validity().cloned())
+}
+
+/// Returns whether each value of `array` is within the values of `low` and `high` at the same
+/// slot, compared lexicographically by their bytes. Slots where either of them is null are null.
+/// # Errors
+/// Errors iff the arrays have a different length.
+pub fn between_utf8<O: Offset>(
+    array: &Utf8Array<O>,
+    low: &Utf8Array<O>,
+    high: &Utf8Array<O>,
+    options: BetweenOptions,
+) -> Result<BooleanArray> {
+    check_same_len(array, low)?;
+    check_same_len(array, high)?;
+    let values = Bitmap::from_trusted_len_iter(
+        array
+            .values_iter()
+            .zip(low.values_iter())
+            .zip(high.values_iter())
+            .map(|((x, low), high)| is_between(x, low, high, options)),
+    );
+    let validity = validity(array, low, high);
+    Ok(BooleanArray::from_data(DataType::Boolean, values, validity))
+}
+
+macro_rules! with_match_ord_primitive_type {(
+    $key_type:expr, | $_:tt $T:ident | $($body:tt)*
+) => ({
+    macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
+    use crate::datatypes::PrimitiveType::*;
+    match $key_type {
+        Int8 => __with_ty__! { i8 },
+        Int16 => __with_ty__! { i16 },
+        Int32 => __with_ty__! { i32 },
+        Int64 => __with_ty__! { i64 },
+        Int128 => __with_ty__! { i128 },
+        UInt8 => __with_ty__! { u8 },
+        UInt16 => __with_ty__! { u16 },
+        UInt32 => __with_ty__! { u32 },
+        UInt64 => __with_ty__! { u64 },
+        Float32 => __with_ty__! { f32 },
+        Float64 => __with_ty__! { f64 },
+        _ => unreachable!(),
+    }
+})}
+
+/// Returns whether each value of `array` is within `low` and `high`, scalars of the same
+/// logical type as `array`. The result is null where `array` is null, and entirely null when
+/// either bound is null.
+/// # Errors
+/// Errors iff the bounds are not of the logical type of `array` or it is not supported,
+/// see [`can_between`].
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::between::{between_scalar_dyn, BetweenOptions};
+/// use arrow2::scalar::Utf8Scalar;
+///
+/// let array = Utf8Array::<i32>::from([Some("apple"), Some("kiwi"), None]);
+/// let low = Utf8Scalar::<i32>::from(Some("b"));
+/// let high = Utf8Scalar::<i32>::from(Some("m"));
+/// let result = between_scalar_dyn(&array, &low, &high, BetweenOptions::default()).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(false), Some(true), None]));
+/// ```
+pub fn between_scalar_dyn(
+    array: &dyn Array,
+    low: &dyn Scalar,
+    high: &dyn Scalar,
+    options: BetweenOptions,
+) -> Result<BooleanArray> {
+    let data_type = array.data_type();
+    if !can_between(data_type) {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "between is not supported for arrays of type {:?}",
+            data_type
+        )));
+    }
+    if low.data_type().to_logical_type() != data_type.to_logical_type()
+        || high.data_type().to_logical_type() != data_type.to_logical_type()
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The bounds of between must be of the type of the array, {:?}, got {:?} and {:?}",
+            data_type,
+            low.data_type(),
+            high.data_type()
+        )));
+    }
+    if !low.is_valid() || !high.is_valid() {
+        return Ok(BooleanArray::new_null(DataType::Boolean, array.len()));
+    }
+
+    Ok(match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => with_match_ord_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            let low = low.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            let high = high.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            between_scalar(array, low.value().unwrap(), high.value().unwrap(), options)
+        }),
+        PhysicalType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let low = low.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            let high = high.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            between_utf8_scalar(array, low.value().unwrap(), high.value().unwrap(), options)
+        }
+        PhysicalType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let low = low.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            let high = high.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            between_utf8_scalar(array, low.value().unwrap(), high.value().unwrap(), options)
+        }
+        _ => unreachable!(),
+    })
+}
+
+/// Returns whether [`between_scalar_dyn`] supports arrays of `data_type`: integers, floats,
+/// decimals, temporal types and (large) utf8.
+pub fn can_between(data_type: &DataType) -> bool {
+    match data_type.to_physical_type() {
+        PhysicalType::Primitive(primitive) => !matches!(
+            primitive,
+            PrimitiveType::DaysMs | PrimitiveType::MonthDayNano
+        ),
+        PhysicalType::Utf8 | PhysicalType::LargeUtf8 => true,
+        _ => false,
+    }
+}
@@ -1,4 +1,9 @@
-//! null-preserving operators such as [`and`], [`or`] and [`not`].
+//! null-preserving operators such as [`and`], [`or`], [`xor`] and [`not`], following SQL
+//! semantics where the result is null whenever an operand is null.
+//!
+//! The operators are applied on the bitmaps of the values and validities, 64 slots at a time.
+//! See [`boolean_kleene`](crate::compute::boolean_kleene) for operators of Kleene logic, where
+//! e.g. `false AND null` is `false`.
 use crate::array::{Array, BooleanArray};
 use crate::bitmap::{Bitmap, MutableBitmap};
 use crate::datatypes::DataType;
@@ -70,6 +75,27 @@ pub fn or(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
     binary_boolean_kernel(lhs, rhs, |lhs, rhs| lhs | rhs)
 }
 
+/// Performs `XOR` operation on two arrays. If either left or right value is null then the
+/// result is also null.
+/// # Error
+/// This function errors when the arrays have different lengths.
+/// # Example
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::error::Result;
+/// use arrow2::compute::boolean::xor;
+/// # fn main() -> Result<()> {
+/// let a = BooleanArray::from(&[Some(false), Some(true), None]);
+/// let b = BooleanArray::from(&[Some(true), Some(true), Some(false)]);
+/// let xor_ab = xor(&a, &b)?;
+/// assert_eq!(xor_ab, BooleanArray::from(&[Some(true), Some(false), None]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn xor(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
+    binary_boolean_kernel(lhs, rhs, |lhs, rhs| lhs ^ rhs)
+}
+
 /// Performs unary `NOT` operation on an arrays. If value is null then the result is also
 /// null.
 /// # Example
@@ -186,12 +212,35 @@ pub fn or_scalar(array: &BooleanArray, scalar: &BooleanScalar) -> BooleanArray {
     }
 }
 
+/// Performs `XOR` operation on an array and a scalar value. If either left or right value
+/// is null then the result is also null.
+/// # Example
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean::xor_scalar;
+/// use arrow2::scalar::BooleanScalar;
+/// # fn main() {
+/// let array = BooleanArray::from_slice(&[false, true]);
+/// let scalar = BooleanScalar::new(Some(true));
+/// let result = xor_scalar(&array, &scalar);
+/// assert_eq!(result, BooleanArray::from_slice(&[true, false]));
+/// # }
+/// ```
+pub fn xor_scalar(array: &BooleanArray, scalar: &BooleanScalar) -> BooleanArray {
+    match scalar.value() {
+        Some(true) => not(array),
+        Some(false) => array.clone(),
+        None => BooleanArray::new_null(DataType::Boolean, array.len()),
+    }
+}
+
 /// Check if any of the values in the array is `true`
 pub fn any(array: &BooleanArray) -> bool {
     if array.is_empty() {
         false
-    } else if array.validity().is_some() {
-        array.into_iter().any(|v| v == Some(true))
+    } else if let Some(validity) = array.validity() {
+        let values = array.values() & validity;
+        values.null_count() != values.len()
     } else {
         let vals = array.values();
         vals.null_count() != vals.len()
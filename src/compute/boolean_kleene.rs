@@ -1,9 +1,15 @@
-//! Boolean operators of [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics).
+//! Boolean operators of [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics),
+//! where null means "unknown": e.g. `true OR null` is `true` and `false AND null` is `false`,
+//! since the result does not depend on the unknown value.
+//!
+//! Like [`boolean`](crate::compute::boolean), the operators are applied on the bitmaps of the
+//! values and validities, 64 slots at a time.
+use crate::compute::utils::combine_validities;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 use crate::scalar::BooleanScalar;
 use crate::{
-    array::BooleanArray,
+    array::{Array, BooleanArray},
     bitmap::{binary, quaternary, ternary, unary, Bitmap, MutableBitmap},
 };
 
@@ -186,6 +192,59 @@ pub fn and(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
     ))
 }
 
+/// Logical 'xor' operation on two arrays with [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics).
+/// Since the result always depends on both operands, it is null whenever either is null.
+/// # Errors
+/// This function errors if the operands have different lengths.
+/// # Example
+///
+/// ```rust
+/// # use arrow2::error::Result;
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean_kleene::xor;
+/// # fn main() -> Result<()> {
+/// let a = BooleanArray::from(&[Some(true), Some(false), None]);
+/// let b = BooleanArray::from(&[Some(true), Some(true), Some(true)]);
+/// let xor_ab = xor(&a, &b)?;
+/// assert_eq!(xor_ab, BooleanArray::from(&[Some(false), Some(true), None]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn xor(lhs: &BooleanArray, rhs: &BooleanArray) -> Result<BooleanArray> {
+    if lhs.len() != rhs.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Cannot perform bitwise operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    Ok(BooleanArray::from_data(
+        DataType::Boolean,
+        lhs.values() ^ rhs.values(),
+        validity,
+    ))
+}
+
+/// Logical 'not' operation on an array with [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics),
+/// where the negation of null is null.
+/// # Example
+///
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean_kleene::not;
+/// # fn main() {
+/// let a = BooleanArray::from(&[Some(true), Some(false), None]);
+/// assert_eq!(not(&a), BooleanArray::from(&[Some(false), Some(true), None]));
+/// # }
+/// ```
+pub fn not(array: &BooleanArray) -> BooleanArray {
+    BooleanArray::from_data(
+        DataType::Boolean,
+        !array.values(),
+        array.validity().cloned(),
+    )
+}
+
 /// Logical 'or' operation on an array and a scalar value with [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics)
 /// # Example
 ///
@@ -250,3 +309,89 @@ pub fn and_scalar(array: &BooleanArray, scalar: &BooleanScalar) -> BooleanArray
         }
     }
 }
+
+/// Logical 'xor' operation on an array and a scalar value with [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics)
+/// # Example
+///
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::scalar::BooleanScalar;
+/// use arrow2::compute::boolean_kleene::xor_scalar;
+/// # fn main() {
+/// let array = BooleanArray::from(&[Some(true), Some(false), None]);
+/// let scalar = BooleanScalar::new(Some(true));
+/// let result = xor_scalar(&array, &scalar);
+/// assert_eq!(result, BooleanArray::from(&[Some(false), Some(true), None]));
+/// # }
+/// ```
+pub fn xor_scalar(array: &BooleanArray, scalar: &BooleanScalar) -> BooleanArray {
+    match scalar.value() {
+        Some(true) => not(array),
+        Some(false) => array.clone(),
+        None => BooleanArray::new_null(DataType::Boolean, array.len()),
+    }
+}
+
+/// Returns whether any of the values in the array is `true` with [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics):
+/// * `Some(true)` if a value is `true`
+/// * `None` if no value is `true` and a value is null
+/// * `Some(false)` otherwise, including when the array is empty
+/// # Example
+///
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean_kleene::any;
+/// # fn main() {
+/// assert_eq!(any(&BooleanArray::from(&[Some(true), None])), Some(true));
+/// assert_eq!(any(&BooleanArray::from(&[Some(false), None])), None);
+/// assert_eq!(any(&BooleanArray::from(&[Some(false), Some(false)])), Some(false));
+/// # }
+/// ```
+pub fn any(array: &BooleanArray) -> Option<bool> {
+    let any_true = match array.validity() {
+        Some(validity) => {
+            let values = array.values() & validity;
+            values.null_count() != values.len()
+        }
+        None => array.values().null_count() != array.len(),
+    };
+    if any_true {
+        Some(true)
+    } else if array.null_count() > 0 {
+        None
+    } else {
+        Some(false)
+    }
+}
+
+/// Returns whether all of the values in the array are `true` with [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics):
+/// * `Some(false)` if a value is `false`
+/// * `None` if no value is `false` and a value is null
+/// * `Some(true)` otherwise, including when the array is empty
+/// # Example
+///
+/// ```rust
+/// use arrow2::array::BooleanArray;
+/// use arrow2::compute::boolean_kleene::all;
+/// # fn main() {
+/// assert_eq!(all(&BooleanArray::from(&[Some(false), None])), Some(false));
+/// assert_eq!(all(&BooleanArray::from(&[Some(true), None])), None);
+/// assert_eq!(all(&BooleanArray::from(&[Some(true), Some(true)])), Some(true));
+/// # }
+/// ```
+pub fn all(array: &BooleanArray) -> Option<bool> {
+    let any_false = match array.validity() {
+        Some(validity) => {
+            let values = &!array.values() & validity;
+            values.null_count() != values.len()
+        }
+        None => array.values().null_count() > 0,
+    };
+    if any_false {
+        Some(false)
+    } else if array.null_count() > 0 {
+        None
+    } else {
+        Some(true)
+    }
+}
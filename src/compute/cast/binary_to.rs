@@ -1,7 +1,9 @@
 use std::convert::TryFrom;
 
 use crate::error::{ArrowError, Result};
-use crate::{array::*, buffer::Buffer, datatypes::DataType, types::NativeType};
+use crate::{
+    array::*, bitmap::MutableBitmap, buffer::Buffer, datatypes::DataType, types::NativeType,
+};
 
 use super::CastOptions;
 
@@ -32,6 +34,59 @@ pub fn binary_large_to_binary(
     ))
 }
 
+fn to_fixed_size_binary<O: Offset>(
+    from: &BinaryArray<O>,
+    size: usize,
+    is_strict: bool,
+) -> Result<FixedSizeBinaryArray> {
+    let mut values = Vec::<u8>::with_capacity(size * from.len());
+    let mut validity = MutableBitmap::with_capacity(from.len());
+    for (index, value) in from.iter().enumerate() {
+        match value {
+            Some(value) if value.len() == size => {
+                values.extend_from_slice(value);
+                validity.push(true);
+            }
+            Some(value) if is_strict => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The value at slot {} has {} bytes and cannot be cast to FixedSizeBinary({})",
+                    index,
+                    value.len(),
+                    size
+                )))
+            }
+            _ => {
+                values.resize(values.len() + size, 0);
+                validity.push(false);
+            }
+        }
+    }
+    Ok(FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(size),
+        values.into(),
+        validity.into(),
+    ))
+}
+
+/// Casts a [`BinaryArray`] to a [`FixedSizeBinaryArray`] of `size`, making any value whose
+/// length differs from `size` a null.
+pub fn binary_to_fixed_size_binary<O: Offset>(
+    from: &BinaryArray<O>,
+    size: usize,
+) -> FixedSizeBinaryArray {
+    to_fixed_size_binary(from, size, false).unwrap()
+}
+
+/// Casts a [`BinaryArray`] to a [`FixedSizeBinaryArray`] of `size`.
+/// # Errors
+/// This function errors iff a (non-null) value's length differs from `size`.
+pub fn try_binary_to_fixed_size_binary<O: Offset>(
+    from: &BinaryArray<O>,
+    size: usize,
+) -> Result<FixedSizeBinaryArray> {
+    to_fixed_size_binary(from, size, true)
+}
+
 /// Casts a [`BinaryArray`] to a [`PrimitiveArray`] at best-effort using `lexical_core::parse_partial`, making any uncastable value as zero.
 pub fn partial_binary_to_primitive<O: Offset, T>(
     from: &BinaryArray<O>,
@@ -59,6 +114,14 @@ where
     PrimitiveArray::<T>::from_trusted_len_iter(iter).to(to.clone())
 }
 
+pub(super) fn binary_to_fixed_size_binary_dyn<O: Offset>(
+    from: &dyn Array,
+    size: usize,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(binary_to_fixed_size_binary::<O>(from, size)))
+}
+
 pub(super) fn binary_to_primitive_dyn<O: Offset, T>(
     from: &dyn Array,
     to: &DataType,
@@ -2,6 +2,7 @@ use num_traits::{AsPrimitive, Float, NumCast};
 
 use crate::error::Result;
 use crate::types::NativeType;
+use crate::util::decimal::write_decimal;
 use crate::{array::*, datatypes::DataType};
 
 #[inline]
@@ -134,3 +135,35 @@ where
     let from = from.as_any().downcast_ref().unwrap();
     Ok(Box::new(decimal_to_integer::<T>(from)))
 }
+
+/// Casts a [`PrimitiveArray<i128>`] of [`DataType::Decimal`] to a [`Utf8Array`] of its textual
+/// representation, e.g. `-1234` with a scale of 2 to `"-12.34"`.
+pub fn decimal_to_utf8<O: Offset>(from: &PrimitiveArray<i128>) -> Utf8Array<O> {
+    let scale = if let DataType::Decimal(_, s) = from.data_type().to_logical_type() {
+        *s
+    } else {
+        panic!("internal error: i128 is always a decimal")
+    };
+
+    let mut values: Vec<u8> = Vec::with_capacity(from.len());
+    let mut offsets: Vec<O> = Vec::with_capacity(from.len() + 1);
+    offsets.push(O::default());
+    for x in from.values().iter() {
+        write_decimal(*x, scale, &mut values);
+        offsets.push(O::from_usize(values.len()).unwrap());
+    }
+    // Safety: `write_decimal` only writes ASCII and the offsets are monotonically increasing
+    unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            Utf8Array::<O>::default_data_type(),
+            offsets.into(),
+            values.into(),
+            from.validity().cloned(),
+        )
+    }
+}
+
+pub(super) fn decimal_to_utf8_dyn<O: Offset>(from: &dyn Array) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(decimal_to_utf8::<O>(from)))
+}
@@ -50,6 +50,7 @@ pub fn wrapping_dictionary_to_dictionary_values<K: DictionaryKey>(
         CastOptions {
             wrapped: true,
             partial: false,
+            epoch_unit: None,
         },
     )?
     .into();
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{growable::make_growable, *},
+    bitmap::{Bitmap, MutableBitmap},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::{cast, CastOptions};
+
+fn to_fixed_size_list<O: Offset>(
+    from: &ListArray<O>,
+    to_type: &DataType,
+    options: CastOptions,
+    is_strict: bool,
+) -> Result<FixedSizeListArray> {
+    let (field, size) = match to_type.to_logical_type() {
+        DataType::FixedSizeList(field, size) => (field, *size),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot cast a list to {:?}",
+                to_type
+            )))
+        }
+    };
+    let offsets = from.offsets();
+    let lengths = offsets.windows(2).map(|x| (x[1] - x[0]).to_usize());
+
+    let (values, validity): (Arc<dyn Array>, Option<Bitmap>) = if from.null_count() == 0
+        && lengths.clone().all(|length| length == size)
+    {
+        // the values are already laid out as the values of a fixed-size list
+        let start = offsets[0].to_usize();
+        (from.values().slice(start, from.len() * size).into(), None)
+    } else {
+        let mut growable = make_growable(&[from.values().as_ref()], true, from.len() * size);
+        let mut validity = MutableBitmap::with_capacity(from.len());
+        for (index, length) in lengths.enumerate() {
+            let is_valid = from.is_valid(index);
+            if is_valid && length == size {
+                growable.extend(0, offsets[index].to_usize(), size);
+                validity.push(true);
+            } else if is_valid && is_strict {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                        "The list at slot {} has {} items and cannot be cast to a fixed-size list of {}",
+                        index, length, size
+                    )));
+            } else {
+                growable.extend_validity(size);
+                validity.push(false);
+            }
+        }
+        (growable.as_arc(), validity.into())
+    };
+
+    let values = cast(values.as_ref(), field.data_type(), options)?;
+    Ok(FixedSizeListArray::from_data(
+        to_type.clone(),
+        values.into(),
+        validity,
+    ))
+}
+
+/// Casts a [`ListArray`] to a [`FixedSizeListArray`] of `to_type`, making any list whose length
+/// differs from the fixed size a null. The values are cast to the type of `to_type`'s field.
+/// # Errors
+/// This function errors iff `to_type` is not a `FixedSizeList` or the values cannot be cast.
+pub fn list_to_fixed_size_list<O: Offset>(
+    from: &ListArray<O>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<FixedSizeListArray> {
+    to_fixed_size_list(from, to_type, options, false)
+}
+
+/// Casts a [`ListArray`] to a [`FixedSizeListArray`] of `to_type`, whose values are cast to the
+/// type of `to_type`'s field.
+/// # Errors
+/// This function errors iff `to_type` is not a `FixedSizeList`, the values cannot be cast, or
+/// the length of a (non-null) list differs from the fixed size.
+pub fn try_list_to_fixed_size_list<O: Offset>(
+    from: &ListArray<O>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<FixedSizeListArray> {
+    to_fixed_size_list(from, to_type, options, true)
+}
+
+pub(super) fn list_to_fixed_size_list_dyn<O: Offset>(
+    from: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    list_to_fixed_size_list::<O>(from, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use crate::{
+    array::*,
+    bitmap::Bitmap,
+    buffer::Buffer,
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::{cast, CastOptions};
+
+/// Casts a [`MapArray`] to a [`ListArray`] of `to_type`, whose values are the map's entries
+/// cast to the list's child type (usually a `Struct` with two fields).
+pub fn map_to_list(
+    from: &MapArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<ListArray<i32>> {
+    let values = cast(
+        from.field().as_ref(),
+        ListArray::<i32>::get_child_type(to_type),
+        options,
+    )?
+    .into();
+
+    Ok(ListArray::<i32>::from_data(
+        to_type.clone(),
+        from.offsets().clone(),
+        values,
+        from.validity().cloned(),
+    ))
+}
+
+pub(super) fn map_to_list_dyn(
+    from: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    map_to_list(from, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
+fn entries_to_map(
+    offsets: &Buffer<i32>,
+    entries: &dyn Array,
+    validity: Option<&Bitmap>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<MapArray> {
+    let entries_type = MapArray::get_field(to_type).data_type();
+    let entries: Arc<dyn Array> = cast(entries, entries_type, options)?.into();
+    Ok(MapArray::from_data(
+        to_type.clone(),
+        offsets.clone(),
+        entries,
+        validity.cloned(),
+    ))
+}
+
+/// Casts a [`MapArray`] to a [`MapArray`] of `to_type`, casting its entries.
+pub fn map_to_map(from: &MapArray, to_type: &DataType, options: CastOptions) -> Result<MapArray> {
+    entries_to_map(
+        from.offsets(),
+        from.field().as_ref(),
+        from.validity(),
+        to_type,
+        options,
+    )
+}
+
+pub(super) fn map_to_map_dyn(
+    from: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    map_to_map(from, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
+/// Casts a [`ListArray`] to a [`MapArray`] of `to_type`, whose entries are the list's values
+/// cast to the map's entries (a `Struct` with two fields).
+/// # Errors
+/// This function errors if the list's values can't be cast to the map's entries.
+pub fn list_to_map(
+    from: &ListArray<i32>,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<MapArray> {
+    if !matches!(
+        from.values().data_type().to_logical_type(),
+        DataType::Struct(_)
+    ) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot cast a list of {:?} to a map",
+            from.values().data_type()
+        )));
+    }
+    entries_to_map(
+        from.offsets(),
+        from.values().as_ref(),
+        from.validity(),
+        to_type,
+        options,
+    )
+}
+
+pub(super) fn list_to_map_dyn(
+    from: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    list_to_map(from, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
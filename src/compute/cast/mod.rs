@@ -4,14 +4,20 @@ mod binary_to;
 mod boolean_to;
 mod decimal_to;
 mod dictionary_to;
+mod list_to;
+mod map_to;
 mod primitive_to;
+mod struct_to;
 mod utf8_to;
 
 pub use binary_to::*;
 pub use boolean_to::*;
 pub use decimal_to::*;
 pub use dictionary_to::*;
+pub use list_to::*;
+pub use map_to::*;
 pub use primitive_to::*;
+pub use struct_to::*;
 pub use utf8_to::*;
 
 use crate::{
@@ -30,6 +36,11 @@ pub struct CastOptions {
     /// default to false
     /// whether to cast to an integer at the best-effort
     pub partial: bool,
+    /// default to `None`
+    /// when set, `Int64` and `Utf8` values cast to `Timestamp` are interpreted as integers since the
+    /// epoch in this unit (e.g. `"1612051200"` seconds), and timestamps cast to `Int64` are
+    /// converted to this unit.
+    pub epoch_unit: Option<TimeUnit>,
 }
 
 impl CastOptions {
@@ -89,8 +100,25 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
             | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
             Null,
         ) => true,
+        (Struct(from_fields), Struct(to_fields)) => {
+            from_fields.len() == to_fields.len()
+                && from_fields
+                    .iter()
+                    .zip(to_fields.iter())
+                    .all(|(from, to)| can_cast_types(from.data_type(), to.data_type()))
+        }
+        (Struct(from_fields), Map(to, _)) => match to.data_type() {
+            Struct(entries) if matches!(entries[0].data_type(), Utf8 | LargeUtf8) => from_fields
+                .iter()
+                .all(|from| can_cast_types(from.data_type(), entries[1].data_type())),
+            _ => false,
+        },
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
+        (Map(from, _), List(to)) | (Map(from, _), Map(to, _)) | (List(from), Map(to, _)) => {
+            matches!(from.data_type(), Struct(_))
+                && can_cast_types(from.data_type(), to.data_type())
+        }
         (List(list_from), List(list_to)) => {
             can_cast_types(&list_from.data_type, &list_to.data_type)
         }
@@ -99,6 +127,9 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         }
         (List(list_from), LargeList(list_to)) if list_from == list_to => true,
         (LargeList(list_from), List(list_to)) if list_from == list_to => true,
+        (List(list_from) | LargeList(list_from), FixedSizeList(list_to, _)) => {
+            can_cast_types(&list_from.data_type, &list_to.data_type)
+        }
         (_, List(list_to)) => can_cast_types(from_type, &list_to.data_type),
         (Dictionary(_, from_value_type, _), Dictionary(_, to_value_type, _)) => {
             can_cast_types(from_value_type, to_value_type)
@@ -117,19 +148,28 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 
         (Utf8, Date32) => true,
         (Utf8, Date64) => true,
-        (Utf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
+        (Utf8, Timestamp(_, _)) => true,
         (Utf8, LargeUtf8) => true,
-        (Utf8, _) => is_numeric(to_type),
+        (Utf8, _) => is_numeric(to_type) || matches!(to_type, Decimal(_, _)),
         (LargeUtf8, Date32) => true,
         (LargeUtf8, Date64) => true,
-        (LargeUtf8, Timestamp(TimeUnit::Nanosecond, _)) => true,
+        (LargeUtf8, Timestamp(_, _)) => true,
         (LargeUtf8, Utf8) => true,
-        (LargeUtf8, _) => is_numeric(to_type),
+        (LargeUtf8, _) => is_numeric(to_type) || matches!(to_type, Decimal(_, _)),
         (Timestamp(_, _), Utf8) => true,
         (Timestamp(_, _), LargeUtf8) => true,
-        (_, Utf8) => is_numeric(from_type) || from_type == &Binary,
-        (_, LargeUtf8) => is_numeric(from_type) || from_type == &Binary,
+        (Date32, Utf8) => true,
+        (Date32, LargeUtf8) => true,
+        (Date64, Utf8) => true,
+        (Date64, LargeUtf8) => true,
+        (_, Utf8) => {
+            is_numeric(from_type) || from_type == &Binary || matches!(from_type, Decimal(_, _))
+        }
+        (_, LargeUtf8) => {
+            is_numeric(from_type) || from_type == &Binary || matches!(from_type, Decimal(_, _))
+        }
 
+        (Binary | LargeBinary, FixedSizeBinary(_)) => true,
         (Binary, _) => is_numeric(to_type) || to_type == &LargeBinary,
         (LargeBinary, _) => is_numeric(to_type) || to_type == &Binary,
         (_, Binary) => is_numeric(from_type),
@@ -362,8 +402,17 @@ fn cast_large_to_list(array: &ListArray<i64>, to_type: &DataType) -> ListArray<i
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Utf8 to and from Timestamp: RFC3339, e.g. `"1996-12-19T16:39:57-02:00"`; Date{32|64} to
+///   Utf8: `"1996-12-19"`
+/// * Int64 and Utf8 to Timestamp and Timestamp to Int64 with [`CastOptions::epoch_unit`]:
+///   integers since the epoch in that unit
+/// * Struct to Struct: each field is cast to the field in the same position
+/// * Struct to Map: each slot becomes a map from the field names to the field values
+/// * Map to and from List of Struct: the offsets are re-used and the entries cast
+/// * Binary to FixedSizeBinary and List to FixedSizeList: values whose length differs from the
+///   fixed size are null
 /// Unsupported Casts
-/// * To or from `StructArray`
+/// * From `StructArray` to non-nested types and to `StructArray` from any other type
 /// * List to primitive
 /// * Utf8 to boolean
 /// * Interval and duration
@@ -388,12 +437,17 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             | Int64 | UInt64 | Float64 | Date64 | List(_) | Dictionary(..),
             Null,
         ) => Ok(new_null_array(to_type.clone(), array.len())),
+        (Struct(_), Struct(_)) => struct_to_struct_dyn(array, to_type, options),
+        (Struct(_), Map(..)) => struct_to_map_dyn(array, to_type, options),
         (Struct(_), _) => Err(ArrowError::NotYetImplemented(
             "Cannot cast from struct to other types".to_string(),
         )),
         (_, Struct(_)) => Err(ArrowError::NotYetImplemented(
             "Cannot cast to struct from other types".to_string(),
         )),
+        (Map(..), List(_)) => map_to_list_dyn(array, to_type, options),
+        (Map(..), Map(..)) => map_to_map_dyn(array, to_type, options),
+        (List(_), Map(..)) => list_to_map_dyn(array, to_type, options),
         (List(_), List(_)) => {
             cast_list::<i32>(array.as_any().downcast_ref().unwrap(), to_type, options)
                 .map(|x| Box::new(x) as Box<dyn Array>)
@@ -413,6 +467,15 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         ))
         .map(|x| Box::new(x) as Box<dyn Array>),
 
+        (List(_), FixedSizeList(..)) => list_to_fixed_size_list_dyn::<i32>(array, to_type, options),
+        (LargeList(_), FixedSizeList(..)) => {
+            list_to_fixed_size_list_dyn::<i64>(array, to_type, options)
+        }
+        (Binary, FixedSizeBinary(size)) => binary_to_fixed_size_binary_dyn::<i32>(array, *size),
+        (LargeBinary, FixedSizeBinary(size)) => {
+            binary_to_fixed_size_binary_dyn::<i64>(array, *size)
+        }
+
         (_, List(to)) => {
             // cast primitive to list's primitive
             let values = cast(array, &to.data_type, options)?.into();
@@ -479,14 +542,14 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i32, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i32, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i32, f64>(array, to_type, options),
+            Decimal(p, s) => utf8_to_decimal_dyn::<i32>(array, *p, *s, options),
             Date32 => utf8_to_date32_dyn::<i32>(array),
             Date64 => utf8_to_date64_dyn::<i32>(array),
             LargeUtf8 => Ok(Box::new(utf8_to_large_utf8(
                 array.as_any().downcast_ref().unwrap(),
             ))),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i32>(array),
-            Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
-                utf8_to_timestamp_ns_dyn::<i32>(array, tz.clone())
+            Timestamp(time_unit, tz) => {
+                utf8_to_timestamp_dyn::<i32>(array, *time_unit, tz.clone(), options)
             }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
@@ -504,13 +567,13 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => utf8_to_primitive_dyn::<i64, i64>(array, to_type, options),
             Float32 => utf8_to_primitive_dyn::<i64, f32>(array, to_type, options),
             Float64 => utf8_to_primitive_dyn::<i64, f64>(array, to_type, options),
+            Decimal(p, s) => utf8_to_decimal_dyn::<i64>(array, *p, *s, options),
             Date32 => utf8_to_date32_dyn::<i64>(array),
             Date64 => utf8_to_date64_dyn::<i64>(array),
             Utf8 => utf8_large_to_utf8(array.as_any().downcast_ref().unwrap())
                 .map(|x| Box::new(x) as Box<dyn Array>),
-            Timestamp(TimeUnit::Nanosecond, None) => utf8_to_naive_timestamp_ns_dyn::<i64>(array),
-            Timestamp(TimeUnit::Nanosecond, Some(tz)) => {
-                utf8_to_timestamp_ns_dyn::<i64>(array, tz.clone())
+            Timestamp(time_unit, tz) => {
+                utf8_to_timestamp_dyn::<i64>(array, *time_unit, tz.clone(), options)
             }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
@@ -529,6 +592,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i32>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i32>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i32>(array),
+            Decimal(_, _) => decimal_to_utf8_dyn::<i32>(array),
             Binary => {
                 let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
 
@@ -548,6 +612,14 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(naive_timestamp_to_utf8::<i32>(from, *from_unit)))
             }
+            Date32 => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(date32_to_utf8::<i32>(from)))
+            }
+            Date64 => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(date64_to_utf8::<i32>(from)))
+            }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -565,6 +637,7 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
             Int64 => primitive_to_utf8_dyn::<i64, i64>(array),
             Float32 => primitive_to_utf8_dyn::<f32, i64>(array),
             Float64 => primitive_to_utf8_dyn::<f64, i64>(array),
+            Decimal(_, _) => decimal_to_utf8_dyn::<i64>(array),
             Binary => {
                 let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
 
@@ -584,6 +657,14 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
                 let from = array.as_any().downcast_ref().unwrap();
                 Ok(Box::new(naive_timestamp_to_utf8::<i64>(from, *from_unit)))
             }
+            Date32 => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(date32_to_utf8::<i64>(from)))
+            }
+            Date64 => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(date64_to_utf8::<i64>(from)))
+            }
             _ => Err(ArrowError::NotYetImplemented(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -832,8 +913,25 @@ pub fn cast(array: &dyn Array, to_type: &DataType, options: CastOptions) -> Resu
         (Time64(from_unit), Time32(to_unit)) => {
             primitive_dyn!(array, time64_to_time32, *from_unit, *to_unit)
         }
-        (Timestamp(_, _), Int64) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
-        (Int64, Timestamp(_, _)) => primitive_to_same_primitive_dyn::<i64>(array, to_type),
+        (Timestamp(_, _), Int64) => match options.epoch_unit {
+            Some(epoch_unit) => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(timestamp_to_epoch(from, epoch_unit)?))
+            }
+            None => primitive_to_same_primitive_dyn::<i64>(array, to_type),
+        },
+        (Int64, Timestamp(time_unit, tz)) => match options.epoch_unit {
+            Some(epoch_unit) => {
+                let from = array.as_any().downcast_ref().unwrap();
+                Ok(Box::new(epoch_to_timestamp(
+                    from,
+                    epoch_unit,
+                    *time_unit,
+                    tz.clone(),
+                )?))
+            }
+            None => primitive_to_same_primitive_dyn::<i64>(array, to_type),
+        },
         (Timestamp(from_unit, _), Timestamp(to_unit, tz)) => {
             primitive_dyn!(array, timestamp_to_timestamp, *from_unit, *to_unit, tz)
         }
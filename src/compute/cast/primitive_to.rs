@@ -399,6 +399,30 @@ pub fn time64_to_time32(
     )
 }
 
+/// Casts integers since the epoch in `epoch_unit` to timestamps in `time_unit` and `timezone`.
+/// # Errors
+/// This function errors iff a (non-null) timestamp overflows when converted to `time_unit`.
+pub fn epoch_to_timestamp(
+    from: &PrimitiveArray<i64>,
+    epoch_unit: TimeUnit,
+    time_unit: TimeUnit,
+    timezone: Option<String>,
+) -> Result<PrimitiveArray<i64>> {
+    let from = from.clone().to(DataType::Timestamp(epoch_unit, timezone));
+    timestamp_to_time_unit(&from, time_unit)
+}
+
+/// Casts timestamps to integers since the epoch in `epoch_unit`.
+/// # Errors
+/// This function errors iff `from` is not a timestamp array or a (non-null) timestamp
+/// overflows when converted to `epoch_unit`.
+pub fn timestamp_to_epoch(
+    from: &PrimitiveArray<i64>,
+    epoch_unit: TimeUnit,
+) -> Result<PrimitiveArray<i64>> {
+    Ok(timestamp_to_time_unit(from, epoch_unit)?.to(DataType::Int64))
+}
+
 /// Conversion of timestamp
 pub fn timestamp_to_timestamp(
     from: &PrimitiveArray<i64>,
@@ -552,3 +576,19 @@ pub fn naive_timestamp_to_utf8<O: Offset>(
         }
     }
 }
+
+/// Returns a [`Utf8Array`] where every element is the date in the RFC3339 format, e.g. `2021-01-30`.
+pub fn date32_to_utf8<O: Offset>(from: &PrimitiveArray<i32>) -> Utf8Array<O> {
+    let iter = from
+        .iter()
+        .map(|x| x.copied().map(date32_to_date).map(|x| x.to_string()));
+    Utf8Array::from_trusted_len_iter(iter)
+}
+
+/// Returns a [`Utf8Array`] where every element is the date in the RFC3339 format, e.g. `2021-01-30`.
+pub fn date64_to_utf8<O: Offset>(from: &PrimitiveArray<i64>) -> Utf8Array<O> {
+    let iter = from
+        .iter()
+        .map(|x| x.copied().map(date64_to_date).map(|x| x.to_string()));
+    Utf8Array::from_trusted_len_iter(iter)
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use crate::{
+    array::{growable::make_growable, *},
+    datatypes::DataType,
+    error::{ArrowError, Result},
+};
+
+use super::{cast, CastOptions};
+
+/// Casts a [`StructArray`] to a [`StructArray`] of `to_type`, casting each of its fields
+/// to the field of `to_type` in the same position.
+/// # Errors
+/// This function errors if the number of fields of `to_type` differs from `from`'s or
+/// if any of the fields can't be cast.
+pub fn struct_to_struct(
+    from: &StructArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<StructArray> {
+    let to_fields = StructArray::get_fields(to_type);
+    if to_fields.len() != from.fields().len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Cannot cast a struct with {} fields to a struct with {} fields",
+            from.fields().len(),
+            to_fields.len()
+        )));
+    }
+
+    let values = from
+        .values()
+        .iter()
+        .zip(to_fields.iter())
+        .map(|(array, field)| cast(array.as_ref(), field.data_type(), options).map(|x| x.into()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(StructArray::from_data(
+        to_type.clone(),
+        values,
+        from.validity().cloned(),
+    ))
+}
+
+pub(super) fn struct_to_struct_dyn(
+    from: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    struct_to_struct(from, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
+
+/// Casts a [`StructArray`] to a [`MapArray`] of `to_type`, where each slot of the struct
+/// becomes a map whose keys are the struct's field names and whose values are the struct's values.
+///
+/// This requires the key of `to_type` to be `Utf8` or `LargeUtf8` and every field of the
+/// struct to be castable to the value of `to_type`, i.e. all fields to be uniform.
+/// Null slots of the struct are null maps.
+/// # Errors
+/// This function errors if the conditions above are not met.
+pub fn struct_to_map(
+    from: &StructArray,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<MapArray> {
+    let entries_type = MapArray::get_field(to_type).data_type();
+    let entries = StructArray::get_fields(entries_type);
+    let (key_type, value_type) = (entries[0].data_type(), entries[1].data_type());
+
+    let names = from.fields().iter().map(|field| field.name.as_str());
+    let keys: Arc<dyn Array> = match key_type {
+        DataType::Utf8 => Arc::new(Utf8Array::<i32>::from_iter_values(
+            names.cycle().take(from.len() * from.fields().len()),
+        )),
+        DataType::LargeUtf8 => Arc::new(Utf8Array::<i64>::from_iter_values(
+            names.cycle().take(from.len() * from.fields().len()),
+        )),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot cast a struct to a map with keys of type {:?}",
+                key_type
+            )))
+        }
+    };
+
+    let values = from
+        .values()
+        .iter()
+        .map(|array| cast(array.as_ref(), value_type, options))
+        .collect::<Result<Vec<_>>>()?;
+    let values_ref = values.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+
+    // interleave the values so that the entries of each slot are contiguous
+    let mut growable = make_growable(&values_ref, false, keys.len());
+    for index in 0..from.len() {
+        for array in 0..values.len() {
+            growable.extend(array, index, 1);
+        }
+    }
+    let values: Arc<dyn Array> = growable.as_arc();
+
+    let offsets = (0..=from.len())
+        .map(|x| (x * from.fields().len()) as i32)
+        .collect::<Vec<_>>();
+
+    let field = StructArray::from_data(entries_type.clone(), vec![keys, values], None);
+
+    Ok(MapArray::from_data(
+        to_type.clone(),
+        offsets.into(),
+        Arc::new(field),
+        from.validity().cloned(),
+    ))
+}
+
+pub(super) fn struct_to_map_dyn(
+    from: &dyn Array,
+    to_type: &DataType,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    struct_to_map(from, to_type, options).map(|x| Box::new(x) as Box<dyn Array>)
+}
@@ -4,13 +4,16 @@ use chrono::Datelike;
 
 use crate::{
     array::*,
-    datatypes::DataType,
+    datatypes::{DataType, TimeUnit},
     error::{ArrowError, Result},
     temporal_conversions::{
+        timestamp_to_time_unit, utf8_to_naive_timestamp as utf8_to_naive_timestamp_,
         utf8_to_naive_timestamp_ns as utf8_to_naive_timestamp_ns_,
-        utf8_to_timestamp_ns as utf8_to_timestamp_ns_, EPOCH_DAYS_FROM_CE,
+        utf8_to_timestamp as utf8_to_timestamp_, utf8_to_timestamp_ns as utf8_to_timestamp_ns_,
+        EPOCH_DAYS_FROM_CE,
     },
     types::NativeType,
+    util::decimal::parse_decimal,
 };
 
 use super::CastOptions;
@@ -60,6 +63,36 @@ where
     }
 }
 
+/// Casts a [`Utf8Array`] to a [`PrimitiveArray<i128>`] of [`DataType::Decimal`] with `precision`
+/// and `scale`, making any uncastable value a Null. When `lenient` is true, values are trimmed
+/// of whitespace and rounded to `scale` (see [`parse_decimal`]).
+pub fn utf8_to_decimal<O: Offset>(
+    from: &Utf8Array<O>,
+    precision: usize,
+    scale: usize,
+    lenient: bool,
+) -> PrimitiveArray<i128> {
+    let iter = from
+        .iter()
+        .map(|x| x.and_then(|x| parse_decimal(x.as_bytes(), precision, scale, lenient)));
+    PrimitiveArray::<i128>::from_trusted_len_iter(iter).to(DataType::Decimal(precision, scale))
+}
+
+pub(super) fn utf8_to_decimal_dyn<O: Offset>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+    options: CastOptions,
+) -> Result<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(utf8_to_decimal::<O>(
+        from,
+        precision,
+        scale,
+        options.partial,
+    )))
+}
+
 /// Casts a [`Utf8Array`] to a Date32 primitive, making any uncastable value a Null.
 pub fn utf8_to_date32<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i32> {
     let iter = from.iter().map(|x| {
@@ -114,26 +147,57 @@ pub fn utf8_to_dictionary<O: Offset, K: DictionaryKey>(
     Ok(array.into())
 }
 
-pub(super) fn utf8_to_naive_timestamp_ns_dyn<O: Offset>(
+pub(super) fn utf8_to_timestamp_dyn<O: Offset>(
     from: &dyn Array,
+    time_unit: TimeUnit,
+    timezone: Option<String>,
+    options: CastOptions,
 ) -> Result<Box<dyn Array>> {
     let from = from.as_any().downcast_ref().unwrap();
-    Ok(Box::new(utf8_to_naive_timestamp_ns::<O>(from)))
+    let array = match (options.epoch_unit, timezone) {
+        (Some(epoch_unit), timezone) => {
+            utf8_epoch_to_timestamp::<O>(from, epoch_unit, time_unit, timezone)?
+        }
+        (None, Some(timezone)) => utf8_to_timestamp::<O>(from, time_unit, timezone)?,
+        (None, None) => utf8_to_naive_timestamp::<O>(from, time_unit),
+    };
+    Ok(Box::new(array))
 }
 
-/// [`crate::temporal_conversions::utf8_to_timestamp_ns`] applied for RFC3339 formatting
+/// [`crate::temporal_conversions::utf8_to_naive_timestamp_ns`] applied for RFC3339 formatting
 pub fn utf8_to_naive_timestamp_ns<O: Offset>(from: &Utf8Array<O>) -> PrimitiveArray<i64> {
     utf8_to_naive_timestamp_ns_(from, RFC3339)
 }
 
-pub(super) fn utf8_to_timestamp_ns_dyn<O: Offset>(
-    from: &dyn Array,
+/// [`crate::temporal_conversions::utf8_to_naive_timestamp`] applied for RFC3339 formatting
+pub fn utf8_to_naive_timestamp<O: Offset>(
+    from: &Utf8Array<O>,
+    time_unit: TimeUnit,
+) -> PrimitiveArray<i64> {
+    utf8_to_naive_timestamp_(from, RFC3339, time_unit)
+}
+
+/// [`crate::temporal_conversions::utf8_to_timestamp`] applied for RFC3339 formatting
+pub fn utf8_to_timestamp<O: Offset>(
+    from: &Utf8Array<O>,
+    time_unit: TimeUnit,
     timezone: String,
-) -> Result<Box<dyn Array>> {
-    let from = from.as_any().downcast_ref().unwrap();
-    utf8_to_timestamp_ns::<O>(from, timezone)
-        .map(Box::new)
-        .map(|x| x as Box<dyn Array>)
+) -> Result<PrimitiveArray<i64>> {
+    utf8_to_timestamp_(from, RFC3339, timezone, time_unit)
+}
+
+/// Casts a [`Utf8Array`] of integers since the epoch in `epoch_unit` (e.g. `"1612051200"` seconds)
+/// to timestamps in `time_unit` and `timezone`. Non-parsable elements are null.
+/// # Errors
+/// This function errors iff a timestamp overflows when converted to `time_unit`.
+pub fn utf8_epoch_to_timestamp<O: Offset>(
+    from: &Utf8Array<O>,
+    epoch_unit: TimeUnit,
+    time_unit: TimeUnit,
+    timezone: Option<String>,
+) -> Result<PrimitiveArray<i64>> {
+    let from = utf8_to_primitive::<O, i64>(from, &DataType::Timestamp(epoch_unit, timezone));
+    timestamp_to_time_unit(&from, time_unit)
 }
 
 /// [`crate::temporal_conversions::utf8_to_timestamp_ns`] applied for RFC3339 formatting
@@ -6,6 +6,7 @@ use crate::{
 };
 
 use super::super::utils::combine_validities;
+use super::{null_safe_eq, null_safe_neq};
 
 /// Evaluate `op(lhs, rhs)` for [`BinaryArray`]s using a specified
 /// comparison function.
@@ -66,6 +67,32 @@ pub fn neq_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray {
     compare_op_scalar(lhs, rhs, |a, b| a != b)
 }
 
+/// Perform `lhs IS DISTINCT FROM rhs` operation on [`BinaryArray`]: like [`neq`], but a null
+/// value is distinct from a non-null value and not distinct from another null value.
+/// The result has no null values.
+pub fn is_distinct_from<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BooleanArray {
+    null_safe_neq(&eq(lhs, rhs), lhs.validity(), rhs.validity())
+}
+
+/// Perform `lhs IS DISTINCT FROM rhs` operation on [`BinaryArray`] and a scalar.
+/// The result has no null values.
+pub fn is_distinct_from_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray {
+    null_safe_neq(&eq_scalar(lhs, rhs), lhs.validity(), None)
+}
+
+/// Perform `lhs IS NOT DISTINCT FROM rhs` operation on [`BinaryArray`]: like [`eq`], but a null
+/// value is equal to another null value and different from a non-null value.
+/// The result has no null values.
+pub fn is_not_distinct_from<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BooleanArray {
+    null_safe_eq(&eq(lhs, rhs), lhs.validity(), rhs.validity())
+}
+
+/// Perform `lhs IS NOT DISTINCT FROM rhs` operation on [`BinaryArray`] and a scalar.
+/// The result has no null values.
+pub fn is_not_distinct_from_scalar<O: Offset>(lhs: &BinaryArray<O>, rhs: &[u8]) -> BooleanArray {
+    null_safe_eq(&eq_scalar(lhs, rhs), lhs.validity(), None)
+}
+
 /// Perform `lhs < rhs` operation on [`BinaryArray`].
 pub fn lt<O: Offset>(lhs: &BinaryArray<O>, rhs: &BinaryArray<O>) -> BooleanArray {
     compare_op(lhs, rhs, |a, b| a < b)
@@ -6,6 +6,7 @@ use crate::{
 };
 
 use super::super::utils::combine_validities;
+use super::{null_safe_eq, null_safe_neq};
 
 /// Evaluate `op(lhs, rhs)` for [`BooleanArray`]s using a specified
 /// comparison function.
@@ -57,6 +58,32 @@ pub fn neq_scalar(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
     eq_scalar(lhs, !rhs)
 }
 
+/// Perform `left IS DISTINCT FROM right` operation on two arrays: like [`neq`], but a null
+/// value is distinct from a non-null value and not distinct from another null value.
+/// The result has no null values.
+pub fn is_distinct_from(lhs: &BooleanArray, rhs: &BooleanArray) -> BooleanArray {
+    null_safe_neq(&eq(lhs, rhs), lhs.validity(), rhs.validity())
+}
+
+/// Perform `left IS DISTINCT FROM right` operation on an array and a scalar value.
+/// The result has no null values.
+pub fn is_distinct_from_scalar(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
+    null_safe_neq(&eq_scalar(lhs, rhs), lhs.validity(), None)
+}
+
+/// Perform `left IS NOT DISTINCT FROM right` operation on two arrays: like [`eq`], but a null
+/// value is equal to another null value and different from a non-null value.
+/// The result has no null values.
+pub fn is_not_distinct_from(lhs: &BooleanArray, rhs: &BooleanArray) -> BooleanArray {
+    null_safe_eq(&eq(lhs, rhs), lhs.validity(), rhs.validity())
+}
+
+/// Perform `left IS NOT DISTINCT FROM right` operation on an array and a scalar value.
+/// The result has no null values.
+pub fn is_not_distinct_from_scalar(lhs: &BooleanArray, rhs: bool) -> BooleanArray {
+    null_safe_eq(&eq_scalar(lhs, rhs), lhs.validity(), None)
+}
+
 /// Perform `left < right` operation on two arrays.
 pub fn lt(lhs: &BooleanArray, rhs: &BooleanArray) -> BooleanArray {
     compare_op(lhs, rhs, |a, b| !a & b)
@@ -2,7 +2,10 @@
 //!
 //! The module contains functions that compare either an [`Array`] and a [`Scalar`]
 //! or two [`Array`]s (of the same [`DataType`]). The scalar-oriented functions are
-//! suffixed with `_scalar`.
+//! suffixed with `_scalar`. They compare each value to the [`Scalar`] without materializing
+//! it as an [`Array`], and also accept [`DictionaryArray`]s whose values are comparable with
+//! the [`Scalar`], in which case each value of the dictionary is compared only once.
+//! Two [`DictionaryArray`]s of the same [`DataType`] are compared via their values.
 //!
 //! The functions are organized in two variants:
 //! * statically typed
@@ -43,9 +46,25 @@
 //! let result = neq_scalar(&array, "compare");
 //! assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
 //! ```
+//!
+//! Compare (greater than) a timestamp [`PrimitiveArray`] to a [`PrimitiveScalar`]:
+//! ```
+//! use arrow2::array::{BooleanArray, PrimitiveArray};
+//! use arrow2::compute::comparison::gt_scalar;
+//! use arrow2::datatypes::{DataType, TimeUnit};
+//! use arrow2::scalar::PrimitiveScalar;
+//!
+//! let data_type = DataType::Timestamp(TimeUnit::Second, None);
+//! let array = PrimitiveArray::<i64>::from([Some(1), None, Some(3)]).to(data_type.clone());
+//! let scalar = PrimitiveScalar::<i64>::new(data_type, Some(2));
+//! let result = gt_scalar(&array, &scalar);
+//! assert_eq!(result, BooleanArray::from([Some(false), None, Some(true)]));
+//! ```
 
+use crate::array::growable::make_growable;
 use crate::array::*;
-use crate::datatypes::{DataType, IntervalUnit};
+use crate::bitmap::{ternary, Bitmap};
+use crate::datatypes::{DataType, IntervalUnit, PhysicalType};
 use crate::scalar::*;
 
 pub mod binary;
@@ -147,6 +166,14 @@ macro_rules! compare {
                 let rhs = rhs.as_any().downcast_ref().unwrap();
                 binary::$op::<i64>(lhs, rhs)
             }
+            Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let rhs = rhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                $op(
+                    dictionary_values(lhs).as_ref(),
+                    dictionary_values(rhs).as_ref(),
+                )
+            }),
             _ => todo!(
                 "Comparison between {:?} are not yet supported",
                 lhs.data_type()
@@ -155,6 +182,17 @@ macro_rules! compare {
     }};
 }
 
+/// Returns the values of `array` in the order of its keys, so that two dictionaries (whose
+/// keys refer to different values) are compared via their values.
+fn dictionary_values<K: DictionaryKey>(array: &DictionaryArray<K>) -> Box<dyn Array> {
+    let mut growable = make_growable(&[array.values().as_ref()], true, array.len());
+    array.keys().iter().for_each(|key| match key {
+        Some(key) => growable.extend(0, key.to_usize().unwrap(), 1),
+        None => growable.extend_validity(1),
+    });
+    growable.as_box()
+}
+
 /// `==` between two [`Array`]s.
 /// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
@@ -221,14 +259,51 @@ pub fn gt_eq(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
     compare!(lhs, rhs, gt_eq, match_eq_ord)
 }
 
+/// `IS DISTINCT FROM` between two [`Array`]s: null-safe `!=`, where a null value is distinct
+/// from a non-null value and not distinct from another null value. The result has no null values.
+/// Use [`can_is_distinct_from`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn is_distinct_from(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if lhs.data_type().to_physical_type() == PhysicalType::Null {
+        assert_eq!(lhs.len(), rhs.len());
+        return all(false, lhs.len());
+    }
+    compare!(lhs, rhs, is_distinct_from, match_eq)
+}
+
+/// `IS NOT DISTINCT FROM` between two [`Array`]s: null-safe `==`, where a null value is equal
+/// to another null value and different from a non-null value. The result has no null values.
+/// Use [`can_is_distinct_from`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * the arrays do not have have the same logical type
+/// * the arrays do not have the same length
+/// * the operation is not supported for the logical type
+pub fn is_not_distinct_from(lhs: &dyn Array, rhs: &dyn Array) -> BooleanArray {
+    if lhs.data_type().to_physical_type() == PhysicalType::Null {
+        assert_eq!(lhs.len(), rhs.len());
+        return all(true, lhs.len());
+    }
+    compare!(lhs, rhs, is_not_distinct_from, match_eq)
+}
+
 macro_rules! compare_scalar {
     ($lhs:expr, $rhs:expr, $op:tt, $p:tt) => {{
+        compare_scalar!($lhs, $rhs, $op, $p, None)
+    }};
+    ($lhs:expr, $rhs:expr, $op:tt, $p:tt, $null_keys:expr) => {{
         let lhs = $lhs;
         let rhs = $rhs;
-        assert_eq!(
-            lhs.data_type().to_logical_type(),
-            rhs.data_type().to_logical_type()
-        );
+        // dictionaries are compared via their values
+        let data_type = match lhs.data_type().to_logical_type() {
+            DataType::Dictionary(_, values, _) => values.to_logical_type(),
+            data_type => data_type,
+        };
+        assert_eq!(data_type, rhs.data_type().to_logical_type());
         if !rhs.is_valid() {
             return BooleanArray::new_null(DataType::Boolean, lhs.len());
         }
@@ -266,11 +341,35 @@ macro_rules! compare_scalar {
                 let rhs = rhs.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
                 binary::$op::<i64>(lhs, rhs.value().unwrap())
             }
+            Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let lhs = lhs.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let values = $op(lhs.values().as_ref(), rhs);
+                take_dictionary(lhs.keys(), &values, $null_keys)
+            }),
             _ => todo!("Comparisons of {:?} are not yet supported", lhs.data_type()),
         }
     }};
 }
 
+/// Returns the result of a comparison of a [`DictionaryArray`] from the result of the
+/// comparison of its values (`values`), whereby each value is compared only once.
+/// Null keys are `null_keys`.
+fn take_dictionary<K: DictionaryKey>(
+    keys: &PrimitiveArray<K>,
+    values: &BooleanArray,
+    null_keys: Option<bool>,
+) -> BooleanArray {
+    keys.iter()
+        .map(|key| match key {
+            Some(key) => {
+                let key = key.to_usize().unwrap();
+                values.is_valid(key).then(|| values.value(key))
+            }
+            None => null_keys,
+        })
+        .collect()
+}
+
 /// `==` between an [`Array`] and a [`Scalar`].
 /// Use [`can_eq`] to check whether the operation is valid
 /// # Panic
@@ -331,6 +430,34 @@ pub fn gt_eq_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
     compare_scalar!(lhs, rhs, gt_eq_scalar, match_eq_ord)
 }
 
+/// `IS DISTINCT FROM` between an [`Array`] and a [`Scalar`]. When the scalar is null, this is
+/// whether each value of the array is valid. The result has no null values.
+/// Use [`can_is_distinct_from`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn is_distinct_from_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    if !rhs.is_valid() {
+        return validity(lhs, true);
+    }
+    compare_scalar!(lhs, rhs, is_distinct_from_scalar, match_eq, Some(true))
+}
+
+/// `IS NOT DISTINCT FROM` between an [`Array`] and a [`Scalar`]. When the scalar is null, this
+/// is whether each value of the array is null. The result has no null values.
+/// Use [`can_is_distinct_from`] to check whether the operation is valid
+/// # Panic
+/// Panics iff either:
+/// * they do not have have the same logical type
+/// * the operation is not supported for the logical type
+pub fn is_not_distinct_from_scalar(lhs: &dyn Array, rhs: &dyn Scalar) -> BooleanArray {
+    if !rhs.is_valid() {
+        return validity(lhs, false);
+    }
+    compare_scalar!(lhs, rhs, is_not_distinct_from_scalar, match_eq, Some(false))
+}
+
 /// Returns whether a [`DataType`] is comparable (either array or scalar).
 pub fn can_eq(data_type: &DataType) -> bool {
     can_partial_eq(data_type)
@@ -361,8 +488,17 @@ pub fn can_gt_eq(data_type: &DataType) -> bool {
     can_partial_eq_and_ord(data_type)
 }
 
+/// Returns whether a [`DataType`] is comparable with [`is_distinct_from`] and
+/// [`is_not_distinct_from`] (either array or scalar).
+pub fn can_is_distinct_from(data_type: &DataType) -> bool {
+    can_partial_eq(data_type) || data_type.to_physical_type() == PhysicalType::Null
+}
+
 // The list of operations currently supported.
 fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+        return can_partial_eq_and_ord(values);
+    }
     matches!(
         data_type,
         DataType::Boolean
@@ -393,6 +529,9 @@ fn can_partial_eq_and_ord(data_type: &DataType) -> bool {
 
 // The list of operations currently supported.
 fn can_partial_eq(data_type: &DataType) -> bool {
+    if let DataType::Dictionary(_, values, _) = data_type.to_logical_type() {
+        return can_partial_eq(values);
+    }
     can_partial_eq_and_ord(data_type)
         || matches!(
             data_type.to_logical_type(),
@@ -400,3 +539,52 @@ fn can_partial_eq(data_type: &DataType) -> bool {
                 | DataType::Interval(IntervalUnit::MonthDayNano)
         )
 }
+
+/// Returns a [`BooleanArray`] of `length` values, all `value`.
+fn all(value: bool, length: usize) -> BooleanArray {
+    let values = Bitmap::new_zeroed(length);
+    let values = if value { !&values } else { values };
+    BooleanArray::from_data(DataType::Boolean, values, None)
+}
+
+/// Returns whether each value of `array` is valid (`is_valid`) or null (`!is_valid`).
+fn validity(array: &dyn Array, is_valid: bool) -> BooleanArray {
+    if array.data_type().to_physical_type() == PhysicalType::Null {
+        return all(!is_valid, array.len());
+    }
+    match array.validity() {
+        Some(validity) if is_valid => {
+            BooleanArray::from_data(DataType::Boolean, validity.clone(), None)
+        }
+        Some(validity) => BooleanArray::from_data(DataType::Boolean, !validity, None),
+        None => all(is_valid, array.len()),
+    }
+}
+
+/// Returns the null-safe equality from `eq`, the result of `==` between `lhs` and `rhs` (of
+/// validities `lhs` and `rhs`): the values of `eq` where both sides are valid, `true` where both
+/// sides are null and `false` otherwise.
+pub(super) fn null_safe_eq(
+    eq: &BooleanArray,
+    lhs: Option<&Bitmap>,
+    rhs: Option<&Bitmap>,
+) -> BooleanArray {
+    let values = match (lhs, rhs) {
+        (None, None) => eq.values().clone(),
+        (Some(validity), None) | (None, Some(validity)) => eq.values() & validity,
+        (Some(lhs), Some(rhs)) => ternary(eq.values(), lhs, rhs, |eq, lhs, rhs| {
+            (eq & lhs & rhs) | !(lhs | rhs)
+        }),
+    };
+    BooleanArray::from_data(DataType::Boolean, values, None)
+}
+
+/// Returns the negation of [`null_safe_eq`].
+pub(super) fn null_safe_neq(
+    eq: &BooleanArray,
+    lhs: Option<&Bitmap>,
+    rhs: Option<&Bitmap>,
+) -> BooleanArray {
+    let eq = null_safe_eq(eq, lhs, rhs);
+    BooleanArray::from_data(DataType::Boolean, !eq.values(), None)
+}
@@ -8,6 +8,7 @@ use crate::{
 
 use super::super::utils::combine_validities;
 use super::simd::{Simd8, Simd8Lanes, Simd8PartialEq, Simd8PartialOrd};
+use super::{null_safe_eq, null_safe_neq};
 
 pub(crate) fn compare_values_op<T, F>(lhs: &[T], rhs: &[T], op: F) -> MutableBitmap
 where
@@ -126,6 +127,48 @@ where
     compare_op_scalar(lhs, rhs, |a, b| a.neq(b))
 }
 
+/// Perform `left IS DISTINCT FROM right` operation on two arrays: like [`neq`], but a null
+/// value is distinct from a non-null value and not distinct from another null value.
+/// The result has no null values.
+pub fn is_distinct_from<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialEq,
+{
+    null_safe_neq(&eq(lhs, rhs), lhs.validity(), rhs.validity())
+}
+
+/// Perform `left IS DISTINCT FROM right` operation on an array and a scalar value.
+/// The result has no null values.
+pub fn is_distinct_from_scalar<T>(lhs: &PrimitiveArray<T>, rhs: T) -> BooleanArray
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialEq,
+{
+    null_safe_neq(&eq_scalar(lhs, rhs), lhs.validity(), None)
+}
+
+/// Perform `left IS NOT DISTINCT FROM right` operation on two arrays: like [`eq`], but a null
+/// value is equal to another null value and different from a non-null value.
+/// The result has no null values.
+pub fn is_not_distinct_from<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialEq,
+{
+    null_safe_eq(&eq(lhs, rhs), lhs.validity(), rhs.validity())
+}
+
+/// Perform `left IS NOT DISTINCT FROM right` operation on an array and a scalar value.
+/// The result has no null values.
+pub fn is_not_distinct_from_scalar<T>(lhs: &PrimitiveArray<T>, rhs: T) -> BooleanArray
+where
+    T: NativeType + Simd8,
+    T::Simd: Simd8PartialEq,
+{
+    null_safe_eq(&eq_scalar(lhs, rhs), lhs.validity(), None)
+}
+
 /// Perform `left < right` operation on two arrays.
 pub fn lt<T>(lhs: &PrimitiveArray<T>, rhs: &PrimitiveArray<T>) -> BooleanArray
 where
@@ -0,0 +1,224 @@
+//! Defines [`describe`], that profiles the columns of a [`Chunk`] into a new [`Chunk`] with one
+//! row of summary statistics per column (null fraction, distinct count, min, max, mean and most
+//! frequent values), e.g. to explore a dataset or check its quality in a single call.
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::chunk::Chunk;
+use crate::compute::aggregate::{min_max, sum_primitive};
+use crate::compute::cast::{cast, CastOptions};
+use crate::compute::hash::{can_hash, hash};
+use crate::datatypes::{DataType, Field, PhysicalType};
+use crate::error::{ArrowError, Result};
+use crate::scalar::*;
+use crate::types::PrimitiveType;
+
+/// Returns the [`Field`]s of the [`Chunk`] returned by [`describe`]:
+/// * `column` (`Utf8`): the name of the column
+/// * `data_type` (`Utf8`): the (debug) representation of the column's [`DataType`]
+/// * `null_fraction` (`Float64`): the number of null values over the number of values
+/// * `distinct_count` (`UInt64`): an estimate of the number of distinct non-null values, based
+///   on their hashes
+/// * `min` and `max` (`Utf8`): the display of the minimum and maximum values
+/// * `mean` (`Float64`): the mean of the non-null values of numeric columns
+/// * `top_values` (`List<Utf8>`): the most frequent values of string columns, most frequent first
+///
+/// Statistics that do not apply to a column are null.
+pub fn describe_fields() -> Vec<Field> {
+    vec![
+        Field::new("column", DataType::Utf8, false),
+        Field::new("data_type", DataType::Utf8, false),
+        Field::new("null_fraction", DataType::Float64, true),
+        Field::new("distinct_count", DataType::UInt64, true),
+        Field::new("min", DataType::Utf8, true),
+        Field::new("max", DataType::Utf8, true),
+        Field::new("mean", DataType::Float64, true),
+        Field::new(
+            "top_values",
+            ListArray::<i32>::default_datatype(DataType::Utf8),
+            true,
+        ),
+    ]
+}
+
+/// Returns a [`Chunk`] with one row of statistics per column of `columns`, named `names`, whose
+/// columns are described by [`describe_fields`]. `top_k` is the maximum number of most frequent
+/// values reported for string columns.
+/// # Errors
+/// This function errors iff `names` and `columns` have a different number of columns.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Float64Array, Int32Array, Utf8Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::compute::describe::describe;
+///
+/// let a = Int32Array::from([Some(1), None, Some(3), Some(3)]);
+/// let b = Utf8Array::<i32>::from_slice(["x", "y", "x", "z"]);
+/// let columns = Chunk::new(vec![&a as &dyn Array, &b]);
+///
+/// let result = describe(&["a", "b"], &columns, 2).unwrap();
+/// assert_eq!(result.len(), 2);
+/// // the mean of the column "a" is (1 + 3 + 3) / 3
+/// let mean = result.arrays()[6].as_any().downcast_ref::<Float64Array>().unwrap();
+/// assert_eq!(mean.value(0), 7.0 / 3.0);
+/// assert!(mean.is_null(1));
+/// ```
+pub fn describe<N: AsRef<str>, A: AsRef<dyn Array>>(
+    names: &[N],
+    columns: &Chunk<A>,
+    top_k: usize,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    if names.len() != columns.arrays().len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "describe requires one name per column ({} names for {} columns)",
+            names.len(),
+            columns.arrays().len()
+        )));
+    }
+
+    let mut column = MutableUtf8Array::<i32>::with_capacity(names.len());
+    let mut data_type = MutableUtf8Array::<i32>::with_capacity(names.len());
+    let mut null_fraction = MutablePrimitiveArray::<f64>::with_capacity(names.len());
+    let mut distinct_count = MutablePrimitiveArray::<u64>::with_capacity(names.len());
+    let mut min = MutableUtf8Array::<i32>::with_capacity(names.len());
+    let mut max = MutableUtf8Array::<i32>::with_capacity(names.len());
+    let mut mean = MutablePrimitiveArray::<f64>::with_capacity(names.len());
+    let mut top_values = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+
+    for (name, array) in names.iter().zip(columns.arrays()) {
+        let array = array.as_ref();
+        column.push(Some(name.as_ref()));
+        data_type.push(Some(format!("{:?}", array.data_type())));
+        null_fraction.push(if array.is_empty() {
+            None
+        } else {
+            Some(array.null_count() as f64 / array.len() as f64)
+        });
+        distinct_count.push(distinct(array));
+        let (min_value, max_value) = bounds(array);
+        min.push(min_value);
+        max.push(max_value);
+        mean.push(mean_numeric(array)?);
+        top_values.try_push(top(array, top_k))?;
+    }
+
+    Ok(Chunk::new(vec![
+        column.as_arc(),
+        data_type.as_arc(),
+        null_fraction.as_arc(),
+        distinct_count.as_arc(),
+        min.as_arc(),
+        max.as_arc(),
+        mean.as_arc(),
+        top_values.as_arc(),
+    ]))
+}
+
+/// Returns the number of distinct hashes of the non-null values of `array`.
+fn distinct(array: &dyn Array) -> Option<u64> {
+    let hashes: HashSet<u64> = match array.data_type().to_physical_type() {
+        // floats are not hashable: their bit patterns are counted instead
+        PhysicalType::Primitive(PrimitiveType::Float32) => {
+            let array = array.as_any().downcast_ref::<Float32Array>().unwrap();
+            array.iter().flatten().map(|x| x.to_bits() as u64).collect()
+        }
+        PhysicalType::Primitive(PrimitiveType::Float64) => {
+            let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+            array.iter().flatten().map(|x| x.to_bits()).collect()
+        }
+        _ if can_hash(array.data_type()) => hash(array).ok()?.iter().flatten().copied().collect(),
+        _ => return None,
+    };
+    Some(hashes.len() as u64)
+}
+
+/// Returns the display of the minimum and maximum values of `array`.
+fn bounds(array: &dyn Array) -> (Option<String>, Option<String>) {
+    match min_max(array) {
+        Ok((min, max)) => (display(min.as_ref()), display(max.as_ref())),
+        Err(_) => (None, None),
+    }
+}
+
+/// Returns the display of `scalar`, as it is displayed when it is part of an array.
+fn display(scalar: &dyn Scalar) -> Option<String> {
+    if !scalar.is_valid() {
+        return None;
+    }
+    let data_type = scalar.data_type().clone();
+    let array: Box<dyn Array> = match data_type.to_physical_type() {
+        PhysicalType::Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            return scalar.value().map(|x| x.to_string());
+        }
+        PhysicalType::Utf8 => {
+            let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            return scalar.value().map(|x| x.to_string());
+        }
+        PhysicalType::LargeUtf8 => {
+            let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            return scalar.value().map(|x| x.to_string());
+        }
+        PhysicalType::Binary => {
+            let scalar = scalar.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+            Box::new(BinaryArray::<i32>::from([scalar.value()]))
+        }
+        PhysicalType::LargeBinary => {
+            let scalar = scalar.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+            Box::new(BinaryArray::<i64>::from([scalar.value()]))
+        }
+        PhysicalType::Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let scalar = scalar.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            Box::new(PrimitiveArray::<$T>::from([scalar.value()]).to(data_type))
+        }),
+        _ => return None,
+    };
+    let display = get_display(array.as_ref());
+    Some(display(0))
+}
+
+fn is_numeric(data_type: &DataType) -> bool {
+    use DataType::*;
+    matches!(
+        data_type,
+        UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | Float32 | Float64
+    )
+}
+
+/// Returns the mean of the non-null values of `array` if it is numeric.
+fn mean_numeric(array: &dyn Array) -> Result<Option<f64>> {
+    if !is_numeric(array.data_type()) {
+        return Ok(None);
+    }
+    let array = cast(array, &DataType::Float64, CastOptions::default())?;
+    let array = array.as_any().downcast_ref::<Float64Array>().unwrap();
+    let count = array.len() - array.null_count();
+    Ok(sum_primitive(array).map(|sum| sum / count as f64))
+}
+
+/// Returns the (up to) `k` most frequent non-null values of `array` if it is a string array, most
+/// frequent first and ties ordered by value.
+fn top(array: &dyn Array, k: usize) -> Option<Vec<Option<String>>> {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Utf8 => Some(top_utf8::<i32>(array.as_any().downcast_ref().unwrap(), k)),
+        PhysicalType::LargeUtf8 => Some(top_utf8::<i64>(array.as_any().downcast_ref().unwrap(), k)),
+        _ => None,
+    }
+}
+
+fn top_utf8<O: Offset>(array: &Utf8Array<O>, k: usize) -> Vec<Option<String>> {
+    let mut counts = HashMap::<&str, usize>::new();
+    array.iter().flatten().for_each(|value| {
+        *counts.entry(value).or_default() += 1;
+    });
+    let mut counts = counts.into_iter().collect::<Vec<_>>();
+    counts.sort_unstable_by(|(lhs_value, lhs), (rhs_value, rhs)| {
+        rhs.cmp(lhs).then_with(|| lhs_value.cmp(rhs_value))
+    });
+    counts
+        .into_iter()
+        .take(k)
+        .map(|(value, _)| Some(value.to_string()))
+        .collect()
+}
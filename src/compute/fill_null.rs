@@ -0,0 +1,229 @@
+//! Contains kernels that replace the null values of arrays: [`fill_null`] replaces them by a
+//! [`Scalar`], while [`forward_fill`] and [`backward_fill`] replace them by the previous and next
+//! non-null value, as commonly done to clean time series.
+use std::sync::Arc;
+
+use crate::array::growable::make_growable;
+use crate::array::*;
+use crate::bitmap::utils::SlicesIterator;
+use crate::bitmap::Bitmap;
+use crate::datatypes::PhysicalType;
+use crate::error::{ArrowError, Result};
+use crate::scalar::*;
+
+/// Returns an [`Array`] of length 1 with the value of `scalar`.
+fn scalar_to_array(scalar: &dyn Scalar) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    let data_type = scalar.data_type().clone();
+    let validity = if scalar.is_valid() {
+        None
+    } else {
+        Some(Bitmap::from([false]))
+    };
+    Ok(match data_type.to_physical_type() {
+        Null => Box::new(NullArray::from_data(data_type, 1)),
+        Boolean => {
+            let scalar = scalar.as_any().downcast_ref::<BooleanScalar>().unwrap();
+            Box::new(BooleanArray::from([scalar.value()]))
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let scalar = scalar.as_any().downcast_ref::<PrimitiveScalar<$T>>().unwrap();
+            Box::new(PrimitiveArray::<$T>::from([scalar.value()]).to(data_type))
+        }),
+        Utf8 => {
+            let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<i32>>().unwrap();
+            Box::new(Utf8Array::<i32>::from([scalar.value()]))
+        }
+        LargeUtf8 => {
+            let scalar = scalar.as_any().downcast_ref::<Utf8Scalar<i64>>().unwrap();
+            Box::new(Utf8Array::<i64>::from([scalar.value()]))
+        }
+        Binary => {
+            let scalar = scalar.as_any().downcast_ref::<BinaryScalar<i32>>().unwrap();
+            Box::new(BinaryArray::<i32>::from([scalar.value()]))
+        }
+        LargeBinary => {
+            let scalar = scalar.as_any().downcast_ref::<BinaryScalar<i64>>().unwrap();
+            Box::new(BinaryArray::<i64>::from([scalar.value()]))
+        }
+        List => {
+            let scalar = scalar.as_any().downcast_ref::<ListScalar<i32>>().unwrap();
+            let values = scalar.values().clone();
+            let offsets = vec![0, values.len() as i32];
+            Box::new(ListArray::<i32>::from_data(
+                data_type,
+                offsets.into(),
+                values,
+                validity,
+            ))
+        }
+        LargeList => {
+            let scalar = scalar.as_any().downcast_ref::<ListScalar<i64>>().unwrap();
+            let values = scalar.values().clone();
+            let offsets = vec![0, values.len() as i64];
+            Box::new(ListArray::<i64>::from_data(
+                data_type,
+                offsets.into(),
+                values,
+                validity,
+            ))
+        }
+        Struct => {
+            let scalar = scalar.as_any().downcast_ref::<StructScalar>().unwrap();
+            let values = if scalar.is_valid() {
+                scalar
+                    .values()
+                    .iter()
+                    .map(|x| scalar_to_array(x.as_ref()).map(Arc::from))
+                    .collect::<Result<Vec<_>>>()?
+            } else {
+                StructArray::get_fields(&data_type)
+                    .iter()
+                    .map(|field| new_null_array(field.data_type().clone(), 1).into())
+                    .collect()
+            };
+            Box::new(StructArray::from_data(data_type, values, validity))
+        }
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let scalar = scalar.as_any().downcast_ref::<DictionaryScalar<$T>>().unwrap();
+            match scalar.value() {
+                Some(value) => {
+                    let keys = PrimitiveArray::<$T>::from_slice([0]);
+                    let values = scalar_to_array(value.as_ref())?;
+                    Box::new(DictionaryArray::<$T>::from_data(keys, values.into()))
+                }
+                None => Box::new(DictionaryArray::<$T>::new_null(data_type, 1)),
+            }
+        }),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Scalars of {:?} are not supported",
+                data_type
+            )))
+        }
+    })
+}
+
+/// Returns `array` with its null values replaced by `value`.
+/// # Errors
+/// This function errors iff `array` and `value` have different data types.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::fill_null::fill_null;
+/// use arrow2::datatypes::DataType;
+/// use arrow2::scalar::PrimitiveScalar;
+///
+/// let array = Int32Array::from([Some(1), None, Some(3)]);
+/// let value = PrimitiveScalar::new(DataType::Int32, Some(0));
+/// let result = fill_null(&array, &value).unwrap();
+/// assert_eq!(result.as_ref(), &Int32Array::from_slice([1, 0, 3]) as &dyn Array);
+/// ```
+pub fn fill_null(array: &dyn Array, value: &dyn Scalar) -> Result<Box<dyn Array>> {
+    if array.data_type() != value.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "fill_null requires the value to have the data type of the array ({:?} != {:?})",
+            value.data_type(),
+            array.data_type()
+        )));
+    }
+    let validity = match array.validity() {
+        Some(validity) if validity.null_count() > 0 && value.is_valid() => validity,
+        _ => return Ok(clone(array)),
+    };
+    let value = scalar_to_array(value)?;
+
+    let mut growable = make_growable(&[array, value.as_ref()], false, array.len());
+    let mut end = 0;
+    for (start, length) in SlicesIterator::new(validity) {
+        (end..start).for_each(|_| growable.extend(1, 0, 1));
+        growable.extend(0, start, length);
+        end = start + length;
+    }
+    (end..array.len()).for_each(|_| growable.extend(1, 0, 1));
+    Ok(growable.as_box())
+}
+
+/// Returns an [`Array`] whose slot `i` is the slot `sources[i]` of `array`, or null when it is `None`.
+fn gather<I: Iterator<Item = Option<usize>>>(array: &dyn Array, sources: I) -> Box<dyn Array> {
+    let mut growable = make_growable(&[array], true, array.len());
+    // consecutive sources are extended at once
+    let mut run: Option<(usize, usize)> = None;
+    for source in sources {
+        run = match (run, source) {
+            (Some((start, length)), Some(source)) if start + length == source => {
+                Some((start, length + 1))
+            }
+            (run, source) => {
+                if let Some((start, length)) = run {
+                    growable.extend(0, start, length);
+                }
+                if source.is_none() {
+                    growable.extend_validity(1);
+                }
+                source.map(|source| (source, 1))
+            }
+        };
+    }
+    if let Some((start, length)) = run {
+        growable.extend(0, start, length);
+    }
+    growable.as_box()
+}
+
+/// Returns `array` with each null value replaced by the closest non-null value before it.
+/// Null values without a non-null value before them remain null.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::fill_null::forward_fill;
+///
+/// let array = Utf8Array::<i32>::from([None, Some("a"), None, Some("b"), None]);
+/// let result = forward_fill(&array);
+/// let expected = Utf8Array::<i32>::from([None, Some("a"), Some("a"), Some("b"), Some("b")]);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+pub fn forward_fill(array: &dyn Array) -> Box<dyn Array> {
+    let validity = match array.validity() {
+        Some(validity) if validity.null_count() > 0 => validity,
+        _ => return clone(array),
+    };
+    let sources = validity
+        .iter()
+        .enumerate()
+        .scan(None, |last, (i, is_valid)| {
+            if is_valid {
+                *last = Some(i);
+            }
+            Some(*last)
+        });
+    gather(array, sources)
+}
+
+/// Returns `array` with each null value replaced by the closest non-null value after it.
+/// Null values without a non-null value after them remain null.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::fill_null::backward_fill;
+///
+/// let array = Int32Array::from([None, Some(1), None, Some(2), None]);
+/// let result = backward_fill(&array);
+/// let expected = Int32Array::from([Some(1), Some(1), Some(2), Some(2), None]);
+/// assert_eq!(result.as_ref(), &expected as &dyn Array);
+/// ```
+pub fn backward_fill(array: &dyn Array) -> Box<dyn Array> {
+    let validity = match array.validity() {
+        Some(validity) if validity.null_count() > 0 => validity,
+        _ => return clone(array),
+    };
+    let mut sources = vec![None; array.len()];
+    let mut next = None;
+    for i in (0..array.len()).rev() {
+        if validity.get_bit(i) {
+            next = Some(i);
+        }
+        sources[i] = next;
+    }
+    gather(array, sources.into_iter())
+}
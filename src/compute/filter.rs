@@ -3,12 +3,68 @@ use crate::array::growable::{make_growable, Growable};
 use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
 use crate::chunk::Chunk;
 use crate::datatypes::DataType;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::{array::*, types::NativeType};
 
 /// Function that can filter arbitrary arrays
 pub type Filter<'a> = Box<dyn Fn(&dyn Array) -> Box<dyn Array> + 'a + Send + Sync>;
 
+/// Calls `f(start, length)` for every run of set bits of `mask`, in increasing order.
+///
+/// The mask is processed in chunks of 64 bits, so that chunks without set bits are skipped,
+/// chunks with all bits set are a single run and the runs of other chunks are found with
+/// `trailing_zeros` instead of branching on every bit. Runs spanning more than one chunk
+/// are merged into a single run.
+fn for_each_run<F: FnMut(usize, usize)>(mask: &Bitmap, mut f: F) {
+    let chunks = mask.chunks::<u64>();
+    // the bits of the remainder beyond the length of the mask are not part of it
+    let remainder = chunks.remainder() & ((1u64 << chunks.remainder_len()) - 1);
+    let remainder_offset = mask.len() - chunks.remainder_len();
+
+    let mut run_start = 0;
+    let mut run_length = 0;
+    let mut push = |start: usize, length: usize| {
+        if run_start + run_length == start {
+            run_length += length;
+        } else {
+            if run_length > 0 {
+                f(run_start, run_length);
+            }
+            run_start = start;
+            run_length = length;
+        }
+    };
+    chunks
+        .enumerate()
+        .for_each(|(index, chunk)| chunk_runs(chunk, index * 64, &mut push));
+    chunk_runs(remainder, remainder_offset, &mut push);
+
+    if run_length > 0 {
+        f(run_start, run_length);
+    }
+}
+
+/// Calls `push(start, length)` for every run of set bits of `chunk`, offset by `offset`.
+#[inline]
+fn chunk_runs<F: FnMut(usize, usize)>(mut chunk: u64, offset: usize, push: &mut F) {
+    if chunk == u64::MAX {
+        push(offset, 64);
+        return;
+    }
+    while chunk != 0 {
+        let start = chunk.trailing_zeros() as usize;
+        let length = (!(chunk >> start)).trailing_zeros() as usize;
+        push(offset + start, length);
+        // clear the run (the bits before it are already clear)
+        let end = start + length;
+        chunk = if end == 64 {
+            0
+        } else {
+            chunk & (u64::MAX << end)
+        };
+    }
+}
+
 fn filter_nonnull_primitive<T: NativeType>(
     array: &PrimitiveArray<T>,
     mask: &Bitmap,
@@ -16,21 +72,16 @@ fn filter_nonnull_primitive<T: NativeType>(
     assert_eq!(array.len(), mask.len());
     let filter_count = mask.len() - mask.null_count();
 
+    let values = array.values().as_slice();
     let mut buffer = Vec::<T>::with_capacity(filter_count);
     if let Some(validity) = array.validity() {
+        let (slice, offset, _) = validity.as_slice();
         let mut new_validity = MutableBitmap::with_capacity(filter_count);
 
-        array
-            .values()
-            .iter()
-            .zip(validity.iter())
-            .zip(mask.iter())
-            .filter(|x| x.1)
-            .map(|x| x.0)
-            .for_each(|(item, is_valid)| unsafe {
-                buffer.push(*item);
-                new_validity.push_unchecked(is_valid);
-            });
+        for_each_run(mask, |start, length| {
+            buffer.extend_from_slice(&values[start..start + length]);
+            new_validity.extend_from_slice(slice, offset + start, length);
+        });
 
         PrimitiveArray::<T>::from_data(
             array.data_type().clone(),
@@ -38,13 +89,9 @@ fn filter_nonnull_primitive<T: NativeType>(
             new_validity.into(),
         )
     } else {
-        array
-            .values()
-            .iter()
-            .zip(mask.iter())
-            .filter(|x| x.1)
-            .map(|x| x.0)
-            .for_each(|item| buffer.push(*item));
+        for_each_run(mask, |start, length| {
+            buffer.extend_from_slice(&values[start..start + length])
+        });
 
         PrimitiveArray::<T>::from_data(array.data_type().clone(), buffer.into(), None)
     }
@@ -64,6 +111,14 @@ fn filter_growable<'a>(growable: &mut impl Growable<'a>, chunks: &[(usize, usize
         .for_each(|(start, len)| growable.extend(0, *start, *len));
 }
 
+/// The filtered [`DictionaryArray`] of `array` with `keys`, sharing (not copying) its values.
+fn filter_dictionary<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+    keys: PrimitiveArray<K>,
+) -> DictionaryArray<K> {
+    DictionaryArray::from_data_type(array.data_type().clone(), keys, array.values().clone())
+}
+
 /// Returns a prepared function optimized to filter multiple arrays.
 /// Creating this function requires time, but using it is faster than [filter] when the
 /// same filter needs to be applied to multiple arrays (e.g. a multiple columns).
@@ -97,6 +152,14 @@ pub fn build_filter(filter: &BooleanArray) -> Result<Filter> {
                 let array: Utf8Array<i64> = growable.into();
                 Box::new(array)
             }
+            Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let mut growable =
+                    growable::GrowablePrimitive::<$T>::new(vec![array.keys()], false, filter_count);
+                filter_growable(&mut growable, &chunks);
+                let keys: PrimitiveArray<$T> = growable.into();
+                Box::new(filter_dictionary(array, keys))
+            }),
             _ => {
                 let mut mutable = make_growable(&[array], false, filter_count);
                 chunks
@@ -143,6 +206,11 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
             let array = array.as_any().downcast_ref().unwrap();
             Ok(Box::new(filter_primitive::<$T>(array, filter)))
         }),
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let keys = filter_primitive::<$T>(array.keys(), filter);
+            Ok(Box::new(filter_dictionary(array, keys)))
+        }),
         _ => {
             let iter = SlicesIterator::new(filter.values());
             let mut mutable = make_growable(&[array], false, iter.slots());
@@ -152,6 +220,85 @@ pub fn filter(array: &dyn Array, filter: &BooleanArray) -> Result<Box<dyn Array>
     }
 }
 
+/// Appends the slots of `array` matching the filter (i.e. where the values are true) to `out`,
+/// without allocating intermediary arrays.
+///
+/// This is equivalent to extending `out` with the result of [`filter`], and is useful to
+/// assemble an array from (the slots of) many arrays. As in [`filter`], the nulls of `filter`
+/// are interpreted as `false`.
+/// # Example
+/// ```rust
+/// use arrow2::array::{BooleanArray, Int32Array, MutablePrimitiveArray};
+/// use arrow2::compute::filter::filter_into;
+///
+/// let mut out = MutablePrimitiveArray::<i32>::new();
+/// let array = Int32Array::from_slice([1, 2, 3]);
+/// filter_into(&array, &BooleanArray::from_slice([true, false, true]), &mut out).unwrap();
+/// let array = Int32Array::from([Some(4), None]);
+/// filter_into(&array, &BooleanArray::from_slice([false, true]), &mut out).unwrap();
+/// let out: Int32Array = out.into();
+/// assert_eq!(out, Int32Array::from([Some(1), Some(3), None]));
+/// ```
+/// # Errors
+/// This function errors iff the arrays have different lengths, `out` has a different
+/// [`DataType`] than `array`, or `out` is not the [`MutableArray`] of `array`, whose physical
+/// type must be boolean, primitive, binary or utf8.
+pub fn filter_into(
+    array: &dyn Array,
+    filter: &BooleanArray,
+    out: &mut dyn MutableArray,
+) -> Result<()> {
+    if array.len() != filter.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Filter into requires the array and the filter to have the same length".to_string(),
+        ));
+    }
+    if array.data_type() != out.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Filter into requires arrays of the same data type, but got {:?} and {:?}",
+            array.data_type(),
+            out.data_type()
+        )));
+    }
+
+    let mask = match filter.validity() {
+        Some(validity) => filter.values() & validity,
+        None => filter.values().clone(),
+    };
+
+    macro_rules! filter_into_typed {
+        ($array:ty, $mutable:ty) => {{
+            let array = array.as_any().downcast_ref::<$array>().unwrap();
+            let out = out.as_mut_any().downcast_mut::<$mutable>().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Filter into {:?} requires a {}",
+                    array.data_type(),
+                    stringify!($mutable)
+                ))
+            })?;
+            SlicesIterator::new(&mask)
+                .for_each(|(start, len)| out.extend_trusted_len(array.slice(start, len).iter()));
+            Ok(())
+        }};
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Boolean => filter_into_typed!(BooleanArray, MutableBooleanArray),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            filter_into_typed!(PrimitiveArray<$T>, MutablePrimitiveArray<$T>)
+        }),
+        Utf8 => filter_into_typed!(Utf8Array<i32>, MutableUtf8Array<i32>),
+        LargeUtf8 => filter_into_typed!(Utf8Array<i64>, MutableUtf8Array<i64>),
+        Binary => filter_into_typed!(BinaryArray<i32>, MutableBinaryArray<i32>),
+        LargeBinary => filter_into_typed!(BinaryArray<i64>, MutableBinaryArray<i64>),
+        t => Err(ArrowError::NotYetImplemented(format!(
+            "Filter into is not supported for data type {:?}",
+            t
+        ))),
+    }
+}
+
 /// Returns a new [Chunk] with arrays containing only values matching the filter.
 /// This is a convenience function: filter multiple columns is embarassingly parallel.
 pub fn filter_chunk<A: AsRef<dyn Array>>(
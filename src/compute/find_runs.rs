@@ -0,0 +1,59 @@
+//! Contains the [`find_runs`] kernel, that finds the runs of consecutive equal values of an
+//! [`Array`], e.g. to run-length encode it or to check whether it is constant.
+use std::cmp::Ordering;
+
+use crate::array::growable::make_growable;
+use crate::array::ord::build_compare;
+use crate::array::{equal, Array};
+
+/// Returns the (exclusive) ends of the runs of consecutive equal values of `array` and
+/// an [`Array`] with the value of each run.
+/// Null values are equal to each other and different from any other value, and `NaN`s are
+/// equal to each other.
+///
+/// An empty `array` has no runs, and `array` is constant iff it has at most one run.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::compute::find_runs::find_runs;
+///
+/// let array = Int32Array::from([Some(1), Some(1), None, None, Some(2), Some(1)]);
+/// let (ends, values) = find_runs(&array);
+/// assert_eq!(ends, vec![2, 4, 5, 6]);
+/// let expected = Int32Array::from([Some(1), None, Some(2), Some(1)]);
+/// assert_eq!(values.as_ref(), &expected as &dyn Array);
+/// ```
+pub fn find_runs(array: &dyn Array) -> (Vec<usize>, Box<dyn Array>) {
+    let ends = run_ends(array);
+
+    let mut growable = make_growable(&[array], true, ends.len());
+    let mut start = 0;
+    for end in ends.iter() {
+        growable.extend(0, start, 1);
+        start = *end;
+    }
+    (ends, growable.as_box())
+}
+
+fn run_ends(array: &dyn Array) -> Vec<usize> {
+    let len = array.len();
+    if len == 0 {
+        return vec![];
+    }
+    let comparator = build_compare(array, array).ok();
+    let is_equal = |i: usize, j: usize| match (array.is_valid(i), array.is_valid(j)) {
+        (true, true) => match &comparator {
+            Some(comparator) => comparator(i, j) == Ordering::Equal,
+            // types without a natural order are compared by equality
+            None => equal(array.slice(i, 1).as_ref(), array.slice(j, 1).as_ref()),
+        },
+        (false, false) => true,
+        _ => false,
+    };
+
+    let mut ends = (1..len)
+        .filter(|i| !is_equal(i - 1, *i))
+        .collect::<Vec<_>>();
+    ends.push(len);
+    ends
+}
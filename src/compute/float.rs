@@ -0,0 +1,81 @@
+//! Defines kernels that classify the values of floating point arrays, [`is_nan`],
+//! [`is_finite`] and [`is_infinite`], e.g. to check the quality of data or to exclude `NaN`s
+//! from aggregations.
+use num_traits::Float;
+
+use crate::array::{Array, BooleanArray, PrimitiveArray};
+use crate::bitmap::Bitmap;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+fn classify<T, F>(array: &PrimitiveArray<T>, op: F) -> BooleanArray
+where
+    T: NativeType + Float,
+    F: Fn(T) -> bool,
+{
+    let values = Bitmap::from_trusted_len_iter(array.values().iter().map(|x| op(*x)));
+    BooleanArray::from_data(DataType::Boolean, values, array.validity().cloned())
+}
+
+fn classify_dyn(
+    array: &dyn Array,
+    name: &str,
+    op32: fn(f32) -> bool,
+    op64: fn(f64) -> bool,
+) -> Result<BooleanArray> {
+    match array.data_type().to_logical_type() {
+        DataType::Float32 => Ok(classify(array.as_any().downcast_ref().unwrap(), op32)),
+        DataType::Float64 => Ok(classify(array.as_any().downcast_ref().unwrap(), op64)),
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "{} not supported for {:?}",
+            name, other
+        ))),
+    }
+}
+
+/// Returns whether each value of `array` is `NaN`. Null values are null.
+/// # Error
+/// This function errors iff `array` is not a `Float32` or `Float64` array.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Float64Array};
+/// use arrow2::compute::float::is_nan;
+///
+/// let array = Float64Array::from([Some(1.0), Some(f64::NAN), None]);
+/// let result = is_nan(&array).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(false), Some(true), None]));
+/// ```
+pub fn is_nan(array: &dyn Array) -> Result<BooleanArray> {
+    classify_dyn(array, "is_nan", f32::is_nan, f64::is_nan)
+}
+
+/// Returns whether each value of `array` is neither infinite nor `NaN`. Null values are null.
+/// # Error
+/// This function errors iff `array` is not a `Float32` or `Float64` array.
+pub fn is_finite(array: &dyn Array) -> Result<BooleanArray> {
+    classify_dyn(array, "is_finite", f32::is_finite, f64::is_finite)
+}
+
+/// Returns whether each value of `array` is positive or negative infinity. Null values are null.
+/// # Error
+/// This function errors iff `array` is not a `Float32` or `Float64` array.
+pub fn is_infinite(array: &dyn Array) -> Result<BooleanArray> {
+    classify_dyn(array, "is_infinite", f32::is_infinite, f64::is_infinite)
+}
+
+/// Returns whether [`is_nan`], [`is_finite`] and [`is_infinite`] support arrays of `data_type`.
+/// # Examples
+/// ```
+/// use arrow2::compute::float::can_classify;
+/// use arrow2::datatypes::DataType;
+///
+/// assert_eq!(can_classify(&DataType::Float32), true);
+/// assert_eq!(can_classify(&DataType::Int32), false);
+/// ```
+pub fn can_classify(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Float32 | DataType::Float64
+    )
+}
@@ -0,0 +1,111 @@
+//! Defines kernels that assign numeric values to buckets (also known as bins), [`bin`] with
+//! explicit bin edges and [`bin_uniform`] with bins of equal width, and [`histogram`], that
+//! counts the values of each bucket, e.g. to group by ranges of values or to profile data.
+use num_traits::AsPrimitive;
+
+use crate::array::{PrimitiveArray, UInt32Array, UInt64Array};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+/// Returns the bucket of each value of `array`, where bucket `i` contains the values in
+/// `[edges[i], edges[i + 1])` and the last bucket also contains `edges[edges.len() - 1]`.
+/// Null values, values outside of the edges and `NaN`s are null.
+/// # Errors
+/// This function errors iff there are less than 2 `edges` or they are not strictly increasing.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, UInt32Array};
+/// use arrow2::compute::histogram::bin;
+///
+/// let array = Int32Array::from([Some(1), Some(5), None, Some(10), Some(11)]);
+/// let result = bin(&array, &[0, 5, 10]).unwrap();
+/// assert_eq!(result, UInt32Array::from([Some(0), Some(1), None, Some(1), None]));
+/// ```
+pub fn bin<T: NativeType + PartialOrd>(
+    array: &PrimitiveArray<T>,
+    edges: &[T],
+) -> Result<UInt32Array> {
+    if edges.len() < 2 || edges.len() - 1 > u32::MAX as usize {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "bin requires between 2 and {} edges, got {}",
+            u32::MAX as usize + 1,
+            edges.len()
+        )));
+    }
+    if !edges.windows(2).all(|x| x[0] < x[1]) {
+        return Err(ArrowError::InvalidArgumentError(
+            "bin requires strictly increasing edges".to_string(),
+        ));
+    }
+    let last = edges.len() - 1;
+    let bucket = |value: T| match edges.partition_point(|edge| *edge <= value) {
+        0 => None,
+        n if n < edges.len() => Some((n - 1) as u32),
+        _ if value == edges[last] => Some((last - 1) as u32),
+        _ => None,
+    };
+    Ok(UInt32Array::from_trusted_len_iter(
+        array.iter().map(|x| x.and_then(|x| bucket(*x))),
+    ))
+}
+
+/// Returns the bucket of each value of `array`, where the range `[min, max]` is divided in
+/// `bins` buckets of equal width. Null values, values outside of the range and `NaN`s are null.
+/// # Errors
+/// This function errors iff `min` is not smaller than `max` or `bins` is zero or does not fit
+/// in a `u32`.
+/// # Example
+/// ```
+/// use arrow2::array::{Float64Array, UInt32Array};
+/// use arrow2::compute::histogram::bin_uniform;
+///
+/// let array = Float64Array::from([Some(0.0), Some(2.5), None, Some(10.0), Some(-1.0)]);
+/// let result = bin_uniform(&array, 0.0, 10.0, 4).unwrap();
+/// assert_eq!(result, UInt32Array::from([Some(0), Some(1), None, Some(3), None]));
+/// ```
+pub fn bin_uniform<T: NativeType + AsPrimitive<f64>>(
+    array: &PrimitiveArray<T>,
+    min: f64,
+    max: f64,
+    bins: usize,
+) -> Result<UInt32Array> {
+    if min.is_nan() || max.is_nan() || min >= max || bins == 0 || bins > u32::MAX as usize {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "bin_uniform requires min < max and a positive number of bins, got [{}, {}] and {}",
+            min, max, bins
+        )));
+    }
+    let width = (max - min) / bins as f64;
+    let bucket = |value: T| {
+        let value: f64 = value.as_();
+        if (min..=max).contains(&value) {
+            Some((((value - min) / width) as usize).min(bins - 1) as u32)
+        } else {
+            None
+        }
+    };
+    Ok(UInt32Array::from_trusted_len_iter(
+        array.iter().map(|x| x.and_then(|x| bucket(*x))),
+    ))
+}
+
+/// Returns the number of values in each of the `num_buckets` buckets of `buckets`, as returned
+/// by [`bin`] or [`bin_uniform`]. Null values and values from `num_buckets` on are not counted.
+/// # Example
+/// ```
+/// use arrow2::array::{UInt32Array, UInt64Array};
+/// use arrow2::compute::histogram::histogram;
+///
+/// let buckets = UInt32Array::from([Some(0), Some(2), None, Some(2)]);
+/// let result = histogram(&buckets, 3);
+/// assert_eq!(result, UInt64Array::from_slice([1, 0, 2]));
+/// ```
+pub fn histogram(buckets: &UInt32Array, num_buckets: usize) -> UInt64Array {
+    let mut counts = vec![0u64; num_buckets];
+    buckets.iter().flatten().for_each(|bucket| {
+        if let Some(count) = counts.get_mut(*bucket as usize) {
+            *count += 1;
+        }
+    });
+    UInt64Array::from_vec(counts)
+}
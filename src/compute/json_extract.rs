@@ -0,0 +1,203 @@
+//! Defines kernels to extract values from \[Large\]Utf8 arrays containing JSON documents,
+//! such as [`json_extract`].
+//!
+//! Paths follow a subset of JSONPath: they start with `$` (the root), followed by
+//! any number of `.key`, `["key"]` or `[index]` accessors, e.g. `$.a.b[0]["c d"]`.
+use serde_json::Value;
+
+use crate::array::{Array, BooleanArray, Offset, PrimitiveArray, Utf8Array};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathElement {
+    Key(String),
+    Index(usize),
+}
+
+fn invalid_path(path: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!("\"{}\" is not a valid JSON path", path))
+}
+
+fn parse_path(path: &str) -> Result<Vec<PathElement>> {
+    let mut rest = path
+        .trim()
+        .strip_prefix('$')
+        .ok_or_else(|| invalid_path(path))?;
+
+    let mut elements = vec![];
+    while !rest.is_empty() {
+        if let Some(remaining) = rest.strip_prefix('.') {
+            let end = remaining.find(['.', '[']).unwrap_or(remaining.len());
+            if end == 0 {
+                return Err(invalid_path(path));
+            }
+            elements.push(PathElement::Key(remaining[..end].to_string()));
+            rest = &remaining[end..];
+        } else if let Some(remaining) = rest.strip_prefix('[') {
+            let end = remaining.find(']').ok_or_else(|| invalid_path(path))?;
+            let accessor = remaining[..end].trim();
+            let element = if let Some(key) = accessor
+                .strip_prefix('"')
+                .and_then(|x| x.strip_suffix('"'))
+                .or_else(|| {
+                    accessor
+                        .strip_prefix('\'')
+                        .and_then(|x| x.strip_suffix('\''))
+                }) {
+                PathElement::Key(key.to_string())
+            } else {
+                PathElement::Index(accessor.parse().map_err(|_| invalid_path(path))?)
+            };
+            elements.push(element);
+            rest = &remaining[end + 1..];
+        } else {
+            return Err(invalid_path(path));
+        }
+    }
+    Ok(elements)
+}
+
+/// Applies `op` to the value at `path` of the JSON document `value`.
+/// Invalid documents and missing paths yield `None`.
+fn extract<T, F: Fn(&Value) -> Option<T>>(value: &str, path: &[PathElement], op: &F) -> Option<T> {
+    let document: Value = serde_json::from_str(value).ok()?;
+    let value = path
+        .iter()
+        .try_fold(&document, |value, element| match element {
+            PathElement::Key(key) => value.get(key),
+            PathElement::Index(index) => value.get(index),
+        })?;
+    op(value)
+}
+
+fn extract_values<O: Offset, T, F: Fn(&Value) -> Option<T>>(
+    array: &Utf8Array<O>,
+    path: &str,
+    op: F,
+) -> Result<Vec<Option<T>>> {
+    let path = parse_path(path)?;
+    Ok(array
+        .iter()
+        .map(|value| value.and_then(|value| extract(value, &path, &op)))
+        .collect())
+}
+
+/// Extracts the value at the JSON `path` of each JSON document of `array` into a [`Utf8Array`].
+///
+/// Strings are returned without quotes; any other non-null value (numbers, booleans,
+/// objects and arrays) is returned as its JSON representation.
+/// The result is null for null values, invalid JSON documents, missing paths and JSON `null`s.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::json_extract::json_extract_utf8;
+///
+/// let array = Utf8Array::<i32>::from([
+///     Some(r#"{"a": {"b": "x"}}"#),
+///     Some(r#"{"a": {"b": [1, 2]}}"#),
+///     Some(r#"{"a": 1}"#),
+///     None,
+/// ]);
+/// let result = json_extract_utf8(&array, "$.a.b").unwrap();
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("x"), Some("[1,2]"), None, None]));
+/// ```
+/// # Errors
+/// This function errors iff `path` is not a valid path.
+pub fn json_extract_utf8<O: Offset>(array: &Utf8Array<O>, path: &str) -> Result<Utf8Array<O>> {
+    let values = extract_values(array, path, |value| match value {
+        Value::Null => None,
+        Value::String(value) => Some(value.clone()),
+        other => Some(other.to_string()),
+    })?;
+    Ok(Utf8Array::<O>::from(values))
+}
+
+/// Extracts the number at the JSON `path` of each JSON document of `array` into a
+/// [`PrimitiveArray<f64>`].
+///
+/// The result is null for null values, invalid JSON documents, missing paths and values
+/// that are not JSON numbers.
+/// # Errors
+/// This function errors iff `path` is not a valid path.
+pub fn json_extract_f64<O: Offset>(
+    array: &Utf8Array<O>,
+    path: &str,
+) -> Result<PrimitiveArray<f64>> {
+    let values = extract_values(array, path, Value::as_f64)?;
+    Ok(PrimitiveArray::<f64>::from(values))
+}
+
+/// Extracts the boolean at the JSON `path` of each JSON document of `array` into a
+/// [`BooleanArray`].
+///
+/// The result is null for null values, invalid JSON documents, missing paths and values
+/// that are not JSON booleans.
+/// # Errors
+/// This function errors iff `path` is not a valid path.
+pub fn json_extract_bool<O: Offset>(array: &Utf8Array<O>, path: &str) -> Result<BooleanArray> {
+    let values = extract_values(array, path, Value::as_bool)?;
+    Ok(BooleanArray::from(values))
+}
+
+/// Extracts the value at the JSON `path` of each JSON document of `array` into an array of
+/// `data_type`, which must be `Utf8`, `LargeUtf8`, `Float64` or `Boolean`.
+///
+/// See [`json_extract_utf8`], [`json_extract_f64`] and [`json_extract_bool`] for the semantics
+/// of each type. Strings are extracted to the type of `array` (e.g. `LargeUtf8` to `LargeUtf8`).
+/// # Errors
+/// This function errors if `array` is not a \[Large\]Utf8 array, if `data_type` is not supported
+/// (see [`can_json_extract`]) or if `path` is not a valid path.
+pub fn json_extract(array: &dyn Array, path: &str, data_type: &DataType) -> Result<Box<dyn Array>> {
+    match (array.data_type(), data_type) {
+        (DataType::Utf8, DataType::Utf8) => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            json_extract_utf8(array, path).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (DataType::LargeUtf8, DataType::LargeUtf8) => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            json_extract_utf8(array, path).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (DataType::Utf8, DataType::Float64) => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            json_extract_f64(array, path).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (DataType::LargeUtf8, DataType::Float64) => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            json_extract_f64(array, path).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (DataType::Utf8, DataType::Boolean) => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            json_extract_bool(array, path).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (DataType::LargeUtf8, DataType::Boolean) => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            json_extract_bool(array, path).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        (from, to) => Err(ArrowError::InvalidArgumentError(format!(
+            "json_extract does not support extracting {:?} from {:?}",
+            to, from
+        ))),
+    }
+}
+
+/// Checks if JSON values can be extracted from arrays of `from_type` into arrays of `to_type`
+/// via [`json_extract`].
+/// # Examples
+/// ```
+/// use arrow2::compute::json_extract::can_json_extract;
+/// use arrow2::datatypes::DataType;
+///
+/// assert_eq!(can_json_extract(&DataType::Utf8, &DataType::Float64), true);
+/// assert_eq!(can_json_extract(&DataType::Utf8, &DataType::Int32), false);
+/// assert_eq!(can_json_extract(&DataType::Int32, &DataType::Utf8), false);
+/// ```
+pub fn can_json_extract(from_type: &DataType, to_type: &DataType) -> bool {
+    matches!(
+        (from_type, to_type),
+        (DataType::Utf8, DataType::Utf8)
+            | (DataType::LargeUtf8, DataType::LargeUtf8)
+            | (DataType::Utf8 | DataType::LargeUtf8, DataType::Float64)
+            | (DataType::Utf8 | DataType::LargeUtf8, DataType::Boolean)
+    )
+}
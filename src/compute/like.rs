@@ -1,4 +1,8 @@
-//! Contains "like" operators such as [`like_utf8`] and [`like_utf8_scalar`].
+//! Contains "like" operators such as [`like_utf8`], [`like_utf8_scalar`] and their
+//! case-insensitive variants, [`ilike_utf8`] and [`ilike_utf8_scalar`].
+//!
+//! Scalar patterns without wildcards or of the form `abc%` (prefix), `%abc` (suffix) and
+//! `%abc%` (containment) are evaluated without a regex.
 use std::collections::HashMap;
 
 use regex::bytes::Regex as BytesRegex;
@@ -17,10 +21,42 @@ fn is_like_pattern(c: char) -> bool {
     c == '%' || c == '_'
 }
 
+/// Returns the regex equivalent to the LIKE `pattern`, whose characters other than the
+/// wildcards are matched literally.
+fn like_to_regex(pattern: &str, case_insensitive: bool) -> String {
+    let mut regex = String::with_capacity(pattern.len() + 8);
+    // `%` and `_` also match new lines
+    regex.push_str(if case_insensitive { "(?is)^" } else { "(?s)^" });
+    let mut buffer = [0; 4];
+    for c in pattern.chars() {
+        match c {
+            '%' => regex.push_str(".*"),
+            '_' => regex.push('.'),
+            c => regex.push_str(&regex::escape(c.encode_utf8(&mut buffer))),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+fn like_error(error: regex::Error) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "Unable to build regex from LIKE pattern: {}",
+        error
+    ))
+}
+
+/// Returns whether `haystack` contains `needle`.
+#[inline]
+fn contains_bytes(haystack: &[u8], needle: &[u8]) -> bool {
+    needle.is_empty() || haystack.windows(needle.len()).any(|x| x == needle)
+}
+
 #[inline]
 fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &Utf8Array<O>,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
     if lhs.len() != rhs.len() {
@@ -40,13 +76,8 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
                     let pattern = if let Some(pattern) = map.get(pattern) {
                         pattern
                     } else {
-                        let re_pattern = pattern.replace("%", ".*").replace("_", ".");
-                        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                            ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
-                                e
-                            ))
-                        })?;
+                        let re = Regex::new(&like_to_regex(pattern, case_insensitive))
+                            .map_err(like_error)?;
                         map.insert(pattern, re);
                         map.get(pattern).unwrap()
                     };
@@ -82,7 +113,7 @@ fn a_like_utf8<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, false, true, false, true]));
 /// ```
 pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| x)
+    a_like_utf8(lhs, rhs, false, |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation on two [`Utf8Array`].
@@ -92,18 +123,22 @@ pub fn like_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<Bo
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 pub fn nlike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
-    a_like_utf8(lhs, rhs, |x| !x)
+    a_like_utf8(lhs, rhs, false, |x| !x)
 }
 
 fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
     lhs: &Utf8Array<O>,
     rhs: &str,
+    case_insensitive: bool,
     op: F,
 ) -> Result<BooleanArray> {
     let validity = lhs.validity();
 
-    let values = if !rhs.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| x == rhs))
+    let values = if case_insensitive {
+        let re = Regex::new(&like_to_regex(rhs, true)).map_err(like_error)?;
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
+    } else if !rhs.contains(is_like_pattern) {
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
     } else if rhs.ends_with('%') && !rhs[..rhs.len() - 1].contains(is_like_pattern) {
         // fast path, can use starts_with
         let starts_with = &rhs[..rhs.len() - 1];
@@ -112,14 +147,16 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
         // fast path, can use ends_with
         let ends_with = &rhs[1..];
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
+    } else if rhs.len() >= 2
+        && rhs.starts_with('%')
+        && rhs.ends_with('%')
+        && !rhs[1..rhs.len() - 1].contains(is_like_pattern)
+    {
+        // fast path, can use contains
+        let contains = &rhs[1..rhs.len() - 1];
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.contains(contains))))
     } else {
-        let re_pattern = rhs.replace("%", ".*").replace("_", ".");
-        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
+        let re = Regex::new(&like_to_regex(rhs, false)).map_err(like_error)?;
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
     };
     Ok(BooleanArray::from_data(
@@ -151,7 +188,7 @@ fn a_like_utf8_scalar<O: Offset, F: Fn(bool) -> bool>(
 /// assert_eq!(result, BooleanArray::from_slice(&[true, true, true, false]));
 /// ```
 pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| x)
+    a_like_utf8_scalar(lhs, rhs, false, |x| x)
 }
 
 /// Returns `lhs NOT LIKE rhs` operation.
@@ -161,7 +198,76 @@ pub fn like_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<Bool
 /// * `%` - The percent sign represents zero, one, or multiple characters
 /// * `_` - The underscore represents a single character
 pub fn nlike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
-    a_like_utf8_scalar(lhs, rhs, |x| !x)
+    a_like_utf8_scalar(lhs, rhs, false, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation on two [`Utf8Array`], the case-insensitive [`like_utf8`].
+///
+/// There are two wildcards supported:
+///
+/// * `%` - The percent sign represents zero, one, or multiple characters
+/// * `_` - The underscore represents a single character
+///
+/// # Error
+/// Errors iff:
+/// * the arrays have a different length
+/// * any of the patterns is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8;
+///
+/// let strings = Utf8Array::<i32>::from_slice(&["Arrow", "Arrow", "Arrow"]);
+/// let patterns = Utf8Array::<i32>::from_slice(&["a%", "%ROW", "b%"]);
+///
+/// let result = ilike_utf8(&strings, &patterns).unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, false]));
+/// ```
+pub fn ilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation on two [`Utf8Array`].
+///
+/// There are two wildcards supported:
+///
+/// * `%` - The percent sign represents zero, one, or multiple characters
+/// * `_` - The underscore represents a single character
+pub fn nilike_utf8<O: Offset>(lhs: &Utf8Array<O>, rhs: &Utf8Array<O>) -> Result<BooleanArray> {
+    a_like_utf8(lhs, rhs, true, |x| !x)
+}
+
+/// Returns `lhs ILIKE rhs` operation, the case-insensitive [`like_utf8_scalar`].
+///
+/// There are two wildcards supported:
+///
+/// * `%` - The percent sign represents zero, one, or multiple characters
+/// * `_` - The underscore represents a single character
+///
+/// # Error
+/// Errors iff the pattern is not valid
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, BooleanArray};
+/// use arrow2::compute::like::ilike_utf8_scalar;
+///
+/// let array = Utf8Array::<i32>::from_slice(&["Arrow", "ARROW", "arrows", "BA"]);
+///
+/// let result = ilike_utf8_scalar(&array, "arrow").unwrap();
+/// assert_eq!(result, BooleanArray::from_slice(&[true, true, false, false]));
+/// ```
+pub fn ilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, true, |x| x)
+}
+
+/// Returns `lhs NOT ILIKE rhs` operation.
+///
+/// There are two wildcards supported:
+///
+/// * `%` - The percent sign represents zero, one, or multiple characters
+/// * `_` - The underscore represents a single character
+pub fn nilike_utf8_scalar<O: Offset>(lhs: &Utf8Array<O>, rhs: &str) -> Result<BooleanArray> {
+    a_like_utf8_scalar(lhs, rhs, true, |x| !x)
 }
 
 #[inline]
@@ -187,16 +293,14 @@ fn a_like_binary<O: Offset, F: Fn(bool) -> bool>(
                     let pattern = if let Some(pattern) = map.get(pattern) {
                         pattern
                     } else {
-                        let re_pattern = simdutf8::basic::from_utf8(pattern)
-                            .unwrap()
-                            .replace("%", ".*")
-                            .replace("_", ".");
-                        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
+                        let re_pattern = simdutf8::basic::from_utf8(pattern).map_err(|e| {
                             ArrowError::InvalidArgumentError(format!(
-                                "Unable to build regex from LIKE pattern: {}",
+                                "Unable to convert the LIKE pattern to string: {}",
                                 e
                             ))
                         })?;
+                        let re = BytesRegex::new(&like_to_regex(re_pattern, false))
+                            .map_err(like_error)?;
                         map.insert(pattern, re);
                         map.get(pattern).unwrap()
                     };
@@ -260,7 +364,7 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
     })?;
 
     let values = if !pattern.contains(is_like_pattern) {
-        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| x == rhs))
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x == rhs)))
     } else if pattern.ends_with('%') && !pattern[..pattern.len() - 1].contains(is_like_pattern) {
         // fast path, can use starts_with
         let starts_with = &rhs[..rhs.len() - 1];
@@ -269,14 +373,16 @@ fn a_like_binary_scalar<O: Offset, F: Fn(bool) -> bool>(
         // fast path, can use ends_with
         let ends_with = &rhs[1..];
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(x.ends_with(ends_with))))
+    } else if pattern.len() >= 2
+        && pattern.starts_with('%')
+        && pattern.ends_with('%')
+        && !pattern[1..pattern.len() - 1].contains(is_like_pattern)
+    {
+        // fast path, can search for the bytes
+        let contains = &rhs[1..rhs.len() - 1];
+        Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(contains_bytes(x, contains))))
     } else {
-        let re_pattern = pattern.replace("%", ".*").replace("_", ".");
-        let re = BytesRegex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::InvalidArgumentError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
+        let re = BytesRegex::new(&like_to_regex(pattern, false)).map_err(like_error)?;
         Bitmap::from_trusted_len_iter(lhs.values_iter().map(|x| op(re.is_match(x))))
     };
     Ok(BooleanArray::from_data(
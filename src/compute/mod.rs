@@ -15,10 +15,16 @@
 #[cfg(feature = "compute_aggregate")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_aggregate")))]
 pub mod aggregate;
+#[cfg(feature = "compute_approx_distinct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_approx_distinct")))]
+pub mod approx_distinct;
 #[cfg(feature = "compute_arithmetics")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_arithmetics")))]
 pub mod arithmetics;
 pub mod arity;
+#[cfg(feature = "compute_between")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_between")))]
+pub mod between;
 #[cfg(feature = "compute_bitwise")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_bitwise")))]
 pub mod bitwise;
@@ -40,15 +46,33 @@ pub mod concatenate;
 #[cfg(feature = "compute_contains")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_contains")))]
 pub mod contains;
+#[cfg(feature = "compute_describe")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_describe")))]
+pub mod describe;
+#[cfg(feature = "compute_fill_null")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_fill_null")))]
+pub mod fill_null;
 #[cfg(feature = "compute_filter")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_filter")))]
 pub mod filter;
+#[cfg(feature = "compute_find_runs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_find_runs")))]
+pub mod find_runs;
+#[cfg(feature = "compute_float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_float")))]
+pub mod float;
 #[cfg(feature = "compute_hash")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_hash")))]
 pub mod hash;
+#[cfg(feature = "compute_histogram")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_histogram")))]
+pub mod histogram;
 #[cfg(feature = "compute_if_then_else")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_if_then_else")))]
 pub mod if_then_else;
+#[cfg(feature = "compute_json_extract")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_json_extract")))]
+pub mod json_extract;
 #[cfg(feature = "compute_length")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_length")))]
 pub mod length;
@@ -70,12 +94,30 @@ pub mod nullif;
 #[cfg(feature = "compute_partition")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_partition")))]
 pub mod partition;
+#[cfg(feature = "compute_rechunk")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_rechunk")))]
+pub mod rechunk;
 #[cfg(feature = "compute_regex_match")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_regex_match")))]
 pub mod regex_match;
+#[cfg(feature = "compute_registry")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_registry")))]
+pub mod registry;
+#[cfg(feature = "compute_selection")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_selection")))]
+pub mod selection;
 #[cfg(feature = "compute_sort")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_sort")))]
 pub mod sort;
+#[cfg(feature = "compute_split")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_split")))]
+pub mod split;
+#[cfg(feature = "compute_string")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_string")))]
+pub mod string;
+#[cfg(feature = "compute_string_distance")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_string_distance")))]
+pub mod string_distance;
 #[cfg(feature = "compute_substring")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_substring")))]
 pub mod substring;
@@ -85,6 +127,9 @@ pub mod take;
 #[cfg(feature = "compute_temporal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_temporal")))]
 pub mod temporal;
+#[cfg(feature = "compute_tokenize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compute_tokenize")))]
+pub mod tokenize;
 #[cfg(feature = "compute_upper")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compute_upper")))]
 pub mod upper;
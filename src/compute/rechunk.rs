@@ -0,0 +1,138 @@
+//! Defines [`Rechunk`], an iterator adapter that re-chunks an iterator of [`Chunk`]s to chunks
+//! of a target number of rows.
+//!
+//! Some readers produce many small chunks, on which most kernels perform poorly;
+//! [`Rechunk`] coalesces them (by concatenating their arrays) and splits chunks that are
+//! larger than the target (by slicing them, without copying).
+//!
+//! # Example
+//! ```
+//! use std::sync::Arc;
+//! use arrow2::array::{Array, Int32Array};
+//! use arrow2::chunk::Chunk;
+//! use arrow2::compute::rechunk::Rechunk;
+//!
+//! let chunks = vec![
+//!     Ok(Chunk::new(vec![Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>])),
+//!     Ok(Chunk::new(vec![Arc::new(Int32Array::from_slice([3])) as Arc<dyn Array>])),
+//!     Ok(Chunk::new(vec![Arc::new(Int32Array::from_slice([4, 5, 6, 7])) as Arc<dyn Array>])),
+//! ];
+//! let lengths = Rechunk::new(chunks.into_iter(), 3)
+//!     .map(|chunk| chunk.unwrap().len())
+//!     .collect::<Vec<_>>();
+//! assert_eq!(lengths, vec![3, 3, 1]);
+//! ```
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::compute::concatenate::concatenate;
+use crate::error::{ArrowError, Result};
+
+/// An [`Iterator`] adapter that yields [`Chunk`]s of `length` rows from an iterator of
+/// [`Chunk`]s of any number of rows. Only the last chunk may have fewer rows.
+///
+/// Chunks are sliced when they are larger than needed and are only concatenated when
+/// more than one is needed to fill a chunk, so that chunks that already have `length` rows
+/// are yielded without copying.
+pub struct Rechunk<I: Iterator<Item = Result<Chunk<Arc<dyn Array>>>>> {
+    iter: I,
+    length: usize,
+    // chunks (or their remainders) read from `iter` and not yet yielded
+    pending: VecDeque<Chunk<Arc<dyn Array>>>,
+    pending_length: usize,
+    is_finished: bool,
+}
+
+impl<I: Iterator<Item = Result<Chunk<Arc<dyn Array>>>>> Rechunk<I> {
+    /// Creates a new [`Rechunk`] yielding chunks of `length` rows from `iter`.
+    /// # Panics
+    /// Panics iff `length` is zero.
+    pub fn new(iter: I, length: usize) -> Self {
+        assert!(
+            length > 0,
+            "The length of re-chunked chunks must be positive"
+        );
+        Self {
+            iter,
+            length,
+            pending: VecDeque::new(),
+            pending_length: 0,
+            is_finished: false,
+        }
+    }
+
+    /// Removes the first `length` pending rows, concatenating them to a single chunk.
+    fn take_pending(&mut self, length: usize) -> Result<Chunk<Arc<dyn Array>>> {
+        let mut parts = Vec::with_capacity(self.pending.len());
+        let mut remaining = length;
+        while remaining > 0 {
+            let chunk = self.pending.pop_front().unwrap();
+            if chunk.len() <= remaining {
+                remaining -= chunk.len();
+                parts.push(chunk);
+            } else {
+                self.pending
+                    .push_front(chunk.slice(remaining, chunk.len() - remaining));
+                parts.push(chunk.slice(0, remaining));
+                remaining = 0;
+            }
+        }
+        self.pending_length -= length;
+
+        if parts.len() == 1 {
+            Ok(parts.pop().unwrap())
+        } else {
+            merge(&parts)
+        }
+    }
+}
+
+impl<I: Iterator<Item = Result<Chunk<Arc<dyn Array>>>>> Iterator for Rechunk<I> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.is_finished && self.pending_length < self.length {
+            match self.iter.next() {
+                Some(Ok(chunk)) => {
+                    if !chunk.is_empty() {
+                        self.pending_length += chunk.len();
+                        self.pending.push_back(chunk);
+                    }
+                }
+                Some(Err(error)) => return Some(Err(error)),
+                None => self.is_finished = true,
+            }
+        }
+        if self.pending_length == 0 {
+            return None;
+        }
+        let length = self.pending_length.min(self.length);
+        Some(self.take_pending(length))
+    }
+}
+
+/// Concatenates the arrays of every column of `chunks`.
+fn merge(chunks: &[Chunk<Arc<dyn Array>>]) -> Result<Chunk<Arc<dyn Array>>> {
+    let num_columns = chunks[0].arrays().len();
+    if chunks
+        .iter()
+        .any(|chunk| chunk.arrays().len() != num_columns)
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "Chunks with a different number of columns cannot be re-chunked together".to_string(),
+        ));
+    }
+
+    let arrays = (0..num_columns)
+        .map(|column| {
+            let arrays = chunks
+                .iter()
+                .map(|chunk| chunk.arrays()[column].as_ref())
+                .collect::<Vec<_>>();
+            concatenate(&arrays).map(|array| array.into())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Chunk::new(arrays))
+}
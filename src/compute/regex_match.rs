@@ -1,8 +1,14 @@
-//! Contains regex matching operators [`regex_match`] and [`regex_match_scalar`].
+//! Contains regex matching operators [`regex_match`], [`regex_match_scalar`] and
+//! [`regex_match_compiled`], e.g. for `RLIKE` filters.
+//!
+//! Patterns are compiled once per call (and once per distinct pattern in [`regex_match`]);
+//! [`regex_match_compiled`] reuses a [`Regex`] compiled by the caller, e.g. across the
+//! chunks of a column.
 
 use std::collections::HashMap;
 
-use regex::Regex;
+/// Re-export of [`regex::Regex`], the compiled patterns of [`regex_match_compiled`].
+pub use regex::Regex;
 
 use super::utils::{combine_validities, unary_utf8_boolean};
 use crate::array::{BooleanArray, Offset, Utf8Array};
@@ -10,7 +16,22 @@ use crate::bitmap::Bitmap;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
 
-/// Regex matches
+/// Returns whether each value of `values` matches the pattern at the same slot of `regex`.
+/// The result is null when either the value or the pattern is null. Each distinct pattern
+/// is compiled once.
+/// # Errors
+/// Errors iff the arrays have a different length or a pattern is not a valid regex.
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::regex_match::regex_match;
+///
+/// let strings = Utf8Array::<i32>::from([Some("ArAow"), Some("A_B"), None]);
+/// let patterns = Utf8Array::<i32>::from_slice(["^A.A", "^A.A", "B"]);
+///
+/// let result = regex_match(&strings, &patterns).unwrap();
+/// assert_eq!(result, BooleanArray::from([Some(true), Some(false), None]));
+/// ```
 pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Result<BooleanArray> {
     if values.len() != regex.len() {
         return Err(ArrowError::InvalidArgumentError(
@@ -33,10 +54,7 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
             regex
         } else {
             let re = Regex::new(regex).map_err(|e| {
-                ArrowError::InvalidArgumentError(format!(
-                    "Unable to build regex from LIKE pattern: {}",
-                    e
-                ))
+                ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e))
             })?;
             map.insert(regex, re);
             map.get(regex).unwrap()
@@ -53,7 +71,11 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
     ))
 }
 
-/// Regex matches
+/// Returns whether each value of `values` matches the pattern `regex`.
+/// The pattern is compiled once; use [`regex_match_compiled`] to reuse a compiled pattern
+/// across calls.
+/// # Errors
+/// Errors iff `regex` is not a valid regex.
 /// # Example
 /// ```
 /// use arrow2::array::{Utf8Array, BooleanArray};
@@ -67,5 +89,29 @@ pub fn regex_match<O: Offset>(values: &Utf8Array<O>, regex: &Utf8Array<O>) -> Re
 pub fn regex_match_scalar<O: Offset>(values: &Utf8Array<O>, regex: &str) -> Result<BooleanArray> {
     let regex = Regex::new(regex)
         .map_err(|e| ArrowError::InvalidArgumentError(format!("Unable to compile regex: {}", e)))?;
-    Ok(unary_utf8_boolean(values, |x| regex.is_match(x)))
+    Ok(regex_match_compiled(values, &regex))
+}
+
+/// Returns whether each value of `values` matches `regex`, a compiled pattern, so that the
+/// pattern is compiled once for many arrays (e.g. the chunks of a column).
+/// # Example
+/// ```
+/// use arrow2::array::{BooleanArray, Utf8Array};
+/// use arrow2::compute::regex_match::{regex_match_compiled, Regex};
+///
+/// let regex = Regex::new("^A.A").unwrap();
+/// let chunks = [
+///     Utf8Array::<i32>::from_slice(["ArAow", "A_B"]),
+///     Utf8Array::<i32>::from([Some("AAA"), None]),
+/// ];
+///
+/// let result = chunks
+///     .iter()
+///     .map(|chunk| regex_match_compiled(chunk, &regex))
+///     .collect::<Vec<_>>();
+/// assert_eq!(result[0], BooleanArray::from_slice([true, false]));
+/// assert_eq!(result[1], BooleanArray::from([Some(true), None]));
+/// ```
+pub fn regex_match_compiled<O: Offset>(values: &Utf8Array<O>, regex: &Regex) -> BooleanArray {
+    unary_utf8_boolean(values, |x| regex.is_match(x))
 }
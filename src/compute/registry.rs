@@ -0,0 +1,297 @@
+//! Defines [`Registry`], a dispatch table from named operations to kernels of [`Array`]s.
+//!
+//! Kernels of this crate (e.g. [`crate::compute::arithmetics::add`]) dispatch on the
+//! [`DataType`] of their arguments with `match` statements that cannot be extended.
+//! A [`Registry`] maps an operation (e.g. `"add"`) and the [`DataType`]s of its arguments to
+//! a kernel instead, so that:
+//! * embedders can register kernels for their own types, e.g. [`DataType::Extension`], and
+//!   override the kernels of this crate.
+//! * only the kernels whose features are enabled are registered by [`Registry::builtin`].
+//!
+//! # Example
+//! ```
+//! use arrow2::array::{Array, Int32Array};
+//! use arrow2::compute::registry::Registry;
+//! use arrow2::datatypes::DataType;
+//!
+//! let mut registry = Registry::new();
+//! registry.register_unary(
+//!     "double",
+//!     |data_type| data_type == &DataType::Int32,
+//!     |array| {
+//!         let array = array.as_any().downcast_ref::<Int32Array>().unwrap();
+//!         Ok(Box::new(array.iter().map(|x| x.map(|x| x * 2)).collect::<Int32Array>()))
+//!     },
+//! );
+//!
+//! let array = Int32Array::from([Some(1), None]);
+//! let result = registry.unary("double", &array).unwrap();
+//! assert_eq!(result.as_ref(), &Int32Array::from([Some(2), None]) as &dyn Array);
+//! assert!(registry.unary("triple", &array).is_err());
+//! ```
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// A kernel of one [`Array`].
+pub type UnaryKernel = Arc<dyn Fn(&dyn Array) -> Result<Box<dyn Array>> + Send + Sync>;
+
+/// A kernel of two [`Array`]s.
+pub type BinaryKernel = Arc<dyn Fn(&dyn Array, &dyn Array) -> Result<Box<dyn Array>> + Send + Sync>;
+
+type UnaryPredicate = Arc<dyn Fn(&DataType) -> bool + Send + Sync>;
+type BinaryPredicate = Arc<dyn Fn(&DataType, &DataType) -> bool + Send + Sync>;
+
+/// A dispatch table from named operations and the [`DataType`]s of their arguments to kernels.
+///
+/// Every kernel is registered with a predicate of the [`DataType`]s it supports. When more than
+/// one kernel of an operation supports the arguments, the most recently registered one is used.
+#[derive(Clone, Default)]
+pub struct Registry {
+    unary: HashMap<String, Vec<(UnaryPredicate, UnaryKernel)>>,
+    binary: HashMap<String, Vec<(BinaryPredicate, BinaryKernel)>>,
+}
+
+impl std::fmt::Debug for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut unary = self.unary.keys().collect::<Vec<_>>();
+        unary.sort();
+        let mut binary = self.binary.keys().collect::<Vec<_>>();
+        binary.sort();
+        f.debug_struct("Registry")
+            .field("unary", &unary)
+            .field("binary", &binary)
+            .finish()
+    }
+}
+
+impl Registry {
+    /// Creates a new, empty [`Registry`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`Registry`] with the kernels of this crate whose features are enabled:
+    /// * `compute_arithmetics`: `"add"`, `"sub"`, `"mul"`, `"div"`, `"rem"` and `"neg"`, as well as
+    ///   their checked (e.g. `"checked_add"`) and saturating (e.g. `"saturating_add"`) variants
+    /// * `compute_comparison`: `"eq"`, `"neq"`, `"lt"`, `"lt_eq"`, `"gt"` and `"gt_eq"`
+    /// * `compute_boolean`: `"and"`, `"or"`, `"xor"` and `"not"`
+    /// * `compute_length`: `"length"`
+    pub fn builtin() -> Self {
+        #[allow(unused_mut)]
+        let mut registry = Self::new();
+        #[cfg(feature = "compute_arithmetics")]
+        register_arithmetics(&mut registry);
+        #[cfg(feature = "compute_comparison")]
+        register_comparison(&mut registry);
+        #[cfg(feature = "compute_boolean")]
+        register_boolean(&mut registry);
+        #[cfg(feature = "compute_length")]
+        registry.register_unary(
+            "length",
+            crate::compute::length::can_length,
+            crate::compute::length::length,
+        );
+        registry
+    }
+
+    /// Registers `kernel` as the unary operation `op` of arrays whose [`DataType`] satisfies
+    /// `can_apply`, taking precedence over the kernels of `op` registered before it.
+    pub fn register_unary<P, K>(&mut self, op: &str, can_apply: P, kernel: K)
+    where
+        P: Fn(&DataType) -> bool + Send + Sync + 'static,
+        K: Fn(&dyn Array) -> Result<Box<dyn Array>> + Send + Sync + 'static,
+    {
+        self.unary
+            .entry(op.to_string())
+            .or_default()
+            .push((Arc::new(can_apply), Arc::new(kernel)));
+    }
+
+    /// Registers `kernel` as the binary operation `op` of arrays whose [`DataType`]s satisfy
+    /// `can_apply`, taking precedence over the kernels of `op` registered before it.
+    pub fn register_binary<P, K>(&mut self, op: &str, can_apply: P, kernel: K)
+    where
+        P: Fn(&DataType, &DataType) -> bool + Send + Sync + 'static,
+        K: Fn(&dyn Array, &dyn Array) -> Result<Box<dyn Array>> + Send + Sync + 'static,
+    {
+        self.binary
+            .entry(op.to_string())
+            .or_default()
+            .push((Arc::new(can_apply), Arc::new(kernel)));
+    }
+
+    /// Returns the kernel of the unary operation `op` for `data_type`, if any.
+    pub fn get_unary(&self, op: &str, data_type: &DataType) -> Option<&UnaryKernel> {
+        self.unary.get(op).and_then(|kernels| {
+            kernels
+                .iter()
+                .rev()
+                .find(|(can_apply, _)| can_apply(data_type))
+                .map(|(_, kernel)| kernel)
+        })
+    }
+
+    /// Returns the kernel of the binary operation `op` for `lhs` and `rhs`, if any.
+    pub fn get_binary(&self, op: &str, lhs: &DataType, rhs: &DataType) -> Option<&BinaryKernel> {
+        self.binary.get(op).and_then(|kernels| {
+            kernels
+                .iter()
+                .rev()
+                .find(|(can_apply, _)| can_apply(lhs, rhs))
+                .map(|(_, kernel)| kernel)
+        })
+    }
+
+    /// Returns whether the unary operation `op` is registered for `data_type`.
+    pub fn can_unary(&self, op: &str, data_type: &DataType) -> bool {
+        self.get_unary(op, data_type).is_some()
+    }
+
+    /// Returns whether the binary operation `op` is registered for `lhs` and `rhs`.
+    pub fn can_binary(&self, op: &str, lhs: &DataType, rhs: &DataType) -> bool {
+        self.get_binary(op, lhs, rhs).is_some()
+    }
+
+    /// Applies the unary operation `op` to `array`.
+    /// # Errors
+    /// This function errors iff `op` is not registered for the [`DataType`] of `array` or the
+    /// kernel errors.
+    pub fn unary(&self, op: &str, array: &dyn Array) -> Result<Box<dyn Array>> {
+        let kernel = self.get_unary(op, array.data_type()).ok_or_else(|| {
+            ArrowError::NotYetImplemented(format!(
+                "Operation \"{}\" is not registered for data type {:?}",
+                op,
+                array.data_type()
+            ))
+        })?;
+        kernel(array)
+    }
+
+    /// Applies the binary operation `op` to `lhs` and `rhs`.
+    /// # Errors
+    /// This function errors iff `op` is not registered for the [`DataType`]s of `lhs` and `rhs`
+    /// or the kernel errors.
+    pub fn binary(&self, op: &str, lhs: &dyn Array, rhs: &dyn Array) -> Result<Box<dyn Array>> {
+        let kernel = self
+            .get_binary(op, lhs.data_type(), rhs.data_type())
+            .ok_or_else(|| {
+                ArrowError::NotYetImplemented(format!(
+                    "Operation \"{}\" is not registered for data types {:?} and {:?}",
+                    op,
+                    lhs.data_type(),
+                    rhs.data_type()
+                ))
+            })?;
+        kernel(lhs, rhs)
+    }
+}
+
+/// The kernels of this crate panic on arrays of different lengths
+#[cfg(any(feature = "compute_arithmetics", feature = "compute_comparison"))]
+fn check_same_len(lhs: &dyn Array, rhs: &dyn Array) -> Result<()> {
+    if lhs.len() != rhs.len() {
+        Err(ArrowError::InvalidArgumentError(format!(
+            "Arrays of a binary operation must have the same length, but got {} and {}",
+            lhs.len(),
+            rhs.len()
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "compute_arithmetics")]
+fn register_arithmetics(registry: &mut Registry) {
+    use crate::compute::arithmetics::*;
+
+    macro_rules! register {
+        ($op:literal, $can:ident, $kernel:ident) => {
+            registry.register_binary($op, $can, |lhs, rhs| {
+                check_same_len(lhs, rhs)?;
+                Ok($kernel(lhs, rhs))
+            });
+        };
+    }
+    register!("add", can_add, add);
+    register!("sub", can_sub, sub);
+    register!("mul", can_mul, mul);
+    register!("div", can_div, div);
+    register!("rem", can_rem, rem);
+    // fallible kernels, whose errors are passed through
+    macro_rules! register_try {
+        ($op:literal, $can:ident, $kernel:ident) => {
+            registry.register_binary($op, $can, |lhs, rhs| {
+                check_same_len(lhs, rhs)?;
+                $kernel(lhs, rhs)
+            });
+        };
+    }
+    register_try!("checked_add", can_checked_arithmetics, checked_add);
+    register_try!("checked_sub", can_checked_arithmetics, checked_sub);
+    register_try!("checked_mul", can_checked_arithmetics, checked_mul);
+    register_try!("checked_div", can_checked_arithmetics, checked_div);
+    register_try!("checked_rem", can_integer_arithmetics, checked_rem);
+    register_try!("saturating_add", can_checked_arithmetics, saturating_add);
+    register_try!("saturating_sub", can_checked_arithmetics, saturating_sub);
+    register_try!("saturating_mul", can_checked_arithmetics, saturating_mul);
+    registry.register_unary("neg", can_neg, |array| Ok(neg(array)));
+}
+
+#[cfg(feature = "compute_comparison")]
+fn register_comparison(registry: &mut Registry) {
+    use crate::compute::comparison::*;
+
+    macro_rules! register {
+        ($op:literal, $can:ident, $kernel:ident) => {
+            registry.register_binary(
+                $op,
+                |lhs, rhs| lhs == rhs && $can(lhs),
+                |lhs, rhs| {
+                    check_same_len(lhs, rhs)?;
+                    Ok(Box::new($kernel(lhs, rhs)))
+                },
+            );
+        };
+    }
+    register!("eq", can_eq, eq);
+    register!("neq", can_neq, neq);
+    register!("lt", can_lt, lt);
+    register!("lt_eq", can_lt_eq, lt_eq);
+    register!("gt", can_gt, gt);
+    register!("gt_eq", can_gt_eq, gt_eq);
+}
+
+#[cfg(feature = "compute_boolean")]
+fn register_boolean(registry: &mut Registry) {
+    use crate::array::BooleanArray;
+    use crate::compute::boolean::*;
+
+    fn is_boolean(data_type: &DataType) -> bool {
+        data_type == &DataType::Boolean
+    }
+
+    macro_rules! register {
+        ($op:literal, $kernel:ident) => {
+            registry.register_binary(
+                $op,
+                |lhs, rhs| is_boolean(lhs) && is_boolean(rhs),
+                |lhs, rhs| {
+                    let lhs = lhs.as_any().downcast_ref::<BooleanArray>().unwrap();
+                    let rhs = rhs.as_any().downcast_ref::<BooleanArray>().unwrap();
+                    Ok(Box::new($kernel(lhs, rhs)?))
+                },
+            );
+        };
+    }
+    register!("and", and);
+    register!("or", or);
+    register!("xor", xor);
+    registry.register_unary("not", is_boolean, |array| {
+        let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+        Ok(Box::new(not(array)))
+    });
+}
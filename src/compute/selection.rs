@@ -0,0 +1,286 @@
+//! Defines [`Selection`], a set of selected rows of an array, and conversions between its
+//! representations: masks ([`BooleanArray`]), indices ([`UInt32Array`]) and runs of rows.
+//!
+//! Which representation is the cheapest depends on the selectivity: indices are the smallest
+//! when few rows are selected, runs when the selected rows are clustered, and masks otherwise.
+//! [`boolean_to_selection`] picks the smallest one, so that operators chaining filters (e.g.
+//! via [`Selection::compose`]) can operate on it.
+//!
+//! # Example
+//! ```
+//! use arrow2::array::{BooleanArray, UInt32Array};
+//! use arrow2::compute::selection::{boolean_to_selection, selection_to_indices, Selection};
+//!
+//! let mask = BooleanArray::from_slice([false, true, true, true, false, false]);
+//! let selection = boolean_to_selection(&mask);
+//! assert_eq!(selection, Selection::Runs { runs: vec![(1, 3)], length: 6 });
+//! assert_eq!(
+//!     selection_to_indices(&selection).unwrap(),
+//!     UInt32Array::from_slice([1, 2, 3])
+//! );
+//! ```
+use crate::array::{Array, BooleanArray, PrimitiveArray, UInt32Array};
+use crate::bitmap::{utils::SlicesIterator, Bitmap, MutableBitmap};
+use crate::buffer::Buffer;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// The selected rows of an array of `length` rows.
+///
+/// The indices and runs of a [`Selection`] are sorted in increasing order and do not overlap.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+    /// A mask of the rows of the array, where the selected rows are set.
+    Mask(Bitmap),
+    /// The indices of the selected rows.
+    Indices {
+        /// The indices, in increasing order
+        indices: Buffer<u32>,
+        /// The number of rows of the array
+        length: usize,
+    },
+    /// Runs `(start, length)` of consecutive selected rows.
+    Runs {
+        /// The runs, in increasing order and non-adjacent
+        runs: Vec<(usize, usize)>,
+        /// The number of rows of the array
+        length: usize,
+    },
+}
+
+impl Selection {
+    /// Returns the number of rows of the array this [`Selection`] selects from.
+    pub fn len(&self) -> usize {
+        match self {
+            Selection::Mask(mask) => mask.len(),
+            Selection::Indices { length, .. } | Selection::Runs { length, .. } => *length,
+        }
+    }
+
+    /// Returns whether the array this [`Selection`] selects from has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of selected rows.
+    pub fn selected(&self) -> usize {
+        match self {
+            Selection::Mask(mask) => mask.len() - mask.null_count(),
+            Selection::Indices { indices, .. } => indices.len(),
+            Selection::Runs { runs, .. } => runs.iter().map(|(_, length)| length).sum(),
+        }
+    }
+
+    /// Returns the selected rows as a mask.
+    pub fn to_mask(&self) -> Bitmap {
+        match self {
+            Selection::Mask(mask) => mask.clone(),
+            Selection::Indices { indices, length } => {
+                let mut mask = MutableBitmap::from_len_zeroed(*length);
+                indices
+                    .iter()
+                    .for_each(|index| mask.set(*index as usize, true));
+                mask.into()
+            }
+            Selection::Runs { runs, length } => {
+                let mut mask = MutableBitmap::with_capacity(*length);
+                runs.iter().for_each(|(start, run_length)| {
+                    mask.extend_constant(start - mask.len(), false);
+                    mask.extend_constant(*run_length, true);
+                });
+                mask.extend_constant(length - mask.len(), false);
+                mask.into()
+            }
+        }
+    }
+
+    /// Returns the selected rows as indices.
+    /// # Errors
+    /// Errors iff a selected row is not representable as `u32`.
+    pub fn to_indices(&self) -> Result<Buffer<u32>> {
+        match self {
+            Selection::Indices { indices, .. } => Ok(indices.clone()),
+            _ => {
+                if self.len() > u32::MAX as usize {
+                    return Err(ArrowError::Overflow);
+                }
+                let mut indices = Vec::with_capacity(self.selected());
+                self.for_each_run(|start, length| {
+                    indices.extend((start..start + length).map(|index| index as u32))
+                });
+                Ok(indices.into())
+            }
+        }
+    }
+
+    /// Returns the selected rows as runs `(start, length)` of consecutive rows.
+    pub fn to_runs(&self) -> Vec<(usize, usize)> {
+        match self {
+            Selection::Runs { runs, .. } => runs.clone(),
+            _ => {
+                let mut runs = vec![];
+                self.for_each_run(|start, length| runs.push((start, length)));
+                runs
+            }
+        }
+    }
+
+    /// Calls `f(start, length)` for every run of selected rows, in increasing order.
+    fn for_each_run<F: FnMut(usize, usize)>(&self, mut f: F) {
+        match self {
+            Selection::Mask(mask) => {
+                SlicesIterator::new(mask).for_each(|(start, length)| f(start, length))
+            }
+            Selection::Indices { indices, .. } => {
+                let mut iter = indices.iter().map(|index| *index as usize);
+                if let Some(first) = iter.next() {
+                    let (start, length) = iter.fold((first, 1), |(start, length), index| {
+                        if index == start + length {
+                            (start, length + 1)
+                        } else {
+                            f(start, length);
+                            (index, 1)
+                        }
+                    });
+                    f(start, length);
+                }
+            }
+            Selection::Runs { runs, .. } => {
+                runs.iter().for_each(|(start, length)| f(*start, *length))
+            }
+        }
+    }
+
+    /// Returns the [`Selection`] of the rows of this array selected by `next`, a selection
+    /// of the rows selected by `self`, i.e. the selection of chaining both filters.
+    /// # Errors
+    /// Errors iff `next.len()` is different from the number of rows selected by `self`
+    /// or the selected rows are not representable as `u32`.
+    pub fn compose(&self, next: &Selection) -> Result<Selection> {
+        if next.len() != self.selected() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "A selection of {} rows cannot select from the {} rows selected by another",
+                next.len(),
+                self.selected()
+            )));
+        }
+        let rows = self.to_indices()?;
+        let indices = next
+            .to_indices()?
+            .iter()
+            .map(|index| rows[*index as usize])
+            .collect::<Vec<_>>();
+        Ok(sorted_indices_to_selection(indices.into(), self.len()))
+    }
+}
+
+/// Returns the [`Selection`] of `indices`, sorted and within `length`, in its smallest
+/// representation.
+fn sorted_indices_to_selection(indices: Buffer<u32>, length: usize) -> Selection {
+    let selection = Selection::Indices { indices, length };
+    if is_sparse(selection.selected(), length) {
+        selection
+    } else {
+        let mask = selection.to_mask();
+        bitmap_to_selection(mask)
+    }
+}
+
+/// Whether indices (32 bits per selected row) are smaller than a mask (1 bit per row).
+#[inline]
+fn is_sparse(selected: usize, length: usize) -> bool {
+    selected * 32 < length && length <= u32::MAX as usize
+}
+
+/// Returns the [`Selection`] of the set bits of `mask` in its smallest representation.
+fn bitmap_to_selection(mask: Bitmap) -> Selection {
+    let length = mask.len();
+    let selected = length - mask.null_count();
+    if selected == 0 {
+        return Selection::Runs {
+            runs: vec![],
+            length,
+        };
+    }
+    if selected == length {
+        return Selection::Runs {
+            runs: vec![(0, length)],
+            length,
+        };
+    }
+    if is_sparse(selected, length) {
+        let indices = Selection::Mask(mask).to_indices().unwrap();
+        return Selection::Indices { indices, length };
+    }
+
+    // runs (128 bits each) are smaller than the mask (1 bit per row) when they are few,
+    // and a single run is never more expensive than a mask
+    let max_runs = (length / 128).max(1);
+    let mut runs = vec![];
+    for run in SlicesIterator::new(&mask) {
+        if runs.len() == max_runs {
+            return Selection::Mask(mask);
+        }
+        runs.push(run);
+    }
+    Selection::Runs { runs, length }
+}
+
+/// Returns the [`Selection`] of the rows of `mask` that are `true`, in the representation
+/// that takes the least memory:
+/// * runs, when the selected rows are a single run or are clustered in few runs
+/// * indices, when less than 1 in 32 rows are selected
+/// * a mask otherwise
+///
+/// Like [`filter`](crate::compute::filter::filter), null values of `mask` are not selected.
+pub fn boolean_to_selection(mask: &BooleanArray) -> Selection {
+    let values = match mask.validity() {
+        Some(validity) if validity.null_count() > 0 => mask.values() & validity,
+        _ => mask.values().clone(),
+    };
+    bitmap_to_selection(values)
+}
+
+/// Returns the [`Selection`] of `indices` of an array of `length` rows, in the representation
+/// that takes the least memory.
+/// # Errors
+/// Errors iff `indices` has nulls, is not strictly increasing or has an index out of bounds.
+pub fn indices_to_selection(indices: &UInt32Array, length: usize) -> Result<Selection> {
+    if indices.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "The indices of a selection cannot be null".to_string(),
+        ));
+    }
+    let values = indices.values();
+    if values.windows(2).any(|window| window[0] >= window[1]) {
+        return Err(ArrowError::InvalidArgumentError(
+            "The indices of a selection must be strictly increasing".to_string(),
+        ));
+    }
+    if let Some(last) = values.last() {
+        if *last as usize >= length {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The index {} is out of bounds of an array of {} rows",
+                last, length
+            )));
+        }
+    }
+    Ok(sorted_indices_to_selection(values.clone(), length))
+}
+
+/// Returns the selected rows of `selection` as a [`BooleanArray`] without nulls.
+pub fn selection_to_boolean(selection: &Selection) -> BooleanArray {
+    BooleanArray::from_data(DataType::Boolean, selection.to_mask(), None)
+}
+
+/// Returns the selected rows of `selection` as a [`UInt32Array`] of indices, e.g. to be used
+/// by [`take`](crate::compute::take::take).
+/// # Errors
+/// Errors iff a selected row is not representable as `u32`.
+pub fn selection_to_indices(selection: &Selection) -> Result<UInt32Array> {
+    Ok(PrimitiveArray::from_data(
+        DataType::UInt32,
+        selection.to_indices()?,
+        None,
+    ))
+}
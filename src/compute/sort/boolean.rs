@@ -13,40 +13,34 @@ pub fn sort_boolean<I: Index>(
     options: &SortOptions,
     limit: Option<usize>,
 ) -> PrimitiveArray<I> {
-    let descending = options.descending;
+    let length = values.len();
 
-    // create tuples that are used for sorting
-    let mut valids = value_indices
+    // a stable partition of the valid values is a (linear) stable sort of booleans
+    let (trues, falses): (Vec<I>, Vec<I>) = value_indices
         .into_iter()
-        .map(|index| (index, values.value(index.to_usize())))
-        .collect::<Vec<(I, bool)>>();
+        .partition(|index| values.value(index.to_usize()));
+    let valids = if options.descending {
+        trues.into_iter().chain(falses)
+    } else {
+        falses.into_iter().chain(trues)
+    };
 
     let mut nulls = null_indices;
-
-    if !descending {
-        valids.sort_by(|a, b| a.1.cmp(&b.1));
-    } else {
-        valids.sort_by(|a, b| b.1.cmp(&a.1));
+    if options.descending {
         // reverse to keep a stable ordering
         nulls.reverse();
     }
 
-    let mut values = Vec::<I>::with_capacity(values.len());
-
-    if options.nulls_first {
-        values.extend_from_slice(nulls.as_slice());
-        values.extend(valids.iter().map(|x| x.0));
+    let limit = limit.unwrap_or(length).min(length);
+    let values = if options.nulls_first {
+        nulls
+            .into_iter()
+            .chain(valids)
+            .take(limit)
+            .collect::<Vec<_>>()
     } else {
-        // nulls last
-        values.extend(valids.iter().map(|x| x.0));
-        values.extend_from_slice(nulls.as_slice());
-    }
-
-    // un-efficient; there are much more performant ways of sorting nulls above, anyways.
-    if let Some(limit) = limit {
-        values.truncate(limit);
-        values.shrink_to_fit();
-    }
+        valids.chain(nulls).take(limit).collect::<Vec<_>>()
+    };
 
     let data_type = I::PRIMITIVE.into();
     PrimitiveArray::<I>::from_data(data_type, values.into(), None)
@@ -22,7 +22,7 @@ pub struct SortColumn<'a> {
 }
 
 /// Sort a list of [`Array`] using [`SortOptions`] provided for each array.
-/// # Implementaqtion
+/// # Implementation
 /// The sort is stable and lexicographical on values.
 ///
 /// Returns an [`ArrowError`] if any of the array type is either unsupported by
@@ -164,19 +164,20 @@ pub fn lexsort_to_indices<I: Index>(
             }
         }
 
-        Ordering::Equal
+        // ties are ordered by position, so that the (unstable) sorts below are stable
+        a_idx.cmp(&b_idx)
     };
 
     let mut values = I::range(0, row_count).unwrap().collect::<Vec<_>>();
 
-    if let Some(limit) = limit {
-        let limit = limit.min(row_count);
-        let (before, _, _) = values.select_nth_unstable_by(limit, lex_comparator);
-        before.sort_unstable_by(lex_comparator);
-        values.truncate(limit);
-        values.shrink_to_fit();
-    } else {
-        values.sort_unstable_by(lex_comparator);
+    match limit {
+        Some(limit) if limit < row_count => {
+            let (before, _, _) = values.select_nth_unstable_by(limit, lex_comparator);
+            before.sort_unstable_by(lex_comparator);
+            values.truncate(limit);
+            values.shrink_to_fit();
+        }
+        _ => values.sort_unstable_by(lex_comparator),
     }
 
     let data_type = I::PRIMITIVE.into();
@@ -15,10 +15,12 @@ mod boolean;
 mod common;
 mod lex_sort;
 mod primitive;
+mod sorted;
 mod utf8;
 
 pub(crate) use lex_sort::build_compare;
 pub use lex_sort::{lexsort, lexsort_to_indices, SortColumn};
+pub use sorted::{is_lexsorted, is_sorted, monotonicity, Monotonicity};
 
 macro_rules! dyn_sort {
     ($ty:ty, $array:expr, $cmp:expr, $options:expr, $limit:expr) => {{
@@ -36,6 +38,9 @@ macro_rules! dyn_sort {
 ///
 /// Performs an unstable sort on values and indices. Nulls are ordered according to the `nulls_first` flag in `options`.
 /// Floats are sorted using IEEE 754 totalOrder
+///
+/// When `limit` is set, only the first `limit` values of the sorted array are returned.
+/// They are found by partial selection, in `O(n + limit * log(limit))` instead of `O(n * log(n))`.
 /// # Errors
 /// Errors if the [`DataType`] is not supported.
 pub fn sort(
@@ -94,6 +99,8 @@ macro_rules! dyn_sort_indices {
 }
 
 /// Sort elements from `values` into a non-nullable [`PrimitiveArray`] of indices that sort `values`.
+///
+/// When `limit` is set, only the first `limit` indices are returned (see [`sort`]).
 pub fn sort_to_indices<I: Index>(
     values: &dyn Array,
     options: &SortOptions,
@@ -341,11 +348,27 @@ where
             },
         );
 
-    if !options.descending {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()))
-    } else {
-        valids.sort_by(|a, b| cmp_array(b.1.as_ref(), a.1.as_ref()))
+    // ties are ordered by index so that the partial selection below is stable
+    let compare = |a: &(I, Box<dyn Array>), b: &(I, Box<dyn Array>)| {
+        let ordering = if !options.descending {
+            cmp_array(a.1.as_ref(), b.1.as_ref())
+        } else {
+            cmp_array(b.1.as_ref(), a.1.as_ref())
+        };
+        ordering.then_with(|| a.0.cmp(&b.0))
+    };
+
+    // only the valid values within the limit need to be sorted
+    let num_valids = match limit {
+        Some(limit) if options.nulls_first => limit.saturating_sub(null_indices.len()),
+        Some(limit) => limit,
+        None => valids.len(),
+    };
+    if num_valids < valids.len() {
+        valids.select_nth_unstable_by(num_valids, compare);
+        valids.truncate(num_valids);
     }
+    valids.sort_unstable_by(compare);
 
     let values = valids.iter().map(|tuple| tuple.0);
 
@@ -0,0 +1,120 @@
+use std::cmp::Ordering;
+
+use crate::array::{ord, Array};
+use crate::error::{ArrowError, Result};
+
+use super::lex_sort::build_compare;
+use super::{SortColumn, SortOptions};
+
+/// Returns whether `array` is sorted according to `options`, i.e. whether sorting it with
+/// [`super::sort`] would not change it. Arrays with less than two elements are sorted.
+/// # Errors
+/// This function errors iff the [`crate::datatypes::DataType`] of `array` has no natural order.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::sort::{is_sorted, SortOptions};
+///
+/// let array = Int32Array::from([None, Some(1), Some(1), Some(3)]);
+/// assert_eq!(is_sorted(&array, &SortOptions::default()).unwrap(), true);
+///
+/// let options = SortOptions {
+///     descending: true,
+///     nulls_first: true,
+/// };
+/// assert_eq!(is_sorted(&array, &options).unwrap(), false);
+/// ```
+pub fn is_sorted(array: &dyn Array, options: &SortOptions) -> Result<bool> {
+    let comparator = build_compare(array, *options)?;
+    Ok((1..array.len()).all(|i| comparator(i - 1, i) != Ordering::Greater))
+}
+
+/// Returns whether the rows of `columns` are sorted lexicographically according to the
+/// options of each column, i.e. whether [`super::lexsort`] would not change them.
+/// # Errors
+/// This function errors iff `columns` is empty, the columns have different lengths or
+/// the [`crate::datatypes::DataType`] of a column has no natural order.
+pub fn is_lexsorted(columns: &[SortColumn]) -> Result<bool> {
+    if columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "is_lexsorted requires at least one column".to_string(),
+        ));
+    }
+    let row_count = columns[0].values.len();
+    if columns
+        .iter()
+        .any(|column| column.values.len() != row_count)
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "lexical sort columns have different row counts".to_string(),
+        ));
+    };
+    let comparators = columns
+        .iter()
+        .map(|column| build_compare(column.values, column.options.unwrap_or_default()))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((1..row_count).all(|i| {
+        comparators
+            .iter()
+            .map(|comparator| comparator(i - 1, i))
+            .find(|ordering| *ordering != Ordering::Equal)
+            != Some(Ordering::Greater)
+    }))
+}
+
+/// The monotonicity of the non-null values of an [`Array`], as returned by [`monotonicity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Monotonicity {
+    /// All values are equal (or there are no values)
+    Constant,
+    /// Each value is larger than or equal to the previous one, and they are not all equal
+    Increasing,
+    /// Each value is smaller than or equal to the previous one, and they are not all equal
+    Decreasing,
+    /// The values are neither increasing nor decreasing
+    NotMonotonic,
+}
+
+/// Returns the [`Monotonicity`] of the non-null values of `array`. Null values are ignored.
+///
+/// This allows e.g. a reader to declare that a column of a chunk is sorted, or an operator
+/// to skip sorting chunks that are already sorted.
+/// # Errors
+/// This function errors iff the [`crate::datatypes::DataType`] of `array` has no natural order.
+/// # Example
+/// ```
+/// use arrow2::array::Int32Array;
+/// use arrow2::compute::sort::{monotonicity, Monotonicity};
+///
+/// let array = Int32Array::from([Some(3), None, Some(2), Some(2)]);
+/// assert_eq!(monotonicity(&array).unwrap(), Monotonicity::Decreasing);
+/// ```
+pub fn monotonicity(array: &dyn Array) -> Result<Monotonicity> {
+    let comparator = ord::build_compare(array, array)?;
+
+    let mut valid = (0..array.len()).filter(|i| array.is_valid(*i));
+    let mut previous = match valid.next() {
+        Some(previous) => previous,
+        None => return Ok(Monotonicity::Constant),
+    };
+    let mut increases = false;
+    let mut decreases = false;
+    for i in valid {
+        match comparator(previous, i) {
+            Ordering::Less => increases = true,
+            Ordering::Greater => decreases = true,
+            Ordering::Equal => {}
+        }
+        if increases && decreases {
+            return Ok(Monotonicity::NotMonotonic);
+        }
+        previous = i;
+    }
+    Ok(match (increases, decreases) {
+        (false, false) => Monotonicity::Constant,
+        (true, false) => Monotonicity::Increasing,
+        (false, true) => Monotonicity::Decreasing,
+        (true, true) => unreachable!(),
+    })
+}
@@ -0,0 +1,149 @@
+//! Defines kernels to split the values of a \[Large\]Utf8 array by a delimiter into a
+//! \[Large\]List of \[Large\]Utf8, such as [`split`] and [`split_n`].
+use std::sync::Arc;
+
+use crate::array::{Array, ListArray, MutableUtf8Array, Offset, Utf8Array};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+fn split_with<'a, O, I, F>(array: &'a Utf8Array<O>, delimiter: &str, op: F) -> Result<ListArray<O>>
+where
+    O: Offset,
+    I: Iterator<Item = &'a str>,
+    F: Fn(&'a str) -> I,
+{
+    if delimiter.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "The delimiter of split can't be empty".to_string(),
+        ));
+    }
+
+    let mut values = MutableUtf8Array::<O>::with_capacities(array.len(), array.values().len());
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::zero());
+    let mut length = 0usize;
+    for (i, value) in array.values_iter().enumerate() {
+        // the values of null slots are not split
+        if array.is_valid(i) {
+            for part in op(value) {
+                values.push(Some(part));
+                length += 1;
+            }
+        }
+        offsets.push(O::from_usize(length).ok_or(ArrowError::Overflow)?);
+    }
+
+    let values: Utf8Array<O> = values.into();
+    let data_type = ListArray::<O>::default_datatype(values.data_type().clone());
+    Ok(ListArray::<O>::from_data(
+        data_type,
+        offsets.into(),
+        Arc::new(values),
+        array.validity().cloned(),
+    ))
+}
+
+fn check_n(n: usize) -> Result<()> {
+    if n == 0 {
+        Err(ArrowError::InvalidArgumentError(
+            "The maximum number of parts of split_n must be larger than 0".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Splits each value of `array` by `delimiter`, returning a list of the parts of each value.
+///
+/// The result is null whenever the value is null. Consecutive delimiters yield empty parts.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::split::split;
+///
+/// let array = Utf8Array::<i32>::from([Some("a,b,,c"), Some(""), None]);
+/// let result = split(&array, ",").unwrap();
+/// assert_eq!(result.offsets().as_slice(), &[0, 4, 5, 5]);
+/// assert_eq!(
+///     result.values().as_ref(),
+///     &Utf8Array::<i32>::from_slice(["a", "b", "", "c", ""]) as &dyn Array
+/// );
+/// assert!(result.is_null(2));
+/// ```
+/// # Errors
+/// This function errors iff `delimiter` is empty or the number of parts overflows `O`.
+pub fn split<O: Offset>(array: &Utf8Array<O>, delimiter: &str) -> Result<ListArray<O>> {
+    split_with(array, delimiter, |value| value.split(delimiter))
+}
+
+/// Splits each value of `array` by `delimiter` into at most `n` parts, returning a list of the
+/// parts of each value. The last part contains the remainder of the value.
+///
+/// The result is null whenever the value is null.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::split::split_n;
+///
+/// let array = Utf8Array::<i32>::from_slice(["a,b,c"]);
+/// let result = split_n(&array, ",", 2).unwrap();
+/// assert_eq!(
+///     result.values().as_ref(),
+///     &Utf8Array::<i32>::from_slice(["a", "b,c"]) as &dyn Array
+/// );
+/// ```
+/// # Errors
+/// This function errors iff `delimiter` is empty, `n` is zero or the number of parts overflows `O`.
+pub fn split_n<O: Offset>(array: &Utf8Array<O>, delimiter: &str, n: usize) -> Result<ListArray<O>> {
+    check_n(n)?;
+    split_with(array, delimiter, |value| value.splitn(n, delimiter))
+}
+
+/// Splits each value of `array` by `delimiter` into at most `n` parts starting from the end of
+/// the value, returning a list of the parts of each value *in reverse order*
+/// (like [`str::rsplitn`]). The last part contains the beginning of the value.
+///
+/// The result is null whenever the value is null.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, Utf8Array};
+/// use arrow2::compute::split::rsplit_n;
+///
+/// let array = Utf8Array::<i32>::from_slice(["a,b,c"]);
+/// let result = rsplit_n(&array, ",", 2).unwrap();
+/// assert_eq!(
+///     result.values().as_ref(),
+///     &Utf8Array::<i32>::from_slice(["c", "a,b"]) as &dyn Array
+/// );
+/// ```
+/// # Errors
+/// This function errors iff `delimiter` is empty, `n` is zero or the number of parts overflows `O`.
+pub fn rsplit_n<O: Offset>(
+    array: &Utf8Array<O>,
+    delimiter: &str,
+    n: usize,
+) -> Result<ListArray<O>> {
+    check_n(n)?;
+    split_with(array, delimiter, |value| value.rsplitn(n, delimiter))
+}
+
+/// Splits each value of `array` by `delimiter`, see [`split`].
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]Utf8 array, `delimiter` is empty or the
+/// number of parts overflows.
+pub fn split_dyn(array: &dyn Array, delimiter: &str) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            split(array, delimiter).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            split(array, delimiter).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "split does not support type {:?}",
+            other
+        ))),
+    }
+}
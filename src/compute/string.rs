@@ -0,0 +1,204 @@
+//! Defines element-wise kernels over [`Utf8Array`]s: [`lower`], [`upper`], [`length`],
+//! [`substring`] and [`trim`] (and its variants).
+//!
+//! The kernels write the offsets and values of their result directly to two buffers allocated
+//! upfront, instead of materializing a [`String`] per value. Arrays whose values are all ASCII
+//! (the common case) take fast paths that operate on the values buffer as a whole.
+//! The validity of the result is the validity of the input.
+//!
+//! # Example
+//! ```
+//! use arrow2::array::Utf8Array;
+//! use arrow2::compute::string::{trim, upper};
+//!
+//! let array = Utf8Array::<i32>::from([Some(" a "), None, Some("bc ")]);
+//! let result = upper(&trim(&array));
+//! assert_eq!(result, Utf8Array::<i32>::from([Some("A"), None, Some("BC")]));
+//! ```
+use crate::array::{Array, Offset, PrimitiveArray, Utf8Array};
+use crate::buffer::Buffer;
+use crate::datatypes::DataType;
+
+/// Returns the (contiguous) bytes of the values of `array`, and its offsets starting at zero.
+fn values_and_offsets<O: Offset>(array: &Utf8Array<O>) -> (&[u8], Buffer<O>) {
+    let offsets = array.offsets();
+    let start = offsets[0];
+    let end = offsets[offsets.len() - 1];
+    let offsets = if start == O::zero() {
+        offsets.clone()
+    } else {
+        offsets.iter().map(|offset| *offset - start).collect()
+    };
+    (&array.values()[start.to_usize()..end.to_usize()], offsets)
+}
+
+/// Returns a [`Utf8Array`] whose values are written by `op` from the values of `array`, with
+/// the data type and validity of `array`.
+/// # Panics
+/// Panics iff the values overflow the offsets of `O`.
+fn apply<O: Offset, F: FnMut(&str, &mut String)>(
+    array: &Utf8Array<O>,
+    capacity: usize,
+    mut op: F,
+) -> Utf8Array<O> {
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    let mut values = String::with_capacity(capacity);
+    offsets.push(O::zero());
+    for value in array.values_iter() {
+        op(value, &mut values);
+        offsets.push(O::from_usize(values.len()).expect("The values overflow the offsets"));
+    }
+    // Safety: `values` is utf8 and each offset is at the end of a value, a char boundary
+    unsafe {
+        Utf8Array::<O>::from_data_unchecked(
+            array.data_type().clone(),
+            offsets.into(),
+            values.into_bytes().into(),
+            array.validity().cloned(),
+        )
+    }
+}
+
+/// Returns `array` with the bytes of its values mapped by `op`, when they are all ASCII.
+fn apply_ascii<O: Offset, F: Fn(&[u8]) -> Vec<u8>>(
+    array: &Utf8Array<O>,
+    op: F,
+) -> Option<Utf8Array<O>> {
+    let (values, offsets) = values_and_offsets(array);
+    values.is_ascii().then(|| {
+        // Safety: ASCII is utf8, and the offsets are at the same positions as in `array`
+        unsafe {
+            Utf8Array::<O>::from_data_unchecked(
+                array.data_type().clone(),
+                offsets,
+                op(values).into(),
+                array.validity().cloned(),
+            )
+        }
+    })
+}
+
+/// Returns a new [`Utf8Array`] with each value lower-cased, as [`str::to_lowercase`].
+/// # Panics
+/// Panics iff the lower-cased values are larger than the offsets of `O` support.
+pub fn lower<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    if let Some(result) = apply_ascii(array, <[u8]>::to_ascii_lowercase) {
+        return result;
+    }
+    apply(array, array.values().len(), |value, values| {
+        if value.is_ascii() {
+            values.extend(value.chars().map(|x| x.to_ascii_lowercase()))
+        } else {
+            // lower-casing depends on the context of a char (e.g. a final 'Σ')
+            values.push_str(&value.to_lowercase())
+        }
+    })
+}
+
+/// Returns a new [`Utf8Array`] with each value upper-cased, as [`str::to_uppercase`].
+/// # Panics
+/// Panics iff the upper-cased values are larger than the offsets of `O` support.
+pub fn upper<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    if let Some(result) = apply_ascii(array, <[u8]>::to_ascii_uppercase) {
+        return result;
+    }
+    apply(array, array.values().len(), |value, values| {
+        values.extend(value.chars().flat_map(char::to_uppercase))
+    })
+}
+
+/// Returns the number of characters (unicode scalar values) of each value of `array`, as
+/// `Int32` (`Int64` for `LargeUtf8`). Use [`compute::length`](crate::compute::length) for the
+/// number of bytes.
+pub fn length<O: Offset>(array: &Utf8Array<O>) -> PrimitiveArray<O> {
+    let data_type = if O::is_large() {
+        DataType::Int64
+    } else {
+        DataType::Int32
+    };
+    let (values, offsets) = values_and_offsets(array);
+    let lengths: Buffer<O> = if values.is_ascii() {
+        offsets.windows(2).map(|x| x[1] - x[0]).collect()
+    } else {
+        array
+            .values_iter()
+            .map(|value| O::from_usize(value.chars().count()).unwrap())
+            .collect()
+    };
+    PrimitiveArray::<O>::from_data(data_type, lengths, array.validity().cloned())
+}
+
+/// Returns the byte index of the `index`th character of `value` (or its length).
+#[inline]
+fn byte_index(value: &str, index: usize) -> usize {
+    if value.is_ascii() {
+        index.min(value.len())
+    } else {
+        value
+            .char_indices()
+            .nth(index)
+            .map_or(value.len(), |(index, _)| index)
+    }
+}
+
+/// Returns the substring of `value` of at most `length` characters starting at the character
+/// `start`, where negative starts count from the end.
+#[inline]
+fn substring_value(value: &str, start: i64, length: Option<u64>) -> &str {
+    let chars = if value.is_ascii() {
+        value.len()
+    } else {
+        value.chars().count()
+    };
+    let start = if start >= 0 {
+        (start as u64).min(chars as u64) as usize
+    } else {
+        chars.saturating_sub(start.unsigned_abs().min(chars as u64) as usize)
+    };
+    let end = length.map_or(chars, |length| {
+        (start as u64).saturating_add(length).min(chars as u64) as usize
+    });
+    &value[byte_index(value, start)..byte_index(value, end)]
+}
+
+/// Returns a new [`Utf8Array`] with the substring of each value starting at the character
+/// `start` with at most `length` characters (or until its end when `None`). A negative `start`
+/// counts from the end of each value, e.g. `-2` are the last two characters.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::string::substring;
+///
+/// let array = Utf8Array::<i32>::from([Some("hello"), None, Some("ação")]);
+/// let result = substring(&array, -3, Some(2));
+/// assert_eq!(result, Utf8Array::<i32>::from([Some("ll"), None, Some("çã")]));
+/// ```
+pub fn substring<O: Offset>(array: &Utf8Array<O>, start: i64, length: Option<u64>) -> Utf8Array<O> {
+    apply(array, values_and_offsets(array).0.len(), |value, values| {
+        values.push_str(substring_value(value, start, length))
+    })
+}
+
+/// Returns a new [`Utf8Array`] with the leading and trailing whitespace of each value removed,
+/// as [`str::trim`].
+pub fn trim<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    apply(array, values_and_offsets(array).0.len(), |value, values| {
+        values.push_str(value.trim())
+    })
+}
+
+/// Returns a new [`Utf8Array`] with the leading whitespace of each value removed,
+/// as [`str::trim_start`].
+pub fn trim_start<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    apply(array, values_and_offsets(array).0.len(), |value, values| {
+        values.push_str(value.trim_start())
+    })
+}
+
+/// Returns a new [`Utf8Array`] with the trailing whitespace of each value removed,
+/// as [`str::trim_end`].
+pub fn trim_end<O: Offset>(array: &Utf8Array<O>) -> Utf8Array<O> {
+    apply(array, values_and_offsets(array).0.len(), |value, values| {
+        values.push_str(value.trim_end())
+    })
+}
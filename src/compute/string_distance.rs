@@ -0,0 +1,181 @@
+//! Defines kernels to compute the similarity between strings, such as [`levenshtein`]
+//! and [`jaro_winkler`].
+//!
+//! Distances are computed over Unicode scalar values (`char`s), not bytes.
+use crate::array::{Offset, PrimitiveArray, Utf8Array};
+use crate::datatypes::DataType;
+use crate::error::Result;
+use crate::types::NativeType;
+
+use super::utils::{check_same_len, combine_validities};
+
+/// Returns the Levenshtein distance between `lhs` and `rhs`: the minimum number of
+/// single-char insertions, deletions and substitutions required to change one into the other.
+fn levenshtein_distance(lhs: &str, rhs: &str) -> u32 {
+    let rhs = rhs.chars().collect::<Vec<_>>();
+    // the previous row of the distance matrix
+    let mut row = (0..=rhs.len() as u32).collect::<Vec<_>>();
+    for (i, l) in lhs.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i as u32 + 1;
+        for (j, r) in rhs.iter().enumerate() {
+            let substitution = diagonal + (l != *r) as u32;
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[rhs.len()]
+}
+
+/// Returns the Jaro similarity between `lhs` and `rhs`, between 0 (no similarity) and 1 (equal).
+fn jaro_similarity(lhs: &[char], rhs: &[char]) -> f64 {
+    if lhs.is_empty() && rhs.is_empty() {
+        return 1.0;
+    }
+    if lhs.is_empty() || rhs.is_empty() {
+        return 0.0;
+    }
+    let window = (lhs.len().max(rhs.len()) / 2).saturating_sub(1);
+
+    let mut lhs_matches = vec![false; lhs.len()];
+    let mut rhs_matches = vec![false; rhs.len()];
+    let mut matches = 0usize;
+    for (i, l) in lhs.iter().enumerate() {
+        let start = i.saturating_sub(window);
+        let end = (i + window + 1).min(rhs.len());
+        for j in start..end {
+            if !rhs_matches[j] && rhs[j] == *l {
+                lhs_matches[i] = true;
+                rhs_matches[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // number of matched chars that are not in the same order
+    let lhs_matched = lhs
+        .iter()
+        .zip(lhs_matches.iter())
+        .filter(|(_, is_match)| **is_match)
+        .map(|(c, _)| c);
+    let rhs_matched = rhs
+        .iter()
+        .zip(rhs_matches.iter())
+        .filter(|(_, is_match)| **is_match)
+        .map(|(c, _)| c);
+    let transpositions = lhs_matched.zip(rhs_matched).filter(|(l, r)| l != r).count() / 2;
+
+    let matches = matches as f64;
+    (matches / lhs.len() as f64
+        + matches / rhs.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0
+}
+
+/// Returns the Jaro-Winkler similarity between `lhs` and `rhs`, between 0 (no similarity)
+/// and 1 (equal), which favors strings with a common prefix of up to 4 chars.
+fn jaro_winkler_similarity(lhs: &str, rhs: &str) -> f64 {
+    let lhs = lhs.chars().collect::<Vec<_>>();
+    let rhs = rhs.chars().collect::<Vec<_>>();
+    let jaro = jaro_similarity(&lhs, &rhs);
+
+    let prefix = lhs
+        .iter()
+        .zip(rhs.iter())
+        .take(4)
+        .take_while(|(l, r)| l == r)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn binary<O: Offset, T: NativeType, F: Fn(&str, &str) -> T>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+    data_type: DataType,
+    op: F,
+) -> Result<PrimitiveArray<T>> {
+    check_same_len(lhs, rhs)?;
+    let validity = combine_validities(lhs.validity(), rhs.validity());
+    let values = lhs
+        .values_iter()
+        .zip(rhs.values_iter())
+        .map(|(lhs, rhs)| op(lhs, rhs))
+        .collect::<Vec<_>>();
+    Ok(PrimitiveArray::<T>::from_data(
+        data_type,
+        values.into(),
+        validity,
+    ))
+}
+
+fn unary<O: Offset, T: NativeType, F: Fn(&str) -> T>(
+    array: &Utf8Array<O>,
+    data_type: DataType,
+    op: F,
+) -> PrimitiveArray<T> {
+    let values = array.values_iter().map(op).collect::<Vec<_>>();
+    PrimitiveArray::<T>::from_data(data_type, values.into(), array.validity().cloned())
+}
+
+/// Returns the Levenshtein distance between each pair of values of `lhs` and `rhs`.
+/// The result is null whenever any of the values is null.
+/// # Example
+/// ```
+/// use arrow2::array::{Utf8Array, UInt32Array};
+/// use arrow2::compute::string_distance::levenshtein;
+///
+/// let lhs = Utf8Array::<i32>::from([Some("kitten"), Some("arrow"), None]);
+/// let rhs = Utf8Array::<i32>::from([Some("sitting"), Some("arrow"), Some("a")]);
+/// let result = levenshtein(&lhs, &rhs).unwrap();
+/// assert_eq!(result, UInt32Array::from([Some(3), Some(0), None]));
+/// ```
+/// # Errors
+/// This function errors iff the arrays have a different length.
+pub fn levenshtein<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+) -> Result<PrimitiveArray<u32>> {
+    binary(lhs, rhs, DataType::UInt32, levenshtein_distance)
+}
+
+/// Returns the Levenshtein distance between each value of `array` and `rhs`.
+/// The result is null whenever the value is null.
+pub fn levenshtein_scalar<O: Offset>(array: &Utf8Array<O>, rhs: &str) -> PrimitiveArray<u32> {
+    unary(array, DataType::UInt32, |lhs| {
+        levenshtein_distance(lhs, rhs)
+    })
+}
+
+/// Returns the Jaro-Winkler similarity (between 0 and 1) between each pair of values
+/// of `lhs` and `rhs`. The result is null whenever any of the values is null.
+/// # Example
+/// ```
+/// use arrow2::array::Utf8Array;
+/// use arrow2::compute::string_distance::jaro_winkler;
+///
+/// let lhs = Utf8Array::<i32>::from_slice(["martha", "arrow"]);
+/// let rhs = Utf8Array::<i32>::from_slice(["marhta", "arrow"]);
+/// let result = jaro_winkler(&lhs, &rhs).unwrap();
+/// assert!((result.value(0) - 0.9611).abs() < 1e-4);
+/// assert_eq!(result.value(1), 1.0);
+/// ```
+/// # Errors
+/// This function errors iff the arrays have a different length.
+pub fn jaro_winkler<O: Offset>(
+    lhs: &Utf8Array<O>,
+    rhs: &Utf8Array<O>,
+) -> Result<PrimitiveArray<f64>> {
+    binary(lhs, rhs, DataType::Float64, jaro_winkler_similarity)
+}
+
+/// Returns the Jaro-Winkler similarity (between 0 and 1) between each value of `array` and `rhs`.
+/// The result is null whenever the value is null.
+pub fn jaro_winkler_scalar<O: Offset>(array: &Utf8Array<O>, rhs: &str) -> PrimitiveArray<f64> {
+    unary(array, DataType::Float64, |lhs| {
+        jaro_winkler_similarity(lhs, rhs)
+    })
+}
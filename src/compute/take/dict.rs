@@ -15,7 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::array::{DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray};
+use crate::error::Result;
 
 use super::primitive::take as take_primitive;
 use super::Index;
@@ -23,12 +24,87 @@ use super::Index;
 /// `take` implementation for dictionary arrays
 ///
 /// applies `take` to the keys of the dictionary array and returns a new dictionary array
-/// with the same dictionary values and reordered keys
+/// with the same (shared) dictionary values and reordered keys
 pub fn take<K, I>(values: &DictionaryArray<K>, indices: &PrimitiveArray<I>) -> DictionaryArray<K>
 where
     K: DictionaryKey,
     I: Index,
 {
     let keys = take_primitive::<K, I>(values.keys(), indices);
-    DictionaryArray::<K>::from_data(keys, values.values().clone())
+    DictionaryArray::<K>::from_data_type(values.data_type().clone(), keys, values.values().clone())
+}
+
+/// Returns a [`DictionaryArray`] equal to `array` whose values only contain the values referenced
+/// by its (non-null) keys, in their original order.
+///
+/// [`take`](super::take) and [`filter`](crate::compute::filter::filter) keep the values of a
+/// [`DictionaryArray`] shared with the original array; use this kernel to release the values that
+/// are no longer referenced. It returns a clone of `array` when all values are referenced.
+/// # Example
+/// ```
+/// use arrow2::array::*;
+/// use arrow2::compute::take::{compact_dictionary, take};
+/// # use arrow2::error::Result;
+/// # fn main() -> Result<()> {
+/// let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+/// array.try_extend([Some("a"), Some("b"), None, Some("c")])?;
+/// let array: DictionaryArray<i32> = array.into();
+///
+/// let taken = take(&array, &UInt32Array::from_slice([3, 2]))?;
+/// let taken = taken.as_any().downcast_ref::<DictionaryArray<i32>>().unwrap();
+/// assert_eq!(taken.values().len(), 3);
+///
+/// let compacted = compact_dictionary(taken)?;
+/// let expected = Utf8Array::<i32>::from_slice(["c"]);
+/// assert_eq!(compacted.values().as_ref(), &expected as &dyn Array);
+/// assert_eq!(compacted.keys(), &Int32Array::from([Some(0), None]));
+/// # Ok(())
+/// # }
+/// ```
+/// # Errors
+/// This function errors iff the values of `array` are not supported by [`take`](super::take).
+/// # Panics
+/// This function panics iff a (non-null) key is out of bounds of the values.
+pub fn compact_dictionary<K: DictionaryKey>(
+    array: &DictionaryArray<K>,
+) -> Result<DictionaryArray<K>> {
+    let values = array.values();
+    let keys = array.keys();
+
+    let mut is_used = vec![false; values.len()];
+    keys.iter()
+        .flatten()
+        .for_each(|key| is_used[key.to_usize().unwrap()] = true);
+
+    // the position of each used value in the compacted values
+    let mut new_keys = vec![K::default(); values.len()];
+    let mut indices = Vec::<u64>::new();
+    for (index, is_used) in is_used.into_iter().enumerate() {
+        if is_used {
+            new_keys[index] = K::from_usize(indices.len()).unwrap();
+            indices.push(index as u64);
+        }
+    }
+    if indices.len() == values.len() {
+        return Ok(array.clone());
+    }
+    let values = super::take(values.as_ref(), &PrimitiveArray::from_vec(indices))?;
+
+    let new_keys = keys
+        .iter()
+        .map(|key| {
+            key.map(|key| new_keys[key.to_usize().unwrap()])
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+    let keys = PrimitiveArray::<K>::from_data(
+        keys.data_type().clone(),
+        new_keys.into(),
+        keys.validity().cloned(),
+    );
+    Ok(DictionaryArray::from_data_type(
+        array.data_type().clone(),
+        keys,
+        values.into(),
+    ))
 }
@@ -29,11 +29,13 @@ pub fn take<I: Offset, O: Index>(
 ) -> ListArray<I> {
     let mut capacity = 0;
     let arrays = indices
-        .values()
         .iter()
         .map(|index| {
-            let index = index.to_usize();
-            let slice = values.slice(index, 1);
+            // null indices are not dereferenced, since they may be out of bounds
+            let slice = match index {
+                Some(index) => values.slice(index.to_usize(), 1),
+                None => values.slice(0, 0),
+            };
             capacity += slice.len();
             slice
         })
@@ -18,21 +18,28 @@
 //! Defines take kernel for [`Array`]
 
 use crate::{
-    array::{new_empty_array, Array, NullArray, PrimitiveArray},
+    array::{
+        new_empty_array, Array, BinaryArray, BooleanArray, MutableArray, MutableBinaryArray,
+        MutableBooleanArray, MutablePrimitiveArray, MutableUtf8Array, NullArray, PrimitiveArray,
+        Utf8Array,
+    },
     datatypes::DataType,
-    error::Result,
+    error::{ArrowError, Result},
     types::Index,
 };
 
 mod binary;
 mod boolean;
 mod dict;
+mod fixed_size_list;
 mod generic_binary;
 mod list;
 mod primitive;
 mod structure;
 mod utf8;
 
+pub use dict::compact_dictionary;
+
 /// Returns a new [`Array`] with only indices at `indices`. Null indices are taken as nulls.
 /// The returned array has a length equal to `indices.len()`.
 pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result<Box<dyn Array>> {
@@ -88,10 +95,84 @@ pub fn take<O: Index>(values: &dyn Array, indices: &PrimitiveArray<O>) -> Result
             let array = values.as_any().downcast_ref().unwrap();
             Ok(Box::new(list::take::<i64, O>(array, indices)))
         }
+        FixedSizeList => {
+            let array = values.as_any().downcast_ref().unwrap();
+            Ok(Box::new(fixed_size_list::take::<O>(array, indices)))
+        }
         t => unimplemented!("Take not supported for data type {:?}", t),
     }
 }
 
+/// Appends the slots of `values` at `indices` to `out`, without allocating intermediary arrays.
+///
+/// This is equivalent to extending `out` with the result of [`take`], and is useful to assemble
+/// an array from (the slots of) many arrays. Null indices are appended as nulls.
+/// # Example
+/// ```
+/// use arrow2::array::{Int32Array, MutablePrimitiveArray, UInt32Array};
+/// use arrow2::compute::take::take_into;
+///
+/// let mut out = MutablePrimitiveArray::<i32>::new();
+/// take_into(&Int32Array::from_slice([1, 2, 3]), &UInt32Array::from_slice([2, 0]), &mut out).unwrap();
+/// take_into(&Int32Array::from_slice([4, 5]), &UInt32Array::from([Some(1), None]), &mut out).unwrap();
+/// let out: Int32Array = out.into();
+/// assert_eq!(out, Int32Array::from([Some(3), Some(1), Some(5), None]));
+/// ```
+/// # Errors
+/// This function errors iff `out` has a different [`DataType`] than `values`, or `out` is not
+/// the [`MutableArray`] of `values`, whose physical type must be boolean, primitive, binary or utf8.
+/// # Panics
+/// This function panics iff an index is out of bounds.
+pub fn take_into<O: Index>(
+    values: &dyn Array,
+    indices: &PrimitiveArray<O>,
+    out: &mut dyn MutableArray,
+) -> Result<()> {
+    if values.data_type() != out.data_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Take into requires arrays of the same data type, but got {:?} and {:?}",
+            values.data_type(),
+            out.data_type()
+        )));
+    }
+
+    macro_rules! take_into_typed {
+        ($array:ty, $mutable:ty) => {{
+            let values = values.as_any().downcast_ref::<$array>().unwrap();
+            let out = out.as_mut_any().downcast_mut::<$mutable>().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Take into {:?} requires a {}",
+                    values.data_type(),
+                    stringify!($mutable)
+                ))
+            })?;
+            out.extend_trusted_len(indices.iter().map(|index| {
+                index.and_then(|index| {
+                    let index = index.to_usize();
+                    values.is_valid(index).then(|| values.value(index))
+                })
+            }));
+            Ok(())
+        }};
+    }
+
+    use crate::datatypes::PhysicalType::*;
+    match values.data_type().to_physical_type() {
+        Boolean => take_into_typed!(BooleanArray, MutableBooleanArray),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            take_into_typed!(PrimitiveArray<$T>, MutablePrimitiveArray<$T>)
+        }),
+        Utf8 => take_into_typed!(Utf8Array<i32>, MutableUtf8Array<i32>),
+        LargeUtf8 => take_into_typed!(Utf8Array<i64>, MutableUtf8Array<i64>),
+        Binary => take_into_typed!(BinaryArray<i32>, MutableBinaryArray<i32>),
+        LargeBinary => take_into_typed!(BinaryArray<i64>, MutableBinaryArray<i64>),
+        t => Err(ArrowError::NotYetImplemented(format!(
+            "Take into is not supported for data type {:?}",
+            t
+        ))),
+    }
+}
+
 /// Checks if an array of type `datatype` can perform take operation
 ///
 /// # Examples
@@ -133,6 +214,7 @@ pub fn can_take(data_type: &DataType) -> bool {
             | DataType::Struct(_)
             | DataType::List(_)
             | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
             | DataType::Dictionary(..)
     )
 }
@@ -0,0 +1,96 @@
+//! Defines [`tokenize`], a kernel that splits the values of a \[Large\]Utf8 array into the
+//! matches of a regex, returning a \[Large\]List of dictionary-encoded tokens.
+//!
+//! Tokens are dictionary-encoded across the whole array, so that repeated tokens are
+//! stored once, which is the typical case in text analytics (e.g. counting words).
+use regex::Regex;
+
+use crate::array::{
+    Array, DictionaryKey, ListArray, MutableDictionaryArray, MutableUtf8Array, Offset, TryExtend,
+    Utf8Array,
+};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// Returns a list with the matches of `pattern` on each value of `array`, dictionary-encoded
+/// with keys of type `K`.
+///
+/// The result is null whenever the value is null. Values without matches yield empty lists.
+/// # Example
+/// ```
+/// use arrow2::array::{Array, DictionaryArray, Utf8Array};
+/// use arrow2::compute::tokenize::tokenize;
+///
+/// let array = Utf8Array::<i32>::from([Some("the cat, the hat"), Some("!"), None]);
+/// let result = tokenize::<i32, u32>(&array, r"\w+").unwrap();
+/// assert_eq!(result.offsets().as_slice(), &[0, 4, 4, 4]);
+/// assert!(result.is_null(2));
+///
+/// let tokens = result
+///     .values()
+///     .as_any()
+///     .downcast_ref::<DictionaryArray<u32>>()
+///     .unwrap();
+/// assert_eq!(tokens.keys().values().as_slice(), &[0, 1, 0, 2]);
+/// assert_eq!(
+///     tokens.values().as_ref(),
+///     &Utf8Array::<i32>::from_slice(["the", "cat", "hat"]) as &dyn Array
+/// );
+/// ```
+/// # Errors
+/// This function errors iff `pattern` is not a valid regex, the number of distinct tokens
+/// overflows `K` or the number of tokens overflows `O`.
+pub fn tokenize<O: Offset, K: DictionaryKey>(
+    array: &Utf8Array<O>,
+    pattern: &str,
+) -> Result<ListArray<O>> {
+    let regex = Regex::new(pattern).map_err(|e| {
+        ArrowError::InvalidArgumentError(format!("Unable to build regex from pattern: {}", e))
+    })?;
+
+    let mut tokens = MutableDictionaryArray::<K, MutableUtf8Array<O>>::new();
+    let mut offsets = Vec::<O>::with_capacity(array.len() + 1);
+    offsets.push(O::zero());
+    let mut length = 0usize;
+    for (i, value) in array.values_iter().enumerate() {
+        // the values of null slots are not tokenized
+        if array.is_valid(i) {
+            for token in regex.find_iter(value) {
+                tokens.try_extend(std::iter::once(Some(token.as_str())))?;
+                length += 1;
+            }
+        }
+        offsets.push(O::from_usize(length).ok_or(ArrowError::Overflow)?);
+    }
+
+    let tokens = tokens.into_arc();
+    let data_type = ListArray::<O>::default_datatype(tokens.data_type().clone());
+    Ok(ListArray::<O>::from_data(
+        data_type,
+        offsets.into(),
+        tokens,
+        array.validity().cloned(),
+    ))
+}
+
+/// Returns a list with the matches of `pattern` on each value of `array`, dictionary-encoded
+/// with `i32` keys, see [`tokenize`].
+/// # Errors
+/// This function errors iff `array` is not a \[Large\]Utf8 array, `pattern` is not a valid
+/// regex or the number of tokens overflows.
+pub fn tokenize_dyn(array: &dyn Array, pattern: &str) -> Result<Box<dyn Array>> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            tokenize::<i32, i32>(array, pattern).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            tokenize::<i64, i32>(array, pattern).map(|x| Box::new(x) as Box<dyn Array>)
+        }
+        other => Err(ArrowError::InvalidArgumentError(format!(
+            "tokenize does not support type {:?}",
+            other
+        ))),
+    }
+}
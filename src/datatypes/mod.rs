@@ -8,6 +8,7 @@ mod schema;
 
 pub use field::Field;
 pub use physical_type::*;
+pub(crate) use schema::child_field;
 pub use schema::Schema;
 
 use std::collections::BTreeMap;
@@ -313,3 +314,13 @@ pub fn get_extension(metadata: &Metadata) -> Extension {
         None
     }
 }
+
+/// Removes the extension keys from `metadata`, returning the extension they declare.
+/// Used by readers so that the metadata of a [`Field`] of an extension type round-trips.
+#[cfg(any(feature = "io_ipc", feature = "io_json"))]
+pub(crate) fn take_extension(metadata: &mut Metadata) -> Extension {
+    let extension = get_extension(metadata);
+    metadata.remove("ARROW:extension:name");
+    metadata.remove("ARROW:extension:metadata");
+    extension
+}
@@ -1,4 +1,5 @@
-use super::{Field, Metadata};
+use super::{DataType, Field, Metadata};
+use crate::error::{ArrowError, Result};
 
 /// An ordered sequence of [`Field`]s with associated [`Metadata`].
 ///
@@ -22,6 +23,92 @@ impl Schema {
             metadata,
         }
     }
+
+    /// Returns the [`Field`] at the dotted `path` (e.g. `a.b.c`).
+    ///
+    /// Each component of the path is the name of a field: the first one of a field of this
+    /// [`Schema`] and the remaining ones of a field of the `Struct` reached so far.
+    /// `List`, `LargeList` and `FixedSizeList` are traversed transparently and are preserved in
+    /// the returned field, e.g. `a.b` of `a: List<Struct<b: Int32>>` is a field named `b` of type
+    /// `List<Int32>`. Field names containing a `.` can't be addressed by this function.
+    /// # Errors
+    /// This function errors iff a component of the path does not exist.
+    pub fn field_at_path(&self, path: &str) -> Result<Field> {
+        let mut components = path.split('.');
+        let first = components.next().unwrap();
+        let mut field = self.find_field(first)?.1.clone();
+        for name in components {
+            field = child_field(&field, name)?;
+        }
+        Ok(field)
+    }
+
+    /// Returns the sorted and deduplicated indices of the fields of this [`Schema`] required to
+    /// read the dotted `paths` (see [`Schema::field_at_path`]), so that it can be used as a
+    /// projection in readers.
+    /// # Errors
+    /// This function errors iff any of the paths does not exist.
+    pub fn projection_from_paths<P: AsRef<str>>(&self, paths: &[P]) -> Result<Vec<usize>> {
+        let mut projection = paths
+            .iter()
+            .map(|path| {
+                self.field_at_path(path.as_ref())?;
+                let first = path.as_ref().split('.').next().unwrap();
+                self.find_field(first).map(|x| x.0)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        projection.sort_unstable();
+        projection.dedup();
+        Ok(projection)
+    }
+
+    fn find_field(&self, name: &str) -> Result<(usize, &Field)> {
+        self.fields
+            .iter()
+            .enumerate()
+            .find(|(_, field)| field.name == name)
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The schema has no field named \"{}\"",
+                    name
+                ))
+            })
+    }
+}
+
+/// Returns the field `name` of the `Struct` in `field`, wrapped in the lists wrapping the struct.
+/// The returned field is nullable if either `field` or the child is.
+pub(crate) fn child_field(field: &Field, name: &str) -> Result<Field> {
+    let data_type = match field.data_type() {
+        DataType::Struct(fields) => {
+            let child = fields
+                .iter()
+                .find(|field| field.name == name)
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "The struct has no field named \"{}\"",
+                        name
+                    ))
+                })?;
+            return Ok(Field::new(
+                name,
+                child.data_type().clone(),
+                field.is_nullable || child.is_nullable,
+            ));
+        }
+        DataType::List(inner) => DataType::List(Box::new(child_field(inner, name)?)),
+        DataType::LargeList(inner) => DataType::LargeList(Box::new(child_field(inner, name)?)),
+        DataType::FixedSizeList(inner, size) => {
+            DataType::FixedSizeList(Box::new(child_field(inner, name)?), *size)
+        }
+        other => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot access the field \"{}\" of the non-nested type {:?}",
+                name, other
+            )))
+        }
+    };
+    Ok(Field::new(name, data_type, field.is_nullable))
 }
 
 impl From<Vec<Field>> for Schema {
@@ -95,7 +95,7 @@ fn deserialize_value<'a>(
 ) -> Result<&'a [u8]> {
     let data_type = array.data_type();
     match data_type {
-        DataType::List(inner) => {
+        DataType::List(_) => {
             let avro_inner = match avro_field {
                 AvroSchema::Array(inner) => inner.as_ref(),
                 AvroSchema::Union(u) => match &u.as_slice() {
@@ -107,13 +107,23 @@ fn deserialize_value<'a>(
                 _ => unreachable!(),
             };
 
-            let is_nullable = inner.is_nullable;
+            // the field of the mutable array is always nullable: use the avro schema instead
+            let is_nullable = matches!(avro_inner, AvroSchema::Union(_));
             let array = array
                 .as_mut_any()
                 .downcast_mut::<DynMutableListArray<i32>>()
                 .unwrap();
+            // https://avro.apache.org/docs/current/spec.html#array
+            // a series of blocks of items, terminated by an empty block
             loop {
-                let len = util::zigzag_i64(&mut block)? as usize;
+                let len = util::zigzag_i64(&mut block)?;
+                let len = if len < 0 {
+                    // a negative count is followed by the size of the block in bytes
+                    let _ = util::zigzag_i64(&mut block)?;
+                    -len
+                } else {
+                    len
+                } as usize;
 
                 if len == 0 {
                     break;
@@ -123,8 +133,8 @@ fn deserialize_value<'a>(
                 for _ in 0..len {
                     block = deserialize_item(values, is_nullable, avro_inner, block)?;
                 }
-                array.try_push_valid()?;
             }
+            array.try_push_valid()?;
         }
         DataType::Interval(IntervalUnit::MonthDayNano) => {
             // https://avro.apache.org/docs/current/spec.html#Duration
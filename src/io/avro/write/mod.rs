@@ -16,6 +16,8 @@ pub use block::*;
 mod compress;
 pub(super) mod util;
 pub use compress::compress;
+mod writer;
+pub use writer::Writer;
 
 pub use super::{Block, CompressedBlock};
 
@@ -1,5 +1,5 @@
 use avro_schema::{
-    Field as AvroField, Fixed, FixedLogical, IntLogical, LongLogical, Schema as AvroSchema,
+    Field as AvroField, Fixed, FixedLogical, IntLogical, LongLogical, Record, Schema as AvroSchema,
 };
 
 use crate::datatypes::*;
@@ -11,19 +11,21 @@ pub fn to_avro_schema(schema: &Schema) -> Result<Vec<AvroField>> {
 }
 
 fn field_to_field(field: &Field) -> Result<AvroField> {
-    let schema = type_to_schema(field.data_type(), field.is_nullable)?;
+    let schema = type_to_schema(field.data_type(), field.is_nullable, &field.name)?;
     Ok(AvroField::new(&field.name, schema))
 }
 
-fn type_to_schema(data_type: &DataType, is_nullable: bool) -> Result<AvroSchema> {
-    Ok(if is_nullable {
-        AvroSchema::Union(vec![AvroSchema::Null, _type_to_schema(data_type)?])
+/// `name` is the name of avro records, that must be unique
+fn type_to_schema(data_type: &DataType, is_nullable: bool, name: &str) -> Result<AvroSchema> {
+    // a union of null with null is not valid avro
+    Ok(if is_nullable && data_type != &DataType::Null {
+        AvroSchema::Union(vec![AvroSchema::Null, _type_to_schema(data_type, name)?])
     } else {
-        _type_to_schema(data_type)?
+        _type_to_schema(data_type, name)?
     })
 }
 
-fn _type_to_schema(data_type: &DataType) -> Result<AvroSchema> {
+fn _type_to_schema(data_type: &DataType, name: &str) -> Result<AvroSchema> {
     Ok(match data_type.to_logical_type() {
         DataType::Null => AvroSchema::Null,
         DataType::Boolean => AvroSchema::Boolean,
@@ -31,12 +33,22 @@ fn _type_to_schema(data_type: &DataType) -> Result<AvroSchema> {
         DataType::Int64 => AvroSchema::Long(None),
         DataType::Float32 => AvroSchema::Float,
         DataType::Float64 => AvroSchema::Double,
-        DataType::Binary => AvroSchema::Bytes(None),
-        DataType::Utf8 => AvroSchema::String(None),
-        DataType::List(inner) => AvroSchema::Array(Box::new(type_to_schema(
-            &inner.data_type,
-            inner.is_nullable,
-        )?)),
+        DataType::Binary | DataType::LargeBinary => AvroSchema::Bytes(None),
+        DataType::Utf8 | DataType::LargeUtf8 => AvroSchema::String(None),
+        DataType::List(inner) | DataType::LargeList(inner) => AvroSchema::Array(Box::new(
+            type_to_schema(&inner.data_type, inner.is_nullable, &inner.name)?,
+        )),
+        DataType::Struct(fields) => AvroSchema::Record(Record::new(
+            name,
+            fields
+                .iter()
+                .map(|field| {
+                    let name = format!("{}.{}", name, field.name);
+                    let schema = type_to_schema(field.data_type(), field.is_nullable, &name)?;
+                    Ok(AvroField::new(&field.name, schema))
+                })
+                .collect::<Result<Vec<_>>>()?,
+        )),
         DataType::Date32 => AvroSchema::Int(Some(IntLogical::Date)),
         DataType::Time32(TimeUnit::Millisecond) => AvroSchema::Int(Some(IntLogical::Time)),
         DataType::Time64(TimeUnit::Microsecond) => AvroSchema::Long(Some(LongLogical::Time)),
@@ -1,6 +1,6 @@
-use avro_schema::Schema as AvroSchema;
+use avro_schema::{Field as AvroField, Schema as AvroSchema};
 
-use crate::datatypes::{IntervalUnit, PhysicalType, PrimitiveType};
+use crate::datatypes::{IntervalUnit, PhysicalType, PrimitiveType, TimeUnit};
 use crate::types::months_days_ns;
 use crate::{array::*, datatypes::DataType};
 
@@ -46,50 +46,53 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
             ))
         }
         (PhysicalType::Utf8, AvroSchema::Union(_)) => {
-            let values = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-            Box::new(BufStreamingIterator::new(
-                values.iter(),
-                |x, buf| {
-                    util::zigzag_encode(x.is_some() as i64, buf).unwrap();
-                    if let Some(x) = x {
-                        util::zigzag_encode(x.len() as i64, buf).unwrap();
-                        buf.extend_from_slice(x.as_bytes());
-                    }
-                },
-                vec![],
-            ))
+            utf8_optional::<i32>(array.as_any().downcast_ref().unwrap())
+        }
+        (PhysicalType::LargeUtf8, AvroSchema::Union(_)) => {
+            utf8_optional::<i64>(array.as_any().downcast_ref().unwrap())
         }
         (PhysicalType::Utf8, AvroSchema::String(_)) => {
-            let values = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
-            Box::new(BufStreamingIterator::new(
-                values.values_iter(),
-                |x, buf| {
-                    util::zigzag_encode(x.len() as i64, buf).unwrap();
-                    buf.extend_from_slice(x.as_bytes());
-                },
-                vec![],
-            ))
+            utf8_required::<i32>(array.as_any().downcast_ref().unwrap())
+        }
+        (PhysicalType::LargeUtf8, AvroSchema::String(_)) => {
+            utf8_required::<i64>(array.as_any().downcast_ref().unwrap())
         }
         (PhysicalType::Binary, AvroSchema::Union(_)) => {
-            let values = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            binary_optional::<i32>(array.as_any().downcast_ref().unwrap())
+        }
+        (PhysicalType::LargeBinary, AvroSchema::Union(_)) => {
+            binary_optional::<i64>(array.as_any().downcast_ref().unwrap())
+        }
+        (PhysicalType::Binary, AvroSchema::Bytes(_)) => {
+            binary_required::<i32>(array.as_any().downcast_ref().unwrap())
+        }
+        (PhysicalType::LargeBinary, AvroSchema::Bytes(_)) => {
+            binary_required::<i64>(array.as_any().downcast_ref().unwrap())
+        }
+        (PhysicalType::FixedSizeBinary, AvroSchema::Union(_)) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
             Box::new(BufStreamingIterator::new(
                 values.iter(),
                 |x, buf| {
                     util::zigzag_encode(x.is_some() as i64, buf).unwrap();
                     if let Some(x) = x {
-                        util::zigzag_encode(x.len() as i64, buf).unwrap();
                         buf.extend_from_slice(x);
                     }
                 },
                 vec![],
             ))
         }
-        (PhysicalType::Binary, AvroSchema::Bytes(_)) => {
-            let values = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+        (PhysicalType::FixedSizeBinary, AvroSchema::Fixed(_)) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
             Box::new(BufStreamingIterator::new(
-                values.values_iter(),
+                values.iter_values(),
                 |x, buf| {
-                    util::zigzag_encode(x.len() as i64, buf).unwrap();
                     buf.extend_from_slice(x);
                 },
                 vec![],
@@ -154,6 +157,22 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
                 vec![],
             ))
         }
+        (PhysicalType::Primitive(PrimitiveType::Float32), AvroSchema::Union(_)) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f32>>()
+                .unwrap();
+            Box::new(BufStreamingIterator::new(
+                values.iter(),
+                |x, buf| {
+                    util::zigzag_encode(x.is_some() as i64, buf).unwrap();
+                    if let Some(x) = x {
+                        buf.extend_from_slice(&x.to_le_bytes());
+                    }
+                },
+                vec![],
+            ))
+        }
         (PhysicalType::Primitive(PrimitiveType::Float32), AvroSchema::Float) => {
             let values = array
                 .as_any()
@@ -167,6 +186,22 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
                 vec![],
             ))
         }
+        (PhysicalType::Primitive(PrimitiveType::Float64), AvroSchema::Union(_)) => {
+            let values = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap();
+            Box::new(BufStreamingIterator::new(
+                values.iter(),
+                |x, buf| {
+                    util::zigzag_encode(x.is_some() as i64, buf).unwrap();
+                    if let Some(x) = x {
+                        buf.extend_from_slice(&x.to_le_bytes());
+                    }
+                },
+                vec![],
+            ))
+        }
         (PhysicalType::Primitive(PrimitiveType::Float64), AvroSchema::Double) => {
             let values = array
                 .as_any()
@@ -207,17 +242,195 @@ pub fn new_serializer<'a>(array: &'a dyn Array, schema: &AvroSchema) -> BoxSeria
                 vec![],
             ))
         }
+        (PhysicalType::List, AvroSchema::Array(inner)) => {
+            list_serializer::<i32>(array.as_any().downcast_ref().unwrap(), inner, false)
+        }
+        (PhysicalType::List, AvroSchema::Union(inner)) => {
+            let inner = match union_inner(inner) {
+                AvroSchema::Array(inner) => inner,
+                _ => unreachable!(),
+            };
+            list_serializer::<i32>(array.as_any().downcast_ref().unwrap(), inner, true)
+        }
+        (PhysicalType::LargeList, AvroSchema::Array(inner)) => {
+            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), inner, false)
+        }
+        (PhysicalType::LargeList, AvroSchema::Union(inner)) => {
+            let inner = match union_inner(inner) {
+                AvroSchema::Array(inner) => inner,
+                _ => unreachable!(),
+            };
+            list_serializer::<i64>(array.as_any().downcast_ref().unwrap(), inner, true)
+        }
+        (PhysicalType::Struct, AvroSchema::Record(inner)) => {
+            struct_serializer(array.as_any().downcast_ref().unwrap(), &inner.fields, false)
+        }
+        (PhysicalType::Struct, AvroSchema::Union(inner)) => {
+            let inner = match union_inner(inner) {
+                AvroSchema::Record(inner) => inner,
+                _ => unreachable!(),
+            };
+            struct_serializer(array.as_any().downcast_ref().unwrap(), &inner.fields, true)
+        }
+        (PhysicalType::Null, AvroSchema::Null) => {
+            // nulls are not written
+            Box::new(BufStreamingIterator::new(0..array.len(), |_, _| {}, vec![]))
+        }
         (a, b) => todo!("{:?} -> {:?} not supported", a, b),
     }
 }
 
+/// Returns the non-null schema of the union `schemas` of a nullable field.
+fn union_inner(schemas: &[AvroSchema]) -> &AvroSchema {
+    schemas
+        .iter()
+        .find(|x| !matches!(x, AvroSchema::Null))
+        .unwrap()
+}
+
+fn utf8_required<'a, O: Offset>(array: &'a Utf8Array<O>) -> BoxSerializer<'a> {
+    Box::new(BufStreamingIterator::new(
+        array.values_iter(),
+        |x, buf| {
+            util::zigzag_encode(x.len() as i64, buf).unwrap();
+            buf.extend_from_slice(x.as_bytes());
+        },
+        vec![],
+    ))
+}
+
+fn utf8_optional<'a, O: Offset>(array: &'a Utf8Array<O>) -> BoxSerializer<'a> {
+    Box::new(BufStreamingIterator::new(
+        array.iter(),
+        |x, buf| {
+            util::zigzag_encode(x.is_some() as i64, buf).unwrap();
+            if let Some(x) = x {
+                util::zigzag_encode(x.len() as i64, buf).unwrap();
+                buf.extend_from_slice(x.as_bytes());
+            }
+        },
+        vec![],
+    ))
+}
+
+fn binary_required<'a, O: Offset>(array: &'a BinaryArray<O>) -> BoxSerializer<'a> {
+    Box::new(BufStreamingIterator::new(
+        array.values_iter(),
+        |x, buf| {
+            util::zigzag_encode(x.len() as i64, buf).unwrap();
+            buf.extend_from_slice(x);
+        },
+        vec![],
+    ))
+}
+
+fn binary_optional<'a, O: Offset>(array: &'a BinaryArray<O>) -> BoxSerializer<'a> {
+    Box::new(BufStreamingIterator::new(
+        array.iter(),
+        |x, buf| {
+            util::zigzag_encode(x.is_some() as i64, buf).unwrap();
+            if let Some(x) = x {
+                util::zigzag_encode(x.len() as i64, buf).unwrap();
+                buf.extend_from_slice(x);
+            }
+        },
+        vec![],
+    ))
+}
+
+fn list_serializer<'a, O: Offset>(
+    array: &'a ListArray<O>,
+    schema: &AvroSchema,
+    is_nullable: bool,
+) -> BoxSerializer<'a> {
+    let mut inner = new_serializer(array.values().as_ref(), schema);
+    // skip the values before the first offset, e.g. when the array is sliced
+    (0..array.offsets()[0].to_usize()).for_each(|_| {
+        inner.next();
+    });
+
+    Box::new(BufStreamingIterator::new(
+        array.offsets().windows(2).enumerate(),
+        move |(i, offsets), buf| {
+            let length = (offsets[1] - offsets[0]).to_usize();
+            let is_valid = array.is_valid(i);
+            if is_nullable {
+                util::zigzag_encode(is_valid as i64, buf).unwrap();
+            }
+            if !is_valid {
+                (0..length).for_each(|_| {
+                    inner.next();
+                });
+                return;
+            }
+            // https://avro.apache.org/docs/current/spec.html#array
+            // a block with all items followed by an empty block
+            if length > 0 {
+                util::zigzag_encode(length as i64, buf).unwrap();
+                (0..length).for_each(|_| buf.extend_from_slice(inner.next().unwrap()));
+            }
+            util::zigzag_encode(0, buf).unwrap();
+        },
+        vec![],
+    ))
+}
+
+fn struct_serializer<'a>(
+    array: &'a StructArray,
+    fields: &[AvroField],
+    is_nullable: bool,
+) -> BoxSerializer<'a> {
+    let mut inner = array
+        .values()
+        .iter()
+        .zip(fields.iter())
+        .map(|(array, field)| new_serializer(array.as_ref(), &field.schema))
+        .collect::<Vec<_>>();
+
+    Box::new(BufStreamingIterator::new(
+        0..array.len(),
+        move |i, buf| {
+            let is_valid = array.is_valid(i);
+            if is_nullable {
+                util::zigzag_encode(is_valid as i64, buf).unwrap();
+            }
+            inner.iter_mut().for_each(|serializer| {
+                let item = serializer.next().unwrap();
+                if is_valid {
+                    buf.extend_from_slice(item);
+                }
+            });
+        },
+        vec![],
+    ))
+}
+
 /// Whether [`new_serializer`] supports `data_type`.
 pub fn can_serialize(data_type: &DataType) -> bool {
     use DataType::*;
-    matches!(
-        data_type,
-        Boolean | Int32 | Int64 | Utf8 | Binary | Interval(IntervalUnit::MonthDayNano)
-    )
+    match data_type.to_logical_type() {
+        List(inner) | LargeList(inner) => can_serialize(&inner.data_type),
+        Struct(fields) => fields.iter().all(|field| can_serialize(&field.data_type)),
+        other => matches!(
+            other,
+            Null | Boolean
+                | Int32
+                | Int64
+                | Float32
+                | Float64
+                | Date32
+                | Time32(TimeUnit::Millisecond)
+                | Time64(TimeUnit::Microsecond)
+                | Timestamp(TimeUnit::Millisecond, None)
+                | Timestamp(TimeUnit::Microsecond, None)
+                | Utf8
+                | LargeUtf8
+                | Binary
+                | LargeBinary
+                | FixedSizeBinary(_)
+                | Interval(IntervalUnit::MonthDayNano)
+        ),
+    }
 }
 
 #[inline]
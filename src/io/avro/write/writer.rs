@@ -0,0 +1,114 @@
+use std::io::Write;
+
+use avro_schema::Field as AvroField;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Schema};
+use crate::error::{ArrowError, Result};
+
+use super::super::{Block, CompressedBlock, Compression};
+use super::{compress, new_serializer, serialize, to_avro_schema, write_block, write_metadata};
+
+/// Writes [`Chunk`]s to an Avro object container file, one block per [`Chunk`].
+///
+/// The metadata (including the Avro schema derived from the arrow [`Schema`]) is written
+/// when the [`Writer`] is created, and each block is compressed with the chosen [`Compression`].
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow2::array::{Array, Int32Array};
+/// use arrow2::chunk::Chunk;
+/// use arrow2::datatypes::{DataType, Field, Schema};
+/// use arrow2::io::avro::write::Writer;
+///
+/// let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+/// let array = Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>;
+///
+/// let mut writer = Writer::try_new(vec![], &schema, None).unwrap();
+/// writer.write(&Chunk::new(vec![array])).unwrap();
+/// let file: Vec<u8> = writer.into_inner();
+/// ```
+pub struct Writer<W: Write> {
+    writer: W,
+    fields: Vec<AvroField>,
+    data_types: Vec<DataType>,
+    compression: Option<Compression>,
+    block: Block,
+    compressed_block: CompressedBlock,
+}
+
+impl<W: Write> Writer<W> {
+    /// Creates a new [`Writer`], writing the metadata of the file to `writer`.
+    /// # Errors
+    /// This function errors iff a field of `schema` cannot be written to Avro or iff
+    /// writing to `writer` errors.
+    pub fn try_new(
+        mut writer: W,
+        schema: &Schema,
+        compression: Option<Compression>,
+    ) -> Result<Self> {
+        let fields = to_avro_schema(schema)?;
+        write_metadata(&mut writer, fields.clone(), compression)?;
+        Ok(Self {
+            writer,
+            fields,
+            data_types: schema
+                .fields
+                .iter()
+                .map(|field| field.data_type().clone())
+                .collect(),
+            compression,
+            block: Block::default(),
+            compressed_block: CompressedBlock::default(),
+        })
+    }
+
+    /// Serializes, compresses and writes `columns` as a new block.
+    /// # Errors
+    /// This function errors iff `columns` does not match the schema of the [`Writer`] or iff
+    /// compressing or writing the block errors.
+    pub fn write<A: AsRef<dyn Array>>(&mut self, columns: &Chunk<A>) -> Result<()> {
+        if columns.arrays().len() != self.data_types.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The chunk has {} columns but the schema has {} fields",
+                columns.arrays().len(),
+                self.data_types.len()
+            )));
+        }
+        if let Some((array, data_type)) = columns
+            .arrays()
+            .iter()
+            .zip(self.data_types.iter())
+            .find(|(array, data_type)| array.as_ref().data_type() != *data_type)
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The chunk has a column of type {:?} but the schema expects {:?}",
+                array.as_ref().data_type(),
+                data_type
+            )));
+        }
+
+        let mut serializers = columns
+            .arrays()
+            .iter()
+            .zip(self.fields.iter())
+            .map(|(array, field)| new_serializer(array.as_ref(), &field.schema))
+            .collect::<Vec<_>>();
+        self.block.number_of_rows = columns.len();
+        serialize(&mut serializers, &mut self.block);
+
+        compress(
+            &mut self.block,
+            &mut self.compressed_block,
+            self.compression,
+        )?;
+
+        write_block(&mut self.writer, &self.compressed_block)
+    }
+
+    /// Returns the inner writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
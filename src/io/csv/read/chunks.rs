@@ -0,0 +1,85 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use super::{deserialize_batch, deserialize_column, read_rows, ByteRecord, Reader};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Field;
+use crate::error::Result;
+
+/// An [`Iterator`] of [`Chunk`]s with the (projected) fields of the rows of a [`Reader`],
+/// each with at most `chunk_size` rows. Created by [`read_chunks`].
+pub struct ChunkIterator<R: Read> {
+    reader: Reader<R>,
+    rows: Vec<ByteRecord>,
+    fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    line_number: usize,
+    finished: bool,
+}
+
+/// Returns an [`Iterator`] of [`Chunk`]s with the (`projected`) `fields` of the rows of `reader`,
+/// each with at most `chunk_size` rows.
+///
+/// Only `chunk_size` rows are held in memory at a time (and re-used across chunks), so that
+/// files larger than memory can be processed chunk by chunk. The iterator stops after
+/// the first error.
+/// # Panics
+/// Panics iff `chunk_size` is zero.
+pub fn read_chunks<R: Read>(
+    reader: Reader<R>,
+    fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    chunk_size: usize,
+) -> ChunkIterator<R> {
+    assert!(chunk_size > 0, "chunk_size must be larger than zero");
+    ChunkIterator {
+        reader,
+        rows: vec![ByteRecord::default(); chunk_size],
+        fields,
+        projection,
+        line_number: 0,
+        finished: false,
+    }
+}
+
+impl<R: Read> ChunkIterator<R> {
+    /// Returns the underlying [`Reader`], e.g. to read the remaining rows of a file.
+    pub fn into_inner(self) -> Reader<R> {
+        self.reader
+    }
+}
+
+impl<R: Read> Iterator for ChunkIterator<R> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let rows_read = match read_rows(&mut self.reader, 0, &mut self.rows) {
+            Ok(rows_read) => rows_read,
+            Err(error) => {
+                self.finished = true;
+                return Some(Err(error));
+            }
+        };
+        if rows_read == 0 {
+            self.finished = true;
+            return None;
+        }
+        let chunk = deserialize_batch(
+            &self.rows[..rows_read],
+            &self.fields,
+            self.projection.as_deref(),
+            self.line_number,
+            deserialize_column,
+        );
+        self.line_number += rows_read;
+
+        // fewer rows than requested means that the reader is exhausted
+        self.finished = chunk.is_err() || rows_read < self.rows.len();
+        Some(chunk)
+    }
+}
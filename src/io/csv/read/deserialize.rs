@@ -11,8 +11,9 @@ use crate::{
 
 use super::super::read_utils::{
     deserialize_batch as deserialize_batch_gen, deserialize_column as deserialize_column_gen,
-    ByteRecordGeneric,
+    deserialize_column_with as deserialize_column_with_gen, ByteRecordGeneric,
 };
+use super::super::utils::InferOptions;
 
 impl ByteRecordGeneric for ByteRecord {
     #[inline]
@@ -31,9 +32,28 @@ pub fn deserialize_column(
     deserialize_column_gen(rows, column, datatype, line_number)
 }
 
-/// Deserializes rows [`ByteRecord`] into a [`Chunk`].
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`, parsing
+/// booleans, dates and timestamps in accordance to `options`, i.e. the way they were inferred
+/// by [`InferOptions::infer`].
+pub fn deserialize_column_with(
+    rows: &[ByteRecord],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+    options: &InferOptions,
+) -> Result<Arc<dyn Array>> {
+    deserialize_column_with_gen(rows, column, datatype, line_number, options)
+}
+
+/// Deserializes rows [`ByteRecord`] into a [`Chunk`] with the columns in `projection`
+/// (in its order), or all `fields` when it is `None`.
+/// Only the projected columns are deserialized, which makes projecting a few columns of
+/// wide files significantly faster than deserializing all of them.
 /// Note that this is a convenience function: column deserialization
 /// is trivially parallelizable (e.g. rayon).
+/// # Errors
+/// This function errors iff `projection` contains a column not in `fields` or a
+/// column cannot be deserialized.
 pub fn deserialize_batch<F>(
     rows: &[ByteRecord],
     fields: &[Field],
@@ -6,7 +6,7 @@ use std::{
 use crate::datatypes::{DataType, Field};
 use crate::error::Result;
 
-use super::super::utils::merge_schema;
+use super::super::utils::{merge_schema, InferOptions};
 use super::{ByteRecord, Reader};
 
 /// Infers the [`Field`]s of a CSV file by reading through the first n records up to `max_rows`.
@@ -60,3 +60,20 @@ pub fn infer_schema<R: Read + Seek, F: Fn(&[u8]) -> DataType>(
 
     Ok((fields, records_count))
 }
+
+/// Infers the [`Field`]s of a CSV file by reading through the first n records up to `max_rows`,
+/// inferring the [`DataType`] of each value with [`InferOptions::infer`] and using
+/// [`InferOptions::overrides`] for the columns they name.
+/// Also returns the number of rows used to infer.
+/// Seeks back to the begining of the file _after_ the header
+pub fn infer_schema_with_options<R: Read + Seek>(
+    reader: &mut Reader<R>,
+    max_rows: Option<usize>,
+    has_header: bool,
+    options: &InferOptions,
+) -> Result<(Vec<Field>, usize)> {
+    let (mut fields, records_count) =
+        infer_schema(reader, max_rows, has_header, &|bytes| options.infer(bytes))?;
+    options.apply_overrides(&mut fields);
+    Ok((fields, records_count))
+}
@@ -1,4 +1,5 @@
 //! APIs to read from CSV
+mod chunks;
 mod deserialize;
 mod reader;
 
@@ -6,8 +7,18 @@ mod reader;
 pub use csv::{ByteRecord, Reader, ReaderBuilder};
 
 mod infer_schema;
+// rayon's thread pool is unavailable on wasm32
+#[cfg(all(feature = "io_csv_read_parallel", not(target_arch = "wasm32")))]
+mod parallel;
 
-pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
-pub use infer_schema::infer_schema;
+pub use super::utils::{infer, InferOptions};
+pub use chunks::{read_chunks, ChunkIterator};
+pub use deserialize::{deserialize_batch, deserialize_column, deserialize_column_with};
+pub use infer_schema::{infer_schema, infer_schema_with_options};
+#[cfg(all(feature = "io_csv_read_parallel", not(target_arch = "wasm32")))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "io_csv_read_parallel", not(target_arch = "wasm32"))))
+)]
+pub use parallel::read_parallel;
 pub use reader::*;
@@ -0,0 +1,66 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Field;
+use crate::error::{ArrowError, Result};
+
+use super::{deserialize_batch, deserialize_column, read_rows, ByteRecord, Reader};
+
+/// Reads all rows of `reader` into [`Chunk`]s of at most `chunk_size` rows with the
+/// (`projected`) `fields`, deserializing the chunks in parallel in rayon's thread pool.
+///
+/// Rows are read from `reader` in the current thread, in rounds of as many chunks as there
+/// are threads in the pool, and each round is deserialized in parallel. Reading rows only
+/// splits lines and fields and is thus much faster than deserializing them.
+/// For more control (e.g. to overlap reading and deserializing), use [`read_rows`] and
+/// [`deserialize_batch`] directly.
+/// # Errors
+/// This function errors iff `chunk_size` is zero or reading or deserializing a row fails.
+pub fn read_parallel<R: Read>(
+    reader: &mut Reader<R>,
+    fields: &[Field],
+    projection: Option<&[usize]>,
+    chunk_size: usize,
+) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    if chunk_size == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "chunk_size must be larger than zero".to_string(),
+        ));
+    }
+    let round_size = rayon::current_num_threads().max(1);
+
+    let mut chunks = vec![];
+    let mut line_number = 0;
+    let mut exhausted = false;
+    while !exhausted {
+        // the cheap stage: split the rows of the round
+        let mut round = Vec::with_capacity(round_size);
+        while round.len() < round_size {
+            let mut rows = vec![ByteRecord::default(); chunk_size];
+            let rows_read = read_rows(reader, 0, &mut rows)?;
+            rows.truncate(rows_read);
+            if rows_read > 0 {
+                round.push((rows, line_number));
+                line_number += rows_read;
+            }
+            if rows_read < chunk_size {
+                exhausted = true;
+                break;
+            }
+        }
+
+        // the expensive stage: deserialize them in parallel
+        let round = round
+            .par_iter()
+            .map(|(rows, line_number)| {
+                deserialize_batch(rows, fields, projection, *line_number, deserialize_column)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        chunks.extend(round);
+    }
+    Ok(chunks)
+}
@@ -4,7 +4,7 @@ use super::{AsyncReader, ByteRecord};
 
 use crate::datatypes::{DataType, Field};
 use crate::error::Result;
-use crate::io::csv::utils::merge_schema;
+use crate::io::csv::utils::{merge_schema, InferOptions};
 
 use futures::{AsyncRead, AsyncSeek};
 
@@ -67,3 +67,22 @@ where
 
     Ok((fields, records_count))
 }
+
+/// Infers the [`Field`]s of a CSV file by reading through the first n records up to `max_rows`,
+/// inferring the [`DataType`] of each value with [`InferOptions::infer`] and using
+/// [`InferOptions::overrides`] for the columns they name.
+/// Seeks back to the begining of the file _after_ the header
+pub async fn infer_schema_with_options<R>(
+    reader: &mut AsyncReader<R>,
+    max_rows: Option<usize>,
+    has_header: bool,
+    options: &InferOptions,
+) -> Result<(Vec<Field>, usize)>
+where
+    R: AsyncRead + AsyncSeek + Unpin + Send + Sync,
+{
+    let (mut fields, records_count) =
+        infer_schema(reader, max_rows, has_header, &|bytes| options.infer(bytes)).await?;
+    options.apply_overrides(&mut fields);
+    Ok((fields, records_count))
+}
@@ -6,11 +6,13 @@ pub use csv_async::{AsyncReader, AsyncReaderBuilder, ByteRecord};
 mod deserialize;
 mod infer_schema;
 mod reader;
+mod stream;
 
-pub use super::utils::infer;
-pub use deserialize::{deserialize_batch, deserialize_column};
-pub use infer_schema::infer_schema;
+pub use super::utils::{infer, InferOptions};
+pub use deserialize::{deserialize_batch, deserialize_column, deserialize_column_with};
+pub use infer_schema::{infer_schema, infer_schema_with_options};
 pub use reader::*;
+pub use stream::read_chunks;
 
 pub use csv_async::Error as CSVError;
 
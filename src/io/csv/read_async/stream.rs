@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use futures::{AsyncRead, Stream};
+
+use super::{deserialize_batch, deserialize_column, read_rows, AsyncReader, ByteRecord};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Field;
+use crate::error::Result;
+
+struct State<R> {
+    reader: AsyncReader<R>,
+    rows: Vec<ByteRecord>,
+    fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    line_number: usize,
+}
+
+/// Returns a [`Stream`] of [`Chunk`]s with the (`projected`) `fields` of the rows of `reader`,
+/// each with at most `chunk_size` rows.
+///
+/// Rows are read asynchronously, so that e.g. a server can parse an upload as it arrives
+/// without blocking on it. The deserialization of each chunk is CPU-bound and happens in
+/// the task polling the stream.
+/// # Panics
+/// Panics iff `chunk_size` is zero.
+pub fn read_chunks<R>(
+    reader: AsyncReader<R>,
+    fields: Vec<Field>,
+    projection: Option<Vec<usize>>,
+    chunk_size: usize,
+) -> impl Stream<Item = Result<Chunk<Arc<dyn Array>>>>
+where
+    R: AsyncRead + Unpin + Send + Sync,
+{
+    assert!(chunk_size > 0, "chunk_size must be larger than zero");
+    let state = State {
+        reader,
+        rows: vec![ByteRecord::default(); chunk_size],
+        fields,
+        projection,
+        line_number: 0,
+    };
+
+    futures::stream::unfold(Some(state), |state| async move {
+        let mut state = state?;
+        let rows_read = match read_rows(&mut state.reader, 0, &mut state.rows).await {
+            Ok(rows_read) => rows_read,
+            Err(error) => return Some((Err(error), None)),
+        };
+        if rows_read == 0 {
+            return None;
+        }
+        let chunk = deserialize_batch(
+            &state.rows[..rows_read],
+            &state.fields,
+            state.projection.as_deref(),
+            state.line_number,
+            deserialize_column,
+        );
+        state.line_number += rows_read;
+
+        // fewer rows than requested means that the reader is exhausted
+        let state = if chunk.is_ok() && rows_read == state.rows.len() {
+            Some(state)
+        } else {
+            None
+        };
+        Some((chunk, state))
+    })
+}
@@ -16,9 +16,10 @@ use crate::{
     error::{ArrowError, Result},
     temporal_conversions,
     types::NativeType,
+    util::decimal::parse_decimal,
 };
 
-use super::utils::RFC3339;
+use super::utils::{InferOptions, RFC3339};
 
 #[inline]
 fn to_utf8(bytes: &[u8]) -> Option<&str> {
@@ -48,50 +49,6 @@ where
     Arc::new(PrimitiveArray::<T>::from_trusted_len_iter(iter).to(datatype))
 }
 
-#[inline]
-fn significant_bytes(bytes: &[u8]) -> usize {
-    bytes.iter().map(|byte| (*byte != b'0') as usize).sum()
-}
-
-/// Deserializes bytes to a single i128 representing a decimal
-/// The decimal precision and scale are not checked.
-#[inline]
-fn deserialize_decimal(bytes: &[u8], precision: usize, scale: usize) -> Option<i128> {
-    let mut a = bytes.split(|x| *x == b'.');
-    let lhs = a.next();
-    let rhs = a.next();
-    match (lhs, rhs) {
-        (Some(lhs), Some(rhs)) => lexical_core::parse::<i128>(lhs).ok().and_then(|x| {
-            lexical_core::parse::<i128>(rhs)
-                .ok()
-                .map(|y| (x, lhs, y, rhs))
-                .and_then(|(lhs, lhs_b, rhs, rhs_b)| {
-                    let lhs_s = significant_bytes(lhs_b);
-                    let rhs_s = significant_bytes(rhs_b);
-                    if lhs_s + rhs_s > precision || rhs_s > scale {
-                        None
-                    } else {
-                        Some((lhs, rhs, rhs_s))
-                    }
-                })
-                .map(|(lhs, rhs, rhs_s)| lhs * 10i128.pow(rhs_s as u32) + rhs)
-        }),
-        (None, Some(rhs)) => {
-            if rhs.len() != precision || rhs.len() != scale {
-                return None;
-            }
-            lexical_core::parse::<i128>(rhs).ok()
-        }
-        (Some(lhs), None) => {
-            if lhs.len() != precision || scale != 0 {
-                return None;
-            }
-            lexical_core::parse::<i128>(lhs).ok()
-        }
-        (None, None) => None,
-    }
-}
-
 #[inline]
 fn deserialize_boolean<B, F>(rows: &[B], column: usize, op: F) -> Arc<dyn Array>
 where
@@ -128,22 +85,6 @@ fn deserialize_binary<O: Offset, B: ByteRecordGeneric>(
     Arc::new(BinaryArray::<O>::from_trusted_len_iter(iter))
 }
 
-#[inline]
-fn deserialize_datetime<T: chrono::TimeZone>(string: &str, tz: &T) -> Option<i64> {
-    let mut parsed = chrono::format::Parsed::new();
-    let fmt = chrono::format::StrftimeItems::new(RFC3339);
-    if chrono::format::parse(&mut parsed, string, fmt).is_ok() {
-        parsed
-            .to_datetime()
-            .map(|x| x.naive_utc())
-            .map(|x| tz.from_utc_datetime(&x))
-            .map(|x| x.timestamp_nanos())
-            .ok()
-    } else {
-        None
-    }
-}
-
 /// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`.
 #[inline]
 pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
@@ -154,6 +95,7 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
 ) -> Result<Arc<dyn Array>> {
     use DataType::*;
     Ok(match datatype {
+        Null => Arc::new(NullArray::from_data(datatype, rows.len())),
         Boolean => deserialize_boolean(rows, column, |bytes| {
             if bytes.eq_ignore_ascii_case(b"false") {
                 Some(false)
@@ -203,46 +145,21 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
                 .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
                 .map(|x| x.timestamp_millis())
         }),
-        Timestamp(TimeUnit::Nanosecond, None) => {
-            deserialize_primitive(rows, column, datatype, |bytes| {
-                to_utf8(bytes)
-                    .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                    .map(|x| x.timestamp_nanos())
-            })
-        }
-        Timestamp(TimeUnit::Microsecond, None) => {
-            deserialize_primitive(rows, column, datatype, |bytes| {
-                to_utf8(bytes)
-                    .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                    .map(|x| x.timestamp_nanos() / 1000)
-            })
-        }
         Timestamp(time_unit, None) => deserialize_primitive(rows, column, datatype, |bytes| {
             to_utf8(bytes)
                 .and_then(|x| x.parse::<chrono::NaiveDateTime>().ok())
-                .map(|x| x.timestamp_nanos())
-                .map(|x| match time_unit {
-                    TimeUnit::Second => x / 1_000_000_000,
-                    TimeUnit::Millisecond => x / 1_000_000,
-                    TimeUnit::Microsecond => x / 1_000,
-                    TimeUnit::Nanosecond => x,
-                })
+                .map(|x| temporal_conversions::naive_datetime_to_timestamp(&x, time_unit))
         }),
         Timestamp(time_unit, Some(ref tz)) => {
             let tz = temporal_conversions::parse_offset(tz)?;
             deserialize_primitive(rows, column, datatype, |bytes| {
-                to_utf8(bytes)
-                    .and_then(|x| deserialize_datetime(x, &tz))
-                    .map(|x| match time_unit {
-                        TimeUnit::Second => x / 1_000_000_000,
-                        TimeUnit::Millisecond => x / 1_000_000,
-                        TimeUnit::Microsecond => x / 1_000,
-                        TimeUnit::Nanosecond => x,
-                    })
+                to_utf8(bytes).and_then(|x| {
+                    temporal_conversions::utf8_to_timestamp_scalar(x, RFC3339, &tz, time_unit)
+                })
             })
         }
         Decimal(precision, scale) => deserialize_primitive(rows, column, datatype, |x| {
-            deserialize_decimal(x, precision, scale)
+            parse_decimal(x, precision, scale, false)
         }),
         Utf8 => deserialize_utf8::<i32, _>(rows, column),
         LargeUtf8 => deserialize_utf8::<i64, _>(rows, column),
@@ -257,6 +174,40 @@ pub(crate) fn deserialize_column<B: ByteRecordGeneric>(
     })
 }
 
+/// Deserializes `column` of `rows` into an [`Array`] of [`DataType`] `datatype`, parsing
+/// booleans, dates and timestamps in accordance to `options`.
+pub(crate) fn deserialize_column_with<B: ByteRecordGeneric>(
+    rows: &[B],
+    column: usize,
+    datatype: DataType,
+    line_number: usize,
+    options: &InferOptions,
+) -> Result<Arc<dyn Array>> {
+    use DataType::*;
+    Ok(match datatype {
+        Boolean => deserialize_boolean(rows, column, |bytes| options.parse_boolean(bytes)),
+        Date32 => deserialize_primitive(rows, column, datatype, |bytes| {
+            to_utf8(bytes)
+                .and_then(|x| {
+                    x.parse::<chrono::NaiveDate>()
+                        .ok()
+                        .or_else(|| options.parse_date(x))
+                })
+                .map(|x| x.num_days_from_ce() - temporal_conversions::EPOCH_DAYS_FROM_CE)
+        }),
+        Timestamp(time_unit, None) => deserialize_primitive(rows, column, datatype, |bytes| {
+            to_utf8(bytes)
+                .and_then(|x| {
+                    x.parse::<chrono::NaiveDateTime>()
+                        .ok()
+                        .or_else(|| options.parse_datetime(x))
+                })
+                .map(|x| temporal_conversions::naive_datetime_to_timestamp(&x, time_unit))
+        }),
+        _ => return deserialize_column(rows, column, datatype, line_number),
+    })
+}
+
 /// Deserializes rows [`ByteRecord`] into [`Chunk`].
 /// Note that this is a convenience function: column deserialization
 /// is embarassingly parallel (e.g. rayon).
@@ -270,23 +221,30 @@ pub(crate) fn deserialize_batch<F, B: ByteRecordGeneric>(
 where
     F: Fn(&[B], usize, DataType, usize) -> Result<Arc<dyn Array>>,
 {
-    let projection: Vec<usize> = match projection {
-        Some(v) => v.to_vec(),
-        None => fields.iter().enumerate().map(|(i, _)| i).collect(),
-    };
+    if let Some(column) = projection.and_then(|x| x.iter().find(|x| **x >= fields.len())) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The projection contains the column {} but there are only {} fields",
+            column,
+            fields.len()
+        )));
+    }
 
     if rows.is_empty() {
         return Ok(Chunk::new(vec![]));
     }
 
-    projection
-        .iter()
-        .map(|column| {
-            let column = *column;
-            let field = &fields[column];
-            let data_type = field.data_type();
-            deserialize_column(rows, column, data_type.clone(), line_number)
-        })
-        .collect::<Result<Vec<_>>>()
-        .and_then(Chunk::try_new)
+    let deserialize = |column: usize| {
+        let data_type = fields[column].data_type().clone();
+        deserialize_column(rows, column, data_type, line_number)
+    };
+    match projection {
+        Some(projection) => projection
+            .iter()
+            .map(|column| deserialize(*column))
+            .collect::<Result<Vec<_>>>(),
+        None => (0..fields.len())
+            .map(deserialize)
+            .collect::<Result<Vec<_>>>(),
+    }
+    .and_then(Chunk::try_new)
 }
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::datatypes::{DataType, Field, TimeUnit};
 
@@ -61,40 +61,207 @@ pub fn infer(bytes: &[u8]) -> DataType {
     } else if is_float(bytes) {
         DataType::Float64
     } else if let Ok(string) = simdutf8::basic::from_utf8(bytes) {
-        if is_date(string) {
-            DataType::Date32
-        } else if is_time(string) {
-            DataType::Time32(TimeUnit::Millisecond)
-        } else if is_naive_datetime(string) {
+        infer_string(string)
+    } else {
+        // invalid utf8
+        DataType::Binary
+    }
+}
+
+fn infer_string(string: &str) -> DataType {
+    if is_date(string) {
+        DataType::Date32
+    } else if is_time(string) {
+        DataType::Time32(TimeUnit::Millisecond)
+    } else if is_naive_datetime(string) {
+        DataType::Timestamp(TimeUnit::Millisecond, None)
+    } else if let Some(offset) = is_datetime(string) {
+        DataType::Timestamp(TimeUnit::Millisecond, Some(offset))
+    } else {
+        DataType::Utf8
+    }
+}
+
+/// Returns the [`DataType::Decimal`] with the least precision and scale that represents `bytes`,
+/// a number with a decimal point such as `-12.340`.
+fn infer_decimal(bytes: &[u8]) -> Option<DataType> {
+    let digits = match bytes.first() {
+        Some(b'-') | Some(b'+') => &bytes[1..],
+        _ => bytes,
+    };
+    let position = digits.iter().position(|x| *x == b'.')?;
+    let (integral, fractional) = (&digits[..position], &digits[position + 1..]);
+    if integral.is_empty() && fractional.is_empty() {
+        return None;
+    }
+    if !integral
+        .iter()
+        .chain(fractional.iter())
+        .all(|x| x.is_ascii_digit())
+    {
+        return None;
+    }
+    let precision = (integral.len() + fractional.len()).max(1);
+    if precision <= 38 {
+        Some(DataType::Decimal(precision, fractional.len()))
+    } else {
+        None
+    }
+}
+
+/// Options to infer the [`DataType`] of the values of a CSV file with [`InferOptions::infer`]
+/// and to deserialize them accordingly.
+/// Its [`Default`] infers the same types as [`infer`], with the exception that empty values are
+/// inferred as [`DataType::Null`] and thus do not conflict with the other values of the column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InferOptions {
+    /// Formats (e.g. `"%d/%m/%Y %H:%M"`, see [`chrono::format::strftime`]) of datetimes and dates,
+    /// that are mapped to [`DataType::Timestamp`] of milliseconds without timezone and
+    /// to [`DataType::Date32`] respectively. They are tried after the formats of [`infer`].
+    pub datetime_formats: Vec<String>,
+    /// Case insensitive literals of the `true` [`DataType::Boolean`]. Defaults to `["true"]`.
+    pub true_values: Vec<String>,
+    /// Case insensitive literals of the `false` [`DataType::Boolean`]. Defaults to `["false"]`.
+    pub false_values: Vec<String>,
+    /// Whether numbers with a decimal point (e.g. `1.20`) are inferred as [`DataType::Decimal`]
+    /// with the precision and scale that represent them instead of [`DataType::Float64`].
+    pub decimals: bool,
+    /// [`DataType`]s of columns, by name, that are used instead of inferring them.
+    pub overrides: HashMap<String, DataType>,
+}
+
+impl Default for InferOptions {
+    fn default() -> Self {
+        Self {
+            datetime_formats: vec![],
+            true_values: vec!["true".to_string()],
+            false_values: vec!["false".to_string()],
+            decimals: false,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl InferOptions {
+    /// Infers [`DataType`] from `bytes`, as [`infer`] does but in accordance to the options.
+    pub fn infer(&self, bytes: &[u8]) -> DataType {
+        if bytes.is_empty() {
+            DataType::Null
+        } else if self.parse_boolean(bytes).is_some() {
+            DataType::Boolean
+        } else if is_integer(bytes) {
+            DataType::Int64
+        } else if let Some(data_type) = self.infer_decimal(bytes) {
+            data_type
+        } else if is_float(bytes) {
+            DataType::Float64
+        } else if let Ok(string) = simdutf8::basic::from_utf8(bytes) {
+            match infer_string(string) {
+                DataType::Utf8 => self.infer_formats(string),
+                data_type => data_type,
+            }
+        } else {
+            // invalid utf8
+            DataType::Binary
+        }
+    }
+
+    fn infer_decimal(&self, bytes: &[u8]) -> Option<DataType> {
+        if self.decimals {
+            infer_decimal(bytes)
+        } else {
+            None
+        }
+    }
+
+    fn infer_formats(&self, string: &str) -> DataType {
+        if self.parse_datetime(string).is_some() {
             DataType::Timestamp(TimeUnit::Millisecond, None)
-        } else if let Some(offset) = is_datetime(string) {
-            DataType::Timestamp(TimeUnit::Millisecond, Some(offset))
+        } else if self.parse_date(string).is_some() {
+            DataType::Date32
         } else {
             DataType::Utf8
         }
+    }
+
+    /// Parses `bytes` to a boolean according to [`InferOptions::true_values`] and
+    /// [`InferOptions::false_values`].
+    pub(super) fn parse_boolean(&self, bytes: &[u8]) -> Option<bool> {
+        let matches = |values: &[String]| {
+            values
+                .iter()
+                .any(|value| bytes.eq_ignore_ascii_case(value.as_bytes()))
+        };
+        if matches(&self.true_values) {
+            Some(true)
+        } else if matches(&self.false_values) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Parses `string` to a datetime according to [`InferOptions::datetime_formats`].
+    pub(super) fn parse_datetime(&self, string: &str) -> Option<chrono::NaiveDateTime> {
+        self.datetime_formats
+            .iter()
+            .find_map(|format| chrono::NaiveDateTime::parse_from_str(string, format).ok())
+    }
+
+    /// Parses `string` to a date according to [`InferOptions::datetime_formats`].
+    pub(super) fn parse_date(&self, string: &str) -> Option<chrono::NaiveDate> {
+        self.datetime_formats
+            .iter()
+            .find_map(|format| chrono::NaiveDate::parse_from_str(string, format).ok())
+    }
+
+    /// Replaces the [`DataType`] of `fields` by their [`InferOptions::overrides`].
+    pub(crate) fn apply_overrides(&self, fields: &mut [Field]) {
+        for field in fields.iter_mut() {
+            if let Some(data_type) = self.overrides.get(&field.name) {
+                field.data_type = data_type.clone();
+            }
+        }
+    }
+}
+
+/// Returns the [`DataType`] that represents values of both `lhs` and `rhs`, if any.
+fn merge_data_types(lhs: &DataType, rhs: &DataType) -> Option<DataType> {
+    use DataType::*;
+    match (lhs, rhs) {
+        (lhs, rhs) if lhs == rhs => Some(lhs.clone()),
+        (Null, other) | (other, Null) => Some(other.clone()),
+        // we have an integer and double, fall down to double
+        (Int64, Float64) | (Float64, Int64) => Some(Float64),
+        (Decimal(_, _), Float64) | (Float64, Decimal(_, _)) => Some(Float64),
+        (Decimal(p1, s1), Decimal(p2, s2)) => Some(merge_decimals(p1 - s1, p2 - s2, *s1.max(s2))),
+        // an i64 has up to 19 digits
+        (Decimal(p, s), Int64) | (Int64, Decimal(p, s)) => Some(merge_decimals(p - s, 19, *s)),
+        _ => None,
+    }
+}
+
+/// The decimal that represents `integral` digits of either side and `scale`, or [`DataType::Float64`]
+/// if it exceeds the maximum precision.
+fn merge_decimals(lhs_integral: usize, rhs_integral: usize, scale: usize) -> DataType {
+    let precision = lhs_integral.max(rhs_integral) + scale;
+    if precision <= 38 {
+        DataType::Decimal(precision, scale)
     } else {
-        // invalid utf8
-        DataType::Binary
+        DataType::Float64
     }
 }
 
 fn merge_fields(field_name: &str, possibilities: &mut HashSet<DataType>) -> Field {
     // determine data type based on possible types
     // if there are incompatible types, use DataType::Utf8
-    let data_type = match possibilities.len() {
-        1 => possibilities.drain().next().unwrap(),
-        2 => {
-            if possibilities.contains(&DataType::Int64)
-                && possibilities.contains(&DataType::Float64)
-            {
-                // we have an integer and double, fall down to double
-                DataType::Float64
-            } else {
-                // default to Utf8 for conflicting datatypes (e.g bool and int)
-                DataType::Utf8
-            }
-        }
-        _ => DataType::Utf8,
+    let mut possibilities = possibilities.drain();
+    let data_type = match possibilities.next() {
+        Some(first) => possibilities
+            .try_fold(first, |acc, data_type| merge_data_types(&acc, &data_type))
+            // default to Utf8 for conflicting datatypes (e.g bool and int)
+            .unwrap_or(DataType::Utf8),
+        None => DataType::Utf8,
     };
     Field::new(field_name, data_type, true)
 }
@@ -1,13 +1,14 @@
 use lexical_core::ToLexical;
 
-use crate::datatypes::IntegerType;
 use crate::temporal_conversions;
 use crate::types::NativeType;
+use crate::util::decimal::write_decimal;
 use crate::util::lexical_to_bytes_mut;
 use crate::{
     array::{Array, BinaryArray, BooleanArray, PrimitiveArray, Utf8Array},
+    bitmap::utils::BitmapIter,
     datatypes::{DataType, TimeUnit},
-    error::Result,
+    error::{ArrowError, Result},
 };
 
 use super::super::super::iterator::{BufStreamingIterator, StreamingIterator};
@@ -28,6 +29,8 @@ pub struct SerializeOptions {
     pub time64_format: Option<String>,
     /// used for [`DataType::Timestamp`]
     pub timestamp_format: Option<String>,
+    /// the representation of null values; empty by default
+    pub null: String,
 }
 
 fn primitive_write<'a, T: NativeType + ToLexical>(
@@ -189,16 +192,67 @@ fn timestamp_with_tz<'a>(
 
 /// Returns a [`StreamingIterator`] that yields `&[u8]` serialized from `array` according to `options`.
 /// For numeric types, this serializes as usual. For dates, times and timestamps, it uses `options` to
+/// format them. Null values are serialized as [`SerializeOptions::null`].
 /// Supported types:
 /// * boolean
-/// * numeric types (i.e. floats, int, uint)
-/// * times and dates
-/// * naive timestamps (timestamps without timezone information)
+/// * numeric types (i.e. floats, int, uint) and decimals
+/// * times, dates and timestamps
+/// * utf8 and binary
+/// * dictionaries of utf8 values
 /// # Error
 /// This function errors if any of the logical types in `batch` is not supported.
 pub fn new_serializer<'a>(
     array: &'a dyn Array,
     options: &'a SerializeOptions,
+) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
+    let serializer = value_serializer(array, options)?;
+    Ok(match array.validity() {
+        Some(validity) if !options.null.is_empty() && validity.null_count() > 0 => {
+            Box::new(NullSerializer {
+                serializer,
+                validity: validity.iter(),
+                null: options.null.as_bytes(),
+                is_null: false,
+            })
+        }
+        _ => serializer,
+    })
+}
+
+/// A [`StreamingIterator`] that yields `null` instead of the (empty) items of `serializer`
+/// whose slots are null.
+struct NullSerializer<'a> {
+    serializer: Box<dyn StreamingIterator<Item = [u8]> + 'a>,
+    validity: BitmapIter<'a>,
+    null: &'a [u8],
+    is_null: bool,
+}
+
+impl<'a> StreamingIterator for NullSerializer<'a> {
+    type Item = [u8];
+
+    #[inline]
+    fn advance(&mut self) {
+        self.serializer.advance();
+        self.is_null = self.validity.next() == Some(false);
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&Self::Item> {
+        self.serializer
+            .get()
+            .map(|item| if self.is_null { self.null } else { item })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.serializer.size_hint()
+    }
+}
+
+fn value_serializer<'a>(
+    array: &'a dyn Array,
+    options: &'a SerializeOptions,
 ) -> Result<Box<dyn StreamingIterator<Item = [u8]> + 'a>> {
     Ok(match array.data_type() {
         DataType::Boolean => {
@@ -265,6 +319,22 @@ pub fn new_serializer<'a>(
         DataType::Int64 => {
             dyn_primitive!(i64, array)
         }
+        DataType::Decimal(_, scale) => {
+            let scale = *scale;
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i128>>()
+                .unwrap();
+            Box::new(BufStreamingIterator::new(
+                array.iter(),
+                move |x, buf| {
+                    if let Some(x) = x {
+                        write_decimal(*x, scale, buf)
+                    }
+                },
+                vec![],
+            ))
+        }
         DataType::Date64 => {
             dyn_date!(
                 i64,
@@ -383,22 +453,31 @@ pub fn new_serializer<'a>(
                 vec![],
             ))
         }
-        DataType::Dictionary(keys_dt, values_dt, _) => match &**values_dt {
-            DataType::LargeUtf8 => match *keys_dt {
-                IntegerType::UInt32 => serialize_utf8_dict::<u32, i64>(array.as_any()),
-                IntegerType::UInt64 => serialize_utf8_dict::<u64, i64>(array.as_any()),
-                _ => todo!(),
-            },
-            DataType::Utf8 => match *keys_dt {
-                IntegerType::UInt32 => serialize_utf8_dict::<u32, i32>(array.as_any()),
-                IntegerType::UInt64 => serialize_utf8_dict::<u64, i32>(array.as_any()),
-                _ => todo!(),
-            },
+        DataType::Dictionary(key_type, values_dt, _) => match values_dt.to_logical_type() {
+            DataType::Utf8 => match_integer_type!(key_type, |$T| {
+                serialize_utf8_dict::<$T, i32>(array.as_any())
+            }),
+            DataType::LargeUtf8 => match_integer_type!(key_type, |$T| {
+                serialize_utf8_dict::<$T, i64>(array.as_any())
+            }),
             _ => {
-                panic!("only dictionary with string values are supported by csv writer")
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "The csv writer does not support dictionaries of {:?}",
+                    values_dt
+                )))
             }
         },
-        dt => panic!("data type: {:?} not supported by csv writer", dt),
+        DataType::Null => Box::new(BufStreamingIterator::new(
+            (0..array.len()).map(|_| ()),
+            |_, _| {},
+            vec![],
+        )),
+        dt => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "The csv writer does not support {:?}",
+                dt
+            )))
+        }
     })
 }
 
@@ -0,0 +1,457 @@
+//! The metadata of Feather v1 files, a [FlatBuffers](https://google.github.io/flatbuffers/)
+//! table declared in Arrow's `feather.fbs`, and a minimal (de)serializer of FlatBuffers tables.
+use crate::error::{ArrowError, Result};
+
+/// The physical type of a Feather v1 array (`Type` in `feather.fbs`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Type {
+    Bool = 0,
+    Int8 = 1,
+    Int16 = 2,
+    Int32 = 3,
+    Int64 = 4,
+    UInt8 = 5,
+    UInt16 = 6,
+    UInt32 = 7,
+    UInt64 = 8,
+    Float = 9,
+    Double = 10,
+    Utf8 = 11,
+    Binary = 12,
+    Category = 13,
+    Timestamp = 14,
+    Date = 15,
+    Time = 16,
+    LargeUtf8 = 17,
+    LargeBinary = 18,
+}
+
+impl TryFrom<u8> for Type {
+    type Error = ArrowError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        use Type::*;
+        Ok(match value {
+            0 => Bool,
+            1 => Int8,
+            2 => Int16,
+            3 => Int32,
+            4 => Int64,
+            5 => UInt8,
+            6 => UInt16,
+            7 => UInt32,
+            8 => UInt64,
+            9 => Float,
+            10 => Double,
+            11 => Utf8,
+            12 => Binary,
+            13 => Category,
+            14 => Timestamp,
+            15 => Date,
+            16 => Time,
+            17 => LargeUtf8,
+            18 => LargeBinary,
+            other => return Err(ArrowError::oos(format!("Feather: unknown type {}", other))),
+        })
+    }
+}
+
+/// The location and physical type of the values of a column (`PrimitiveArray` in `feather.fbs`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrayMetadata {
+    pub type_: Type,
+    /// Whether the values are dictionary-encoded (unsupported)
+    pub is_dictionary: bool,
+    /// The offset of the values from the start of the file
+    pub offset: i64,
+    pub length: i64,
+    pub null_count: i64,
+    pub total_bytes: i64,
+}
+
+/// The logical type of a column (the union `TypeMetadata` in `feather.fbs`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeMetadata {
+    None,
+    Category {
+        levels: ArrayMetadata,
+        ordered: bool,
+    },
+    Timestamp {
+        unit: u8,
+        timezone: Option<String>,
+    },
+    Date,
+    Time {
+        unit: u8,
+    },
+}
+
+/// The metadata of a column (`Column` in `feather.fbs`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMetadata {
+    pub name: String,
+    pub values: ArrayMetadata,
+    pub metadata: TypeMetadata,
+}
+
+/// The metadata of a file (`CTable` in `feather.fbs`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableMetadata {
+    pub description: Option<String>,
+    pub num_rows: i64,
+    pub columns: Vec<ColumnMetadata>,
+    pub version: i32,
+}
+
+/// A table of a FlatBuffers buffer.
+#[derive(Clone, Copy)]
+struct Table<'a> {
+    buffer: &'a [u8],
+    position: usize,
+    vtable: &'a [u8],
+}
+
+fn read_u32(buffer: &[u8], position: usize) -> Result<u32> {
+    buffer
+        .get(position..position + 4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .ok_or_else(|| ArrowError::oos("Feather: the metadata is out of bounds"))
+}
+
+/// Returns the position that the offset stored at `position` points to.
+fn follow(buffer: &[u8], position: usize) -> Result<usize> {
+    Ok(position + read_u32(buffer, position)? as usize)
+}
+
+impl<'a> Table<'a> {
+    fn try_new(buffer: &'a [u8], position: usize) -> Result<Self> {
+        let soffset = read_u32(buffer, position)? as i32 as i64;
+        let vtable = usize::try_from(position as i64 - soffset)
+            .map_err(|_| ArrowError::oos("Feather: the metadata is out of bounds"))?;
+        let vtable_length = buffer
+            .get(vtable..vtable + 2)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()) as usize)
+            .ok_or_else(|| ArrowError::oos("Feather: the metadata is out of bounds"))?;
+        let vtable = buffer
+            .get(vtable..vtable + vtable_length)
+            .ok_or_else(|| ArrowError::oos("Feather: the metadata is out of bounds"))?;
+        Ok(Self {
+            buffer,
+            position,
+            vtable,
+        })
+    }
+
+    /// The bytes of the field `index`, if present
+    fn field(&self, index: usize, size: usize) -> Result<Option<&'a [u8]>> {
+        let entry = 4 + 2 * index;
+        let offset = match self.vtable.get(entry..entry + 2) {
+            Some(bytes) => u16::from_le_bytes(bytes.try_into().unwrap()) as usize,
+            None => return Ok(None),
+        };
+        if offset == 0 {
+            return Ok(None);
+        }
+        let start = self.position + offset;
+        self.buffer
+            .get(start..start + size)
+            .map(Some)
+            .ok_or_else(|| ArrowError::oos("Feather: the metadata is out of bounds"))
+    }
+
+    fn u8(&self, index: usize) -> Result<u8> {
+        Ok(self.field(index, 1)?.map(|bytes| bytes[0]).unwrap_or(0))
+    }
+
+    fn i32(&self, index: usize) -> Result<i32> {
+        Ok(self
+            .field(index, 4)?
+            .map(|bytes| i32::from_le_bytes(bytes.try_into().unwrap()))
+            .unwrap_or(0))
+    }
+
+    fn i64(&self, index: usize) -> Result<i64> {
+        Ok(self
+            .field(index, 8)?
+            .map(|bytes| i64::from_le_bytes(bytes.try_into().unwrap()))
+            .unwrap_or(0))
+    }
+
+    /// The position of the object the offset field `index` points to, if present
+    fn offset(&self, index: usize) -> Result<Option<usize>> {
+        if self.field(index, 4)?.is_none() {
+            return Ok(None);
+        }
+        let offset = u16::from_le_bytes(
+            self.vtable[4 + 2 * index..6 + 2 * index]
+                .try_into()
+                .unwrap(),
+        );
+        follow(self.buffer, self.position + offset as usize).map(Some)
+    }
+
+    fn string(&self, index: usize) -> Result<Option<String>> {
+        self.offset(index)?
+            .map(|position| {
+                let length = read_u32(self.buffer, position)? as usize;
+                let bytes = self
+                    .buffer
+                    .get(position + 4..position + 4 + length)
+                    .ok_or_else(|| ArrowError::oos("Feather: the metadata is out of bounds"))?;
+                Ok(std::str::from_utf8(bytes)?.to_string())
+            })
+            .transpose()
+    }
+
+    fn table(&self, index: usize) -> Result<Option<Table<'a>>> {
+        self.offset(index)?
+            .map(|position| Table::try_new(self.buffer, position))
+            .transpose()
+    }
+
+    fn tables(&self, index: usize) -> Result<Vec<Table<'a>>> {
+        let position = match self.offset(index)? {
+            Some(position) => position,
+            None => return Ok(vec![]),
+        };
+        let length = read_u32(self.buffer, position)? as usize;
+        (0..length)
+            .map(|i| {
+                let position = follow(self.buffer, position + 4 + 4 * i)?;
+                Table::try_new(self.buffer, position)
+            })
+            .collect()
+    }
+}
+
+fn deserialize_array(table: Table) -> Result<ArrayMetadata> {
+    Ok(ArrayMetadata {
+        type_: table.u8(0)?.try_into()?,
+        is_dictionary: table.u8(1)? == 1,
+        offset: table.i64(2)?,
+        length: table.i64(3)?,
+        null_count: table.i64(4)?,
+        total_bytes: table.i64(5)?,
+    })
+}
+
+fn deserialize_type_metadata(column: Table) -> Result<TypeMetadata> {
+    let table = match column.table(3)? {
+        Some(table) => table,
+        None => return Ok(TypeMetadata::None),
+    };
+    Ok(match column.u8(2)? {
+        0 => TypeMetadata::None,
+        1 => {
+            TypeMetadata::Category {
+                levels: deserialize_array(table.table(0)?.ok_or_else(|| {
+                    ArrowError::oos("Feather: a categorical column has no levels")
+                })?)?,
+                ordered: table.u8(1)? != 0,
+            }
+        }
+        2 => TypeMetadata::Timestamp {
+            unit: table.u8(0)?,
+            timezone: table.string(1)?,
+        },
+        3 => TypeMetadata::Date,
+        4 => TypeMetadata::Time { unit: table.u8(0)? },
+        other => {
+            return Err(ArrowError::oos(format!(
+                "Feather: unknown type metadata {}",
+                other
+            )))
+        }
+    })
+}
+
+fn deserialize_column(table: Table) -> Result<ColumnMetadata> {
+    Ok(ColumnMetadata {
+        name: table.string(0)?.unwrap_or_default(),
+        values: deserialize_array(
+            table
+                .table(1)?
+                .ok_or_else(|| ArrowError::oos("Feather: a column has no values"))?,
+        )?,
+        metadata: deserialize_type_metadata(table)?,
+    })
+}
+
+/// Deserializes the [`TableMetadata`] of a file from its FlatBuffers representation.
+pub fn deserialize(buffer: &[u8]) -> Result<TableMetadata> {
+    let table = Table::try_new(buffer, follow(buffer, 0)?)?;
+    Ok(TableMetadata {
+        description: table.string(0)?,
+        num_rows: table.i64(1)?,
+        columns: table
+            .tables(2)?
+            .into_iter()
+            .map(deserialize_column)
+            .collect::<Result<_>>()?,
+        version: table.i32(3)?,
+    })
+}
+
+/// A field of a FlatBuffers table to be serialized.
+enum Field {
+    U8(u8),
+    I32(i32),
+    I64(i64),
+    String(String),
+    Table(Vec<Option<Field>>),
+    Tables(Vec<Vec<Option<Field>>>),
+}
+
+impl Field {
+    /// The size of the field in its table
+    fn size(&self) -> usize {
+        match self {
+            Field::U8(_) => 1,
+            Field::I32(_) => 4,
+            Field::I64(_) => 8,
+            _ => 4,
+        }
+    }
+}
+
+fn align(buffer: &mut Vec<u8>, alignment: usize) {
+    let padding = (alignment - buffer.len() % alignment) % alignment;
+    buffer.resize(buffer.len() + padding, 0);
+}
+
+fn patch_offset(buffer: &mut [u8], position: usize, target: usize) {
+    buffer[position..position + 4].copy_from_slice(&((target - position) as u32).to_le_bytes());
+}
+
+/// Serializes a table whose fields are `fields` after the end of `buffer`, followed by the
+/// objects it points to, returning its position.
+/// Objects are serialized after the ones pointing to them since offsets are unsigned.
+fn serialize_table(buffer: &mut Vec<u8>, fields: Vec<Option<Field>>) -> usize {
+    // the layout of the table: a (signed) offset to its vtable followed by its fields
+    let mut field_offsets = vec![0u16; fields.len()];
+    let mut table_length = 4;
+    fields.iter().enumerate().for_each(|(index, field)| {
+        if let Some(field) = field {
+            let size = field.size();
+            table_length += (size - table_length % size) % size;
+            field_offsets[index] = table_length as u16;
+            table_length += size;
+        }
+    });
+
+    // the vtable precedes the table; tables are aligned to 8 bytes
+    align(buffer, 2);
+    let vtable = buffer.len();
+    buffer.extend_from_slice(&((4 + 2 * fields.len()) as u16).to_le_bytes());
+    buffer.extend_from_slice(&(table_length as u16).to_le_bytes());
+    field_offsets
+        .iter()
+        .for_each(|offset| buffer.extend_from_slice(&offset.to_le_bytes()));
+    align(buffer, 8);
+    let table = buffer.len();
+    buffer.extend_from_slice(&((table - vtable) as i32).to_le_bytes());
+    buffer.resize(table + table_length, 0);
+
+    let mut children = vec![];
+    for (field, offset) in fields.into_iter().zip(field_offsets) {
+        let position = table + offset as usize;
+        match field {
+            Some(Field::U8(value)) => buffer[position] = value,
+            Some(Field::I32(value)) => {
+                buffer[position..position + 4].copy_from_slice(&value.to_le_bytes())
+            }
+            Some(Field::I64(value)) => {
+                buffer[position..position + 8].copy_from_slice(&value.to_le_bytes())
+            }
+            Some(child) => children.push((position, child)),
+            None => {}
+        }
+    }
+    for (position, child) in children {
+        let target = match child {
+            Field::String(value) => {
+                align(buffer, 4);
+                let target = buffer.len();
+                buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+                buffer.extend_from_slice(value.as_bytes());
+                buffer.push(0);
+                target
+            }
+            Field::Table(fields) => serialize_table(buffer, fields),
+            Field::Tables(tables) => {
+                align(buffer, 4);
+                let target = buffer.len();
+                buffer.extend_from_slice(&(tables.len() as u32).to_le_bytes());
+                buffer.resize(target + 4 + 4 * tables.len(), 0);
+                for (i, fields) in tables.into_iter().enumerate() {
+                    let table = serialize_table(buffer, fields);
+                    patch_offset(buffer, target + 4 + 4 * i, table);
+                }
+                target
+            }
+            _ => unreachable!(),
+        };
+        patch_offset(buffer, position, target);
+    }
+    table
+}
+
+fn array_fields(array: &ArrayMetadata) -> Vec<Option<Field>> {
+    vec![
+        Some(Field::U8(array.type_ as u8)),
+        Some(Field::U8(array.is_dictionary as u8)),
+        Some(Field::I64(array.offset)),
+        Some(Field::I64(array.length)),
+        Some(Field::I64(array.null_count)),
+        Some(Field::I64(array.total_bytes)),
+    ]
+}
+
+fn column_fields(column: &ColumnMetadata) -> Vec<Option<Field>> {
+    let (type_, metadata) = match &column.metadata {
+        TypeMetadata::None => (0, None),
+        TypeMetadata::Category { levels, ordered } => (
+            1,
+            Some(vec![
+                Some(Field::Table(array_fields(levels))),
+                Some(Field::U8(*ordered as u8)),
+            ]),
+        ),
+        TypeMetadata::Timestamp { unit, timezone } => (
+            2,
+            Some(vec![
+                Some(Field::U8(*unit)),
+                timezone.clone().map(Field::String),
+            ]),
+        ),
+        TypeMetadata::Date => (3, Some(vec![])),
+        TypeMetadata::Time { unit } => (4, Some(vec![Some(Field::U8(*unit))])),
+    };
+    vec![
+        Some(Field::String(column.name.clone())),
+        Some(Field::Table(array_fields(&column.values))),
+        Some(Field::U8(type_)),
+        metadata.map(Field::Table),
+    ]
+}
+
+/// Serializes `metadata` to its FlatBuffers representation.
+pub fn serialize(metadata: &TableMetadata) -> Vec<u8> {
+    let fields = vec![
+        metadata.description.clone().map(Field::String),
+        Some(Field::I64(metadata.num_rows)),
+        Some(Field::Tables(
+            metadata.columns.iter().map(column_fields).collect(),
+        )),
+        Some(Field::I32(metadata.version)),
+    ];
+
+    // the root is an offset to the table
+    let mut buffer = vec![0; 4];
+    let table = serialize_table(&mut buffer, fields);
+    patch_offset(&mut buffer, 0, table);
+    align(&mut buffer, 8);
+    buffer
+}
@@ -0,0 +1,57 @@
+//! APIs to read from and write to Feather v1 files, the legacy format written by
+//! `feather-format` and by `pyarrow.feather.write_feather(..., version=1)`.
+//!
+//! A Feather v1 file is a single table whose columns are laid out contiguously, followed by
+//! its metadata as a FlatBuffers table. Feather v2 files are Arrow IPC files and are read and
+//! written via [`io::ipc`](crate::io::ipc).
+//!
+//! # Supported types
+//! Booleans, integers, floats, (large) utf8 and binary, `Date32`, `Timestamp`, `Time32`,
+//! `Time64` and dictionaries of them ("categories" in Feather v1).
+//! All columns are read as nullable.
+//!
+//! # Example
+//! ```
+//! use std::io::Cursor;
+//! use std::sync::Arc;
+//! use arrow2::array::{Array, Int32Array, Utf8Array};
+//! use arrow2::chunk::Chunk;
+//! use arrow2::datatypes::{DataType, Field, Schema};
+//! use arrow2::io::feather::{read, write};
+//!
+//! let schema = Schema::from(vec![
+//!     Field::new("a", DataType::Int32, true),
+//!     Field::new("b", DataType::Utf8, true),
+//! ]);
+//! let chunk = Chunk::new(vec![
+//!     Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+//!     Arc::new(Utf8Array::<i32>::from([Some("a"), Some("bb"), None])),
+//! ]);
+//!
+//! let mut file = Cursor::new(vec![]);
+//! write::write(&mut file, &schema, &chunk).unwrap();
+//!
+//! let metadata = read::read_metadata(&mut file).unwrap();
+//! assert_eq!(metadata.schema, schema);
+//! assert_eq!(metadata.num_rows, 3);
+//! let read = read::read(&mut file, &metadata, None).unwrap();
+//! assert_eq!(read, chunk);
+//! ```
+
+mod format;
+pub mod read;
+pub mod write;
+
+/// The magic number at the start and at the end of Feather v1 files.
+const MAGIC: [u8; 4] = *b"FEA1";
+
+/// The version of the metadata written by this crate.
+const VERSION: i32 = 2;
+
+/// Buffers are aligned to 8 bytes.
+const ALIGNMENT: usize = 8;
+
+#[inline]
+fn padded_length(length: usize) -> usize {
+    length + (ALIGNMENT - length % ALIGNMENT) % ALIGNMENT
+}
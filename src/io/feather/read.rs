@@ -0,0 +1,451 @@
+//! APIs to read Feather v1 files.
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::bitmap::{utils::bytes_for, Bitmap};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, IntegerType, PhysicalType, Schema, TimeUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::format::{self, ArrayMetadata, ColumnMetadata, Type, TypeMetadata};
+use super::{padded_length, MAGIC, VERSION};
+
+/// The metadata of a Feather v1 file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileMetadata {
+    /// The schema of the file. All its fields are nullable.
+    pub schema: Schema,
+    /// The number of rows of the file
+    pub num_rows: usize,
+    /// The description of the file, if any
+    pub description: Option<String>,
+    /// The location of the columns
+    columns: Vec<ColumnMetadata>,
+}
+
+fn deserialize_time_unit(unit: u8) -> Result<TimeUnit> {
+    Ok(match unit {
+        0 => TimeUnit::Second,
+        1 => TimeUnit::Millisecond,
+        2 => TimeUnit::Microsecond,
+        3 => TimeUnit::Nanosecond,
+        other => {
+            return Err(ArrowError::oos(format!(
+                "Feather: unknown time unit {}",
+                other
+            )))
+        }
+    })
+}
+
+/// The [`DataType`] of the values of an array
+fn deserialize_physical_type(type_: Type) -> Result<DataType> {
+    Ok(match type_ {
+        Type::Bool => DataType::Boolean,
+        Type::Int8 => DataType::Int8,
+        Type::Int16 => DataType::Int16,
+        Type::Int32 => DataType::Int32,
+        Type::Int64 => DataType::Int64,
+        Type::UInt8 => DataType::UInt8,
+        Type::UInt16 => DataType::UInt16,
+        Type::UInt32 => DataType::UInt32,
+        Type::UInt64 => DataType::UInt64,
+        Type::Float => DataType::Float32,
+        Type::Double => DataType::Float64,
+        Type::Utf8 => DataType::Utf8,
+        Type::Binary => DataType::Binary,
+        Type::LargeUtf8 => DataType::LargeUtf8,
+        Type::LargeBinary => DataType::LargeBinary,
+        other => {
+            return Err(ArrowError::oos(format!(
+                "Feather: the values of an array cannot be of type {:?}",
+                other
+            )))
+        }
+    })
+}
+
+fn deserialize_data_type(column: &ColumnMetadata) -> Result<DataType> {
+    let physical = deserialize_physical_type(column.values.type_)?;
+    let invalid = || {
+        ArrowError::oos(format!(
+            "Feather: the column \"{}\" of type {:?} cannot be stored as {:?}",
+            column.name, column.metadata, physical
+        ))
+    };
+    Ok(match &column.metadata {
+        TypeMetadata::None => physical,
+        TypeMetadata::Category { levels, ordered } => {
+            let key = match physical {
+                DataType::Int8 => IntegerType::Int8,
+                DataType::Int16 => IntegerType::Int16,
+                DataType::Int32 => IntegerType::Int32,
+                DataType::Int64 => IntegerType::Int64,
+                DataType::UInt8 => IntegerType::UInt8,
+                DataType::UInt16 => IntegerType::UInt16,
+                DataType::UInt32 => IntegerType::UInt32,
+                DataType::UInt64 => IntegerType::UInt64,
+                _ => return Err(invalid()),
+            };
+            let values = deserialize_physical_type(levels.type_)?;
+            DataType::Dictionary(key, Box::new(values), *ordered)
+        }
+        TypeMetadata::Timestamp { unit, timezone } => match physical {
+            DataType::Int64 => DataType::Timestamp(deserialize_time_unit(*unit)?, timezone.clone()),
+            _ => return Err(invalid()),
+        },
+        TypeMetadata::Date => match physical {
+            DataType::Int32 => DataType::Date32,
+            _ => return Err(invalid()),
+        },
+        TypeMetadata::Time { unit } => match (&physical, deserialize_time_unit(*unit)?) {
+            (DataType::Int32, unit @ (TimeUnit::Second | TimeUnit::Millisecond)) => {
+                DataType::Time32(unit)
+            }
+            (DataType::Int64, unit @ (TimeUnit::Microsecond | TimeUnit::Nanosecond)) => {
+                DataType::Time64(unit)
+            }
+            _ => return Err(invalid()),
+        },
+    })
+}
+
+/// Reads the metadata of a Feather v1 file.
+/// # Errors
+/// Errors iff the file is not a Feather v1 file, its metadata is invalid or it contains
+/// unsupported types.
+pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata> {
+    let file_length = reader.seek(SeekFrom::End(0))?;
+    if file_length < 12 {
+        return Err(ArrowError::oos(
+            "Feather: a Feather v1 file must have at least 12 bytes",
+        ));
+    }
+
+    let mut magic = [0; 4];
+    reader.seek(SeekFrom::Start(0))?;
+    reader.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(ArrowError::oos(
+            "Feather: the file does not start with the magic number of Feather v1 files",
+        ));
+    }
+
+    // the footer is the length of the metadata followed by the magic number
+    let mut footer = [0; 8];
+    reader.seek(SeekFrom::End(-8))?;
+    reader.read_exact(&mut footer)?;
+    if footer[4..] != MAGIC {
+        return Err(ArrowError::oos(
+            "Feather: the file does not end with the magic number of Feather v1 files",
+        ));
+    }
+    let metadata_length = u32::from_le_bytes(footer[..4].try_into().unwrap()) as u64;
+    if metadata_length + 12 > file_length {
+        return Err(ArrowError::oos(
+            "Feather: the length of the metadata exceeds the length of the file",
+        ));
+    }
+
+    let mut metadata = vec![0; metadata_length as usize];
+    reader.seek(SeekFrom::End(-8 - metadata_length as i64))?;
+    reader.read_exact(&mut metadata)?;
+    let metadata = format::deserialize(&metadata)?;
+
+    if metadata.version < VERSION {
+        return Err(ArrowError::nyi(format!(
+            "Feather: reading files of version {} is not supported",
+            metadata.version
+        )));
+    }
+    let num_rows = usize::try_from(metadata.num_rows)
+        .map_err(|_| ArrowError::oos("Feather: the number of rows must be positive"))?;
+
+    let fields = metadata
+        .columns
+        .iter()
+        .map(|column| {
+            if column.values.is_dictionary {
+                return Err(ArrowError::nyi(
+                    "Feather: reading dictionary-encoded arrays is not supported",
+                ));
+            }
+            if column.values.length != metadata.num_rows {
+                return Err(ArrowError::oos(format!(
+                    "Feather: the column \"{}\" has {} rows but the file has {}",
+                    column.name, column.values.length, metadata.num_rows
+                )));
+            }
+            Ok(Field::new(
+                &column.name,
+                deserialize_data_type(column)?,
+                true,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(FileMetadata {
+        schema: fields.into(),
+        num_rows,
+        description: metadata.description,
+        columns: metadata.columns,
+    })
+}
+
+/// Reads the bytes of `array`
+fn read_buffer<R: Read + Seek>(reader: &mut R, array: &ArrayMetadata) -> Result<Vec<u8>> {
+    let (offset, length) = match (
+        u64::try_from(array.offset),
+        u64::try_from(array.total_bytes),
+    ) {
+        (Ok(offset), Ok(length)) => (offset, length),
+        _ => {
+            return Err(ArrowError::oos(
+                "Feather: the offset and length of an array must be positive",
+            ))
+        }
+    };
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut buffer = vec![];
+    reader.take(length).read_to_end(&mut buffer)?;
+    if buffer.len() as u64 != length {
+        return Err(ArrowError::oos(
+            "Feather: an array exceeds the length of the file",
+        ));
+    }
+    Ok(buffer)
+}
+
+/// The number of slots of `array`, whose bytes are `buffer`
+fn array_length(array: &ArrayMetadata, buffer: &[u8]) -> Result<usize> {
+    // every slot takes at least one bit
+    match usize::try_from(array.length) {
+        Ok(length) if length / 8 <= buffer.len() => Ok(length),
+        _ => Err(ArrowError::oos(
+            "Feather: the length of an array is inconsistent with its total bytes",
+        )),
+    }
+}
+
+/// Splits the first `length` bytes from `buffer`, followed by padding
+fn split_padded(buffer: &[u8], length: usize) -> Result<(&[u8], &[u8])> {
+    let padded = padded_length(length);
+    if padded > buffer.len() {
+        return Err(ArrowError::oos(
+            "Feather: the buffers of an array exceed its total bytes",
+        ));
+    }
+    Ok((&buffer[..length], &buffer[padded..]))
+}
+
+/// Splits the validity of an array of `length` slots from `buffer`
+fn read_validity<'a>(
+    buffer: &'a [u8],
+    array: &ArrayMetadata,
+    length: usize,
+) -> Result<(Option<Bitmap>, &'a [u8])> {
+    if array.null_count > 0 {
+        let (validity, buffer) = split_padded(buffer, bytes_for(length))?;
+        Ok((Some(Bitmap::from_u8_slice(validity, length)), buffer))
+    } else {
+        Ok((None, buffer))
+    }
+}
+
+fn read_values<T: NativeType>(buffer: &[u8], length: usize) -> Result<Vec<T>> {
+    let size = std::mem::size_of::<T>();
+    let bytes = buffer
+        .get(..length * size)
+        .ok_or_else(|| ArrowError::oos("Feather: the values of an array exceed its total bytes"))?;
+
+    let mut values = vec![T::default(); length];
+    bytemuck::cast_slice_mut(&mut values).copy_from_slice(bytes);
+    if cfg!(target_endian = "big") {
+        bytemuck::cast_slice_mut::<_, u8>(&mut values)
+            .chunks_exact_mut(size)
+            .for_each(|value| value.reverse());
+    }
+    Ok(values)
+}
+
+fn read_primitive<T: NativeType>(
+    buffer: &[u8],
+    array: &ArrayMetadata,
+    length: usize,
+    data_type: DataType,
+) -> Result<PrimitiveArray<T>> {
+    let (validity, buffer) = read_validity(buffer, array, length)?;
+    let values = read_values::<T>(buffer, length)?;
+    Ok(PrimitiveArray::from_data(
+        data_type,
+        values.into(),
+        validity,
+    ))
+}
+
+fn read_boolean(buffer: &[u8], array: &ArrayMetadata, length: usize) -> Result<BooleanArray> {
+    let (validity, buffer) = read_validity(buffer, array, length)?;
+    if buffer.len() * 8 < length {
+        return Err(ArrowError::oos(
+            "Feather: the values of an array exceed its total bytes",
+        ));
+    }
+    let values = Bitmap::from_u8_slice(buffer, length);
+    Ok(BooleanArray::from_data(DataType::Boolean, values, validity))
+}
+
+/// Splits the validity, offsets and values of a variable-sized array from `buffer`
+#[allow(clippy::type_complexity)]
+fn read_offsets_values<O: Offset>(
+    buffer: &[u8],
+    array: &ArrayMetadata,
+    length: usize,
+) -> Result<(Option<Bitmap>, Vec<O>, Vec<u8>)> {
+    let (validity, buffer) = read_validity(buffer, array, length)?;
+    let (offsets, buffer) = split_padded(buffer, (length + 1) * std::mem::size_of::<O>())?;
+    let offsets = read_values::<O>(offsets, length + 1)?;
+
+    let start = offsets[0].to_usize();
+    let end = offsets[length].to_usize();
+    if offsets.windows(2).any(|w| w[0] > w[1]) || start > end || end > buffer.len() {
+        return Err(ArrowError::oos(
+            "Feather: the offsets of an array are invalid",
+        ));
+    }
+    Ok((validity, offsets, buffer[..end].to_vec()))
+}
+
+fn read_utf8<O: Offset>(
+    buffer: &[u8],
+    array: &ArrayMetadata,
+    length: usize,
+    data_type: DataType,
+) -> Result<Utf8Array<O>> {
+    let (validity, offsets, values) = read_offsets_values::<O>(buffer, array, length)?;
+    if offsets
+        .windows(2)
+        .any(|w| std::str::from_utf8(&values[w[0].to_usize()..w[1].to_usize()]).is_err())
+    {
+        return Err(ArrowError::oos(
+            "Feather: the values of a utf8 array are not utf8",
+        ));
+    }
+    Ok(Utf8Array::<O>::from_data(
+        data_type,
+        offsets.into(),
+        values.into(),
+        validity,
+    ))
+}
+
+fn read_binary<O: Offset>(
+    buffer: &[u8],
+    array: &ArrayMetadata,
+    length: usize,
+    data_type: DataType,
+) -> Result<BinaryArray<O>> {
+    let (validity, offsets, values) = read_offsets_values::<O>(buffer, array, length)?;
+    Ok(BinaryArray::<O>::from_data(
+        data_type,
+        offsets.into(),
+        values.into(),
+        validity,
+    ))
+}
+
+/// Reads the array described by `array`, whose data type is not a dictionary
+fn read_array<R: Read + Seek>(
+    reader: &mut R,
+    array: &ArrayMetadata,
+    data_type: DataType,
+) -> Result<Arc<dyn Array>> {
+    let buffer = read_buffer(reader, array)?;
+    let length = array_length(array, &buffer)?;
+
+    use PhysicalType::*;
+    Ok(match data_type.to_physical_type() {
+        Boolean => Arc::new(read_boolean(&buffer, array, length)?),
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            Arc::new(read_primitive::<$T>(&buffer, array, length, data_type)?)
+        }),
+        Utf8 => Arc::new(read_utf8::<i32>(&buffer, array, length, data_type)?),
+        LargeUtf8 => Arc::new(read_utf8::<i64>(&buffer, array, length, data_type)?),
+        Binary => Arc::new(read_binary::<i32>(&buffer, array, length, data_type)?),
+        LargeBinary => Arc::new(read_binary::<i64>(&buffer, array, length, data_type)?),
+        _ => unreachable!(),
+    })
+}
+
+fn read_dictionary<K: DictionaryKey, R: Read + Seek>(
+    reader: &mut R,
+    keys: &ArrayMetadata,
+    levels: &ArrayMetadata,
+    data_type: DataType,
+) -> Result<DictionaryArray<K>> {
+    let values = read_array(
+        reader,
+        levels,
+        DictionaryArray::<K>::get_child(&data_type).clone(),
+    )?;
+
+    let buffer = read_buffer(reader, keys)?;
+    let length = array_length(keys, &buffer)?;
+    let keys = read_primitive::<K>(&buffer, keys, length, K::PRIMITIVE.into())?;
+    if keys.iter().flatten().any(|key| {
+        num_traits::ToPrimitive::to_usize(key)
+            .map(|key| key >= values.len())
+            .unwrap_or(true)
+    }) {
+        return Err(ArrowError::oos(
+            "Feather: the codes of a categorical column are out of bounds of its levels",
+        ));
+    }
+    Ok(DictionaryArray::<K>::from_data_type(
+        data_type, keys, values,
+    ))
+}
+
+fn read_column<R: Read + Seek>(
+    reader: &mut R,
+    column: &ColumnMetadata,
+    data_type: &DataType,
+) -> Result<Arc<dyn Array>> {
+    match (data_type, &column.metadata) {
+        (DataType::Dictionary(key, _, _), TypeMetadata::Category { levels, .. }) => {
+            match_integer_type!(key, |$T| {
+                let array = read_dictionary::<$T, _>(reader, &column.values, levels, data_type.clone())?;
+                Ok(Arc::new(array))
+            })
+        }
+        _ => read_array(reader, &column.values, data_type.clone()),
+    }
+}
+
+/// Reads the columns of a Feather v1 file, optionally projected by their indices.
+/// # Errors
+/// Errors iff the file is invalid or `projection` contains an index out of bounds.
+pub fn read<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let all = (0..metadata.columns.len()).collect::<Vec<_>>();
+    let projection = projection.unwrap_or(&all);
+
+    let arrays = projection
+        .iter()
+        .map(|index| {
+            let column = metadata.columns.get(*index).ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "The projection index {} is out of bounds of the {} columns of the file",
+                    index,
+                    metadata.columns.len()
+                ))
+            })?;
+            read_column(reader, column, metadata.schema.fields[*index].data_type())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
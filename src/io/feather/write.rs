@@ -0,0 +1,266 @@
+//! APIs to write Feather v1 files.
+use std::io::Write;
+
+use crate::array::*;
+use crate::bitmap::{utils::bytes_for, Bitmap};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType, Schema, TimeUnit};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::format::{self, ArrayMetadata, ColumnMetadata, TableMetadata, Type, TypeMetadata};
+use super::{padded_length, MAGIC, VERSION};
+
+fn pad(buffer: &mut Vec<u8>) {
+    buffer.resize(padded_length(buffer.len()), 0);
+}
+
+fn write_bitmap(bitmap: &Bitmap, buffer: &mut Vec<u8>) {
+    let (slice, offset, length) = bitmap.as_slice();
+    if offset == 0 {
+        buffer.extend_from_slice(&slice[..bytes_for(length)]);
+    } else {
+        let bitmap = bitmap.iter().collect::<Bitmap>();
+        buffer.extend_from_slice(bitmap.as_slice().0);
+    }
+}
+
+fn write_validity(array: &dyn Array, buffer: &mut Vec<u8>) {
+    if let Some(validity) = array.validity().filter(|_| array.null_count() > 0) {
+        write_bitmap(validity, buffer);
+        pad(buffer);
+    }
+}
+
+fn write_values<T: NativeType>(values: &[T], buffer: &mut Vec<u8>) {
+    if cfg!(target_endian = "little") {
+        buffer.extend_from_slice(bytemuck::cast_slice(values));
+    } else {
+        values
+            .iter()
+            .for_each(|value| buffer.extend_from_slice(value.to_le_bytes().as_ref()));
+    }
+}
+
+/// Writes the offsets of a variable-sized array starting at zero, returning the range of
+/// its values.
+fn write_offsets<O: Offset>(offsets: &[O], buffer: &mut Vec<u8>) -> (usize, usize) {
+    let first = offsets[0];
+    let offsets = offsets
+        .iter()
+        .map(|offset| *offset - first)
+        .collect::<Vec<_>>();
+    write_values(&offsets, buffer);
+    pad(buffer);
+    (
+        first.to_usize(),
+        offsets.last().unwrap().to_usize() + first.to_usize(),
+    )
+}
+
+/// The physical type of an array of `data_type` in Feather v1
+fn serialize_physical_type(data_type: &DataType) -> Result<Type> {
+    use PrimitiveType::*;
+    Ok(match data_type.to_physical_type() {
+        PhysicalType::Boolean => Type::Bool,
+        PhysicalType::Primitive(Int8) => Type::Int8,
+        PhysicalType::Primitive(Int16) => Type::Int16,
+        PhysicalType::Primitive(Int32) => Type::Int32,
+        PhysicalType::Primitive(Int64) => Type::Int64,
+        PhysicalType::Primitive(UInt8) => Type::UInt8,
+        PhysicalType::Primitive(UInt16) => Type::UInt16,
+        PhysicalType::Primitive(UInt32) => Type::UInt32,
+        PhysicalType::Primitive(UInt64) => Type::UInt64,
+        PhysicalType::Primitive(Float32) => Type::Float,
+        PhysicalType::Primitive(Float64) => Type::Double,
+        PhysicalType::Utf8 => Type::Utf8,
+        PhysicalType::LargeUtf8 => Type::LargeUtf8,
+        PhysicalType::Binary => Type::Binary,
+        PhysicalType::LargeBinary => Type::LargeBinary,
+        _ => {
+            return Err(ArrowError::nyi(format!(
+                "Feather v1 does not support arrays of type {:?}",
+                data_type
+            )))
+        }
+    })
+}
+
+fn serialize_time_unit(unit: &TimeUnit) -> u8 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 1,
+        TimeUnit::Microsecond => 2,
+        TimeUnit::Nanosecond => 3,
+    }
+}
+
+/// The logical type of a column of `data_type` in Feather v1, except for dictionaries
+fn serialize_type_metadata(data_type: &DataType) -> Result<TypeMetadata> {
+    Ok(match data_type {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary => TypeMetadata::None,
+        DataType::Date32 => TypeMetadata::Date,
+        DataType::Timestamp(unit, timezone) => TypeMetadata::Timestamp {
+            unit: serialize_time_unit(unit),
+            timezone: timezone.clone(),
+        },
+        DataType::Time32(unit) | DataType::Time64(unit) => TypeMetadata::Time {
+            unit: serialize_time_unit(unit),
+        },
+        other => {
+            return Err(ArrowError::nyi(format!(
+                "Feather v1 does not support arrays of type {:?}",
+                other
+            )))
+        }
+    })
+}
+
+/// Writes the validity, offsets and values of `array` to `buffer`, padded to 8 bytes.
+fn write_array(array: &dyn Array, buffer: &mut Vec<u8>) {
+    write_validity(array, buffer);
+
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            write_bitmap(array.values(), buffer);
+        }
+        Primitive(primitive) => with_match_primitive_type!(primitive, |$T| {
+            let array = array.as_any().downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            write_values(array.values().as_slice(), buffer);
+        }),
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            let (start, end) = write_offsets(array.offsets().as_slice(), buffer);
+            buffer.extend_from_slice(&array.values()[start..end]);
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let (start, end) = write_offsets(array.offsets().as_slice(), buffer);
+            buffer.extend_from_slice(&array.values()[start..end]);
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            let (start, end) = write_offsets(array.offsets().as_slice(), buffer);
+            buffer.extend_from_slice(&array.values()[start..end]);
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let (start, end) = write_offsets(array.offsets().as_slice(), buffer);
+            buffer.extend_from_slice(&array.values()[start..end]);
+        }
+        _ => unreachable!(),
+    }
+    pad(buffer);
+}
+
+/// Writes `array` at `offset`, returning its [`ArrayMetadata`].
+fn serialize_array<W: Write>(
+    writer: &mut W,
+    array: &dyn Array,
+    offset: &mut usize,
+) -> Result<ArrayMetadata> {
+    let type_ = serialize_physical_type(array.data_type())?;
+
+    let mut buffer = vec![];
+    write_array(array, &mut buffer);
+    writer.write_all(&buffer)?;
+
+    let metadata = ArrayMetadata {
+        type_,
+        is_dictionary: false,
+        offset: *offset as i64,
+        length: array.len() as i64,
+        null_count: array.null_count() as i64,
+        total_bytes: buffer.len() as i64,
+    };
+    *offset += buffer.len();
+    Ok(metadata)
+}
+
+fn serialize_column<W: Write>(
+    writer: &mut W,
+    name: &str,
+    array: &dyn Array,
+    offset: &mut usize,
+) -> Result<ColumnMetadata> {
+    let (values, metadata) = match array.data_type().to_logical_type() {
+        DataType::Dictionary(key_type, values, ordered) => {
+            // the levels of categories are stored without their logical type
+            if serialize_type_metadata(values)? != TypeMetadata::None {
+                return Err(ArrowError::nyi(format!(
+                    "Feather v1 does not support dictionaries of type {:?}",
+                    values
+                )));
+            }
+            match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                let levels = serialize_array(writer, array.values().as_ref(), offset)?;
+                let keys = serialize_array(writer, array.keys(), offset)?;
+                (keys, TypeMetadata::Category { levels, ordered: *ordered })
+            })
+        }
+        data_type => {
+            let metadata = serialize_type_metadata(data_type)?;
+            (serialize_array(writer, array, offset)?, metadata)
+        }
+    };
+    Ok(ColumnMetadata {
+        name: name.to_string(),
+        values,
+        metadata,
+    })
+}
+
+/// Writes `chunk`, whose fields are declared in `schema`, to `writer` as a Feather v1 file.
+/// # Errors
+/// Errors iff an array is not supported by Feather v1, e.g. nested types,
+/// or the writer errors.
+pub fn write<W: Write, A: AsRef<dyn Array>>(
+    writer: &mut W,
+    schema: &Schema,
+    chunk: &Chunk<A>,
+) -> Result<()> {
+    if schema.fields.len() != chunk.arrays().len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "The schema and the chunk must have the same number of columns".to_string(),
+        ));
+    }
+
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&[0; 4])?;
+    let mut offset = 8;
+
+    let columns = schema
+        .fields
+        .iter()
+        .zip(chunk.arrays())
+        .map(|(field, array)| serialize_column(writer, &field.name, array.as_ref(), &mut offset))
+        .collect::<Result<Vec<_>>>()?;
+
+    let metadata = format::serialize(&TableMetadata {
+        description: None,
+        num_rows: chunk.len() as i64,
+        columns,
+        version: VERSION,
+    });
+    writer.write_all(&metadata)?;
+    writer.write_all(&(metadata.len() as u32).to_le_bytes())?;
+    writer.write_all(&MAGIC)?;
+    Ok(())
+}
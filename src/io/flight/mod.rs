@@ -1,3 +1,10 @@
+//! Serialization and deserialization of [`Chunk`]s and [`Schema`]s to and from Arrow Flight's
+//! [`FlightData`] messages, whose headers and bodies are IPC-encoded.
+//!
+//! A Flight stream is composed by a schema message followed by dictionary and record batch
+//! messages. [`FlightEncoder`] and [`FlightDecoder`] keep the state (e.g. the dictionaries)
+//! required to encode and decode a stream, while the free functions encode and decode
+//! individual messages.
 use std::sync::Arc;
 
 use arrow_format::flight::data::{FlightData, SchemaResult};
@@ -88,6 +95,62 @@ pub fn deserialize_schemas(bytes: &[u8]) -> Result<(Schema, IpcSchema)> {
     read::deserialize_schema(bytes)
 }
 
+/// Deserializes [`FlightData`] containing a dictionary batch, inserting (or, for deltas,
+/// appending to) the dictionary in `dictionaries`.
+pub fn deserialize_dictionary(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut read::Dictionaries,
+) -> Result<()> {
+    let message = ipc::MessageRef::read_as_root(&data.data_header).map_err(|err| {
+        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+    })?;
+
+    let mut reader = std::io::Cursor::new(&data.data_body);
+
+    match message.header()?.ok_or_else(|| {
+        ArrowError::oos("Unable to convert flight data header to a dictionary batch")
+    })? {
+        ipc::MessageHeaderRef::DictionaryBatch(batch) => {
+            read::read_dictionary(batch, fields, ipc_schema, dictionaries, &mut reader, 0)
+        }
+        _ => Err(ArrowError::oos(
+            "Expecting a dictionary batch message in flight data",
+        )),
+    }
+}
+
+/// Deserializes [`FlightData`] containing either a dictionary batch or a record batch.
+///
+/// Dictionary batches update `dictionaries` and return `None`; record batches return their
+/// [`Chunk`].
+pub fn deserialize_message(
+    data: &FlightData,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut read::Dictionaries,
+) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+    let message = ipc::MessageRef::read_as_root(&data.data_header).map_err(|err| {
+        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+    })?;
+
+    match message.header()? {
+        Some(ipc::MessageHeaderRef::DictionaryBatch(_)) => {
+            deserialize_dictionary(data, fields, ipc_schema, dictionaries)?;
+            Ok(None)
+        }
+        Some(ipc::MessageHeaderRef::RecordBatch(_)) => {
+            deserialize_batch(data, fields, ipc_schema, dictionaries).map(Some)
+        }
+        Some(t) => Err(ArrowError::nyi(format!(
+            "Reading flight messages of type {:?}",
+            t
+        ))),
+        None => Err(ArrowError::oos("The flight data message has no header")),
+    }
+}
+
 /// Deserializes [`FlightData`] to [`Chunk`].
 pub fn deserialize_batch(
     data: &FlightData,
@@ -123,3 +186,108 @@ pub fn deserialize_batch(
         )),
     }
 }
+
+/// An encoder of a stream of [`Chunk`]s to [`FlightData`] messages.
+///
+/// Unlike [`serialize_batch`], the encoder keeps track of the dictionaries already sent, so
+/// that each dictionary is only sent when it first appears or changes (as a delta when new
+/// values were appended to it).
+pub struct FlightEncoder {
+    fields: Vec<IpcField>,
+    options: WriteOptions,
+    dictionary_tracker: DictionaryTracker,
+}
+
+impl FlightEncoder {
+    /// Creates a new [`FlightEncoder`] of chunks whose fields are `fields`
+    /// (e.g. from [`write::default_ipc_fields`]).
+    pub fn new(fields: Vec<IpcField>, options: WriteOptions) -> Self {
+        Self {
+            fields,
+            options,
+            dictionary_tracker: DictionaryTracker::new(false),
+        }
+    }
+
+    /// Encodes the schema message of the stream.
+    pub fn encode_schema(&self, schema: &Schema) -> FlightData {
+        serialize_schema(schema, &self.fields)
+    }
+
+    /// Encodes `chunk` to the dictionary messages it requires followed by its record batch
+    /// message, whose [`FlightData::app_metadata`] is `app_metadata`.
+    /// # Errors
+    /// This function errors iff `chunk` cannot be encoded (e.g. its arrays do not match the
+    /// fields).
+    pub fn encode(
+        &mut self,
+        chunk: &Chunk<Arc<dyn Array>>,
+        app_metadata: Vec<u8>,
+    ) -> Result<Vec<FlightData>> {
+        let (dictionaries, batch) = encode_chunk(
+            chunk,
+            &self.fields,
+            &mut self.dictionary_tracker,
+            &self.options,
+        )?;
+        let mut batch: FlightData = batch.into();
+        batch.app_metadata = app_metadata;
+
+        let mut messages = dictionaries
+            .into_iter()
+            .map(FlightData::from)
+            .collect::<Vec<_>>();
+        messages.push(batch);
+        Ok(messages)
+    }
+}
+
+/// A decoder of a stream of [`FlightData`] messages, starting with its schema message.
+pub struct FlightDecoder {
+    schema: Schema,
+    ipc_schema: IpcSchema,
+    dictionaries: read::Dictionaries,
+}
+
+impl FlightDecoder {
+    /// Creates a new [`FlightDecoder`] from the schema message of the stream.
+    /// # Errors
+    /// This function errors iff `data` is not a valid schema message.
+    pub fn try_new(data: &FlightData) -> Result<Self> {
+        let (schema, ipc_schema) = deserialize_schemas(&data.data_header)?;
+        Ok(Self {
+            schema,
+            ipc_schema,
+            dictionaries: Default::default(),
+        })
+    }
+
+    /// Returns the [`Schema`] of the stream.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Returns the [`IpcSchema`] of the stream.
+    pub fn ipc_schema(&self) -> &IpcSchema {
+        &self.ipc_schema
+    }
+
+    /// Decodes the next message of the stream.
+    ///
+    /// Dictionary messages update the dictionaries of the decoder and return `None`; record
+    /// batch messages return their [`Chunk`] and their [`FlightData::app_metadata`].
+    /// # Errors
+    /// This function errors iff `data` is not a valid dictionary or record batch message.
+    pub fn decode(
+        &mut self,
+        data: &FlightData,
+    ) -> Result<Option<(Chunk<Arc<dyn Array>>, Vec<u8>)>> {
+        Ok(deserialize_message(
+            data,
+            &self.schema.fields,
+            &self.ipc_schema,
+            &mut self.dictionaries,
+        )?
+        .map(|chunk| (chunk, data.app_metadata.clone())))
+    }
+}
@@ -0,0 +1,244 @@
+//! APIs to convert between Arrow's IPC stream and file formats.
+//!
+//! Both formats share the same message framing; a file is a stream prefixed by
+//! the magic bytes `ARROW1` and suffixed by a footer pointing to each message.
+//! The functions in this module rewrite the framing and build (or strip) the
+//! footer without deserializing any array, which makes them e.g. suitable to
+//! cheaply persist a stream received over the network to disk.
+use std::convert::TryInto;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use arrow_format::ipc::planus::{Builder, ReadAsRoot};
+
+use crate::error::{ArrowError, Result};
+
+use super::read::read_footer_data;
+use super::write::common::pad_to_8;
+use super::write::common_sync::write_continuation;
+use super::{ARROW_MAGIC, CONTINUATION_MARKER};
+
+/// Reads the next message of a stream into `message` (its flatbuffer) and `body`,
+/// returning `false` when the stream ended.
+fn read_stream_message<R: Read>(
+    reader: &mut R,
+    message: &mut Vec<u8>,
+    body: &mut Vec<u8>,
+) -> Result<bool> {
+    let mut meta_length: [u8; 4] = [0; 4];
+    match reader.read_exact(&mut meta_length) {
+        Ok(()) => (),
+        Err(e) => {
+            return if e.kind() == std::io::ErrorKind::UnexpectedEof {
+                // a stream without the "0xFFFFFFFF 0x00000000" end-of-stream marker
+                Ok(false)
+            } else {
+                Err(ArrowError::from(e))
+            };
+        }
+    }
+    // If a continuation marker is encountered, skip over it and read
+    // the size from the next four bytes.
+    if meta_length == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_length)?;
+    }
+    let meta_length = i32::from_le_bytes(meta_length);
+    if meta_length < 0 {
+        return Err(ArrowError::oos(
+            "IPC: a message can't have a negative length",
+        ));
+    }
+    if meta_length == 0 {
+        return Ok(false);
+    }
+
+    message.clear();
+    message.resize(meta_length as usize, 0);
+    reader.read_exact(message)?;
+
+    let body_length = arrow_format::ipc::MessageRef::read_as_root(message)
+        .map_err(|err| ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err)))?
+        .body_length()?;
+    if body_length < 0 {
+        return Err(ArrowError::oos(
+            "IPC: a message's body can't have a negative length",
+        ));
+    }
+
+    body.clear();
+    body.resize(body_length as usize, 0);
+    reader.read_exact(body)?;
+    Ok(true)
+}
+
+/// Writes a message with the continuation marker, padding its flatbuffer to 8 bytes.
+/// Returns the number of bytes written for the metadata (including its prefix) and the body.
+fn write_stream_message<W: Write>(
+    writer: &mut W,
+    message: &[u8],
+    body: &[u8],
+) -> Result<(usize, usize)> {
+    let padding = pad_to_8(message.len());
+    write_continuation(writer, (message.len() + padding) as i32)?;
+    writer.write_all(message)?;
+    writer.write_all(&vec![0; padding])?;
+    writer.write_all(body)?;
+    Ok((8 + message.len() + padding, body.len()))
+}
+
+/// Converts an Arrow IPC stream read from `reader` into an Arrow IPC file written to `writer`.
+///
+/// Messages are copied verbatim; no array is deserialized.
+/// # Errors
+/// This function errors if the stream does not start with a schema message, if a message is
+/// not a dictionary batch nor a record batch, or if the stream is otherwise corrupted.
+pub fn stream_to_file<R: Read, W: Write>(reader: &mut R, writer: &mut W) -> Result<()> {
+    let mut message = vec![];
+    let mut body = vec![];
+
+    if !read_stream_message(reader, &mut message, &mut body)? {
+        return Err(ArrowError::oos("The IPC stream has no schema message"));
+    }
+    let schema: arrow_format::ipc::Schema = {
+        let message = arrow_format::ipc::MessageRef::read_as_root(&message).map_err(|err| {
+            ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+        })?;
+        let header = message
+            .header()?
+            .ok_or_else(|| ArrowError::oos("Unable to read the first IPC message"))?;
+        if let arrow_format::ipc::MessageHeaderRef::Schema(schema) = header {
+            schema.try_into()?
+        } else {
+            return Err(ArrowError::oos(
+                "The first IPC message of the stream must be a schema",
+            ));
+        }
+    };
+
+    // write magic to header and create an 8-byte boundary after it
+    writer.write_all(&ARROW_MAGIC)?;
+    writer.write_all(&[0, 0])?;
+    let (meta, data) = write_stream_message(writer, &message, &body)?;
+    let mut block_offsets = ARROW_MAGIC.len() + 2 + meta + data;
+
+    let mut dictionary_blocks = vec![];
+    let mut record_blocks = vec![];
+    while read_stream_message(reader, &mut message, &mut body)? {
+        let is_dictionary = {
+            let message = arrow_format::ipc::MessageRef::read_as_root(&message).map_err(|err| {
+                ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+            })?;
+            let header = message.header()?.ok_or_else(|| {
+                ArrowError::oos(
+                    "IPC: unable to fetch the message header. The file or stream is corrupted.",
+                )
+            })?;
+            match header {
+                arrow_format::ipc::MessageHeaderRef::DictionaryBatch(_) => true,
+                arrow_format::ipc::MessageHeaderRef::RecordBatch(_) => false,
+                t => {
+                    return Err(ArrowError::OutOfSpec(format!(
+                        "Expecting a DictionaryBatch or a RecordBatch, found {:?}.",
+                        t
+                    )))
+                }
+            }
+        };
+
+        let (meta, data) = write_stream_message(writer, &message, &body)?;
+        let block = arrow_format::ipc::Block {
+            offset: block_offsets as i64,
+            meta_data_length: meta as i32,
+            body_length: data as i64,
+        };
+        if is_dictionary {
+            dictionary_blocks.push(block);
+        } else {
+            record_blocks.push(block);
+        }
+        block_offsets += meta + data;
+    }
+
+    // write EOS
+    write_continuation(writer, 0)?;
+
+    let root = arrow_format::ipc::Footer {
+        version: arrow_format::ipc::MetadataVersion::V5,
+        schema: Some(Box::new(schema)),
+        dictionaries: Some(dictionary_blocks),
+        record_batches: Some(record_blocks),
+        custom_metadata: None,
+    };
+    let mut builder = Builder::new();
+    let footer_data = builder.finish(&root, None);
+    writer.write_all(footer_data)?;
+    writer.write_all(&(footer_data.len() as i32).to_le_bytes())?;
+    writer.write_all(&ARROW_MAGIC)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Copies the message starting at `offset` in `reader` to `writer`.
+fn copy_message<R: Read + Seek, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    offset: u64,
+    message: &mut Vec<u8>,
+    body: &mut Vec<u8>,
+) -> Result<()> {
+    reader.seek(SeekFrom::Start(offset))?;
+    if !read_stream_message(reader, message, body)? {
+        return Err(ArrowError::oos(
+            "The IPC file's footer points to an empty message",
+        ));
+    }
+    write_stream_message(writer, message, body)?;
+    Ok(())
+}
+
+/// Converts an Arrow IPC file read from `reader` into an Arrow IPC stream written to `writer`.
+///
+/// Messages are copied verbatim in the order they were written to the file,
+/// so that every dictionary batch precedes the record batches that use it.
+/// No array is deserialized.
+/// # Errors
+/// This function errors if the file's magic bytes or footer are invalid.
+pub fn file_to_stream<R: Read + Seek, W: Write>(reader: &mut R, writer: &mut W) -> Result<()> {
+    let footer_data = read_footer_data(reader)?;
+    let footer = arrow_format::ipc::FooterRef::read_as_root(&footer_data)
+        .map_err(|err| ArrowError::OutOfSpec(format!("Unable to get root as footer: {:?}", err)))?;
+
+    let mut blocks = footer
+        .record_batches()?
+        .ok_or_else(|| {
+            ArrowError::OutOfSpec("Unable to get record batches from footer".to_string())
+        })?
+        .iter()
+        .map(|block| Ok(block.try_into()?))
+        .collect::<Result<Vec<arrow_format::ipc::Block>>>()?;
+    if let Some(dictionaries) = footer.dictionaries()? {
+        for block in dictionaries {
+            blocks.push(block.try_into()?);
+        }
+    }
+    blocks.sort_by_key(|block| block.offset);
+
+    let mut message = vec![];
+    let mut body = vec![];
+
+    // the schema message is right after the magic and its padding
+    copy_message(
+        reader,
+        writer,
+        (ARROW_MAGIC.len() + 2) as u64,
+        &mut message,
+        &mut body,
+    )?;
+    for block in blocks {
+        copy_message(reader, writer, block.offset as u64, &mut message, &mut body)?;
+    }
+
+    // write EOS
+    write_continuation(writer, 0)?;
+    writer.flush()?;
+    Ok(())
+}
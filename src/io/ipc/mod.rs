@@ -75,12 +75,16 @@
 //! ([1](https://github.com/jorgecarleitao/arrow2/blob/main/examples/ipc_file_read.rs),
 //! [2](https://github.com/jorgecarleitao/arrow2/blob/main/examples/ipc_file_write.rs),
 //! [3](https://github.com/jorgecarleitao/arrow2/tree/main/examples/ipc_pyarrow)).
+//!
+//! To convert between the stream and file formats without deserializing them, use
+//! [`convert::stream_to_file`] and [`convert::file_to_stream`].
 
 use crate::error::ArrowError;
 
 mod compression;
 mod endianess;
 
+pub mod convert;
 pub mod read;
 pub mod write;
 
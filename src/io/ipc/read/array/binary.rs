@@ -1,5 +1,4 @@
 use std::collections::VecDeque;
-use std::io::{Read, Seek};
 
 use crate::array::{BinaryArray, Offset};
 use crate::buffer::Buffer;
@@ -9,7 +8,7 @@ use crate::error::{ArrowError, Result};
 use super::super::read_basic::*;
 use super::super::{Compression, IpcBuffer, Node};
 
-pub fn read_binary<O: Offset, R: Read + Seek>(
+pub fn read_binary<O: Offset, R: IpcReader>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -1,16 +1,16 @@
 use std::collections::{HashSet, VecDeque};
 use std::convert::TryInto;
-use std::io::{Read, Seek};
 
 use crate::array::{DictionaryArray, DictionaryKey};
 use crate::error::{ArrowError, Result};
 
+use super::super::read_basic::IpcReader;
 use super::super::Dictionaries;
 use super::super::{Compression, IpcBuffer, Node};
 use super::{read_primitive, skip_primitive};
 
 #[allow(clippy::too_many_arguments)]
-pub fn read_dictionary<T: DictionaryKey, R: Read + Seek>(
+pub fn read_dictionary<T: DictionaryKey, R: IpcReader>(
     field_nodes: &mut VecDeque<Node>,
     id: Option<i64>,
     buffers: &mut VecDeque<IpcBuffer>,
@@ -1,4 +1,3 @@
-use std::io::{Read, Seek};
 use std::{collections::VecDeque, convert::TryInto};
 
 use crate::datatypes::DataType;
@@ -8,7 +7,7 @@ use crate::{array::PrimitiveArray, types::NativeType};
 use super::super::read_basic::*;
 use super::super::{Compression, IpcBuffer, Node};
 
-pub fn read_primitive<T: NativeType, R: Read + Seek>(
+pub fn read_primitive<T: NativeType, R: IpcReader>(
     field_nodes: &mut VecDeque<Node>,
     data_type: DataType,
     buffers: &mut VecDeque<IpcBuffer>,
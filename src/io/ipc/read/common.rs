@@ -11,6 +11,7 @@ use crate::error::{ArrowError, Result};
 use crate::io::ipc::{IpcField, IpcSchema};
 
 use super::deserialize::{read, skip};
+use super::read_basic::IpcReader;
 use super::Dictionaries;
 
 type ArrayRef = Arc<dyn Array>;
@@ -78,6 +79,31 @@ impl<'a, A, I: Iterator<Item = A>> Iterator for ProjectionIter<'a, A, I> {
 /// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
 #[allow(clippy::too_many_arguments)]
 pub fn read_record_batch<R: Read + Seek>(
+    batch: arrow_format::ipc::RecordBatchRef,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    projection: Option<&[usize]>,
+    dictionaries: &Dictionaries,
+    version: arrow_format::ipc::MetadataVersion,
+    mut reader: &mut R,
+    block_offset: u64,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    deserialize_record_batch(
+        batch,
+        fields,
+        ipc_schema,
+        projection,
+        dictionaries,
+        version,
+        &mut reader,
+        block_offset,
+    )
+}
+
+/// Same as [`read_record_batch`], but reading the buffers from an [`IpcReader`], which may
+/// not copy them.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn deserialize_record_batch<R: IpcReader>(
     batch: arrow_format::ipc::RecordBatchRef,
     fields: &[Field],
     ipc_schema: &IpcSchema,
@@ -201,21 +227,37 @@ fn first_dict_field<'a>(
 }
 
 /// Read the dictionary from the buffer and provided metadata,
-/// updating the `dictionaries` with the resulting dictionary
+/// updating the `dictionaries` with the resulting dictionary.
+/// The values of a delta dictionary batch are appended to the existing dictionary with the same id,
+/// while the values of other dictionary batches replace it.
 pub fn read_dictionary<R: Read + Seek>(
     batch: arrow_format::ipc::DictionaryBatchRef,
     fields: &[Field],
     ipc_schema: &IpcSchema,
     dictionaries: &mut Dictionaries,
-    reader: &mut R,
+    mut reader: &mut R,
     block_offset: u64,
 ) -> Result<()> {
-    if batch.is_delta()? {
-        return Err(ArrowError::NotYetImplemented(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
+    deserialize_dictionary(
+        batch,
+        fields,
+        ipc_schema,
+        dictionaries,
+        &mut reader,
+        block_offset,
+    )
+}
 
+/// Same as [`read_dictionary`], but reading the buffers from an [`IpcReader`], which may not
+/// copy them.
+pub(super) fn deserialize_dictionary<R: IpcReader>(
+    batch: arrow_format::ipc::DictionaryBatchRef,
+    fields: &[Field],
+    ipc_schema: &IpcSchema,
+    dictionaries: &mut Dictionaries,
+    reader: &mut R,
+    block_offset: u64,
+) -> Result<()> {
     let id = batch.id()?;
     let (first_field, first_ipc_field) = first_dict_field(id, fields, &ipc_schema.fields)?;
 
@@ -230,7 +272,7 @@ pub fn read_dictionary<R: Read + Seek>(
                 fields: vec![first_ipc_field.clone()],
                 is_little_endian: ipc_schema.is_little_endian,
             };
-            let columns = read_record_batch(
+            let columns = deserialize_record_batch(
                 batch
                     .data()?
                     .ok_or_else(|| ArrowError::oos("The dictionary batch must have data."))?,
@@ -251,11 +293,32 @@ pub fn read_dictionary<R: Read + Seek>(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    let dictionary_values = if batch.is_delta()? {
+        let existing = dictionaries.get(&id).ok_or_else(|| {
+            ArrowError::OutOfSpec(format!(
+                "The delta dictionary batch with id {} has no dictionary to be appended to",
+                id
+            ))
+        })?;
+        append(existing.as_ref(), dictionary_values.as_ref())
+    } else {
+        dictionary_values
+    };
+
     dictionaries.insert(id, dictionary_values);
 
     Ok(())
 }
 
+/// Returns the values of `delta` appended to the values of `existing`, of the same data type.
+fn append(existing: &dyn Array, delta: &dyn Array) -> ArrayRef {
+    let mut growable =
+        growable::make_growable(&[existing, delta], false, existing.len() + delta.len());
+    growable.extend(0, 0, existing.len());
+    growable.extend(1, 0, delta.len());
+    growable.as_arc()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,8 +1,5 @@
 use std::collections::VecDeque;
-use std::{
-    io::{Read, Seek},
-    sync::Arc,
-};
+use std::sync::Arc;
 
 use arrow_format::ipc::BodyCompressionRef;
 use arrow_format::ipc::MetadataVersion;
@@ -12,11 +9,12 @@ use crate::datatypes::{DataType, Field, PhysicalType};
 use crate::error::Result;
 use crate::io::ipc::IpcField;
 
+use super::read_basic::IpcReader;
 use super::{array::*, Dictionaries};
 use super::{IpcBuffer, Node};
 
 #[allow(clippy::too_many_arguments)]
-pub fn read<R: Read + Seek>(
+pub fn read<R: IpcReader>(
     field_nodes: &mut VecDeque<Node>,
     field: &Field,
     ipc_field: &IpcField,
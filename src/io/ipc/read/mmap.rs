@@ -0,0 +1,129 @@
+//! APIs to read Arrow IPC files from memory (e.g. a memory-mapped file) without copying their
+//! buffers.
+//!
+//! The buffers of the arrays read point directly to the memory of the file, which is kept
+//! alive for as long as any of them is. Only buffers that are uncompressed, in the native
+//! endianness and aligned to their type are not copied; other buffers are copied as usual.
+//!
+//! # Example
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use arrow2::io::ipc::read::mmap::{mmap_batch, mmap_file_metadata};
+//! # fn main() -> arrow2::error::Result<()> {
+//! // any `AsRef<[u8]> + Send + Sync`, e.g. a `memmap2::Mmap`
+//! let data: Arc<Vec<u8>> = Arc::new(std::fs::read("data.arrow")?);
+//!
+//! let metadata = mmap_file_metadata(&data)?;
+//! for block in 0..metadata.num_batches() {
+//!     let chunk = mmap_batch(&data, &metadata, None, block)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use crate::array::Array;
+use crate::buffer::bytes::Bytes;
+use crate::chunk::Chunk;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::read_basic::IpcReader;
+use super::reader::{deserialize_batch, deserialize_file_metadata};
+use super::FileMetadata;
+
+/// A [`Read`] and [`Seek`] over shared bytes, whose buffers are read without copying them.
+struct Mapped<O: AsRef<[u8]> + Send + Sync + 'static> {
+    data: Arc<O>,
+    position: u64,
+}
+
+impl<O: AsRef<[u8]> + Send + Sync + 'static> Mapped<O> {
+    fn new(data: &Arc<O>) -> Self {
+        Self {
+            data: data.clone(),
+            position: 0,
+        }
+    }
+}
+
+impl<O: AsRef<[u8]> + Send + Sync + 'static> Read for Mapped<O> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let data: &[u8] = (*self.data).as_ref();
+        let start = (self.position as usize).min(data.len());
+        let length = buf.len().min(data.len() - start);
+        buf[..length].copy_from_slice(&data[start..start + length]);
+        self.position += length as u64;
+        Ok(length)
+    }
+}
+
+impl<O: AsRef<[u8]> + Send + Sync + 'static> Seek for Mapped<O> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let length = (*self.data).as_ref().len() as u64;
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => (length as i64).checked_add(offset),
+            SeekFrom::Current(offset) => (self.position as i64).checked_add(offset),
+        };
+        match position {
+            Some(position) if position >= 0 => {
+                self.position = position as u64;
+                Ok(self.position)
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
+
+impl<O: AsRef<[u8]> + Send + Sync + 'static> IpcReader for Mapped<O> {
+    fn zero_copy<T: NativeType>(&mut self, offset: u64, length: usize) -> Option<Bytes<T>> {
+        Bytes::from_owner(self.data.clone(), offset as usize, length)
+    }
+}
+
+/// Reads the [`FileMetadata`] of the Arrow IPC file in `data`.
+///
+/// The buffers of its dictionaries point to `data` (see the [module documentation](self)).
+/// # Errors
+/// This function errors iff `data` is not a valid Arrow IPC file.
+pub fn mmap_file_metadata<O: AsRef<[u8]> + Send + Sync + 'static>(
+    data: &Arc<O>,
+) -> Result<FileMetadata> {
+    deserialize_file_metadata(&mut Mapped::new(data))
+}
+
+/// Reads the record batch at `block` of the Arrow IPC file in `data`, whose metadata is
+/// `metadata` (e.g. read with [`mmap_file_metadata`]). Use `projection` to only read certain
+/// columns.
+///
+/// The buffers of the arrays point to `data` (see the [module documentation](self)).
+/// # Errors
+/// This function errors iff `block` is out of bounds or the batch is invalid.
+/// # Panic
+/// Panics iff the projection is not in increasing order (e.g. `[1, 0]` nor `[0, 1, 1]` are valid)
+pub fn mmap_batch<O: AsRef<[u8]> + Send + Sync + 'static>(
+    data: &Arc<O>,
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+    block: usize,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    if block >= metadata.num_batches() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The block {} is out of bounds of a file with {} record batches",
+            block,
+            metadata.num_batches()
+        )));
+    }
+    deserialize_batch(
+        &mut Mapped::new(data),
+        metadata,
+        projection,
+        block,
+        &mut vec![],
+    )
+}
@@ -4,6 +4,10 @@
 //! which provides arbitrary access to any of its messages, and the
 //! [`StreamReader`](stream::StreamReader), which only supports reading
 //! data in the order it was written in.
+//!
+//! The [`FileReader`](reader::FileReader) reads from any [`Read`](std::io::Read) +
+//! [`Seek`](std::io::Seek), e.g. a [`ReadableFile`](crate::io::range::ReadableFile) over a
+//! source that supports reading ranges of bytes, such as an object store.
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -12,12 +16,17 @@ use crate::array::Array;
 mod array;
 mod common;
 mod deserialize;
+pub mod mmap;
 mod read_basic;
 mod reader;
 mod schema;
 mod stream;
+#[cfg(feature = "io_ipc_read_async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_ipc_read_async")))]
+pub mod stream_async;
 
 pub use common::{read_dictionary, read_record_batch};
+pub(crate) use reader::read_footer_data;
 pub use reader::{read_file_metadata, FileMetadata, FileReader};
 pub use schema::deserialize_schema;
 pub use stream::{read_stream_metadata, StreamMetadata, StreamReader, StreamState};
@@ -1,7 +1,7 @@
 use std::io::{Read, Seek, SeekFrom};
 use std::{collections::VecDeque, convert::TryInto};
 
-use crate::buffer::Buffer;
+use crate::buffer::{bytes::Bytes, Buffer};
 use crate::error::{ArrowError, Result};
 use crate::{bitmap::Bitmap, types::NativeType};
 
@@ -9,6 +9,17 @@ use super::super::compression;
 use super::super::endianess::is_native_little_endian;
 use super::{Compression, IpcBuffer, Node};
 
+/// A reader of the bodies of IPC messages, that may read buffers without copying them.
+pub trait IpcReader: Read + Seek {
+    /// Returns the `length` slots starting at byte `offset` of the source without copying them,
+    /// or `None` if this is not supported (e.g. because they are not aligned).
+    fn zero_copy<T: NativeType>(&mut self, _offset: u64, _length: usize) -> Option<Bytes<T>> {
+        None
+    }
+}
+
+impl<R: Read + Seek> IpcReader for &mut R {}
+
 fn read_swapped<T: NativeType, R: Read + Seek>(
     reader: &mut R,
     length: usize,
@@ -78,6 +89,51 @@ fn read_uncompressed_buffer<T: NativeType, R: Read + Seek>(
     Ok(buffer)
 }
 
+/// Decompresses `slice`, a buffer compressed with `compression`, into `out_slice`.
+/// As declared in the specification, the buffer is prefixed by its uncompressed length, or by
+/// `-1` when it was written uncompressed. An empty buffer has no prefix.
+fn decompress(slice: &[u8], out_slice: &mut [u8], compression: Compression) -> Result<()> {
+    if slice.is_empty() {
+        return if out_slice.is_empty() {
+            Ok(())
+        } else {
+            Err(ArrowError::oos(
+                "IPC: a compressed buffer is empty but the array requires values",
+            ))
+        };
+    }
+    if slice.len() < 8 {
+        return Err(ArrowError::oos(
+            "IPC: a compressed buffer must start with its uncompressed length",
+        ));
+    }
+    let uncompressed_length = i64::from_le_bytes(slice[..8].try_into().unwrap());
+    let slice = &slice[8..];
+
+    if uncompressed_length == -1 {
+        // the buffer was written uncompressed
+        let slice = slice.get(..out_slice.len()).ok_or_else(|| {
+            ArrowError::oos("IPC: an uncompressed buffer is smaller than required by the array")
+        })?;
+        out_slice.copy_from_slice(slice);
+        return Ok(());
+    }
+    if uncompressed_length < out_slice.len() as i64 {
+        return Err(ArrowError::oos(format!(
+            "IPC: a compressed buffer declares {} bytes but the array requires {} bytes",
+            uncompressed_length,
+            out_slice.len()
+        )));
+    }
+
+    match compression.codec()? {
+        arrow_format::ipc::CompressionType::Lz4Frame => {
+            compression::decompress_lz4(slice, out_slice)
+        }
+        arrow_format::ipc::CompressionType::Zstd => compression::decompress_zstd(slice, out_slice),
+    }
+}
+
 fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     reader: &mut R,
     buffer_length: usize,
@@ -85,9 +141,9 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
     is_little_endian: bool,
     compression: Compression,
 ) -> Result<Vec<T>> {
-    if is_little_endian != is_native_little_endian() {
+    if is_little_endian && !is_native_little_endian() {
         return Err(ArrowError::NotYetImplemented(
-            "Reading compressed and big endian IPC".to_string(),
+            "Reading little endian files from big endian machines".to_string(),
         ));
     }
 
@@ -102,19 +158,18 @@ fn read_compressed_buffer<T: NativeType, R: Read + Seek>(
 
     let out_slice = bytemuck::cast_slice_mut(&mut buffer);
 
-    match compression.codec()? {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&slice[8..], out_slice)?;
-            Ok(buffer)
-        }
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&slice[8..], out_slice)?;
-            Ok(buffer)
-        }
+    decompress(&slice, out_slice, compression)?;
+
+    if !is_little_endian && is_native_little_endian() {
+        // machine is little endian, file is big endian
+        buffer
+            .iter_mut()
+            .for_each(|slot| *slot = T::from_be_bytes(slot.to_le_bytes()));
     }
+    Ok(buffer)
 }
 
-pub fn read_buffer<T: NativeType, R: Read + Seek>(
+pub fn read_buffer<T: NativeType, R: IpcReader>(
     buf: &mut VecDeque<IpcBuffer>,
     length: usize, // in slots
     reader: &mut R,
@@ -126,7 +181,8 @@ pub fn read_buffer<T: NativeType, R: Read + Seek>(
         .pop_front()
         .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
 
-    reader.seek(SeekFrom::Start(block_offset + buf.offset() as u64))?;
+    let offset = block_offset + buf.offset() as u64;
+    reader.seek(SeekFrom::Start(offset))?;
 
     let buffer_length = buf.length() as usize;
 
@@ -136,6 +192,14 @@ pub fn read_buffer<T: NativeType, R: Read + Seek>(
                 .into(),
         )
     } else {
+        let fits = length
+            .checked_mul(std::mem::size_of::<T>())
+            .map_or(false, |bytes| bytes <= buffer_length);
+        if fits && is_native_little_endian() == is_little_endian {
+            if let Some(bytes) = reader.zero_copy(offset, length) {
+                return Ok(Buffer::from_bytes(bytes));
+            }
+        }
         Ok(read_uncompressed_buffer(reader, buffer_length, length, is_little_endian)?.into())
     }
 }
@@ -175,19 +239,11 @@ fn read_compressed_bitmap<R: Read + Seek>(
     let mut slice = vec![0u8; bytes];
     reader.read_exact(&mut slice)?;
 
-    match compression.codec()? {
-        arrow_format::ipc::CompressionType::Lz4Frame => {
-            compression::decompress_lz4(&slice[8..], &mut buffer)?;
-            Ok(buffer)
-        }
-        arrow_format::ipc::CompressionType::Zstd => {
-            compression::decompress_zstd(&slice[8..], &mut buffer)?;
-            Ok(buffer)
-        }
-    }
+    decompress(&slice, &mut buffer, compression)?;
+    Ok(buffer)
 }
 
-pub fn read_bitmap<R: Read + Seek>(
+pub fn read_bitmap<R: IpcReader>(
     buf: &mut VecDeque<IpcBuffer>,
     length: usize,
     reader: &mut R,
@@ -199,10 +255,17 @@ pub fn read_bitmap<R: Read + Seek>(
         .pop_front()
         .ok_or_else(|| ArrowError::oos("IPC: unable to fetch a buffer. The file is corrupted."))?;
 
-    reader.seek(SeekFrom::Start(block_offset + buf.offset() as u64))?;
+    let offset = block_offset + buf.offset() as u64;
+    reader.seek(SeekFrom::Start(offset))?;
 
     let bytes = buf.length() as usize;
 
+    if compression.is_none() && length <= bytes * 8 {
+        if let Some(bytes) = reader.zero_copy(offset, bytes) {
+            return Ok(Bitmap::from_bytes(bytes, length));
+        }
+    }
+
     let buffer = if let Some(compression) = compression {
         read_compressed_bitmap(length, bytes, compression, reader)
     } else {
@@ -212,7 +275,7 @@ pub fn read_bitmap<R: Read + Seek>(
     Ok(Bitmap::from_bytes(buffer.into(), length))
 }
 
-pub fn read_validity<R: Read + Seek>(
+pub fn read_validity<R: IpcReader>(
     buffers: &mut VecDeque<IpcBuffer>,
     field_node: Node,
     reader: &mut R,
@@ -2,14 +2,16 @@ use std::convert::TryInto;
 use std::io::{Read, Seek, SeekFrom};
 use std::sync::Arc;
 
-use crate::array::Array;
+use crate::array::{Array, PrimitiveArray};
 use crate::chunk::Chunk;
-use crate::datatypes::{Field, Schema};
+use crate::datatypes::{DataType, Field, Schema, TimeUnit};
 use crate::error::{ArrowError, Result};
 use crate::io::ipc::IpcSchema;
+use crate::temporal_conversions::timestamp_to_time_unit;
 
 use super::super::{ARROW_MAGIC, CONTINUATION_MARKER};
 use super::common::*;
+use super::read_basic::IpcReader;
 use super::schema::fb_to_schema;
 use super::Dictionaries;
 use arrow_format::ipc::planus::{ReadAsRoot, Vector};
@@ -31,12 +33,21 @@ pub struct FileMetadata {
     dictionaries: Dictionaries,
 }
 
+impl FileMetadata {
+    /// Returns the number of record batches (blocks) of the file.
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+}
+
 /// Arrow File reader
 pub struct FileReader<R: Read + Seek> {
     reader: R,
     metadata: FileMetadata,
     current_block: usize,
     projection: Option<(Vec<usize>, Schema)>,
+    // the unit that timestamps are converted to, and the resulting schema
+    timestamp_time_unit: Option<(TimeUnit, Schema)>,
     buffer: Vec<u8>,
 }
 
@@ -61,7 +72,7 @@ fn read_dictionary_message<R: Read + Seek>(
     Ok(())
 }
 
-fn read_dictionaries<R: Read + Seek>(
+fn read_dictionaries<R: IpcReader>(
     reader: &mut R,
     fields: &[Field],
     ipc_schema: &IpcSchema,
@@ -86,7 +97,7 @@ fn read_dictionaries<R: Read + Seek>(
         match header {
             arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch) => {
                 let block_offset = offset + length;
-                read_dictionary(
+                deserialize_dictionary(
                     batch,
                     fields,
                     ipc_schema,
@@ -106,8 +117,8 @@ fn read_dictionaries<R: Read + Seek>(
     Ok(dictionaries)
 }
 
-/// Read the IPC file's metadata
-pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata> {
+/// Reads the footer's (flatbuffer) bytes of an IPC file, checking the file's magic bytes.
+pub(crate) fn read_footer_data<R: Read + Seek>(reader: &mut R) -> Result<Vec<u8>> {
     // check if header and footer contain correct magic bytes
     let mut magic_buffer: [u8; 6] = [0; 6];
     reader.read_exact(&mut magic_buffer)?;
@@ -133,6 +144,18 @@ pub fn read_file_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetadata
     let mut footer_data = vec![0; footer_len as usize];
     reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
     reader.read_exact(&mut footer_data)?;
+    Ok(footer_data)
+}
+
+/// Read the IPC file's metadata
+pub fn read_file_metadata<R: Read + Seek>(mut reader: &mut R) -> Result<FileMetadata> {
+    deserialize_file_metadata(&mut reader)
+}
+
+/// Same as [`read_file_metadata`], but reading the dictionaries from an [`IpcReader`], which
+/// may not copy them.
+pub(super) fn deserialize_file_metadata<R: IpcReader>(reader: &mut R) -> Result<FileMetadata> {
+    let footer_data = read_footer_data(reader)?;
 
     let footer = arrow_format::ipc::FooterRef::read_as_root(&footer_data)
         .map_err(|err| ArrowError::OutOfSpec(format!("Unable to get root as footer: {:?}", err)))?;
@@ -185,6 +208,18 @@ fn get_serialized_batch<'a>(
 
 /// Read a batch from the reader.
 pub fn read_batch<R: Read + Seek>(
+    mut reader: &mut R,
+    metadata: &FileMetadata,
+    projection: Option<&[usize]>,
+    block: usize,
+    block_data: &mut Vec<u8>,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    deserialize_batch(&mut reader, metadata, projection, block, block_data)
+}
+
+/// Same as [`read_batch`], but reading the buffers from an [`IpcReader`], which may not copy
+/// them.
+pub(super) fn deserialize_batch<R: IpcReader>(
     reader: &mut R,
     metadata: &FileMetadata,
     projection: Option<&[usize]>,
@@ -212,7 +247,7 @@ pub fn read_batch<R: Read + Seek>(
 
     let batch = get_serialized_batch(&message)?;
 
-    read_record_batch(
+    deserialize_record_batch(
         batch,
         &metadata.schema.fields,
         &metadata.ipc_schema,
@@ -252,6 +287,7 @@ impl<R: Read + Seek> FileReader<R> {
             reader,
             metadata,
             projection,
+            timestamp_time_unit: None,
             current_block: 0,
             buffer: vec![],
         }
@@ -259,12 +295,40 @@ impl<R: Read + Seek> FileReader<R> {
 
     /// Return the schema of the file
     pub fn schema(&self) -> &Schema {
+        if let Some((_, schema)) = &self.timestamp_time_unit {
+            return schema;
+        }
         self.projection
             .as_ref()
             .map(|x| &x.1)
             .unwrap_or(&self.metadata.schema)
     }
 
+    /// Sets the [`TimeUnit`] of all (top-level) timestamp fields, so that the chunks read have
+    /// a single unit regardless of the units of the file.
+    /// Timestamps are truncated when converted to a coarser unit, and reading errors with
+    /// [`ArrowError::Overflow`] when a timestamp does not fit in a finer unit.
+    pub fn set_timestamp_time_unit(&mut self, time_unit: TimeUnit) {
+        self.timestamp_time_unit = None;
+        let schema = self.schema();
+        let fields = schema
+            .fields
+            .iter()
+            .map(|field| {
+                let mut field = field.clone();
+                if let DataType::Timestamp(_, tz) = &field.data_type {
+                    field.data_type = DataType::Timestamp(time_unit, tz.clone());
+                }
+                field
+            })
+            .collect();
+        let schema = Schema {
+            fields,
+            metadata: schema.metadata.clone(),
+        };
+        self.timestamp_time_unit = Some((time_unit, schema));
+    }
+
     /// Returns the [`FileMetadata`]
     pub fn metadata(&self) -> &FileMetadata {
         &self.metadata
@@ -284,15 +348,43 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
         if self.current_block < self.metadata.blocks.len() {
             let block = self.current_block;
             self.current_block += 1;
-            Some(read_batch(
+            let chunk = read_batch(
                 &mut self.reader,
                 &self.metadata,
                 self.projection.as_ref().map(|x| x.0.as_ref()),
                 block,
                 &mut self.buffer,
-            ))
+            );
+            Some(match &self.timestamp_time_unit {
+                Some((time_unit, _)) => {
+                    chunk.and_then(|chunk| coerce_timestamps(chunk, *time_unit))
+                }
+                None => chunk,
+            })
         } else {
             None
         }
     }
 }
+
+/// Converts the (top-level) timestamp arrays of `chunk` to `time_unit`.
+fn coerce_timestamps(
+    chunk: Chunk<Arc<dyn Array>>,
+    time_unit: TimeUnit,
+) -> Result<Chunk<Arc<dyn Array>>> {
+    let arrays = chunk
+        .into_arrays()
+        .into_iter()
+        .map(|array| match array.data_type() {
+            DataType::Timestamp(unit, _) if *unit != time_unit => {
+                let array = array
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i64>>()
+                    .unwrap();
+                Ok(Arc::new(timestamp_to_time_unit(array, time_unit)?) as Arc<dyn Array>)
+            }
+            _ => Ok(array),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
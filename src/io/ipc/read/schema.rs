@@ -2,7 +2,7 @@ use arrow_format::ipc::planus::ReadAsRoot;
 
 use crate::{
     datatypes::{
-        get_extension, DataType, Extension, Field, IntegerType, IntervalUnit, Metadata, Schema,
+        take_extension, DataType, Extension, Field, IntegerType, IntervalUnit, Metadata, Schema,
         TimeUnit, UnionMode,
     },
     error::{ArrowError, Result},
@@ -23,9 +23,10 @@ fn try_unzip_vec<A, B, I: Iterator<Item = Result<(A, B)>>>(iter: I) -> Result<(V
 }
 
 fn deserialize_field(ipc_field: arrow_format::ipc::FieldRef) -> Result<(Field, IpcField)> {
-    let metadata = read_metadata(&ipc_field)?;
+    let mut metadata = read_metadata(&ipc_field)?;
 
-    let extension = get_extension(&metadata);
+    // the extension is restored as a data type and thus not kept in the metadata
+    let extension = take_extension(&mut metadata);
 
     let (data_type, ipc_field_) = get_data_type(ipc_field, extension, true)?;
 
@@ -42,10 +42,14 @@ pub fn read_stream_metadata<R: Read>(reader: &mut R) -> Result<StreamMetadata> {
     let mut meta_buffer = vec![0; meta_len as usize];
     reader.read_exact(&mut meta_buffer)?;
 
-    let message =
-        arrow_format::ipc::MessageRef::read_as_root(meta_buffer.as_slice()).map_err(|err| {
-            ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
-        })?;
+    deserialize_stream_metadata(&meta_buffer)
+}
+
+/// Deserializes the metadata of a stream from its first message
+pub(super) fn deserialize_stream_metadata(meta_buffer: &[u8]) -> Result<StreamMetadata> {
+    let message = arrow_format::ipc::MessageRef::read_as_root(meta_buffer).map_err(|err| {
+        ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err))
+    })?;
     let version = message.version()?;
     // message header is a Schema, so read it
     let header = message
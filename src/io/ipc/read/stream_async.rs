@@ -0,0 +1,217 @@
+//! APIs to read Arrow streams asynchronously
+use std::sync::Arc;
+
+use arrow_format::ipc::planus::ReadAsRoot;
+use futures::future::BoxFuture;
+use futures::AsyncRead;
+use futures::AsyncReadExt;
+use futures::Stream;
+
+use crate::array::*;
+use crate::chunk::Chunk;
+use crate::error::{ArrowError, Result};
+
+use super::super::CONTINUATION_MARKER;
+use super::common::{read_dictionary, read_record_batch};
+use super::stream::{deserialize_stream_metadata, StreamMetadata};
+use super::Dictionaries;
+
+/// The state of an Arrow stream
+struct ReadState<R> {
+    reader: R,
+    metadata: StreamMetadata,
+    dictionaries: Dictionaries,
+    /// The internal buffer to read data inside the messages (records and dictionaries) to
+    data_buffer: Vec<u8>,
+    /// The internal buffer to read messages to
+    message_buffer: Vec<u8>,
+}
+
+/// The state of an Arrow stream after the next message was read
+enum StreamState<R> {
+    /// The stream does not contain new chunks (and it has not been closed)
+    Waiting(ReadState<R>),
+    /// The stream contains a new chunk
+    Some((ReadState<R>, Chunk<Arc<dyn Array>>)),
+}
+
+/// Reads the [`StreamMetadata`] of the Arrow stream asynchronously
+pub async fn read_stream_metadata_async<R: AsyncRead + Unpin + Send>(
+    reader: &mut R,
+) -> Result<StreamMetadata> {
+    // determine metadata length
+    let mut meta_size: [u8; 4] = [0; 4];
+    reader.read_exact(&mut meta_size).await?;
+    let meta_len = {
+        // If a continuation marker is encountered, skip over it and read
+        // the size from the next four bytes.
+        if meta_size == CONTINUATION_MARKER {
+            reader.read_exact(&mut meta_size).await?;
+        }
+        i32::from_le_bytes(meta_size)
+    };
+
+    let mut meta_buffer = vec![0; meta_len as usize];
+    reader.read_exact(&mut meta_buffer).await?;
+
+    deserialize_stream_metadata(&meta_buffer)
+}
+
+fn read_message(buffer: &[u8]) -> Result<arrow_format::ipc::MessageRef> {
+    arrow_format::ipc::MessageRef::read_as_root(buffer)
+        .map_err(|err| ArrowError::OutOfSpec(format!("Unable to get root as message: {:?}", err)))
+}
+
+/// Reads the next item, yielding `None` if the stream has been closed,
+/// or a [`StreamState`] otherwise.
+async fn maybe_next<R: AsyncRead + Unpin + Send>(
+    mut state: ReadState<R>,
+) -> Result<Option<StreamState<R>>> {
+    loop {
+        // determine metadata length
+        let mut meta_length: [u8; 4] = [0; 4];
+
+        match state.reader.read_exact(&mut meta_length).await {
+            Ok(()) => (),
+            Err(e) => {
+                return if e.kind() == std::io::ErrorKind::UnexpectedEof {
+                    // Handle EOF without the "0xFFFFFFFF 0x00000000"
+                    // valid according to:
+                    // https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+                    Ok(Some(StreamState::Waiting(state)))
+                } else {
+                    Err(ArrowError::from(e))
+                };
+            }
+        }
+
+        let meta_length = {
+            // If a continuation marker is encountered, skip over it and read
+            // the size from the next four bytes.
+            if meta_length == CONTINUATION_MARKER {
+                state.reader.read_exact(&mut meta_length).await?;
+            }
+            i32::from_le_bytes(meta_length) as usize
+        };
+
+        if meta_length == 0 {
+            // the stream has ended, mark the reader as finished
+            return Ok(None);
+        }
+
+        state.message_buffer.clear();
+        state.message_buffer.resize(meta_length, 0);
+        state.reader.read_exact(&mut state.message_buffer).await?;
+
+        // read the block that makes up the record batch or dictionary batch into a buffer
+        let body_length = read_message(&state.message_buffer)?.body_length()?;
+        state.data_buffer.clear();
+        state.data_buffer.resize(body_length as usize, 0);
+        state.reader.read_exact(&mut state.data_buffer).await?;
+        let mut reader = std::io::Cursor::new(&state.data_buffer);
+
+        let header = read_message(&state.message_buffer)?
+            .header()?
+            .ok_or_else(|| {
+                ArrowError::oos(
+                    "IPC: unable to fetch the message header. The file or stream is corrupted.",
+                )
+            })?;
+
+        match header {
+            arrow_format::ipc::MessageHeaderRef::RecordBatch(batch) => {
+                let chunk = read_record_batch(
+                    batch,
+                    &state.metadata.schema.fields,
+                    &state.metadata.ipc_schema,
+                    None,
+                    &state.dictionaries,
+                    state.metadata.version,
+                    &mut reader,
+                    0,
+                )?;
+                return Ok(Some(StreamState::Some((state, chunk))));
+            }
+            arrow_format::ipc::MessageHeaderRef::DictionaryBatch(batch) => {
+                read_dictionary(
+                    batch,
+                    &state.metadata.schema.fields,
+                    &state.metadata.ipc_schema,
+                    &mut state.dictionaries,
+                    &mut reader,
+                    0,
+                )?;
+                // read the next message until we encounter a RecordBatch message
+            }
+            t => {
+                return Err(ArrowError::OutOfSpec(format!(
+                "Reading types other than record batches not yet supported, unable to read {:?} ",
+                t
+            )))
+            }
+        }
+    }
+}
+
+/// A [`Stream`] over an Arrow stream that yields its [`Chunk`]s, read asynchronously.
+///
+/// Unlike [`StreamReader`](super::StreamReader), reaching the end of `reader` without the
+/// end-of-stream marker ends the stream.
+pub struct AsyncStreamReader<R: AsyncRead + Unpin + Send + 'static> {
+    metadata: StreamMetadata,
+    future: Option<BoxFuture<'static, Result<Option<StreamState<R>>>>>,
+}
+
+impl<R: AsyncRead + Unpin + Send + 'static> AsyncStreamReader<R> {
+    /// Creates a new [`AsyncStreamReader`] of `reader`, whose [`StreamMetadata`] was already
+    /// read (e.g. with [`read_stream_metadata_async`]).
+    pub fn new(reader: R, metadata: StreamMetadata) -> Self {
+        let state = ReadState {
+            reader,
+            metadata: metadata.clone(),
+            dictionaries: Default::default(),
+            data_buffer: vec![],
+            message_buffer: vec![],
+        };
+        let future = Some(Box::pin(maybe_next(state)) as _);
+        Self { metadata, future }
+    }
+
+    /// Return the metadata of the stream
+    pub fn metadata(&self) -> &StreamMetadata {
+        &self.metadata
+    }
+}
+
+impl<R: AsyncRead + Unpin + Send + 'static> Stream for AsyncStreamReader<R> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::future::Future;
+        use std::pin::Pin;
+        use std::task::Poll;
+        let me = Pin::into_inner(self);
+
+        match &mut me.future {
+            Some(fut) => match fut.as_mut().poll(cx) {
+                Poll::Ready(Ok(None)) | Poll::Ready(Ok(Some(StreamState::Waiting(_)))) => {
+                    me.future = None;
+                    Poll::Ready(None)
+                }
+                Poll::Ready(Ok(Some(StreamState::Some((state, chunk))))) => {
+                    me.future = Some(Box::pin(maybe_next(state)));
+                    Poll::Ready(Some(Ok(chunk)))
+                }
+                Poll::Ready(Err(err)) => {
+                    me.future = None;
+                    Poll::Ready(Some(Err(err)))
+                }
+                Poll::Pending => Poll::Pending,
+            },
+            None => Poll::Ready(None),
+        }
+    }
+}
@@ -10,7 +10,7 @@ use crate::io::ipc::endianess::is_native_little_endian;
 use crate::io::ipc::read::Dictionaries;
 
 use super::super::IpcField;
-use super::{write, write_dictionary};
+use super::write;
 
 /// Compression codec
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -52,12 +52,15 @@ fn encode_dictionary(
                 encoded_dictionaries
             )?;
 
-            let emit = dictionary_tracker.insert(dict_id, array)?;
-
-            if emit {
+            if let Some(update) = dictionary_tracker.insert(dict_id, array)? {
+                let (values, is_delta) = match &update {
+                    DictionaryUpdate::Replacement(values) => (values, false),
+                    DictionaryUpdate::Delta(values) => (values, true),
+                };
                 encoded_dictionaries.push(dictionary_batch_to_bytes(
                     dict_id,
-                    array.as_ref(),
+                    values.as_ref(),
+                    is_delta,
                     options,
                     is_native_little_endian(),
                 ));
@@ -176,6 +179,12 @@ pub fn encode_chunk(
     dictionary_tracker: &mut DictionaryTracker,
     options: &WriteOptions,
 ) -> Result<(Vec<EncodedData>, EncodedData)> {
+    if options.compression.is_some() && !cfg!(feature = "io_ipc_compression") {
+        return Err(ArrowError::InvalidArgumentError(
+            "The crate was compiled without IPC compression. Use `io_ipc_compression` to write compressed IPC.".to_string(),
+        ));
+    }
+
     let mut encoded_dictionaries = vec![];
 
     for (field, array) in fields.iter().zip(columns.as_ref()) {
@@ -255,10 +264,11 @@ fn columns_to_bytes(columns: &Chunk<Arc<dyn Array>>, options: &WriteOptions) ->
 }
 
 /// Write dictionary values into two sets of bytes, one for the header (ipc::Schema::Message) and the
-/// other for the data
+/// other for the data. When `is_delta`, `values` are appended to the dictionary with the same id.
 fn dictionary_batch_to_bytes(
     dict_id: i64,
-    array: &dyn Array,
+    values: &dyn Array,
+    is_delta: bool,
     options: &WriteOptions,
     is_little_endian: bool,
 ) -> EncodedData {
@@ -266,16 +276,16 @@ fn dictionary_batch_to_bytes(
     let mut buffers: Vec<arrow_format::ipc::Buffer> = vec![];
     let mut arrow_data: Vec<u8> = vec![];
 
-    let length = write_dictionary(
-        array,
+    write(
+        values,
         &mut buffers,
         &mut arrow_data,
         &mut nodes,
         &mut 0,
         is_little_endian,
         options.compression,
-        false,
     );
+    let length = values.len();
 
     let compression = serialize_compression(options.compression);
 
@@ -290,7 +300,7 @@ fn dictionary_batch_to_bytes(
                     buffers: Some(buffers),
                     compression,
                 })),
-                is_delta: false,
+                is_delta,
             },
         ))),
         body_length: arrow_data.len() as i64,
@@ -306,8 +316,20 @@ fn dictionary_batch_to_bytes(
     }
 }
 
+/// The dictionary batch that must be emitted for a dictionary, as returned by
+/// [`DictionaryTracker::insert`].
+#[derive(Debug, Clone)]
+pub enum DictionaryUpdate {
+    /// The dictionary was never emitted or its values changed: emit all its values
+    Replacement(Arc<dyn Array>),
+    /// The values of the dictionary extend the values previously emitted: emit only the new
+    /// values, as a delta
+    Delta(Arc<dyn Array>),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
-/// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
+/// multiple times, and to emit only the new values of dictionaries that grow.
+/// Can optionally error if a replacement of an existing dictionary is attempted, which
 /// isn't allowed in the `FileWriter`.
 pub struct DictionaryTracker {
     written: Dictionaries,
@@ -324,14 +346,19 @@ impl DictionaryTracker {
 
     /// Keep track of the dictionary with the given ID and values. Behavior:
     ///
-    /// * If this ID has been written already and has the same data, return `Ok(false)` to indicate
-    ///   that the dictionary was not actually inserted (because it's already been seen).
+    /// * If this ID has been written already and has the same data, return `Ok(None)` to indicate
+    ///   that the dictionary does not need to be emitted (because it's already been seen).
+    /// * If this ID has been written already and its values are a prefix of the new values,
+    ///   return `Ok(Some(DictionaryUpdate::Delta))` with the new values.
     /// * If this ID has been written already but with different data, and this tracker is
     ///   configured to return an error, return an error.
     /// * If the tracker has not been configured to error on replacement or this dictionary
-    ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
-    ///   inserted.
-    pub fn insert(&mut self, dict_id: i64, array: &Arc<dyn Array>) -> Result<bool> {
+    ///   has never been seen before, return `Ok(Some(DictionaryUpdate::Replacement))`.
+    pub fn insert(
+        &mut self,
+        dict_id: i64,
+        array: &Arc<dyn Array>,
+    ) -> Result<Option<DictionaryUpdate>> {
         let values = match array.data_type() {
             DataType::Dictionary(key_type, _, _) => {
                 match_integer_type!(key_type, |$T| {
@@ -345,23 +372,32 @@ impl DictionaryTracker {
             _ => unreachable!(),
         };
 
-        // If a dictionary with this id was already emitted, check if it was the same.
-        if let Some(last) = self.written.get(&dict_id) {
-            if last.as_ref() == values.as_ref() {
+        // If a dictionary with this id was already emitted, check if it was the same or a prefix.
+        let update = match self.written.get(&dict_id) {
+            Some(last) if last.as_ref() == values.as_ref() => {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
-            } else if self.error_on_replacement {
+                return Ok(None);
+            }
+            Some(last)
+                if last.len() < values.len()
+                    && values.slice(0, last.len()).as_ref() == last.as_ref() =>
+            {
+                let delta = values.slice(last.len(), values.len() - last.len());
+                DictionaryUpdate::Delta(delta.into())
+            }
+            Some(_) if self.error_on_replacement => {
                 return Err(ArrowError::InvalidArgumentError(
                     "Dictionary replacement detected when writing IPC file format. \
                      Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
+                     across all batches, whose values can only be extended."
                         .to_string(),
                 ));
             }
+            _ => DictionaryUpdate::Replacement(values.clone()),
         };
 
         self.written.insert(dict_id, values.clone());
-        Ok(true)
+        Ok(Some(update))
     }
 }
 
@@ -644,15 +644,7 @@ fn write_bytes(
 ) {
     let start = arrow_data.len();
     if let Some(compression) = compression {
-        arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            }
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            }
-        }
+        write_compressed(bytes, arrow_data, compression);
     } else {
         arrow_data.extend_from_slice(bytes);
     };
@@ -760,15 +752,7 @@ fn _write_compressed_buffer_from_iter<T: NativeType, I: TrustedLen<Item = T>>(
             .map(|x| T::to_be_bytes(&x))
             .for_each(|x| swapped.extend_from_slice(x.as_ref()))
     };
-    arrow_data.extend_from_slice(&(swapped.len() as i64).to_le_bytes());
-    match compression {
-        Compression::LZ4 => {
-            compression::compress_lz4(&swapped, arrow_data).unwrap();
-        }
-        Compression::ZSTD => {
-            compression::compress_zstd(&swapped, arrow_data).unwrap();
-        }
-    }
+    write_compressed(&swapped, arrow_data, compression);
 }
 
 fn _write_buffer<T: NativeType>(buffer: &[T], arrow_data: &mut Vec<u8>, is_little_endian: bool) {
@@ -789,17 +773,38 @@ fn _write_compressed_buffer<T: NativeType>(
 ) {
     if is_little_endian == is_native_little_endian() {
         let bytes = bytemuck::cast_slice(buffer);
-        arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
-        match compression {
-            Compression::LZ4 => {
-                compression::compress_lz4(bytes, arrow_data).unwrap();
-            }
-            Compression::ZSTD => {
-                compression::compress_zstd(bytes, arrow_data).unwrap();
-            }
-        }
+        write_compressed(bytes, arrow_data, compression);
     } else {
-        todo!()
+        _write_compressed_buffer_from_iter(
+            buffer.iter().copied(),
+            arrow_data,
+            is_little_endian,
+            compression,
+        )
+    }
+}
+
+/// Writes `bytes` compressed with `compression` to `arrow_data`, prefixed by their uncompressed
+/// length. As allowed by the specification, `bytes` are written uncompressed and prefixed by `-1`
+/// when compressing them does not reduce their size (e.g. small or random buffers).
+/// # Panics
+/// This function panics iff the crate was compiled without `io_ipc_compression`, which
+/// `encode_chunk` checks beforehand.
+fn write_compressed(bytes: &[u8], arrow_data: &mut Vec<u8>, compression: Compression) {
+    let start = arrow_data.len();
+    arrow_data.extend_from_slice(&(bytes.len() as i64).to_le_bytes());
+    match compression {
+        Compression::LZ4 => {
+            compression::compress_lz4(bytes, arrow_data).unwrap();
+        }
+        Compression::ZSTD => {
+            compression::compress_zstd(bytes, arrow_data).unwrap();
+        }
+    }
+    if arrow_data.len() - start - 8 >= bytes.len() {
+        arrow_data.truncate(start);
+        arrow_data.extend_from_slice(&(-1i64).to_le_bytes());
+        arrow_data.extend_from_slice(bytes);
     }
 }
 
@@ -2,6 +2,7 @@
 //! Convert data between the Arrow memory format and JSON line-delimited records.
 
 pub mod read;
+pub mod schema;
 pub mod write;
 
 use crate::error::ArrowError;
@@ -0,0 +1,73 @@
+use std::io::BufRead;
+use std::sync::Arc;
+
+use super::{deserialize, read_rows};
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::Field;
+use crate::error::Result;
+
+/// An [`Iterator`] of [`Chunk`]s with the `fields` of the rows of a line-delimited JSON
+/// reader, each with at most `chunk_size` rows. Created by [`read_chunks`].
+pub struct ChunkIterator<R: BufRead> {
+    reader: R,
+    rows: Vec<String>,
+    fields: Vec<Field>,
+    finished: bool,
+}
+
+/// Returns an [`Iterator`] of [`Chunk`]s with the `fields` of the rows of `reader`, a
+/// line-delimited JSON (e.g. NDJSON), each with at most `chunk_size` rows.
+///
+/// Only `chunk_size` rows are held in memory at a time (and re-used across chunks), so that
+/// files larger than memory can be processed chunk by chunk. Empty lines are skipped.
+/// The iterator stops after the first error.
+/// # Panics
+/// Panics iff `chunk_size` is zero.
+pub fn read_chunks<R: BufRead>(
+    reader: R,
+    fields: Vec<Field>,
+    chunk_size: usize,
+) -> ChunkIterator<R> {
+    assert!(chunk_size > 0, "chunk_size must be larger than zero");
+    ChunkIterator {
+        reader,
+        rows: vec![String::default(); chunk_size],
+        fields,
+        finished: false,
+    }
+}
+
+impl<R: BufRead> ChunkIterator<R> {
+    /// Returns the underlying reader, e.g. to read the remaining rows of a file.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead> Iterator for ChunkIterator<R> {
+    type Item = Result<Chunk<Arc<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let rows_read = match read_rows(&mut self.reader, &mut self.rows) {
+            Ok(rows_read) => rows_read,
+            Err(error) => {
+                self.finished = true;
+                return Some(Err(error));
+            }
+        };
+        if rows_read == 0 {
+            self.finished = true;
+            return None;
+        }
+        let chunk = deserialize(&self.rows[..rows_read], &self.fields);
+
+        // fewer rows than requested means that the reader is exhausted
+        self.finished = chunk.is_err() || rows_read < self.rows.len();
+        Some(chunk)
+    }
+}
@@ -12,8 +12,9 @@ use crate::{
     bitmap::MutableBitmap,
     chunk::Chunk,
     datatypes::{DataType, Field, IntervalUnit},
-    error::ArrowError,
+    error::{ArrowError, Result},
     types::NativeType,
+    util::decimal::parse_decimal,
 };
 
 /// A function that converts a &Value into an optional tuple of a byte slice and a Value.
@@ -109,37 +110,79 @@ fn deserialize_utf8<O: Offset, A: Borrow<Value>>(rows: &[A]) -> Utf8Array<O> {
     Utf8Array::<O>::from_trusted_len_iter(iter)
 }
 
-fn deserialize_list<O: Offset, A: Borrow<Value>>(rows: &[A], data_type: DataType) -> ListArray<O> {
+fn deserialize_decimal<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> PrimitiveArray<i128> {
+    let (precision, scale) = match data_type.to_logical_type() {
+        DataType::Decimal(precision, scale) => (*precision, *scale),
+        _ => unreachable!(),
+    };
+    let iter = rows.iter().map(|row| match row.borrow() {
+        Value::Number(v) => parse_decimal(v.to_string().as_bytes(), precision, scale, true),
+        Value::String(v) => parse_decimal(v.as_bytes(), precision, scale, true),
+        _ => None,
+    });
+    PrimitiveArray::from_trusted_len_iter(iter).to(data_type)
+}
+
+fn deserialize_list<O: Offset, A: Borrow<Value>>(
+    rows: &[A],
+    data_type: DataType,
+) -> Result<ListArray<O>> {
     let child = ListArray::<O>::get_child_type(&data_type);
 
     let mut validity = MutableBitmap::with_capacity(rows.len());
     let mut offsets = Vec::<O>::with_capacity(rows.len() + 1);
     let mut inner = vec![];
     offsets.push(O::zero());
-    rows.iter().fold(O::zero(), |mut length, row| {
+    for row in rows {
         match row.borrow() {
             Value::Array(value) => {
                 inner.extend(value.iter());
                 validity.push(true);
-                // todo make this an Err
-                length += O::from_usize(value.len()).expect("List offset is too large :/");
-                offsets.push(length);
-                length
-            }
-            _ => {
-                validity.push(false);
-                offsets.push(length);
-                length
             }
+            _ => validity.push(false),
+        }
+        offsets.push(O::from_usize(inner.len()).ok_or(ArrowError::Overflow)?);
+    }
+
+    let values = _deserialize(&inner, child.clone())?;
+
+    Ok(ListArray::<O>::from_data(
+        data_type,
+        offsets.into(),
+        values,
+        validity.into(),
+    ))
+}
+
+fn deserialize_fixed_size_list<A: Borrow<Value>>(
+    rows: &[A],
+    data_type: DataType,
+) -> Result<FixedSizeListArray> {
+    let (child, size) = FixedSizeListArray::get_child_and_size(&data_type);
+
+    let mut validity = MutableBitmap::with_capacity(rows.len());
+    let mut inner = Vec::with_capacity(rows.len() * size);
+    rows.iter().for_each(|row| match row.borrow() {
+        Value::Array(value) if value.len() == size => {
+            inner.extend(value.iter());
+            validity.push(true);
+        }
+        _ => {
+            inner.extend((0..size).map(|_| &Value::Null));
+            validity.push(false);
         }
     });
 
-    let values = _deserialize(&inner, child.clone());
+    let values = _deserialize(&inner, child.data_type().clone())?;
 
-    ListArray::<O>::from_data(data_type, offsets.into(), values, validity.into())
+    Ok(FixedSizeListArray::from_data(
+        data_type,
+        values,
+        validity.into(),
+    ))
 }
 
-fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> StructArray {
+fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Result<StructArray> {
     let fields = StructArray::get_fields(&data_type);
 
     let mut values = fields
@@ -147,17 +190,20 @@ fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Stru
         .map(|f| (&f.name, (f.data_type(), vec![])))
         .collect::<HashMap<_, _>>();
 
+    let mut validity = MutableBitmap::with_capacity(rows.len());
     rows.iter().for_each(|row| {
         match row.borrow() {
             Value::Object(value) => {
                 values
                     .iter_mut()
                     .for_each(|(s, (_, inner))| inner.push(value.get(*s).unwrap_or(&Value::Null)));
+                validity.push(true);
             }
             _ => {
                 values
                     .iter_mut()
                     .for_each(|(_, (_, inner))| inner.push(&Value::Null));
+                validity.push(false);
             }
         };
     });
@@ -165,15 +211,15 @@ fn deserialize_struct<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Stru
     let values = values
         .into_iter()
         .map(|(_, (data_type, values))| _deserialize(&values, data_type.clone()))
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>>>()?;
 
-    StructArray::from_data(data_type, values, None)
+    Ok(StructArray::from_data(data_type, values, validity.into()))
 }
 
 fn deserialize_dictionary<K: DictionaryKey, A: Borrow<Value>>(
     rows: &[A],
     data_type: DataType,
-) -> DictionaryArray<K> {
+) -> Result<DictionaryArray<K>> {
     let child = DictionaryArray::<K>::get_child(&data_type);
 
     let mut map = HashedMap::<u64, K>::default();
@@ -186,25 +232,24 @@ fn deserialize_dictionary<K: DictionaryKey, A: Borrow<Value>>(
         .map(|x| extractor(x.borrow()))
         .map(|item| match item {
             Some((hash, v)) => match map.get(&hash) {
-                Some(key) => Some(*key),
+                Some(key) => Ok(Some(*key)),
                 None => {
-                    // todo: convert this to an error.
-                    let key = K::from_usize(map.len()).unwrap();
+                    let key = K::from_usize(map.len()).ok_or(ArrowError::Overflow)?;
                     inner.push(v);
                     map.insert(hash, key);
-                    Some(key)
+                    Ok(Some(key))
                 }
             },
-            None => None,
+            None => Ok(None),
         })
-        .collect::<PrimitiveArray<K>>();
+        .collect::<Result<PrimitiveArray<K>>>()?;
 
-    let values = _deserialize(&inner, child.clone());
-    DictionaryArray::<K>::from_data(keys, values)
+    let values = _deserialize(&inner, child.clone())?;
+    Ok(DictionaryArray::<K>::from_data(keys, values))
 }
 
-fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Arc<dyn Array> {
-    match &data_type {
+fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Result<Arc<dyn Array>> {
+    Ok(match data_type.to_logical_type() {
         DataType::Null => Arc::new(NullArray::from_data(data_type, rows.len())),
         DataType::Boolean => Arc::new(deserialize_boolean(rows)),
         DataType::Int8 => Arc::new(deserialize_int::<i8, _>(rows, data_type)),
@@ -215,9 +260,6 @@ fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Arc<dyn Ar
         | DataType::Interval(IntervalUnit::YearMonth) => {
             Arc::new(deserialize_int::<i32, _>(rows, data_type))
         }
-        DataType::Interval(IntervalUnit::DayTime) => {
-            unimplemented!("There is no natural representation of DayTime in JSON.")
-        }
         DataType::Int64
         | DataType::Date64
         | DataType::Time64(_)
@@ -227,36 +269,37 @@ fn _deserialize<A: Borrow<Value>>(rows: &[A], data_type: DataType) -> Arc<dyn Ar
         DataType::UInt16 => Arc::new(deserialize_int::<u16, _>(rows, data_type)),
         DataType::UInt32 => Arc::new(deserialize_int::<u32, _>(rows, data_type)),
         DataType::UInt64 => Arc::new(deserialize_int::<u64, _>(rows, data_type)),
-        DataType::Float16 => unreachable!(),
         DataType::Float32 => Arc::new(deserialize_float::<f32, _>(rows, data_type)),
         DataType::Float64 => Arc::new(deserialize_float::<f64, _>(rows, data_type)),
+        DataType::Decimal(_, _) => Arc::new(deserialize_decimal(rows, data_type)),
         DataType::Utf8 => Arc::new(deserialize_utf8::<i32, _>(rows)),
         DataType::LargeUtf8 => Arc::new(deserialize_utf8::<i64, _>(rows)),
-        DataType::List(_) => Arc::new(deserialize_list::<i32, _>(rows, data_type)),
-        DataType::LargeList(_) => Arc::new(deserialize_list::<i64, _>(rows, data_type)),
+        DataType::List(_) => Arc::new(deserialize_list::<i32, _>(rows, data_type)?),
+        DataType::LargeList(_) => Arc::new(deserialize_list::<i64, _>(rows, data_type)?),
+        DataType::FixedSizeList(_, _) => Arc::new(deserialize_fixed_size_list(rows, data_type)?),
         DataType::Binary => Arc::new(deserialize_binary::<i32, _>(rows)),
         DataType::LargeBinary => Arc::new(deserialize_binary::<i64, _>(rows)),
-        DataType::Struct(_) => Arc::new(deserialize_struct(rows, data_type)),
+        DataType::Struct(_) => Arc::new(deserialize_struct(rows, data_type)?),
         DataType::Dictionary(key_type, _, _) => {
             match_integer_type!(key_type, |$T| {
-                Arc::new(deserialize_dictionary::<$T, _>(rows, data_type))
+                Arc::new(deserialize_dictionary::<$T, _>(rows, data_type)?)
             })
         }
-        _ => todo!(),
-        /*
-        DataType::FixedSizeBinary(_) => Box::new(FixedSizeBinaryArray::new_empty(data_type)),
-        DataType::FixedSizeList(_, _) => Box::new(FixedSizeListArray::new_empty(data_type)),
-        DataType::Decimal(_, _) => Box::new(PrimitiveArray::<i128>::new_empty(data_type)),
-        */
-    }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Deserializing {:?} from JSON",
+                other
+            )))
+        }
+    })
 }
 
 /// Deserializes `rows` into a [`Chunk`] according to `fields`.
 /// This is CPU-bounded.
-pub fn deserialize<A: AsRef<str>>(
-    rows: &[A],
-    fields: &[Field],
-) -> Result<Chunk<Arc<dyn Array>>, ArrowError> {
+/// # Errors
+/// This function errors iff a row is not valid JSON or a field has a [`DataType`] that
+/// cannot be deserialized from JSON.
+pub fn deserialize<A: AsRef<str>>(rows: &[A], fields: &[Field]) -> Result<Chunk<Arc<dyn Array>>> {
     let data_type = DataType::Struct(fields.to_vec());
 
     // convert rows to `Value`
@@ -266,15 +309,20 @@ pub fn deserialize<A: AsRef<str>>(
             let row: Value = serde_json::from_str(row.as_ref()).map_err(ArrowError::from)?;
             Ok(row)
         })
-        .collect::<Result<Vec<_>, ArrowError>>()?;
+        .collect::<Result<Vec<_>>>()?;
 
-    let (_, columns, _) = deserialize_struct(&rows, data_type).into_data();
+    let (_, columns, _) = deserialize_struct(&rows, data_type)?.into_data();
     Ok(Chunk::new(columns))
 }
 
 /// Deserializes a slice of [`Value`] to an Array of logical type [`DataType`].
+/// Objects are deserialized to [`StructArray`], arrays to [`ListArray`] (or
+/// [`FixedSizeListArray`]) and scalars to the corresponding arrays, at any nesting depth.
+/// Values that do not match `data_type` are deserialized as nulls.
 ///
 /// This function allows consuming deserialized JSON to Arrow.
-pub fn deserialize_json(rows: &[Value], data_type: DataType) -> Arc<dyn Array> {
+/// # Errors
+/// This function errors iff `data_type` (or one of its children) cannot be deserialized from JSON.
+pub fn deserialize_json(rows: &[Value], data_type: DataType) -> Result<Arc<dyn Array>> {
     _deserialize(rows, data_type)
 }
@@ -1,10 +1,12 @@
 //! APIs to read and deserialize from JSON
+mod chunks;
 mod deserialize;
 mod infer_schema;
 mod iterator;
 
 use crate::error::{ArrowError, Result};
 
+pub use chunks::{read_chunks, ChunkIterator};
 pub use deserialize::{deserialize, deserialize_json};
 pub use infer_schema::*;
 
@@ -0,0 +1,537 @@
+//! APIs to serialize and deserialize [`Schema`]s, [`Field`]s and [`DataType`]s to and from the
+//! JSON representation of schemas defined by Arrow for its integration tests, which other Arrow
+//! implementations (e.g. Arrow C++) also read and write.
+//!
+//! A field is represented as
+//! `{"name": .., "nullable": .., "type": {"name": .., ..}, "children": [..]}`, with an optional
+//! `"dictionary"` (`{"id": .., "indexType": .., "isOrdered": ..}`) when it is dictionary-encoded
+//! and an optional `"metadata"` (`[{"key": .., "value": ..}]`).
+//! Extension types are represented by their storage type and the field's metadata keys
+//! `ARROW:extension:name` and `ARROW:extension:metadata`.
+//!
+//! # Example
+//! ```
+//! use arrow2::datatypes::{DataType, Field, Schema};
+//! use arrow2::io::json::schema::{deserialize_schema, serialize_schema};
+//!
+//! let schema = Schema::from(vec![
+//!     Field::new("a", DataType::Int32, true),
+//!     Field::new("b", DataType::List(Box::new(Field::new("item", DataType::Utf8, true))), false),
+//! ]);
+//!
+//! let json = serialize_schema(&schema);
+//! assert_eq!(json["fields"][0]["type"]["name"], "int");
+//!
+//! assert_eq!(deserialize_schema(&json).unwrap(), schema);
+//! ```
+use serde_json::{json, Map, Value};
+
+use crate::datatypes::{
+    take_extension, DataType, Field, IntegerType, IntervalUnit, Metadata, Schema, TimeUnit,
+    UnionMode,
+};
+use crate::error::{ArrowError, Result};
+
+const EXTENSION_NAME: &str = "ARROW:extension:name";
+const EXTENSION_METADATA: &str = "ARROW:extension:metadata";
+
+fn serialize_time_unit(unit: &TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "SECOND",
+        TimeUnit::Millisecond => "MILLISECOND",
+        TimeUnit::Microsecond => "MICROSECOND",
+        TimeUnit::Nanosecond => "NANOSECOND",
+    }
+}
+
+fn serialize_integer(integer: &IntegerType) -> Value {
+    use IntegerType::*;
+    let (bit_width, is_signed) = match integer {
+        Int8 => (8, true),
+        Int16 => (16, true),
+        Int32 => (32, true),
+        Int64 => (64, true),
+        UInt8 => (8, false),
+        UInt16 => (16, false),
+        UInt32 => (32, false),
+        UInt64 => (64, false),
+    };
+    json!({"name": "int", "bitWidth": bit_width, "isSigned": is_signed})
+}
+
+/// Serializes `data_type` to the JSON `"type"` of a field.
+///
+/// The children of nested types and the dictionary encoding of [`DataType::Dictionary`] are
+/// part of the field (see [`serialize_field`]) and thus not serialized here: dictionaries
+/// are serialized as their values' type, and extension types as their storage type.
+pub fn serialize_data_type(data_type: &DataType) -> Value {
+    use DataType::*;
+    match data_type {
+        Null => json!({"name": "null"}),
+        Boolean => json!({"name": "bool"}),
+        Int8 => serialize_integer(&IntegerType::Int8),
+        Int16 => serialize_integer(&IntegerType::Int16),
+        Int32 => serialize_integer(&IntegerType::Int32),
+        Int64 => serialize_integer(&IntegerType::Int64),
+        UInt8 => serialize_integer(&IntegerType::UInt8),
+        UInt16 => serialize_integer(&IntegerType::UInt16),
+        UInt32 => serialize_integer(&IntegerType::UInt32),
+        UInt64 => serialize_integer(&IntegerType::UInt64),
+        Float16 => json!({"name": "floatingpoint", "precision": "HALF"}),
+        Float32 => json!({"name": "floatingpoint", "precision": "SINGLE"}),
+        Float64 => json!({"name": "floatingpoint", "precision": "DOUBLE"}),
+        Utf8 => json!({"name": "utf8"}),
+        LargeUtf8 => json!({"name": "largeutf8"}),
+        Binary => json!({"name": "binary"}),
+        LargeBinary => json!({"name": "largebinary"}),
+        FixedSizeBinary(byte_width) => {
+            json!({"name": "fixedsizebinary", "byteWidth": byte_width})
+        }
+        Struct(_) => json!({"name": "struct"}),
+        Union(_, ids, mode) => {
+            let mode = if mode.is_sparse() { "SPARSE" } else { "DENSE" };
+            match ids {
+                Some(ids) => json!({"name": "union", "mode": mode, "typeIds": ids}),
+                None => json!({"name": "union", "mode": mode}),
+            }
+        }
+        Map(_, keys_sorted) => json!({"name": "map", "keysSorted": keys_sorted}),
+        List(_) => json!({"name": "list"}),
+        LargeList(_) => json!({"name": "largelist"}),
+        FixedSizeList(_, size) => json!({"name": "fixedsizelist", "listSize": size}),
+        Time32(unit) => {
+            json!({"name": "time", "bitWidth": 32, "unit": serialize_time_unit(unit)})
+        }
+        Time64(unit) => {
+            json!({"name": "time", "bitWidth": 64, "unit": serialize_time_unit(unit)})
+        }
+        Date32 => json!({"name": "date", "unit": "DAY"}),
+        Date64 => json!({"name": "date", "unit": "MILLISECOND"}),
+        Timestamp(unit, None) => {
+            json!({"name": "timestamp", "unit": serialize_time_unit(unit)})
+        }
+        Timestamp(unit, Some(tz)) => {
+            json!({"name": "timestamp", "unit": serialize_time_unit(unit), "timezone": tz})
+        }
+        Interval(unit) => json!({"name": "interval", "unit": match unit {
+            IntervalUnit::YearMonth => "YEAR_MONTH",
+            IntervalUnit::DayTime => "DAY_TIME",
+            IntervalUnit::MonthDayNano => "MONTH_DAY_NANO",
+        }}),
+        Duration(unit) => json!({"name": "duration", "unit": serialize_time_unit(unit)}),
+        Decimal(precision, scale) => {
+            json!({"name": "decimal", "precision": precision, "scale": scale, "bitWidth": 128})
+        }
+        Dictionary(_, values, _) => serialize_data_type(values),
+        Extension(_, storage, _) => serialize_data_type(storage),
+    }
+}
+
+fn serialize_metadata(metadata: &Metadata) -> Value {
+    Value::Array(
+        metadata
+            .iter()
+            .map(|(key, value)| json!({"key": key, "value": value}))
+            .collect(),
+    )
+}
+
+/// Serializes `field`, assigning ids to its dictionaries starting at `dictionary_id`.
+fn serialize_field_with_ids(field: &Field, dictionary_id: &mut i64) -> Value {
+    let mut metadata = field.metadata.clone();
+    let mut map = Map::new();
+    map.insert("name".to_string(), Value::String(field.name.clone()));
+    map.insert("nullable".to_string(), Value::Bool(field.is_nullable));
+    map.insert("type".to_string(), serialize_data_type(field.data_type()));
+
+    let mut data_type = field.data_type();
+    if let DataType::Dictionary(index_type, values, is_ordered) = data_type {
+        map.insert(
+            "dictionary".to_string(),
+            json!({
+                "id": *dictionary_id,
+                "indexType": serialize_integer(index_type),
+                "isOrdered": is_ordered,
+            }),
+        );
+        *dictionary_id += 1;
+        data_type = values.as_ref();
+    }
+    if let DataType::Extension(name, storage, extension_metadata) = data_type {
+        metadata.insert(EXTENSION_NAME.to_string(), name.clone());
+        if let Some(extension_metadata) = extension_metadata {
+            metadata.insert(EXTENSION_METADATA.to_string(), extension_metadata.clone());
+        }
+        data_type = storage.as_ref();
+    }
+
+    let children = match data_type {
+        DataType::Union(fields, ..) | DataType::Struct(fields) => fields.iter().collect(),
+        DataType::Map(field, ..)
+        | DataType::FixedSizeList(field, _)
+        | DataType::LargeList(field)
+        | DataType::List(field) => vec![field.as_ref()],
+        _ => vec![],
+    };
+    let children = children
+        .into_iter()
+        .map(|field| serialize_field_with_ids(field, dictionary_id))
+        .collect();
+    map.insert("children".to_string(), Value::Array(children));
+
+    if !metadata.is_empty() {
+        map.insert("metadata".to_string(), serialize_metadata(&metadata));
+    }
+    Value::Object(map)
+}
+
+/// Serializes `field` (and its children).
+///
+/// The dictionaries of `field` are assigned sequential ids starting at 0.
+pub fn serialize_field(field: &Field) -> Value {
+    serialize_field_with_ids(field, &mut 0)
+}
+
+/// Serializes `schema`.
+///
+/// The dictionaries of all fields are assigned sequential ids starting at 0, in the order of
+/// the fields (and their children).
+pub fn serialize_schema(schema: &Schema) -> Value {
+    let mut dictionary_id = 0;
+    let fields = schema
+        .fields
+        .iter()
+        .map(|field| serialize_field_with_ids(field, &mut dictionary_id))
+        .collect();
+    let mut map = Map::new();
+    map.insert("fields".to_string(), Value::Array(fields));
+    if !schema.metadata.is_empty() {
+        map.insert("metadata".to_string(), serialize_metadata(&schema.metadata));
+    }
+    Value::Object(map)
+}
+
+fn deserialize_time_unit(value: Option<&Value>) -> Result<TimeUnit> {
+    match value.and_then(|value| value.as_str()) {
+        Some("SECOND") => Ok(TimeUnit::Second),
+        Some("MILLISECOND") => Ok(TimeUnit::Millisecond),
+        Some("MICROSECOND") => Ok(TimeUnit::Microsecond),
+        Some("NANOSECOND") => Ok(TimeUnit::Nanosecond),
+        _ => Err(ArrowError::OutOfSpec(
+            "time unit missing or invalid".to_string(),
+        )),
+    }
+}
+
+fn deserialize_integer(value: &Value) -> Result<IntegerType> {
+    let is_signed = value.get("isSigned").and_then(|x| x.as_bool());
+    let bit_width = value.get("bitWidth").and_then(|x| x.as_u64());
+    Ok(match (bit_width, is_signed) {
+        (Some(8), Some(true)) => IntegerType::Int8,
+        (Some(16), Some(true)) => IntegerType::Int16,
+        (Some(32), Some(true)) => IntegerType::Int32,
+        (Some(64), Some(true)) => IntegerType::Int64,
+        (Some(8), Some(false)) => IntegerType::UInt8,
+        (Some(16), Some(false)) => IntegerType::UInt16,
+        (Some(32), Some(false)) => IntegerType::UInt32,
+        (Some(64), Some(false)) => IntegerType::UInt64,
+        _ => {
+            return Err(ArrowError::OutOfSpec(
+                "int bitWidth or isSigned missing or invalid".to_string(),
+            ))
+        }
+    })
+}
+
+fn get_usize(value: &Value, key: &str, type_: &str) -> Result<usize> {
+    value
+        .get(key)
+        .and_then(|x| x.as_u64())
+        .map(|x| x as usize)
+        .ok_or_else(|| ArrowError::OutOfSpec(format!("Expecting a {} for {}", key, type_)))
+}
+
+/// Returns the single child of a nested type
+fn single_child(mut children: Vec<Field>, type_: &str) -> Result<Box<Field>> {
+    if children.len() != 1 {
+        return Err(ArrowError::OutOfSpec(format!(
+            "The type {} must have exactly one child, but it has {}",
+            type_,
+            children.len()
+        )));
+    }
+    Ok(Box::new(children.pop().unwrap()))
+}
+
+/// Deserializes the JSON `"type"` of a field whose (deserialized) children are `children`.
+///
+/// This is the inverse of [`serialize_data_type`]: dictionary encodings and extension types,
+/// which are part of the field, are handled by [`deserialize_field`].
+/// # Errors
+/// This function errors iff `value` is not a valid type or the number of children is invalid
+/// for the type.
+pub fn deserialize_data_type(value: &Value, children: Vec<Field>) -> Result<DataType> {
+    let type_ = value
+        .get("name")
+        .and_then(|x| x.as_str())
+        .ok_or_else(|| ArrowError::OutOfSpec("type name missing or invalid".to_string()))?;
+
+    use DataType::*;
+    Ok(match type_ {
+        "null" => Null,
+        "bool" => Boolean,
+        "int" => deserialize_integer(value)?.into(),
+        "floatingpoint" => match value.get("precision").and_then(|x| x.as_str()) {
+            Some("HALF") => Float16,
+            Some("SINGLE") => Float32,
+            Some("DOUBLE") => Float64,
+            _ => {
+                return Err(ArrowError::OutOfSpec(
+                    "floatingpoint precision missing or invalid".to_string(),
+                ))
+            }
+        },
+        "binary" => Binary,
+        "largebinary" => LargeBinary,
+        "fixedsizebinary" => FixedSizeBinary(get_usize(value, "byteWidth", type_)?),
+        "utf8" => Utf8,
+        "largeutf8" => LargeUtf8,
+        "decimal" => {
+            match value.get("bitWidth").and_then(|x| x.as_u64()) {
+                None | Some(128) => {}
+                Some(bit_width) => {
+                    return Err(ArrowError::NotYetImplemented(format!(
+                        "Decimals of {} bits",
+                        bit_width
+                    )))
+                }
+            }
+            Decimal(
+                get_usize(value, "precision", type_)?,
+                get_usize(value, "scale", type_)?,
+            )
+        }
+        "date" => match value.get("unit").and_then(|x| x.as_str()) {
+            Some("DAY") => Date32,
+            Some("MILLISECOND") => Date64,
+            _ => {
+                return Err(ArrowError::OutOfSpec(
+                    "date unit missing or invalid".to_string(),
+                ))
+            }
+        },
+        "time" => {
+            let unit = deserialize_time_unit(value.get("unit"))?;
+            match value.get("bitWidth").and_then(|x| x.as_u64()) {
+                Some(32) => Time32(unit),
+                Some(64) => Time64(unit),
+                _ => {
+                    return Err(ArrowError::OutOfSpec(
+                        "time bitWidth missing or invalid".to_string(),
+                    ))
+                }
+            }
+        }
+        "timestamp" => {
+            let unit = deserialize_time_unit(value.get("unit"))?;
+            let tz = match value.get("timezone") {
+                None | Some(Value::Null) => None,
+                Some(Value::String(tz)) => Some(tz.clone()),
+                _ => {
+                    return Err(ArrowError::OutOfSpec(
+                        "timezone must be a string".to_string(),
+                    ))
+                }
+            };
+            Timestamp(unit, tz)
+        }
+        "interval" => match value.get("unit").and_then(|x| x.as_str()) {
+            Some("YEAR_MONTH") => Interval(IntervalUnit::YearMonth),
+            Some("DAY_TIME") => Interval(IntervalUnit::DayTime),
+            Some("MONTH_DAY_NANO") => Interval(IntervalUnit::MonthDayNano),
+            _ => {
+                return Err(ArrowError::OutOfSpec(
+                    "interval unit missing or invalid".to_string(),
+                ))
+            }
+        },
+        "duration" => Duration(deserialize_time_unit(value.get("unit"))?),
+        "list" => List(single_child(children, type_)?),
+        "largelist" => LargeList(single_child(children, type_)?),
+        "fixedsizelist" => {
+            let size = get_usize(value, "listSize", type_)?;
+            FixedSizeList(single_child(children, type_)?, size)
+        }
+        "struct" => Struct(children),
+        "map" => {
+            let keys_sorted = value
+                .get("keysSorted")
+                .and_then(|x| x.as_bool())
+                .unwrap_or(false);
+            Map(single_child(children, type_)?, keys_sorted)
+        }
+        "union" => {
+            let mode = match value.get("mode").and_then(|x| x.as_str()) {
+                Some("SPARSE") => UnionMode::Sparse,
+                Some("DENSE") => UnionMode::Dense,
+                _ => {
+                    return Err(ArrowError::OutOfSpec(
+                        "union mode missing or invalid".to_string(),
+                    ))
+                }
+            };
+            let ids = match value.get("typeIds") {
+                None => None,
+                Some(Value::Array(ids)) => Some(
+                    ids.iter()
+                        .map(|id| {
+                            id.as_i64().map(|id| id as i32).ok_or_else(|| {
+                                ArrowError::OutOfSpec("union typeIds must be integers".to_string())
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                ),
+                _ => {
+                    return Err(ArrowError::OutOfSpec(
+                        "union typeIds must be an array".to_string(),
+                    ))
+                }
+            };
+            if let Some(ids) = &ids {
+                if ids.len() != children.len() {
+                    return Err(ArrowError::OutOfSpec(format!(
+                        "The union has {} typeIds but {} children",
+                        ids.len(),
+                        children.len()
+                    )));
+                }
+            }
+            Union(children, ids, mode)
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Deserializing the JSON type \"{}\"",
+                other
+            )))
+        }
+    })
+}
+
+/// Deserializes metadata, either an array of `{"key": .., "value": ..}` or an object.
+fn deserialize_metadata(value: &Value) -> Result<Metadata> {
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .map(|value| {
+                match (
+                    value.get("key").and_then(|x| x.as_str()),
+                    value.get("value").and_then(|x| x.as_str()),
+                ) {
+                    (Some(key), Some(value)) => Ok((key.to_string(), value.to_string())),
+                    _ => Err(ArrowError::OutOfSpec(
+                        "metadata entries must have a string \"key\" and \"value\"".to_string(),
+                    )),
+                }
+            })
+            .collect(),
+        Value::Object(values) => values
+            .iter()
+            .map(|(key, value)| {
+                value
+                    .as_str()
+                    .map(|value| (key.clone(), value.to_string()))
+                    .ok_or_else(|| {
+                        ArrowError::OutOfSpec(format!(
+                            "metadata contains a non-string value for key {}",
+                            key
+                        ))
+                    })
+            })
+            .collect(),
+        _ => Err(ArrowError::OutOfSpec(
+            "metadata must be an array or an object".to_string(),
+        )),
+    }
+}
+
+/// Deserializes a field (and its children), as serialized by [`serialize_field`].
+///
+/// Dictionary ids are ignored.
+/// # Errors
+/// This function errors iff `value` is not a valid field.
+pub fn deserialize_field(value: &Value) -> Result<Field> {
+    let map = value
+        .as_object()
+        .ok_or_else(|| ArrowError::OutOfSpec("A field must be an object".to_string()))?;
+
+    let name = map
+        .get("name")
+        .and_then(|x| x.as_str())
+        .ok_or_else(|| ArrowError::OutOfSpec("Field missing 'name' attribute".to_string()))?;
+    let is_nullable = map
+        .get("nullable")
+        .and_then(|x| x.as_bool())
+        .ok_or_else(|| ArrowError::OutOfSpec("Field missing 'nullable' attribute".to_string()))?;
+
+    let mut metadata = match map.get("metadata") {
+        Some(metadata) => deserialize_metadata(metadata)?,
+        None => Metadata::default(),
+    };
+    let extension = take_extension(&mut metadata);
+
+    let children = match map.get("children") {
+        None => vec![],
+        Some(Value::Array(children)) => children
+            .iter()
+            .map(deserialize_field)
+            .collect::<Result<Vec<_>>>()?,
+        _ => {
+            return Err(ArrowError::OutOfSpec(
+                "Field 'children' must be an array".to_string(),
+            ))
+        }
+    };
+    let type_ = map
+        .get("type")
+        .ok_or_else(|| ArrowError::OutOfSpec("Field missing 'type' attribute".to_string()))?;
+    let mut data_type = deserialize_data_type(type_, children)?;
+
+    if let Some((name, extension_metadata)) = extension {
+        data_type = DataType::Extension(name, Box::new(data_type), extension_metadata);
+    }
+
+    if let Some(dictionary) = map.get("dictionary") {
+        let index_type = dictionary.get("indexType").ok_or_else(|| {
+            ArrowError::OutOfSpec("Dictionary missing 'indexType' attribute".to_string())
+        })?;
+        let is_ordered = dictionary
+            .get("isOrdered")
+            .and_then(|x| x.as_bool())
+            .unwrap_or(false);
+        data_type = DataType::Dictionary(
+            deserialize_integer(index_type)?,
+            Box::new(data_type),
+            is_ordered,
+        );
+    }
+
+    Ok(Field::new(name, data_type, is_nullable).with_metadata(metadata))
+}
+
+/// Deserializes a schema, as serialized by [`serialize_schema`].
+/// # Errors
+/// This function errors iff `value` is not a valid schema.
+pub fn deserialize_schema(value: &Value) -> Result<Schema> {
+    let fields = value
+        .get("fields")
+        .and_then(|x| x.as_array())
+        .ok_or_else(|| ArrowError::OutOfSpec("Schema fields should be an array".to_string()))?
+        .iter()
+        .map(deserialize_field)
+        .collect::<Result<Vec<_>>>()?;
+    let metadata = match value.get("metadata") {
+        Some(metadata) => deserialize_metadata(metadata)?,
+        None => Metadata::default(),
+    };
+    Ok(Schema::from(fields).with_metadata(metadata))
+}
@@ -76,8 +76,9 @@ where
         self.batches
             .next()
             .map(|maybe_chunk| {
-                maybe_chunk
-                    .map(|columns| serialize(&self.names, &columns, self.format, &mut self.buffer))
+                maybe_chunk.and_then(|columns| {
+                    serialize(&self.names, &columns, self.format, &mut self.buffer)
+                })
             })
             .transpose()?;
         Ok(())
@@ -1,18 +1,24 @@
+use std::fmt::Display;
+use std::io::Write;
+
 use lexical_core::ToLexical;
 use serde_json::Value;
 use streaming_iterator::StreamingIterator;
 
 use crate::bitmap::utils::zip_validity;
 use crate::chunk::Chunk;
+use crate::datatypes::TimeUnit;
+use crate::error::{ArrowError, Result};
 use crate::io::iterator::BufStreamingIterator;
-use crate::util::lexical_to_bytes_mut;
+use crate::temporal_conversions;
+use crate::util::{decimal::write_decimal, lexical_to_bytes_mut};
 use crate::{array::*, datatypes::DataType, types::NativeType};
 
+type Serializer<'a> = Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync>;
+
 use super::{JsonArray, JsonFormat};
 
-fn boolean_serializer<'a>(
-    array: &'a BooleanArray,
-) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+fn boolean_serializer<'a>(array: &'a BooleanArray) -> Serializer<'a> {
     Box::new(BufStreamingIterator::new(
         array.iter(),
         |x, buf| match x {
@@ -26,7 +32,7 @@ fn boolean_serializer<'a>(
 
 fn primitive_serializer<'a, T: NativeType + ToLexical>(
     array: &'a PrimitiveArray<T>,
-) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+) -> Serializer<'a> {
     Box::new(BufStreamingIterator::new(
         array.iter(),
         |x, buf| {
@@ -40,9 +46,7 @@ fn primitive_serializer<'a, T: NativeType + ToLexical>(
     ))
 }
 
-fn utf8_serializer<'a, O: Offset>(
-    array: &'a Utf8Array<O>,
-) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+fn utf8_serializer<'a, O: Offset>(array: &'a Utf8Array<O>) -> Serializer<'a> {
     Box::new(BufStreamingIterator::new(
         array.iter(),
         |x, buf| {
@@ -56,9 +60,7 @@ fn utf8_serializer<'a, O: Offset>(
     ))
 }
 
-fn struct_serializer<'a>(
-    array: &'a StructArray,
-) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+fn struct_serializer<'a>(array: &'a StructArray) -> Result<Serializer<'a>> {
     // {"a": [1, 2, 3], "b": [a, b, c], "c": {"a": [1, 2, 3]}}
     // [
     //  {"a": 1, "b": a, "c": {"a": 1}},
@@ -71,10 +73,10 @@ fn struct_serializer<'a>(
         .iter()
         .map(|x| x.as_ref())
         .map(new_serializer)
-        .collect::<Vec<_>>();
+        .collect::<Result<Vec<_>>>()?;
     let names = array.fields().iter().map(|f| f.name.as_str());
 
-    Box::new(BufStreamingIterator::new(
+    Ok(Box::new(BufStreamingIterator::new(
         zip_validity(0..array.len(), array.validity().map(|x| x.iter())),
         move |maybe, buf| {
             if maybe.is_some() {
@@ -97,46 +99,160 @@ fn struct_serializer<'a>(
             }
         },
         vec![],
-    ))
+    )))
 }
 
-fn list_serializer<'a, O: Offset>(
-    array: &'a ListArray<O>,
-) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
+fn list_serializer<'a, O: Offset>(array: &'a ListArray<O>) -> Result<Serializer<'a>> {
     // [[1, 2], [3]]
     // [
     //  [1, 2],
     //  [3]
     // ]
     //
-    let mut serializer = new_serializer(array.values().as_ref());
+    let mut serializer = new_serializer(array.values().as_ref())?;
+    // skip the values before the first offset, e.g. when the array is sliced
+    (0..array.offsets()[0].to_usize()).for_each(|_| {
+        serializer.next();
+    });
+
+    Ok(Box::new(BufStreamingIterator::new(
+        array.offsets().windows(2).enumerate(),
+        move |(i, offset), buf| {
+            let length = (offset[1] - offset[0]).to_usize();
+            serialize_values(&mut serializer, length, array.is_valid(i), buf)
+        },
+        vec![],
+    )))
+}
+
+fn fixed_size_list_serializer<'a>(array: &'a FixedSizeListArray) -> Result<Serializer<'a>> {
+    let mut serializer = new_serializer(array.values().as_ref())?;
+    let (_, size) = FixedSizeListArray::get_child_and_size(array.data_type());
+
+    Ok(Box::new(BufStreamingIterator::new(
+        zip_validity(0..array.len(), array.validity().map(|x| x.iter())),
+        move |is_valid, buf| serialize_values(&mut serializer, size, is_valid.is_some(), buf),
+        vec![],
+    )))
+}
+
+/// Serializes the next `length` items of `serializer` as a JSON array, or `null` when
+/// `is_valid` is false (consuming the items nevertheless).
+fn serialize_values(serializer: &mut Serializer, length: usize, is_valid: bool, buf: &mut Vec<u8>) {
+    if !is_valid {
+        (0..length).for_each(|_| {
+            serializer.next();
+        });
+        buf.extend(b"null");
+        return;
+    }
+    buf.push(b'[');
+    for i in 0..length {
+        if i > 0 {
+            buf.push(b',');
+        }
+        buf.extend(serializer.next().unwrap());
+    }
+    buf.push(b']');
+}
+
+fn dictionary_serializer<'a, K: DictionaryKey>(
+    array: &'a DictionaryArray<K>,
+) -> Result<Serializer<'a>> {
+    // the values are serialized once and each key writes the serialized value
+    let mut serializer = new_serializer(array.values().as_ref())?;
+    let mut values = Vec::with_capacity(array.values().len());
+    while let Some(value) = serializer.next() {
+        values.push(value.to_vec());
+    }
+
+    Ok(Box::new(BufStreamingIterator::new(
+        array.keys().iter(),
+        move |key, buf| match key {
+            Some(key) => buf.extend_from_slice(&values[key.to_usize().unwrap()]),
+            None => buf.extend_from_slice(b"null"),
+        },
+        vec![],
+    )))
+}
 
+fn null_serializer<'a>(len: usize) -> Serializer<'a> {
     Box::new(BufStreamingIterator::new(
-        zip_validity(
-            array.offsets().windows(2),
-            array.validity().map(|x| x.iter()),
-        ),
-        move |offset, buf| {
-            if let Some(offset) = offset {
-                let length = (offset[1] - offset[0]).to_usize();
-                buf.push(b'[');
-                let mut is_first_row = true;
-                for _ in 0..length {
-                    if !is_first_row {
-                        buf.push(b',');
-                    }
-                    is_first_row = false;
-                    buf.extend(serializer.next().unwrap());
-                }
-                buf.push(b']');
-            } else {
-                buf.extend(b"null");
+        0..len,
+        |_, buf| buf.extend_from_slice(b"null"),
+        vec![],
+    ))
+}
+
+fn decimal_serializer<'a>(array: &'a PrimitiveArray<i128>, scale: usize) -> Serializer<'a> {
+    // decimals are serialized as strings to not lose precision when read as floats
+    Box::new(BufStreamingIterator::new(
+        array.iter(),
+        move |x, buf| match x {
+            Some(x) => {
+                buf.push(b'"');
+                write_decimal(*x, scale, buf);
+                buf.push(b'"');
+            }
+            None => buf.extend_from_slice(b"null"),
+        },
+        vec![],
+    ))
+}
+
+/// Serializes each value of `array` as a JSON string of the [`Display`] of `convert(value)`.
+fn display_serializer<'a, T, D, F>(array: &'a PrimitiveArray<T>, convert: F) -> Serializer<'a>
+where
+    T: NativeType,
+    D: Display,
+    F: Fn(T) -> D + 'a + Send + Sync,
+{
+    Box::new(BufStreamingIterator::new(
+        array.iter(),
+        move |x, buf| match x {
+            Some(x) => {
+                // writing to a `Vec` is infallible
+                write!(buf, "\"{}\"", convert(*x)).unwrap();
             }
+            None => buf.extend_from_slice(b"null"),
         },
         vec![],
     ))
 }
 
+fn timestamp_serializer<'a>(
+    array: &'a PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+    timezone: Option<&str>,
+) -> Result<Serializer<'a>> {
+    const NAIVE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+    let timezone = match timezone {
+        Some(timezone) => timezone,
+        None => {
+            return Ok(display_serializer(array, move |x| {
+                temporal_conversions::timestamp_to_naive_datetime(x, time_unit).format(NAIVE_FORMAT)
+            }))
+        }
+    };
+    if let Ok(offset) = temporal_conversions::parse_offset(timezone) {
+        return Ok(display_serializer(array, move |x| {
+            temporal_conversions::timestamp_to_datetime(x, time_unit, &offset).to_rfc3339()
+        }));
+    }
+    #[cfg(feature = "chrono-tz")]
+    {
+        let timezone = temporal_conversions::parse_offset_tz(timezone)?;
+        Ok(display_serializer(array, move |x| {
+            temporal_conversions::timestamp_to_datetime(x, time_unit, &timezone).to_rfc3339()
+        }))
+    }
+    #[cfg(not(feature = "chrono-tz"))]
+    Err(ArrowError::InvalidArgumentError(format!(
+        "Timezone \"{}\" is not a fixed offset (must be [-]00:00) and the chrono-tz feature is not active",
+        timezone
+    )))
+}
+
 #[inline]
 fn utf8_serialize(value: &str, buf: &mut Vec<u8>) {
     if value.as_bytes().is_ascii() {
@@ -151,28 +267,69 @@ fn utf8_serialize(value: &str, buf: &mut Vec<u8>) {
     }
 }
 
-fn new_serializer<'a>(
-    array: &'a dyn Array,
-) -> Box<dyn StreamingIterator<Item = [u8]> + 'a + Send + Sync> {
-    match array.data_type().to_logical_type() {
-        DataType::Boolean => boolean_serializer(array.as_any().downcast_ref().unwrap()),
-        DataType::Int8 => primitive_serializer::<i8>(array.as_any().downcast_ref().unwrap()),
-        DataType::Int16 => primitive_serializer::<i16>(array.as_any().downcast_ref().unwrap()),
-        DataType::Int32 => primitive_serializer::<i32>(array.as_any().downcast_ref().unwrap()),
-        DataType::Int64 => primitive_serializer::<i64>(array.as_any().downcast_ref().unwrap()),
-        DataType::UInt8 => primitive_serializer::<u8>(array.as_any().downcast_ref().unwrap()),
-        DataType::UInt16 => primitive_serializer::<u16>(array.as_any().downcast_ref().unwrap()),
-        DataType::UInt32 => primitive_serializer::<u32>(array.as_any().downcast_ref().unwrap()),
-        DataType::UInt64 => primitive_serializer::<u64>(array.as_any().downcast_ref().unwrap()),
-        DataType::Float32 => primitive_serializer::<f32>(array.as_any().downcast_ref().unwrap()),
-        DataType::Float64 => primitive_serializer::<f64>(array.as_any().downcast_ref().unwrap()),
-        DataType::Utf8 => utf8_serializer::<i32>(array.as_any().downcast_ref().unwrap()),
-        DataType::LargeUtf8 => utf8_serializer::<i64>(array.as_any().downcast_ref().unwrap()),
-        DataType::Struct(_) => struct_serializer(array.as_any().downcast_ref().unwrap()),
-        DataType::List(_) => list_serializer::<i32>(array.as_any().downcast_ref().unwrap()),
-        DataType::LargeList(_) => list_serializer::<i64>(array.as_any().downcast_ref().unwrap()),
-        other => todo!("Writing {:?} to JSON", other),
-    }
+fn new_serializer<'a>(array: &'a dyn Array) -> Result<Serializer<'a>> {
+    use DataType::*;
+    Ok(match array.data_type().to_logical_type() {
+        Null => null_serializer(array.len()),
+        Boolean => boolean_serializer(array.as_any().downcast_ref().unwrap()),
+        Int8 => primitive_serializer::<i8>(array.as_any().downcast_ref().unwrap()),
+        Int16 => primitive_serializer::<i16>(array.as_any().downcast_ref().unwrap()),
+        Int32 | Interval(crate::datatypes::IntervalUnit::YearMonth) => {
+            primitive_serializer::<i32>(array.as_any().downcast_ref().unwrap())
+        }
+        Int64 | Duration(_) => primitive_serializer::<i64>(array.as_any().downcast_ref().unwrap()),
+        UInt8 => primitive_serializer::<u8>(array.as_any().downcast_ref().unwrap()),
+        UInt16 => primitive_serializer::<u16>(array.as_any().downcast_ref().unwrap()),
+        UInt32 => primitive_serializer::<u32>(array.as_any().downcast_ref().unwrap()),
+        UInt64 => primitive_serializer::<u64>(array.as_any().downcast_ref().unwrap()),
+        Float32 => primitive_serializer::<f32>(array.as_any().downcast_ref().unwrap()),
+        Float64 => primitive_serializer::<f64>(array.as_any().downcast_ref().unwrap()),
+        Decimal(_, scale) => decimal_serializer(array.as_any().downcast_ref().unwrap(), *scale),
+        Date32 => display_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            temporal_conversions::date32_to_date,
+        ),
+        Date64 => display_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            temporal_conversions::date64_to_date,
+        ),
+        Time32(TimeUnit::Second) => display_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            temporal_conversions::time32s_to_time,
+        ),
+        Time32(TimeUnit::Millisecond) => display_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            temporal_conversions::time32ms_to_time,
+        ),
+        Time64(TimeUnit::Microsecond) => display_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            temporal_conversions::time64us_to_time,
+        ),
+        Time64(TimeUnit::Nanosecond) => display_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            temporal_conversions::time64ns_to_time,
+        ),
+        Timestamp(time_unit, timezone) => timestamp_serializer(
+            array.as_any().downcast_ref().unwrap(),
+            *time_unit,
+            timezone.as_deref(),
+        )?,
+        Utf8 => utf8_serializer::<i32>(array.as_any().downcast_ref().unwrap()),
+        LargeUtf8 => utf8_serializer::<i64>(array.as_any().downcast_ref().unwrap()),
+        Struct(_) => struct_serializer(array.as_any().downcast_ref().unwrap())?,
+        List(_) => list_serializer::<i32>(array.as_any().downcast_ref().unwrap())?,
+        LargeList(_) => list_serializer::<i64>(array.as_any().downcast_ref().unwrap())?,
+        FixedSizeList(_, _) => fixed_size_list_serializer(array.as_any().downcast_ref().unwrap())?,
+        Dictionary(key_type, _, _) => match_integer_type!(key_type, |$T| {
+            dictionary_serializer::<$T>(array.as_any().downcast_ref().unwrap())?
+        }),
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing {:?} to JSON",
+                other
+            )))
+        }
+    })
 }
 
 fn serialize_item<F: JsonFormat>(
@@ -199,7 +356,19 @@ fn serialize_item<F: JsonFormat>(
 
 /// Serializes a (name, array) to a valid JSON to `buffer`
 /// This is CPU-bounded
-pub fn serialize<N, A, F>(names: &[N], columns: &Chunk<A>, format: F, buffer: &mut Vec<u8>)
+///
+/// Temporal values are serialized as strings in ISO 8601 (e.g. `"2021-01-01T10:00:00"`),
+/// timestamps with a timezone in RFC 3339 (e.g. `"2021-01-01T10:00:00+02:00"`), decimals as
+/// strings (e.g. `"1.20"`) to preserve their precision, and dictionary-encoded values as their
+/// values.
+/// # Errors
+/// This function errors iff a column has a [`DataType`] that cannot be serialized to JSON.
+pub fn serialize<N, A, F>(
+    names: &[N],
+    columns: &Chunk<A>,
+    format: F,
+    buffer: &mut Vec<u8>,
+) -> Result<()>
 where
     N: AsRef<str>,
     A: AsRef<dyn Array>,
@@ -207,11 +376,11 @@ where
 {
     let num_rows = columns.len();
 
-    let mut serializers: Vec<_> = columns
+    let mut serializers = columns
         .arrays()
         .iter()
         .map(|array| new_serializer(array.as_ref()))
-        .collect();
+        .collect::<Result<Vec<_>>>()?;
 
     let mut is_first_row = true;
     (0..num_rows).for_each(|_| {
@@ -226,5 +395,6 @@ where
             });
         serialize_item(buffer, &record, format, is_first_row);
         is_first_row = false;
-    })
+    });
+    Ok(())
 }
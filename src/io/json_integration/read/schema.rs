@@ -4,24 +4,10 @@ use serde_json::Value;
 use crate::{
     error::{ArrowError, Result},
     io::ipc::IpcField,
+    io::json::schema::deserialize_data_type,
 };
 
-use crate::datatypes::{
-    get_extension, DataType, Field, IntegerType, IntervalUnit, Metadata, Schema, TimeUnit,
-    UnionMode,
-};
-
-fn to_time_unit(item: Option<&Value>) -> Result<TimeUnit> {
-    match item {
-        Some(p) if p == "SECOND" => Ok(TimeUnit::Second),
-        Some(p) if p == "MILLISECOND" => Ok(TimeUnit::Millisecond),
-        Some(p) if p == "MICROSECOND" => Ok(TimeUnit::Microsecond),
-        Some(p) if p == "NANOSECOND" => Ok(TimeUnit::Nanosecond),
-        _ => Err(ArrowError::OutOfSpec(
-            "time unit missing or invalid".to_string(),
-        )),
-    }
-}
+use crate::datatypes::{take_extension, DataType, Field, IntegerType, Metadata, Schema};
 
 fn to_int(item: &Value) -> Result<IntegerType> {
     Ok(match item.get("isSigned") {
@@ -143,154 +129,6 @@ fn read_metadata(metadata: &Value) -> Result<Metadata> {
     }
 }
 
-fn to_data_type(item: &Value, mut children: Vec<Field>) -> Result<DataType> {
-    let type_ = item
-        .get("name")
-        .ok_or_else(|| ArrowError::OutOfSpec("type missing".to_string()))?;
-
-    let type_ = if let Value::String(name) = type_ {
-        name.as_str()
-    } else {
-        return Err(ArrowError::OutOfSpec("type is not a string".to_string()));
-    };
-
-    use DataType::*;
-    Ok(match type_ {
-        "null" => Null,
-        "bool" => Boolean,
-        "binary" => Binary,
-        "largebinary" => LargeBinary,
-        "fixedsizebinary" => {
-            // return a list with any type as its child isn't defined in the map
-            if let Some(Value::Number(size)) = item.get("byteWidth") {
-                DataType::FixedSizeBinary(size.as_i64().unwrap() as usize)
-            } else {
-                return Err(ArrowError::OutOfSpec(
-                    "Expecting a byteWidth for fixedsizebinary".to_string(),
-                ));
-            }
-        }
-        "utf8" => Utf8,
-        "largeutf8" => LargeUtf8,
-        "decimal" => {
-            // return a list with any type as its child isn't defined in the map
-            let precision = match item.get("precision") {
-                Some(p) => Ok(p.as_u64().unwrap() as usize),
-                None => Err(ArrowError::OutOfSpec(
-                    "Expecting a precision for decimal".to_string(),
-                )),
-            };
-            let scale = match item.get("scale") {
-                Some(s) => Ok(s.as_u64().unwrap() as usize),
-                _ => Err(ArrowError::OutOfSpec(
-                    "Expecting a scale for decimal".to_string(),
-                )),
-            };
-
-            DataType::Decimal(precision?, scale?)
-        }
-        "floatingpoint" => match item.get("precision") {
-            Some(p) if p == "HALF" => DataType::Float16,
-            Some(p) if p == "SINGLE" => DataType::Float32,
-            Some(p) if p == "DOUBLE" => DataType::Float64,
-            _ => {
-                return Err(ArrowError::OutOfSpec(
-                    "floatingpoint precision missing or invalid".to_string(),
-                ))
-            }
-        },
-        "timestamp" => {
-            let unit = to_time_unit(item.get("unit"))?;
-            let tz = match item.get("timezone") {
-                None => Ok(None),
-                Some(Value::String(tz)) => Ok(Some(tz.clone())),
-                _ => Err(ArrowError::OutOfSpec(
-                    "timezone must be a string".to_string(),
-                )),
-            }?;
-            DataType::Timestamp(unit, tz)
-        }
-        "date" => match item.get("unit") {
-            Some(p) if p == "DAY" => DataType::Date32,
-            Some(p) if p == "MILLISECOND" => DataType::Date64,
-            _ => {
-                return Err(ArrowError::OutOfSpec(
-                    "date unit missing or invalid".to_string(),
-                ))
-            }
-        },
-        "time" => {
-            let unit = to_time_unit(item.get("unit"))?;
-            match item.get("bitWidth") {
-                Some(p) if p == 32 => DataType::Time32(unit),
-                Some(p) if p == 64 => DataType::Time64(unit),
-                _ => {
-                    return Err(ArrowError::OutOfSpec(
-                        "time bitWidth missing or invalid".to_string(),
-                    ))
-                }
-            }
-        }
-        "duration" => {
-            let unit = to_time_unit(item.get("unit"))?;
-            DataType::Duration(unit)
-        }
-        "interval" => match item.get("unit") {
-            Some(p) if p == "DAY_TIME" => DataType::Interval(IntervalUnit::DayTime),
-            Some(p) if p == "YEAR_MONTH" => DataType::Interval(IntervalUnit::YearMonth),
-            Some(p) if p == "MONTH_DAY_NANO" => DataType::Interval(IntervalUnit::MonthDayNano),
-            _ => {
-                return Err(ArrowError::OutOfSpec(
-                    "interval unit missing or invalid".to_string(),
-                ))
-            }
-        },
-        "int" => to_int(item).map(|x| x.into())?,
-        "list" => DataType::List(Box::new(children.pop().unwrap())),
-        "largelist" => DataType::LargeList(Box::new(children.pop().unwrap())),
-        "fixedsizelist" => {
-            if let Some(Value::Number(size)) = item.get("listSize") {
-                DataType::FixedSizeList(
-                    Box::new(children.pop().unwrap()),
-                    size.as_i64().unwrap() as usize,
-                )
-            } else {
-                return Err(ArrowError::OutOfSpec(
-                    "Expecting a listSize for fixedsizelist".to_string(),
-                ));
-            }
-        }
-        "struct" => DataType::Struct(children),
-        "union" => {
-            let mode = if let Some(Value::String(mode)) = item.get("mode") {
-                UnionMode::sparse(mode == "SPARSE")
-            } else {
-                return Err(ArrowError::OutOfSpec("union requires mode".to_string()));
-            };
-            let ids = if let Some(Value::Array(ids)) = item.get("typeIds") {
-                Some(ids.iter().map(|x| x.as_i64().unwrap() as i32).collect())
-            } else {
-                return Err(ArrowError::OutOfSpec("union requires ids".to_string()));
-            };
-            DataType::Union(children, ids, mode)
-        }
-        "map" => {
-            let sorted_keys = if let Some(Value::Bool(sorted_keys)) = item.get("keysSorted") {
-                *sorted_keys
-            } else {
-                return Err(ArrowError::OutOfSpec("sorted keys not defined".to_string()));
-            };
-            DataType::Map(Box::new(children.pop().unwrap()), sorted_keys)
-        }
-        other => {
-            return Err(ArrowError::NotYetImplemented(format!(
-                "invalid json value type \"{}\"",
-                other
-            )))
-        }
-    })
-}
-
 fn deserialize_ipc_field(value: &Value) -> Result<IpcField> {
     let map = if let Value::Object(map) = value {
         map
@@ -360,20 +198,20 @@ fn deserialize_field(value: &Value) -> Result<Field> {
         }
     };
 
-    let metadata = if let Some(metadata) = map.get("metadata") {
+    let mut metadata = if let Some(metadata) = map.get("metadata") {
         read_metadata(metadata)?
     } else {
         Metadata::default()
     };
 
-    let extension = get_extension(&metadata);
+    let extension = take_extension(&mut metadata);
 
     let type_ = map
         .get("type")
         .ok_or_else(|| ArrowError::OutOfSpec("type missing".to_string()))?;
 
     let children = deserialize_fields(map.get("children"))?;
-    let data_type = to_data_type(type_, children)?;
+    let data_type = deserialize_data_type(type_, children)?;
 
     let data_type = if let Some((name, metadata)) = extension {
         DataType::Extension(name, Box::new(data_type), metadata)
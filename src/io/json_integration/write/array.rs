@@ -1,14 +1,229 @@
 use std::sync::Arc;
 
+use serde_json::{json, Value};
+
 use crate::{
-    array::{Array, PrimitiveArray},
+    array::*,
     chunk::Chunk,
-    datatypes::DataType,
+    datatypes::{DataType, PhysicalType, PrimitiveType},
+    io::ipc::IpcField,
+    types::{days_ms, months_days_ns, NativeType},
 };
 
-use super::super::{ArrowJsonBatch, ArrowJsonColumn};
+use super::super::{ArrowJsonBatch, ArrowJsonColumn, ArrowJsonDictionaryBatch};
+
+fn serialize_validity(array: &dyn Array) -> Option<Vec<u8>> {
+    Some((0..array.len()).map(|i| array.is_valid(i) as u8).collect())
+}
+
+/// Serializes the offsets relative to the first one, so that sliced arrays are serialized
+/// like their unsliced counterparts. 64-bit offsets are serialized as strings.
+fn serialize_offsets<O: Offset>(offsets: &[O]) -> Vec<Value> {
+    let first = offsets[0].to_usize();
+    offsets
+        .iter()
+        .map(|offset| {
+            let offset = offset.to_usize() - first;
+            if O::is_large() {
+                Value::String(offset.to_string())
+            } else {
+                Value::from(offset)
+            }
+        })
+        .collect()
+}
+
+fn serialize_primitive<T: NativeType, F: Fn(&T) -> Value>(
+    array: &dyn Array,
+    serialize: F,
+) -> Vec<Value> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array.values().iter().map(serialize).collect()
+}
+
+/// The children of `array`, i.e. its values and their fields.
+fn serialize_children(array: &dyn Array) -> Option<Vec<ArrowJsonColumn>> {
+    use PhysicalType::*;
+    let fields = match array.data_type().to_logical_type() {
+        DataType::Union(fields, ..) | DataType::Struct(fields) => fields.clone(),
+        DataType::Map(field, ..)
+        | DataType::FixedSizeList(field, _)
+        | DataType::LargeList(field)
+        | DataType::List(field) => vec![field.as_ref().clone()],
+        _ => return None,
+    };
+    let values: Vec<Arc<dyn Array>> = match array.data_type().to_physical_type() {
+        List => vec![list_values::<i32>(array)],
+        LargeList => vec![list_values::<i64>(array)],
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            vec![array.values().clone()]
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = array.offsets();
+            let start = offsets[0] as usize;
+            let length = offsets[offsets.len() - 1] as usize - start;
+            vec![array.field().slice(start, length).into()]
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array.values().to_vec()
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            array.fields().clone()
+        }
+        _ => unreachable!(),
+    };
+    Some(
+        values
+            .iter()
+            .zip(fields.iter())
+            .map(|(values, field)| serialize_array(values.as_ref(), &field.name))
+            .collect(),
+    )
+}
+
+/// The values of a list, sliced to the range its offsets point to.
+fn list_values<O: Offset>(array: &dyn Array) -> Arc<dyn Array> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let offsets = array.offsets();
+    let start = offsets[0].to_usize();
+    let length = offsets[offsets.len() - 1].to_usize() - start;
+    array.values().slice(start, length).into()
+}
+
+fn serialize_array(array: &dyn Array, name: &str) -> ArrowJsonColumn {
+    use PhysicalType::*;
+    let mut column = ArrowJsonColumn {
+        name: name.to_string(),
+        count: array.len(),
+        validity: serialize_validity(array),
+        data: None,
+        offset: None,
+        type_id: None,
+        children: serialize_children(array),
+    };
+    match array.data_type().to_physical_type() {
+        Null => column.validity = None,
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            column.data = Some(array.values().iter().map(Value::Bool).collect());
+        }
+        Primitive(primitive) => {
+            column.data = Some(match primitive {
+                PrimitiveType::Int8 => serialize_primitive::<i8, _>(array, |x| Value::from(*x)),
+                PrimitiveType::Int16 => serialize_primitive::<i16, _>(array, |x| Value::from(*x)),
+                PrimitiveType::Int32 => serialize_primitive::<i32, _>(array, |x| Value::from(*x)),
+                PrimitiveType::Int64 => {
+                    serialize_primitive::<i64, _>(array, |x| Value::String(x.to_string()))
+                }
+                PrimitiveType::Int128 => {
+                    serialize_primitive::<i128, _>(array, |x| Value::String(x.to_string()))
+                }
+                PrimitiveType::UInt8 => serialize_primitive::<u8, _>(array, |x| Value::from(*x)),
+                PrimitiveType::UInt16 => serialize_primitive::<u16, _>(array, |x| Value::from(*x)),
+                PrimitiveType::UInt32 => serialize_primitive::<u32, _>(array, |x| Value::from(*x)),
+                PrimitiveType::UInt64 => {
+                    serialize_primitive::<u64, _>(array, |x| Value::String(x.to_string()))
+                }
+                PrimitiveType::Float32 => serialize_primitive::<f32, _>(array, |x| Value::from(*x)),
+                PrimitiveType::Float64 => serialize_primitive::<f64, _>(array, |x| Value::from(*x)),
+                PrimitiveType::DaysMs => serialize_primitive::<days_ms, _>(
+                    array,
+                    |x| json!({"days": x.days(), "milliseconds": x.milliseconds()}),
+                ),
+                PrimitiveType::MonthDayNano => serialize_primitive::<months_days_ns, _>(
+                    array,
+                    |x| json!({"months": x.months(), "days": x.days(), "nanoseconds": x.ns()}),
+                ),
+            })
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            column.offset = Some(serialize_offsets(array.offsets()));
+            column.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(hex::encode_upper(array.value(i))))
+                    .collect(),
+            );
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            column.offset = Some(serialize_offsets(array.offsets()));
+            column.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(hex::encode_upper(array.value(i))))
+                    .collect(),
+            );
+        }
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            column.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(hex::encode_upper(array.value(i))))
+                    .collect(),
+            );
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            column.offset = Some(serialize_offsets(array.offsets()));
+            column.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(array.value(i).to_string()))
+                    .collect(),
+            );
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            column.offset = Some(serialize_offsets(array.offsets()));
+            column.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(array.value(i).to_string()))
+                    .collect(),
+            );
+        }
+        List => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            column.offset = Some(serialize_offsets(array.offsets()));
+        }
+        LargeList => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            column.offset = Some(serialize_offsets(array.offsets()));
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            column.offset = Some(serialize_offsets(array.offsets()));
+        }
+        FixedSizeList | Struct => {}
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            column.validity = None;
+            column.type_id = Some(array.types().iter().map(|x| Value::from(*x)).collect());
+            column.offset = array
+                .offsets()
+                .as_ref()
+                .map(|offsets| offsets.iter().map(|x| Value::from(*x)).collect());
+        }
+        Dictionary(key_type) => {
+            // the column of a dictionary-encoded array is its keys
+            match_integer_type!(key_type, |$T| {
+                let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+                return serialize_array(array.keys(), name);
+            })
+        }
+    }
+    column
+}
 
 /// Serializes a [`Chunk`] to [`ArrowJsonBatch`].
+///
+/// Dictionary-encoded arrays are serialized as their keys; their values are serialized
+/// by [`serialize_dictionaries`].
 pub fn serialize_chunk<A: ToString>(
     columns: &Chunk<Arc<dyn Array>>,
     names: &[A],
@@ -19,36 +234,96 @@ pub fn serialize_chunk<A: ToString>(
         .arrays()
         .iter()
         .zip(names.iter())
-        .map(|(array, name)| match array.data_type() {
-            DataType::Int8 => {
-                let array = array.as_any().downcast_ref::<PrimitiveArray<i8>>().unwrap();
-
-                let (validity, data) = array
-                    .iter()
-                    .map(|x| (x.is_some() as u8, x.copied().unwrap_or_default().into()))
-                    .unzip();
-
-                ArrowJsonColumn {
-                    name: name.to_string(),
-                    count: array.len(),
-                    validity: Some(validity),
-                    data: Some(data),
-                    offset: None,
-                    type_id: None,
-                    children: None,
-                }
-            }
-            _ => ArrowJsonColumn {
-                name: name.to_string(),
-                count: array.len(),
-                validity: None,
-                data: None,
-                offset: None,
-                type_id: None,
-                children: None,
-            },
-        })
+        .map(|(array, name)| serialize_array(array.as_ref(), &name.to_string()))
         .collect();
 
     ArrowJsonBatch { count, columns }
 }
+
+fn collect_dictionaries(
+    array: &dyn Array,
+    ipc_field: &IpcField,
+    dictionaries: &mut Vec<ArrowJsonDictionaryBatch>,
+) {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array.as_any().downcast_ref::<DictionaryArray<$T>>().unwrap();
+            let id = ipc_field
+                .dictionary_id
+                .expect("All Dictionary types have `dict_id`");
+            if dictionaries.iter().all(|dictionary| dictionary.id != id) {
+                let values = array.values().as_ref();
+                let name = format!("DICT{}", id);
+                dictionaries.push(ArrowJsonDictionaryBatch {
+                    id,
+                    data: ArrowJsonBatch {
+                        count: values.len(),
+                        columns: vec![serialize_array(values, &name)],
+                    },
+                });
+            }
+            // the children of the field are the children of the values
+            collect_dictionaries(array.values().as_ref(), ipc_field, dictionaries)
+        }),
+        List => collect_dictionaries(
+            list_values::<i32>(array).as_ref(),
+            &ipc_field.fields[0],
+            dictionaries,
+        ),
+        LargeList => collect_dictionaries(
+            list_values::<i64>(array).as_ref(),
+            &ipc_field.fields[0],
+            dictionaries,
+        ),
+        FixedSizeList => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            collect_dictionaries(array.values().as_ref(), &ipc_field.fields[0], dictionaries)
+        }
+        Map => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            collect_dictionaries(array.field().as_ref(), &ipc_field.fields[0], dictionaries)
+        }
+        Struct => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            array
+                .values()
+                .iter()
+                .zip(ipc_field.fields.iter())
+                .for_each(|(values, ipc_field)| {
+                    collect_dictionaries(values.as_ref(), ipc_field, dictionaries)
+                })
+        }
+        Union => {
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            array
+                .fields()
+                .iter()
+                .zip(ipc_field.fields.iter())
+                .for_each(|(values, ipc_field)| {
+                    collect_dictionaries(values.as_ref(), ipc_field, dictionaries)
+                })
+        }
+        _ => {}
+    }
+}
+
+/// Serializes the dictionaries of the dictionary-encoded arrays of `columns` (and of their
+/// children) to [`ArrowJsonDictionaryBatch`]es, one per dictionary id of `ipc_fields`.
+///
+/// Only the first dictionary of each id is serialized, since the integration JSON format
+/// does not support replacing nor extending dictionaries.
+pub fn serialize_dictionaries(
+    columns: &Chunk<Arc<dyn Array>>,
+    ipc_fields: &[IpcField],
+) -> Vec<ArrowJsonDictionaryBatch> {
+    let mut dictionaries = vec![];
+    columns
+        .arrays()
+        .iter()
+        .zip(ipc_fields.iter())
+        .for_each(|(array, ipc_field)| {
+            collect_dictionaries(array.as_ref(), ipc_field, &mut dictionaries)
+        });
+    dictionaries
+}
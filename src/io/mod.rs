@@ -36,13 +36,27 @@ pub mod json_integration;
 #[cfg_attr(docsrs, doc(cfg(feature = "io_parquet")))]
 pub mod parquet;
 
+#[cfg(feature = "io_feather")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_feather")))]
+pub mod feather;
+
 #[cfg(feature = "io_avro")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_avro")))]
 pub mod avro;
 
+#[cfg(feature = "io_serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_serde")))]
+pub mod serde;
+
+#[cfg(feature = "io_odbc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_odbc")))]
+pub mod odbc;
+
 #[cfg(feature = "io_print")]
 #[cfg_attr(docsrs, doc(cfg(feature = "io_print")))]
 pub mod print;
 
+pub mod range;
+
 #[cfg(any(feature = "io_csv_write", feature = "io_avro", feature = "io_json"))]
 mod iterator;
@@ -0,0 +1,63 @@
+#![deny(missing_docs)]
+//! Read and write from and to ODBC (Open Database Connectivity) data sources.
+//!
+//! Data is exchanged via columnar buffers of [`odbc_api`] (re-exported as [`api`]):
+//! [`read::deserialize`] converts the columns of a fetched buffer to arrays and
+//! [`write::serialize`] fills the columns of a buffer from arrays, so that it can be
+//! bulk-inserted in a single round-trip.
+//!
+//! # Example
+//! ```no_run
+//! use std::sync::Arc;
+//! use arrow2::array::Array;
+//! use arrow2::chunk::Chunk;
+//! use arrow2::error::Result;
+//! use arrow2::io::odbc::{api, api::Cursor, read, write};
+//!
+//! fn main() -> Result<()> {
+//!     let connector = "Driver={SQLite3};Database=sqlite-test.db";
+//!     let env = api::Environment::new()?;
+//!     let connection = env.connect_with_connection_string(connector)?;
+//!
+//!     // read
+//!     let cursor = connection
+//!         .execute("SELECT a, b FROM example", ())?
+//!         .expect("the query returns a result set");
+//!     let fields = read::infer_schema(&cursor)?;
+//!     let buffer = read::buffer_from_metadata(&cursor, 1024)?;
+//!     let mut cursor = cursor.bind_buffer(buffer)?;
+//!     while let Some(batch) = cursor.fetch()? {
+//!         let arrays = (0..batch.num_cols())
+//!             .zip(fields.iter())
+//!             .map(|(index, field)| {
+//!                 read::deserialize(batch.column(index), field.data_type.clone()).into()
+//!             })
+//!             .collect::<Vec<Arc<dyn Array>>>();
+//!         let chunk = Chunk::new(arrays);
+//!     }
+//!
+//!     // write
+//!     let mut prepared = connection.prepare("INSERT INTO example (a, b) VALUES (?, ?)")?;
+//!     let descriptions = write::infer_descriptions(&fields)?;
+//!     let mut buffer = write::buffer_from_description(descriptions, 1024);
+//!     # let chunk: Chunk<Arc<dyn Array>> = unimplemented!();
+//!     buffer.set_num_rows(chunk.len());
+//!     for (index, array) in chunk.arrays().iter().enumerate() {
+//!         write::serialize(array.as_ref(), &mut buffer.column_mut(index))?;
+//!     }
+//!     prepared.execute(&buffer)?;
+//!     Ok(())
+//! }
+//! ```
+pub use odbc_api as api;
+
+pub mod read;
+pub mod write;
+
+use crate::error::ArrowError;
+
+impl From<api::Error> for ArrowError {
+    fn from(error: api::Error) -> Self {
+        ArrowError::External("".to_string(), Box::new(error))
+    }
+}
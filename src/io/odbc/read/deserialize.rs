@@ -0,0 +1,225 @@
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, PrimitiveArray, Utf8Array,
+};
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::{DataType, TimeUnit};
+use crate::temporal_conversions::{naive_datetime_to_timestamp, EPOCH_DAYS_FROM_CE};
+use crate::types::NativeType;
+use crate::util::decimal::parse_decimal;
+
+use super::super::api;
+use super::super::api::buffers::{AnyColumnView, BinColumnIt, NullableSlice, TextColumnIt};
+
+/// Deserializes a [`AnyColumnView`] into an array of [`DataType`].
+/// This is CPU-bounded
+/// # Panics
+/// Panics iff `data_type` is not the one inferred by [`super::infer_schema`] for the column.
+pub fn deserialize(column: AnyColumnView, data_type: DataType) -> Box<dyn Array> {
+    match column {
+        AnyColumnView::Text(iter) => match data_type {
+            DataType::Decimal(precision, scale) => Box::new(decimal(iter, precision, scale)),
+            _ => Box::new(utf8(iter)),
+        },
+        AnyColumnView::WText(iter) => Box::new(utf16(iter)),
+        AnyColumnView::Binary(iter) => match data_type {
+            DataType::FixedSizeBinary(_) => Box::new(fixed_binary(data_type, iter)),
+            _ => Box::new(binary(iter)),
+        },
+        AnyColumnView::Date(values) => Box::new(date(data_type, values)),
+        AnyColumnView::Time(values) => Box::new(time(values)),
+        AnyColumnView::Timestamp(values) => Box::new(timestamp(data_type, values)),
+        AnyColumnView::F64(values) => Box::new(primitive(data_type, values)),
+        AnyColumnView::F32(values) => Box::new(primitive(data_type, values)),
+        AnyColumnView::I8(values) => Box::new(primitive(data_type, values)),
+        AnyColumnView::I16(values) => Box::new(primitive(data_type, values)),
+        AnyColumnView::I32(values) => Box::new(primitive(data_type, values)),
+        AnyColumnView::I64(values) => Box::new(primitive(data_type, values)),
+        AnyColumnView::U8(values) => Box::new(primitive(data_type, values)),
+        AnyColumnView::Bit(values) => Box::new(bool(data_type, values)),
+        AnyColumnView::NullableDate(slice) => Box::new(date_optional(data_type, slice)),
+        AnyColumnView::NullableTime(slice) => Box::new(time_optional(slice)),
+        AnyColumnView::NullableTimestamp(slice) => Box::new(timestamp_optional(data_type, slice)),
+        AnyColumnView::NullableF64(slice) => Box::new(primitive_optional(data_type, slice)),
+        AnyColumnView::NullableF32(slice) => Box::new(primitive_optional(data_type, slice)),
+        AnyColumnView::NullableI8(slice) => Box::new(primitive_optional(data_type, slice)),
+        AnyColumnView::NullableI16(slice) => Box::new(primitive_optional(data_type, slice)),
+        AnyColumnView::NullableI32(slice) => Box::new(primitive_optional(data_type, slice)),
+        AnyColumnView::NullableI64(slice) => Box::new(primitive_optional(data_type, slice)),
+        AnyColumnView::NullableU8(slice) => Box::new(primitive_optional(data_type, slice)),
+        AnyColumnView::NullableBit(slice) => Box::new(bool_optional(data_type, slice)),
+    }
+}
+
+/// The validity of ODBC indicators, where `NULL_DATA` (-1) denotes a null value.
+fn bitmap(indicators: &[isize]) -> Option<Bitmap> {
+    let is_valid = indicators
+        .iter()
+        .map(|indicator| *indicator != api::sys::NULL_DATA);
+    let validity: Bitmap = MutableBitmap::from_trusted_len_iter(is_valid).into();
+    if validity.null_count() > 0 {
+        Some(validity)
+    } else {
+        None
+    }
+}
+
+fn primitive<T: NativeType>(data_type: DataType, values: &[T]) -> PrimitiveArray<T> {
+    PrimitiveArray::from_data(data_type, values.to_vec().into(), None)
+}
+
+fn primitive_optional<T: NativeType>(
+    data_type: DataType,
+    values: NullableSlice<T>,
+) -> PrimitiveArray<T> {
+    let (values, indicators) = values.raw_values();
+    PrimitiveArray::from_data(data_type, values.to_vec().into(), bitmap(indicators))
+}
+
+fn bool(data_type: DataType, values: &[api::Bit]) -> BooleanArray {
+    let values = values.iter().map(|x| x.as_bool());
+    let values = MutableBitmap::from_trusted_len_iter(values);
+    BooleanArray::from_data(data_type, values.into(), None)
+}
+
+fn bool_optional(data_type: DataType, values: NullableSlice<api::Bit>) -> BooleanArray {
+    let (values, indicators) = values.raw_values();
+    let values = values.iter().map(|x| x.as_bool());
+    let values = MutableBitmap::from_trusted_len_iter(values);
+    BooleanArray::from_data(data_type, values.into(), bitmap(indicators))
+}
+
+fn binary(view: BinColumnIt) -> BinaryArray<i32> {
+    let values = view.collect::<Vec<_>>();
+    BinaryArray::from(values)
+}
+
+fn fixed_binary(data_type: DataType, view: BinColumnIt) -> FixedSizeBinaryArray {
+    let size = FixedSizeBinaryArray::get_size(&data_type);
+    let mut values = Vec::<u8>::new();
+    let mut validity = MutableBitmap::new();
+    view.for_each(|value| {
+        let start = values.len();
+        if let Some(value) = value {
+            values.extend_from_slice(value);
+        }
+        // values are padded (or truncated) to the size of the type
+        values.resize(start + size, 0);
+        validity.push(value.is_some());
+    });
+    FixedSizeBinaryArray::from_data(data_type, values.into(), validity.into())
+}
+
+fn utf8(view: TextColumnIt<u8>) -> Utf8Array<i32> {
+    let values = view
+        .map(|value| value.map(|value| String::from_utf8_lossy(value).into_owned()))
+        .collect::<Vec<_>>();
+    Utf8Array::from(values)
+}
+
+fn utf16(view: TextColumnIt<u16>) -> Utf8Array<i32> {
+    let values = view
+        .map(|value| value.map(String::from_utf16_lossy))
+        .collect::<Vec<_>>();
+    Utf8Array::from(values)
+}
+
+/// Decimals are fetched as text, e.g. `"-12.34"`
+fn decimal(view: TextColumnIt<u8>, precision: usize, scale: usize) -> PrimitiveArray<i128> {
+    let values = view
+        .map(|value| value.and_then(|value| parse_decimal(value, precision, scale, true)))
+        .collect::<Vec<_>>();
+    PrimitiveArray::from(values).to(DataType::Decimal(precision, scale))
+}
+
+fn date(data_type: DataType, values: &[api::sys::Date]) -> PrimitiveArray<i32> {
+    let values = values.iter().map(days_since_epoch).collect::<Vec<_>>();
+    PrimitiveArray::from_data(data_type, values.into(), None)
+}
+
+fn date_optional(
+    data_type: DataType,
+    values: NullableSlice<api::sys::Date>,
+) -> PrimitiveArray<i32> {
+    let (values, indicators) = values.raw_values();
+    let values = values.iter().map(days_since_epoch).collect::<Vec<_>>();
+    PrimitiveArray::from_data(data_type, values.into(), bitmap(indicators))
+}
+
+fn days_since_epoch(date: &api::sys::Date) -> i32 {
+    NaiveDate::from_ymd_opt(date.year as i32, date.month as u32, date.day as u32)
+        .map(|date| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+        .unwrap_or(0)
+}
+
+/// Time columns are fetched as `Time64(Nanosecond)`
+fn time(values: &[api::sys::Time]) -> PrimitiveArray<i64> {
+    let values = values.iter().map(time_to_ns).collect::<Vec<_>>();
+    PrimitiveArray::from_data(DataType::Time64(TimeUnit::Nanosecond), values.into(), None)
+}
+
+fn time_optional(values: NullableSlice<api::sys::Time>) -> PrimitiveArray<i64> {
+    let (values, indicators) = values.raw_values();
+    let values = values.iter().map(time_to_ns).collect::<Vec<_>>();
+    PrimitiveArray::from_data(
+        DataType::Time64(TimeUnit::Nanosecond),
+        values.into(),
+        bitmap(indicators),
+    )
+}
+
+fn time_to_ns(time: &api::sys::Time) -> i64 {
+    (time.hour as i64 * 3_600 + time.minute as i64 * 60 + time.second as i64) * 1_000_000_000
+}
+
+fn timestamp(data_type: DataType, values: &[api::sys::Timestamp]) -> PrimitiveArray<i64> {
+    let unit = time_unit(&data_type);
+    let values = values
+        .iter()
+        .map(|timestamp| timestamp_to_unit(timestamp, unit))
+        .collect::<Vec<_>>();
+    PrimitiveArray::from_data(data_type, values.into(), None)
+}
+
+fn timestamp_optional(
+    data_type: DataType,
+    values: NullableSlice<api::sys::Timestamp>,
+) -> PrimitiveArray<i64> {
+    let unit = time_unit(&data_type);
+    let (values, indicators) = values.raw_values();
+    let values = values
+        .iter()
+        .map(|timestamp| timestamp_to_unit(timestamp, unit))
+        .collect::<Vec<_>>();
+    PrimitiveArray::from_data(data_type, values.into(), bitmap(indicators))
+}
+
+fn time_unit(data_type: &DataType) -> TimeUnit {
+    if let DataType::Timestamp(unit, _) = data_type.to_logical_type() {
+        *unit
+    } else {
+        panic!("The data type of an ODBC timestamp column must be a Timestamp")
+    }
+}
+
+fn timestamp_to_naive(timestamp: &api::sys::Timestamp) -> Option<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(
+        timestamp.year as i32,
+        timestamp.month as u32,
+        timestamp.day as u32,
+    )?
+    .and_hms_nano_opt(
+        timestamp.hour as u32,
+        timestamp.minute as u32,
+        timestamp.second as u32,
+        // the fraction is in nanoseconds
+        timestamp.fraction,
+    )
+}
+
+fn timestamp_to_unit(timestamp: &api::sys::Timestamp, unit: TimeUnit) -> i64 {
+    timestamp_to_naive(timestamp)
+        .map(|datetime| naive_datetime_to_timestamp(&datetime, unit))
+        .unwrap_or(0)
+}
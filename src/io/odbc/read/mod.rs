@@ -0,0 +1,70 @@
+//! APIs to read from ODBC
+mod deserialize;
+mod schema;
+
+pub use deserialize::deserialize;
+pub use schema::infer_schema;
+
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+use super::api;
+use super::api::buffers::{AnyColumnBuffer, BufferDescription, BufferKind, ColumnarBuffer};
+use super::api::ResultSetMetadata;
+
+/// Creates a [`ColumnarBuffer`] to fetch up to `max_batch_size` rows at a time of the
+/// result set described by `resut`. The columns of the buffer are deserialized by [`deserialize`]
+/// to the data types inferred by [`infer_schema`].
+/// # Errors
+/// Errors iff the result set has columns of unknown types.
+pub fn buffer_from_metadata(
+    resut: &impl ResultSetMetadata,
+    max_batch_size: usize,
+) -> Result<ColumnarBuffer<AnyColumnBuffer>> {
+    let num_cols: u16 = resut.num_result_cols()?.try_into().unwrap();
+
+    let descriptions = (0..num_cols)
+        .map(|index| {
+            let mut column_description = api::ColumnDescription::default();
+            resut.describe_col(index + 1, &mut column_description)?;
+            Ok(BufferDescription {
+                nullable: column_description.could_be_nullable(),
+                kind: column_to_buffer_kind(&column_description.data_type)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(api::buffers::buffer_from_description(
+        max_batch_size,
+        descriptions.into_iter(),
+    ))
+}
+
+/// The [`BufferKind`] of columns of type `data_type`, matching the [`DataType`] inferred by [`infer_schema`].
+fn column_to_buffer_kind(data_type: &api::DataType) -> Result<BufferKind> {
+    if let api::DataType::Unknown = data_type {
+        return Err(ArrowError::nyi("Reading ODBC columns of unknown type"));
+    }
+    Ok(match schema::column_to_data_type(data_type) {
+        // decimals are fetched as text, so that no precision is lost
+        DataType::Decimal(precision, _) => BufferKind::Text {
+            // the sign and the decimal point
+            max_str_len: precision + 2,
+        },
+        DataType::Int8 => BufferKind::I8,
+        DataType::Int16 => BufferKind::I16,
+        DataType::Int32 => BufferKind::I32,
+        DataType::Int64 => BufferKind::I64,
+        DataType::Float32 => BufferKind::F32,
+        DataType::Float64 => BufferKind::F64,
+        DataType::Boolean => BufferKind::Bit,
+        DataType::Date32 => BufferKind::Date,
+        DataType::Timestamp(_, _) => BufferKind::Timestamp,
+        DataType::FixedSizeBinary(_) | DataType::Binary => BufferKind::Binary {
+            length: data_type.column_size(),
+        },
+        _ => BufferKind::Text {
+            max_str_len: data_type.column_size(),
+        },
+    })
+}
@@ -0,0 +1,64 @@
+use crate::datatypes::{DataType, Field, TimeUnit};
+use crate::error::{ArrowError, Result};
+
+use super::super::api;
+use super::super::api::ResultSetMetadata;
+
+/// Infers the [`Field`]s of the columns of a result set, e.g. of a cursor
+/// returned by `execute` or of a prepared statement.
+pub fn infer_schema(resut: &impl ResultSetMetadata) -> Result<Vec<Field>> {
+    let num_cols: u16 = resut.num_result_cols()?.try_into().unwrap();
+
+    (0..num_cols)
+        .map(|index| {
+            let mut column_description = api::ColumnDescription::default();
+            resut.describe_col(index + 1, &mut column_description)?;
+            column_to_field(&column_description)
+        })
+        .collect()
+}
+
+fn column_to_field(column_description: &api::ColumnDescription) -> Result<Field> {
+    Ok(Field::new(
+        &column_description
+            .name_to_string()
+            .map_err(ArrowError::from_external_error)?,
+        column_to_data_type(&column_description.data_type),
+        column_description.could_be_nullable(),
+    ))
+}
+
+/// The [`DataType`] a column of the ODBC type `data_type` is deserialized to.
+pub(super) fn column_to_data_type(data_type: &api::DataType) -> DataType {
+    use api::DataType as OdbcDataType;
+    match data_type {
+        OdbcDataType::Numeric { precision, scale } | OdbcDataType::Decimal { precision, scale } => {
+            DataType::Decimal(*precision as usize, *scale as usize)
+        }
+        OdbcDataType::Integer => DataType::Int32,
+        OdbcDataType::SmallInt => DataType::Int16,
+        OdbcDataType::TinyInt => DataType::Int8,
+        OdbcDataType::BigInt => DataType::Int64,
+        OdbcDataType::Real => DataType::Float32,
+        OdbcDataType::Float { .. } | OdbcDataType::Double => DataType::Float64,
+        OdbcDataType::Bit => DataType::Boolean,
+        OdbcDataType::Date => DataType::Date32,
+        OdbcDataType::Timestamp { precision } => {
+            DataType::Timestamp(precision_to_time_unit(*precision as usize), None)
+        }
+        OdbcDataType::Binary { length } => DataType::FixedSizeBinary(*length as usize),
+        OdbcDataType::Varbinary { .. } | OdbcDataType::LongVarbinary { .. } => DataType::Binary,
+        // everything else, including `Time`, is fetched as text.
+        _ => DataType::Utf8,
+    }
+}
+
+/// The [`TimeUnit`] able to represent timestamps with `precision` fractional digits
+fn precision_to_time_unit(precision: usize) -> TimeUnit {
+    match precision {
+        0 => TimeUnit::Second,
+        1..=3 => TimeUnit::Millisecond,
+        4..=6 => TimeUnit::Microsecond,
+        _ => TimeUnit::Nanosecond,
+    }
+}
@@ -0,0 +1,20 @@
+//! APIs to write to ODBC
+mod schema;
+mod serialize;
+
+pub use schema::infer_descriptions;
+pub use serialize::serialize;
+
+use super::api;
+use super::api::buffers::{AnyColumnBuffer, BufferDescription, ColumnarBuffer};
+
+/// Creates a [`ColumnarBuffer`] with columns described by `descriptions` (e.g. inferred
+/// from the fields of a schema by [`infer_descriptions`]) and able to hold up to `capacity`
+/// rows. Its columns are filled by [`serialize`], so that the rows can be bulk-inserted
+/// by executing a prepared statement with it.
+pub fn buffer_from_description(
+    descriptions: Vec<BufferDescription>,
+    capacity: usize,
+) -> ColumnarBuffer<AnyColumnBuffer> {
+    api::buffers::buffer_from_description(capacity, descriptions.into_iter())
+}
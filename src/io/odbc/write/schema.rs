@@ -0,0 +1,49 @@
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+use super::super::api::buffers::{BufferDescription, BufferKind};
+
+/// Infers the [`BufferDescription`]s of the columns to which arrays of `fields` are serialized.
+/// # Errors
+/// Errors iff a field's [`DataType`] cannot be written to ODBC.
+pub fn infer_descriptions(fields: &[Field]) -> Result<Vec<BufferDescription>> {
+    fields
+        .iter()
+        .map(|field| {
+            Ok(BufferDescription {
+                nullable: field.is_nullable,
+                kind: data_type_to_buffer_kind(field.data_type())?,
+            })
+        })
+        .collect()
+}
+
+fn data_type_to_buffer_kind(data_type: &DataType) -> Result<BufferKind> {
+    Ok(match data_type.to_logical_type() {
+        DataType::Boolean => BufferKind::Bit,
+        DataType::Int8 => BufferKind::I8,
+        DataType::Int16 => BufferKind::I16,
+        DataType::Int32 => BufferKind::I32,
+        DataType::Int64 => BufferKind::I64,
+        DataType::UInt8 => BufferKind::U8,
+        DataType::Float32 => BufferKind::F32,
+        DataType::Float64 => BufferKind::F64,
+        DataType::Date32 => BufferKind::Date,
+        DataType::Timestamp(_, None) => BufferKind::Timestamp,
+        // decimals are written as text, so that no precision is lost
+        DataType::Decimal(precision, _) => BufferKind::Text {
+            // the sign and the decimal point
+            max_str_len: precision + 2,
+        },
+        // the length of variable-sized columns is set from the data by `serialize`
+        DataType::Utf8 | DataType::LargeUtf8 => BufferKind::Text { max_str_len: 0 },
+        DataType::Binary | DataType::LargeBinary => BufferKind::Binary { length: 0 },
+        DataType::FixedSizeBinary(size) => BufferKind::Binary { length: *size },
+        other => {
+            return Err(ArrowError::nyi(format!(
+                "Writing arrays of type {:?} to ODBC",
+                other
+            )))
+        }
+    })
+}
@@ -0,0 +1,291 @@
+use chrono::{Datelike, Timelike};
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, Offset, PrimitiveArray, Utf8Array,
+};
+use crate::bitmap::Bitmap;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::temporal_conversions::{date32_to_date, timestamp_to_naive_datetime};
+use crate::types::NativeType;
+use crate::util::decimal::format_decimal;
+
+use super::super::api;
+use super::super::api::buffers::{
+    AnyColumnViewMut, BinColumnWriter, NullableSliceMut, TextColumnWriter,
+};
+
+/// Serializes an [`Array`] to the column [`AnyColumnViewMut`] of a buffer, e.g. one created by
+/// [`super::buffer_from_description`] with the descriptions of [`super::infer_descriptions`].
+/// This operation is CPU-bounded.
+/// # Errors
+/// Errors iff the array does not match the type of the column, or has nulls and the column
+/// is not nullable.
+/// # Panics
+/// Panics iff the column has fewer rows than the array.
+pub fn serialize(array: &dyn Array, column: &mut AnyColumnViewMut) -> Result<()> {
+    match column {
+        AnyColumnViewMut::Text(values) => match array.data_type().to_logical_type() {
+            DataType::Decimal(_, _) => decimal(downcast(array)?, values),
+            DataType::Utf8 => utf8::<i32>(downcast(array)?, values),
+            DataType::LargeUtf8 => utf8::<i64>(downcast(array)?, values),
+            other => Err(mismatch(other, "text")),
+        },
+        AnyColumnViewMut::Binary(values) => match array.data_type().to_logical_type() {
+            DataType::Binary => binary::<i32>(downcast(array)?, values),
+            DataType::LargeBinary => binary::<i64>(downcast(array)?, values),
+            DataType::FixedSizeBinary(_) => fixed_binary(downcast(array)?, values),
+            other => Err(mismatch(other, "binary")),
+        },
+        AnyColumnViewMut::Bit(values) => bool(downcast(array)?, values),
+        AnyColumnViewMut::NullableBit(values) => bool_optional(downcast(array)?, values),
+        AnyColumnViewMut::I8(values) => primitive(downcast(array)?, values),
+        AnyColumnViewMut::NullableI8(values) => primitive_optional(downcast(array)?, values),
+        AnyColumnViewMut::I16(values) => primitive(downcast(array)?, values),
+        AnyColumnViewMut::NullableI16(values) => primitive_optional(downcast(array)?, values),
+        AnyColumnViewMut::I32(values) => primitive(downcast(array)?, values),
+        AnyColumnViewMut::NullableI32(values) => primitive_optional(downcast(array)?, values),
+        AnyColumnViewMut::I64(values) => primitive(downcast(array)?, values),
+        AnyColumnViewMut::NullableI64(values) => primitive_optional(downcast(array)?, values),
+        AnyColumnViewMut::U8(values) => primitive(downcast(array)?, values),
+        AnyColumnViewMut::NullableU8(values) => primitive_optional(downcast(array)?, values),
+        AnyColumnViewMut::F32(values) => primitive(downcast(array)?, values),
+        AnyColumnViewMut::NullableF32(values) => primitive_optional(downcast(array)?, values),
+        AnyColumnViewMut::F64(values) => primitive(downcast(array)?, values),
+        AnyColumnViewMut::NullableF64(values) => primitive_optional(downcast(array)?, values),
+        AnyColumnViewMut::Date(values) => date(downcast(array)?, values),
+        AnyColumnViewMut::NullableDate(values) => date_optional(downcast(array)?, values),
+        AnyColumnViewMut::Timestamp(values) => timestamp(downcast(array)?, values),
+        AnyColumnViewMut::NullableTimestamp(values) => timestamp_optional(downcast(array)?, values),
+        _ => Err(ArrowError::nyi(format!(
+            "Writing arrays of type {:?} to this ODBC column",
+            array.data_type()
+        ))),
+    }
+}
+
+fn mismatch(data_type: &DataType, column: &str) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "Arrays of type {:?} cannot be written to a {} ODBC column",
+        data_type, column
+    ))
+}
+
+fn downcast<A: Array + 'static>(array: &dyn Array) -> Result<&A> {
+    array.as_any().downcast_ref::<A>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Arrays of type {:?} cannot be written to this ODBC column",
+            array.data_type()
+        ))
+    })
+}
+
+/// Non-nullable columns cannot represent nulls
+fn check_no_nulls(array: &dyn Array) -> Result<()> {
+    if array.null_count() > 0 {
+        Err(ArrowError::InvalidArgumentError(
+            "Arrays with nulls cannot be written to a non-nullable ODBC column".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Writes the validity to ODBC indicators, where `NULL_DATA` (-1) denotes a null value.
+fn write_validity(validity: Option<&Bitmap>, indicators: &mut [isize]) {
+    if let Some(validity) = validity {
+        indicators
+            .iter_mut()
+            .zip(validity.iter())
+            .for_each(|(indicator, is_valid)| {
+                *indicator = if is_valid { 0 } else { api::sys::NULL_DATA }
+            })
+    } else {
+        indicators.iter_mut().for_each(|indicator| *indicator = 0)
+    }
+}
+
+fn primitive<T: NativeType>(array: &PrimitiveArray<T>, values: &mut [T]) -> Result<()> {
+    check_no_nulls(array)?;
+    values[..array.len()].copy_from_slice(array.values());
+    Ok(())
+}
+
+fn primitive_optional<T: NativeType>(
+    array: &PrimitiveArray<T>,
+    values: &mut NullableSliceMut<T>,
+) -> Result<()> {
+    let (values, indicators) = values.raw_values();
+    values[..array.len()].copy_from_slice(array.values());
+    write_validity(array.validity(), &mut indicators[..array.len()]);
+    Ok(())
+}
+
+fn bool(array: &BooleanArray, values: &mut [api::Bit]) -> Result<()> {
+    check_no_nulls(array)?;
+    values
+        .iter_mut()
+        .zip(array.values().iter())
+        .for_each(|(bit, value)| *bit = api::Bit::from_bool(value));
+    Ok(())
+}
+
+fn bool_optional(array: &BooleanArray, values: &mut NullableSliceMut<api::Bit>) -> Result<()> {
+    let (values, indicators) = values.raw_values();
+    values
+        .iter_mut()
+        .zip(array.values().iter())
+        .for_each(|(bit, value)| *bit = api::Bit::from_bool(value));
+    write_validity(array.validity(), &mut indicators[..array.len()]);
+    Ok(())
+}
+
+fn to_date(days: i32) -> api::sys::Date {
+    let date = date32_to_date(days);
+    api::sys::Date {
+        year: date.year() as i16,
+        month: date.month() as u16,
+        day: date.day() as u16,
+    }
+}
+
+fn date(array: &PrimitiveArray<i32>, values: &mut [api::sys::Date]) -> Result<()> {
+    check_no_nulls(array)?;
+    values
+        .iter_mut()
+        .zip(array.values().iter())
+        .for_each(|(date, days)| *date = to_date(*days));
+    Ok(())
+}
+
+fn date_optional(
+    array: &PrimitiveArray<i32>,
+    values: &mut NullableSliceMut<api::sys::Date>,
+) -> Result<()> {
+    let (values, indicators) = values.raw_values();
+    values
+        .iter_mut()
+        .zip(array.values().iter())
+        .for_each(|(date, days)| *date = to_date(*days));
+    write_validity(array.validity(), &mut indicators[..array.len()]);
+    Ok(())
+}
+
+fn to_timestamp(value: i64, data_type: &DataType) -> api::sys::Timestamp {
+    let unit = if let DataType::Timestamp(unit, _) = data_type.to_logical_type() {
+        *unit
+    } else {
+        unreachable!()
+    };
+    let datetime = timestamp_to_naive_datetime(value, unit);
+    api::sys::Timestamp {
+        year: datetime.year() as i16,
+        month: datetime.month() as u16,
+        day: datetime.day() as u16,
+        hour: datetime.hour() as u16,
+        minute: datetime.minute() as u16,
+        second: datetime.second() as u16,
+        // in nanoseconds
+        fraction: datetime.nanosecond(),
+    }
+}
+
+fn check_timestamp(array: &PrimitiveArray<i64>) -> Result<()> {
+    if let DataType::Timestamp(_, None) = array.data_type().to_logical_type() {
+        Ok(())
+    } else {
+        Err(mismatch(array.data_type(), "timestamp"))
+    }
+}
+
+fn timestamp(array: &PrimitiveArray<i64>, values: &mut [api::sys::Timestamp]) -> Result<()> {
+    check_timestamp(array)?;
+    check_no_nulls(array)?;
+    values
+        .iter_mut()
+        .zip(array.values().iter())
+        .for_each(|(timestamp, value)| *timestamp = to_timestamp(*value, array.data_type()));
+    Ok(())
+}
+
+fn timestamp_optional(
+    array: &PrimitiveArray<i64>,
+    values: &mut NullableSliceMut<api::sys::Timestamp>,
+) -> Result<()> {
+    check_timestamp(array)?;
+    let (values, indicators) = values.raw_values();
+    values
+        .iter_mut()
+        .zip(array.iter())
+        .for_each(|(timestamp, value)| {
+            // nulls are not converted, since their values may be out of range
+            if let Some(value) = value {
+                *timestamp = to_timestamp(*value, array.data_type())
+            }
+        });
+    write_validity(array.validity(), &mut indicators[..array.len()]);
+    Ok(())
+}
+
+fn utf8<O: Offset>(array: &Utf8Array<O>, writer: &mut TextColumnWriter<u8>) -> Result<()> {
+    let max_len = array
+        .offsets()
+        .windows(2)
+        .map(|x| (x[1] - x[0]).to_usize())
+        .max()
+        .unwrap_or(0);
+    writer.set_max_len(max_len);
+    array
+        .iter()
+        .enumerate()
+        .for_each(|(row, value)| writer.set_value(row, value.map(|x| x.as_bytes())));
+    Ok(())
+}
+
+/// Decimals are written as text, e.g. `"-12.34"`
+fn decimal(array: &PrimitiveArray<i128>, writer: &mut TextColumnWriter<u8>) -> Result<()> {
+    let scale = if let DataType::Decimal(_, scale) = array.data_type().to_logical_type() {
+        *scale
+    } else {
+        return Err(mismatch(array.data_type(), "text"));
+    };
+    let values = array
+        .iter()
+        .map(|value| value.map(|value| format_decimal(*value, scale)))
+        .collect::<Vec<_>>();
+    let max_len = values
+        .iter()
+        .map(|value| value.as_ref().map(|x| x.len()).unwrap_or(0))
+        .max()
+        .unwrap_or(0);
+    writer.set_max_len(max_len);
+    values
+        .iter()
+        .enumerate()
+        .for_each(|(row, value)| writer.set_value(row, value.as_ref().map(|x| x.as_bytes())));
+    Ok(())
+}
+
+fn binary<O: Offset>(array: &BinaryArray<O>, writer: &mut BinColumnWriter) -> Result<()> {
+    let max_len = array
+        .offsets()
+        .windows(2)
+        .map(|x| (x[1] - x[0]).to_usize())
+        .max()
+        .unwrap_or(0);
+    writer.set_max_len(max_len);
+    array
+        .iter()
+        .enumerate()
+        .for_each(|(row, value)| writer.set_value(row, value));
+    Ok(())
+}
+
+fn fixed_binary(array: &FixedSizeBinaryArray, writer: &mut BinColumnWriter) -> Result<()> {
+    writer.set_max_len(array.size());
+    array
+        .iter()
+        .enumerate()
+        .for_each(|(row, value)| writer.set_value(row, value));
+    Ok(())
+}
@@ -0,0 +1,501 @@
+//! APIs to read, build and probe parquet's split-block bloom filters.
+//!
+//! A bloom filter of a column chunk answers whether a value is *definitely not* in it, which
+//! allows skipping column chunks (and thus row groups) when looking up specific values.
+//! Values are hashed with `xxhash64` (seed 0) over their plain encoding, as declared in
+//! [parquet's specification](https://github.com/apache/parquet-format/blob/master/BloomFilter.md).
+//!
+//! Bloom filters are written after the column chunks of a file via [`write`], which the
+//! parquet `FileSink` does for the fields of its [`BloomFilterOptions`].
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader, FileMetaData,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+use xxhash_rust::xxh64::xxh64;
+
+use crate::array::{Array, BinaryArray, PrimitiveArray, Utf8Array};
+use crate::datatypes::{DataType, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::scalar::{BinaryScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+use crate::types::NativeType;
+
+use super::read::ColumnChunkMetaData;
+
+/// The salt of the split-block bloom filter, as declared in the specification.
+const SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+
+/// The number of bytes of a block.
+const BLOCK_SIZE: usize = 32;
+
+/// The maximum number of bytes of a bloom filter, as declared in the specification.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// The magic number at the end of a parquet file.
+const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+
+/// Options to write a bloom filter for each column chunk of some fields of a file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BloomFilterOptions {
+    /// The indices of the fields whose column chunks have a bloom filter. They must be
+    /// non-nested and of a type supported by bloom filters.
+    pub fields: Vec<usize>,
+    /// The false positive probability of the bloom filters, in `(0, 1)`
+    pub fpp: f64,
+}
+
+/// Returns whether bloom filters of fields of `data_type` are supported, i.e. whether they are
+/// non-nested and their values are written to parquet as their plain encoding.
+pub fn is_supported(data_type: &DataType) -> bool {
+    matches!(
+        data_type.to_logical_type(),
+        DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+            | DataType::Duration(_)
+            | DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Binary
+            | DataType::LargeBinary
+    )
+}
+
+fn hash_to_block_index(hash: u64, num_blocks: usize) -> usize {
+    (((hash >> 32) * num_blocks as u64) >> 32) as usize
+}
+
+fn new_mask(x: u32) -> [u32; 8] {
+    let mut a = [0u32; 8];
+    for i in 0..8 {
+        let y = x.wrapping_mul(SALT[i]);
+        a[i] = 1 << (y >> 27);
+    }
+    a
+}
+
+/// Returns the number of bytes of a bloom filter with a false positive probability of `fpp`
+/// for `num_distinct` values. The result is a power of two between 32 bytes and 128MiB.
+pub fn optimal_num_bytes(num_distinct: usize, fpp: f64) -> usize {
+    let num_bits = -8.0 * num_distinct as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(BLOCK_SIZE, MAX_NUM_BYTES)
+        .next_power_of_two()
+}
+
+/// Inserts a new hash to the bloom filter `bitset`.
+/// # Panics
+/// Panics iff `bitset` is empty or its length is not a multiple of 32.
+pub fn insert(bitset: &mut [u8], hash: u64) {
+    assert!(!bitset.is_empty() && bitset.len() % BLOCK_SIZE == 0);
+    let num_blocks = bitset.len() / BLOCK_SIZE;
+    let block_index = hash_to_block_index(hash, num_blocks);
+    let block = &mut bitset[block_index * BLOCK_SIZE..(block_index + 1) * BLOCK_SIZE];
+
+    let mask = new_mask(hash as u32);
+    for (word, mask) in block.chunks_exact_mut(4).zip(mask.iter()) {
+        let value = u32::from_le_bytes([word[0], word[1], word[2], word[3]]) | mask;
+        word.copy_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Returns whether `hash` may be in the bloom filter `bitset`.
+/// `false` means that the value is definitely not in the set.
+/// An empty `bitset` is treated as a filter containing every value.
+pub fn is_in_set(bitset: &[u8], hash: u64) -> bool {
+    let num_blocks = bitset.len() / BLOCK_SIZE;
+    if num_blocks == 0 {
+        return true;
+    }
+    let block_index = hash_to_block_index(hash, num_blocks);
+    let block = &bitset[block_index * BLOCK_SIZE..(block_index + 1) * BLOCK_SIZE];
+
+    let mask = new_mask(hash as u32);
+    block
+        .chunks_exact(4)
+        .zip(mask.iter())
+        .all(|(word, mask)| u32::from_le_bytes([word[0], word[1], word[2], word[3]]) & mask != 0)
+}
+
+/// Returns the hash of a native value, over its plain encoding.
+pub fn hash_native<T: NativeType>(value: T) -> u64 {
+    xxh64(value.to_le_bytes().as_ref(), 0)
+}
+
+/// Returns the hash of a binary value, over its plain encoding.
+pub fn hash_byte<A: AsRef<[u8]>>(value: A) -> u64 {
+    xxh64(value.as_ref(), 0)
+}
+
+/// Maps a value of a logical integer to the value of its parquet physical type
+/// (`Int32` for integers up to 32 bits, `Int64` otherwise).
+fn hash_integer<T: NativeType + num_traits::AsPrimitive<i32> + num_traits::AsPrimitive<i64>>(
+    value: T,
+    is_64: bool,
+) -> u64 {
+    if is_64 {
+        hash_native::<i64>(value.as_())
+    } else {
+        hash_native::<i32>(value.as_())
+    }
+}
+
+fn insert_primitive<T, F: Fn(T) -> u64>(array: &PrimitiveArray<T>, bitset: &mut [u8], op: F)
+where
+    T: NativeType,
+{
+    array
+        .iter()
+        .flatten()
+        .for_each(|value| insert(bitset, op(*value)))
+}
+
+/// Inserts every non-null value of `array` to the bloom filter `bitset`.
+/// # Errors
+/// Errors iff the physical type of the array is not supported by bloom filters.
+/// # Panics
+/// Panics iff `bitset` is empty or its length is not a multiple of 32.
+pub fn insert_array(array: &dyn Array, bitset: &mut [u8]) -> Result<()> {
+    use PhysicalType::*;
+    match array.data_type().to_physical_type() {
+        Primitive(primitive) => {
+            macro_rules! integer {
+                ($type:ty, $is_64:expr) => {{
+                    let array = array
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<$type>>()
+                        .unwrap();
+                    insert_primitive(array, bitset, |x| hash_integer(x, $is_64))
+                }};
+            }
+            match primitive {
+                PrimitiveType::Int8 => integer!(i8, false),
+                PrimitiveType::Int16 => integer!(i16, false),
+                PrimitiveType::Int32 => integer!(i32, false),
+                PrimitiveType::UInt8 => integer!(u8, false),
+                PrimitiveType::UInt16 => integer!(u16, false),
+                PrimitiveType::UInt32 => integer!(u32, false),
+                PrimitiveType::Int64 => integer!(i64, true),
+                PrimitiveType::UInt64 => integer!(u64, true),
+                PrimitiveType::Float32 => {
+                    let array = array.as_any().downcast_ref().unwrap();
+                    insert_primitive::<f32, _>(array, bitset, hash_native)
+                }
+                PrimitiveType::Float64 => {
+                    let array = array.as_any().downcast_ref().unwrap();
+                    insert_primitive::<f64, _>(array, bitset, hash_native)
+                }
+                other => {
+                    return Err(ArrowError::NotYetImplemented(format!(
+                        "Bloom filters of {:?} are not yet supported",
+                        other
+                    )))
+                }
+            }
+        }
+        Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            array
+                .iter()
+                .flatten()
+                .for_each(|x| insert(bitset, hash_byte(x)))
+        }
+        LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            array
+                .iter()
+                .flatten()
+                .for_each(|x| insert(bitset, hash_byte(x)))
+        }
+        Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            array
+                .iter()
+                .flatten()
+                .for_each(|x| insert(bitset, hash_byte(x)))
+        }
+        LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            array
+                .iter()
+                .flatten()
+                .for_each(|x| insert(bitset, hash_byte(x)))
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Bloom filters of {:?} are not yet supported",
+                other
+            )))
+        }
+    };
+    Ok(())
+}
+
+/// Returns a bloom filter of the non-null values of `arrays` (e.g. the arrays of a column
+/// chunk), sized for a false positive probability of `fpp`.
+/// # Errors
+/// Errors iff the physical type of the arrays is not supported by bloom filters.
+pub fn build<A: AsRef<dyn Array>>(arrays: &[A], fpp: f64) -> Result<Vec<u8>> {
+    // the number of non-null values is an upper bound of the number of distinct values
+    let num_values = arrays
+        .iter()
+        .map(|array| array.as_ref().len() - array.as_ref().null_count())
+        .sum();
+    let mut bitset = vec![0; optimal_num_bytes(num_values, fpp)];
+    arrays
+        .iter()
+        .try_for_each(|array| insert_array(array.as_ref(), &mut bitset))?;
+    Ok(bitset)
+}
+
+/// Returns the hash of `scalar` as parquet's bloom filters hash it, or `None` if it is null.
+fn hash_scalar(scalar: &dyn Scalar) -> Result<Option<u64>> {
+    use PhysicalType::*;
+    Ok(match scalar.data_type().to_physical_type() {
+        Primitive(primitive) => {
+            macro_rules! integer {
+                ($type:ty, $is_64:expr) => {{
+                    let scalar = scalar
+                        .as_any()
+                        .downcast_ref::<PrimitiveScalar<$type>>()
+                        .unwrap();
+                    scalar.value().map(|x| hash_integer(x, $is_64))
+                }};
+            }
+            match primitive {
+                PrimitiveType::Int8 => integer!(i8, false),
+                PrimitiveType::Int16 => integer!(i16, false),
+                PrimitiveType::Int32 => integer!(i32, false),
+                PrimitiveType::UInt8 => integer!(u8, false),
+                PrimitiveType::UInt16 => integer!(u16, false),
+                PrimitiveType::UInt32 => integer!(u32, false),
+                PrimitiveType::Int64 => integer!(i64, true),
+                PrimitiveType::UInt64 => integer!(u64, true),
+                PrimitiveType::Float32 => scalar
+                    .as_any()
+                    .downcast_ref::<PrimitiveScalar<f32>>()
+                    .unwrap()
+                    .value()
+                    .map(hash_native),
+                PrimitiveType::Float64 => scalar
+                    .as_any()
+                    .downcast_ref::<PrimitiveScalar<f64>>()
+                    .unwrap()
+                    .value()
+                    .map(hash_native),
+                other => {
+                    return Err(ArrowError::NotYetImplemented(format!(
+                        "Bloom filters of {:?} are not yet supported",
+                        other
+                    )))
+                }
+            }
+        }
+        Utf8 => scalar
+            .as_any()
+            .downcast_ref::<Utf8Scalar<i32>>()
+            .unwrap()
+            .value()
+            .map(hash_byte),
+        LargeUtf8 => scalar
+            .as_any()
+            .downcast_ref::<Utf8Scalar<i64>>()
+            .unwrap()
+            .value()
+            .map(hash_byte),
+        Binary => scalar
+            .as_any()
+            .downcast_ref::<BinaryScalar<i32>>()
+            .unwrap()
+            .value()
+            .map(hash_byte),
+        LargeBinary => scalar
+            .as_any()
+            .downcast_ref::<BinaryScalar<i64>>()
+            .unwrap()
+            .value()
+            .map(hash_byte),
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Bloom filters of {:?} are not yet supported",
+                other
+            )))
+        }
+    })
+}
+
+/// Returns whether the bloom filter `bitset` might contain `scalar`.
+/// `false` means that the value is definitely not in the column chunk.
+/// Null scalars are never contained in bloom filters.
+/// # Errors
+/// Errors iff the scalar's physical type is not supported by bloom filters.
+pub fn might_contain(bitset: &[u8], scalar: &dyn Scalar) -> Result<bool> {
+    Ok(hash_scalar(scalar)?
+        .map(|hash| is_in_set(bitset, hash))
+        .unwrap_or(false))
+}
+
+/// Reads the bloom filter of `column` into `bitset`.
+/// `bitset` is cleared and left empty if the column chunk has no bloom filter
+/// (which [`might_contain`] and [`is_in_set`] treat as a filter containing every value).
+/// # Errors
+/// Errors iff the bloom filter is out of spec or if reading it fails.
+pub fn read<R: Read + Seek>(
+    column: &ColumnChunkMetaData,
+    reader: &mut R,
+    bitset: &mut Vec<u8>,
+) -> Result<()> {
+    bitset.clear();
+    let offset = column
+        .column_chunk()
+        .meta_data
+        .as_ref()
+        .and_then(|meta| meta.bloom_filter_offset);
+    let offset = if let Some(offset) = offset {
+        offset as u64
+    } else {
+        return Ok(());
+    };
+    reader.seek(SeekFrom::Start(offset))?;
+
+    let mut protocol = TCompactInputProtocol::new(&mut *reader);
+    let header = BloomFilterHeader::read_from_in_protocol(&mut protocol).map_err(|error| {
+        ArrowError::External(
+            "Unable to deserialize the bloom filter header".to_string(),
+            Box::new(error),
+        )
+    })?;
+    if header.num_bytes < 0 || header.num_bytes as usize > MAX_NUM_BYTES {
+        return Err(ArrowError::oos(
+            "The number of bytes of a bloom filter must be between 0 and 128MiB",
+        ));
+    }
+
+    bitset.resize(header.num_bytes as usize, 0);
+    reader.read_exact(bitset)?;
+    Ok(())
+}
+
+fn thrift_error(error: parquet_format_async_temp::thrift::Error) -> ArrowError {
+    ArrowError::External(
+        "Unable to (de)serialize the metadata of the file".to_string(),
+        Box::new(error),
+    )
+}
+
+/// Writes the bloom filters `bitsets` to a complete parquet file in `writer` (e.g. written by
+/// [`write_file`](super::write::write_file)), where `bitsets[i][j]` is the bloom filter, if any,
+/// of the `j`th column chunk of the `i`th row group.
+///
+/// The bloom filters are written where the footer was, and the footer is re-written after them
+/// with their offsets. Returns the new size of the file.
+/// # Errors
+/// Errors iff the file is out of spec, `bitsets` does not match its row groups and column
+/// chunks, a bitset is not a valid bloom filter, or reading or writing fails.
+pub fn write<W: Read + Write + Seek>(
+    writer: &mut W,
+    bitsets: &[Vec<Option<Vec<u8>>>],
+) -> Result<u64> {
+    let file_size = writer.seek(SeekFrom::End(0))?;
+    if file_size < 12 {
+        return Err(ArrowError::oos(
+            "A parquet file must have at least 12 bytes (its header and footer)",
+        ));
+    }
+    writer.seek(SeekFrom::End(-8))?;
+    let mut footer = [0; 8];
+    writer.read_exact(&mut footer)?;
+    if footer[4..] != PARQUET_MAGIC {
+        return Err(ArrowError::oos("A parquet file must end with PAR1"));
+    }
+    let metadata_len = i32::from_le_bytes([footer[0], footer[1], footer[2], footer[3]]);
+    if metadata_len < 0 || metadata_len as u64 + 12 > file_size {
+        return Err(ArrowError::oos(
+            "The length of the metadata of a parquet file must fit in the file",
+        ));
+    }
+    let metadata_start = file_size - 8 - metadata_len as u64;
+
+    writer.seek(SeekFrom::Start(metadata_start))?;
+    let mut protocol = TCompactInputProtocol::new(&mut *writer);
+    let mut metadata = FileMetaData::read_from_in_protocol(&mut protocol).map_err(thrift_error)?;
+    if metadata.row_groups.len() != bitsets.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The file has {} row groups but {} were given bloom filters",
+            metadata.row_groups.len(),
+            bitsets.len()
+        )));
+    }
+
+    writer.seek(SeekFrom::Start(metadata_start))?;
+    let mut offset = metadata_start;
+    for (row_group, bitsets) in metadata.row_groups.iter_mut().zip(bitsets) {
+        if row_group.columns.len() != bitsets.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The row group has {} column chunks but {} were given bloom filters",
+                row_group.columns.len(),
+                bitsets.len()
+            )));
+        }
+        for (column, bitset) in row_group.columns.iter_mut().zip(bitsets) {
+            let bitset = if let Some(bitset) = bitset {
+                bitset
+            } else {
+                continue;
+            };
+            if bitset.is_empty() || bitset.len() % BLOCK_SIZE != 0 || bitset.len() > MAX_NUM_BYTES {
+                return Err(ArrowError::InvalidArgumentError(
+                    "The number of bytes of a bloom filter must be a positive multiple of 32 up to 128MiB"
+                        .to_string(),
+                ));
+            }
+            let meta_data = column
+                .meta_data
+                .as_mut()
+                .ok_or_else(|| ArrowError::oos("A column chunk must have metadata"))?;
+
+            let header = BloomFilterHeader::new(
+                bitset.len() as i32,
+                BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm::new()),
+                BloomFilterHash::XXHASH(XxHash::new()),
+                BloomFilterCompression::UNCOMPRESSED(Uncompressed::new()),
+            );
+            let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+            let header_len = header
+                .write_to_out_protocol(&mut protocol)
+                .map_err(thrift_error)?;
+            protocol.flush().map_err(thrift_error)?;
+            writer.write_all(bitset)?;
+
+            meta_data.bloom_filter_offset = Some(offset as i64);
+            offset += (header_len + bitset.len()) as u64;
+        }
+    }
+
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    let metadata_len = metadata
+        .write_to_out_protocol(&mut protocol)
+        .map_err(thrift_error)?;
+    protocol.flush().map_err(thrift_error)?;
+    writer.write_all(&(metadata_len as i32).to_le_bytes())?;
+    writer.write_all(&PARQUET_MAGIC)?;
+    Ok(offset + metadata_len as u64 + 8)
+}
@@ -0,0 +1,207 @@
+//! AES-GCM and AES-CTR ciphers of the modules of encrypted files.
+use std::io::Read;
+
+use aes::{Aes128, Aes192, Aes256};
+use aes_gcm::aead::consts::U12;
+use aes_gcm::aead::{Aead, NewAead, Payload};
+use aes_gcm::AesGcm;
+use ctr::cipher::{NewCipher, StreamCipher};
+
+use crate::error::{ArrowError, Result};
+
+/// The number of bytes of the nonce of a module.
+pub const NONCE_LEN: usize = 12;
+/// The number of bytes of the authentication tag of a module encrypted with AES-GCM.
+pub const TAG_LEN: usize = 16;
+/// The number of bytes of the length of a module.
+const LENGTH_LEN: usize = 4;
+
+/// The type of a module, which is part of its additional authenticated data (AAD).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+}
+
+/// Returns the AAD of a module: the AAD of the file followed by the type of the module and,
+/// for modules other than the footer, the ordinals of its row group, column and page.
+pub fn module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group: i16,
+    column: i16,
+    page: Option<i16>,
+) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    if module_type == ModuleType::Footer {
+        return aad;
+    }
+    aad.extend_from_slice(&row_group.to_le_bytes());
+    aad.extend_from_slice(&column.to_le_bytes());
+    if let Some(page) = page {
+        aad.extend_from_slice(&page.to_le_bytes());
+    }
+    aad
+}
+
+/// Returns the AAD of the footer.
+pub fn footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    module_aad(file_aad, ModuleType::Footer, 0, 0, None)
+}
+
+fn crypto_error(what: &str) -> ArrowError {
+    ArrowError::ExternalFormat(format!(
+        "Unable to {} a module of an encrypted parquet file; the key or the AAD may be wrong",
+        what
+    ))
+}
+
+fn invalid_key(key: &[u8]) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "The keys of encrypted parquet files must have 16, 24 or 32 bytes, got {}",
+        key.len()
+    ))
+}
+
+// dispatches to the cipher of the length of `$key`
+macro_rules! with_cipher {
+    ($key:expr, $aes:ident, $body:expr) => {
+        match $key.len() {
+            16 => {
+                type $aes = Aes128;
+                $body
+            }
+            24 => {
+                type $aes = Aes192;
+                $body
+            }
+            32 => {
+                type $aes = Aes256;
+                $body
+            }
+            _ => Err(invalid_key($key)),
+        }
+    };
+}
+
+/// Returns `plaintext` encrypted with AES-GCM, followed by its tag.
+pub fn gcm_encrypt(
+    key: &[u8],
+    nonce: &[u8; NONCE_LEN],
+    plaintext: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>> {
+    with_cipher!(key, Aes, {
+        let cipher = AesGcm::<Aes, U12>::new_from_slice(key).map_err(|_| invalid_key(key))?;
+        let payload = Payload {
+            msg: plaintext,
+            aad,
+        };
+        cipher
+            .encrypt(&(*nonce).into(), payload)
+            .map_err(|_| crypto_error("encrypt"))
+    })
+}
+
+/// Returns the plaintext of `ciphertext` (followed by its tag) decrypted with AES-GCM.
+pub fn gcm_decrypt(
+    key: &[u8],
+    nonce: &[u8; NONCE_LEN],
+    ciphertext: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>> {
+    with_cipher!(key, Aes, {
+        let cipher = AesGcm::<Aes, U12>::new_from_slice(key).map_err(|_| invalid_key(key))?;
+        let payload = Payload {
+            msg: ciphertext,
+            aad,
+        };
+        cipher
+            .decrypt(&(*nonce).into(), payload)
+            .map_err(|_| crypto_error("decrypt"))
+    })
+}
+
+/// Applies the AES-CTR keystream of `nonce` to `data`, which encrypts and decrypts it.
+/// As declared in the specification, the counter is the last 4 bytes of the IV and starts at 1.
+pub fn ctr_apply(key: &[u8], nonce: &[u8; NONCE_LEN], data: &mut [u8]) -> Result<()> {
+    let mut iv = [0; 16];
+    iv[..NONCE_LEN].copy_from_slice(nonce);
+    iv[15] = 1;
+    with_cipher!(key, Aes, {
+        let mut cipher =
+            ctr::Ctr32BE::<Aes>::new_from_slices(key, &iv).map_err(|_| invalid_key(key))?;
+        cipher.apply_keystream(data);
+        Ok(())
+    })
+}
+
+/// Encrypts `plaintext` into a module: its length (4 bytes, little endian), a random nonce and
+/// the ciphertext, followed by its tag when `is_ctr` is false (AES-GCM).
+pub fn encrypt_module(key: &[u8], plaintext: &[u8], aad: &[u8], is_ctr: bool) -> Result<Vec<u8>> {
+    let nonce: [u8; NONCE_LEN] = rand::random();
+    let ciphertext = if is_ctr {
+        let mut data = plaintext.to_vec();
+        ctr_apply(key, &nonce, &mut data)?;
+        data
+    } else {
+        gcm_encrypt(key, &nonce, plaintext, aad)?
+    };
+    let length = (NONCE_LEN + ciphertext.len()) as u32;
+    let mut module = Vec::with_capacity(LENGTH_LEN + length as usize);
+    module.extend_from_slice(&length.to_le_bytes());
+    module.extend_from_slice(&nonce);
+    module.extend_from_slice(&ciphertext);
+    Ok(module)
+}
+
+/// Decrypts a module (see [`encrypt_module`]) without its length.
+pub fn decrypt_module(key: &[u8], module: &[u8], aad: &[u8], is_ctr: bool) -> Result<Vec<u8>> {
+    let min_len = if is_ctr {
+        NONCE_LEN
+    } else {
+        NONCE_LEN + TAG_LEN
+    };
+    if module.len() < min_len {
+        return Err(ArrowError::oos(
+            "A module of an encrypted parquet file is shorter than its nonce and tag",
+        ));
+    }
+    let (nonce, ciphertext) = module.split_at(NONCE_LEN);
+    let nonce: &[u8; NONCE_LEN] = nonce.try_into().unwrap();
+    if is_ctr {
+        let mut data = ciphertext.to_vec();
+        ctr_apply(key, nonce, &mut data)?;
+        Ok(data)
+    } else {
+        gcm_decrypt(key, nonce, ciphertext, aad)
+    }
+}
+
+/// Reads a module, without its length, from `reader`.
+pub fn read_module<R: Read>(reader: &mut R) -> Result<Vec<u8>> {
+    let mut length = [0; LENGTH_LEN];
+    reader.read_exact(&mut length)?;
+    let length = u32::from_le_bytes(length) as u64;
+    let mut module = vec![];
+    reader.take(length).read_to_end(&mut module)?;
+    if module.len() as u64 != length {
+        return Err(ArrowError::oos(
+            "A module of an encrypted parquet file is longer than the file",
+        ));
+    }
+    Ok(module)
+}
+
+/// Returns the number of bytes of the plaintext of a module of `length` bytes (including its
+/// length).
+pub fn plaintext_len(length: usize, is_ctr: bool) -> Option<usize> {
+    let overhead = LENGTH_LEN + NONCE_LEN + if is_ctr { 0 } else { TAG_LEN };
+    length.checked_sub(overhead)
+}
@@ -0,0 +1,120 @@
+//! APIs to read and write parquet files with
+//! [modular encryption](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! The modules of an encrypted file (its footer, and the page headers, pages and metadata of
+//! its column chunks) are encrypted with AES-GCM (the pages with AES-CTR in
+//! [`Algorithm::AesGcmCtrV1`]) with the footer key or the key of their column. Keys are
+//! identified by their key metadata, which is stored in the file and passed to a
+//! [`KeyRetriever`] when reading.
+//!
+//! [`DecryptedReader`] decrypts a file while it is read, so that the readers of
+//! [`read`](super::read) read it as a plaintext file, and [`encrypt`] encrypts a plaintext file,
+//! e.g. written by [`write_file`](super::write::write_file).
+//!
+//! Encrypted page indexes and bloom filters are not supported: they are not written, and they
+//! are ignored when reading.
+use std::sync::Arc;
+
+use parquet_format_async_temp::{AesGcmCtrV1, AesGcmV1, EncryptionAlgorithm};
+
+use crate::error::{ArrowError, Result};
+
+mod crypto;
+mod read;
+mod write;
+
+pub use read::DecryptedReader;
+pub use write::encrypt;
+
+/// A function that returns a key from its key metadata, which is empty when the file does not
+/// declare one, e.g. by fetching it from a key management service.
+pub type KeyRetriever = Arc<dyn Fn(&[u8]) -> Result<Vec<u8>> + Send + Sync>;
+
+/// The algorithm used to encrypt a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    /// Every module is encrypted with AES-GCM
+    AesGcmV1,
+    /// Pages are encrypted with AES-CTR and the other modules with AES-GCM, which is faster but
+    /// does not authenticate the pages
+    AesGcmCtrV1,
+}
+
+/// Options to read encrypted files.
+#[derive(Clone)]
+pub struct DecryptionOptions {
+    /// Returns the footer key and the keys of the columns from their key metadata
+    pub key_retriever: KeyRetriever,
+    /// The AAD prefix of the file, required iff it was not stored in the file
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+/// The key of a column of an encrypted file.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ColumnKey {
+    /// The path of the column in the schema, e.g. `["a"]` for the field `a`
+    pub path: Vec<String>,
+    /// The key, of 16, 24 or 32 bytes
+    pub key: Vec<u8>,
+    /// The metadata of the key, stored in the file
+    pub key_metadata: Option<Vec<u8>>,
+}
+
+/// Options to write encrypted files.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionOptions {
+    /// The algorithm of the encryption
+    pub algorithm: Algorithm,
+    /// The key of the footer and of the columns without a [`ColumnKey`], of 16, 24 or 32 bytes
+    pub footer_key: Vec<u8>,
+    /// The metadata of the footer key, stored in the file
+    pub footer_key_metadata: Option<Vec<u8>>,
+    /// The keys of the columns encrypted with their own key
+    pub column_keys: Vec<ColumnKey>,
+    /// A prefix of the AAD of every module, e.g. the name of the file, to detect files that
+    /// were replaced by other files encrypted with the same keys
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Whether `aad_prefix` is stored in the file. When not, readers must supply it.
+    pub store_aad_prefix: bool,
+}
+
+/// Returns the AAD of the file (its AAD prefix followed by its unique AAD) and whether its
+/// pages are encrypted with AES-CTR.
+fn file_aad(algorithm: &EncryptionAlgorithm, aad_prefix: Option<&[u8]>) -> Result<(Vec<u8>, bool)> {
+    let (stored_prefix, unique, supply_prefix, is_ctr) = match algorithm {
+        EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+            aad_prefix,
+            aad_file_unique,
+            supply_aad_prefix,
+        }) => (aad_prefix, aad_file_unique, supply_aad_prefix, false),
+        EncryptionAlgorithm::AESGCMCTRV1(AesGcmCtrV1 {
+            aad_prefix,
+            aad_file_unique,
+            supply_aad_prefix,
+        }) => (aad_prefix, aad_file_unique, supply_aad_prefix, true),
+    };
+    let prefix = match (aad_prefix, stored_prefix.as_deref()) {
+        (Some(prefix), Some(stored)) if prefix != stored => {
+            return Err(ArrowError::InvalidArgumentError(
+                "The AAD prefix differs from the one stored in the file".to_string(),
+            ))
+        }
+        (Some(prefix), _) | (None, Some(prefix)) => prefix,
+        (None, None) if supply_prefix.unwrap_or(false) => {
+            return Err(ArrowError::InvalidArgumentError(
+                "The file was encrypted with an AAD prefix that must be supplied".to_string(),
+            ))
+        }
+        (None, None) => &[],
+    };
+    let mut aad = prefix.to_vec();
+    aad.extend_from_slice(unique.as_deref().unwrap_or_default());
+    Ok((aad, is_ctr))
+}
+
+fn thrift_error(error: parquet_format_async_temp::thrift::Error) -> ArrowError {
+    ArrowError::External(
+        "Unable to (de)serialize the metadata of an encrypted file".to_string(),
+        Box::new(error),
+    )
+}
@@ -0,0 +1,547 @@
+use std::collections::HashMap;
+use std::io::{self, Cursor, Read, Seek, SeekFrom};
+use std::sync::Arc;
+
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{
+    ColumnChunk, ColumnCryptoMetaData, ColumnMetaData, EncryptionWithColumnKey, FileCryptoMetaData,
+    FileMetaData, PageHeader, PageType,
+};
+
+use crate::error::{ArrowError, Result};
+
+use super::crypto::{
+    decrypt_module, footer_aad, gcm_encrypt, module_aad, plaintext_len, read_module, ModuleType,
+    NONCE_LEN, TAG_LEN,
+};
+use super::{file_aad, thrift_error, DecryptionOptions};
+
+const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+const ENCRYPTED_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'E'];
+
+/// Where the bytes of a [`Segment`] of the plaintext file come from.
+enum Source {
+    /// Bytes decrypted or re-written when the file was opened (the page headers and the footer)
+    Memory(Vec<u8>),
+    /// Plaintext bytes of the file, starting at this offset
+    File(u64),
+    /// A page, decrypted when read
+    Page {
+        /// The offset of the module (after its length) in the file
+        offset: u64,
+        /// The number of bytes of the module (after its length)
+        length: usize,
+        key: Arc<[u8]>,
+        aad: Vec<u8>,
+        is_ctr: bool,
+    },
+}
+
+/// A contiguous range of bytes of the plaintext file.
+struct Segment {
+    start: u64,
+    length: u64,
+    source: Source,
+}
+
+/// The segments of the plaintext file, in order.
+#[derive(Default)]
+struct Segments {
+    segments: Vec<Segment>,
+    length: u64,
+}
+
+impl Segments {
+    fn push(&mut self, length: u64, source: Source) {
+        self.segments.push(Segment {
+            start: self.length,
+            length,
+            source,
+        });
+        self.length += length;
+    }
+}
+
+/// A reader of an encrypted parquet file that reads it as the plaintext file it was encrypted
+/// from, so that it can be read with [`read_metadata`](crate::io::parquet::read::read_metadata),
+/// [`RecordReader`](crate::io::parquet::read::RecordReader) and the other readers.
+///
+/// The footer and the page headers are decrypted when the reader is created, and each page
+/// when it is read. Encrypted page indexes and bloom filters are ignored, and files without
+/// encryption are read as is.
+pub struct DecryptedReader<R: Read + Seek> {
+    reader: R,
+    segments: Vec<Segment>,
+    length: u64,
+    position: u64,
+    // the index of the last decrypted page segment and its plaintext
+    page: Option<(usize, Vec<u8>)>,
+}
+
+/// Retrieves keys from their key metadata, retrieving each one once.
+struct Keys<'a> {
+    options: &'a DecryptionOptions,
+    keys: HashMap<Vec<u8>, Arc<[u8]>>,
+}
+
+impl<'a> Keys<'a> {
+    fn get(&mut self, key_metadata: Option<&[u8]>) -> Result<Arc<[u8]>> {
+        let key_metadata = key_metadata.unwrap_or_default();
+        if let Some(key) = self.keys.get(key_metadata) {
+            return Ok(key.clone());
+        }
+        let key: Arc<[u8]> = (self.options.key_retriever)(key_metadata)?.into();
+        self.keys.insert(key_metadata.to_vec(), key.clone());
+        Ok(key)
+    }
+}
+
+fn read_thrift<T, F>(bytes: &[u8], read: F) -> Result<(T, usize)>
+where
+    F: FnOnce(&mut TCompactInputProtocol<&mut Cursor<&[u8]>>) -> Result<T>,
+{
+    let mut cursor = Cursor::new(bytes);
+    let mut protocol = TCompactInputProtocol::new(&mut cursor);
+    let value = read(&mut protocol)?;
+    Ok((value, cursor.position() as usize))
+}
+
+fn decrypt_page<R: Read + Seek>(
+    reader: &mut R,
+    offset: u64,
+    length: usize,
+    key: &[u8],
+    aad: &[u8],
+    is_ctr: bool,
+) -> Result<Vec<u8>> {
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut module = vec![0; length];
+    reader.read_exact(&mut module)?;
+    decrypt_module(key, &module, aad, is_ctr)
+}
+
+impl<R: Read + Seek> DecryptedReader<R> {
+    /// Returns a new [`DecryptedReader`] of the encrypted file in `reader`.
+    /// # Errors
+    /// Errors iff the file is out of spec, a key can't be retrieved or is wrong, the AAD prefix
+    /// of `options` is missing or wrong, or the footer can't be authenticated.
+    pub fn try_new(mut reader: R, options: &DecryptionOptions) -> Result<Self> {
+        let file_size = reader.seek(SeekFrom::End(0))?;
+        if file_size < 12 {
+            return Err(ArrowError::oos(
+                "A parquet file must have at least 12 bytes (its header and footer)",
+            ));
+        }
+        reader.seek(SeekFrom::End(-8))?;
+        let mut end = [0; 8];
+        reader.read_exact(&mut end)?;
+        let footer_len = i32::from_le_bytes([end[0], end[1], end[2], end[3]]);
+        if footer_len < 0 || footer_len as u64 + 12 > file_size {
+            return Err(ArrowError::oos(
+                "The length of the metadata of a parquet file must fit in the file",
+            ));
+        }
+        reader.seek(SeekFrom::Start(file_size - 8 - footer_len as u64))?;
+        let mut footer = vec![0; footer_len as usize];
+        reader.read_exact(&mut footer)?;
+
+        let mut keys = Keys {
+            options,
+            keys: HashMap::new(),
+        };
+
+        let (metadata, footer_key, file_aad, is_ctr) = if end[4..] == ENCRYPTED_MAGIC {
+            let (crypto_metadata, crypto_len) = read_thrift(&footer, |protocol| {
+                FileCryptoMetaData::read_from_in_protocol(protocol).map_err(thrift_error)
+            })?;
+            let (file_aad, is_ctr) = file_aad(
+                &crypto_metadata.encryption_algorithm,
+                options.aad_prefix.as_deref(),
+            )?;
+            let key = keys.get(crypto_metadata.key_metadata.as_deref())?;
+            let module = read_module(&mut &footer[crypto_len..])?;
+            let footer = decrypt_module(&key, &module, &footer_aad(&file_aad), false)?;
+            let (metadata, _) = read_thrift(&footer, |protocol| {
+                FileMetaData::read_from_in_protocol(protocol).map_err(thrift_error)
+            })?;
+            (metadata, key, file_aad, is_ctr)
+        } else if end[4..] == PARQUET_MAGIC {
+            let (metadata, metadata_len) = read_thrift(&footer, |protocol| {
+                FileMetaData::read_from_in_protocol(protocol).map_err(thrift_error)
+            })?;
+            let algorithm = if let Some(algorithm) = &metadata.encryption_algorithm {
+                algorithm
+            } else {
+                // not encrypted
+                return Ok(Self {
+                    reader,
+                    segments: vec![Segment {
+                        start: 0,
+                        length: file_size,
+                        source: Source::File(0),
+                    }],
+                    length: file_size,
+                    position: 0,
+                    page: None,
+                });
+            };
+            let (file_aad, is_ctr) = file_aad(algorithm, options.aad_prefix.as_deref())?;
+
+            // the footer is signed with the nonce and tag of its AES-GCM encryption
+            let signature = &footer[metadata_len..];
+            if signature.len() != NONCE_LEN + TAG_LEN {
+                return Err(ArrowError::oos(
+                    "The plaintext footer of an encrypted parquet file must be followed by its signature",
+                ));
+            }
+            let key = keys.get(metadata.footer_signing_key_metadata.as_deref())?;
+            let nonce: &[u8; NONCE_LEN] = signature[..NONCE_LEN].try_into().unwrap();
+            let encrypted =
+                gcm_encrypt(&key, nonce, &footer[..metadata_len], &footer_aad(&file_aad))?;
+            if encrypted[metadata_len..] != signature[NONCE_LEN..] {
+                return Err(ArrowError::ExternalFormat(
+                    "The signature of the footer of an encrypted parquet file is wrong; the key or the AAD may be wrong"
+                        .to_string(),
+                ));
+            }
+            (metadata, key, file_aad, is_ctr)
+        } else {
+            return Err(ArrowError::oos(
+                "A parquet file must end with PAR1 or, when its footer is encrypted, PARE",
+            ));
+        };
+
+        Self::try_new_encrypted(reader, metadata, footer_key, &file_aad, is_ctr, &mut keys)
+    }
+
+    fn try_new_encrypted(
+        mut reader: R,
+        mut metadata: FileMetaData,
+        footer_key: Arc<[u8]>,
+        file_aad: &[u8],
+        is_ctr: bool,
+        keys: &mut Keys,
+    ) -> Result<Self> {
+        let mut segments = Segments::default();
+        segments.push(
+            PARQUET_MAGIC.len() as u64,
+            Source::Memory(PARQUET_MAGIC.to_vec()),
+        );
+        for (row_group_index, row_group) in metadata.row_groups.iter_mut().enumerate() {
+            let row_group_ordinal = row_group.ordinal.unwrap_or(row_group_index as i16);
+            let row_group_start = segments.length;
+            for (column_ordinal, column) in row_group.columns.iter_mut().enumerate() {
+                let column_ordinal = column_ordinal as i16;
+                let chunk_start = segments.length;
+                let mut meta_data = Self::column_metadata(
+                    column,
+                    keys,
+                    file_aad,
+                    row_group_ordinal,
+                    column_ordinal,
+                )?;
+
+                let has_dictionary = matches!(meta_data.dictionary_page_offset, Some(x) if x > 0);
+                let start = if has_dictionary {
+                    meta_data.dictionary_page_offset.unwrap()
+                } else {
+                    meta_data.data_page_offset
+                };
+                if start < 0 || meta_data.total_compressed_size < 0 {
+                    return Err(ArrowError::oos(
+                        "The offset and size of a column chunk must be positive",
+                    ));
+                }
+                let (start, length) = (start as u64, meta_data.total_compressed_size as u64);
+
+                let column_key = match &column.crypto_metadata {
+                    None => None,
+                    Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => {
+                        Some(footer_key.clone())
+                    }
+                    Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(
+                        EncryptionWithColumnKey { key_metadata, .. },
+                    )) => Some(keys.get(key_metadata.as_deref())?),
+                };
+
+                let data_page_offset = if let Some(key) = column_key {
+                    reader.seek(SeekFrom::Start(start))?;
+                    let mut data_page_offset = None;
+                    let mut page_ordinal = 0i16;
+                    let mut is_dictionary = has_dictionary;
+                    while reader.stream_position()? < start + length {
+                        let (header_type, page_type, page) = if is_dictionary {
+                            (
+                                ModuleType::DictionaryPageHeader,
+                                ModuleType::DictionaryPage,
+                                None,
+                            )
+                        } else {
+                            (
+                                ModuleType::DataPageHeader,
+                                ModuleType::DataPage,
+                                Some(page_ordinal),
+                            )
+                        };
+                        let header_aad = module_aad(
+                            file_aad,
+                            header_type,
+                            row_group_ordinal,
+                            column_ordinal,
+                            page,
+                        );
+                        let module = read_module(&mut reader)?;
+                        let header = decrypt_module(&key, &module, &header_aad, false)?;
+                        let (mut header, _) = read_thrift(&header, |protocol| {
+                            PageHeader::read_from_in_protocol(protocol).map_err(thrift_error)
+                        })?;
+                        if (header.type_ == PageType::DictionaryPage) != is_dictionary {
+                            return Err(ArrowError::oos(
+                                "Only the first page of an encrypted column chunk can be a dictionary page",
+                            ));
+                        }
+
+                        let page_len = usize::try_from(header.compressed_page_size)
+                            .ok()
+                            .and_then(|length| plaintext_len(length, is_ctr))
+                            .ok_or_else(|| {
+                                ArrowError::oos(
+                                    "The size of an encrypted page must fit its nonce and tag",
+                                )
+                            })?;
+                        header.compressed_page_size = page_len as i32;
+                        let mut bytes = vec![];
+                        let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+                        header
+                            .write_to_out_protocol(&mut protocol)
+                            .map_err(thrift_error)?;
+                        protocol.flush().map_err(thrift_error)?;
+
+                        if !is_dictionary && data_page_offset.is_none() {
+                            data_page_offset = Some(segments.length);
+                        }
+                        segments.push(bytes.len() as u64, Source::Memory(bytes));
+
+                        let module_start = reader.stream_position()? + 4;
+                        let module_len = read_module_len(&mut reader)?;
+                        reader.seek(SeekFrom::Current(module_len as i64))?;
+                        let page_aad = module_aad(
+                            file_aad,
+                            page_type,
+                            row_group_ordinal,
+                            column_ordinal,
+                            page,
+                        );
+                        segments.push(
+                            page_len as u64,
+                            Source::Page {
+                                offset: module_start,
+                                length: module_len,
+                                key: key.clone(),
+                                aad: page_aad,
+                                is_ctr,
+                            },
+                        );
+
+                        if is_dictionary {
+                            is_dictionary = false;
+                        } else {
+                            page_ordinal = page_ordinal.checked_add(1).ok_or_else(|| {
+                                ArrowError::oos(
+                                    "An encrypted column chunk can have at most 32767 data pages",
+                                )
+                            })?;
+                        }
+                    }
+                    data_page_offset.unwrap_or(segments.length)
+                } else {
+                    // a plaintext column chunk, whose pages are moved as is
+                    let data_page_offset = meta_data.data_page_offset as u64;
+                    segments.push(length, Source::File(start));
+                    chunk_start + data_page_offset.saturating_sub(start)
+                };
+
+                meta_data.data_page_offset = data_page_offset as i64;
+                meta_data.dictionary_page_offset = has_dictionary.then_some(chunk_start as i64);
+                meta_data.index_page_offset = None;
+                meta_data.bloom_filter_offset = None;
+                meta_data.total_compressed_size = (segments.length - chunk_start) as i64;
+                column.meta_data = Some(meta_data);
+                column.file_offset = chunk_start as i64;
+                column.crypto_metadata = None;
+                column.encrypted_column_metadata = None;
+                column.offset_index_offset = None;
+                column.offset_index_length = None;
+                column.column_index_offset = None;
+                column.column_index_length = None;
+            }
+            row_group.file_offset = Some(row_group_start as i64);
+            row_group.total_compressed_size = Some((segments.length - row_group_start) as i64);
+        }
+        metadata.encryption_algorithm = None;
+        metadata.footer_signing_key_metadata = None;
+
+        let mut footer = vec![];
+        let mut protocol = TCompactOutputProtocol::new(&mut footer);
+        let footer_len = metadata
+            .write_to_out_protocol(&mut protocol)
+            .map_err(thrift_error)?;
+        protocol.flush().map_err(thrift_error)?;
+        footer.extend_from_slice(&(footer_len as i32).to_le_bytes());
+        footer.extend_from_slice(&PARQUET_MAGIC);
+        segments.push(footer.len() as u64, Source::Memory(footer));
+
+        Ok(Self {
+            reader,
+            length: segments.length,
+            segments: segments.segments,
+            position: 0,
+            page: None,
+        })
+    }
+
+    /// Returns the plaintext metadata of `column`, decrypting it when it is encrypted.
+    fn column_metadata(
+        column: &mut ColumnChunk,
+        keys: &mut Keys,
+        file_aad: &[u8],
+        row_group_ordinal: i16,
+        column_ordinal: i16,
+    ) -> Result<ColumnMetaData> {
+        let encrypted = match (&column.encrypted_column_metadata, &column.crypto_metadata) {
+            (
+                Some(encrypted),
+                Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                    key_metadata,
+                    ..
+                })),
+            ) => Some((encrypted, key_metadata)),
+            _ => None,
+        };
+        if let Some((encrypted, key_metadata)) = encrypted {
+            let key = keys.get(key_metadata.as_deref())?;
+            let aad = module_aad(
+                file_aad,
+                ModuleType::ColumnMetaData,
+                row_group_ordinal,
+                column_ordinal,
+                None,
+            );
+            let module = read_module(&mut encrypted.as_slice())?;
+            let meta_data = decrypt_module(&key, &module, &aad, false)?;
+            let (meta_data, _) = read_thrift(&meta_data, |protocol| {
+                ColumnMetaData::read_from_in_protocol(protocol).map_err(thrift_error)
+            })?;
+            Ok(meta_data)
+        } else {
+            column
+                .meta_data
+                .take()
+                .ok_or_else(|| ArrowError::oos("A column chunk must have metadata"))
+        }
+    }
+
+    /// Returns the size of the plaintext file.
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    /// Returns whether the plaintext file is empty, which never happens for valid files.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the reader of the encrypted file.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+fn read_module_len<R: Read>(reader: &mut R) -> Result<usize> {
+    let mut length = [0; 4];
+    reader.read_exact(&mut length)?;
+    Ok(u32::from_le_bytes(length) as usize)
+}
+
+impl<R: Read + Seek> Read for DecryptedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.position >= self.length {
+            return Ok(0);
+        }
+        let position = self.position;
+        let index = self
+            .segments
+            .partition_point(|segment| segment.start + segment.length <= position);
+        let segment = &self.segments[index];
+        let offset = position - segment.start;
+        let length = buf.len().min((segment.length - offset) as usize);
+        let offset = offset as usize;
+        let buf = &mut buf[..length];
+
+        match &segment.source {
+            Source::Memory(bytes) => buf.copy_from_slice(&bytes[offset..offset + length]),
+            Source::File(start) => {
+                self.reader.seek(SeekFrom::Start(start + offset as u64))?;
+                self.reader.read_exact(buf)?;
+            }
+            Source::Page {
+                offset: module_offset,
+                length: module_len,
+                key,
+                aad,
+                is_ctr,
+            } => {
+                if !matches!(&self.page, Some((page, _)) if *page == index) {
+                    let page = decrypt_page(
+                        &mut self.reader,
+                        *module_offset,
+                        *module_len,
+                        key,
+                        aad,
+                        *is_ctr,
+                    )
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+                    if page.len() as u64 != segment.length {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "The size of a decrypted page differs from the one of its header",
+                        ));
+                    }
+                    self.page = Some((index, page));
+                }
+                let page = &self.page.as_ref().unwrap().1;
+                buf.copy_from_slice(&page[offset..offset + length]);
+            }
+        }
+        self.position += length as u64;
+        Ok(length)
+    }
+}
+
+impl<R: Read + Seek> Seek for DecryptedReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(position) => Some(position),
+            SeekFrom::End(delta) => checked_add(self.length, delta),
+            SeekFrom::Current(delta) => checked_add(self.position, delta),
+        };
+        let position = position.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        self.position = position;
+        Ok(position)
+    }
+}
+
+fn checked_add(position: u64, delta: i64) -> Option<u64> {
+    if delta >= 0 {
+        position.checked_add(delta as u64)
+    } else {
+        position.checked_sub(delta.unsigned_abs())
+    }
+}
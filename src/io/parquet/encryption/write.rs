@@ -0,0 +1,298 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use parquet_format_async_temp::thrift::protocol::{
+    TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol,
+};
+use parquet_format_async_temp::{
+    AesGcmCtrV1, AesGcmV1, ColumnCryptoMetaData, EncryptionAlgorithm, EncryptionWithColumnKey,
+    EncryptionWithFooterKey, FileCryptoMetaData, FileMetaData, PageHeader, PageType,
+};
+
+use crate::error::{ArrowError, Result};
+
+use super::crypto::{encrypt_module, footer_aad, module_aad, ModuleType};
+use super::{thrift_error, Algorithm, EncryptionOptions};
+
+const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+const ENCRYPTED_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'E'];
+
+fn serialize<F>(write: F) -> Result<Vec<u8>>
+where
+    F: FnOnce(&mut TCompactOutputProtocol<&mut Vec<u8>>) -> Result<()>,
+{
+    let mut bytes = vec![];
+    let mut protocol = TCompactOutputProtocol::new(&mut bytes);
+    write(&mut protocol)?;
+    protocol.flush().map_err(thrift_error)?;
+    Ok(bytes)
+}
+
+fn ordinal(index: usize, what: &str) -> Result<i16> {
+    i16::try_from(index).map_err(|_| {
+        ArrowError::InvalidArgumentError(format!(
+            "An encrypted parquet file can have at most 32768 {}",
+            what
+        ))
+    })
+}
+
+/// Encrypts the plaintext parquet file in `reader` (e.g. written by
+/// [`write_file`](crate::io::parquet::write::write_file)) to `writer` with an encrypted footer.
+///
+/// Every column chunk is encrypted with the key of its path in `options.column_keys` or, when
+/// it has none, with the footer key. Page indexes and bloom filters are not encrypted and are
+/// thus dropped. Returns the size of the encrypted file.
+/// # Errors
+/// Errors iff the file is out of spec or already encrypted, a key does not have 16, 24 or 32
+/// bytes, a column key matches no column chunk, or reading or writing fails.
+pub fn encrypt<R: Read + Seek, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    options: &EncryptionOptions,
+) -> Result<u64> {
+    let file_size = reader.seek(SeekFrom::End(0))?;
+    if file_size < 12 {
+        return Err(ArrowError::oos(
+            "A parquet file must have at least 12 bytes (its header and footer)",
+        ));
+    }
+    reader.seek(SeekFrom::End(-8))?;
+    let mut end = [0; 8];
+    reader.read_exact(&mut end)?;
+    if end[4..] != PARQUET_MAGIC {
+        return Err(ArrowError::oos(
+            "A parquet file must end with PAR1; files with an encrypted footer can't be encrypted",
+        ));
+    }
+    let metadata_len = i32::from_le_bytes([end[0], end[1], end[2], end[3]]);
+    if metadata_len < 0 || metadata_len as u64 + 12 > file_size {
+        return Err(ArrowError::oos(
+            "The length of the metadata of a parquet file must fit in the file",
+        ));
+    }
+    reader.seek(SeekFrom::Start(file_size - 8 - metadata_len as u64))?;
+    let mut protocol = TCompactInputProtocol::new(&mut *reader);
+    let mut metadata = FileMetaData::read_from_in_protocol(&mut protocol).map_err(thrift_error)?;
+    if metadata.encryption_algorithm.is_some() {
+        return Err(ArrowError::InvalidArgumentError(
+            "The parquet file is already encrypted".to_string(),
+        ));
+    }
+
+    let aad_file_unique = rand::random::<[u8; 8]>().to_vec();
+    let stored_prefix = options
+        .aad_prefix
+        .clone()
+        .filter(|_| options.store_aad_prefix);
+    let supply_aad_prefix = Some(options.aad_prefix.is_some() && !options.store_aad_prefix);
+    let (algorithm, is_ctr) = match options.algorithm {
+        Algorithm::AesGcmV1 => (
+            EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix: stored_prefix,
+                aad_file_unique: Some(aad_file_unique.clone()),
+                supply_aad_prefix,
+            }),
+            false,
+        ),
+        Algorithm::AesGcmCtrV1 => (
+            EncryptionAlgorithm::AESGCMCTRV1(AesGcmCtrV1 {
+                aad_prefix: stored_prefix,
+                aad_file_unique: Some(aad_file_unique.clone()),
+                supply_aad_prefix,
+            }),
+            true,
+        ),
+    };
+    let mut file_aad = options.aad_prefix.clone().unwrap_or_default();
+    file_aad.extend_from_slice(&aad_file_unique);
+
+    let mut used_column_keys = vec![false; options.column_keys.len()];
+
+    writer.write_all(&ENCRYPTED_MAGIC)?;
+    let mut offset = ENCRYPTED_MAGIC.len() as u64;
+    for (row_group_index, row_group) in metadata.row_groups.iter_mut().enumerate() {
+        let row_group_ordinal = ordinal(row_group_index, "row groups")?;
+        let row_group_start = offset;
+        for (column_index, column) in row_group.columns.iter_mut().enumerate() {
+            let column_ordinal = ordinal(column_index, "columns")?;
+            let chunk_start = offset;
+            let mut meta_data = column
+                .meta_data
+                .take()
+                .ok_or_else(|| ArrowError::oos("A column chunk must have metadata"))?;
+
+            let column_key = options
+                .column_keys
+                .iter()
+                .position(|key| key.path == meta_data.path_in_schema);
+            let key = if let Some(index) = column_key {
+                used_column_keys[index] = true;
+                &options.column_keys[index].key
+            } else {
+                &options.footer_key
+            };
+
+            let start = meta_data
+                .dictionary_page_offset
+                .filter(|offset| *offset > 0)
+                .unwrap_or(meta_data.data_page_offset);
+            if start < 0 || meta_data.total_compressed_size < 0 {
+                return Err(ArrowError::oos(
+                    "The offset and size of a column chunk must be positive",
+                ));
+            }
+            let end = start as u64 + meta_data.total_compressed_size as u64;
+            reader.seek(SeekFrom::Start(start as u64))?;
+
+            let mut dictionary_page_offset = None;
+            let mut data_page_offset = None;
+            let mut data_pages = 0;
+            let mut position = start as u64;
+            while position < end {
+                let mut protocol = TCompactInputProtocol::new(&mut *reader);
+                let mut header =
+                    PageHeader::read_from_in_protocol(&mut protocol).map_err(thrift_error)?;
+                let mut page = vec![0; header.compressed_page_size.max(0) as usize];
+                reader.read_exact(&mut page)?;
+                position = reader.stream_position()?;
+
+                let is_dictionary = header.type_ == PageType::DictionaryPage;
+                let (header_type, page_type, page_ordinal) = if is_dictionary {
+                    if dictionary_page_offset.is_some() || data_page_offset.is_some() {
+                        return Err(ArrowError::oos(
+                            "Only the first page of a column chunk can be a dictionary page",
+                        ));
+                    }
+                    dictionary_page_offset = Some(offset as i64);
+                    (
+                        ModuleType::DictionaryPageHeader,
+                        ModuleType::DictionaryPage,
+                        None,
+                    )
+                } else {
+                    data_page_offset.get_or_insert(offset as i64);
+                    let page_ordinal = ordinal(data_pages, "data pages per column chunk")?;
+                    data_pages += 1;
+                    (
+                        ModuleType::DataPageHeader,
+                        ModuleType::DataPage,
+                        Some(page_ordinal),
+                    )
+                };
+
+                let page_aad = module_aad(
+                    &file_aad,
+                    page_type,
+                    row_group_ordinal,
+                    column_ordinal,
+                    page_ordinal,
+                );
+                let page = encrypt_module(key, &page, &page_aad, is_ctr)?;
+                header.compressed_page_size = page.len() as i32;
+
+                let header = serialize(|protocol| {
+                    header
+                        .write_to_out_protocol(protocol)
+                        .map(|_| ())
+                        .map_err(thrift_error)
+                })?;
+                let header_aad = module_aad(
+                    &file_aad,
+                    header_type,
+                    row_group_ordinal,
+                    column_ordinal,
+                    page_ordinal,
+                );
+                let header = encrypt_module(key, &header, &header_aad, false)?;
+
+                writer.write_all(&header)?;
+                writer.write_all(&page)?;
+                offset += (header.len() + page.len()) as u64;
+            }
+            if position != end {
+                return Err(ArrowError::oos(
+                    "The pages of a column chunk must fit in its total compressed size",
+                ));
+            }
+
+            meta_data.dictionary_page_offset = dictionary_page_offset;
+            meta_data.data_page_offset = data_page_offset.unwrap_or(offset as i64);
+            meta_data.index_page_offset = None;
+            meta_data.bloom_filter_offset = None;
+            meta_data.total_compressed_size = (offset - chunk_start) as i64;
+            column.file_offset = chunk_start as i64;
+            column.offset_index_offset = None;
+            column.offset_index_length = None;
+            column.column_index_offset = None;
+            column.column_index_length = None;
+
+            if let Some(index) = column_key {
+                let column_key = &options.column_keys[index];
+                let bytes = serialize(|protocol| {
+                    meta_data
+                        .write_to_out_protocol(protocol)
+                        .map(|_| ())
+                        .map_err(thrift_error)
+                })?;
+                let aad = module_aad(
+                    &file_aad,
+                    ModuleType::ColumnMetaData,
+                    row_group_ordinal,
+                    column_ordinal,
+                    None,
+                );
+                column.encrypted_column_metadata =
+                    Some(encrypt_module(&column_key.key, &bytes, &aad, false)?);
+                column.crypto_metadata = Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(
+                    EncryptionWithColumnKey {
+                        path_in_schema: column_key.path.clone(),
+                        key_metadata: column_key.key_metadata.clone(),
+                    },
+                ));
+            } else {
+                column.meta_data = Some(meta_data);
+                column.crypto_metadata = Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+                    EncryptionWithFooterKey::new(),
+                ));
+            }
+        }
+        row_group.file_offset = Some(row_group_start as i64);
+        row_group.total_compressed_size = Some((offset - row_group_start) as i64);
+        row_group.ordinal = Some(row_group_ordinal);
+    }
+
+    if let Some(index) = used_column_keys.iter().position(|used| !used) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The column key of {:?} matches no column chunk of the file",
+            options.column_keys[index].path
+        )));
+    }
+
+    let crypto_metadata = FileCryptoMetaData {
+        encryption_algorithm: algorithm,
+        key_metadata: options.footer_key_metadata.clone(),
+    };
+    let mut footer = serialize(|protocol| {
+        crypto_metadata
+            .write_to_out_protocol(protocol)
+            .map(|_| ())
+            .map_err(thrift_error)
+    })?;
+    let metadata = serialize(|protocol| {
+        metadata
+            .write_to_out_protocol(protocol)
+            .map(|_| ())
+            .map_err(thrift_error)
+    })?;
+    footer.extend(encrypt_module(
+        &options.footer_key,
+        &metadata,
+        &footer_aad(&file_aad),
+        false,
+    )?);
+
+    writer.write_all(&footer)?;
+    writer.write_all(&(footer.len() as i32).to_le_bytes())?;
+    writer.write_all(&ENCRYPTED_MAGIC)?;
+    Ok(offset + footer.len() as u64 + 8)
+}
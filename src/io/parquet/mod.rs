@@ -1,6 +1,10 @@
 //! APIs to read from and write to Parquet format.
 use crate::error::ArrowError;
 
+pub mod bloom_filter;
+#[cfg(feature = "io_parquet_encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "io_parquet_encryption")))]
+pub mod encryption;
 pub mod read;
 pub mod write;
 
@@ -10,6 +10,7 @@ use crate::{
     error::Result,
 };
 
+use super::super::delta;
 use super::super::utils;
 
 /// Assumptions: No rep levels
@@ -287,6 +288,21 @@ pub(super) fn extend_from_page<O: Offset>(
         (Encoding::Plain, _, false) => {
             read_plain_required::<O>(page.buffer(), page.num_values(), offsets, values)
         }
+        (Encoding::DeltaByteArray, _, true) => {
+            let values_buffer = delta::byte_array_to_plain(values_buffer)?;
+            read_plain_optional::<O>(
+                validity_buffer,
+                &values_buffer,
+                additional,
+                offsets,
+                values,
+                validity,
+            )
+        }
+        (Encoding::DeltaByteArray, _, false) => {
+            let values_buffer = delta::byte_array_to_plain(values_buffer)?;
+            read_plain_required::<O>(&values_buffer, additional, offsets, values)
+        }
         _ => {
             return Err(utils::not_implemented(
                 &page.encoding(),
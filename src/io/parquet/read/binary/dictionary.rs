@@ -30,12 +30,7 @@ fn read_dict_optional<K, O>(
     O: Offset,
 {
     let length = indices.len() + additional;
-    values.extend_from_slice(dict.values());
-    offsets.extend(
-        dict.offsets()
-            .iter()
-            .map(|x| O::from_usize(*x as usize).unwrap()),
-    );
+    extend_dict(dict, offsets, values);
 
     // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
     // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
@@ -71,13 +66,49 @@ fn read_dict_optional<K, O>(
                         indices.push(index)
                     })
                 } else {
-                    indices.resize(indices.len() + additional, *indices.last().unwrap());
+                    indices.resize(indices.len() + additional, K::default());
                 }
             }
         }
     }
 }
 
+/// Extends `offsets` and `values` by the dictionary of all pages of the column chunk, `dict`,
+/// if they were not yet extended by a previous page.
+fn extend_dict<O: Offset>(dict: &BinaryPageDict, offsets: &mut Vec<O>, values: &mut Vec<u8>) {
+    if offsets.is_empty() {
+        values.extend_from_slice(dict.values());
+        offsets.extend(
+            dict.offsets()
+                .iter()
+                .map(|x| O::from_usize(*x as usize).unwrap()),
+        );
+    }
+}
+
+fn read_dict_required<K, O>(
+    indices_buffer: &[u8],
+    additional: usize,
+    dict: &BinaryPageDict,
+    indices: &mut Vec<K>,
+    offsets: &mut Vec<O>,
+    values: &mut Vec<u8>,
+    validity: &mut MutableBitmap,
+) where
+    K: DictionaryKey,
+    O: Offset,
+{
+    extend_dict(dict, offsets, values);
+
+    let bit_width = indices_buffer[0];
+    let indices_buffer = &indices_buffer[1..];
+
+    let new_indices =
+        hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
+    indices.extend(new_indices.map(|index| K::from_u32(index).unwrap()));
+    validity.extend_constant(additional, true);
+}
+
 fn extend_from_page<K, O>(
     page: &DataPage,
     descriptor: &ColumnDescriptor,
@@ -110,6 +141,17 @@ where
                 validity,
             )
         }
+        (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), false) => {
+            read_dict_required(
+                values_buffer,
+                additional,
+                dict.as_any().downcast_ref().unwrap(),
+                indices,
+                offsets,
+                values,
+                validity,
+            )
+        }
         _ => {
             return Err(other_utils::not_implemented(
                 &page.encoding(),
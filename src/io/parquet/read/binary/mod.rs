@@ -60,6 +60,7 @@ where
             )?
         }
     }
+    check_offsets::<O>(&values, &data_type)?;
     Ok(utils::finish_array(data_type, offsets, values, validity))
 }
 
@@ -92,5 +93,19 @@ where
         )?
     }
 
+    check_offsets::<O>(&values, data_type)?;
     Ok(finish_array(data_type.clone(), offsets, values, validity))
 }
+
+/// Errors iff the total length of `values` does not fit in the offsets `O`, in which case the
+/// offsets overflowed while reading.
+fn check_offsets<O: Offset>(values: &[u8], data_type: &DataType) -> Result<()> {
+    if O::from_usize(values.len()).is_none() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The values of the column ({} bytes) do not fit in the offsets of {:?}. Read it as a large (64 bit offsets) type instead.",
+            values.len(),
+            data_type
+        )));
+    }
+    Ok(())
+}
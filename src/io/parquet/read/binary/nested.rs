@@ -1,9 +1,4 @@
-use parquet2::{
-    encoding::{hybrid_rle::HybridRleDecoder, Encoding},
-    metadata::ColumnDescriptor,
-    page::DataPage,
-    read::levels::get_bit_width,
-};
+use parquet2::{encoding::Encoding, metadata::ColumnDescriptor, page::DataPage};
 
 use super::super::nested_utils::*;
 use super::super::utils;
@@ -14,6 +9,7 @@ use crate::{array::Offset, bitmap::MutableBitmap, error::Result};
 fn read_values<'a, O, D, G>(
     def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     offsets: &mut Vec<O>,
     values: &mut Vec<u8>,
@@ -29,7 +25,8 @@ fn read_values<'a, O, D, G>(
             values.extend_from_slice(v);
             offsets.push(*offsets.last().unwrap() + O::from_usize(v.len()).unwrap());
             validity.push(true);
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
+            // the leaf or one of its ancestor structs is null
             offsets.push(*offsets.last().unwrap());
             validity.push(false);
         }
@@ -55,18 +52,20 @@ fn read<O: Offset>(
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels =
-                HybridRleDecoder::new(rep_levels, get_bit_width(rep_level_encoding.1), additional);
-            if is_nullable {
-                let def_levels = HybridRleDecoder::new(
-                    def_levels,
-                    get_bit_width(def_level_encoding.1),
-                    additional,
-                );
+            // the offsets of the innermost list point to the values read so far
+            let values_length = offsets.len() - 1;
+
+            // slots defined below `max_def_level` are nulls from `min_def_level` onwards
+            let min_def_level = leaf_definition_level(nested);
+
+            let rep_levels = levels_iter(rep_levels, rep_level_encoding.1, additional);
+            if min_def_level < max_def_level {
+                let def_levels = levels_iter(def_levels, def_level_encoding.1, additional);
                 let new_values = utils::BinaryIter::new(values_buffer);
                 read_values(
                     def_levels,
                     max_def_level,
+                    min_def_level,
                     new_values,
                     offsets,
                     values,
@@ -76,8 +75,7 @@ fn read<O: Offset>(
                 read_plain_required(values_buffer, additional, offsets, values)
             }
 
-            let def_levels =
-                HybridRleDecoder::new(def_levels, get_bit_width(def_level_encoding.1), additional);
+            let def_levels = levels_iter(def_levels, def_level_encoding.1, additional);
 
             extend_offsets(
                 rep_levels,
@@ -85,6 +83,7 @@ fn read<O: Offset>(
                 is_nullable,
                 max_rep_level,
                 max_def_level,
+                values_length,
                 nested,
             )
         }
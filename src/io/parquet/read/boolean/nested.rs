@@ -1,9 +1,4 @@
-use parquet2::{
-    encoding::{hybrid_rle::HybridRleDecoder, Encoding},
-    metadata::ColumnDescriptor,
-    page::DataPage,
-    read::levels::get_bit_width,
-};
+use parquet2::{encoding::Encoding, metadata::ColumnDescriptor, page::DataPage};
 
 use super::super::nested_utils::*;
 use super::super::utils;
@@ -16,6 +11,7 @@ use crate::{
 fn read_values<D, G>(
     def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     values: &mut MutableBitmap,
     validity: &mut MutableBitmap,
@@ -27,7 +23,8 @@ fn read_values<D, G>(
         if def == max_def {
             values.push(new_values.next().unwrap());
             validity.push(true);
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
+            // the leaf or one of its ancestor structs is null
             values.push(false);
             validity.push(false);
         }
@@ -52,26 +49,33 @@ fn read(
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels =
-                HybridRleDecoder::new(rep_levels, get_bit_width(rep_level_encoding.1), additional);
-            if is_nullable {
-                let def_levels = HybridRleDecoder::new(
-                    def_levels,
-                    get_bit_width(def_level_encoding.1),
-                    additional,
-                );
+            // the offsets of the innermost list point to the values read so far
+            let values_length = values.len();
+
+            // slots defined below `max_def_level` are nulls from `min_def_level` onwards
+            let min_def_level = leaf_definition_level(nested);
+
+            let rep_levels = levels_iter(rep_levels, rep_level_encoding.1, additional);
+            if min_def_level < max_def_level {
+                let def_levels = levels_iter(def_levels, def_level_encoding.1, additional);
 
                 // don't know how many values there is: using the max possible
                 let num_valid_values = additional.min(values_buffer.len() * 8);
 
                 let new_values = BitmapIter::new(values_buffer, 0, num_valid_values);
-                read_values(def_levels, max_def_level, new_values, values, validity)
+                read_values(
+                    def_levels,
+                    max_def_level,
+                    min_def_level,
+                    new_values,
+                    values,
+                    validity,
+                )
             } else {
                 read_required(values_buffer, additional, values)
             }
 
-            let def_levels =
-                HybridRleDecoder::new(def_levels, get_bit_width(def_level_encoding.1), additional);
+            let def_levels = levels_iter(def_levels, def_level_encoding.1, additional);
 
             extend_offsets(
                 rep_levels,
@@ -79,6 +83,7 @@ fn read(
                 is_nullable,
                 max_rep_level,
                 max_def_level,
+                values_length,
                 nested,
             )
         }
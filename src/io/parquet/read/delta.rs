@@ -0,0 +1,193 @@
+//! Decoders of parquet's delta encodings (`DELTA_BINARY_PACKED`, `DELTA_LENGTH_BYTE_ARRAY`
+//! and `DELTA_BYTE_ARRAY`).
+//!
+//! Pages encoded with these encodings are decoded to their `PLAIN` representation, so that
+//! they share the (nullable and required) plain decoders of each physical type.
+use crate::error::{ArrowError, Result};
+
+fn oos(message: &str) -> ArrowError {
+    ArrowError::OutOfSpec(format!("Invalid delta-encoded parquet page: {}", message))
+}
+
+/// Decodes an unsigned LEB128 integer from `data` at `position`, advancing `position`.
+fn uleb128(data: &[u8], position: &mut usize) -> Result<u64> {
+    let mut result = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *data
+            .get(*position)
+            .ok_or_else(|| oos("a varint is out of bounds"))?;
+        *position += 1;
+        if shift >= 64 {
+            return Err(oos("a varint overflows 64 bits"));
+        }
+        result |= ((byte & 0x7F) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+    }
+}
+
+/// Decodes a zigzag-encoded LEB128 integer from `data` at `position`, advancing `position`.
+fn zigzag_leb128(data: &[u8], position: &mut usize) -> Result<i64> {
+    let value = uleb128(data, position)?;
+    Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+/// Returns the `num_bits`-wide value starting at bit `offset` of `data` (LSB first).
+/// Bits past the end of `data` are read as zeros.
+#[inline]
+fn unpack(data: &[u8], offset: usize, num_bits: usize) -> u64 {
+    let start = offset / 8;
+    let shift = offset % 8;
+    let end = (start + 9).min(data.len());
+    let mut window = 0u128;
+    for (i, byte) in data[start.min(end)..end].iter().enumerate() {
+        window |= (*byte as u128) << (8 * i);
+    }
+    let mask = if num_bits == 64 {
+        u64::MAX
+    } else {
+        (1u64 << num_bits) - 1
+    };
+    (window >> shift) as u64 & mask
+}
+
+/// Decodes a `DELTA_BINARY_PACKED`-encoded buffer, returning its values and the number of
+/// bytes of `data` that they occupied.
+pub fn decode_binary_packed(data: &[u8]) -> Result<(Vec<i64>, usize)> {
+    let mut position = 0;
+    let block_size = uleb128(data, &mut position)? as usize;
+    let num_miniblocks = uleb128(data, &mut position)? as usize;
+    let length = uleb128(data, &mut position)? as usize;
+    let first_value = zigzag_leb128(data, &mut position)?;
+
+    if num_miniblocks == 0 || block_size % num_miniblocks != 0 {
+        return Err(oos(
+            "the block size must be a multiple of the number of miniblocks",
+        ));
+    }
+    let values_per_miniblock = block_size / num_miniblocks;
+    if values_per_miniblock % 8 != 0 {
+        return Err(oos(
+            "the number of values of a miniblock must be a multiple of 8",
+        ));
+    }
+
+    let mut values = Vec::with_capacity(length);
+    if length == 0 {
+        return Ok((values, position));
+    }
+    values.push(first_value);
+    let mut last = first_value;
+
+    while values.len() < length {
+        let min_delta = zigzag_leb128(data, &mut position)?;
+        let bit_widths = data
+            .get(position..position + num_miniblocks)
+            .ok_or_else(|| oos("the bit widths of a block are out of bounds"))?;
+        position += num_miniblocks;
+
+        for &num_bits in bit_widths {
+            let remaining = length - values.len();
+            if remaining == 0 {
+                // bit widths of unneeded miniblocks are present, but not their values
+                break;
+            }
+            let num_bits = num_bits as usize;
+            if num_bits > 64 {
+                return Err(oos("the bit width of a miniblock must be at most 64"));
+            }
+            let num_bytes = values_per_miniblock * num_bits / 8;
+            let needed = remaining.min(values_per_miniblock);
+            let start = position.min(data.len());
+            let end = (position + num_bytes).min(data.len());
+            let miniblock = &data[start..end];
+            if miniblock.len() * 8 < needed * num_bits {
+                return Err(oos("a miniblock is out of bounds"));
+            }
+            for i in 0..needed {
+                let delta = unpack(miniblock, i * num_bits, num_bits) as i64;
+                last = last.wrapping_add(min_delta).wrapping_add(delta);
+                values.push(last);
+            }
+            position += miniblock.len();
+        }
+    }
+    Ok((values, position))
+}
+
+/// Decodes a `DELTA_BINARY_PACKED`-encoded buffer of a physical type of `size` bytes
+/// (4 for `INT32`, 8 for `INT64`) to its `PLAIN` encoding.
+pub fn binary_packed_to_plain(data: &[u8], size: usize) -> Result<Vec<u8>> {
+    let (values, _) = decode_binary_packed(data)?;
+    let mut plain = Vec::with_capacity(values.len() * size);
+    match size {
+        4 => values
+            .iter()
+            .for_each(|x| plain.extend_from_slice(&(*x as i32).to_le_bytes())),
+        8 => values
+            .iter()
+            .for_each(|x| plain.extend_from_slice(&x.to_le_bytes())),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(
+                "DELTA_BINARY_PACKED is only supported for INT32 and INT64".to_string(),
+            ))
+        }
+    }
+    Ok(plain)
+}
+
+/// Decodes a `DELTA_LENGTH_BYTE_ARRAY`-encoded buffer, returning the length of each value
+/// and the concatenated values.
+fn decode_length_byte_array(data: &[u8]) -> Result<(Vec<usize>, &[u8])> {
+    let (lengths, position) = decode_binary_packed(data)?;
+    let lengths = lengths
+        .into_iter()
+        .map(|length| {
+            if length < 0 {
+                Err(oos("the length of a value can't be negative"))
+            } else {
+                Ok(length as usize)
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let total = lengths.iter().sum::<usize>();
+    let values = data
+        .get(position..position + total)
+        .ok_or_else(|| oos("the values are out of bounds"))?;
+    Ok((lengths, values))
+}
+
+/// Decodes a `DELTA_BYTE_ARRAY`-encoded buffer to its `PLAIN` encoding.
+pub fn byte_array_to_plain(data: &[u8]) -> Result<Vec<u8>> {
+    let (prefix_lengths, position) = decode_binary_packed(data)?;
+    let (suffix_lengths, suffixes) = decode_length_byte_array(&data[position..])?;
+    if prefix_lengths.len() != suffix_lengths.len() {
+        return Err(oos(
+            "the number of prefixes must equal the number of suffixes",
+        ));
+    }
+
+    let mut plain = Vec::with_capacity(4 * suffix_lengths.len() + suffixes.len());
+    let mut previous = vec![];
+    let mut current = vec![];
+    let mut offset = 0;
+    for (prefix_length, suffix_length) in prefix_lengths.into_iter().zip(suffix_lengths) {
+        if prefix_length < 0 || prefix_length as usize > previous.len() {
+            return Err(oos(
+                "the prefix of a value must be part of the previous value",
+            ));
+        }
+        current.clear();
+        current.extend_from_slice(&previous[..prefix_length as usize]);
+        current.extend_from_slice(&suffixes[offset..offset + suffix_length]);
+        offset += suffix_length;
+
+        plain.extend_from_slice(&(current.len() as u32).to_le_bytes());
+        plain.extend_from_slice(&current);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    Ok(plain)
+}
@@ -0,0 +1,281 @@
+//! APIs to read parquet's page index (column index and offset index) into arrow.
+//!
+//! The page index is an optional part of parquet files that stores, for every page of a column
+//! chunk, its statistics (the column index) and its location (the offset index).
+//! It enables pruning of pages, which is much finer than the pruning of row groups.
+use std::convert::TryInto;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::ops::Range;
+
+use parquet_format_async_temp::thrift::protocol::TCompactInputProtocol;
+pub use parquet_format_async_temp::{BoundaryOrder, PageLocation};
+use parquet_format_async_temp::{ColumnIndex, OffsetIndex};
+
+use crate::array::{
+    Array, BinaryArray, BooleanArray, FixedSizeBinaryArray, PrimitiveArray, Utf8Array,
+};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::schema::to_data_type;
+use super::utils::i128_from_be_slice;
+use super::{ColumnChunkMetaData, PhysicalType};
+
+/// The statistics of every page of a column chunk, as declared in its column index.
+#[derive(Debug)]
+pub struct PageStatistics {
+    /// The minimum value of each page (null for pages with only nulls)
+    pub min: Box<dyn Array>,
+    /// The maximum value of each page (null for pages with only nulls)
+    pub max: Box<dyn Array>,
+    /// The number of nulls of each page (null if not declared)
+    pub null_count: PrimitiveArray<i64>,
+    /// Whether the values of the pages are ordered
+    pub boundary_order: BoundaryOrder,
+}
+
+fn read_bytes<R: Read + Seek>(reader: &mut R, offset: i64, length: i32) -> Result<Vec<u8>> {
+    if offset < 0 || length < 0 {
+        return Err(ArrowError::oos(
+            "The offset and length of a page index must be positive",
+        ));
+    }
+    reader.seek(SeekFrom::Start(offset as u64))?;
+    let mut data = vec![0; length as usize];
+    reader.read_exact(&mut data)?;
+    Ok(data)
+}
+
+fn thrift_error(error: parquet_format_async_temp::thrift::Error) -> ArrowError {
+    ArrowError::External(
+        "Unable to deserialize the page index".to_string(),
+        Box::new(error),
+    )
+}
+
+fn primitive<T: NativeType, const N: usize, F: Fn([u8; N]) -> T>(
+    values: &[Vec<u8>],
+    null_pages: &[bool],
+    data_type: DataType,
+    op: F,
+) -> Result<Box<dyn Array>> {
+    let values = values
+        .iter()
+        .zip(null_pages.iter())
+        .map(|(value, is_null)| {
+            if *is_null {
+                return Ok(None);
+            }
+            let value: [u8; N] = value.as_slice().try_into().map_err(|_| {
+                ArrowError::oos("The value of a page index does not match its physical type")
+            })?;
+            Ok(Some(op(value)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(PrimitiveArray::<T>::from(values).to(data_type)))
+}
+
+/// Deserializes the (plain-encoded) `values` of a column index into an [`Array`] of `data_type`.
+fn deserialize_values(
+    values: &[Vec<u8>],
+    null_pages: &[bool],
+    physical_type: &PhysicalType,
+    data_type: DataType,
+) -> Result<Box<dyn Array>> {
+    use DataType::*;
+    match (physical_type, data_type.to_logical_type()) {
+        (PhysicalType::Boolean, _) => Ok(Box::new(BooleanArray::from_iter(
+            values
+                .iter()
+                .zip(null_pages.iter())
+                .map(|(value, is_null)| (!is_null).then(|| value.first() == Some(&1))),
+        ))),
+        (PhysicalType::Int32, UInt8) => primitive(values, null_pages, data_type, |x| {
+            i32::from_le_bytes(x) as u8
+        }),
+        (PhysicalType::Int32, UInt16) => primitive(values, null_pages, data_type, |x| {
+            i32::from_le_bytes(x) as u16
+        }),
+        (PhysicalType::Int32, UInt32) => primitive(values, null_pages, data_type, |x| {
+            i32::from_le_bytes(x) as u32
+        }),
+        (PhysicalType::Int32, Int8) => primitive(values, null_pages, data_type, |x| {
+            i32::from_le_bytes(x) as i8
+        }),
+        (PhysicalType::Int32, Int16) => primitive(values, null_pages, data_type, |x| {
+            i32::from_le_bytes(x) as i16
+        }),
+        (PhysicalType::Int32, Decimal(_, _)) => primitive(values, null_pages, data_type, |x| {
+            i32::from_le_bytes(x) as i128
+        }),
+        (PhysicalType::Int32, _) => primitive(values, null_pages, data_type, i32::from_le_bytes),
+        (PhysicalType::Int64, UInt64) => primitive(values, null_pages, data_type, |x| {
+            i64::from_le_bytes(x) as u64
+        }),
+        (PhysicalType::Int64, Decimal(_, _)) => primitive(values, null_pages, data_type, |x| {
+            i64::from_le_bytes(x) as i128
+        }),
+        (PhysicalType::Int64, _) => primitive(values, null_pages, data_type, i64::from_le_bytes),
+        (PhysicalType::Float, _) => primitive(values, null_pages, data_type, f32::from_le_bytes),
+        (PhysicalType::Double, _) => primitive(values, null_pages, data_type, f64::from_le_bytes),
+        (PhysicalType::ByteArray, Utf8) => {
+            let values = values
+                .iter()
+                .zip(null_pages.iter())
+                .map(|(value, is_null)| {
+                    (!is_null)
+                        .then(|| simdutf8::basic::from_utf8(value))
+                        .transpose()
+                })
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            Ok(Box::new(values.into_iter().collect::<Utf8Array<i32>>()))
+        }
+        (PhysicalType::ByteArray, _) => Ok(Box::new(
+            values
+                .iter()
+                .zip(null_pages.iter())
+                .map(|(value, is_null)| (!is_null).then(|| value))
+                .collect::<BinaryArray<i32>>(),
+        )),
+        (PhysicalType::FixedLenByteArray(size), Decimal(_, _)) if *size <= 16 => {
+            let values = values
+                .iter()
+                .zip(null_pages.iter())
+                .map(|(value, is_null)| {
+                    if *is_null {
+                        return Ok(None);
+                    }
+                    if value.len() != *size as usize {
+                        return Err(ArrowError::oos(
+                            "The value of a page index does not match its physical type",
+                        ));
+                    }
+                    Ok(Some(i128_from_be_slice(value)))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Box::new(PrimitiveArray::<i128>::from(values).to(data_type)))
+        }
+        (PhysicalType::FixedLenByteArray(size), _) => {
+            let array = FixedSizeBinaryArray::try_from_iter(
+                values
+                    .iter()
+                    .zip(null_pages.iter())
+                    .map(|(value, is_null)| (!is_null).then(|| value)),
+                *size as usize,
+            )?;
+            Ok(Box::new(array))
+        }
+        (other, _) => Err(ArrowError::NotYetImplemented(format!(
+            "Reading the page index of the physical type {:?} is not yet supported",
+            other
+        ))),
+    }
+}
+
+/// Reads the column index of `column`, returning the statistics of each of its pages
+/// as arrow arrays, or `None` if the column chunk has no column index.
+/// # Errors
+/// This function errors if the column index is out of spec or if reading it fails.
+pub fn read_page_statistics<R: Read + Seek>(
+    reader: &mut R,
+    column: &ColumnChunkMetaData,
+) -> Result<Option<PageStatistics>> {
+    let chunk = column.column_chunk();
+    let (offset, length) = match (chunk.column_index_offset, chunk.column_index_length) {
+        (Some(offset), Some(length)) => (offset, length),
+        _ => return Ok(None),
+    };
+    let data = read_bytes(reader, offset, length)?;
+    let mut protocol = TCompactInputProtocol::new(Cursor::new(data));
+    let index = ColumnIndex::read_from_in_protocol(&mut protocol).map_err(thrift_error)?;
+
+    let data_type = to_data_type(column.descriptor().type_())?.ok_or_else(|| {
+        ArrowError::nyi("Reading the page index of this parquet type is not yet supported")
+    })?;
+    let physical_type = column.physical_type();
+
+    let min = deserialize_values(
+        &index.min_values,
+        &index.null_pages,
+        &physical_type,
+        data_type.clone(),
+    )?;
+    let max = deserialize_values(
+        &index.max_values,
+        &index.null_pages,
+        &physical_type,
+        data_type,
+    )?;
+    let null_count = match index.null_counts {
+        Some(null_counts) => PrimitiveArray::<i64>::from_vec(null_counts),
+        None => PrimitiveArray::<i64>::new_null(DataType::Int64, index.null_pages.len()),
+    };
+
+    Ok(Some(PageStatistics {
+        min,
+        max,
+        null_count,
+        boundary_order: index.boundary_order,
+    }))
+}
+
+/// Reads the offset index of `column`, returning the location of each of its pages,
+/// or `None` if the column chunk has no offset index.
+/// # Errors
+/// This function errors if the offset index is out of spec or if reading it fails.
+pub fn read_page_locations<R: Read + Seek>(
+    reader: &mut R,
+    column: &ColumnChunkMetaData,
+) -> Result<Option<Vec<PageLocation>>> {
+    let chunk = column.column_chunk();
+    let (offset, length) = match (chunk.offset_index_offset, chunk.offset_index_length) {
+        (Some(offset), Some(length)) => (offset, length),
+        _ => return Ok(None),
+    };
+    let data = read_bytes(reader, offset, length)?;
+    let mut protocol = TCompactInputProtocol::new(Cursor::new(data));
+    let index = OffsetIndex::read_from_in_protocol(&mut protocol).map_err(thrift_error)?;
+    Ok(Some(index.page_locations))
+}
+
+/// Returns the (sorted and non-overlapping) ranges of rows covered by the `selected` pages,
+/// out of a column chunk with `num_rows` rows whose pages are at `locations`.
+///
+/// Contiguous selected pages are merged into a single range.
+/// # Errors
+/// This function errors if `locations` and `selected` have different lengths or if the
+/// first row of the pages is not monotonically increasing.
+pub fn select_rows(
+    locations: &[PageLocation],
+    selected: &[bool],
+    num_rows: usize,
+) -> Result<Vec<Range<usize>>> {
+    if locations.len() != selected.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "The number of selected pages must equal the number of page locations".to_string(),
+        ));
+    }
+
+    let mut ranges: Vec<Range<usize>> = vec![];
+    for (i, (location, is_selected)) in locations.iter().zip(selected.iter()).enumerate() {
+        let start = location.first_row_index as usize;
+        let end = locations
+            .get(i + 1)
+            .map(|next| next.first_row_index as usize)
+            .unwrap_or(num_rows);
+        if end < start {
+            return Err(ArrowError::oos(
+                "The first row of each page must be monotonically increasing",
+            ));
+        }
+        if !is_selected {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(last) if last.end == start => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    Ok(ranges)
+}
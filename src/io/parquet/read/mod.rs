@@ -23,12 +23,13 @@ pub use parquet2::{
         LogicalType, ParquetType, PhysicalType, PrimitiveConvertedType,
         TimeUnit as ParquetTimeUnit, TimestampType,
     },
-    types::int96_to_i64_ns,
     FallibleStreamingIterator,
 };
 
 use crate::{
-    array::{Array, DictionaryKey, NullArray, PrimitiveArray, StructArray},
+    array::{Array, DictionaryKey, MapArray, NullArray, PrimitiveArray, StructArray},
+    bitmap::Bitmap,
+    buffer::Buffer,
     datatypes::{DataType, Field, IntervalUnit, TimeUnit},
     error::{ArrowError, Result},
     io::parquet::read::nested_utils::{create_list, init_nested},
@@ -36,17 +37,23 @@ use crate::{
 
 mod binary;
 mod boolean;
+mod delta;
 mod fixed_size_binary;
+pub mod indexes;
 mod nested_utils;
 mod primitive;
+mod pruning;
 mod record_batch;
 pub mod schema;
 pub mod statistics;
 mod utils;
 
-pub use record_batch::RecordReader;
+pub use pruning::{prune_row_groups, Operator, Predicate};
+pub use record_batch::{DecodeTask, Decoder, RecordReader, RowFilter};
 pub(crate) use schema::is_type_nullable;
-pub use schema::{get_schema, FileMetaData};
+pub use schema::{
+    apply_type_overrides, get_schema, get_schema_with_overrides, FileMetaData, TypeOverride,
+};
 
 use self::nested_utils::Nested;
 
@@ -77,6 +84,12 @@ pub async fn get_page_stream<'a, RR: AsyncRead + Unpin + Send + AsyncSeek>(
 }
 
 /// Reads parquets' metadata syncronously.
+///
+/// Sources other than files (e.g. object stores) can be read via a
+/// [`ReadableFile`](crate::io::range::ReadableFile), which only reads the ranges of the source
+/// that are needed.
+/// # Errors
+/// This function errors iff the file is not a parquet file.
 pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> Result<FileMetaData> {
     Ok(_read_metadata(reader)?)
 }
@@ -88,6 +101,29 @@ pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
     Ok(_read_metadata_async(reader).await?)
 }
 
+/// Whether the column chunk is of the (legacy) physical type INT96.
+fn is_int96(metadata: &ColumnChunkMetaData) -> bool {
+    matches!(metadata.physical_type(), PhysicalType::Int96)
+}
+
+const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Converts an INT96, the nanoseconds of a day followed by its julian day, to a timestamp in
+/// `time_unit`, truncating the precision finer than `time_unit`.
+/// Coarser units support a larger range of dates than nanoseconds (~1677-2262).
+fn int96_to_i64(value: [u32; 3], time_unit: TimeUnit) -> i64 {
+    let nanoseconds = ((value[1] as i64) << 32) + value[0] as i64;
+    let days = value[2] as i64 - JULIAN_DAY_OF_EPOCH;
+    let factor = match time_unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    };
+    days * SECONDS_PER_DAY * factor + nanoseconds / (1_000_000_000 / factor)
+}
+
 fn dict_read<
     K: DictionaryKey,
     I: FallibleStreamingIterator<Item = DataPage, Error = ParquetError>,
@@ -142,18 +178,20 @@ fn dict_read<
                 |x: i32| x as i32,
             )
         }
+        &Timestamp(time_unit, _) if is_int96(metadata) => {
+            primitive::iter_to_dict_array::<K, _, _, _, _, _>(
+                iter,
+                metadata,
+                data_type,
+                move |x: [u32; 3]| int96_to_i64(x, time_unit),
+            )
+        }
         Timestamp(TimeUnit::Nanosecond, None) => match metadata.descriptor().type_() {
             ParquetType::PrimitiveType {
                 physical_type,
                 logical_type,
                 ..
             } => match (physical_type, logical_type) {
-                (PhysicalType::Int96, _) => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
-                    iter,
-                    metadata,
-                    DataType::Timestamp(TimeUnit::Nanosecond, None),
-                    int96_to_i64_ns,
-                ),
                 (_, Some(LogicalType::TIMESTAMP(TimestampType { unit, .. }))) => match unit {
                     ParquetTimeUnit::MILLIS(_) => {
                         primitive::iter_to_dict_array::<K, _, _, _, _, _>(
@@ -190,6 +228,18 @@ fn dict_read<
         Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
             primitive::iter_to_dict_array::<K, _, _, _, _, _>(iter, metadata, data_type, |x: i64| x)
         }
+        UInt64 => primitive::iter_to_dict_array::<K, _, _, _, _, _>(
+            iter,
+            metadata,
+            data_type,
+            |x: i64| x as u64,
+        ),
+        Float32 => {
+            primitive::iter_to_dict_array::<K, _, _, _, _, _>(iter, metadata, data_type, |x: f32| x)
+        }
+        Float64 => {
+            primitive::iter_to_dict_array::<K, _, _, _, _, _>(iter, metadata, data_type, |x: f64| x)
+        }
         Utf8 => binary::iter_to_dict_array::<K, i32, _, _>(iter, metadata, data_type),
         LargeUtf8 => binary::iter_to_dict_array::<K, i64, _, _>(iter, metadata, data_type),
         other => Err(ArrowError::NotYetImplemented(format!(
@@ -199,49 +249,29 @@ fn dict_read<
     }
 }
 
-fn column_offset(data_type: &DataType) -> usize {
-    use crate::datatypes::PhysicalType::*;
-    match data_type.to_physical_type() {
-        Null | Boolean | Primitive(_) | FixedSizeBinary | Binary | LargeBinary | Utf8
-        | LargeUtf8 | Dictionary(_) | List | LargeList | FixedSizeList => 0,
-        Struct => {
-            if let DataType::Struct(v) = data_type.to_logical_type() {
-                v.iter().map(|x| 1 + column_offset(x.data_type())).sum()
-            } else {
-                unreachable!()
-            }
-        }
-        Union => todo!(),
-        Map => todo!(),
-    }
-}
-
-fn column_datatype(data_type: &DataType, column: usize) -> DataType {
+/// Pushes the data types of the leaves (parquet columns) of `data_type` to `leaves`.
+fn leaf_datatypes(data_type: &DataType, leaves: &mut Vec<DataType>) {
     use crate::datatypes::PhysicalType::*;
     match data_type.to_physical_type() {
         Null | Boolean | Primitive(_) | FixedSizeBinary | Binary | LargeBinary | Utf8
-        | LargeUtf8 | Dictionary(_) | List | LargeList | FixedSizeList => data_type.clone(),
+        | LargeUtf8 | Dictionary(_) => leaves.push(data_type.clone()),
+        List | LargeList | FixedSizeList | Map => match data_type.to_logical_type() {
+            DataType::List(inner)
+            | DataType::LargeList(inner)
+            | DataType::FixedSizeList(inner, _)
+            | DataType::Map(inner, _) => leaf_datatypes(inner.data_type(), leaves),
+            _ => unreachable!(),
+        },
         Struct => {
             if let DataType::Struct(fields) = data_type.to_logical_type() {
-                let mut total_chunk = 0;
-                let mut total_fields = 0;
-                for f in fields {
-                    let field_chunk = column_offset(f.data_type());
-                    if column < total_chunk + field_chunk {
-                        return column_datatype(f.data_type(), column + total_chunk);
-                    }
-                    total_fields += (field_chunk > 0) as usize;
-                    total_chunk += field_chunk;
-                }
-                fields[column + total_fields - total_chunk]
-                    .data_type()
-                    .clone()
+                fields
+                    .iter()
+                    .for_each(|field| leaf_datatypes(field.data_type(), leaves))
             } else {
                 unreachable!()
             }
         }
         Union => todo!(),
-        Map => todo!(),
     }
 }
 
@@ -269,19 +299,17 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
             primitive::iter_to_array(iter, metadata, data_type, nested, |x: i32| x as i32)
         }
 
+        &Timestamp(time_unit, _) if is_int96(metadata) => {
+            primitive::iter_to_array(iter, metadata, data_type, nested, move |x: [u32; 3]| {
+                int96_to_i64(x, time_unit)
+            })
+        }
         Timestamp(TimeUnit::Nanosecond, None) => match metadata.descriptor().type_() {
             ParquetType::PrimitiveType {
                 physical_type,
                 logical_type,
                 ..
             } => match (physical_type, logical_type) {
-                (PhysicalType::Int96, _) => primitive::iter_to_array(
-                    iter,
-                    metadata,
-                    DataType::Timestamp(TimeUnit::Nanosecond, None),
-                    nested,
-                    int96_to_i64_ns,
-                ),
                 (_, Some(LogicalType::TIMESTAMP(TimestampType { unit, .. }))) => match unit {
                     ParquetTimeUnit::MILLIS(_) => {
                         primitive::iter_to_array(iter, metadata, data_type, nested, |x: i64| {
@@ -329,15 +357,7 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
                     let values = fixed_size_binary_array
                         .values()
                         .chunks_exact(n)
-                        .map(|value: &[u8]| {
-                            // Copy the fixed-size byte value to the start of a 16 byte stack
-                            // allocated buffer, then use an arithmetic right shift to fill in
-                            // MSBs, which accounts for leading 1's in negative (two's complement)
-                            // values.
-                            let mut bytes = [0u8; 16];
-                            bytes[..n].copy_from_slice(value);
-                            i128::from_be_bytes(bytes) >> (8 * (16 - n))
-                        })
+                        .map(utils::i128_from_be_slice)
                         .collect::<Vec<_>>();
                     let validity = fixed_size_binary_array.validity().cloned();
                     let i128_array =
@@ -367,15 +387,6 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
             dict_read::<$T, _>(iter, metadata, data_type)
         }),
 
-        List(ref inner) => {
-            let values = page_iter_to_array(iter, nested, metadata, inner.data_type().clone())?;
-            create_list(data_type, nested, values.into())
-        }
-        LargeList(ref inner) => {
-            let values = page_iter_to_array(iter, nested, metadata, inner.data_type().clone())?;
-            create_list(data_type, nested, values.into())
-        }
-
         other => Err(ArrowError::NotYetImplemented(format!(
             "Reading {:?} from parquet still not implemented",
             other
@@ -383,27 +394,74 @@ fn page_iter_to_array<I: FallibleStreamingIterator<Item = DataPage, Error = Parq
     }
 }
 
-fn finish_array(data_type: DataType, arrays: &mut VecDeque<Box<dyn Array>>) -> Box<dyn Array> {
+/// A leaf array read from a column chunk and the [`Nested`] of each of its ancestors
+type Column = (Vec<Box<dyn Nested>>, Box<dyn Array>);
+
+/// Returns the offsets and validity of the level at `depth` of the first of `columns`.
+fn take_nested(columns: &mut VecDeque<Column>, depth: usize) -> (Buffer<i64>, Option<Bitmap>) {
+    let (nested, array) = columns.front_mut().unwrap();
+    // the last offset of the level is the length of its child
+    let length = nested
+        .get(depth + 1)
+        .map(|child| child.num_values())
+        .unwrap_or_else(|| array.len());
+    nested[depth].close(length as i64);
+    nested[depth].inner()
+}
+
+/// Assembles the array of `data_type` at `depth` from its leaf `columns`, consuming them.
+/// Levels shared by multiple leaves (e.g. a list of structs) are taken from their first leaf.
+fn finish_array(
+    data_type: DataType,
+    columns: &mut VecDeque<Column>,
+    depth: usize,
+) -> Result<Box<dyn Array>> {
     use crate::datatypes::PhysicalType::*;
     match data_type.to_physical_type() {
         Null | Boolean | Primitive(_) | FixedSizeBinary | Binary | LargeBinary | Utf8
-        | LargeUtf8 | List | LargeList | FixedSizeList | Dictionary(_) => {
-            arrays.pop_front().unwrap()
+        | LargeUtf8 | Dictionary(_) => Ok(columns.pop_front().unwrap().1),
+        List | LargeList | FixedSizeList => {
+            let inner = match data_type.to_logical_type() {
+                DataType::List(inner)
+                | DataType::LargeList(inner)
+                | DataType::FixedSizeList(inner, _) => inner.data_type().clone(),
+                _ => unreachable!(),
+            };
+            let (offsets, validity) = take_nested(columns, depth);
+            let values = finish_array(inner, columns, depth + 1)?;
+            create_list(data_type, offsets, validity, values.into())
         }
         Struct => {
             if let DataType::Struct(fields) = data_type.to_logical_type() {
+                let (_, validity) = take_nested(columns, depth);
                 let values = fields
                     .iter()
-                    .map(|f| finish_array(f.data_type().clone(), arrays))
-                    .map(|x| x.into())
-                    .collect();
-                Box::new(StructArray::from_data(data_type, values, None))
+                    .map(|f| finish_array(f.data_type().clone(), columns, depth + 1))
+                    .map(|x| x.map(|x| x.into()))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Box::new(StructArray::from_data(
+                    data_type, values, validity,
+                )))
             } else {
                 unreachable!()
             }
         }
+        Map => {
+            let inner = match data_type.to_logical_type() {
+                DataType::Map(inner, _) => inner.data_type().clone(),
+                _ => unreachable!(),
+            };
+            let (offsets, validity) = take_nested(columns, depth);
+            let offsets = Buffer::<i32>::from_trusted_len_iter(offsets.iter().map(|x| *x as i32));
+            let field = finish_array(inner, columns, depth + 1)?;
+            Ok(Box::new(MapArray::from_data(
+                data_type,
+                offsets,
+                field.into(),
+                validity,
+            )))
+        }
         Union => todo!(),
-        Map => todo!(),
     }
 }
 
@@ -419,27 +477,31 @@ where
     II: Iterator<Item = std::result::Result<CompressedDataPage, ParquetError>>,
     I: ColumnChunkIter<II>,
 {
-    let mut nested_info = vec![];
-    init_nested(field, 0, &mut nested_info);
-
     let data_type = field.data_type().clone();
 
+    let mut leaves = vec![];
+    leaf_datatypes(&data_type, &mut leaves);
+    let mut leaves = leaves.into_iter().zip(init_nested(field, 0));
+
     let mut arrays = VecDeque::new();
     let page_buffer;
-    let mut column = 0;
     loop {
         match columns.advance()? {
             State::Some(mut new_iter) => {
-                let data_type = column_datatype(&data_type, column);
+                let (data_type, mut nested) = leaves.next().ok_or_else(|| {
+                    ArrowError::OutOfSpec(format!(
+                        "The field \"{}\" has fewer leaves than column chunks",
+                        field.name
+                    ))
+                })?;
                 if let Some((pages, metadata)) = new_iter.get() {
                     let mut iterator = BasicDecompressor::new(pages, buffer);
 
                     let array =
-                        page_iter_to_array(&mut iterator, &mut nested_info, metadata, data_type)?;
+                        page_iter_to_array(&mut iterator, &mut nested, metadata, data_type)?;
                     buffer = iterator.into_inner();
-                    arrays.push_back(array)
+                    arrays.push_back((nested, array))
                 }
-                column += 1;
                 columns = new_iter;
             }
             State::Finished(b) => {
@@ -449,7 +511,7 @@ where
         }
     }
 
-    let array = finish_array(data_type, &mut arrays);
+    let array = finish_array(data_type, &mut arrays, 0)?;
     assert!(arrays.is_empty());
     Ok((array, page_buffer, buffer))
 }
@@ -476,21 +538,12 @@ pub async fn page_stream_to_array<I: Stream<Item = std::result::Result<DataPage,
             primitive::stream_to_array(pages, metadata, data_type, |x: i32| x as i32).await
         }
 
-        Timestamp(TimeUnit::Nanosecond, None) => match metadata.descriptor().type_() {
-            ParquetType::PrimitiveType { physical_type, .. } => match physical_type {
-                PhysicalType::Int96 => {
-                    primitive::stream_to_array(
-                        pages,
-                        metadata,
-                        DataType::Timestamp(TimeUnit::Nanosecond, None),
-                        int96_to_i64_ns,
-                    )
-                    .await
-                }
-                _ => primitive::stream_to_array(pages, metadata, data_type, |x: i64| x).await,
-            },
-            _ => unreachable!(),
-        },
+        &Timestamp(time_unit, _) if is_int96(metadata) => {
+            primitive::stream_to_array(pages, metadata, data_type, move |x: [u32; 3]| {
+                int96_to_i64(x, time_unit)
+            })
+            .await
+        }
 
         // INT64
         Int64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) => {
@@ -1,5 +1,8 @@
 use std::sync::Arc;
 
+use parquet2::encoding::hybrid_rle::HybridRleDecoder;
+use parquet2::read::levels::get_bit_width;
+
 use crate::{
     array::{Array, ListArray},
     bitmap::{Bitmap, MutableBitmap},
@@ -12,15 +15,17 @@ use crate::{
 pub trait Nested: std::fmt::Debug {
     fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>);
 
-    fn last_offset(&self) -> i64;
+    /// The number of slots pushed so far
+    fn num_values(&self) -> usize;
 
     fn push(&mut self, length: i64, is_valid: bool);
 
-    fn offsets(&mut self) -> &[i64];
-
     fn close(&mut self, length: i64);
 
     fn is_nullable(&self) -> bool;
+
+    /// Whether this level is repeated (a list), i.e. whether it has a repetition level
+    fn is_repeated(&self) -> bool;
 }
 
 #[derive(Debug, Default)]
@@ -40,7 +45,7 @@ impl Nested for NestedPrimitive {
     }
 
     #[inline]
-    fn last_offset(&self) -> i64 {
+    fn num_values(&self) -> usize {
         0
     }
 
@@ -48,12 +53,12 @@ impl Nested for NestedPrimitive {
         self.is_nullable
     }
 
-    fn push(&mut self, _value: i64, _is_valid: bool) {}
-
-    fn offsets(&mut self) -> &[i64] {
-        &[]
+    fn is_repeated(&self) -> bool {
+        false
     }
 
+    fn push(&mut self, _value: i64, _is_valid: bool) {}
+
     fn close(&mut self, _length: i64) {}
 }
 
@@ -71,23 +76,23 @@ impl Nested for NestedOptional {
     }
 
     #[inline]
-    fn last_offset(&self) -> i64 {
-        *self.offsets.last().unwrap()
+    fn num_values(&self) -> usize {
+        self.offsets.len()
     }
 
     fn is_nullable(&self) -> bool {
         true
     }
 
+    fn is_repeated(&self) -> bool {
+        true
+    }
+
     fn push(&mut self, value: i64, is_valid: bool) {
         self.offsets.push(value);
         self.validity.push(is_valid);
     }
 
-    fn offsets(&mut self) -> &[i64] {
-        &self.offsets
-    }
-
     fn close(&mut self, length: i64) {
         self.offsets.push(length)
     }
@@ -116,19 +121,19 @@ impl Nested for NestedValid {
         false
     }
 
+    fn is_repeated(&self) -> bool {
+        true
+    }
+
     #[inline]
-    fn last_offset(&self) -> i64 {
-        *self.offsets.last().unwrap()
+    fn num_values(&self) -> usize {
+        self.offsets.len()
     }
 
     fn push(&mut self, value: i64, _is_valid: bool) {
         self.offsets.push(value);
     }
 
-    fn offsets(&mut self) -> &[i64] {
-        &self.offsets
-    }
-
     fn close(&mut self, length: i64) {
         self.offsets.push(length)
     }
@@ -141,107 +146,193 @@ impl NestedValid {
     }
 }
 
+/// The validity of a struct. Its slots have no offsets: the struct has the same length as
+/// each of its fields.
+#[derive(Debug, Default)]
+pub struct NestedStruct {
+    is_nullable: bool,
+    validity: MutableBitmap,
+}
+
+impl NestedStruct {
+    pub fn with_capacity(is_nullable: bool, capacity: usize) -> Self {
+        Self {
+            is_nullable,
+            validity: MutableBitmap::with_capacity(capacity),
+        }
+    }
+}
+
+impl Nested for NestedStruct {
+    fn inner(&mut self) -> (Buffer<i64>, Option<Bitmap>) {
+        let validity = std::mem::take(&mut self.validity);
+        (Default::default(), validity.into())
+    }
+
+    #[inline]
+    fn num_values(&self) -> usize {
+        self.validity.len()
+    }
+
+    fn is_nullable(&self) -> bool {
+        self.is_nullable
+    }
+
+    fn is_repeated(&self) -> bool {
+        false
+    }
+
+    fn push(&mut self, _value: i64, is_valid: bool) {
+        self.validity.push(is_valid)
+    }
+
+    fn close(&mut self, _length: i64) {}
+}
+
+/// Returns an iterator over the `length` repetition or definition levels encoded in `buffer`.
+/// When `max_level` is zero, the levels are not encoded and are all zero.
+pub fn levels_iter(buffer: &[u8], max_level: i16, length: usize) -> impl Iterator<Item = u32> + '_ {
+    let (decoder, zeros) = if max_level == 0 {
+        (None, length)
+    } else {
+        let decoder = HybridRleDecoder::new(buffer, get_bit_width(max_level), length);
+        (Some(decoder), 0)
+    };
+    decoder
+        .into_iter()
+        .flatten()
+        .chain(std::iter::repeat(0).take(zeros))
+}
+
+/// The definition levels that a slot of each level of `nested` (and, last, of the leaf) requires:
+/// the level from which the slot exists (i.e. all its ancestor lists are non-empty), and the
+/// level from which all its ancestors are valid.
+fn definition_levels(nested: &[Box<dyn Nested>]) -> Vec<(u32, u32)> {
+    let mut levels = Vec::with_capacity(nested.len() + 1);
+    let mut exists_from = 0;
+    let mut defined_from = 0;
+    nested.iter().for_each(|nested| {
+        levels.push((exists_from, defined_from));
+        defined_from += nested.is_nullable() as u32;
+        if nested.is_repeated() {
+            // an empty list is defined one level above a list with values
+            defined_from += 1;
+            exists_from = defined_from;
+        }
+    });
+    levels.push((exists_from, defined_from));
+    levels
+}
+
+/// Returns the definition level from which a leaf value whose ancestors are `nested` exists.
+/// Leaf slots whose definition level is between this level and the maximum definition level
+/// are null (because the leaf or one of its ancestor structs is null).
+pub fn leaf_definition_level(nested: &[Box<dyn Nested>]) -> u32 {
+    definition_levels(nested).last().unwrap().0
+}
+
+/// Extends the offsets and validities of each level (list or struct) of `nested` from the
+/// repetition and definition levels of a page (Dremel's record assembly).
+///
+/// `values_length` is the number of values of the innermost (leaf) array read before this page,
+/// since the offsets of the innermost list point to them.
+/// The offsets are not closed: the last offset of each level is only known after all pages
+/// are read, and is pushed when the array of the column is finished.
 pub fn extend_offsets<R, D>(
     rep_levels: R,
     def_levels: D,
     is_nullable: bool,
     max_rep: u32,
     max_def: u32,
+    values_length: usize,
     nested: &mut Vec<Box<dyn Nested>>,
 ) where
     R: Iterator<Item = u32>,
     D: Iterator<Item = u32>,
 {
-    let mut values_count = vec![0; nested.len()];
-    let mut prev_def: u32 = 0;
-    let mut is_first = true;
+    let levels = definition_levels(nested);
+    // the number of repeated levels (lists) above each level
+    let mut repeated = Vec::with_capacity(nested.len() + 1);
+    let mut rep = 0;
+    nested.iter().for_each(|nested| {
+        repeated.push(rep);
+        rep += nested.is_repeated() as u32;
+    });
+    debug_assert_eq!(max_rep, rep);
+    debug_assert_eq!(levels[nested.len()].1 + is_nullable as u32, max_def);
 
+    let mut values_length = values_length;
     rep_levels.zip(def_levels).for_each(|(rep, def)| {
-        let mut closures = max_rep - rep;
-        if prev_def <= 1 {
-            closures = 1;
-        };
-        if is_first {
-            // close on first run to ensure offsets start with 0.
-            closures = max_rep;
-            is_first = false;
+        // a repetition level `rep` starts a new slot on every level below the `rep`th list
+        for depth in 0..nested.len() {
+            if repeated[depth] < rep {
+                continue;
+            }
+            let (exists_from, defined_from) = levels[depth];
+            if def < exists_from {
+                break;
+            }
+            // the new slot starts at the current length of its child
+            let length = if depth + 1 < nested.len() {
+                nested[depth + 1].num_values()
+            } else {
+                values_length
+            };
+            let is_valid = !nested[depth].is_nullable() || def > defined_from;
+            nested[depth].push(length as i64, is_valid);
+        }
+        if def >= levels[nested.len()].0 {
+            values_length += 1;
         }
-
-        nested
-            .iter_mut()
-            .zip(values_count.iter())
-            .enumerate()
-            .skip(rep as usize)
-            .take((rep + closures) as usize)
-            .for_each(|(depth, (nested, length))| {
-                let is_null = (def - rep) as usize == depth && depth == rep as usize;
-                nested.push(*length, !is_null);
-            });
-
-        values_count
-            .iter_mut()
-            .enumerate()
-            .for_each(|(depth, values)| {
-                if depth == 1 {
-                    if def == max_def || (is_nullable && def == max_def - 1) {
-                        *values += 1
-                    }
-                } else if depth == 0 {
-                    let a = nested
-                        .get(depth + 1)
-                        .map(|x| x.is_nullable())
-                        .unwrap_or_default(); // todo: cumsum this
-                    let condition = rep == 1
-                        || rep == 0
-                            && def >= max_def.saturating_sub((a as u32) + (is_nullable as u32));
-
-                    if condition {
-                        *values += 1;
-                    }
-                }
-            });
-        prev_def = def;
     });
-
-    // close validities
-    nested
-        .iter_mut()
-        .zip(values_count.iter())
-        .for_each(|(nested, length)| {
-            nested.close(*length);
-        });
 }
 
-pub fn init_nested(field: &Field, capacity: usize, container: &mut Vec<Box<dyn Nested>>) {
+/// Returns the [`Nested`] of every level of each leaf (parquet column) of `field`, from the
+/// outermost level to the leaf itself.
+pub fn init_nested(field: &Field, capacity: usize) -> Vec<Vec<Box<dyn Nested>>> {
     let is_nullable = field.is_nullable;
 
     use crate::datatypes::PhysicalType::*;
     match field.data_type().to_physical_type() {
         Null | Boolean | Primitive(_) | FixedSizeBinary | Binary | LargeBinary | Utf8
         | LargeUtf8 | Dictionary(_) => {
-            container.push(Box::new(NestedPrimitive::new(is_nullable)) as Box<dyn Nested>)
+            vec![vec![
+                Box::new(NestedPrimitive::new(is_nullable)) as Box<dyn Nested>
+            ]]
         }
-        List | LargeList | FixedSizeList => {
-            if is_nullable {
-                container.push(Box::new(NestedOptional::with_capacity(capacity)) as Box<dyn Nested>)
-            } else {
-                container.push(Box::new(NestedValid::with_capacity(capacity)) as Box<dyn Nested>)
-            }
-            match field.data_type().to_logical_type() {
+        List | LargeList | FixedSizeList | Map => {
+            // a map is a list of (required) entries
+            let inner = match field.data_type().to_logical_type() {
                 DataType::List(ref inner)
                 | DataType::LargeList(ref inner)
-                | DataType::FixedSizeList(ref inner, _) => {
-                    init_nested(inner.as_ref(), capacity, container)
-                }
+                | DataType::FixedSizeList(ref inner, _)
+                | DataType::Map(ref inner, _) => inner.as_ref(),
                 _ => unreachable!(),
             };
+            init_nested(inner, capacity)
+                .into_iter()
+                .map(|mut leaf| {
+                    let nested = if is_nullable {
+                        Box::new(NestedOptional::with_capacity(capacity)) as Box<dyn Nested>
+                    } else {
+                        Box::new(NestedValid::with_capacity(capacity)) as Box<dyn Nested>
+                    };
+                    leaf.insert(0, nested);
+                    leaf
+                })
+                .collect()
         }
         Struct => {
-            container.push(Box::new(NestedPrimitive::new(is_nullable)) as Box<dyn Nested>);
             if let DataType::Struct(fields) = field.data_type().to_logical_type() {
                 fields
                     .iter()
-                    .for_each(|field| init_nested(field, capacity, container));
+                    .flat_map(|field| init_nested(field, capacity))
+                    .map(|mut leaf| {
+                        let nested = NestedStruct::with_capacity(is_nullable, capacity);
+                        leaf.insert(0, Box::new(nested) as Box<dyn Nested>);
+                        leaf
+                    })
+                    .collect()
             } else {
                 unreachable!()
             }
@@ -252,25 +343,20 @@ pub fn init_nested(field: &Field, capacity: usize, container: &mut Vec<Box<dyn N
 
 pub fn create_list(
     data_type: DataType,
-    nested: &mut Vec<Box<dyn Nested>>,
+    offsets: Buffer<i64>,
+    validity: Option<Bitmap>,
     values: Arc<dyn Array>,
 ) -> Result<Box<dyn Array>> {
     Ok(match data_type {
         DataType::List(_) => {
-            let (offsets, validity) = nested.pop().unwrap().inner();
-
             let offsets = Buffer::<i32>::from_trusted_len_iter(offsets.iter().map(|x| *x as i32));
             Box::new(ListArray::<i32>::from_data(
                 data_type, offsets, values, validity,
             ))
         }
-        DataType::LargeList(_) => {
-            let (offsets, validity) = nested.pop().unwrap().inner();
-
-            Box::new(ListArray::<i64>::from_data(
-                data_type, offsets, values, validity,
-            ))
-        }
+        DataType::LargeList(_) => Box::new(ListArray::<i64>::from_data(
+            data_type, offsets, values, validity,
+        )),
         _ => {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Read nested datatype {:?}",
@@ -4,6 +4,7 @@ use parquet2::{
     types::NativeType,
 };
 
+use super::super::delta;
 use super::super::utils as other_utils;
 use super::utils::chunks;
 use super::ColumnDescriptor;
@@ -212,6 +213,23 @@ where
             op,
         ),
         (Encoding::Plain, _, false) => read_required(page.buffer(), additional, values, op),
+        (Encoding::DeltaBinaryPacked, _, true) => {
+            let values_buffer =
+                delta::binary_packed_to_plain(values_buffer, std::mem::size_of::<T>())?;
+            read_nullable(
+                validity_buffer,
+                &values_buffer,
+                additional,
+                values,
+                validity,
+                op,
+            )
+        }
+        (Encoding::DeltaBinaryPacked, _, false) => {
+            let values_buffer =
+                delta::binary_packed_to_plain(values_buffer, std::mem::size_of::<T>())?;
+            read_required(&values_buffer, additional, values, op)
+        }
         _ => {
             return Err(other_utils::not_implemented(
                 &page.encoding(),
@@ -33,8 +33,8 @@ fn read_dict_optional<K, T, A, F>(
     A: ArrowNativeType,
     F: Fn(T) -> A,
 {
-    let dict_values = dict.values();
-    values.extend(dict_values.iter().map(|x| op(*x)));
+    let length = indices.len() + additional;
+    extend_dict(dict, values, op);
 
     // SPEC: Data page format: the bit width used to encode the entry ids stored as 1 byte (max bit width = 32),
     // SPEC: followed by the values encoded using RLE/Bit packed described above (with the given bit width).
@@ -49,7 +49,7 @@ fn read_dict_optional<K, T, A, F>(
     for run in validity_iterator {
         match run {
             hybrid_rle::HybridEncoded::Bitpacked(packed) => {
-                let remaining = additional - indices.len();
+                let remaining = length - indices.len();
                 let len = std::cmp::min(packed.len() * 8, remaining);
                 for is_valid in BitmapIter::new(packed, 0, len) {
                     let value = if is_valid {
@@ -70,13 +70,51 @@ fn read_dict_optional<K, T, A, F>(
                         indices.push(index)
                     })
                 } else {
-                    values.resize(values.len() + additional, A::default());
+                    indices.resize(indices.len() + additional, K::default());
                 }
             }
         }
     }
 }
 
+/// Extends `values` by the values of `dict`, the dictionary of all pages of the column chunk,
+/// if they were not yet extended by a previous page.
+fn extend_dict<T, A, F>(dict: &PrimitivePageDict<T>, values: &mut Vec<A>, op: F)
+where
+    T: NativeType,
+    A: ArrowNativeType,
+    F: Fn(T) -> A,
+{
+    if values.is_empty() {
+        values.extend(dict.values().iter().map(|x| op(*x)));
+    }
+}
+
+fn read_dict_required<K, T, A, F>(
+    indices_buffer: &[u8],
+    additional: usize,
+    dict: &PrimitivePageDict<T>,
+    indices: &mut Vec<K>,
+    values: &mut Vec<A>,
+    validity: &mut MutableBitmap,
+    op: F,
+) where
+    K: DictionaryKey,
+    T: NativeType,
+    A: ArrowNativeType,
+    F: Fn(T) -> A,
+{
+    extend_dict(dict, values, op);
+
+    let bit_width = indices_buffer[0];
+    let indices_buffer = &indices_buffer[1..];
+
+    let new_indices =
+        hybrid_rle::HybridRleDecoder::new(indices_buffer, bit_width as u32, additional);
+    indices.extend(new_indices.map(|index| K::from_u32(index).unwrap()));
+    validity.extend_constant(additional, true);
+}
+
 fn extend_from_page<K, T, A, F>(
     page: &DataPage,
     descriptor: &ColumnDescriptor,
@@ -111,6 +149,17 @@ where
                 op,
             )
         }
+        (Encoding::PlainDictionary | Encoding::RleDictionary, Some(dict), false) => {
+            read_dict_required(
+                values_buffer,
+                additional,
+                dict.as_any().downcast_ref().unwrap(),
+                indices,
+                values,
+                validity,
+                op,
+            )
+        }
         _ => {
             return Err(utils::not_implemented(
                 &page.encoding(),
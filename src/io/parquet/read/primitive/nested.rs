@@ -1,11 +1,6 @@
-use parquet2::{
-    encoding::{hybrid_rle::HybridRleDecoder, Encoding},
-    page::DataPage,
-    read::levels::get_bit_width,
-    types::NativeType,
-};
+use parquet2::{encoding::Encoding, page::DataPage, types::NativeType};
 
-use super::super::nested_utils::extend_offsets;
+use super::super::nested_utils::{extend_offsets, leaf_definition_level, levels_iter};
 use super::ColumnDescriptor;
 use super::{super::utils, utils::chunks, Nested};
 use crate::{
@@ -16,6 +11,7 @@ use crate::{
 fn read_values<T, D, G, F, A>(
     def_levels: D,
     max_def: u32,
+    min_def: u32,
     mut new_values: G,
     op: F,
     values: &mut Vec<A>,
@@ -31,7 +27,8 @@ fn read_values<T, D, G, F, A>(
         if def == max_def {
             values.push(op(new_values.next().unwrap()));
             validity.push(true);
-        } else if def == max_def - 1 {
+        } else if def >= min_def {
+            // the leaf or one of its ancestor structs is null
             values.push(A::default());
             validity.push(false);
         }
@@ -73,21 +70,29 @@ fn read<T, A, F>(
 
     match (rep_level_encoding.0, def_level_encoding.0) {
         (Encoding::Rle, Encoding::Rle) => {
-            let rep_levels =
-                HybridRleDecoder::new(rep_levels, get_bit_width(rep_level_encoding.1), additional);
-            if is_nullable {
-                let def_levels = HybridRleDecoder::new(
+            // the offsets of the innermost list point to the values read so far
+            let values_length = values.len();
+
+            // slots defined below `max_def_level` are nulls from `min_def_level` onwards
+            let min_def_level = leaf_definition_level(nested);
+
+            let rep_levels = levels_iter(rep_levels, rep_level_encoding.1, additional);
+            if min_def_level < max_def_level {
+                let def_levels = levels_iter(def_levels, def_level_encoding.1, additional);
+                read_values(
                     def_levels,
-                    get_bit_width(def_level_encoding.1),
-                    additional,
-                );
-                read_values(def_levels, max_def_level, new_values, op, values, validity)
+                    max_def_level,
+                    min_def_level,
+                    new_values,
+                    op,
+                    values,
+                    validity,
+                )
             } else {
                 read_values_required(new_values, op, values)
             }
 
-            let def_levels =
-                HybridRleDecoder::new(def_levels, get_bit_width(def_level_encoding.1), additional);
+            let def_levels = levels_iter(def_levels, def_level_encoding.1, additional);
 
             extend_offsets(
                 rep_levels,
@@ -95,6 +100,7 @@ fn read<T, A, F>(
                 is_nullable,
                 max_rep_level,
                 max_def_level,
+                values_length,
                 nested,
             )
         }
@@ -0,0 +1,264 @@
+//! APIs to select the row groups to read based on their statistics.
+use crate::datatypes::{PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::scalar::{BinaryScalar, BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar};
+use crate::types::NativeType;
+
+use super::statistics::{
+    deserialize_statistics, BinaryStatistics, BooleanStatistics, PrimitiveStatistics, Statistics,
+    Utf8Statistics,
+};
+use super::{FileMetaData, RowGroupMetaData};
+
+/// The operator of a [`Predicate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    /// `column == value`
+    Eq,
+    /// `column != value`
+    NotEq,
+    /// `column < value`
+    Lt,
+    /// `column <= value`
+    LtEq,
+    /// `column > value`
+    Gt,
+    /// `column >= value`
+    GtEq,
+    /// `column IS NULL` (the value is ignored)
+    IsNull,
+    /// `column IS NOT NULL` (the value is ignored)
+    IsNotNull,
+}
+
+/// A predicate of the form `column op value`, used to prune row groups.
+#[derive(Debug)]
+pub struct Predicate {
+    /// The index of the column, on the projected schema
+    pub column: usize,
+    /// The operator
+    pub op: Operator,
+    /// The value to compare the column against
+    pub value: Box<dyn Scalar>,
+}
+
+impl Predicate {
+    /// Creates a new [`Predicate`].
+    pub fn new(column: usize, op: Operator, value: Box<dyn Scalar>) -> Self {
+        Self { column, op, value }
+    }
+}
+
+/// Returns whether a row group whose values are within `[min, max]` may contain
+/// values satisfying `op value`.
+fn may_match<T: PartialOrd + ?Sized>(
+    op: Operator,
+    min: Option<&T>,
+    max: Option<&T>,
+    value: &T,
+) -> bool {
+    match op {
+        Operator::Eq => {
+            !(min.map(|min| value < min).unwrap_or(false)
+                || max.map(|max| value > max).unwrap_or(false))
+        }
+        Operator::NotEq => {
+            !matches!((min, max), (Some(min), Some(max)) if min == value && max == value)
+        }
+        Operator::Lt => !min.map(|min| min >= value).unwrap_or(false),
+        Operator::LtEq => !min.map(|min| min > value).unwrap_or(false),
+        Operator::Gt => !max.map(|max| max <= value).unwrap_or(false),
+        Operator::GtEq => !max.map(|max| max < value).unwrap_or(false),
+        Operator::IsNull | Operator::IsNotNull => unreachable!(),
+    }
+}
+
+fn downcast<'a, S: 'static, V: 'static>(
+    statistics: &'a dyn Statistics,
+    value: &'a dyn Scalar,
+) -> Result<(&'a S, &'a V)> {
+    let statistics = statistics.as_any().downcast_ref::<S>();
+    let value = value.as_any().downcast_ref::<V>();
+    statistics.zip(value).ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "The scalar of a predicate must have the same type as its column".to_string(),
+        )
+    })
+}
+
+fn may_match_primitive<T: NativeType + PartialOrd>(
+    op: Operator,
+    statistics: &dyn Statistics,
+    value: &dyn Scalar,
+) -> Result<bool> {
+    let (statistics, value) =
+        downcast::<PrimitiveStatistics<T>, PrimitiveScalar<T>>(statistics, value)?;
+    Ok(may_match(
+        op,
+        statistics.min_value.as_ref(),
+        statistics.max_value.as_ref(),
+        &value.value().unwrap(),
+    ))
+}
+
+/// Returns whether the row group described by `statistics` may contain rows satisfying `op value`.
+fn may_match_statistics(
+    op: Operator,
+    statistics: &dyn Statistics,
+    value: &dyn Scalar,
+    num_rows: i64,
+) -> Result<bool> {
+    match op {
+        Operator::IsNull => return Ok(statistics.null_count() != Some(0)),
+        Operator::IsNotNull => return Ok(statistics.null_count() != Some(num_rows)),
+        _ => {}
+    }
+    if statistics.data_type().to_physical_type() != value.data_type().to_physical_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The scalar of a predicate ({:?}) must have the same type as its column ({:?})",
+            value.data_type(),
+            statistics.data_type()
+        )));
+    }
+    // comparing to a null is never true
+    if !value.is_valid() {
+        return Ok(false);
+    }
+
+    use PhysicalType::*;
+    match statistics.data_type().to_physical_type() {
+        Boolean => {
+            let (statistics, value) =
+                downcast::<BooleanStatistics, BooleanScalar>(statistics, value)?;
+            Ok(may_match(
+                op,
+                statistics.min_value.as_ref(),
+                statistics.max_value.as_ref(),
+                &value.value().unwrap(),
+            ))
+        }
+        Primitive(primitive) => match primitive {
+            PrimitiveType::Int8 => may_match_primitive::<i8>(op, statistics, value),
+            PrimitiveType::Int16 => may_match_primitive::<i16>(op, statistics, value),
+            PrimitiveType::Int32 => may_match_primitive::<i32>(op, statistics, value),
+            PrimitiveType::Int64 => may_match_primitive::<i64>(op, statistics, value),
+            PrimitiveType::Int128 => may_match_primitive::<i128>(op, statistics, value),
+            PrimitiveType::UInt8 => may_match_primitive::<u8>(op, statistics, value),
+            PrimitiveType::UInt16 => may_match_primitive::<u16>(op, statistics, value),
+            PrimitiveType::UInt32 => may_match_primitive::<u32>(op, statistics, value),
+            PrimitiveType::UInt64 => may_match_primitive::<u64>(op, statistics, value),
+            PrimitiveType::Float32 => may_match_primitive::<f32>(op, statistics, value),
+            PrimitiveType::Float64 => may_match_primitive::<f64>(op, statistics, value),
+            _ => Ok(true),
+        },
+        Utf8 => {
+            let (statistics, value) =
+                downcast::<Utf8Statistics, Utf8Scalar<i32>>(statistics, value)?;
+            Ok(may_match(
+                op,
+                statistics.min_value.as_deref(),
+                statistics.max_value.as_deref(),
+                value.value().unwrap(),
+            ))
+        }
+        Binary => {
+            let (statistics, value) =
+                downcast::<BinaryStatistics, BinaryScalar<i32>>(statistics, value)?;
+            Ok(may_match(
+                op,
+                statistics.min_value.as_deref(),
+                statistics.max_value.as_deref(),
+                value.value().unwrap(),
+            ))
+        }
+        // statistics of other types are not used for pruning
+        _ => Ok(true),
+    }
+}
+
+/// Returns the index of the (single) column chunk of the top-level parquet field `field`, or
+/// `None` if the field is nested (e.g. a struct or a list), whose statistics are not used for
+/// pruning.
+fn field_to_column(metadata: &FileMetaData, field: usize) -> Option<usize> {
+    let name = metadata.schema().fields()[field].get_basic_info().name();
+    metadata.schema().columns().iter().position(|column| {
+        let path = column.path_in_schema();
+        path.len() == 1 && path[0] == name
+    })
+}
+
+fn may_match_group(
+    metadata: &FileMetaData,
+    group: &RowGroupMetaData,
+    projection: Option<&[usize]>,
+    predicates: &[Predicate],
+) -> Result<bool> {
+    for predicate in predicates {
+        let field = match projection {
+            Some(projection) => projection.get(predicate.column).copied(),
+            None => Some(predicate.column),
+        }
+        .filter(|field| *field < metadata.schema().fields().len())
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The predicate's column {} does not exist",
+                predicate.column
+            ))
+        })?;
+        // the column chunks of a row group are the leaves of the fields, not the fields
+        let column = match field_to_column(metadata, field) {
+            Some(column) => column,
+            None => continue,
+        };
+        let statistics = group
+            .column(column)
+            .statistics()
+            .map(|x| deserialize_statistics(x?.as_ref()))
+            .transpose();
+        // row groups without (supported) statistics can't be pruned
+        let statistics = match statistics {
+            Ok(Some(statistics)) => statistics,
+            Ok(None) | Err(ArrowError::NotYetImplemented(_)) => continue,
+            Err(e) => return Err(e),
+        };
+        if !may_match_statistics(
+            predicate.op,
+            statistics.as_ref(),
+            predicate.value.as_ref(),
+            group.num_rows(),
+        )? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Returns the indices of the row groups of `metadata` that may contain rows satisfying
+/// all `predicates`, i.e. the row groups that can't be skipped.
+///
+/// Each [`Predicate::column`] refers to a column of the schema projected by `projection`
+/// (or of the file's schema when `projection` is `None`), which allows declaring predicates
+/// with the same indices used by e.g. [`RecordReader`](super::RecordReader).
+/// The statistics of each row group are deserialized via
+/// [`deserialize_statistics`]; row groups without statistics for a column are never pruned,
+/// and neither are predicates on nested fields (e.g. structs and lists) used.
+/// The returned indices are sorted and can be used in a groups filter.
+/// # Errors
+/// This function errors if a predicate's column is out of bounds or if the type of its
+/// scalar differs from the column's type.
+pub fn prune_row_groups(
+    metadata: &FileMetaData,
+    projection: Option<&[usize]>,
+    predicates: &[Predicate],
+) -> Result<Vec<usize>> {
+    metadata
+        .row_groups
+        .iter()
+        .enumerate()
+        .filter_map(|(i, group)| {
+            may_match_group(metadata, group, projection, predicates)
+                .map(|keep| if keep { Some(i) } else { None })
+                .transpose()
+        })
+        .collect()
+}
@@ -1,31 +1,62 @@
 use std::{
+    borrow::Cow,
+    collections::VecDeque,
     io::{Read, Seek},
     sync::Arc,
 };
 
+use parquet_format_async_temp::{Encoding as ThriftEncoding, PageType};
+
 use crate::{
-    array::Array,
+    array::{growable::make_growable, new_empty_array, Array, NullArray, PrimitiveArray},
+    bitmap::Bitmap,
     chunk::Chunk,
-    datatypes::{Field, Schema},
+    datatypes::{
+        DataType, Field, IntegerType, PhysicalType as ArrowPhysicalType, Schema, TimeUnit,
+    },
     error::{ArrowError, Result},
+    temporal_conversions::timestamp_to_time_unit,
 };
 
 use super::{
-    column_iter_to_array, get_column_iterator, get_schema, read_metadata, FileMetaData, PageFilter,
-    RowGroupMetaData,
+    column_iter_to_array, get_column_iterator, get_schema, read_metadata,
+    schema::{get_field_index, override_field, TypeOverride},
+    ColumnChunkMetaData, CompressedDataPage, FileMetaData, MutStreamingIterator, PageFilter,
+    ParquetError, ParquetType, PhysicalType, ReadColumnIterator, RowGroupMetaData, State,
 };
 
+#[cfg(feature = "io_parquet_encryption")]
+use crate::io::parquet::encryption::{DecryptedReader, DecryptionOptions};
+
 type GroupFilter = Arc<dyn Fn(usize, &RowGroupMetaData) -> bool>;
 
+/// A task that decompresses and deserializes the (in-memory) column chunks of a field of a
+/// row group into an [`Array`].
+pub type DecodeTask = Box<dyn FnOnce() -> Result<Box<dyn Array>> + Send>;
+
+/// A function that runs [`DecodeTask`]s (e.g. in parallel) and returns their results, in the
+/// same order as the tasks.
+pub type Decoder = Arc<dyn Fn(Vec<DecodeTask>) -> Vec<Result<Box<dyn Array>>>>;
+
+/// A function that returns which rows of a row group are selected (set bits) from its
+/// predicate columns, in the order they were declared in [`RecordReader::set_row_filter`].
+pub type RowFilter = Arc<dyn Fn(&Chunk<Arc<dyn Array>>) -> Result<Bitmap>>;
+
 /// Single threaded iterator of a paquet file.
 pub struct RecordReader<R: Read + Seek> {
     reader: R,
     schema: Arc<Schema>,
     indices: Vec<usize>,
+    file_fields: Vec<Field>,
     buffer: Vec<u8>,
     decompress_buffer: Vec<u8>,
     groups_filter: Option<GroupFilter>,
     pages_filter: Option<PageFilter>,
+    decoder: Option<Decoder>,
+    chunk_size: Option<usize>,
+    row_filter: Option<(Vec<usize>, RowFilter)>,
+    batches: Vec<ColumnBatches>,
+    group_remaining_rows: usize,
     metadata: FileMetaData,
     current_group: usize,
     remaining_rows: usize,
@@ -44,6 +75,7 @@ impl<R: Read + Seek> RecordReader<R> {
         let metadata = read_metadata(&mut reader)?;
 
         let schema = get_schema(&metadata)?;
+        let file_fields = schema.fields.clone();
 
         let schema_metadata = schema.metadata;
         let (indices, fields): (Vec<usize>, Vec<Field>) = if let Some(projection) = &projection {
@@ -72,6 +104,18 @@ impl<R: Read + Seek> RecordReader<R> {
             }
         }
 
+        // the parquet field of each field, which may differ from its position in the schema
+        let indices = indices
+            .into_iter()
+            .zip(fields.iter())
+            .map(|(position, field)| get_field_index(field, position, metadata.schema(), false))
+            .collect::<Result<Vec<_>>>()?;
+
+        let fields = fields
+            .into_iter()
+            .map(|field| to_large_field(field, &metadata))
+            .collect();
+
         let schema = Arc::new(Schema {
             fields,
             metadata: schema_metadata,
@@ -81,8 +125,14 @@ impl<R: Read + Seek> RecordReader<R> {
             reader,
             schema,
             indices,
+            file_fields,
             groups_filter,
             pages_filter,
+            decoder: None,
+            chunk_size: None,
+            row_filter: None,
+            batches: vec![],
+            group_remaining_rows: 0,
             metadata,
             current_group: 0,
             buffer: vec![],
@@ -105,12 +155,819 @@ impl<R: Read + Seek> RecordReader<R> {
     pub fn set_groups_filter(&mut self, groups_filter: GroupFilter) {
         self.groups_filter = Some(groups_filter);
     }
+
+    /// Sets the [`Decoder`] of this reader, which decodes the columns of each row group
+    /// together, instead of one after the other.
+    ///
+    /// The column chunks of each row group are read to memory, and each (projected) field is
+    /// decompressed and deserialized by a [`DecodeTask`], so that a [`Decoder`] can run them in
+    /// parallel, e.g. with `rayon`:
+    /// ```ignore
+    /// reader.set_decoder(Arc::new(|tasks| tasks.into_par_iter().map(|task| task()).collect()));
+    /// ```
+    pub fn set_decoder(&mut self, decoder: Decoder) {
+        self.decoder = Some(decoder);
+    }
+
+    /// Sets the maximum number of rows of the chunks read, so that each row group is read in
+    /// chunks of at most `chunk_size` rows instead of a single chunk.
+    ///
+    /// The pages of each row group are read to memory, but only the pages of the rows of a
+    /// chunk are decompressed and deserialized, so that the memory used is bounded by
+    /// `chunk_size` (and the size of the pages) rather than the size of the row groups, and
+    /// reading stops early when the `limit` of the reader is reached.
+    /// Chunks are decoded sequentially, i.e. the [`Decoder`] of this reader is not used.
+    /// # Panics
+    /// This function panics iff `chunk_size` is zero.
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        assert!(chunk_size > 0, "The chunk size must be positive");
+        self.chunk_size = Some(chunk_size);
+    }
+
+    /// Sets a [`RowFilter`] of this reader, so that only the rows it selects are read.
+    ///
+    /// Rows are read in two phases: the predicate `columns` (indices of fields of the
+    /// [`Schema`] of this reader) of each row group are decoded and passed to `filter`, which
+    /// returns the selected rows of the row group. Only the pages of the remaining columns with
+    /// selected rows are then decompressed and deserialized, which greatly reduces the work
+    /// of selective filters. Row groups without selected rows are skipped.
+    ///
+    /// Each row group is read as a single chunk with the selected rows of all fields, i.e.
+    /// the [`Decoder`] and chunk size of this reader are not used.
+    /// # Errors
+    /// This function errors iff a predicate column does not exist. Reading errors iff `filter`
+    /// errors or does not return one bit per row of the row group.
+    pub fn set_row_filter(&mut self, columns: Vec<usize>, filter: RowFilter) -> Result<()> {
+        if let Some(column) = columns
+            .iter()
+            .find(|column| **column >= self.schema.fields.len())
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The predicate column {} does not exist in a schema with {} fields",
+                column,
+                self.schema.fields.len()
+            )));
+        }
+        self.row_filter = Some((columns, filter));
+        Ok(())
+    }
+
+    /// Sets the [`Schema`] of the chunks read, whose fields are matched by name to the fields
+    /// of the parquet file (see [`get_field_index`]).
+    /// Use it to read the fields of the file in a different order or with different (compatible)
+    /// data types. Timestamps are converted to the [`TimeUnit`] of their field.
+    /// # Errors
+    /// This function errors iff a field does not exist in the file or, when `is_strict`, is
+    /// not nullable but its parquet field is.
+    pub fn set_schema(&mut self, schema: Schema, is_strict: bool) -> Result<()> {
+        self.indices = schema
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(position, field)| {
+                get_field_index(field, position, self.metadata.schema(), is_strict)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.schema = Arc::new(schema);
+        Ok(())
+    }
+
+    /// Overrides the [`DataType`] of the (top-level) fields matched by `overrides`, e.g. to read
+    /// epochs stored as `INT64` as timestamps (see [`TypeOverride`]).
+    /// # Errors
+    /// This function errors iff an override cannot be read from the physical type of its column.
+    pub fn set_type_overrides(&mut self, overrides: &[TypeOverride]) -> Result<()> {
+        let parquet_fields = self.metadata.schema().fields();
+        let fields = self
+            .schema
+            .fields
+            .iter()
+            .zip(self.indices.iter())
+            .map(|(field, &index)| override_field(field, &parquet_fields[index], overrides))
+            .collect::<Result<Vec<_>>>()?;
+        self.schema = Arc::new(Schema {
+            fields,
+            metadata: self.schema.metadata.clone(),
+        });
+        Ok(())
+    }
+
+    /// Sets the [`TimeUnit`] of the timestamps read from (legacy) INT96 columns, such as the ones
+    /// written by Spark, which are read as nanoseconds by default.
+    /// Coarser units truncate the timestamps, but represent dates outside the range of
+    /// nanoseconds (years 1677-2262).
+    pub fn set_int96_time_unit(&mut self, time_unit: TimeUnit) {
+        let parquet_fields = self.metadata.schema().fields();
+        let fields = self
+            .schema
+            .fields
+            .iter()
+            .map(|field| {
+                let is_int96 = parquet_fields
+                    .iter()
+                    .any(|type_| type_.get_basic_info().name() == field.name && is_int96(type_));
+                let mut field = field.clone();
+                if let (true, DataType::Timestamp(_, tz)) = (is_int96, &field.data_type) {
+                    field.data_type = DataType::Timestamp(time_unit, tz.clone());
+                }
+                field
+            })
+            .collect();
+        self.schema = Arc::new(Schema {
+            fields,
+            metadata: self.schema.metadata.clone(),
+        });
+    }
+
+    /// Sets whether (top-level) fields whose column chunks are fully dictionary-encoded in every
+    /// row group are read as [`DataType::Dictionary`] with `i32` keys, instead of expanding the
+    /// dictionary to its values. This is much faster and uses much less memory for columns
+    /// with few distinct values, e.g. low-cardinality strings.
+    ///
+    /// Only utf8 and primitive (other than decimal and interval) columns are read as dictionaries.
+    pub fn set_read_dictionaries(&mut self, read_dictionaries: bool) {
+        let fields = self
+            .schema
+            .fields
+            .iter()
+            .zip(self.indices.iter())
+            .map(|(field, &field_index)| {
+                let file_field = &self.file_fields[field_index];
+                let mut field = field.clone();
+                match (&field.data_type, read_dictionaries) {
+                    (DataType::Dictionary(IntegerType::Int32, values, _), false)
+                        if !matches!(file_field.data_type, DataType::Dictionary(..)) =>
+                    {
+                        field.data_type = values.as_ref().clone();
+                    }
+                    (data_type, true)
+                        if can_read_as_dictionary(data_type)
+                            && is_dictionary_encoded(&self.metadata, field_index) =>
+                    {
+                        field.data_type = DataType::Dictionary(
+                            IntegerType::Int32,
+                            Box::new(data_type.clone()),
+                            false,
+                        );
+                    }
+                    _ => {}
+                };
+                field
+            })
+            .collect();
+        self.schema = Arc::new(Schema {
+            fields,
+            metadata: self.schema.metadata.clone(),
+        });
+    }
+
+    /// Sets the [`TimeUnit`] of all (top-level) timestamp fields, so that the chunks read have
+    /// a single unit regardless of the units of the file.
+    /// Timestamps are truncated when converted to a coarser unit, and reading errors with
+    /// [`ArrowError::Overflow`] when a timestamp does not fit in a finer unit.
+    pub fn set_timestamp_time_unit(&mut self, time_unit: TimeUnit) {
+        let fields = self
+            .schema
+            .fields
+            .iter()
+            .map(|field| {
+                let mut field = field.clone();
+                if let DataType::Timestamp(_, tz) = &field.data_type {
+                    field.data_type = DataType::Timestamp(time_unit, tz.clone());
+                }
+                field
+            })
+            .collect();
+        self.schema = Arc::new(Schema {
+            fields,
+            metadata: self.schema.metadata.clone(),
+        });
+    }
+}
+
+/// Returns `field` with a large (64 bit offsets) data type if it is a [`DataType::Utf8`] or
+/// [`DataType::Binary`] field with a column chunk whose size exceeds `i32::MAX` bytes, so that
+/// its values fit in the offsets of the arrays read.
+fn to_large_field(mut field: Field, metadata: &FileMetaData) -> Field {
+    let large = match field.data_type {
+        DataType::Utf8 => DataType::LargeUtf8,
+        DataType::Binary => DataType::LargeBinary,
+        _ => return field,
+    };
+    let column = metadata.schema().columns().iter().position(|column| {
+        let path = column.path_in_schema();
+        path.len() == 1 && path[0] == field.name
+    });
+    if let Some(column) = column {
+        let is_large = metadata
+            .row_groups
+            .iter()
+            .any(|group| group.column(column).uncompressed_size() > i32::MAX as i64);
+        if is_large {
+            field.data_type = large;
+        }
+    }
+    field
+}
+
+/// Whether arrays of `data_type` can be read from dictionary-encoded column chunks as
+/// [`DataType::Dictionary`].
+fn can_read_as_dictionary(data_type: &DataType) -> bool {
+    use crate::types::PrimitiveType::*;
+    match data_type.to_physical_type() {
+        ArrowPhysicalType::Utf8 | ArrowPhysicalType::LargeUtf8 => true,
+        ArrowPhysicalType::Primitive(primitive) => {
+            !matches!(primitive, Int128 | DaysMs | MonthDayNano)
+        }
+        _ => false,
+    }
+}
+
+/// Whether every data page of the (single) column of the parquet field `field_index` is
+/// dictionary-encoded, in every row group.
+fn is_dictionary_encoded(metadata: &FileMetaData, field_index: usize) -> bool {
+    let name = metadata.schema().fields()[field_index]
+        .get_basic_info()
+        .name();
+    let column = metadata.schema().columns().iter().position(|column| {
+        let path = column.path_in_schema();
+        path.len() == 1 && path[0] == name
+    });
+    let column = if let Some(column) = column {
+        column
+    } else {
+        return false;
+    };
+    !metadata.row_groups.is_empty()
+        && metadata.row_groups.iter().all(|group| {
+            let meta = if let Some(meta) = group.column(column).column_chunk().meta_data.as_ref() {
+                meta
+            } else {
+                return false;
+            };
+            let has_dictionary = meta.encodings.iter().any(|encoding| {
+                matches!(
+                    encoding,
+                    ThriftEncoding::PlainDictionary | ThriftEncoding::RleDictionary
+                )
+            });
+            if !has_dictionary {
+                return false;
+            }
+            if let Some(stats) = meta.encoding_stats.as_ref() {
+                stats
+                    .iter()
+                    .filter(|stats| {
+                        matches!(stats.page_type, PageType::DataPage | PageType::DataPageV2)
+                    })
+                    .all(|stats| {
+                        stats.count == 0
+                            || matches!(
+                                stats.encoding,
+                                ThriftEncoding::PlainDictionary | ThriftEncoding::RleDictionary
+                            )
+                    })
+            } else {
+                // without statistics, the encodings of the column chunk are used. Dictionary
+                // pages are encoded with `PlainDictionary` (v1) or `Plain` (v2), so `Plain`
+                // is only a value encoding when the dictionary pages are `PlainDictionary`.
+                let is_v1 = meta.encodings.contains(&ThriftEncoding::PlainDictionary);
+                meta.encodings.iter().all(|encoding| match encoding {
+                    ThriftEncoding::Plain => !is_v1,
+                    ThriftEncoding::PlainDictionary
+                    | ThriftEncoding::RleDictionary
+                    | ThriftEncoding::Rle
+                    | ThriftEncoding::BitPacked => true,
+                    _ => false,
+                })
+            }
+        })
+}
+
+fn is_int96(type_: &ParquetType) -> bool {
+    matches!(
+        type_,
+        ParquetType::PrimitiveType {
+            physical_type: PhysicalType::Int96,
+            ..
+        }
+    )
+}
+
+/// Returns the field to read a column of `file_field` into `field`, and the [`TimeUnit`] that
+/// the array read must be converted to, if any.
+/// Timestamps (other than INT96, which are read in any unit) are read in the unit of the file.
+fn read_field<'a>(
+    field: &'a Field,
+    file_field: &Field,
+    type_: &ParquetType,
+) -> (Cow<'a, Field>, Option<TimeUnit>) {
+    match (&field.data_type, &file_field.data_type) {
+        (DataType::Timestamp(unit, tz), DataType::Timestamp(file_unit, _))
+            if unit != file_unit && !is_int96(type_) =>
+        {
+            let mut read_field = field.clone();
+            read_field.data_type = DataType::Timestamp(*file_unit, tz.clone());
+            (Cow::Owned(read_field), Some(*unit))
+        }
+        _ => (Cow::Borrowed(field), None),
+    }
+}
+
+/// Converts the timestamps of `array` to `time_unit`, if any.
+fn to_time_unit(array: Box<dyn Array>, time_unit: Option<TimeUnit>) -> Result<Box<dyn Array>> {
+    if let Some(time_unit) = time_unit {
+        let array = array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()
+            .unwrap();
+        Ok(Box::new(timestamp_to_time_unit(array, time_unit)?))
+    } else {
+        Ok(array)
+    }
+}
+
+type Pages = Vec<std::result::Result<CompressedDataPage, ParquetError>>;
+
+type ColumnChunks = Vec<(Pages, ColumnChunkMetaData)>;
+
+/// Reads the (compressed) pages of the column chunks of the field `field_index` of `row_group`
+/// to memory.
+fn read_column_chunks<R: Read + Seek>(
+    reader: &mut R,
+    metadata: &FileMetaData,
+    row_group: usize,
+    field_index: usize,
+    pages_filter: Option<PageFilter>,
+    buffer: Vec<u8>,
+) -> Result<(ColumnChunks, Vec<u8>)> {
+    let mut columns = get_column_iterator(
+        reader,
+        metadata,
+        row_group,
+        field_index,
+        pages_filter,
+        buffer,
+    );
+    let mut column_chunks = vec![];
+    loop {
+        match columns.advance()? {
+            State::Some(mut new_iter) => {
+                if let Some((pages, metadata)) = new_iter.get() {
+                    let pages = pages.collect::<Vec<_>>();
+                    column_chunks.push((pages, metadata.clone()));
+                }
+                columns = new_iter;
+            }
+            State::Finished(buffer) => return Ok((column_chunks, buffer)),
+        }
+    }
+}
+
+/// The (compressed) pages of the column chunks of a field of a row group, read to memory.
+struct ColumnPages {
+    parquet_field: ParquetType,
+    field: Field,
+    time_unit: Option<TimeUnit>,
+    column_chunks: ColumnChunks,
+}
+
+impl ColumnPages {
+    /// Decompresses and deserializes all pages into an [`Array`].
+    fn decode(self) -> Result<Box<dyn Array>> {
+        let columns = ReadColumnIterator::new(self.parquet_field, self.column_chunks);
+        let (array, _, _) = column_iter_to_array(columns, &self.field, vec![])?;
+        to_time_unit(array, self.time_unit)
+    }
+
+    /// Decompresses and deserializes the rows of `selection` into an [`Array`].
+    ///
+    /// The pages of flat (non-nested) fields without selected rows are neither decompressed
+    /// nor deserialized.
+    fn decode_selected(self, selection: &Bitmap) -> Result<Box<dyn Array>> {
+        let is_flat = matches!(self.parquet_field, ParquetType::PrimitiveType { .. })
+            && self.column_chunks.len() == 1
+            && self.field.data_type != DataType::Null;
+        if !is_flat {
+            return Ok(filter_rows(self.decode()?.as_ref(), selection));
+        }
+        let ColumnPages {
+            parquet_field,
+            field,
+            time_unit,
+            column_chunks,
+        } = self;
+        let (pages, metadata) = column_chunks.into_iter().next().unwrap();
+
+        // decodes consecutive pages with selected rows, starting at row `offset`
+        let decode = |pages: Pages, offset: usize| -> Result<Box<dyn Array>> {
+            let array = ColumnPages {
+                parquet_field: parquet_field.clone(),
+                field: field.clone(),
+                time_unit,
+                column_chunks: vec![(pages, metadata.clone())],
+            }
+            .decode()?;
+            if offset + array.len() > selection.len() {
+                return Err(ArrowError::OutOfSpec(
+                    "The column has more rows than the row group".to_string(),
+                ));
+            }
+            Ok(filter_rows(
+                array.as_ref(),
+                &selection.clone().slice(offset, array.len()),
+            ))
+        };
+
+        let mut arrays = vec![];
+        let mut run = vec![];
+        let mut run_offset = 0;
+        let mut offset = 0;
+        for page in pages {
+            let page = page?;
+            let num_values = page.num_values();
+            let length = num_values.min(selection.len().saturating_sub(offset));
+            let is_selected = selection.null_count_range(offset, length) < length;
+            if is_selected {
+                if run.is_empty() {
+                    run_offset = offset;
+                }
+                run.push(Ok(page));
+            } else if !run.is_empty() {
+                arrays.push(decode(std::mem::take(&mut run), run_offset)?);
+            }
+            offset += num_values;
+        }
+        if !run.is_empty() {
+            arrays.push(decode(run, run_offset)?);
+        }
+
+        Ok(concatenate(arrays, &field.data_type))
+    }
+}
+
+/// Returns the rows of `array` whose bit in `selection` is set, extending consecutive rows
+/// at once.
+fn filter_rows(array: &dyn Array, selection: &Bitmap) -> Box<dyn Array> {
+    let length = selection.len() - selection.null_count();
+    if length == array.len() {
+        return array.slice(0, length);
+    }
+    let mut growable = make_growable(&[array], false, length);
+    let mut start = None;
+    for (index, is_selected) in selection.iter().enumerate() {
+        match (is_selected, start) {
+            (true, None) => start = Some(index),
+            (false, Some(run)) => {
+                growable.extend(0, run, index - run);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(run) = start {
+        growable.extend(0, run, selection.len() - run);
+    }
+    growable.as_box()
+}
+
+/// Returns `arrays` of `data_type` concatenated into a single [`Array`].
+fn concatenate(mut arrays: Vec<Box<dyn Array>>, data_type: &DataType) -> Box<dyn Array> {
+    match arrays.len() {
+        0 => new_empty_array(data_type.clone()),
+        1 => arrays.pop().unwrap(),
+        _ => {
+            let length = arrays.iter().map(|array| array.len()).sum();
+            let arrays = arrays
+                .iter()
+                .map(|array| array.as_ref())
+                .collect::<Vec<_>>();
+            let mut growable = make_growable(&arrays, true, length);
+            arrays
+                .iter()
+                .enumerate()
+                .for_each(|(index, array)| growable.extend(index, 0, array.len()));
+            growable.as_box()
+        }
+    }
+}
+
+/// The state of a field of a row group read in batches of rows.
+///
+/// The pages of flat (non-nested) fields are decoded a few at a time, so that only the pages
+/// of the rows of a batch are decoded. Nested fields are decoded at once, since their records
+/// may span multiple pages.
+struct ColumnBatches {
+    parquet_field: ParquetType,
+    field: Field,
+    time_unit: Option<TimeUnit>,
+    metadata: Option<ColumnChunkMetaData>,
+    /// The pages not yet decoded of a flat field
+    pages: VecDeque<std::result::Result<CompressedDataPage, ParquetError>>,
+    /// The pages of a nested field, until they are decoded
+    nested: Option<ColumnPages>,
+    /// The rows decoded but not yet returned
+    remainder: Option<Box<dyn Array>>,
+}
+
+impl ColumnBatches {
+    fn new(column: ColumnPages) -> Self {
+        let is_flat = matches!(column.parquet_field, ParquetType::PrimitiveType { .. })
+            && column.column_chunks.len() == 1;
+        if is_flat {
+            let (pages, metadata) = column.column_chunks.into_iter().next().unwrap();
+            Self {
+                parquet_field: column.parquet_field,
+                field: column.field,
+                time_unit: column.time_unit,
+                metadata: Some(metadata),
+                pages: pages.into(),
+                nested: None,
+                remainder: None,
+            }
+        } else {
+            Self {
+                parquet_field: column.parquet_field.clone(),
+                field: column.field.clone(),
+                time_unit: column.time_unit,
+                metadata: None,
+                pages: VecDeque::new(),
+                nested: Some(column),
+                remainder: None,
+            }
+        }
+    }
+
+    /// Returns the next `length` rows of this field.
+    fn next(&mut self, length: usize) -> Result<Box<dyn Array>> {
+        if self.field.data_type == DataType::Null {
+            return Ok(Box::new(NullArray::from_data(DataType::Null, length)));
+        }
+        let mut arrays = self.remainder.take().into_iter().collect::<Vec<_>>();
+        if let Some(column) = self.nested.take() {
+            arrays.push(column.decode()?);
+        }
+        let mut available = arrays.iter().map(|array| array.len()).sum::<usize>();
+
+        while available < length && !self.pages.is_empty() {
+            // the pages of (at least) the missing rows
+            let mut pages = vec![];
+            let mut num_values = 0;
+            while num_values < length - available {
+                if let Some(page) = self.pages.pop_front() {
+                    if let Ok(page) = &page {
+                        num_values += page.num_values();
+                    }
+                    pages.push(page);
+                } else {
+                    break;
+                }
+            }
+            let column_chunks = vec![(pages, self.metadata.clone().unwrap())];
+            let array = ColumnPages {
+                parquet_field: self.parquet_field.clone(),
+                field: self.field.clone(),
+                time_unit: self.time_unit,
+                column_chunks,
+            }
+            .decode()?;
+            available += array.len();
+            arrays.push(array);
+        }
+
+        let array = concatenate(arrays, &self.field.data_type);
+        if array.len() > length {
+            self.remainder = Some(array.slice(length, array.len() - length));
+            Ok(array.slice(0, length))
+        } else {
+            Ok(array)
+        }
+    }
+}
+
+impl<R: Read + Seek> RecordReader<R> {
+    /// Reads the pages of the (projected) field `index` of `row_group` to memory.
+    fn read_column(&mut self, row_group: usize, index: usize) -> Result<ColumnPages> {
+        let field_index = self.indices[index];
+        let (column_chunks, buffer) = read_column_chunks(
+            &mut self.reader,
+            &self.metadata,
+            row_group,
+            field_index,
+            self.pages_filter.clone(),
+            std::mem::take(&mut self.buffer),
+        )?;
+        self.buffer = buffer;
+
+        let parquet_field = self.metadata.schema().fields()[field_index].clone();
+        let (field, time_unit) = read_field(
+            &self.schema.fields[index],
+            &self.file_fields[field_index],
+            &parquet_field,
+        );
+        Ok(ColumnPages {
+            field: field.into_owned(),
+            parquet_field,
+            time_unit,
+            column_chunks,
+        })
+    }
+
+    /// Reads the pages of the (projected) fields of `row_group` to memory.
+    fn read_row_group(&mut self, row_group: usize) -> Result<Vec<ColumnPages>> {
+        (0..self.schema.fields.len())
+            .map(|index| self.read_column(row_group, index))
+            .collect()
+    }
+
+    /// Reads the selected rows of `row_group`, returning `None` if no row was selected.
+    /// The predicate `columns` are read and decoded first, and the remaining columns are only
+    /// read if `filter` selects some rows.
+    fn read_filtered_row_group(
+        &mut self,
+        row_group: usize,
+        columns: &[usize],
+        filter: &RowFilter,
+    ) -> Result<Option<Chunk<Arc<dyn Array>>>> {
+        let num_rows = self.metadata.row_groups[row_group].num_rows() as usize;
+
+        // the decoded predicate columns
+        let mut arrays = (0..self.schema.fields.len())
+            .map(|_| None)
+            .collect::<Vec<Option<Box<dyn Array>>>>();
+        let predicates = columns
+            .iter()
+            .map(|&index| {
+                let array = match arrays[index].take() {
+                    Some(array) => array,
+                    None => self.read_column(row_group, index)?.decode()?,
+                };
+                let predicate = Arc::from(array.slice(0, array.len()));
+                arrays[index] = Some(array);
+                Ok(predicate)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let selection = (filter)(&Chunk::new(predicates))?;
+        if selection.len() != num_rows {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The row filter must select one bit per row of the row group ({}), but returned {}",
+                num_rows,
+                selection.len()
+            )));
+        }
+        if selection.null_count() == selection.len() {
+            return Ok(None);
+        }
+
+        let columns = arrays
+            .into_iter()
+            .enumerate()
+            .map(|(index, array)| {
+                let array = match array {
+                    Some(array) => filter_rows(array.as_ref(), &selection),
+                    None => self
+                        .read_column(row_group, index)?
+                        .decode_selected(&selection)?,
+                };
+                let array = if array.len() > self.remaining_rows {
+                    array.slice(0, self.remaining_rows)
+                } else {
+                    array
+                };
+                Ok(Arc::from(array))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.remaining_rows -= columns[0].len();
+        Ok(Some(Chunk::new(columns)))
+    }
+
+    /// Returns the selected rows of the next row group with selected rows.
+    fn next_filtered(
+        &mut self,
+        columns: &[usize],
+        filter: &RowFilter,
+    ) -> Option<Result<Chunk<Arc<dyn Array>>>> {
+        loop {
+            if self.schema.fields.is_empty()
+                || self.remaining_rows == 0
+                || self.current_group == self.metadata.row_groups.len()
+            {
+                return None;
+            }
+            let row_group = self.current_group;
+            self.current_group += 1;
+
+            if let Some(groups_filter) = self.groups_filter.as_ref() {
+                if !(groups_filter)(row_group, &self.metadata.row_groups[row_group]) {
+                    continue;
+                }
+            }
+            match self.read_filtered_row_group(row_group, columns, filter) {
+                Ok(Some(chunk)) => return Some(Ok(chunk)),
+                Ok(None) => continue,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    /// Reads the row group `row_group` by decoding its columns with `decoder`.
+    fn decode_row_group(
+        &mut self,
+        row_group: usize,
+        decoder: &Decoder,
+    ) -> Result<Vec<Box<dyn Array>>> {
+        let tasks = self
+            .read_row_group(row_group)?
+            .into_iter()
+            .map(|column| Box::new(move || column.decode()) as DecodeTask)
+            .collect::<Vec<_>>();
+
+        let num_tasks = tasks.len();
+        let arrays = (decoder)(tasks).into_iter().collect::<Result<Vec<_>>>()?;
+        if arrays.len() != num_tasks {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The decoder returned {} arrays for {} tasks",
+                arrays.len(),
+                num_tasks
+            )));
+        }
+        Ok(arrays)
+    }
+
+    /// Returns the next chunk of at most `chunk_size` rows, reading the next row group
+    /// if all rows of the current one were returned.
+    fn next_batch(&mut self, chunk_size: usize) -> Option<Result<Chunk<Arc<dyn Array>>>> {
+        if self.schema.fields.is_empty() || self.remaining_rows == 0 {
+            return None;
+        }
+        while self.group_remaining_rows == 0 {
+            if self.current_group == self.metadata.row_groups.len() {
+                return None;
+            }
+            let row_group = self.current_group;
+            self.current_group += 1;
+
+            let group = &self.metadata.row_groups[row_group];
+            let num_rows = group.num_rows() as usize;
+            if let Some(groups_filter) = self.groups_filter.as_ref() {
+                if !(groups_filter)(row_group, group) {
+                    continue;
+                }
+            }
+            match self.read_row_group(row_group) {
+                Ok(columns) => {
+                    self.batches = columns.into_iter().map(ColumnBatches::new).collect();
+                    self.group_remaining_rows = num_rows;
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        let length = chunk_size
+            .min(self.group_remaining_rows)
+            .min(self.remaining_rows);
+        self.group_remaining_rows -= length;
+        self.remaining_rows -= length;
+        let columns = self
+            .batches
+            .iter_mut()
+            .map(|column| column.next(length).map(Arc::from))
+            .collect::<Result<Vec<_>>>();
+        if self.group_remaining_rows == 0 {
+            self.batches.clear();
+        }
+        Some(columns.map(Chunk::new))
+    }
+}
+
+#[cfg(feature = "io_parquet_encryption")]
+impl<R: Read + Seek> RecordReader<DecryptedReader<R>> {
+    /// Creates a new [`RecordReader`] of an encrypted file, whose keys are retrieved by the
+    /// [`KeyRetriever`](crate::io::parquet::encryption::KeyRetriever) of `decryption`.
+    /// Files without encryption are read as is.
+    pub fn try_new_decrypted(
+        reader: R,
+        decryption: &DecryptionOptions,
+        projection: Option<Vec<usize>>,
+        limit: Option<usize>,
+        groups_filter: Option<GroupFilter>,
+        pages_filter: Option<PageFilter>,
+    ) -> Result<Self> {
+        let reader = DecryptedReader::try_new(reader, decryption)?;
+        Self::try_new(reader, projection, limit, groups_filter, pages_filter)
+    }
 }
 
 impl<R: Read + Seek> Iterator for RecordReader<R> {
     type Item = Result<Chunk<Arc<dyn Array>>>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some((columns, filter)) = self.row_filter.clone() {
+            return self.next_filtered(&columns, &filter);
+        }
+        if let Some(chunk_size) = self.chunk_size {
+            return self.next_batch(chunk_size);
+        }
         if self.schema.fields.is_empty() {
             return None;
         }
@@ -132,6 +989,25 @@ impl<R: Read + Seek> Iterator for RecordReader<R> {
             }
         }
 
+        if let Some(decoder) = self.decoder.clone() {
+            self.current_group += 1;
+            return Some(self.decode_row_group(row_group, &decoder).map(|arrays| {
+                let columns = arrays
+                    .into_iter()
+                    .map(|array| {
+                        let array = if array.len() > remaining_rows {
+                            array.slice(0, remaining_rows)
+                        } else {
+                            array
+                        };
+                        array.into()
+                    })
+                    .collect::<Vec<Arc<dyn Array>>>();
+                self.remaining_rows -= columns[0].len();
+                Chunk::new(columns)
+            }));
+        }
+
         // todo: avoid these clones.
         let schema = self.schema().clone();
 
@@ -151,7 +1027,13 @@ impl<R: Read + Seek> Iterator for RecordReader<R> {
                     b1,
                 );
 
-                let (array, b1, b2) = column_iter_to_array(column_iter, field, b2)?;
+                let (field, time_unit) = read_field(
+                    field,
+                    &self.file_fields[field_index],
+                    &self.metadata.schema().fields()[field_index],
+                );
+                let (array, b1, b2) = column_iter_to_array(column_iter, &field, b2)?;
+                let array = to_time_unit(array, time_unit)?;
 
                 let array = if array.len() > remaining_rows {
                     array.slice(0, remaining_rows)
@@ -269,6 +269,8 @@ fn to_group_type_inner(
     match (logical_type, converted_type) {
         (Some(LogicalType::LIST(_)), _) => to_list(fields, parent_name),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name),
+        (Some(LogicalType::MAP(_)), _) => to_map(fields),
+        (None, Some(GroupConvertedType::Map | GroupConvertedType::MapKeyValue)) => to_map(fields),
         _ => to_struct(fields),
     }
 }
@@ -289,6 +291,27 @@ fn to_struct(fields: &[ParquetType]) -> Result<Option<DataType>> {
         })
 }
 
+/// Converts a parquet map to an arrow map, whose entries are a struct of the key and the value.
+///
+/// See [parquet doc](https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#maps).
+fn to_map(fields: &[ParquetType]) -> Result<Option<DataType>> {
+    let entries = fields.first().ok_or_else(|| {
+        ArrowError::ExternalFormat("A parquet MAP must have a child group".to_string())
+    })?;
+    match entries {
+        ParquetType::GroupType { fields, .. }
+            if entries.get_basic_info().repetition() == &Repetition::Repeated =>
+        {
+            to_struct(fields).map(|opt| {
+                opt.map(|dt| DataType::Map(Box::new(Field::new(entries.name(), dt, false)), false))
+            })
+        }
+        _ => Err(ArrowError::ExternalFormat(
+            "The child of a parquet MAP must be a repeated group".to_string(),
+        )),
+    }
+}
+
 /// Entry point for converting parquet group type.
 ///
 /// This function takes care of logical type and repetition.
@@ -786,6 +809,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parquet_map() -> Result<()> {
+        let message_type = "
+        message test_schema {
+          OPTIONAL GROUP my_map (MAP) {
+            REPEATED GROUP key_value {
+              REQUIRED BINARY key (UTF8);
+              OPTIONAL INT32 value;
+            }
+          }
+        }
+        ";
+
+        let entries = DataType::Struct(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ]);
+        let arrow_fields = vec![Field::new(
+            "my_map",
+            DataType::Map(Box::new(Field::new("key_value", entries, false)), false),
+            true,
+        )];
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message_type)?;
+        let converted_arrow_schema = parquet_to_arrow_schema(&parquet_schema, &None)?;
+
+        assert_eq!(arrow_fields, converted_arrow_schema.fields);
+        Ok(())
+    }
+
     #[test]
     fn test_nested_schema() -> Result<()> {
         let mut arrow_fields = Vec::new();
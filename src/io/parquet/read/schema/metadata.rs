@@ -9,6 +9,9 @@ use crate::io::ipc::read::deserialize_schema;
 use super::super::super::ARROW_SCHEMA_META_KEY;
 
 /// Reads an arrow schema from Parquet's file metadata. Returns `None` if no schema was found.
+///
+/// The remaining key-value metadata of the file is added to the metadata of the schema,
+/// unless the schema already has the same key.
 /// # Errors
 /// Errors iff the schema cannot be correctly parsed.
 pub fn read_schema_from_metadata(
@@ -17,7 +20,14 @@ pub fn read_schema_from_metadata(
     let mut metadata = parse_key_value_metadata(key_value_metadata).unwrap_or_default();
     metadata
         .remove(ARROW_SCHEMA_META_KEY)
-        .map(|encoded| get_arrow_schema_from_metadata(&encoded))
+        .map(|encoded| {
+            get_arrow_schema_from_metadata(&encoded).map(|mut schema| {
+                for (key, value) in metadata {
+                    schema.metadata.entry(key).or_insert(value);
+                }
+                schema
+            })
+        })
         .transpose()
 }
 
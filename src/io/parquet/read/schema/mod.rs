@@ -1,6 +1,8 @@
 //! APIs to handle Parquet <-> Arrow schemas.
-use crate::datatypes::Schema;
-use crate::error::Result;
+use parquet2::schema::types::PhysicalType;
+
+use crate::datatypes::{DataType, Field, IntervalUnit, Schema};
+use crate::error::{ArrowError, Result};
 
 mod convert;
 mod metadata;
@@ -22,6 +24,216 @@ pub fn get_schema(metadata: &FileMetaData) -> Result<Schema> {
     })
 }
 
+/// An override of the [`DataType`] that (top-level) parquet columns are read as, instead of the
+/// one converted from their parquet types. E.g. epochs stored as plain `INT64` can be read as
+/// [`DataType::Timestamp`], and UUIDs stored as `BYTE_ARRAY` as [`DataType::Utf8`].
+///
+/// Values are read as stored: an `INT64` epoch in milliseconds is read as a timestamp in
+/// milliseconds. `INT96` columns are converted to the [`TimeUnit`](crate::datatypes::TimeUnit)
+/// of their timestamp.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeOverride {
+    /// Reads the column with this name as the [`DataType`].
+    Name(String, DataType),
+    /// Reads the columns whose names match this pattern, where `*` matches any sequence of
+    /// characters (e.g. `"*_at"`), as the [`DataType`].
+    Pattern(String, DataType),
+}
+
+impl TypeOverride {
+    /// Returns whether this override applies to the column `name`.
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Self::Name(expected, _) => expected == name,
+            Self::Pattern(pattern, _) => matches_pattern(pattern, name),
+        }
+    }
+
+    /// The [`DataType`] of the columns of this override.
+    pub fn data_type(&self) -> &DataType {
+        match self {
+            Self::Name(_, data_type) | Self::Pattern(_, data_type) => data_type,
+        }
+    }
+}
+
+/// Returns whether `name` matches `pattern`, where `*` matches any sequence of characters.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // the first part is a prefix, the last a suffix, and the ones in between appear in order
+    let mut rest = match name.strip_prefix(parts.next().unwrap_or_default()) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    let mut parts = parts.collect::<Vec<_>>();
+    let last = match parts.pop() {
+        Some(last) => last,
+        None => return rest.is_empty(),
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// Returns whether a column of `physical_type` can be read as `data_type`.
+fn can_read_as(physical_type: &PhysicalType, data_type: &DataType) -> bool {
+    use DataType::*;
+    match (physical_type, data_type.to_logical_type()) {
+        (_, Dictionary(_, values, _)) => can_read_as(physical_type, values),
+        (PhysicalType::Boolean, Boolean) => true,
+        (
+            PhysicalType::Int32,
+            Int8
+            | Int16
+            | Int32
+            | UInt8
+            | UInt16
+            | UInt32
+            | Date32
+            | Time32(_)
+            | Interval(IntervalUnit::YearMonth)
+            | Decimal(_, _),
+        ) => true,
+        (
+            PhysicalType::Int64,
+            Int64 | UInt64 | Date64 | Time64(_) | Duration(_) | Timestamp(_, _) | Decimal(_, _),
+        ) => true,
+        (PhysicalType::Int96, Timestamp(_, _)) => true,
+        (PhysicalType::Float, Float32) | (PhysicalType::Double, Float64) => true,
+        (PhysicalType::ByteArray, Binary | LargeBinary | Utf8 | LargeUtf8) => true,
+        (PhysicalType::FixedLenByteArray(size), FixedSizeBinary(expected)) => {
+            *size as usize == *expected
+        }
+        (PhysicalType::FixedLenByteArray(size), Decimal(_, _)) => *size <= 16,
+        _ => false,
+    }
+}
+
+/// Returns `field`, read from the parquet field `type_`, with the [`DataType`] of the first of
+/// `overrides` that matches its name, if any.
+pub(crate) fn override_field(
+    field: &Field,
+    type_: &ParquetType,
+    overrides: &[TypeOverride],
+) -> Result<Field> {
+    let data_type = match overrides.iter().find(|x| x.matches(&field.name)) {
+        Some(type_override) => type_override.data_type(),
+        None => return Ok(field.clone()),
+    };
+    match type_ {
+        ParquetType::PrimitiveType { physical_type, .. }
+            if can_read_as(physical_type, data_type) =>
+        {
+            let mut field = field.clone();
+            field.data_type = data_type.clone();
+            Ok(field)
+        }
+        ParquetType::PrimitiveType { physical_type, .. } => {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "The column \"{}\" of physical type {:?} cannot be read as {:?}",
+                field.name, physical_type, data_type
+            )))
+        }
+        ParquetType::GroupType { .. } => Err(ArrowError::InvalidArgumentError(format!(
+            "The type of the nested column \"{}\" cannot be overridden",
+            field.name
+        ))),
+    }
+}
+
+/// Returns `schema`, whose fields are read from `parquet_schema`, with the [`DataType`] of each
+/// (top-level) field replaced by the one of the first of `overrides` that matches its name.
+/// Fields are matched to the parquet fields as by [`get_field_index`].
+/// # Errors
+/// This function errors iff a field cannot be matched or an override cannot be read from the
+/// physical type of its column, e.g. a timestamp from a `BYTE_ARRAY`.
+pub fn apply_type_overrides(
+    schema: Schema,
+    parquet_schema: &SchemaDescriptor,
+    overrides: &[TypeOverride],
+) -> Result<Schema> {
+    let fields = schema
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(position, field)| {
+            let index = get_field_index(field, position, parquet_schema, false)?;
+            override_field(field, &parquet_schema.fields()[index], overrides)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Schema {
+        fields,
+        metadata: schema.metadata,
+    })
+}
+
+/// Parses parquet's metadata into a [`Schema`] as [`get_schema`], with the [`DataType`] of the
+/// (top-level) fields matched by `overrides` replaced.
+/// # Example
+/// ```no_run
+/// use arrow2::datatypes::{DataType, TimeUnit};
+/// use arrow2::io::parquet::read::{get_schema_with_overrides, read_metadata, TypeOverride};
+///
+/// let mut file = std::fs::File::open("events.parquet").unwrap();
+/// let metadata = read_metadata(&mut file).unwrap();
+/// let overrides = [
+///     TypeOverride::Pattern("*_at".to_string(), DataType::Timestamp(TimeUnit::Millisecond, None)),
+///     TypeOverride::Name("id".to_string(), DataType::Utf8),
+/// ];
+/// let schema = get_schema_with_overrides(&metadata, &overrides).unwrap();
+/// ```
+/// # Errors
+/// This function errors iff the schema cannot be parsed or an override cannot be read from the
+/// physical type of its column (see [`apply_type_overrides`]).
+pub fn get_schema_with_overrides(
+    metadata: &FileMetaData,
+    overrides: &[TypeOverride],
+) -> Result<Schema> {
+    apply_type_overrides(get_schema(metadata)?, metadata.schema(), overrides)
+}
+
+/// Returns the index of the (top-level) field of `parquet_schema` that `field`, at `position`
+/// of an arrow schema, is read from.
+///
+/// Fields are matched by name, so that the order of the fields of an arrow schema does not need
+/// to match the order of the fields of the parquet file. When `is_strict`, a non-nullable
+/// `field` must also be read from a non-nullable parquet field.
+/// # Errors
+/// This function errors iff no parquet field has the name of `field`, or `is_strict` and the
+/// nullability of the parquet field is not compatible with `field`.
+pub fn get_field_index(
+    field: &Field,
+    position: usize,
+    parquet_schema: &SchemaDescriptor,
+    is_strict: bool,
+) -> Result<usize> {
+    let parquet_fields = parquet_schema.fields();
+    let has_name = |type_: &ParquetType| type_.get_basic_info().name() == field.name;
+    // prefer the field at the same position, in case of repeated names
+    let index = parquet_fields
+        .get(position)
+        .filter(|type_| has_name(type_))
+        .map(|_| position)
+        .or_else(|| parquet_fields.iter().position(has_name))
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The field \"{}\" does not exist in the parquet file",
+                field.name
+            ))
+        })?;
+    if is_strict && !field.is_nullable && is_type_nullable(&parquet_fields[index]) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The field \"{}\" is not nullable but its parquet field is",
+            field.name
+        )));
+    }
+    Ok(index)
+}
+
 pub(crate) fn is_type_nullable(type_: &ParquetType) -> bool {
     is_nullable(type_.get_basic_info())
 }
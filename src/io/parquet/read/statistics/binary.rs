@@ -29,6 +29,10 @@ impl Statistics for BinaryStatistics {
     fn null_count(&self) -> Option<i64> {
         self.null_count
     }
+
+    fn distinct_count(&self) -> Option<i64> {
+        self.distinct_count
+    }
 }
 
 impl From<&ParquetByteArrayStatistics> for BinaryStatistics {
@@ -62,6 +66,10 @@ impl Statistics for Utf8Statistics {
     fn null_count(&self) -> Option<i64> {
         self.null_count
     }
+
+    fn distinct_count(&self) -> Option<i64> {
+        self.distinct_count
+    }
 }
 
 impl TryFrom<&ParquetByteArrayStatistics> for Utf8Statistics {
@@ -24,6 +24,10 @@ impl Statistics for BooleanStatistics {
     fn null_count(&self) -> Option<i64> {
         self.null_count
     }
+
+    fn distinct_count(&self) -> Option<i64> {
+        self.distinct_count
+    }
 }
 
 impl From<&ParquetBooleanStatistics> for BooleanStatistics {
@@ -1,7 +1,8 @@
 use std::any::Any;
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 
 use super::super::schema;
+use super::super::utils::i128_from_be_slice;
 use super::primitive::PrimitiveStatistics;
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
@@ -36,6 +37,10 @@ impl Statistics for FixedLenStatistics {
     fn null_count(&self) -> Option<i64> {
         self.null_count
     }
+
+    fn distinct_count(&self) -> Option<i64> {
+        self.distinct_count
+    }
 }
 
 impl From<&ParquetFixedLenStatistics> for FixedLenStatistics {
@@ -67,22 +72,8 @@ impl TryFrom<(&ParquetFixedLenStatistics, DataType)> for PrimitiveStatistics<i12
                 byte_lens
             )))
         } else {
-            let paddings = (0..(16 - byte_lens)).map(|_| 0u8).collect::<Vec<_>>();
-            let max_value = stats.max_value.as_ref().and_then(|value| {
-                [paddings.as_slice(), value]
-                    .concat()
-                    .try_into()
-                    .map(i128::from_be_bytes)
-                    .ok()
-            });
-
-            let min_value = stats.min_value.as_ref().and_then(|value| {
-                [paddings.as_slice(), value]
-                    .concat()
-                    .try_into()
-                    .map(i128::from_be_bytes)
-                    .ok()
-            });
+            let max_value = stats.max_value.as_deref().map(i128_from_be_slice);
+            let min_value = stats.min_value.as_deref().map(i128_from_be_slice);
             Ok(Self {
                 data_type,
                 null_count: stats.null_count,
@@ -104,6 +95,12 @@ pub(super) fn statistics_from_fix_len(
     Ok(match data_type {
         Decimal(_, _) => Box::new(PrimitiveStatistics::<i128>::try_from((stats, data_type))?),
         FixedSizeBinary(_) => Box::new(FixedLenStatistics::from(stats)),
+        // intervals have no sort order: their statistics only have a null count
+        Interval(_) => {
+            let mut statistics = FixedLenStatistics::from(stats);
+            statistics.data_type = data_type;
+            Box::new(statistics)
+        }
         other => {
             return Err(ArrowError::NotYetImplemented(format!(
                 "Can't read {:?} from parquet",
@@ -27,6 +27,9 @@ pub trait Statistics: std::fmt::Debug {
 
     /// Return the null count statistic
     fn null_count(&self) -> Option<i64>;
+
+    /// Return the distinct count statistic
+    fn distinct_count(&self) -> Option<i64>;
 }
 
 impl PartialEq for &dyn Statistics {
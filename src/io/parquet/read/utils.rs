@@ -32,6 +32,19 @@ impl<'a> Iterator for BinaryIter<'a> {
     }
 }
 
+/// Converts the big-endian two's complement representation of an integer of at most 16 bytes
+/// (e.g. a decimal stored as `FIXED_LEN_BYTE_ARRAY`) into an `i128`.
+#[inline]
+pub fn i128_from_be_slice(value: &[u8]) -> i128 {
+    let n = value.len();
+    debug_assert!(n <= 16);
+    // copy the value to the start of a 16 byte buffer and use an arithmetic right shift to
+    // fill in the most significant bytes, which accounts for the leading 1's of negative values.
+    let mut bytes = [0u8; 16];
+    bytes[..n].copy_from_slice(value);
+    i128::from_be_bytes(bytes) >> (8 * (16 - n))
+}
+
 pub fn not_implemented(
     encoding: &Encoding,
     is_optional: bool,
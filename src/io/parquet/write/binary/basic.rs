@@ -68,6 +68,13 @@ pub fn array_to_page<O: Offset>(
             is_optional,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => {
+            if is_optional {
+                encode_delta_byte_array(array.iter().flatten(), &mut buffer)
+            } else {
+                encode_delta_byte_array(array.values_iter(), &mut buffer)
+            }
+        }
         _ => {
             return Err(ArrowError::InvalidArgumentError(format!(
                 "Datatype {:?} cannot be encoded by {:?} encoding",
@@ -96,7 +103,7 @@ pub fn array_to_page<O: Offset>(
     )
 }
 
-pub(super) fn build_statistics<O: Offset>(
+pub(crate) fn build_statistics<O: Offset>(
     array: &BinaryArray<O>,
     descriptor: ColumnDescriptor,
 ) -> ParquetStatistics {
@@ -150,6 +157,32 @@ pub(crate) fn encode_delta<O: Offset>(
     )
 }
 
+/// Encodes `values` with `DELTA_BYTE_ARRAY`: the length of the prefix each value shares with
+/// the previous value, followed by the remaining suffixes encoded with `DELTA_LENGTH_BYTE_ARRAY`.
+pub(crate) fn encode_delta_byte_array<'a, I: Iterator<Item = &'a [u8]>>(
+    values: I,
+    buffer: &mut Vec<u8>,
+) {
+    let mut prefix_lengths = vec![];
+    let mut suffix_lengths = vec![];
+    let mut suffixes = vec![];
+    let mut previous: &[u8] = &[];
+    for value in values {
+        let prefix_length = previous
+            .iter()
+            .zip(value.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        prefix_lengths.push(prefix_length as i64);
+        suffix_lengths.push((value.len() - prefix_length) as i64);
+        suffixes.extend_from_slice(&value[prefix_length..]);
+        previous = value;
+    }
+    delta_bitpacked::encode(prefix_lengths.into_iter(), buffer);
+    delta_bitpacked::encode(suffix_lengths.into_iter(), buffer);
+    buffer.extend_from_slice(&suffixes);
+}
+
 /// Returns the ordering of two binary values. This corresponds to pyarrows' ordering
 /// of statistics.
 pub(crate) fn ord_binary<'a>(a: &'a [u8], b: &'a [u8]) -> std::cmp::Ordering {
@@ -10,14 +10,13 @@ use crate::{
     io::parquet::read::is_type_nullable,
 };
 
-pub fn array_to_page<O, OO>(
+pub fn array_to_page<O>(
     array: &BinaryArray<O>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
-    nested: levels::NestedInfo<OO>,
+    nested: levels::NestedInfo,
 ) -> Result<DataPage>
 where
-    OO: Offset,
     O: Offset,
 {
     let is_optional = is_type_nullable(descriptor.type_());
@@ -41,7 +40,7 @@ where
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
+        nested.num_values(),
         array.null_count(),
         repetition_levels_byte_length,
         definition_levels_byte_length,
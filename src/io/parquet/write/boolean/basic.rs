@@ -80,11 +80,18 @@ pub fn array_to_page(
 }
 
 pub(super) fn build_statistics(array: &BooleanArray) -> ParquetStatistics {
+    let max_value = array.iter().flatten().max();
+    let min_value = array.iter().flatten().min();
+    // a boolean column has at most two distinct values
+    let distinct_count = match (min_value, max_value) {
+        (Some(min), Some(max)) => 1 + (min != max) as i64,
+        _ => 0,
+    };
     let statistics = &BooleanStatistics {
         null_count: Some(array.null_count() as i64),
-        distinct_count: None,
-        max_value: array.iter().flatten().max(),
-        min_value: array.iter().flatten().min(),
+        distinct_count: Some(distinct_count),
+        max_value,
+        min_value,
     } as &dyn Statistics;
     serialize_statistics(statistics)
 }
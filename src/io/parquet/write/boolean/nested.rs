@@ -5,20 +5,17 @@ use parquet2::{
 use super::super::{levels, utils};
 use super::basic::{build_statistics, encode_plain};
 use crate::{
-    array::{Array, BooleanArray, Offset},
+    array::{Array, BooleanArray},
     error::Result,
     io::parquet::read::is_type_nullable,
 };
 
-pub fn array_to_page<O>(
+pub fn array_to_page(
     array: &BooleanArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
-    nested: levels::NestedInfo<O>,
-) -> Result<DataPage>
-where
-    O: Offset,
-{
+    nested: levels::NestedInfo,
+) -> Result<DataPage> {
     let is_optional = is_type_nullable(descriptor.type_());
 
     let validity = array.validity();
@@ -40,7 +37,7 @@ where
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
+        nested.num_values(),
         array.null_count(),
         repetition_levels_byte_length,
         definition_levels_byte_length,
@@ -2,11 +2,15 @@ use parquet2::{
     encoding::{hybrid_rle::encode_u32, Encoding},
     metadata::ColumnDescriptor,
     page::{EncodedDictPage, EncodedPage},
+    statistics::ParquetStatistics,
     write::{DynIter, WriteOptions},
 };
 
+use super::binary::build_statistics as binary_build_statistics;
 use super::binary::encode_plain as binary_encode_plain;
+use super::primitive::build_statistics as primitive_build_statistics;
 use super::primitive::encode_plain as primitive_encode_plain;
+use super::utf8::build_statistics as utf8_build_statistics;
 use super::utf8::encode_plain as utf8_encode_plain;
 use crate::array::{Array, DictionaryArray, DictionaryKey, PrimitiveArray};
 use crate::bitmap::Bitmap;
@@ -19,6 +23,7 @@ fn encode_keys<K: DictionaryKey>(
     array: &PrimitiveArray<K>,
     // todo: merge this to not discard values' validity
     validity: Option<&Bitmap>,
+    statistics: Option<ParquetStatistics>,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
 ) -> Result<EncodedPage> {
@@ -94,13 +99,19 @@ fn encode_keys<K: DictionaryKey>(
         encode_u32(&mut buffer, keys, num_bits)?;
     }
 
+    // the null count of the page is only known after the keys are projected
+    let statistics = statistics.map(|mut statistics| {
+        statistics.null_count = Some(null_count as i64);
+        statistics
+    });
+
     utils::build_plain_page(
         buffer,
         array.len(),
-        array.null_count(),
+        null_count,
         0,
         definition_levels_byte_length,
-        None,
+        statistics,
         descriptor,
         options,
         Encoding::RleDictionary,
@@ -109,12 +120,35 @@ fn encode_keys<K: DictionaryKey>(
 }
 
 macro_rules! dyn_prim {
-    ($from:ty, $to:ty, $array:expr, $options:expr) => {{
+    ($from:ty, $to:ty, $array:expr, $options:expr, $descriptor:expr) => {{
         let values = $array.values().as_any().downcast_ref().unwrap();
 
         let mut buffer = vec![];
         primitive_encode_plain::<$from, $to>(values, false, &mut buffer);
-        EncodedDictPage::new(buffer, values.len())
+        let statistics = if $options.write_statistics {
+            Some(primitive_build_statistics::<$from, $to>(
+                values,
+                $descriptor.clone(),
+            ))
+        } else {
+            None
+        };
+        (EncodedDictPage::new(buffer, values.len()), statistics)
+    }};
+}
+
+macro_rules! dyn_binary {
+    ($offset:ty, $encode:ident, $statistics:ident, $array:expr, $options:expr, $descriptor:expr) => {{
+        let values = $array.values().as_any().downcast_ref().unwrap();
+
+        let mut buffer = vec![];
+        $encode::<$offset>(values, false, &mut buffer);
+        let statistics = if $options.write_statistics {
+            Some($statistics::<$offset>(values, $descriptor.clone()))
+        } else {
+            None
+        };
+        (EncodedDictPage::new(buffer, values.len()), statistics)
     }};
 }
 
@@ -127,49 +161,57 @@ pub fn array_to_pages<K: DictionaryKey>(
     match encoding {
         Encoding::PlainDictionary | Encoding::RleDictionary => {
             // write DictPage
-            let dict_page = match array.values().data_type().to_logical_type() {
-                DataType::Int8 => dyn_prim!(i8, i32, array, options),
-                DataType::Int16 => dyn_prim!(i16, i32, array, options),
+            // the statistics of the data page are derived from the dictionary's values, which
+            // bound the values of the page (all of which are dictionary values).
+            let (dict_page, statistics) = match array.values().data_type().to_logical_type() {
+                DataType::Int8 => dyn_prim!(i8, i32, array, options, descriptor),
+                DataType::Int16 => dyn_prim!(i16, i32, array, options, descriptor),
                 DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
-                    dyn_prim!(i32, i32, array, options)
+                    dyn_prim!(i32, i32, array, options, descriptor)
                 }
                 DataType::Int64
                 | DataType::Date64
                 | DataType::Time64(_)
                 | DataType::Timestamp(_, _)
-                | DataType::Duration(_) => dyn_prim!(i64, i64, array, options),
-                DataType::UInt8 => dyn_prim!(u8, i32, array, options),
-                DataType::UInt16 => dyn_prim!(u16, i32, array, options),
-                DataType::UInt32 => dyn_prim!(u32, i32, array, options),
-                DataType::UInt64 => dyn_prim!(i64, i64, array, options),
-                DataType::Utf8 => {
-                    let values = array.values().as_any().downcast_ref().unwrap();
-
-                    let mut buffer = vec![];
-                    utf8_encode_plain::<i32>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
-                }
-                DataType::LargeUtf8 => {
-                    let values = array.values().as_any().downcast_ref().unwrap();
-
-                    let mut buffer = vec![];
-                    utf8_encode_plain::<i64>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
-                }
-                DataType::Binary => {
-                    let values = array.values().as_any().downcast_ref().unwrap();
-
-                    let mut buffer = vec![];
-                    binary_encode_plain::<i32>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
-                }
-                DataType::LargeBinary => {
-                    let values = array.values().as_any().downcast_ref().unwrap();
-
-                    let mut buffer = vec![];
-                    binary_encode_plain::<i64>(values, false, &mut buffer);
-                    EncodedDictPage::new(buffer, values.len())
-                }
+                | DataType::Duration(_) => dyn_prim!(i64, i64, array, options, descriptor),
+                DataType::UInt8 => dyn_prim!(u8, i32, array, options, descriptor),
+                DataType::UInt16 => dyn_prim!(u16, i32, array, options, descriptor),
+                DataType::UInt32 => dyn_prim!(u32, i32, array, options, descriptor),
+                DataType::UInt64 => dyn_prim!(u64, i64, array, options, descriptor),
+                DataType::Float32 => dyn_prim!(f32, f32, array, options, descriptor),
+                DataType::Float64 => dyn_prim!(f64, f64, array, options, descriptor),
+                DataType::Utf8 => dyn_binary!(
+                    i32,
+                    utf8_encode_plain,
+                    utf8_build_statistics,
+                    array,
+                    options,
+                    descriptor
+                ),
+                DataType::LargeUtf8 => dyn_binary!(
+                    i64,
+                    utf8_encode_plain,
+                    utf8_build_statistics,
+                    array,
+                    options,
+                    descriptor
+                ),
+                DataType::Binary => dyn_binary!(
+                    i32,
+                    binary_encode_plain,
+                    binary_build_statistics,
+                    array,
+                    options,
+                    descriptor
+                ),
+                DataType::LargeBinary => dyn_binary!(
+                    i64,
+                    binary_encode_plain,
+                    binary_build_statistics,
+                    array,
+                    options,
+                    descriptor
+                ),
                 other => {
                     return Err(ArrowError::NotYetImplemented(format!(
                         "Writing dictionary arrays to parquet only support data type {:?}",
@@ -180,8 +222,13 @@ pub fn array_to_pages<K: DictionaryKey>(
             let dict_page = EncodedPage::Dict(dict_page);
 
             // write DataPage pointing to DictPage
-            let data_page =
-                encode_keys(array.keys(), array.values().validity(), descriptor, options)?;
+            let data_page = encode_keys(
+                array.keys(),
+                array.values().validity(),
+                statistics,
+                descriptor,
+                options,
+            )?;
 
             let iter = std::iter::once(Ok(dict_page)).chain(std::iter::once(Ok(data_page)));
             Ok(DynIter::new(Box::new(iter)))
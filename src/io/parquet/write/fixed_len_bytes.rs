@@ -8,7 +8,8 @@ use parquet2::{
 
 use super::{binary::ord_binary, utils};
 use crate::{
-    array::{Array, FixedSizeBinaryArray},
+    array::{Array, FixedSizeBinaryArray, PrimitiveArray},
+    datatypes::DataType,
     error::Result,
     io::parquet::read::is_type_nullable,
 };
@@ -17,6 +18,70 @@ pub fn array_to_page(
     array: &FixedSizeBinaryArray,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+) -> Result<DataPage> {
+    let statistics = if options.write_statistics {
+        build_statistics(array, descriptor.clone())
+    } else {
+        None
+    };
+    to_page(array, options, descriptor, statistics)
+}
+
+/// Writes a decimal array as `FIXED_LEN_BYTE_ARRAY` of `size` bytes, i.e. the big-endian
+/// two's complement representation of each value truncated to its `size` least significant bytes.
+pub fn decimal_array_to_page(
+    array: &PrimitiveArray<i128>,
+    size: usize,
+    options: WriteOptions,
+    descriptor: ColumnDescriptor,
+) -> Result<DataPage> {
+    let mut values = Vec::<u8>::with_capacity(size * array.len());
+    array.values().iter().for_each(|x| {
+        let bytes = &x.to_be_bytes()[16 - size..];
+        values.extend_from_slice(bytes)
+    });
+    let fixed_size_binary = FixedSizeBinaryArray::from_data(
+        DataType::FixedSizeBinary(size),
+        values.into(),
+        array.validity().cloned(),
+    );
+
+    // the statistics are computed from the (signed) values, since their bytes do not sort
+    let statistics = if options.write_statistics {
+        build_decimal_statistics(array, size, descriptor.clone())
+    } else {
+        None
+    };
+    to_page(&fixed_size_binary, options, descriptor, statistics)
+}
+
+/// Writes an array whose values have no defined sort order (e.g. intervals), whose
+/// statistics only contain the null count.
+pub fn unordered_array_to_page(
+    array: &FixedSizeBinaryArray,
+    options: WriteOptions,
+    descriptor: ColumnDescriptor,
+) -> Result<DataPage> {
+    let statistics = if options.write_statistics {
+        Some(ParquetStatistics {
+            max: None,
+            min: None,
+            null_count: Some(array.null_count() as i64),
+            distinct_count: None,
+            max_value: None,
+            min_value: None,
+        })
+    } else {
+        None
+    };
+    to_page(array, options, descriptor, statistics)
+}
+
+fn to_page(
+    array: &FixedSizeBinaryArray,
+    options: WriteOptions,
+    descriptor: ColumnDescriptor,
+    statistics: Option<ParquetStatistics>,
 ) -> Result<DataPage> {
     let is_optional = is_type_nullable(descriptor.type_());
     let validity = array.validity();
@@ -44,12 +109,6 @@ pub fn array_to_page(
         buffer.extend_from_slice(array.values());
     }
 
-    let statistics = if options.write_statistics {
-        build_statistics(array, descriptor.clone())
-    } else {
-        None
-    };
-
     utils::build_plain_page(
         buffer,
         array.len(),
@@ -87,3 +146,22 @@ pub(super) fn build_statistics(
         .map(|e| serialize_statistics(&*e))
         .ok()
 }
+
+fn build_decimal_statistics(
+    array: &PrimitiveArray<i128>,
+    size: usize,
+    descriptor: ColumnDescriptor,
+) -> Option<ParquetStatistics> {
+    let to_bytes = |x: &i128| x.to_be_bytes()[16 - size..].to_vec();
+    let pq_statistics = &ParquetStatistics {
+        max: None,
+        min: None,
+        null_count: Some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: array.iter().flatten().max().map(to_bytes),
+        min_value: array.iter().flatten().min().map(to_bytes),
+    };
+    deserialize_statistics(pq_statistics, descriptor)
+        .map(|e| serialize_statistics(&*e))
+        .ok()
+}
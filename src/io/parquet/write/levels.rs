@@ -1,169 +1,184 @@
 use parquet2::encoding::hybrid_rle::encode_u32;
 use parquet2::write::Version;
 
-use crate::{
-    array::Offset,
-    bitmap::{utils::BitmapIter, Bitmap},
-    error::Result,
-};
-
-pub fn num_values<O: Offset>(offsets: &[O]) -> usize {
-    offsets
-        .windows(2)
-        .map(|w| {
-            let length = w[1].to_usize() - w[0].to_usize();
-            if length == 0 {
-                1
-            } else {
-                length
-            }
-        })
-        .sum()
+use crate::{bitmap::Bitmap, error::Result};
+
+use super::utils::get_bit_width;
+
+/// The offsets of a level of nesting
+#[derive(Debug, Clone, Copy)]
+pub enum ListOffsets<'a> {
+    /// The offsets of a `ListArray<i32>`
+    Small(&'a [i32]),
+    /// The offsets of a `ListArray<i64>`
+    Large(&'a [i64]),
+    /// The (implicit) offsets of a `FixedSizeListArray` of `size`
+    Fixed(usize),
 }
 
-/// Iterator adapter of parquet / dremel repetition levels
-#[derive(Debug)]
-pub struct RepLevelsIter<'a, O: Offset> {
-    iter: std::slice::Windows<'a, O>,
-    remaining: usize,
-    length: usize,
-    total_size: usize,
+impl ListOffsets<'_> {
+    /// Returns the offset of slot `index`, i.e. the position of its first child.
+    #[inline]
+    fn offset(&self, index: usize) -> usize {
+        match self {
+            Self::Small(offsets) => offsets[index] as usize,
+            Self::Large(offsets) => offsets[index] as usize,
+            Self::Fixed(size) => index * size,
+        }
+    }
 }
 
-impl<'a, O: Offset> RepLevelsIter<'a, O> {
-    pub fn new(offsets: &'a [O]) -> Self {
-        let total_size = num_values(offsets);
+/// Information about a level of nesting (a list) required to compute repetition and
+/// definition levels.
+#[derive(Debug, Clone)]
+pub struct ListNested<'a> {
+    is_optional: bool,
+    offsets: ListOffsets<'a>,
+    validity: Option<&'a Bitmap>,
+}
 
+impl<'a> ListNested<'a> {
+    pub fn new(offsets: ListOffsets<'a>, validity: Option<&'a Bitmap>, is_optional: bool) -> Self {
         Self {
-            iter: offsets.windows(2),
-            remaining: 0,
-            length: 0,
-            total_size,
+            is_optional,
+            offsets,
+            validity,
         }
     }
-}
 
-impl<O: Offset> Iterator for RepLevelsIter<'_, O> {
-    type Item = u32;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == self.length {
-            if let Some(w) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
-                self.remaining = 0;
-                if self.length == 0 {
-                    self.total_size -= 1;
-                    return Some(0);
-                }
-            } else {
-                return None;
-            }
-        }
-        let old = self.remaining;
-        self.remaining += 1;
-        self.total_size -= 1;
-        Some((old >= 1) as u32)
+    pub fn offsets(&self) -> ListOffsets<'a> {
+        self.offsets
+    }
+
+    pub fn validity(&self) -> Option<&'a Bitmap> {
+        self.validity
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.is_optional
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.total_size, Some(self.total_size))
+    #[inline]
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity
+            .map(|validity| validity.get_bit(index))
+            .unwrap_or(true)
     }
 }
 
-/// Iterator adapter of parquet / dremel definition levels
-pub struct DefLevelsIter<'a, O: Offset> {
-    iter: std::iter::Zip<std::slice::Windows<'a, O>, Box<dyn Iterator<Item = bool> + 'a>>,
-    primitive_validity: Option<BitmapIter<'a>>,
-    remaining: usize,
-    is_valid: bool,
+/// Information about all levels of nesting of a column, from the outermost to the innermost
+/// list, required to compute its repetition and definition levels (Dremel's record shredding).
+#[derive(Debug, Clone)]
+pub struct NestedInfo<'a> {
+    lists: Vec<ListNested<'a>>,
     length: usize,
-    total_size: usize,
+    is_optional: bool,
+    values_offset: usize,
 }
 
-impl<'a, O: Offset> DefLevelsIter<'a, O> {
-    pub fn new(
-        offsets: &'a [O],
-        validity: Option<&'a Bitmap>,
-        primitive_validity: Option<&'a Bitmap>,
-    ) -> Self {
-        let total_size = num_values(offsets);
+impl<'a> NestedInfo<'a> {
+    /// Creates a new [`NestedInfo`] of `length` (outermost) slots from the `lists` of the column,
+    /// and whether its values are optional.
+    /// # Panics
+    /// iff `lists` is empty.
+    pub fn new(lists: Vec<ListNested<'a>>, length: usize, is_optional: bool) -> Self {
+        assert!(!lists.is_empty());
+        let mut nested = Self {
+            lists,
+            length,
+            is_optional,
+            values_offset: 0,
+        };
+        nested.values_offset = nested.values_range().0;
+        nested
+    }
 
-        let primitive_validity = primitive_validity.map(|x| x.iter());
+    /// Returns the range of the (innermost) values that are part of this column.
+    /// Values outside this range are not reachable from any slot (e.g. because the array is sliced).
+    pub fn values_range(&self) -> (usize, usize) {
+        self.lists
+            .iter()
+            .fold((0, self.length), |(start, end), list| {
+                (list.offsets.offset(start), list.offsets.offset(end))
+            })
+    }
 
-        let validity = validity
-            .map(|x| Box::new(x.iter()) as Box<dyn Iterator<Item = bool>>)
-            .unwrap_or_else(|| {
-                Box::new(std::iter::repeat(true).take(offsets.len() - 1))
-                    as Box<dyn Iterator<Item = bool>>
-            });
+    /// The maximum repetition level of this column
+    pub fn max_rep_level(&self) -> u32 {
+        self.lists.len() as u32
+    }
 
-        Self {
-            iter: offsets.windows(2).zip(validity),
-            primitive_validity,
-            remaining: 0,
-            length: 0,
-            is_valid: false,
-            total_size,
-        }
+    /// The maximum definition level of this column
+    pub fn max_def_level(&self) -> u32 {
+        self.lists
+            .iter()
+            .map(|list| list.is_optional as u32 + 1)
+            .sum::<u32>()
+            + self.is_optional as u32
     }
-}
 
-impl<O: Offset> Iterator for DefLevelsIter<'_, O> {
-    type Item = u32;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining == self.length {
-            if let Some((w, is_valid)) = self.iter.next() {
-                let start = w[0].to_usize();
-                let end = w[1].to_usize();
-                self.length = end - start;
-                self.remaining = 0;
-                self.is_valid = is_valid;
-                if self.length == 0 {
-                    self.total_size -= 1;
-                    return Some(is_valid as u32);
-                }
+    /// Traverses slot `index` of the list at `depth`, calling `f` with the repetition and
+    /// definition level of every entry of the column.
+    /// `validity` is the validity of the values, whose first value is at `values_offset`.
+    fn traverse<F: FnMut(u32, u32)>(
+        &self,
+        depth: usize,
+        index: usize,
+        rep: u32,
+        def: u32,
+        validity: Option<&Bitmap>,
+        f: &mut F,
+    ) {
+        let list = &self.lists[depth];
+        if list.is_optional && !list.is_valid(index) {
+            return f(rep, def);
+        }
+        let def = def + list.is_optional as u32;
+
+        let start = list.offsets.offset(index);
+        let end = list.offsets.offset(index + 1);
+        if start == end {
+            return f(rep, def);
+        }
+
+        for (i, child) in (start..end).enumerate() {
+            // the first child continues the parent's repetition; the others repeat this level
+            let rep = if i == 0 { rep } else { depth as u32 + 1 };
+            if depth + 1 < self.lists.len() {
+                self.traverse(depth + 1, child, rep, def + 1, validity, f)
             } else {
-                return None;
+                let is_valid = self.is_optional
+                    && validity
+                        .map(|validity| validity.get_bit(child - self.values_offset))
+                        .unwrap_or(true);
+                f(rep, def + 1 + is_valid as u32)
             }
         }
-        self.remaining += 1;
-        self.total_size -= 1;
-
-        let (base_def, p_is_valid) = self
-            .primitive_validity
-            .as_mut()
-            .map(|x| (1, x.next().unwrap() as u32))
-            .unwrap_or((0, 0));
-        let def_ = (base_def + 1) * self.is_valid as u32 + p_is_valid;
-        Some(def_)
     }
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.total_size, Some(self.total_size))
+    fn for_each<F: FnMut(u32, u32)>(&self, validity: Option<&Bitmap>, mut f: F) {
+        (0..self.length).for_each(|index| self.traverse(0, index, 0, 0, validity, &mut f))
     }
-}
 
-#[derive(Debug)]
-pub struct NestedInfo<'a, O: Offset> {
-    _is_optional: bool,
-    offsets: &'a [O],
-    validity: Option<&'a Bitmap>,
-}
+    /// Returns the repetition levels of this column
+    pub fn rep_levels(&self) -> Vec<u32> {
+        let mut levels = vec![];
+        self.for_each(None, |rep, _| levels.push(rep));
+        levels
+    }
 
-impl<'a, O: Offset> NestedInfo<'a, O> {
-    pub fn new(offsets: &'a [O], validity: Option<&'a Bitmap>, is_optional: bool) -> Self {
-        Self {
-            _is_optional: is_optional,
-            offsets,
-            validity,
-        }
+    /// Returns the definition levels of this column, whose values have `validity`.
+    pub fn def_levels(&self, validity: Option<&Bitmap>) -> Vec<u32> {
+        let mut levels = vec![];
+        self.for_each(validity, |_, def| levels.push(def));
+        levels
     }
 
-    pub fn offsets(&self) -> &'a [O] {
-        self.offsets
+    /// Returns the number of entries (repetition or definition levels) of this column
+    pub fn num_values(&self) -> usize {
+        let mut length = 0;
+        self.for_each(None, |_, _| length += 1);
+        length
     }
 }
 
@@ -185,26 +200,18 @@ fn write_levels_v1<F: Fn(&mut Vec<u8>) -> Result<()>>(
     Ok(())
 }
 
-/// writes the rep levels to a `Vec<u8>`.
-pub fn write_rep_levels<O: Offset>(
-    buffer: &mut Vec<u8>,
-    nested: &NestedInfo<O>,
-    version: Version,
-) -> Result<()> {
-    let num_bits = 1; // todo: compute this
+fn write_levels(buffer: &mut Vec<u8>, levels: &[u32], max: u32, version: Version) -> Result<()> {
+    let num_bits = get_bit_width(max as u64) as u8;
 
     match version {
         Version::V1 => {
             write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = RepLevelsIter::new(nested.offsets);
-                encode_u32(buffer, levels, num_bits)?;
+                encode_u32(buffer, levels.iter().copied(), num_bits)?;
                 Ok(())
             })?;
         }
         Version::V2 => {
-            let levels = RepLevelsIter::new(nested.offsets);
-
-            encode_u32(buffer, levels, num_bits)?;
+            encode_u32(buffer, levels.iter().copied(), num_bits)?;
         }
     }
 
@@ -212,29 +219,20 @@ pub fn write_rep_levels<O: Offset>(
 }
 
 /// writes the rep levels to a `Vec<u8>`.
-pub fn write_def_levels<O: Offset>(
+pub fn write_rep_levels(buffer: &mut Vec<u8>, nested: &NestedInfo, version: Version) -> Result<()> {
+    let levels = nested.rep_levels();
+    write_levels(buffer, &levels, nested.max_rep_level(), version)
+}
+
+/// writes the def levels to a `Vec<u8>`.
+pub fn write_def_levels(
     buffer: &mut Vec<u8>,
-    nested: &NestedInfo<O>,
+    nested: &NestedInfo,
     validity: Option<&Bitmap>,
     version: Version,
 ) -> Result<()> {
-    let num_bits = 2;
-
-    match version {
-        Version::V1 => {
-            write_levels_v1(buffer, |buffer: &mut Vec<u8>| {
-                let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
-                encode_u32(buffer, levels, num_bits)?;
-                Ok(())
-            })?;
-        }
-        Version::V2 => {
-            let levels = DefLevelsIter::new(nested.offsets, nested.validity, validity);
-            encode_u32(buffer, levels, num_bits)?;
-        }
-    }
-
-    Ok(())
+    let levels = nested.def_levels(validity);
+    write_levels(buffer, &levels, nested.max_def_level(), version)
 }
 
 #[cfg(test)]
@@ -246,7 +244,8 @@ mod tests {
         let offsets = [0, 2, 2, 5, 8, 8, 11, 11, 12].as_ref();
         let expected = vec![0u32, 1, 0, 0, 1, 1, 0, 1, 1, 0, 0, 1, 1, 0, 0];
 
-        let result = RepLevelsIter::new(offsets).collect::<Vec<_>>();
+        let list = ListNested::new(ListOffsets::Small(offsets), None, false);
+        let result = NestedInfo::new(vec![list], 8, false).rep_levels();
         assert_eq!(result, expected)
     }
 
@@ -265,8 +264,32 @@ mod tests {
         ]));
         let expected = vec![3u32, 3, 0, 3, 2, 3, 3, 3, 3, 1, 3, 3, 3, 0, 3];
 
-        let result = DefLevelsIter::new(offsets, validity.as_ref(), primitive_validity.as_ref())
-            .collect::<Vec<_>>();
+        let list = ListNested::new(ListOffsets::Small(offsets), validity.as_ref(), true);
+        let result = NestedInfo::new(vec![list], 8, true).def_levels(primitive_validity.as_ref());
         assert_eq!(result, expected)
     }
+
+    #[test]
+    fn test_nested_levels() {
+        // [[[1, None], []], None, [None, [2]], []]
+        let outer = [0, 2, 2, 4, 4].as_ref();
+        let outer_validity = Bitmap::from([true, false, true, true]);
+        let inner = [0, 2, 2, 2, 3].as_ref();
+        let inner_validity = Bitmap::from([true, true, false, true]);
+        let validity = Bitmap::from([true, false, true]);
+
+        let lists = vec![
+            ListNested::new(ListOffsets::Small(outer), Some(&outer_validity), true),
+            ListNested::new(ListOffsets::Large(inner), Some(&inner_validity), true),
+        ];
+        let nested = NestedInfo::new(lists, 4, true);
+        assert_eq!(nested.max_rep_level(), 2);
+        assert_eq!(nested.max_def_level(), 5);
+        assert_eq!(nested.num_values(), 7);
+        assert_eq!(nested.rep_levels(), vec![0, 2, 1, 0, 0, 1, 0]);
+        assert_eq!(
+            nested.def_levels(Some(&validity)),
+            vec![5, 4, 3, 0, 2, 5, 1]
+        );
+    }
 }
@@ -7,18 +7,23 @@ mod levels;
 mod primitive;
 mod record_batch;
 mod schema;
+// writes from a background thread, which is unavailable on wasm32
+#[cfg(not(target_arch = "wasm32"))]
+mod sink;
 mod utf8;
 mod utils;
 
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))]
+pub mod partitioned;
 pub mod stream;
 
 use crate::array::*;
-use crate::bitmap::Bitmap;
 use crate::buffer::Buffer;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::io::parquet::read::is_type_nullable;
-use crate::io::parquet::write::levels::NestedInfo;
+use crate::io::parquet::write::levels::{ListNested, ListOffsets, NestedInfo};
 use crate::types::days_ms;
 use crate::types::NativeType;
 
@@ -36,9 +41,12 @@ pub use parquet2::{
     },
     FallibleStreamingIterator,
 };
-pub use record_batch::RowGroupIterator;
-use schema::schema_to_metadata_key;
+pub use record_batch::{EncodeTask, Encoder, FieldRef, RowGroupIterator};
+pub use schema::schema_to_key_value_metadata;
 pub use schema::to_parquet_type;
+#[cfg(not(target_arch = "wasm32"))]
+#[cfg_attr(docsrs, doc(cfg(not(target_arch = "wasm32"))))]
+pub use sink::FileSink;
 
 pub(self) fn decimal_length_from_precision(precision: usize) -> usize {
     // digits = floor(log_10(2^(8*n - 1) - 1))
@@ -74,12 +82,7 @@ where
     W: std::io::Write,
     I: Iterator<Item = Result<RowGroupIter<'a, ArrowError>>>,
 {
-    let key_value_metadata = key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]));
+    let key_value_metadata = Some(schema_to_key_value_metadata(schema, key_value_metadata));
 
     let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
     Ok(parquet_write_file(
@@ -100,14 +103,67 @@ pub fn can_encode(data_type: &DataType, encoding: Encoding) -> bool {
         (encoding, data_type),
         (Encoding::Plain, _)
             | (
-                Encoding::DeltaLengthByteArray,
+                Encoding::DeltaLengthByteArray | Encoding::DeltaByteArray,
                 DataType::Binary | DataType::LargeBinary | DataType::Utf8 | DataType::LargeUtf8,
             )
+            | (
+                Encoding::DeltaBinaryPacked,
+                DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::Date32
+                    | DataType::Date64
+                    | DataType::Time32(_)
+                    | DataType::Time64(_)
+                    | DataType::Timestamp(_, _)
+                    | DataType::Duration(_),
+            )
             | (Encoding::RleDictionary, DataType::Dictionary(_, _, _))
             | (Encoding::PlainDictionary, DataType::Dictionary(_, _, _))
     )
 }
 
+/// Returns the arrays of the parquet columns (leaves) of `array`, in the order of the parquet
+/// schema of its field, each to be written by [`array_to_pages`].
+///
+/// Each field of the entries of a map is a column, written as a list with the offsets and
+/// validity of the map.
+pub fn to_leaves(array: &dyn Array) -> Vec<Box<dyn Array>> {
+    match array.data_type().to_logical_type() {
+        DataType::Map(_, _) => {
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let entries = array
+                .field()
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .unwrap();
+            entries
+                .fields()
+                .iter()
+                .zip(entries.values())
+                .flat_map(|(field, values)| {
+                    to_leaves(values.as_ref()).into_iter().map(move |leaf| {
+                        let field =
+                            Field::new(&field.name, leaf.data_type().clone(), field.is_nullable);
+                        Box::new(ListArray::<i32>::from_data(
+                            DataType::List(Box::new(field)),
+                            array.offsets().clone(),
+                            leaf.into(),
+                            array.validity().cloned(),
+                        )) as Box<dyn Array>
+                    })
+                })
+                .collect()
+        }
+        _ => vec![clone(array)],
+    }
+}
+
 /// Returns an iterator of [`EncodedPage`].
 pub fn array_to_pages(
     array: &dyn Array,
@@ -126,11 +182,74 @@ pub fn array_to_pages(
                 )
             })
         }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            binary_array_to_pages(array, array.offsets(), descriptor, options, encoding)
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            binary_array_to_pages(array, array.offsets(), descriptor, options, encoding)
+        }
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            binary_array_to_pages(array, array.offsets(), descriptor, options, encoding)
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            binary_array_to_pages(array, array.offsets(), descriptor, options, encoding)
+        }
         _ => array_to_page(array, descriptor, options, encoding)
             .map(|page| DynIter::new(std::iter::once(Ok(page)))),
     }
 }
 
+/// The maximum size of the values of a page of binary or utf8 values. Larger arrays are written
+/// in multiple pages, since the size of a parquet page must fit in an `i32`.
+const MAX_BINARY_PAGE_SIZE: usize = 1 << 30;
+
+/// Returns the ranges `(start, length)` of slots of `offsets` whose values, each prefixed by its
+/// 4 bytes length, fit in a page. A single value larger than a page has its own page.
+fn binary_page_ranges<O: Offset>(offsets: &[O]) -> Vec<(usize, usize)> {
+    let mut ranges = vec![];
+    let mut start = 0;
+    let mut start_offset = offsets[0].to_usize();
+    for i in 0..offsets.len() - 1 {
+        let size = offsets[i + 1].to_usize() - start_offset + 4 * (i + 1 - start);
+        if size > MAX_BINARY_PAGE_SIZE && i > start {
+            ranges.push((start, i - start));
+            start = i;
+            start_offset = offsets[i].to_usize();
+        }
+    }
+    ranges.push((start, offsets.len() - 1 - start));
+    ranges
+}
+
+fn binary_array_to_pages<O: Offset>(
+    array: &dyn Array,
+    offsets: &[O],
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+    encoding: Encoding,
+) -> Result<DynIter<'static, Result<EncodedPage>>> {
+    let ranges = binary_page_ranges(offsets);
+    if ranges.len() == 1 {
+        return array_to_page(array, descriptor, options, encoding)
+            .map(|page| DynIter::new(std::iter::once(Ok(page))));
+    }
+    // the pages are encoded lazily, so that only one of them is in memory at a time
+    let array = clone(array);
+    let pages = ranges.into_iter().map(move |(start, length)| {
+        array_to_page(
+            array.slice(start, length).as_ref(),
+            descriptor.clone(),
+            options,
+            encoding,
+        )
+    });
+    Ok(DynIter::new(pages))
+}
+
 /// Converts an [`Array`] to a [`CompressedPage`] based on options, descriptor and `encoding`.
 pub fn array_to_page(
     array: &dyn Array,
@@ -155,37 +274,44 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt16 => primitive::array_to_page::<u16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt32 => primitive::array_to_page::<u32, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::UInt64 => primitive::array_to_page::<u64, i64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int8 => primitive::array_to_page::<i8, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int16 => primitive::array_to_page::<i16, i32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Int32 | DataType::Date32 | DataType::Time32(_) => {
             primitive::array_to_page::<i32, i32>(
                 array.as_any().downcast_ref().unwrap(),
                 options,
                 descriptor,
+                encoding,
             )
         }
         DataType::Int64
@@ -196,16 +322,19 @@ pub fn array_to_page(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float32 => primitive::array_to_page::<f32, f32>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Float64 => primitive::array_to_page::<f64, f64>(
             array.as_any().downcast_ref().unwrap(),
             options,
             descriptor,
+            encoding,
         ),
         DataType::Utf8 => utf8::array_to_page::<i32>(
             array.as_any().downcast_ref().unwrap(),
@@ -233,7 +362,7 @@ pub fn array_to_page(
         ),
         DataType::Null => {
             let array = Int32Array::new_null(DataType::Int32, array.len());
-            primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+            primitive::array_to_page::<i32, i32>(&array, options, descriptor, encoding)
         }
         DataType::Interval(IntervalUnit::YearMonth) => {
             let array = array
@@ -251,7 +380,7 @@ pub fn array_to_page(
                 values.into(),
                 array.validity().cloned(),
             );
-            fixed_len_bytes::array_to_page(&array, options, descriptor)
+            fixed_len_bytes::unordered_array_to_page(&array, options, descriptor)
         }
         DataType::Interval(IntervalUnit::DayTime) => {
             let array = array
@@ -269,7 +398,7 @@ pub fn array_to_page(
                 values.into(),
                 array.validity().cloned(),
             );
-            fixed_len_bytes::array_to_page(&array, options, descriptor)
+            fixed_len_bytes::unordered_array_to_page(&array, options, descriptor)
         }
         DataType::FixedSizeBinary(_) => fixed_len_bytes::array_to_page(
             array.as_any().downcast_ref().unwrap(),
@@ -290,7 +419,7 @@ pub fn array_to_page(
                     values,
                     array.validity().cloned(),
                 );
-                primitive::array_to_page::<i32, i32>(&array, options, descriptor)
+                primitive::array_to_page::<i32, i32>(&array, options, descriptor, encoding)
             } else if precision <= 18 {
                 let values = array.values().iter().map(|x| *x as i64);
                 let values = Buffer::from_trusted_len_iter(values);
@@ -299,20 +428,10 @@ pub fn array_to_page(
                     values,
                     array.validity().cloned(),
                 );
-                primitive::array_to_page::<i64, i64>(&array, options, descriptor)
+                primitive::array_to_page::<i64, i64>(&array, options, descriptor, encoding)
             } else {
                 let size = decimal_length_from_precision(precision);
-                let mut values = Vec::<u8>::with_capacity(size * array.len());
-                array.values().iter().for_each(|x| {
-                    let bytes = &x.to_be_bytes()[16 - size..];
-                    values.extend_from_slice(bytes)
-                });
-                let array = FixedSizeBinaryArray::from_data(
-                    DataType::FixedSizeBinary(size),
-                    values.into(),
-                    array.validity().cloned(),
-                );
-                fixed_len_bytes::array_to_page(&array, options, descriptor)
+                fixed_len_bytes::decimal_array_to_page(array, size, options, descriptor)
             }
         }
         DataType::FixedSizeList(_, _) | DataType::List(_) | DataType::LargeList(_) => {
@@ -327,138 +446,112 @@ pub fn array_to_page(
 }
 
 macro_rules! dyn_nested_prim {
-    ($from:ty, $to:ty, $offset:ty, $values:expr, $nested:expr,$descriptor:expr, $options:expr) => {{
+    ($from:ty, $to:ty, $values:expr, $nested:expr,$descriptor:expr, $options:expr) => {{
         let values = $values.as_any().downcast_ref().unwrap();
 
-        primitive::nested_array_to_page::<$from, $to, $offset>(
-            values,
-            $options,
-            $descriptor,
-            $nested,
-        )
+        primitive::nested_array_to_page::<$from, $to>(values, $options, $descriptor, $nested)
     }};
 }
 
-fn list_array_to_page<O: Offset>(
-    offsets: &[O],
-    validity: Option<&Bitmap>,
-    values: &dyn Array,
+/// Collects the [`ListNested`] of `array` and of its inner lists into `lists`, returning its
+/// innermost (non-list) values. `is_optional` is whether `array` is optional.
+fn collect_lists<'a>(
+    array: &'a dyn Array,
+    is_optional: bool,
+    lists: &mut Vec<ListNested<'a>>,
+) -> &'a dyn Array {
+    match array.data_type() {
+        DataType::List(inner) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let offsets = ListOffsets::Small(array.offsets().as_slice());
+            lists.push(ListNested::new(offsets, array.validity(), is_optional));
+            collect_lists(array.values().as_ref(), inner.is_nullable, lists)
+        }
+        DataType::LargeList(inner) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let offsets = ListOffsets::Large(array.offsets().as_slice());
+            lists.push(ListNested::new(offsets, array.validity(), is_optional));
+            collect_lists(array.values().as_ref(), inner.is_nullable, lists)
+        }
+        DataType::FixedSizeList(inner, size) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let offsets = ListOffsets::Fixed(*size);
+            lists.push(ListNested::new(offsets, array.validity(), is_optional));
+            collect_lists(array.values().as_ref(), inner.is_nullable, lists)
+        }
+        _ => array,
+    }
+}
+
+fn nested_array_to_page(
+    array: &dyn Array,
     descriptor: ColumnDescriptor,
     options: WriteOptions,
 ) -> Result<DataPage> {
     use DataType::*;
     let is_optional = is_type_nullable(descriptor.type_());
-    let nested = NestedInfo::new(offsets, validity, is_optional);
+
+    let mut lists = vec![];
+    let values = collect_lists(array, false, &mut lists);
+
+    // the nullability of the outermost list is not part of the array; it is derived from the
+    // maximum definition level of the column, which counts every optional and repeated level
+    let inner_def_level = lists[1..]
+        .iter()
+        .map(|list| list.is_optional() as u32 + 1)
+        .sum::<u32>();
+    let is_outer_optional =
+        descriptor.max_def_level() as u32 > inner_def_level + 1 + is_optional as u32;
+    lists[0] = ListNested::new(lists[0].offsets(), lists[0].validity(), is_outer_optional);
+
+    let nested = NestedInfo::new(lists, array.len(), is_optional);
+
+    // only the values within the (outermost) slots are part of the column
+    let (start, end) = nested.values_range();
+    let values = values.slice(start, end - start);
+    let values = values.as_ref();
 
     match values.data_type() {
         Boolean => {
             let values = values.as_any().downcast_ref().unwrap();
-            boolean::nested_array_to_page::<O>(values, options, descriptor, nested)
+            boolean::nested_array_to_page(values, options, descriptor, nested)
         }
-        UInt8 => dyn_nested_prim!(u8, i32, O, values, nested, descriptor, options),
-        UInt16 => dyn_nested_prim!(u16, i32, O, values, nested, descriptor, options),
-        UInt32 => dyn_nested_prim!(u32, i32, O, values, nested, descriptor, options),
-        UInt64 => dyn_nested_prim!(u64, i64, O, values, nested, descriptor, options),
+        UInt8 => dyn_nested_prim!(u8, i32, values, nested, descriptor, options),
+        UInt16 => dyn_nested_prim!(u16, i32, values, nested, descriptor, options),
+        UInt32 => dyn_nested_prim!(u32, i32, values, nested, descriptor, options),
+        UInt64 => dyn_nested_prim!(u64, i64, values, nested, descriptor, options),
 
-        Int8 => dyn_nested_prim!(i8, i32, O, values, nested, descriptor, options),
-        Int16 => dyn_nested_prim!(i16, i32, O, values, nested, descriptor, options),
+        Int8 => dyn_nested_prim!(i8, i32, values, nested, descriptor, options),
+        Int16 => dyn_nested_prim!(i16, i32, values, nested, descriptor, options),
         Int32 | Date32 | Time32(_) => {
-            dyn_nested_prim!(i32, i32, O, values, nested, descriptor, options)
+            dyn_nested_prim!(i32, i32, values, nested, descriptor, options)
         }
         Int64 | Date64 | Time64(_) | Timestamp(_, _) | Duration(_) => {
-            dyn_nested_prim!(i64, i64, O, values, nested, descriptor, options)
+            dyn_nested_prim!(i64, i64, values, nested, descriptor, options)
         }
 
-        Float32 => dyn_nested_prim!(f32, f32, O, values, nested, descriptor, options),
-        Float64 => dyn_nested_prim!(f64, f64, O, values, nested, descriptor, options),
+        Float32 => dyn_nested_prim!(f32, f32, values, nested, descriptor, options),
+        Float64 => dyn_nested_prim!(f64, f64, values, nested, descriptor, options),
 
         Utf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            utf8::nested_array_to_page::<i32, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            utf8::nested_array_to_page::<i32>(values, options, descriptor, nested)
         }
         LargeUtf8 => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            utf8::nested_array_to_page::<i64, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            utf8::nested_array_to_page::<i64>(values, options, descriptor, nested)
         }
         Binary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            binary::nested_array_to_page::<i32, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            binary::nested_array_to_page::<i32>(values, options, descriptor, nested)
         }
         LargeBinary => {
             let values = values.as_any().downcast_ref().unwrap();
-            let is_optional = is_type_nullable(descriptor.type_());
-
-            binary::nested_array_to_page::<i64, O>(
-                values,
-                options,
-                descriptor,
-                NestedInfo::new(offsets, validity, is_optional),
-            )
+            binary::nested_array_to_page::<i64>(values, options, descriptor, nested)
         }
-        _ => todo!(),
-    }
-}
-
-fn nested_array_to_page(
-    array: &dyn Array,
-    descriptor: ColumnDescriptor,
-    options: WriteOptions,
-) -> Result<DataPage> {
-    match array.data_type() {
-        DataType::List(_) => {
-            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-            list_array_to_page(
-                array.offsets(),
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
-        }
-        DataType::LargeList(_) => {
-            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-            list_array_to_page(
-                array.offsets(),
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
-        }
-        DataType::FixedSizeList(_, size) => {
-            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
-            let offsets = (0..array.len())
-                .map(|x| (*size * x) as i32)
-                .collect::<Vec<_>>();
-            list_array_to_page(
-                &offsets,
-                array.validity(),
-                array.values().as_ref(),
-                descriptor,
-                options,
-            )
-        }
-        _ => todo!(),
+        other => Err(ArrowError::NotYetImplemented(format!(
+            "Writing nested parquet pages with values of data type {:?}",
+            other
+        ))),
     }
 }
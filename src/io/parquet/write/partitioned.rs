@@ -0,0 +1,349 @@
+//! APIs to write a directory of parquet files partitioned by the values of some of its columns,
+//! using the (hive-style) layout `root/key1=value1/key2=value2/part-0.parquet`.
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::array::DictionaryKey;
+use crate::array::{get_value_display, growable::make_growable, Array, DictionaryArray};
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, PhysicalType, Schema};
+use crate::error::{ArrowError, Result};
+
+use super::{Encoding, FieldRef, FileSink, WriteOptions};
+
+/// The name of the directory of null (and empty) partition values, as used by hive.
+pub const DEFAULT_PARTITION_NAME: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The name of the file written to each partition directory.
+pub const FILE_NAME: &str = "part-0.parquet";
+
+/// Escapes the characters of `value` that are not allowed in a path segment (or that have a
+/// meaning in the layout) as `%XX`, like hive does.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\u{0}'..='\u{1F}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '\u{7F}'
+            | '{'
+            | '['
+            | ']'
+            | '^' => escaped.push_str(&format!("%{:02X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Returns the directory name (`key=value`) of the partition of `value`.
+fn directory(key: &str, value: Option<&str>) -> String {
+    match value {
+        Some(value) if !value.is_empty() => format!("{}={}", escape(key), escape(value)),
+        _ => format!("{}={}", escape(key), DEFAULT_PARTITION_NAME),
+    }
+}
+
+/// The directory name of each distinct value of a partition column, and the index of each
+/// row's directory.
+type Partitions = (Vec<String>, Vec<usize>);
+
+/// Partitions a dictionary-encoded column by its keys, so that each value of the
+/// dictionary is only formatted once.
+fn partition_dictionary<K: DictionaryKey>(key: &str, array: &DictionaryArray<K>) -> Partitions {
+    let values = array.values().as_ref();
+    let display = get_value_display(values);
+    let mut directories = (0..values.len())
+        .map(|index| {
+            let value = values.is_valid(index).then(|| display(index));
+            directory(key, value.as_deref())
+        })
+        .collect::<Vec<_>>();
+    // the directory of null keys
+    let null = directories.len();
+    directories.push(directory(key, None));
+
+    let indices = array
+        .keys()
+        .iter()
+        .map(|key| key.map(|key| key.to_usize().unwrap()).unwrap_or(null))
+        .collect();
+    (directories, indices)
+}
+
+/// Errors iff the values of `field` cannot be partition values.
+fn check_partition(field: &Field) -> Result<()> {
+    match field.data_type().to_physical_type() {
+        PhysicalType::List
+        | PhysicalType::LargeList
+        | PhysicalType::FixedSizeList
+        | PhysicalType::Struct
+        | PhysicalType::Union
+        | PhysicalType::Map => Err(ArrowError::InvalidArgumentError(format!(
+            "The partition column \"{}\" must not be nested, but is of type {:?}",
+            field.name,
+            field.data_type()
+        ))),
+        _ if field.data_type() == &DataType::Float16 => Err(ArrowError::NotYetImplemented(
+            "Partitioning by a Float16 column".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn partition_column(key: &str, array: &dyn Array) -> Partitions {
+    match array.data_type().to_physical_type() {
+        PhysicalType::Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let array = array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$T>>()
+                .unwrap();
+            partition_dictionary(key, array)
+        }),
+        _ => {
+            let display = get_value_display(array);
+            let mut positions = HashMap::<String, usize>::new();
+            let mut directories = vec![];
+            let indices = (0..array.len())
+                .map(|index| {
+                    let value = array.is_valid(index).then(|| display(index));
+                    let directory = directory(key, value.as_deref());
+                    *positions.entry(directory).or_insert_with_key(|directory| {
+                        directories.push(directory.clone());
+                        directories.len() - 1
+                    })
+                })
+                .collect();
+            (directories, indices)
+        }
+    }
+}
+
+/// Returns `array` taken at `rows` (in increasing order), extending consecutive rows at once.
+fn take_rows(array: &dyn Array, rows: &[usize]) -> Arc<dyn Array> {
+    let mut growable = make_growable(&[array], false, rows.len());
+    let mut start = 0;
+    while start < rows.len() {
+        let mut end = start + 1;
+        while end < rows.len() && rows[end] == rows[end - 1] + 1 {
+            end += 1;
+        }
+        growable.extend(0, rows[start], end - start);
+        start = end;
+    }
+    growable.as_arc()
+}
+
+/// A writer of a directory of parquet files partitioned by the values of some of the columns
+/// of its [`Schema`] (the partition columns).
+///
+/// The rows of each written [`Chunk`] are split by the values of the partition columns, and the
+/// rows of each partition are written (without the partition columns) to the file
+/// `root/key1=value1/.../keyN=valueN/part-0.parquet`, where `key` is the name of a partition
+/// column and `value` its (escaped) value. Null and empty values are written to the directory
+/// `key=__HIVE_DEFAULT_PARTITION__`.
+///
+/// Dictionary-encoded partition columns are split by their keys, so that each value of the
+/// dictionary is only formatted once per chunk.
+///
+/// Each partition is written by a [`FileSink`] and its file is only valid after
+/// [`PartitionedWriter::close`].
+pub struct PartitionedWriter {
+    root: PathBuf,
+    /// the indices of the partition columns in the schema
+    partitions: Vec<usize>,
+    /// the names of the partition columns
+    keys: Vec<String>,
+    /// the indices of the written columns in the schema
+    columns: Vec<usize>,
+    schema: Schema,
+    encodings: Vec<Encoding>,
+    options: WriteOptions,
+    row_group_size: usize,
+    sinks: BTreeMap<PathBuf, FileSink<File>>,
+}
+
+impl PartitionedWriter {
+    /// Creates a new [`PartitionedWriter`] that writes chunks of `schema` to the directory
+    /// `root`, partitioned by `partitions`.
+    /// `encodings` is the encoding of each field of `schema` (those of the partition columns
+    /// are ignored), and `row_group_size` the minimum number of rows of each row group of each file
+    /// (see [`FileSink`]).
+    /// # Errors
+    /// This function errors iff:
+    /// * a partition column does not exist or is nested
+    /// * there are no partition columns or all columns are partition columns
+    /// * the number of `encodings` differs from the number of fields
+    pub fn try_new<P: AsRef<Path>>(
+        root: P,
+        schema: Schema,
+        partitions: Vec<FieldRef>,
+        encodings: Vec<Encoding>,
+        options: WriteOptions,
+        row_group_size: usize,
+    ) -> Result<Self> {
+        if schema.fields.len() != encodings.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The number of encodings ({}) must equal the number of fields ({})",
+                encodings.len(),
+                schema.fields.len()
+            )));
+        }
+        let partitions = partitions
+            .into_iter()
+            .map(|field| {
+                match field {
+                    FieldRef::Index(index) if index < schema.fields.len() => Some(index),
+                    FieldRef::Name(ref name) => schema.fields.iter().position(|f| &f.name == name),
+                    _ => None,
+                }
+                .ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "The partition column {:?} does not exist",
+                        field
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        partitions
+            .iter()
+            .try_for_each(|index| check_partition(&schema.fields[*index]))?;
+        if partitions.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A partitioned writer requires at least one partition column".to_string(),
+            ));
+        }
+
+        let columns = (0..schema.fields.len())
+            .filter(|index| !partitions.contains(index))
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "A partitioned writer requires at least one column that is not a partition column"
+                    .to_string(),
+            ));
+        }
+
+        let keys = partitions
+            .iter()
+            .map(|index| schema.fields[*index].name.clone())
+            .collect();
+        let fields = columns
+            .iter()
+            .map(|index| schema.fields[*index].clone())
+            .collect::<Vec<_>>();
+        let encodings = columns.iter().map(|index| encodings[*index]).collect();
+        Ok(Self {
+            root: root.as_ref().to_path_buf(),
+            partitions,
+            keys,
+            columns,
+            schema: Schema::from(fields).with_metadata(schema.metadata),
+            encodings,
+            options,
+            row_group_size,
+            sinks: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the [`Schema`] of the written files, i.e. without the partition columns.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn sink(&mut self, directory: PathBuf) -> Result<&mut FileSink<File>> {
+        if !self.sinks.contains_key(&directory) {
+            let path = self.root.join(&directory);
+            std::fs::create_dir_all(&path)?;
+            let file = File::create(path.join(FILE_NAME))?;
+            let sink = FileSink::try_new(
+                file,
+                self.schema.clone(),
+                self.encodings.clone(),
+                self.options,
+                self.row_group_size,
+                None,
+            )?;
+            self.sinks.insert(directory.clone(), sink);
+        }
+        Ok(self.sinks.get_mut(&directory).unwrap())
+    }
+
+    /// Splits `chunk` by the values of its partition columns and writes each split to the file
+    /// of its partition, creating it if it does not exist yet.
+    /// # Errors
+    /// This function errors iff the number of arrays of `chunk` differs from the number of
+    /// fields of the schema, a file cannot be created, or writing to a file failed.
+    pub fn write<A: AsRef<dyn Array>>(&mut self, chunk: &Chunk<A>) -> Result<()> {
+        let arrays = chunk.arrays();
+        if arrays.len() != self.partitions.len() + self.columns.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The chunk has {} arrays but the schema has {} fields",
+                arrays.len(),
+                self.partitions.len() + self.columns.len()
+            )));
+        }
+
+        let partitions = self
+            .partitions
+            .iter()
+            .zip(self.keys.iter())
+            .map(|(index, key)| partition_column(key, arrays[*index].as_ref()))
+            .collect::<Vec<_>>();
+
+        // the rows of each combination of partition values, in order of first appearance
+        let mut groups = HashMap::<Vec<usize>, usize>::new();
+        let mut rows: Vec<(Vec<usize>, Vec<usize>)> = vec![];
+        for row in 0..chunk.len() {
+            let key = partitions
+                .iter()
+                .map(|(_, indices)| indices[row])
+                .collect::<Vec<_>>();
+            let group = *groups.entry(key).or_insert_with_key(|key| {
+                rows.push((key.clone(), vec![]));
+                rows.len() - 1
+            });
+            rows[group].1.push(row);
+        }
+
+        for (key, rows) in rows {
+            let directory = partitions
+                .iter()
+                .zip(key)
+                .map(|((directories, _), index)| directories[index].as_str())
+                .collect::<PathBuf>();
+            let arrays = self
+                .columns
+                .iter()
+                .map(|index| take_rows(arrays[*index].as_ref(), &rows))
+                .collect();
+            self.sink(directory)?.write(Chunk::new(arrays))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the footers of all files, returning the path (relative to `root`) and size of
+    /// each written file, ordered by path.
+    /// # Errors
+    /// This function errors iff writing any file failed.
+    pub fn close(self) -> Result<Vec<(PathBuf, u64)>> {
+        self.sinks
+            .into_iter()
+            .map(|(directory, sink)| {
+                let (size, _) = sink.close()?;
+                Ok((directory.join(FILE_NAME), size))
+            })
+            .collect()
+    }
+}
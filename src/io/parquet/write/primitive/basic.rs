@@ -1,5 +1,5 @@
 use parquet2::{
-    encoding::Encoding,
+    encoding::{delta_bitpacked, Encoding},
     metadata::ColumnDescriptor,
     page::DataPage,
     statistics::{serialize_statistics, ParquetStatistics, PrimitiveStatistics, Statistics},
@@ -10,7 +10,7 @@ use parquet2::{
 use super::super::utils;
 use crate::{
     array::{Array, PrimitiveArray},
-    error::Result,
+    error::{ArrowError, Result},
     io::parquet::read::is_type_nullable,
     types::NativeType as ArrowNativeType,
 };
@@ -38,14 +38,34 @@ where
     }
 }
 
+/// Encodes the (non-null if `is_optional`) values of `array` with `DELTA_BINARY_PACKED`.
+fn encode_delta<T, R>(array: &PrimitiveArray<T>, is_optional: bool, buffer: &mut Vec<u8>)
+where
+    T: ArrowNativeType,
+    R: NativeType + num_traits::AsPrimitive<i64>,
+    T: num_traits::AsPrimitive<R>,
+{
+    let to_i64 = |x: &T| {
+        let parquet_native: R = (*x).as_();
+        parquet_native.as_()
+    };
+    let values = if is_optional {
+        array.iter().flatten().map(to_i64).collect::<Vec<i64>>()
+    } else {
+        array.values().iter().map(to_i64).collect::<Vec<i64>>()
+    };
+    delta_bitpacked::encode(values.into_iter(), buffer);
+}
+
 pub fn array_to_page<T, R>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
+    encoding: Encoding,
 ) -> Result<DataPage>
 where
     T: ArrowNativeType,
-    R: NativeType,
+    R: NativeType + num_traits::AsPrimitive<i64>,
     T: num_traits::AsPrimitive<R>,
 {
     let is_optional = is_type_nullable(descriptor.type_());
@@ -63,7 +83,17 @@ where
 
     let definition_levels_byte_length = buffer.len();
 
-    encode_plain(array, is_optional, &mut buffer);
+    match encoding {
+        Encoding::Plain => encode_plain(array, is_optional, &mut buffer),
+        Encoding::DeltaBinaryPacked => encode_delta(array, is_optional, &mut buffer),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Datatype {:?} cannot be encoded by {:?} encoding",
+                array.data_type(),
+                encoding
+            )))
+        }
+    }
 
     let statistics = if options.write_statistics {
         Some(build_statistics(array, descriptor.clone()))
@@ -80,7 +110,7 @@ where
         statistics,
         descriptor,
         options,
-        Encoding::Plain,
+        encoding,
     )
 }
 
@@ -100,6 +130,7 @@ where
         max_value: array
             .iter()
             .flatten()
+            .filter(|x| !is_nan(*x))
             .map(|x| {
                 let x: R = x.as_();
                 x
@@ -108,6 +139,7 @@ where
         min_value: array
             .iter()
             .flatten()
+            .filter(|x| !is_nan(*x))
             .map(|x| {
                 let x: R = x.as_();
                 x
@@ -116,3 +148,10 @@ where
     } as &dyn Statistics;
     serialize_statistics(statistics)
 }
+
+/// Whether `x` is NaN, the only value that is not equal to itself.
+/// The parquet specification requires NaNs to not be part of the min and max statistics.
+#[inline]
+fn is_nan<T: PartialEq>(x: &T) -> bool {
+    x.ne(x)
+}
@@ -7,23 +7,22 @@ use super::super::levels;
 use super::super::utils;
 use super::basic::{build_statistics, encode_plain};
 use crate::{
-    array::{Array, Offset, PrimitiveArray},
+    array::{Array, PrimitiveArray},
     error::Result,
     io::parquet::read::is_type_nullable,
     types::NativeType as ArrowNativeType,
 };
 
-pub fn array_to_page<T, R, O>(
+pub fn array_to_page<T, R>(
     array: &PrimitiveArray<T>,
     options: WriteOptions,
     descriptor: ColumnDescriptor,
-    nested: levels::NestedInfo<O>,
+    nested: levels::NestedInfo,
 ) -> Result<DataPage>
 where
     T: ArrowNativeType,
     R: NativeType,
     T: num_traits::AsPrimitive<R>,
-    O: Offset,
 {
     let is_optional = is_type_nullable(descriptor.type_());
 
@@ -46,7 +45,7 @@ where
 
     utils::build_plain_page(
         buffer,
-        levels::num_values(nested.offsets()),
+        nested.num_values(),
         array.null_count(),
         repetition_levels_byte_length,
         definition_levels_byte_length,
@@ -1,9 +1,12 @@
+use std::sync::Arc;
+
 use parquet2::write::Compressor;
 use parquet2::FallibleStreamingIterator;
 
 use super::{
-    array_to_pages, to_parquet_schema, DynIter, DynStreamingIterator, Encoding, RowGroupIter,
-    SchemaDescriptor, WriteOptions,
+    array_to_pages, compress, to_leaves, to_parquet_schema, ColumnDescriptor, CompressedPage,
+    Compression, DynIter, DynStreamingIterator, Encoding, RowGroupIter, SchemaDescriptor,
+    WriteOptions,
 };
 use crate::{
     array::Array,
@@ -12,6 +15,41 @@ use crate::{
     error::{ArrowError, Result},
 };
 
+/// A task that encodes and compresses the arrays of a parquet column of a row group into
+/// pages.
+pub type EncodeTask = Box<dyn FnOnce() -> Result<Vec<CompressedPage>> + Send>;
+
+/// A function that runs [`EncodeTask`]s (e.g. in parallel) and returns their results, in the
+/// same order as the tasks.
+pub type Encoder = Arc<dyn Fn(Vec<EncodeTask>) -> Vec<Result<Vec<CompressedPage>>>>;
+
+/// A reference to a (top-level) field of a [`Schema`], by name or by index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FieldRef {
+    /// The field with this name
+    Name(String),
+    /// The field at this index
+    Index(usize),
+}
+
+impl From<&str> for FieldRef {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl From<String> for FieldRef {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<usize> for FieldRef {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
 /// An iterator adapter that converts an iterator over [`Chunk`] into an iterator
 /// of row groups.
 /// Use it to create an iterator consumable by the parquet's API.
@@ -20,6 +58,8 @@ pub struct RowGroupIterator<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Re
     options: WriteOptions,
     parquet_schema: SchemaDescriptor,
     encodings: Vec<Encoding>,
+    compressions: Vec<Compression>,
+    encoder: Option<Encoder>,
 }
 
 impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGroupIterator<A, I> {
@@ -33,12 +73,15 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
         assert_eq!(schema.fields.len(), encodings.len());
 
         let parquet_schema = to_parquet_schema(schema)?;
+        let compressions = vec![options.compression; encodings.len()];
 
         Ok(Self {
             iter,
             options,
             parquet_schema,
             encodings,
+            compressions,
+            encoder: None,
         })
     }
 
@@ -46,6 +89,57 @@ impl<A: AsRef<dyn Array> + 'static, I: Iterator<Item = Result<Chunk<A>>>> RowGro
     pub fn parquet_schema(&self) -> &SchemaDescriptor {
         &self.parquet_schema
     }
+
+    fn field_index(&self, field: FieldRef) -> Result<usize> {
+        let fields = self.parquet_schema.fields();
+        match field {
+            FieldRef::Index(index) if index < fields.len() => Some(index),
+            FieldRef::Name(ref name) => fields
+                .iter()
+                .position(|type_| type_.get_basic_info().name() == name.as_str()),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!("The field {:?} does not exist", field))
+        })
+    }
+
+    /// Sets the [`Compression`] of the column chunks of `field`, overriding the compression
+    /// of [`WriteOptions`].
+    /// # Errors
+    /// This function errors iff `field` does not exist.
+    pub fn set_compression<F: Into<FieldRef>>(
+        &mut self,
+        field: F,
+        compression: Compression,
+    ) -> Result<()> {
+        let index = self.field_index(field.into())?;
+        self.compressions[index] = compression;
+        Ok(())
+    }
+
+    /// Sets the [`Encoding`] of `field`, overriding the encoding passed to [`RowGroupIterator::try_new`].
+    /// # Errors
+    /// This function errors iff `field` does not exist.
+    pub fn set_encoding<F: Into<FieldRef>>(&mut self, field: F, encoding: Encoding) -> Result<()> {
+        let index = self.field_index(field.into())?;
+        self.encodings[index] = encoding;
+        Ok(())
+    }
+
+    /// Sets the [`Encoder`] of this iterator, which encodes the parquet columns of each row
+    /// group together, instead of one after the other while they are written.
+    ///
+    /// Each parquet column of a row group is encoded and compressed by an [`EncodeTask`], so
+    /// that an [`Encoder`] can run them in parallel, e.g. with `rayon`:
+    /// ```ignore
+    /// iter.set_encoder(Arc::new(|tasks| tasks.into_par_iter().map(|task| task()).collect()));
+    /// ```
+    /// The pages of each row group are then kept in memory until the row group is written,
+    /// in the order of its columns.
+    pub fn set_encoder(&mut self, encoder: Encoder) {
+        self.encoder = Some(encoder);
+    }
 }
 
 impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chunk<A>>>> Iterator
@@ -57,26 +151,160 @@ impl<A: AsRef<dyn Array> + 'static + Send + Sync, I: Iterator<Item = Result<Chun
         let options = self.options;
 
         self.iter.next().map(|maybe_chunk| {
-            let columns = maybe_chunk?;
-            let encodings = self.encodings.clone();
-            Ok(DynIter::new(
-                columns
-                    .into_arrays()
+            let chunk = maybe_chunk?;
+            if let Some(encoder) = &self.encoder {
+                return encoded_row_group_iter(
+                    &[chunk],
+                    &self.parquet_schema,
+                    options,
+                    &self.encodings,
+                    &self.compressions,
+                    encoder.as_ref(),
+                );
+            }
+            row_group_iter(
+                &[chunk],
+                &self.parquet_schema,
+                options,
+                &self.encodings,
+                &self.compressions,
+            )
+        })
+    }
+}
+
+/// The arrays of a parquet column, with their encoding and compression.
+type Leaves = Vec<(Box<dyn Array>, Encoding, Compression)>;
+
+/// Returns the leaves of each parquet column of `chunks`, one per chunk.
+fn column_leaves<A: AsRef<dyn Array>>(
+    chunks: &[Chunk<A>],
+    parquet_schema: &SchemaDescriptor,
+    encodings: &[Encoding],
+    compressions: &[Compression],
+) -> Vec<Leaves> {
+    let mut columns = (0..parquet_schema.columns().len())
+        .map(|_| Vec::with_capacity(chunks.len()))
+        .collect::<Vec<_>>();
+    chunks.iter().for_each(|chunk| {
+        // the parquet columns of each array, e.g. the keys and values of a map
+        chunk
+            .arrays()
+            .iter()
+            .zip(encodings.iter().zip(compressions.iter()))
+            .flat_map(|(array, (encoding, compression))| {
+                to_leaves(array.as_ref())
                     .into_iter()
-                    .zip(self.parquet_schema.columns().to_vec().into_iter())
-                    .zip(encodings.into_iter())
-                    .map(move |((array, descriptor), encoding)| {
-                        array_to_pages(array.as_ref(), descriptor, options, encoding).map(
-                            move |pages| {
-                                let encoded_pages = DynIter::new(pages.map(|x| Ok(x?)));
-                                let compressed_pages =
-                                    Compressor::new(encoded_pages, options.compression, vec![])
-                                        .map_err(ArrowError::from);
-                                DynStreamingIterator::new(compressed_pages)
-                            },
-                        )
-                    }),
-            ))
+                    .map(move |leaf| (leaf, *encoding, *compression))
+            })
+            .zip(columns.iter_mut())
+            .for_each(|(leaf, column)| column.push(leaf))
+    });
+    columns
+}
+
+/// Returns the row group of `chunks`, each of whose columns has the pages of the arrays of
+/// all `chunks`.
+pub(super) fn row_group_iter<A: AsRef<dyn Array>>(
+    chunks: &[Chunk<A>],
+    parquet_schema: &SchemaDescriptor,
+    options: WriteOptions,
+    encodings: &[Encoding],
+    compressions: &[Compression],
+) -> Result<RowGroupIter<'static, ArrowError>> {
+    let columns = column_leaves(chunks, parquet_schema, encodings, compressions)
+        .into_iter()
+        .zip(parquet_schema.columns().to_vec().into_iter());
+
+    Ok(DynIter::new(columns.map(move |(leaves, descriptor)| {
+        let compression = leaves
+            .first()
+            .map(|(_, _, compression)| *compression)
+            .unwrap_or(options.compression);
+        let pages = leaves
+            .into_iter()
+            .map(|(array, encoding, _)| {
+                array_to_pages(array.as_ref(), descriptor.clone(), options, encoding)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let encoded_pages = DynIter::new(pages.into_iter().flatten().map(|x| Ok(x?)));
+        let compressed_pages =
+            Compressor::new(encoded_pages, compression, vec![]).map_err(ArrowError::from);
+        Ok(DynStreamingIterator::new(compressed_pages))
+    })))
+}
+
+/// Encodes and compresses `leaves` of the parquet column `descriptor` into pages.
+fn encode_column(
+    leaves: Leaves,
+    descriptor: ColumnDescriptor,
+    options: WriteOptions,
+) -> Result<Vec<CompressedPage>> {
+    let compression = leaves
+        .first()
+        .map(|(_, _, compression)| *compression)
+        .unwrap_or(options.compression);
+    let mut pages = vec![];
+    for (array, encoding, _) in leaves {
+        for page in array_to_pages(array.as_ref(), descriptor.clone(), options, encoding)? {
+            pages.push(compress(page?, vec![], compression)?);
+        }
+    }
+    Ok(pages)
+}
+
+/// A [`FallibleStreamingIterator`] over pages that were already compressed.
+struct CompressedPages {
+    pages: std::vec::IntoIter<CompressedPage>,
+    current: Option<CompressedPage>,
+}
+
+impl FallibleStreamingIterator for CompressedPages {
+    type Item = CompressedPage;
+    type Error = ArrowError;
+
+    fn advance(&mut self) -> Result<()> {
+        self.current = self.pages.next();
+        Ok(())
+    }
+
+    fn get(&self) -> Option<&Self::Item> {
+        self.current.as_ref()
+    }
+}
+
+/// Returns the row group of `chunks` like [`row_group_iter`], but whose columns are encoded
+/// and compressed up front by `encoder`.
+pub(super) fn encoded_row_group_iter<A: AsRef<dyn Array>>(
+    chunks: &[Chunk<A>],
+    parquet_schema: &SchemaDescriptor,
+    options: WriteOptions,
+    encodings: &[Encoding],
+    compressions: &[Compression],
+    encoder: &dyn Fn(Vec<EncodeTask>) -> Vec<Result<Vec<CompressedPage>>>,
+) -> Result<RowGroupIter<'static, ArrowError>> {
+    let tasks = column_leaves(chunks, parquet_schema, encodings, compressions)
+        .into_iter()
+        .zip(parquet_schema.columns().iter().cloned())
+        .map(|(leaves, descriptor)| {
+            Box::new(move || encode_column(leaves, descriptor, options)) as EncodeTask
         })
+        .collect::<Vec<_>>();
+    let num_tasks = tasks.len();
+
+    let columns = encoder(tasks).into_iter().collect::<Result<Vec<_>>>()?;
+    if columns.len() != num_tasks {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The encoder must return one result per task ({}), but returned {}",
+            num_tasks,
+            columns.len()
+        )));
     }
+
+    Ok(DynIter::new(columns.into_iter().map(|pages| {
+        Ok(DynStreamingIterator::new(CompressedPages {
+            pages: pages.into_iter(),
+            current: None,
+        }))
+    })))
 }
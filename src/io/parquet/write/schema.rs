@@ -10,6 +10,7 @@ use parquet2::{
 };
 
 use crate::{
+    array::StructArray,
     datatypes::{DataType, Field, Schema, TimeUnit},
     error::{ArrowError, Result},
     io::ipc::write::default_ipc_fields,
@@ -38,6 +39,29 @@ pub fn schema_to_metadata_key(schema: &Schema) -> KeyValue {
     }
 }
 
+/// Returns the parquet key-value metadata of a file of `schema`: `key_value_metadata`, the
+/// metadata of `schema` (unless `key_value_metadata` has the same key) and the serialized
+/// `schema` under `"ARROW:schema"`, from which readers restore the schema and the metadata
+/// of its fields (e.g. extension types).
+pub fn schema_to_key_value_metadata(
+    schema: &Schema,
+    key_value_metadata: Option<Vec<KeyValue>>,
+) -> Vec<KeyValue> {
+    let mut key_value_metadata = key_value_metadata.unwrap_or_default();
+    // a previous schema (e.g. from metadata read from another file) is replaced
+    key_value_metadata.retain(|kv| kv.key != ARROW_SCHEMA_META_KEY);
+    for (key, value) in &schema.metadata {
+        if !key_value_metadata.iter().any(|kv| &kv.key == key) {
+            key_value_metadata.push(KeyValue {
+                key: key.clone(),
+                value: Some(value.clone()),
+            });
+        }
+    }
+    key_value_metadata.push(schema_to_metadata_key(schema));
+    key_value_metadata
+}
+
 /// Creates a [`ParquetType`] from a [`Field`].
 pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
     let name = field.name.clone();
@@ -343,6 +367,34 @@ pub fn to_parquet_type(field: &Field) -> Result<ParquetType> {
                 None,
             )?)
         }
+        DataType::Map(f, _) => {
+            let fields = StructArray::get_fields(f.data_type());
+            if fields.first().map(|key| key.is_nullable).unwrap_or(true) {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Parquet requires the keys of a map to be required".to_string(),
+                ));
+            }
+            // the entries are a repeated group of the key and the value
+            let fields = fields
+                .iter()
+                .map(to_parquet_type)
+                .collect::<Result<Vec<_>>>()?;
+            Ok(ParquetType::try_from_group(
+                name,
+                repetition,
+                None,
+                Some(LogicalType::MAP(Default::default())),
+                vec![ParquetType::try_from_group(
+                    f.name.clone(),
+                    Repetition::Repeated,
+                    None,
+                    None,
+                    fields,
+                    None,
+                )?],
+                None,
+            )?)
+        }
         other => Err(ArrowError::NotYetImplemented(format!(
             "Writing the data type {:?} is not yet implemented",
             other
@@ -0,0 +1,272 @@
+use std::io::{Read, Seek, Write};
+use std::sync::mpsc::{sync_channel, SyncSender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+use crate::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Schema,
+    error::{ArrowError, Result},
+    io::parquet::bloom_filter::{self, BloomFilterOptions},
+};
+
+use super::{
+    record_batch::row_group_iter, to_parquet_schema, write_file, Compression, Encoding, KeyValue,
+    ParquetType, WriteOptions,
+};
+
+type Chunks = Vec<Chunk<Arc<dyn Array>>>;
+
+/// The bloom filters of the row groups written by a [`FileSink`].
+struct BloomFilters<W> {
+    options: BloomFilterOptions,
+    /// the index of the column chunk of each field of `options`
+    columns: Vec<usize>,
+    num_columns: usize,
+    bitsets: Vec<Vec<Option<Vec<u8>>>>,
+    /// writes `bitsets` to the file, once its footer is written
+    write: fn(&mut W, &[Vec<Option<Vec<u8>>>]) -> Result<u64>,
+}
+
+fn count_columns(type_: &ParquetType) -> usize {
+    match type_ {
+        ParquetType::PrimitiveType { .. } => 1,
+        ParquetType::GroupType { fields, .. } => fields.iter().map(count_columns).sum(),
+    }
+}
+
+/// A writer of parquet files that is fed [`Chunk`]s one at a time, as opposed to
+/// [`write_file`], that requires an iterator over all row groups up front.
+///
+/// Chunks are buffered until they have at least `row_group_size` rows, and are then written
+/// as a row group whose column chunks have one page per chunk. The file is written by a
+/// background thread, and its footer is written on [`FileSink::close`].
+///
+/// Columns encoded with a dictionary should be written with a `row_group_size` of one,
+/// since a column chunk can only have one dictionary page.
+///
+/// When created with [`FileSink::try_new_with_bloom_filters`], a bloom filter of each column
+/// chunk of the selected fields is built as its row group is written, and the filters are
+/// written after the column chunks on [`FileSink::close`].
+pub struct FileSink<W: Write + Send + 'static> {
+    sender: Option<SyncSender<Chunks>>,
+    handle: Option<JoinHandle<Result<(u64, W)>>>,
+    buffer: Chunks,
+    buffered_rows: usize,
+    row_group_size: usize,
+    schema: Schema,
+    bloom_filters: Option<BloomFilters<W>>,
+}
+
+impl<W: Write + Send + 'static> FileSink<W> {
+    /// Creates a new [`FileSink`] that writes a parquet file of `schema` to `writer`.
+    /// `encodings` is the encoding of each field of `schema`.
+    /// # Errors
+    /// This function errors iff `schema` cannot be converted to a parquet schema or
+    /// the number of `encodings` differs from the number of fields.
+    pub fn try_new(
+        mut writer: W,
+        schema: Schema,
+        encodings: Vec<Encoding>,
+        options: WriteOptions,
+        row_group_size: usize,
+        key_value_metadata: Option<Vec<KeyValue>>,
+    ) -> Result<Self> {
+        if schema.fields.len() != encodings.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The number of encodings ({}) must equal the number of fields ({})",
+                encodings.len(),
+                schema.fields.len()
+            )));
+        }
+        let parquet_schema = to_parquet_schema(&schema)?;
+        let compressions: Vec<Compression> = vec![options.compression; encodings.len()];
+
+        // a single row group is buffered on the channel, so that at most two are in memory
+        let (sender, receiver) = sync_channel::<Chunks>(1);
+
+        let thread_schema = schema.clone();
+        let handle = std::thread::spawn(move || {
+            let row_groups = receiver.into_iter().map(|chunks| {
+                row_group_iter(&chunks, &parquet_schema, options, &encodings, &compressions)
+            });
+            let size = write_file(
+                &mut writer,
+                row_groups,
+                &thread_schema,
+                parquet_schema.clone(),
+                options,
+                key_value_metadata,
+            )?;
+            Ok((size, writer))
+        });
+
+        Ok(Self {
+            sender: Some(sender),
+            handle: Some(handle),
+            buffer: vec![],
+            buffered_rows: 0,
+            row_group_size,
+            schema,
+            bloom_filters: None,
+        })
+    }
+
+    /// Returns the [`Schema`] of this [`FileSink`].
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Writes `chunk` to the file, buffering it until its row group is full.
+    /// # Errors
+    /// This function errors iff the number of arrays of `chunk` differs from the number of
+    /// fields of the schema, or writing a previous row group failed.
+    pub fn write(&mut self, chunk: Chunk<Arc<dyn Array>>) -> Result<()> {
+        if chunk.arrays().len() != self.schema.fields.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The chunk has {} arrays but the schema has {} fields",
+                chunk.arrays().len(),
+                self.schema.fields.len()
+            )));
+        }
+        self.buffered_rows += chunk.len();
+        self.buffer.push(chunk);
+        if self.buffered_rows >= self.row_group_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the buffered chunks as a row group, even if it is not full.
+    /// # Errors
+    /// This function errors iff writing this or a previous row group failed.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunks = std::mem::take(&mut self.buffer);
+        self.buffered_rows = 0;
+        if let Some(bloom_filters) = self.bloom_filters.as_mut() {
+            let mut bitsets = vec![None; bloom_filters.num_columns];
+            for (&field, &column) in bloom_filters
+                .options
+                .fields
+                .iter()
+                .zip(bloom_filters.columns.iter())
+            {
+                let arrays = chunks
+                    .iter()
+                    .map(|chunk| chunk.arrays()[field].clone())
+                    .collect::<Vec<_>>();
+                bitsets[column] = Some(bloom_filter::build(&arrays, bloom_filters.options.fpp)?);
+            }
+            bloom_filters.bitsets.push(bitsets);
+        }
+        let sent = self.sender.as_ref().map(|sender| sender.send(chunks));
+        if let Some(Err(_)) = sent {
+            // the writing thread stopped, which only happens on errors
+            self.join()?;
+            return Err(ArrowError::InvalidArgumentError(
+                "The parquet file was already closed".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Writes the buffered chunks, the bloom filters (if any) and the footer of the file,
+    /// returning the size of the file and the writer.
+    /// # Errors
+    /// This function errors iff writing any row group, the bloom filters or the footer failed.
+    pub fn close(mut self) -> Result<(u64, W)> {
+        self.flush()?;
+        let (size, mut writer) = self.join()?;
+        if let Some(bloom_filters) = self.bloom_filters.take() {
+            let size = (bloom_filters.write)(&mut writer, &bloom_filters.bitsets)?;
+            return Ok((size, writer));
+        }
+        Ok((size, writer))
+    }
+
+    fn join(&mut self) -> Result<(u64, W)> {
+        // dropping the sender ends the iterator of row groups of the writing thread
+        self.sender = None;
+        let handle = self.handle.take().ok_or_else(|| {
+            ArrowError::InvalidArgumentError("The parquet file was already closed".to_string())
+        })?;
+        match handle.join() {
+            Ok(result) => result,
+            Err(e) => std::panic::resume_unwind(e),
+        }
+    }
+}
+
+impl<W: Read + Write + Seek + Send + 'static> FileSink<W> {
+    /// Creates a new [`FileSink`] like [`FileSink::try_new`] that also writes a split-block
+    /// bloom filter of each column chunk of the fields of `bloom_filters`.
+    /// # Errors
+    /// This function errors iff [`FileSink::try_new`] errors, or a field of `bloom_filters`
+    /// does not exist or is not supported by bloom filters (see [`bloom_filter::is_supported`]),
+    /// or its false positive probability is not in `(0, 1)`.
+    pub fn try_new_with_bloom_filters(
+        writer: W,
+        schema: Schema,
+        encodings: Vec<Encoding>,
+        options: WriteOptions,
+        row_group_size: usize,
+        key_value_metadata: Option<Vec<KeyValue>>,
+        bloom_filters: BloomFilterOptions,
+    ) -> Result<Self> {
+        if !(bloom_filters.fpp > 0.0 && bloom_filters.fpp < 1.0) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The false positive probability of bloom filters must be in (0, 1), got {}",
+                bloom_filters.fpp
+            )));
+        }
+        let parquet_schema = to_parquet_schema(&schema)?;
+        // the index of the first column chunk of each field
+        let mut first_columns = Vec::with_capacity(schema.fields.len());
+        let mut num_columns = 0;
+        for type_ in parquet_schema.fields() {
+            first_columns.push(num_columns);
+            num_columns += count_columns(type_);
+        }
+        let columns = bloom_filters
+            .fields
+            .iter()
+            .map(|&field| {
+                let data_type = schema.fields.get(field).map(|field| field.data_type());
+                match data_type {
+                    Some(data_type) if bloom_filter::is_supported(data_type) => {
+                        Ok(first_columns[field])
+                    }
+                    Some(data_type) => Err(ArrowError::InvalidArgumentError(format!(
+                        "Bloom filters of fields of type {:?} are not supported",
+                        data_type
+                    ))),
+                    None => Err(ArrowError::InvalidArgumentError(format!(
+                        "The schema has no field {} to write a bloom filter of",
+                        field
+                    ))),
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut sink = Self::try_new(
+            writer,
+            schema,
+            encodings,
+            options,
+            row_group_size,
+            key_value_metadata,
+        )?;
+        sink.bloom_filters = Some(BloomFilters {
+            options: bloom_filters,
+            columns,
+            num_columns,
+            bitsets: vec![],
+            write: bloom_filter::write::<W>,
+        });
+        Ok(sink)
+    }
+}
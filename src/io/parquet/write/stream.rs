@@ -12,7 +12,7 @@ use parquet2::{
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
-use super::schema::schema_to_metadata_key;
+use super::schema::schema_to_key_value_metadata;
 use super::WriteOptions;
 
 /// Writes
@@ -29,12 +29,7 @@ where
     F: Future<Output = std::result::Result<RowGroupIter<'a, ArrowError>, ArrowError>>,
     S: Stream<Item = F>,
 {
-    let key_value_metadata = key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(&schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(&schema)]));
+    let key_value_metadata = Some(schema_to_key_value_metadata(&schema, key_value_metadata));
 
     let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
     Ok(parquet_write_stream(
@@ -62,12 +57,7 @@ where
     F: Future<Output = std::result::Result<RowGroupIter<'a, ArrowError>, ArrowError>>,
     S: Stream<Item = F>,
 {
-    let key_value_metadata = key_value_metadata
-        .map(|mut x| {
-            x.push(schema_to_metadata_key(schema));
-            x
-        })
-        .or_else(|| Some(vec![schema_to_metadata_key(schema)]));
+    let key_value_metadata = Some(schema_to_key_value_metadata(schema, key_value_metadata));
 
     let created_by = Some("Arrow2 - Native Rust implementation of Arrow".to_string());
     Ok(parquet_write_stream_stream(
@@ -0,0 +1,219 @@
+//! APIs to read from sources that support reading arbitrary ranges of bytes, such as files,
+//! in-memory buffers and object stores.
+//!
+//! The parquet and IPC file readers only read the ranges they need (e.g. the footer and the
+//! column chunks of the selected columns). Wrapping a [`RangeReader`] in a [`ReadableFile`]
+//! allows them to read from any such source without first copying it into memory:
+//! ```
+//! use std::io::Read;
+//! use arrow2::io::range::{FnRangeReader, ReadableFile};
+//!
+//! let data = b"arrow2".to_vec();
+//! // e.g. a closure issuing ranged requests to an object store
+//! let source = FnRangeReader::new(data.len() as u64, move |offset, buf: &mut [u8]| {
+//!     let start = offset as usize;
+//!     buf.copy_from_slice(&data[start..start + buf.len()]);
+//!     Ok(())
+//! });
+//!
+//! let mut file = ReadableFile::new(source);
+//! let mut buffer = String::new();
+//! file.read_to_string(&mut buffer).unwrap();
+//! assert_eq!(buffer, "arrow2");
+//! ```
+use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom};
+
+/// A source of bytes that supports reading arbitrary ranges of it.
+pub trait RangeReader {
+    /// Returns the total number of bytes of the source.
+    fn length(&mut self) -> Result<u64>;
+
+    /// Reads exactly `buf.len()` bytes starting at `offset` into `buf`.
+    /// # Errors
+    /// Implementations must error if the range is out of bounds of the source.
+    fn read_range(&mut self, offset: u64, buf: &mut [u8]) -> Result<()>;
+}
+
+// there is no file system on wasm32
+#[cfg(not(target_arch = "wasm32"))]
+impl RangeReader for std::fs::File {
+    fn length(&mut self) -> Result<u64> {
+        Ok(self.metadata()?.len())
+    }
+
+    fn read_range(&mut self, offset: u64, buf: &mut [u8]) -> Result<()> {
+        self.seek(SeekFrom::Start(offset))?;
+        self.read_exact(buf)
+    }
+}
+
+/// Copies the range of `data` starting at `offset` into `buf`.
+fn read_slice(data: &[u8], offset: u64, buf: &mut [u8]) -> Result<()> {
+    let start = usize::try_from(offset).unwrap_or(usize::MAX);
+    let range = data
+        .get(start..)
+        .and_then(|data| data.get(..buf.len()))
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "The range {}..{} is out of bounds of a source of {} bytes",
+                    offset,
+                    offset + buf.len() as u64,
+                    data.len()
+                ),
+            )
+        })?;
+    buf.copy_from_slice(range);
+    Ok(())
+}
+
+impl RangeReader for &[u8] {
+    fn length(&mut self) -> Result<u64> {
+        Ok(self.len() as u64)
+    }
+
+    fn read_range(&mut self, offset: u64, buf: &mut [u8]) -> Result<()> {
+        read_slice(self, offset, buf)
+    }
+}
+
+impl RangeReader for Vec<u8> {
+    fn length(&mut self) -> Result<u64> {
+        Ok(self.len() as u64)
+    }
+
+    fn read_range(&mut self, offset: u64, buf: &mut [u8]) -> Result<()> {
+        read_slice(self, offset, buf)
+    }
+}
+
+/// A [`RangeReader`] of a source of known length whose ranges are read by a closure, e.g.
+/// one issuing ranged requests to an object store.
+pub struct FnRangeReader<F: FnMut(u64, &mut [u8]) -> Result<()>> {
+    length: u64,
+    read: F,
+}
+
+impl<F: FnMut(u64, &mut [u8]) -> Result<()>> FnRangeReader<F> {
+    /// Creates a new [`FnRangeReader`] of a source of `length` bytes, whose ranges are read by
+    /// `read(offset, buf)`, which must fill `buf` with the bytes starting at `offset`.
+    pub fn new(length: u64, read: F) -> Self {
+        Self { length, read }
+    }
+}
+
+impl<F: FnMut(u64, &mut [u8]) -> Result<()>> RangeReader for FnRangeReader<F> {
+    fn length(&mut self) -> Result<u64> {
+        Ok(self.length)
+    }
+
+    fn read_range(&mut self, offset: u64, buf: &mut [u8]) -> Result<()> {
+        if offset + buf.len() as u64 > self.length {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "The range {}..{} is out of bounds of a source of {} bytes",
+                    offset,
+                    offset + buf.len() as u64,
+                    self.length
+                ),
+            ));
+        }
+        (self.read)(offset, buf)
+    }
+}
+
+/// An adapter implementing [`Read`] and [`Seek`] over a [`RangeReader`], so that it can be used
+/// by the readers of this crate (e.g. the parquet and IPC file readers).
+///
+/// Each call to [`Read::read`] reads a single range of the source. Readers that issue many small
+/// reads (e.g. of a message's length) should be wrapped in a [`std::io::BufReader`].
+pub struct ReadableFile<R: RangeReader> {
+    reader: R,
+    position: u64,
+    /// the length of the source, read on first use
+    length: Option<u64>,
+}
+
+impl<R: RangeReader> ReadableFile<R> {
+    /// Creates a new [`ReadableFile`] positioned at the start of `reader`.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            position: 0,
+            length: None,
+        }
+    }
+
+    /// Returns the current position in the source.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the total number of bytes of the source.
+    pub fn length(&mut self) -> Result<u64> {
+        match self.length {
+            Some(length) => Ok(length),
+            None => {
+                let length = self.reader.length()?;
+                self.length = Some(length);
+                Ok(length)
+            }
+        }
+    }
+
+    /// Returns a reference to the underlying [`RangeReader`].
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns the underlying [`RangeReader`].
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: RangeReader> Read for ReadableFile<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let remaining = self.length()?.saturating_sub(self.position);
+        let length = (buf.len() as u64).min(remaining) as usize;
+        if length == 0 {
+            return Ok(0);
+        }
+        self.reader.read_range(self.position, &mut buf[..length])?;
+        self.position += length as u64;
+        Ok(length)
+    }
+}
+
+impl<R: RangeReader> Seek for ReadableFile<R> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => {
+                let length = self.length()?;
+                if offset < 0 {
+                    length.checked_sub(offset.unsigned_abs())
+                } else {
+                    length.checked_add(offset as u64)
+                }
+            }
+            SeekFrom::Current(offset) => {
+                if offset < 0 {
+                    self.position.checked_sub(offset.unsigned_abs())
+                } else {
+                    self.position.checked_add(offset as u64)
+                }
+            }
+        };
+        let position = position.ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        self.position = position;
+        Ok(position)
+    }
+}
@@ -0,0 +1,440 @@
+//! Conversions between [`Value`]s and arrays.
+use std::sync::Arc;
+
+use num_traits::NumCast;
+
+use crate::array::*;
+use crate::bitmap::{Bitmap, MutableBitmap};
+use crate::datatypes::{DataType, Field, PhysicalType, PrimitiveType};
+use crate::error::{ArrowError, Result};
+use crate::types::NativeType;
+
+use super::Value;
+
+/// Takes the value of the entry `name` of `entries`, or [`Value::Null`] if there is none.
+/// `index` is the expected position of the entry, which is checked first.
+pub(super) fn take_entry(entries: &mut [(String, Value)], index: usize, name: &str) -> Value {
+    let entry = match entries.get_mut(index) {
+        Some(entry) if entry.0 == name => Some(entry),
+        _ => entries.iter_mut().find(|(key, _)| key == name),
+    };
+    entry
+        .map(|(_, value)| std::mem::take(value))
+        .unwrap_or_default()
+}
+
+fn unexpected(value: &Value, data_type: &DataType) -> ArrowError {
+    ArrowError::InvalidArgumentError(format!(
+        "Cannot convert the value {:?} to an array of {:?}",
+        value, data_type
+    ))
+}
+
+fn to_primitive<T: NativeType + NumCast>(
+    values: Vec<Value>,
+    data_type: &DataType,
+) -> Result<Box<dyn Array>> {
+    let cast = |value: Value| match value {
+        Value::Null => Ok(None),
+        Value::I64(v) => T::from(v)
+            .map(Some)
+            .ok_or_else(|| unexpected(&value, data_type)),
+        Value::U64(v) => T::from(v)
+            .map(Some)
+            .ok_or_else(|| unexpected(&value, data_type)),
+        Value::F64(v) => T::from(v)
+            .map(Some)
+            .ok_or_else(|| unexpected(&value, data_type)),
+        other => Err(unexpected(&other, data_type)),
+    };
+    let array = values
+        .into_iter()
+        .map(cast)
+        .collect::<Result<PrimitiveArray<T>>>()?;
+    Ok(Box::new(array.to(data_type.clone())))
+}
+
+fn to_utf8<O: Offset>(values: Vec<Value>, data_type: &DataType) -> Result<Box<dyn Array>> {
+    let mut array = MutableUtf8Array::<O>::with_capacity(values.len());
+    for value in values {
+        match value {
+            Value::Null => array.push::<&str>(None),
+            Value::String(v) => array.try_push(Some(v))?,
+            other => return Err(unexpected(&other, data_type)),
+        }
+    }
+    let array: Utf8Array<O> = array.into();
+    Ok(Box::new(array))
+}
+
+/// Returns the bytes of `value`, that is either [`Value::Bytes`] or a sequence of bytes.
+fn to_bytes(value: Value, data_type: &DataType) -> Result<Option<Vec<u8>>> {
+    match value {
+        Value::Null => Ok(None),
+        Value::Bytes(v) => Ok(Some(v)),
+        Value::Seq(items) => items
+            .into_iter()
+            .map(|item| match item {
+                Value::I64(v) => u8::try_from(v).map_err(|_| unexpected(&item, data_type)),
+                Value::U64(v) => u8::try_from(v).map_err(|_| unexpected(&item, data_type)),
+                other => Err(unexpected(&other, data_type)),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Some),
+        other => Err(unexpected(&other, data_type)),
+    }
+}
+
+fn to_binary<O: Offset>(values: Vec<Value>, data_type: &DataType) -> Result<Box<dyn Array>> {
+    let mut array = MutableBinaryArray::<O>::with_capacity(values.len());
+    for value in values {
+        array.try_push(to_bytes(value, data_type)?)?;
+    }
+    let array: BinaryArray<O> = array.into();
+    Ok(Box::new(array))
+}
+
+fn to_fixed_size_binary(values: Vec<Value>, data_type: &DataType) -> Result<Box<dyn Array>> {
+    let size = FixedSizeBinaryArray::get_size(data_type);
+    let values = values
+        .into_iter()
+        .map(|value| to_bytes(value, data_type))
+        .collect::<Result<Vec<_>>>()?;
+    let array = FixedSizeBinaryArray::try_from_iter(values, size)?;
+    Ok(Box::new(array.to(data_type.clone())))
+}
+
+/// Returns the items of `value`, that is either [`Value::Seq`] or [`Value::Bytes`].
+fn to_items(value: Value, data_type: &DataType) -> Result<Option<Vec<Value>>> {
+    match value {
+        Value::Null => Ok(None),
+        Value::Seq(items) => Ok(Some(items)),
+        Value::Bytes(bytes) => Ok(Some(
+            bytes.into_iter().map(|x| Value::U64(x as u64)).collect(),
+        )),
+        other => Err(unexpected(&other, data_type)),
+    }
+}
+
+fn to_validity(validity: MutableBitmap) -> Option<Bitmap> {
+    let validity: Bitmap = validity.into();
+    if validity.null_count() > 0 {
+        Some(validity)
+    } else {
+        None
+    }
+}
+
+fn to_list<O: Offset>(values: Vec<Value>, data_type: &DataType) -> Result<Box<dyn Array>> {
+    let child = ListArray::<O>::get_child_type(data_type);
+
+    let mut offsets = Vec::<O>::with_capacity(values.len() + 1);
+    offsets.push(O::default());
+    let mut validity = MutableBitmap::with_capacity(values.len());
+    let mut child_values = vec![];
+    for value in values {
+        let items = to_items(value, data_type)?;
+        validity.push(items.is_some());
+        child_values.extend(items.unwrap_or_default());
+        let offset = O::from_usize(child_values.len()).ok_or(ArrowError::Overflow)?;
+        offsets.push(offset);
+    }
+
+    let child = values_to_array(child_values, child)?;
+    Ok(Box::new(ListArray::<O>::from_data(
+        data_type.clone(),
+        offsets.into(),
+        child.into(),
+        to_validity(validity),
+    )))
+}
+
+fn to_fixed_size_list(values: Vec<Value>, data_type: &DataType) -> Result<Box<dyn Array>> {
+    let (child, size) = FixedSizeListArray::get_child_and_size(data_type);
+
+    let mut validity = MutableBitmap::with_capacity(values.len());
+    let mut child_values = Vec::with_capacity(values.len() * size);
+    for value in values {
+        match to_items(value, data_type)? {
+            Some(items) => {
+                if items.len() != size {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Cannot convert a sequence of {} items to an array of {:?}",
+                        items.len(),
+                        data_type
+                    )));
+                }
+                validity.push(true);
+                child_values.extend(items);
+            }
+            None => {
+                validity.push(false);
+                child_values.extend(std::iter::repeat_with(Value::default).take(size));
+            }
+        }
+    }
+
+    let child = values_to_array(child_values, child.data_type())?;
+    Ok(Box::new(FixedSizeListArray::from_data(
+        data_type.clone(),
+        child.into(),
+        to_validity(validity),
+    )))
+}
+
+fn to_struct(values: Vec<Value>, data_type: &DataType) -> Result<Box<dyn Array>> {
+    let fields = StructArray::get_fields(data_type);
+
+    let mut validity = MutableBitmap::with_capacity(values.len());
+    let mut children = vec![Vec::with_capacity(values.len()); fields.len()];
+    for value in values {
+        match value {
+            Value::Null => {
+                validity.push(false);
+                children
+                    .iter_mut()
+                    .for_each(|child| child.push(Value::Null));
+            }
+            Value::Map(mut entries) => {
+                validity.push(true);
+                for (index, (field, child)) in fields.iter().zip(children.iter_mut()).enumerate() {
+                    child.push(take_entry(&mut entries, index, &field.name));
+                }
+            }
+            other => return Err(unexpected(&other, data_type)),
+        }
+    }
+
+    let children = fields
+        .iter()
+        .zip(children)
+        .map(|(field, values)| values_to_array(values, field.data_type()).map(Arc::from))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(StructArray::from_data(
+        data_type.clone(),
+        children,
+        to_validity(validity),
+    )))
+}
+
+/// Returns an [`Array`] of `data_type` with `values`.
+/// # Errors
+/// This function errors iff a value cannot be represented by `data_type` or if `data_type`
+/// is not yet supported.
+pub(super) fn values_to_array(values: Vec<Value>, data_type: &DataType) -> Result<Box<dyn Array>> {
+    use PhysicalType::*;
+    match data_type.to_physical_type() {
+        Null => Ok(Box::new(NullArray::from_data(
+            data_type.clone(),
+            values.len(),
+        ))),
+        Boolean => {
+            let array = values
+                .into_iter()
+                .map(|value| match value {
+                    Value::Null => Ok(None),
+                    Value::Bool(v) => Ok(Some(v)),
+                    other => Err(unexpected(&other, data_type)),
+                })
+                .collect::<Result<BooleanArray>>()?;
+            Ok(Box::new(array))
+        }
+        Primitive(PrimitiveType::Int8) => to_primitive::<i8>(values, data_type),
+        Primitive(PrimitiveType::Int16) => to_primitive::<i16>(values, data_type),
+        Primitive(PrimitiveType::Int32) => to_primitive::<i32>(values, data_type),
+        Primitive(PrimitiveType::Int64) => to_primitive::<i64>(values, data_type),
+        Primitive(PrimitiveType::UInt8) => to_primitive::<u8>(values, data_type),
+        Primitive(PrimitiveType::UInt16) => to_primitive::<u16>(values, data_type),
+        Primitive(PrimitiveType::UInt32) => to_primitive::<u32>(values, data_type),
+        Primitive(PrimitiveType::UInt64) => to_primitive::<u64>(values, data_type),
+        Primitive(PrimitiveType::Float32) => to_primitive::<f32>(values, data_type),
+        Primitive(PrimitiveType::Float64) => to_primitive::<f64>(values, data_type),
+        Utf8 => to_utf8::<i32>(values, data_type),
+        LargeUtf8 => to_utf8::<i64>(values, data_type),
+        Binary => to_binary::<i32>(values, data_type),
+        LargeBinary => to_binary::<i64>(values, data_type),
+        FixedSizeBinary => to_fixed_size_binary(values, data_type),
+        List => to_list::<i32>(values, data_type),
+        LargeList => to_list::<i64>(values, data_type),
+        FixedSizeList => to_fixed_size_list(values, data_type),
+        Struct => to_struct(values, data_type),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Converting values to an array of {:?}",
+            data_type
+        ))),
+    }
+}
+
+/// Returns an [`Array`] of the [`Field`]'s data type with `values`.
+/// # Errors
+/// This function errors iff [`values_to_array`] errors or the array has null values and
+/// `field` is not nullable.
+pub(super) fn values_to_field(values: Vec<Value>, field: &Field) -> Result<Box<dyn Array>> {
+    let array = values_to_array(values, field.data_type())?;
+    if !field.is_nullable && array.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The field \"{}\" is not nullable but has {} null values",
+            field.name,
+            array.null_count()
+        )));
+    }
+    Ok(array)
+}
+
+fn from_primitive<T: NativeType>(array: &dyn Array, f: fn(T) -> Value) -> Vec<Value> {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    array
+        .iter()
+        .map(|x| x.map(|x| f(*x)).unwrap_or_default())
+        .collect()
+}
+
+fn from_utf8<O: Offset>(array: &dyn Array) -> Vec<Value> {
+    let array = array.as_any().downcast_ref::<Utf8Array<O>>().unwrap();
+    array
+        .iter()
+        .map(|x| x.map(|x| Value::String(x.to_string())).unwrap_or_default())
+        .collect()
+}
+
+fn from_binary<O: Offset>(array: &dyn Array) -> Vec<Value> {
+    let array = array.as_any().downcast_ref::<BinaryArray<O>>().unwrap();
+    array
+        .iter()
+        .map(|x| x.map(|x| Value::Bytes(x.to_vec())).unwrap_or_default())
+        .collect()
+}
+
+/// Splits `values` in sequences delimited by `offsets`, or [`Value::Null`] when not valid.
+fn split<O: Offset>(values: Vec<Value>, offsets: &[O], array: &dyn Array) -> Vec<Value> {
+    let mut values = values.into_iter();
+    let start = offsets[0].to_usize();
+    // skip the values before the first offset (e.g. of a sliced array)
+    values.by_ref().take(start).for_each(drop);
+    offsets
+        .windows(2)
+        .enumerate()
+        .map(|(i, window)| {
+            let length = (window[1] - window[0]).to_usize();
+            let items = values.by_ref().take(length).collect();
+            if array.is_valid(i) {
+                Value::Seq(items)
+            } else {
+                Value::Null
+            }
+        })
+        .collect()
+}
+
+fn from_list<O: Offset>(array: &dyn Array) -> Result<Vec<Value>> {
+    let array = array.as_any().downcast_ref::<ListArray<O>>().unwrap();
+    let values = array_to_values(array.values().as_ref())?;
+    Ok(split(values, array.offsets(), array))
+}
+
+fn from_fixed_size_list(array: &dyn Array) -> Result<Vec<Value>> {
+    let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let size = FixedSizeListArray::get_child_and_size(array.data_type()).1;
+    let mut values = array_to_values(array.values().as_ref())?.into_iter();
+    Ok((0..array.len())
+        .map(|i| {
+            let items = values.by_ref().take(size).collect();
+            if array.is_valid(i) {
+                Value::Seq(items)
+            } else {
+                Value::Null
+            }
+        })
+        .collect())
+}
+
+fn from_struct(array: &dyn Array) -> Result<Vec<Value>> {
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    let mut children = array
+        .values()
+        .iter()
+        .map(|child| array_to_values(child.as_ref()).map(|x| x.into_iter()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((0..array.len())
+        .map(|i| {
+            let entries = array
+                .fields()
+                .iter()
+                .zip(children.iter_mut())
+                .map(|(field, child)| (field.name.clone(), child.next().unwrap()))
+                .collect();
+            if array.is_valid(i) {
+                Value::Map(entries)
+            } else {
+                Value::Null
+            }
+        })
+        .collect())
+}
+
+fn from_dictionary<K: DictionaryKey>(array: &dyn Array) -> Result<Vec<Value>> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = array_to_values(array.values().as_ref())?;
+    Ok(array
+        .keys()
+        .iter()
+        .map(|key| {
+            key.map(|key| values[key.to_usize().unwrap()].clone())
+                .unwrap_or_default()
+        })
+        .collect())
+}
+
+/// Returns the [`Value`]s of `array`.
+/// # Errors
+/// This function errors iff the data type of `array` is not yet supported.
+pub(super) fn array_to_values(array: &dyn Array) -> Result<Vec<Value>> {
+    use PhysicalType::*;
+    Ok(match array.data_type().to_physical_type() {
+        Null => vec![Value::Null; array.len()],
+        Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array
+                .iter()
+                .map(|x| x.map(Value::Bool).unwrap_or_default())
+                .collect()
+        }
+        Primitive(PrimitiveType::Int8) => from_primitive::<i8>(array, |x| Value::I64(x as i64)),
+        Primitive(PrimitiveType::Int16) => from_primitive::<i16>(array, |x| Value::I64(x as i64)),
+        Primitive(PrimitiveType::Int32) => from_primitive::<i32>(array, |x| Value::I64(x as i64)),
+        Primitive(PrimitiveType::Int64) => from_primitive::<i64>(array, Value::I64),
+        Primitive(PrimitiveType::UInt8) => from_primitive::<u8>(array, |x| Value::U64(x as u64)),
+        Primitive(PrimitiveType::UInt16) => from_primitive::<u16>(array, |x| Value::U64(x as u64)),
+        Primitive(PrimitiveType::UInt32) => from_primitive::<u32>(array, |x| Value::U64(x as u64)),
+        Primitive(PrimitiveType::UInt64) => from_primitive::<u64>(array, Value::U64),
+        Primitive(PrimitiveType::Float32) => from_primitive::<f32>(array, |x| Value::F64(x as f64)),
+        Primitive(PrimitiveType::Float64) => from_primitive::<f64>(array, Value::F64),
+        Utf8 => from_utf8::<i32>(array),
+        LargeUtf8 => from_utf8::<i64>(array),
+        Binary => from_binary::<i32>(array),
+        LargeBinary => from_binary::<i64>(array),
+        FixedSizeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            array
+                .iter()
+                .map(|x| x.map(|x| Value::Bytes(x.to_vec())).unwrap_or_default())
+                .collect()
+        }
+        List => from_list::<i32>(array)?,
+        LargeList => from_list::<i64>(array)?,
+        FixedSizeList => from_fixed_size_list(array)?,
+        Struct => from_struct(array)?,
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            from_dictionary::<$T>(array)?
+        }),
+        _ => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Converting an array of {:?} to values",
+                array.data_type()
+            )))
+        }
+    })
+}
@@ -0,0 +1,170 @@
+//! Convert between types implementing [`serde::Serialize`] and [`serde::Deserialize`] and arrow,
+//! treating arrow as a serde data format.
+//!
+//! A slice of rows (e.g. a `#[derive(Serialize, Deserialize)]` struct per record) is converted to
+//! a [`Chunk`] with one column per [`Field`] via [`serialize`], and back via [`deserialize`].
+//! The fields of a row are matched to the [`Field`]s by name:
+//! * [`Option`]s and missing fields are null values
+//! * sequences (e.g. [`Vec`]) are `List`, `LargeList` or `FixedSizeList`
+//! * nested structs and maps with string keys are `Struct`
+//! * bytes are `Binary`, `LargeBinary` or `FixedSizeBinary` (and so is `Vec<u8>`)
+//! * unit enum variants are strings; other variants are maps with a single entry,
+//!   `{variant: value}`
+//!
+//! # Example
+//! ```
+//! use serde_derive::{Deserialize, Serialize};
+//! use arrow2::datatypes::{DataType, Field};
+//! use arrow2::io::serde::{deserialize, serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Shape {
+//!     name: Option<String>,
+//!     points: Vec<Point>,
+//! }
+//!
+//! let point = DataType::Struct(vec![
+//!     Field::new("x", DataType::Int32, false),
+//!     Field::new("y", DataType::Int32, false),
+//! ]);
+//! let fields = vec![
+//!     Field::new("name", DataType::Utf8, true),
+//!     Field::new("points", DataType::List(Box::new(Field::new("item", point, false))), false),
+//! ];
+//!
+//! let rows = vec![
+//!     Shape { name: Some("a".to_string()), points: vec![Point { x: 1, y: 2 }] },
+//!     Shape { name: None, points: vec![] },
+//! ];
+//!
+//! let chunk = serialize(&rows, &fields).unwrap();
+//! assert_eq!(chunk.len(), 2);
+//!
+//! let result: Vec<Shape> = deserialize(&chunk, &fields).unwrap();
+//! assert_eq!(result, rows);
+//! ```
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::array::Array;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+mod convert;
+mod de;
+mod ser;
+
+use convert::{array_to_values, take_entry, values_to_array, values_to_field};
+
+/// An intermediate representation of a serde value, from and to which arrays are converted.
+#[derive(Debug, Clone, PartialEq, Default)]
+enum Value {
+    #[default]
+    Null,
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Seq(Vec<Value>),
+    /// the entries of a map or of the fields of a struct, in order
+    Map(Vec<(String, Value)>),
+}
+
+/// Serializes `rows` to a [`Chunk`] with one column per [`Field`] of `fields`, whose values are
+/// the row's (struct or map) entries with the same name.
+/// # Errors
+/// This function errors iff:
+/// * a row does not serialize to a struct or map (with string keys)
+/// * a value cannot be represented by its field's [`DataType`] (which may not be supported yet)
+/// * a field is not nullable but has null (or missing) values
+pub fn serialize<T: Serialize>(rows: &[T], fields: &[Field]) -> Result<Chunk<Arc<dyn Array>>> {
+    let mut columns = vec![Vec::with_capacity(rows.len()); fields.len()];
+    for row in rows {
+        let mut entries = match ser::to_value(row)? {
+            Value::Map(entries) => entries,
+            other => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Only rows that serialize to a struct or map can be serialized to a chunk, got {:?}",
+                    other
+                )))
+            }
+        };
+        for (index, (field, column)) in fields.iter().zip(columns.iter_mut()).enumerate() {
+            column.push(take_entry(&mut entries, index, &field.name));
+        }
+    }
+
+    let arrays = fields
+        .iter()
+        .zip(columns)
+        .map(|(field, values)| values_to_field(values, field).map(Arc::from))
+        .collect::<Result<Vec<_>>>()?;
+    Chunk::try_new(arrays)
+}
+
+/// Deserializes the rows of `chunk`, whose columns are named after `fields`.
+/// # Errors
+/// This function errors iff `chunk` and `fields` have a different number of columns, the data
+/// type of a column is not yet supported, or a row cannot be deserialized to `T`.
+pub fn deserialize<T: DeserializeOwned, A: AsRef<dyn Array>>(
+    chunk: &Chunk<A>,
+    fields: &[Field],
+) -> Result<Vec<T>> {
+    if chunk.arrays().len() != fields.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The chunk has {} columns but there are {} fields",
+            chunk.arrays().len(),
+            fields.len()
+        )));
+    }
+    let mut columns = chunk
+        .arrays()
+        .iter()
+        .map(|array| array_to_values(array.as_ref()).map(|values| values.into_iter()))
+        .collect::<Result<Vec<_>>>()?;
+
+    (0..chunk.len())
+        .map(|_| {
+            let entries = fields
+                .iter()
+                .zip(columns.iter_mut())
+                .map(|(field, column)| (field.name.clone(), column.next().unwrap()))
+                .collect();
+            T::deserialize(Value::Map(entries))
+        })
+        .collect()
+}
+
+/// Serializes `values` to an [`Array`] of `data_type`.
+/// # Errors
+/// This function errors iff a value cannot be represented by `data_type` (which may not be
+/// supported yet).
+pub fn to_array<T: Serialize>(values: &[T], data_type: &DataType) -> Result<Box<dyn Array>> {
+    let values = values
+        .iter()
+        .map(ser::to_value)
+        .collect::<Result<Vec<_>>>()?;
+    values_to_array(values, data_type)
+}
+
+/// Deserializes the values of `array`.
+/// # Errors
+/// This function errors iff the data type of `array` is not yet supported or a value cannot be
+/// deserialized to `T`.
+pub fn from_array<T: DeserializeOwned>(array: &dyn Array) -> Result<Vec<T>> {
+    array_to_values(array)?
+        .into_iter()
+        .map(T::deserialize)
+        .collect()
+}
@@ -11,6 +11,7 @@ pub mod bitmap;
 pub mod buffer;
 pub mod chunk;
 pub mod error;
+pub mod rows;
 pub mod scalar;
 pub mod trusted_len;
 pub mod types;
@@ -0,0 +1,336 @@
+//! Contains [`ArrowRow`], to convert a slice of rows (e.g. a `struct` per record) to a [`Chunk`]
+//! and back, and [`ArrowField`], to convert a slice of values to an [`Array`] and back.
+//!
+//! [`ArrowRow`] is implemented for a struct with the macro [`impl_arrow_row`](crate::impl_arrow_row),
+//! one column per field:
+//! ```
+//! use arrow2::impl_arrow_row;
+//! use arrow2::rows::ArrowRow;
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct Trade {
+//!     id: u64,
+//!     symbol: String,
+//!     price: f64,
+//!     comment: Option<String>,
+//! }
+//!
+//! impl_arrow_row!(Trade {
+//!     id: u64,
+//!     symbol: String,
+//!     price: f64,
+//!     comment: Option<String>,
+//! });
+//!
+//! let rows = vec![
+//!     Trade { id: 1, symbol: "A".to_string(), price: 1.5, comment: None },
+//!     Trade { id: 2, symbol: "B".to_string(), price: 2.5, comment: Some("c".to_string()) },
+//! ];
+//!
+//! // in batches of 1 row
+//! let chunks = rows.chunks(1).map(Trade::to_chunk).collect::<Vec<_>>();
+//! assert_eq!(chunks.len(), 2);
+//! assert_eq!(Trade::fields()[1].name, "symbol");
+//!
+//! let result = chunks
+//!     .iter()
+//!     .map(Trade::from_chunk)
+//!     .collect::<arrow2::error::Result<Vec<_>>>()
+//!     .unwrap()
+//!     .into_iter()
+//!     .flatten()
+//!     .collect::<Vec<_>>();
+//! assert_eq!(result, rows);
+//! ```
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field};
+use crate::error::{ArrowError, Result};
+
+/// A type that can be converted to and from a column of arrow.
+///
+/// It is implemented for `bool`, the native integers and floats, [`String`] and `Vec<u8>`,
+/// that are not nullable, and for their [`Option`], that are nullable.
+pub trait ArrowField: Sized {
+    /// The [`DataType`] of the column
+    fn data_type() -> DataType;
+
+    /// Whether the column is nullable
+    fn is_nullable() -> bool;
+
+    /// Returns an [`Array`] with `values`.
+    fn to_array<'a, I: Iterator<Item = &'a Self>>(values: I) -> Box<dyn Array>
+    where
+        Self: 'a;
+
+    /// Returns the values of `array`.
+    /// # Errors
+    /// This function errors iff the data type of `array` is not [`ArrowField::data_type`]
+    /// or if it has null values and the column is not nullable.
+    fn from_array(array: &dyn Array) -> Result<Vec<Self>>;
+}
+
+/// A row of a [`Chunk`], usually implemented for a struct via [`impl_arrow_row`](crate::impl_arrow_row).
+pub trait ArrowRow: Sized {
+    /// The [`Field`]s of the columns
+    fn fields() -> Vec<Field>;
+
+    /// Returns a [`Chunk`] whose row `i` is `rows[i]`.
+    fn to_chunk(rows: &[Self]) -> Chunk<Arc<dyn Array>>;
+
+    /// Returns the rows of `chunk`.
+    /// # Errors
+    /// This function errors iff the number of columns or their data types do not match
+    /// [`ArrowRow::fields`], or if a non-nullable column has null values.
+    fn from_chunk<A: AsRef<dyn Array>>(chunk: &Chunk<A>) -> Result<Vec<Self>>;
+}
+
+/// Implements [`ArrowRow`](crate::rows::ArrowRow) for a struct whose fields all implement
+/// [`ArrowField`](crate::rows::ArrowField), with one column per field, named after it.
+/// The fields are declared in the macro as in the struct; see [`rows`](crate::rows).
+#[macro_export]
+macro_rules! impl_arrow_row {
+    ($name:ident { $($field:ident: $ty:ty),+ $(,)? }) => {
+        impl $crate::rows::ArrowRow for $name {
+            fn fields() -> Vec<$crate::datatypes::Field> {
+                vec![$($crate::datatypes::Field::new(
+                    stringify!($field),
+                    <$ty as $crate::rows::ArrowField>::data_type(),
+                    <$ty as $crate::rows::ArrowField>::is_nullable(),
+                )),+]
+            }
+
+            fn to_chunk(
+                rows: &[Self],
+            ) -> $crate::chunk::Chunk<std::sync::Arc<dyn $crate::array::Array>> {
+                $crate::chunk::Chunk::new(vec![$(
+                    <$ty as $crate::rows::ArrowField>::to_array(
+                        rows.iter().map(|row| &row.$field),
+                    )
+                    .into()
+                ),+])
+            }
+
+            fn from_chunk<A: AsRef<dyn $crate::array::Array>>(
+                chunk: &$crate::chunk::Chunk<A>,
+            ) -> $crate::error::Result<Vec<Self>> {
+                let names = [$(stringify!($field)),+];
+                if chunk.arrays().len() != names.len() {
+                    return Err($crate::error::ArrowError::InvalidArgumentError(format!(
+                        "{} requires a chunk with {} columns, got {}",
+                        stringify!($name),
+                        names.len(),
+                        chunk.arrays().len()
+                    )));
+                }
+                let mut arrays = chunk.arrays().iter();
+                $(
+                    let mut $field = <$ty as $crate::rows::ArrowField>::from_array(
+                        arrays.next().unwrap().as_ref(),
+                    )?
+                    .into_iter();
+                )+
+                Ok((0..chunk.len())
+                    .map(|_| Self {
+                        $($field: $field.next().unwrap()),+
+                    })
+                    .collect())
+            }
+        }
+    };
+}
+
+fn downcast<'a, T: 'static>(array: &'a dyn Array, data_type: &DataType) -> Result<&'a T> {
+    if array.data_type() != data_type {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected an array of {:?}, got {:?}",
+            data_type,
+            array.data_type()
+        )));
+    }
+    Ok(array.as_any().downcast_ref::<T>().unwrap())
+}
+
+fn check_non_null(array: &dyn Array) -> Result<()> {
+    if array.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected an array of {:?} without null values, got {} null values",
+            array.data_type(),
+            array.null_count()
+        )));
+    }
+    Ok(())
+}
+
+macro_rules! primitive {
+    ($type:ty, $data_type:expr) => {
+        impl ArrowField for $type {
+            fn data_type() -> DataType {
+                $data_type
+            }
+
+            fn is_nullable() -> bool {
+                false
+            }
+
+            fn to_array<'a, I: Iterator<Item = &'a Self>>(values: I) -> Box<dyn Array> {
+                Box::new(PrimitiveArray::<$type>::from_vec(values.copied().collect()))
+            }
+
+            fn from_array(array: &dyn Array) -> Result<Vec<Self>> {
+                let array = downcast::<PrimitiveArray<$type>>(array, &Self::data_type())?;
+                check_non_null(array)?;
+                Ok(array.values().to_vec())
+            }
+        }
+
+        impl ArrowField for Option<$type> {
+            fn data_type() -> DataType {
+                $data_type
+            }
+
+            fn is_nullable() -> bool {
+                true
+            }
+
+            fn to_array<'a, I: Iterator<Item = &'a Self>>(values: I) -> Box<dyn Array> {
+                Box::new(values.collect::<PrimitiveArray<$type>>())
+            }
+
+            fn from_array(array: &dyn Array) -> Result<Vec<Self>> {
+                let array = downcast::<PrimitiveArray<$type>>(array, &Self::data_type())?;
+                Ok(array.iter().map(|x| x.copied()).collect())
+            }
+        }
+    };
+}
+
+primitive!(i8, DataType::Int8);
+primitive!(i16, DataType::Int16);
+primitive!(i32, DataType::Int32);
+primitive!(i64, DataType::Int64);
+primitive!(u8, DataType::UInt8);
+primitive!(u16, DataType::UInt16);
+primitive!(u32, DataType::UInt32);
+primitive!(u64, DataType::UInt64);
+primitive!(f32, DataType::Float32);
+primitive!(f64, DataType::Float64);
+
+impl ArrowField for bool {
+    fn data_type() -> DataType {
+        DataType::Boolean
+    }
+
+    fn is_nullable() -> bool {
+        false
+    }
+
+    fn to_array<'a, I: Iterator<Item = &'a Self>>(values: I) -> Box<dyn Array> {
+        Box::new(values.map(|x| Some(*x)).collect::<BooleanArray>())
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Self>> {
+        let array = downcast::<BooleanArray>(array, &Self::data_type())?;
+        check_non_null(array)?;
+        Ok(array.values_iter().collect())
+    }
+}
+
+impl ArrowField for Option<bool> {
+    fn data_type() -> DataType {
+        DataType::Boolean
+    }
+
+    fn is_nullable() -> bool {
+        true
+    }
+
+    fn to_array<'a, I: Iterator<Item = &'a Self>>(values: I) -> Box<dyn Array> {
+        Box::new(values.collect::<BooleanArray>())
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Self>> {
+        let array = downcast::<BooleanArray>(array, &Self::data_type())?;
+        Ok(array.iter().collect())
+    }
+}
+
+impl ArrowField for String {
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    fn is_nullable() -> bool {
+        false
+    }
+
+    fn to_array<'a, I: Iterator<Item = &'a Self>>(values: I) -> Box<dyn Array> {
+        Box::new(Utf8Array::<i32>::from_iter_values(values))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Self>> {
+        let array = downcast::<Utf8Array<i32>>(array, &Self::data_type())?;
+        check_non_null(array)?;
+        Ok(array.values_iter().map(|x| x.to_string()).collect())
+    }
+}
+
+impl ArrowField for Option<String> {
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    fn is_nullable() -> bool {
+        true
+    }
+
+    fn to_array<'a, I: Iterator<Item = &'a Self>>(values: I) -> Box<dyn Array> {
+        Box::new(values.map(|x| x.as_ref()).collect::<Utf8Array<i32>>())
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Self>> {
+        let array = downcast::<Utf8Array<i32>>(array, &Self::data_type())?;
+        Ok(array.iter().map(|x| x.map(|x| x.to_string())).collect())
+    }
+}
+
+impl ArrowField for Vec<u8> {
+    fn data_type() -> DataType {
+        DataType::Binary
+    }
+
+    fn is_nullable() -> bool {
+        false
+    }
+
+    fn to_array<'a, I: Iterator<Item = &'a Self>>(values: I) -> Box<dyn Array> {
+        Box::new(BinaryArray::<i32>::from_iter_values(values))
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Self>> {
+        let array = downcast::<BinaryArray<i32>>(array, &Self::data_type())?;
+        check_non_null(array)?;
+        Ok(array.values_iter().map(|x| x.to_vec()).collect())
+    }
+}
+
+impl ArrowField for Option<Vec<u8>> {
+    fn data_type() -> DataType {
+        DataType::Binary
+    }
+
+    fn is_nullable() -> bool {
+        true
+    }
+
+    fn to_array<'a, I: Iterator<Item = &'a Self>>(values: I) -> Box<dyn Array> {
+        Box::new(values.map(|x| x.as_ref()).collect::<BinaryArray<i32>>())
+    }
+
+    fn from_array(array: &dyn Array) -> Result<Vec<Self>> {
+        let array = downcast::<BinaryArray<i32>>(array, &Self::data_type())?;
+        Ok(array.iter().map(|x| x.map(|x| x.to_vec())).collect())
+    }
+}
@@ -7,7 +7,7 @@ use chrono::{
 
 use crate::error::Result;
 use crate::{
-    array::{Offset, PrimitiveArray, Utf8Array},
+    array::{Array, Offset, PrimitiveArray, Utf8Array},
     error::ArrowError,
 };
 use crate::{
@@ -147,6 +147,20 @@ pub fn timestamp_to_naive_datetime(timestamp: i64, time_unit: TimeUnit) -> chron
     }
 }
 
+/// Converts a [`NaiveDateTime`] into a timestamp in `time_unit`, truncating the fraction
+/// of a second finer than `time_unit`.
+#[inline]
+pub fn naive_datetime_to_timestamp(datetime: &NaiveDateTime, time_unit: TimeUnit) -> i64 {
+    match time_unit {
+        TimeUnit::Second => datetime.timestamp(),
+        TimeUnit::Millisecond => datetime.timestamp_millis(),
+        TimeUnit::Microsecond => {
+            datetime.timestamp() * MICROSECONDS + datetime.timestamp_subsec_micros() as i64
+        }
+        TimeUnit::Nanosecond => datetime.timestamp_nanos(),
+    }
+}
+
 /// Converts a timestamp in `time_unit` and `timezone` into [`chrono::DateTime`].
 #[inline]
 pub fn timestamp_to_datetime<T: chrono::TimeZone>(
@@ -209,13 +223,15 @@ pub fn parse_offset(offset: &str) -> Result<FixedOffset> {
     Ok(FixedOffset::east(hours * 60 * 60 + minutes * 60))
 }
 
-/// Parses `value` to `Option<i64>` consistent with the Arrow's definition of timestamp with timezone.
+/// Parses `value` to `Option<i64>` in `time_unit` consistent with the Arrow's definition of
+/// timestamp with timezone.
 /// `tz` must be built from `timezone` (either via [`parse_offset`] or `chrono-tz`).
 #[inline]
-pub fn utf8_to_timestamp_ns_scalar<T: chrono::TimeZone>(
+pub fn utf8_to_timestamp_scalar<T: chrono::TimeZone>(
     value: &str,
     fmt: &str,
     tz: &T,
+    time_unit: TimeUnit,
 ) -> Option<i64> {
     let mut parsed = Parsed::new();
     let fmt = StrftimeItems::new(fmt);
@@ -225,37 +241,55 @@ pub fn utf8_to_timestamp_ns_scalar<T: chrono::TimeZone>(
             .to_datetime()
             .map(|x| x.naive_utc())
             .map(|x| tz.from_utc_datetime(&x))
-            .map(|x| x.timestamp_nanos())
+            .map(|x| naive_datetime_to_timestamp(&x.naive_utc(), time_unit))
             .ok()
     } else {
         None
     }
 }
 
-/// Parses `value` to `Option<i64>` consistent with the Arrow's definition of timestamp without timezone.
+/// Parses `value` to `Option<i64>` consistent with the Arrow's definition of timestamp with timezone.
+/// `tz` must be built from `timezone` (either via [`parse_offset`] or `chrono-tz`).
 #[inline]
-pub fn utf8_to_naive_timestamp_ns_scalar(value: &str, fmt: &str) -> Option<i64> {
+pub fn utf8_to_timestamp_ns_scalar<T: chrono::TimeZone>(
+    value: &str,
+    fmt: &str,
+    tz: &T,
+) -> Option<i64> {
+    utf8_to_timestamp_scalar(value, fmt, tz, TimeUnit::Nanosecond)
+}
+
+/// Parses `value` to `Option<i64>` in `time_unit` consistent with the Arrow's definition of
+/// timestamp without timezone.
+#[inline]
+pub fn utf8_to_naive_timestamp_scalar(value: &str, fmt: &str, time_unit: TimeUnit) -> Option<i64> {
     let fmt = StrftimeItems::new(fmt);
     let mut parsed = Parsed::new();
     parse(&mut parsed, value, fmt.clone()).ok();
     parsed
         .to_naive_datetime_with_offset(0)
-        .map(|x| x.timestamp_nanos())
+        .map(|x| naive_datetime_to_timestamp(&x, time_unit))
         .ok()
 }
 
-fn utf8_to_timestamp_ns_impl<O: Offset, T: chrono::TimeZone>(
+/// Parses `value` to `Option<i64>` consistent with the Arrow's definition of timestamp without timezone.
+#[inline]
+pub fn utf8_to_naive_timestamp_ns_scalar(value: &str, fmt: &str) -> Option<i64> {
+    utf8_to_naive_timestamp_scalar(value, fmt, TimeUnit::Nanosecond)
+}
+
+fn utf8_to_timestamp_impl<O: Offset, T: chrono::TimeZone>(
     array: &Utf8Array<O>,
     fmt: &str,
     timezone: String,
     tz: T,
+    time_unit: TimeUnit,
 ) -> PrimitiveArray<i64> {
     let iter = array
         .iter()
-        .map(|x| x.and_then(|x| utf8_to_timestamp_ns_scalar(x, fmt, &tz)));
+        .map(|x| x.and_then(|x| utf8_to_timestamp_scalar(x, fmt, &tz, time_unit)));
 
-    PrimitiveArray::from_trusted_len_iter(iter)
-        .to(DataType::Timestamp(TimeUnit::Nanosecond, Some(timezone)))
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(time_unit, Some(timezone)))
 }
 
 /// Parses `value` to a [`chrono_tz::Tz`] with the Arrow's definition of timestamp with a timezone.
@@ -269,20 +303,22 @@ pub fn parse_offset_tz(timezone: &str) -> Result<chrono_tz::Tz> {
 
 #[cfg(feature = "chrono-tz")]
 #[cfg_attr(docsrs, doc(cfg(feature = "chrono-tz")))]
-fn chrono_tz_utf_to_timestamp_ns<O: Offset>(
+fn chrono_tz_utf_to_timestamp<O: Offset>(
     array: &Utf8Array<O>,
     fmt: &str,
     timezone: String,
+    time_unit: TimeUnit,
 ) -> Result<PrimitiveArray<i64>> {
     let tz = parse_offset_tz(&timezone)?;
-    Ok(utf8_to_timestamp_ns_impl(array, fmt, timezone, tz))
+    Ok(utf8_to_timestamp_impl(array, fmt, timezone, tz, time_unit))
 }
 
 #[cfg(not(feature = "chrono-tz"))]
-fn chrono_tz_utf_to_timestamp_ns<O: Offset>(
+fn chrono_tz_utf_to_timestamp<O: Offset>(
     _: &Utf8Array<O>,
     _: &str,
     timezone: String,
+    _: TimeUnit,
 ) -> Result<PrimitiveArray<i64>> {
     Err(ArrowError::InvalidArgumentError(format!(
         "timezone \"{}\" cannot be parsed (feature chrono-tz is not active)",
@@ -290,41 +326,71 @@ fn chrono_tz_utf_to_timestamp_ns<O: Offset>(
     )))
 }
 
-/// Parses a [`Utf8Array`] to a timeozone-aware timestamp, i.e. [`PrimitiveArray<i64>`] with type `Timestamp(Nanosecond, Some(timezone))`.
+/// Parses a [`Utf8Array`] to a timeozone-aware timestamp, i.e. [`PrimitiveArray<i64>`] with
+/// type `Timestamp(time_unit, Some(timezone))`.
 /// # Implementation
 /// * parsed values with timezone other than `timezone` are converted to `timezone`.
-/// * parsed values without timezone are null. Use [`utf8_to_naive_timestamp_ns`] to parse naive timezones.
+/// * parsed values without timezone are null. Use [`utf8_to_naive_timestamp`] to parse naive timezones.
 /// * Null elements remain null; non-parsable elements are null.
 /// The feature `"chrono-tz"` enables IANA and zoneinfo formats for `timezone`.
 /// # Error
 /// This function errors iff `timezone` is not parsable to an offset.
-pub fn utf8_to_timestamp_ns<O: Offset>(
+pub fn utf8_to_timestamp<O: Offset>(
     array: &Utf8Array<O>,
     fmt: &str,
     timezone: String,
+    time_unit: TimeUnit,
 ) -> Result<PrimitiveArray<i64>> {
     let tz = parse_offset(timezone.as_str());
 
     if let Ok(tz) = tz {
-        Ok(utf8_to_timestamp_ns_impl(array, fmt, timezone, tz))
+        Ok(utf8_to_timestamp_impl(array, fmt, timezone, tz, time_unit))
     } else {
-        chrono_tz_utf_to_timestamp_ns(array, fmt, timezone)
+        chrono_tz_utf_to_timestamp(array, fmt, timezone, time_unit)
     }
 }
 
+/// Parses a [`Utf8Array`] to a timeozone-aware timestamp, i.e. [`PrimitiveArray<i64>`] with type `Timestamp(Nanosecond, Some(timezone))`.
+/// # Implementation
+/// * parsed values with timezone other than `timezone` are converted to `timezone`.
+/// * parsed values without timezone are null. Use [`utf8_to_naive_timestamp_ns`] to parse naive timezones.
+/// * Null elements remain null; non-parsable elements are null.
+/// The feature `"chrono-tz"` enables IANA and zoneinfo formats for `timezone`.
+/// # Error
+/// This function errors iff `timezone` is not parsable to an offset.
+pub fn utf8_to_timestamp_ns<O: Offset>(
+    array: &Utf8Array<O>,
+    fmt: &str,
+    timezone: String,
+) -> Result<PrimitiveArray<i64>> {
+    utf8_to_timestamp(array, fmt, timezone, TimeUnit::Nanosecond)
+}
+
 /// Parses a [`Utf8Array`] to naive timestamp, i.e.
-/// [`PrimitiveArray<i64>`] with type `Timestamp(Nanosecond, None)`.
+/// [`PrimitiveArray<i64>`] with type `Timestamp(time_unit, None)`.
 /// Timezones are ignored.
 /// Null elements remain null; non-parsable elements are set to null.
-pub fn utf8_to_naive_timestamp_ns<O: Offset>(
+pub fn utf8_to_naive_timestamp<O: Offset>(
     array: &Utf8Array<O>,
     fmt: &str,
+    time_unit: TimeUnit,
 ) -> PrimitiveArray<i64> {
     let iter = array
         .iter()
-        .map(|x| x.and_then(|x| utf8_to_naive_timestamp_ns_scalar(x, fmt)));
+        .map(|x| x.and_then(|x| utf8_to_naive_timestamp_scalar(x, fmt, time_unit)));
 
-    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(TimeUnit::Nanosecond, None))
+    PrimitiveArray::from_trusted_len_iter(iter).to(DataType::Timestamp(time_unit, None))
+}
+
+/// Parses a [`Utf8Array`] to naive timestamp, i.e.
+/// [`PrimitiveArray<i64>`] with type `Timestamp(Nanosecond, None)`.
+/// Timezones are ignored.
+/// Null elements remain null; non-parsable elements are set to null.
+pub fn utf8_to_naive_timestamp_ns<O: Offset>(
+    array: &Utf8Array<O>,
+    fmt: &str,
+) -> PrimitiveArray<i64> {
+    utf8_to_naive_timestamp(array, fmt, TimeUnit::Nanosecond)
 }
 
 fn add_month(year: i32, month: u32, months: i32) -> chrono::NaiveDate {
@@ -395,3 +461,61 @@ pub fn add_interval<T: chrono::TimeZone>(
         TimeUnit::Nanosecond => new_datetime_tz.timestamp_nanos(),
     }
 }
+
+/// Returns the number of `unit`s in a second.
+pub const fn time_unit_multiple(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
+    }
+}
+
+/// Converts a timestamp `array` to `time_unit`, keeping its timezone.
+/// Conversions to a coarser unit truncate the timestamps.
+/// # Errors
+/// This function errors iff `array` is not a timestamp array or a (non-null) timestamp
+/// overflows when converted to a finer unit.
+pub fn timestamp_to_time_unit(
+    array: &PrimitiveArray<i64>,
+    time_unit: TimeUnit,
+) -> Result<PrimitiveArray<i64>> {
+    let (from_unit, tz) = if let DataType::Timestamp(from_unit, tz) = array.data_type() {
+        (*from_unit, tz)
+    } else {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected a timestamp array, found {:?}",
+            array.data_type()
+        )));
+    };
+    let from_size = time_unit_multiple(from_unit);
+    let to_size = time_unit_multiple(time_unit);
+
+    let values = if from_size >= to_size {
+        let factor = from_size / to_size;
+        array
+            .values()
+            .iter()
+            .map(|x| x / factor)
+            .collect::<Vec<_>>()
+    } else {
+        let factor = to_size / from_size;
+        array
+            .values()
+            .iter()
+            .enumerate()
+            .map(|(i, x)| match x.checked_mul(factor) {
+                Some(x) => Ok(x),
+                // the values of null slots are undefined and may overflow
+                None if array.is_null(i) => Ok(0),
+                None => Err(ArrowError::Overflow),
+            })
+            .collect::<Result<Vec<_>>>()?
+    };
+    Ok(PrimitiveArray::from_data(
+        DataType::Timestamp(time_unit, tz.clone()),
+        values.into(),
+        array.validity().cloned(),
+    ))
+}
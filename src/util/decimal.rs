@@ -0,0 +1,116 @@
+//! Parsing and formatting of decimals, i.e. `i128` values with a precision and scale (see
+//! [`DataType::Decimal`](crate::datatypes::DataType::Decimal)), from and to their textual
+//! representation (e.g. `-12.34` for the value `-1234` with a scale of 2).
+
+/// Parses `bytes` to the value of a decimal with `precision` and `scale`, e.g. `b"-1.5"` to
+/// `-150` with a scale of 2. Returns `None` if `bytes` is not a number, or if it does not fit in
+/// `precision`.
+///
+/// The number is an optional sign followed by digits with an optional decimal point,
+/// e.g. `1`, `+1.0`, `-.5`, `1.`.
+/// * When `lenient` is false, numbers whose fractional digits beyond `scale` are not zeros
+///   are invalid.
+/// * When `lenient` is true, leading and trailing whitespace are ignored and numbers with more
+///   fractional digits than `scale` are rounded (half away from zero).
+pub fn parse_decimal(bytes: &[u8], precision: usize, scale: usize, lenient: bool) -> Option<i128> {
+    let bytes = if lenient { trim(bytes) } else { bytes };
+
+    let (is_negative, bytes) = match bytes.first() {
+        Some(b'-') => (true, &bytes[1..]),
+        Some(b'+') => (false, &bytes[1..]),
+        _ => (false, bytes),
+    };
+    let (integral, fractional) = match bytes.iter().position(|x| *x == b'.') {
+        Some(position) => (&bytes[..position], &bytes[position + 1..]),
+        None => (bytes, &bytes[bytes.len()..]),
+    };
+    if integral.is_empty() && fractional.is_empty() {
+        return None;
+    }
+    if !integral
+        .iter()
+        .chain(fractional.iter())
+        .all(|x| x.is_ascii_digit())
+    {
+        return None;
+    }
+    let extra_digits = fractional.get(scale..).unwrap_or_default();
+    if !lenient && extra_digits.iter().any(|x| *x != b'0') {
+        return None;
+    }
+
+    let max = max_value(precision)?;
+    let mut value = 0i128;
+    for digit in integral.iter().chain(fractional.iter().take(scale)) {
+        value = value.checked_mul(10)?.checked_add((digit - b'0') as i128)?;
+        if value > max {
+            return None;
+        }
+    }
+    if fractional.len() < scale {
+        value = value.checked_mul(10i128.checked_pow((scale - fractional.len()) as u32)?)?;
+    } else if extra_digits.first().map(|x| *x >= b'5').unwrap_or(false) {
+        // rounds the fractional digits beyond `scale`
+        value = value.checked_add(1)?;
+    }
+    if value > max {
+        return None;
+    }
+    Some(if is_negative { -value } else { value })
+}
+
+/// The largest value of a decimal with `precision`, i.e. `10^precision - 1`.
+fn max_value(precision: usize) -> Option<i128> {
+    10i128
+        .checked_pow(precision as u32)
+        .map(|x| x - 1)
+        .or(if precision == 39 {
+            Some(i128::MAX)
+        } else {
+            None
+        })
+}
+
+fn trim(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|x| !x.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    let end = bytes
+        .iter()
+        .rposition(|x| !x.is_ascii_whitespace())
+        .map(|x| x + 1)
+        .unwrap_or(start);
+    &bytes[start..end]
+}
+
+/// Writes the textual representation of the decimal `value` with `scale` to `buffer`, e.g.
+/// `-1234` with a scale of 2 as `-12.34` and `5` with a scale of 2 as `0.05`.
+pub fn write_decimal(value: i128, scale: usize, buffer: &mut Vec<u8>) {
+    if value < 0 {
+        buffer.push(b'-');
+    }
+    let digits = value.unsigned_abs().to_string();
+    let digits = digits.as_bytes();
+    if digits.len() <= scale {
+        buffer.extend_from_slice(b"0.");
+        buffer.resize(buffer.len() + scale - digits.len(), b'0');
+        buffer.extend_from_slice(digits);
+    } else {
+        let (integral, fractional) = digits.split_at(digits.len() - scale);
+        buffer.extend_from_slice(integral);
+        if scale > 0 {
+            buffer.push(b'.');
+            buffer.extend_from_slice(fractional);
+        }
+    }
+}
+
+/// Returns the textual representation of the decimal `value` with `scale`, e.g.
+/// `-1234` with a scale of 2 as `-12.34`.
+pub fn format_decimal(value: i128, scale: usize) -> String {
+    let mut buffer = vec![];
+    write_decimal(value, scale, &mut buffer);
+    // Safety: only ASCII digits, `-` and `.` were written
+    unsafe { String::from_utf8_unchecked(buffer) }
+}
@@ -0,0 +1,102 @@
+//! Deterministic generators of synthetic arrays, to build benchmarks and examples.
+//!
+//! All random generators are seeded, so that the same arguments always generate the same array.
+use std::sync::Arc;
+
+use num_traits::One;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::array::*;
+use crate::datatypes::{DataType, TimeUnit};
+use crate::types::NativeType;
+
+/// Returns a [`PrimitiveArray`] without nulls of `length` consecutive values starting at
+/// `start`, i.e. `start, start + 1, ...`.
+/// # Panics
+/// This function panics iff a value overflows `T` (in debug mode).
+pub fn sequential<T>(start: T, length: usize) -> PrimitiveArray<T>
+where
+    T: NativeType + One + std::ops::Add<Output = T>,
+{
+    let values = std::iter::successors(Some(start), |x| Some(*x + T::one()))
+        .take(length)
+        .collect::<Vec<_>>();
+    PrimitiveArray::from_data(T::PRIMITIVE.into(), values.into(), None)
+}
+
+/// Returns a [`DictionaryArray`] of `length` strings whose keys follow a
+/// [Zipf distribution](https://en.wikipedia.org/wiki/Zipf%27s_law) with `exponent` over
+/// `num_values` distinct values, `"value_0", "value_1", ...`, ordered from the most to the
+/// least frequent.
+///
+/// Slots are null with probability `null_density`.
+/// # Panics
+/// This function panics iff `num_values` is zero or does not fit in `K`.
+pub fn zipf_dictionary<K: DictionaryKey>(
+    length: usize,
+    num_values: usize,
+    exponent: f64,
+    null_density: f32,
+    seed: u64,
+) -> DictionaryArray<K> {
+    assert!(num_values > 0, "A dictionary must have at least one value");
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // the (unnormalized) cumulative distribution of the keys
+    let cumulative = (1..=num_values)
+        .scan(0.0, |sum, rank| {
+            *sum += 1.0 / (rank as f64).powf(exponent);
+            Some(*sum)
+        })
+        .collect::<Vec<_>>();
+    let total = cumulative[num_values - 1];
+
+    let keys = (0..length)
+        .map(|_| {
+            if rng.gen::<f32>() < null_density {
+                None
+            } else {
+                let x = rng.gen::<f64>() * total;
+                let key = cumulative
+                    .partition_point(|sum| *sum <= x)
+                    .min(num_values - 1);
+                Some(K::from_usize(key).unwrap())
+            }
+        })
+        .collect::<PrimitiveArray<K>>();
+
+    let values = (0..num_values)
+        .map(|i| Some(format!("value_{}", i)))
+        .collect::<Utf8Array<i32>>();
+
+    DictionaryArray::<K>::from_data(keys, Arc::new(values))
+}
+
+/// Returns a [`PrimitiveArray`] of `length` timestamps with `time_unit` and `timezone` drawn
+/// uniformly from `[start, end)`.
+///
+/// Slots are null with probability `null_density`.
+/// # Panics
+/// This function panics iff `start >= end`.
+pub fn timestamps(
+    length: usize,
+    start: i64,
+    end: i64,
+    time_unit: TimeUnit,
+    timezone: Option<String>,
+    null_density: f32,
+    seed: u64,
+) -> PrimitiveArray<i64> {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..length)
+        .map(|_| {
+            if rng.gen::<f32>() < null_density {
+                None
+            } else {
+                Some(rng.gen_range(start..end))
+            }
+        })
+        .collect::<PrimitiveArray<i64>>()
+        .to(DataType::Timestamp(time_unit, timezone))
+}
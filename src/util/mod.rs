@@ -17,6 +17,16 @@ mod lexical;
 ))]
 pub use lexical::*;
 
+pub mod decimal;
+
 #[cfg(feature = "benchmarks")]
 #[cfg_attr(docsrs, doc(cfg(feature = "benchmarks")))]
 pub mod bench_util;
+
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod strategies;
+
+#[cfg(feature = "benchmarks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "benchmarks")))]
+pub mod generate;
@@ -0,0 +1,320 @@
+//! [`proptest`] strategies that generate arbitrary (valid) arrays and chunks of a given
+//! [`DataType`], so that code consuming arrays (e.g. kernels and IO round trips) can be
+//! property-tested and fuzzed.
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+
+use crate::array::*;
+use crate::bitmap::Bitmap;
+use crate::chunk::Chunk;
+use crate::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
+use crate::temporal_conversions::{time_unit_multiple, MILLISECONDS, NANOSECONDS, SECONDS_IN_DAY};
+use crate::types::{days_ms, months_days_ns, NativeType, Offset};
+
+/// The maximum length of the values of binary and utf8 slots, and of list slots.
+const MAX_SLOT_LENGTH: usize = 8;
+
+/// Returns a [`Strategy`] of arrays of `data_type` whose length is in `length` and whose slots
+/// are null with probability `null_density`. The children of nested arrays are null with
+/// the same probability, unless their field is not nullable.
+///
+/// The values are valid for their logical type, e.g. decimals fit in their precision and
+/// timestamps can be represented in any [`TimeUnit`].
+/// # Panics
+/// The strategy panics iff `data_type` is (or contains) a map, union or extension type.
+pub fn array_strategy(
+    data_type: DataType,
+    length: impl Into<SizeRange>,
+    null_density: f64,
+) -> BoxedStrategy<Box<dyn Array>> {
+    let (start, end) = length.into().start_end_incl();
+    (start..=end)
+        .prop_flat_map(move |length| array_of_length(&data_type, length, null_density))
+        .boxed()
+}
+
+/// Returns a [`Strategy`] of [`Chunk`]s with one array per field of `fields`, whose length is
+/// in `length` and whose slots are null with probability `null_density`
+/// (or never, for fields that are not nullable).
+/// # Panics
+/// The strategy panics iff the data type of a field is (or contains) a map, union or
+/// extension type.
+pub fn chunk_strategy(
+    fields: &[Field],
+    length: impl Into<SizeRange>,
+    null_density: f64,
+) -> BoxedStrategy<Chunk<Arc<dyn Array>>> {
+    let fields = fields.to_vec();
+    let (start, end) = length.into().start_end_incl();
+    (start..=end)
+        .prop_flat_map(move |length| {
+            fields
+                .iter()
+                .map(|field| {
+                    array_of_length(field.data_type(), length, density(field, null_density))
+                })
+                .collect::<Vec<_>>()
+                .prop_map(|arrays| Chunk::new(arrays.into_iter().map(Arc::from).collect()))
+        })
+        .boxed()
+}
+
+fn density(field: &Field, null_density: f64) -> f64 {
+    if field.is_nullable {
+        null_density
+    } else {
+        0.0
+    }
+}
+
+fn validity(length: usize, null_density: f64) -> BoxedStrategy<Option<Bitmap>> {
+    if null_density <= 0.0 {
+        Just(None).boxed()
+    } else {
+        vec(prop::bool::weighted(1.0 - null_density), length)
+            .prop_map(|validity| Some(validity.into_iter().collect()))
+            .boxed()
+    }
+}
+
+fn primitive<T, S>(
+    data_type: DataType,
+    values: S,
+    length: usize,
+    null_density: f64,
+) -> BoxedStrategy<Box<dyn Array>>
+where
+    T: NativeType,
+    S: Strategy<Value = T> + 'static,
+{
+    (vec(values, length), validity(length, null_density))
+        .prop_map(move |(values, validity)| {
+            Box::new(PrimitiveArray::<T>::from_data(
+                data_type.clone(),
+                values.into(),
+                validity,
+            )) as Box<dyn Array>
+        })
+        .boxed()
+}
+
+fn utf8<O: Offset>(length: usize, null_density: f64) -> BoxedStrategy<Box<dyn Array>> {
+    // strings of up to `MAX_SLOT_LENGTH` printable characters
+    let values = vec("\\PC{0,8}", length);
+    (values, validity(length, null_density))
+        .prop_map(|(values, validity)| {
+            Box::new(Utf8Array::<O>::from_slice(values).with_validity(validity)) as Box<dyn Array>
+        })
+        .boxed()
+}
+
+fn binary<O: Offset>(length: usize, null_density: f64) -> BoxedStrategy<Box<dyn Array>> {
+    let values = vec(vec(any::<u8>(), 0..=MAX_SLOT_LENGTH), length);
+    (values, validity(length, null_density))
+        .prop_map(|(values, validity)| {
+            Box::new(BinaryArray::<O>::from_slice(values).with_validity(validity)) as Box<dyn Array>
+        })
+        .boxed()
+}
+
+fn list<O: Offset>(
+    data_type: &DataType,
+    field: &Field,
+    length: usize,
+    null_density: f64,
+) -> BoxedStrategy<Box<dyn Array>> {
+    let data_type = data_type.clone();
+    let field = field.clone();
+    vec(0..=MAX_SLOT_LENGTH, length)
+        .prop_flat_map(move |lengths| {
+            let offsets = std::iter::once(0)
+                .chain(lengths.iter().scan(0, |offset, length| {
+                    *offset += length;
+                    Some(*offset)
+                }))
+                .map(|x| O::from_usize(x).unwrap())
+                .collect::<Vec<_>>();
+            let values_length = offsets.last().unwrap().to_usize();
+            let data_type = data_type.clone();
+            (
+                array_of_length(
+                    field.data_type(),
+                    values_length,
+                    density(&field, null_density),
+                ),
+                validity(length, null_density),
+            )
+                .prop_map(move |(values, validity)| {
+                    Box::new(ListArray::<O>::from_data(
+                        data_type.clone(),
+                        offsets.clone().into(),
+                        values.into(),
+                        validity,
+                    )) as Box<dyn Array>
+                })
+        })
+        .boxed()
+}
+
+fn dictionary<K: DictionaryKey>(
+    values: &DataType,
+    length: usize,
+    null_density: f64,
+) -> BoxedStrategy<Box<dyn Array>> {
+    let values_type = values.clone();
+    // every key must be representable by `K`
+    (1..=length.max(1).min(i8::MAX as usize))
+        .prop_flat_map(move |values_length| {
+            let keys = vec(0..values_length, length);
+            (
+                keys,
+                validity(length, null_density),
+                array_of_length(&values_type, values_length, null_density),
+            )
+        })
+        .prop_map(|(keys, validity, values)| {
+            let keys = keys
+                .into_iter()
+                .map(|key| K::from_usize(key).unwrap())
+                .collect::<Vec<_>>();
+            let keys = PrimitiveArray::<K>::from_data(K::PRIMITIVE.into(), keys.into(), validity);
+            Box::new(DictionaryArray::<K>::from_data(keys, values.into())) as Box<dyn Array>
+        })
+        .boxed()
+}
+
+/// The timestamps in `unit` that can be represented in nanoseconds (years 1677-2262).
+fn timestamps(unit: TimeUnit) -> RangeInclusive<i64> {
+    let bound = i64::MAX / (NANOSECONDS / time_unit_multiple(unit));
+    -bound..=bound
+}
+
+fn array_of_length(
+    data_type: &DataType,
+    length: usize,
+    null_density: f64,
+) -> BoxedStrategy<Box<dyn Array>> {
+    use DataType::*;
+    let data_type = data_type.clone();
+    match data_type.clone() {
+        Null => Just(())
+            .prop_map(move |_| {
+                Box::new(NullArray::from_data(data_type.clone(), length)) as Box<dyn Array>
+            })
+            .boxed(),
+        Boolean => (vec(any::<bool>(), length), validity(length, null_density))
+            .prop_map(|(values, validity)| {
+                Box::new(BooleanArray::from_data(
+                    DataType::Boolean,
+                    values.into_iter().collect(),
+                    validity,
+                )) as Box<dyn Array>
+            })
+            .boxed(),
+        Int8 => primitive(data_type, any::<i8>(), length, null_density),
+        Int16 => primitive(data_type, any::<i16>(), length, null_density),
+        Int32 | Interval(IntervalUnit::YearMonth) => {
+            primitive(data_type, any::<i32>(), length, null_density)
+        }
+        Int64 | Duration(_) => primitive(data_type, any::<i64>(), length, null_density),
+        UInt8 => primitive(data_type, any::<u8>(), length, null_density),
+        UInt16 => primitive(data_type, any::<u16>(), length, null_density),
+        UInt32 => primitive(data_type, any::<u32>(), length, null_density),
+        UInt64 => primitive(data_type, any::<u64>(), length, null_density),
+        Float32 => primitive(data_type, any::<f32>(), length, null_density),
+        Float64 => primitive(data_type, any::<f64>(), length, null_density),
+        Date32 => {
+            let days = timestamps(TimeUnit::Second);
+            let days =
+                (days.start() / SECONDS_IN_DAY) as i32..=(days.end() / SECONDS_IN_DAY) as i32;
+            primitive(data_type, days, length, null_density)
+        }
+        Date64 => {
+            let days = timestamps(TimeUnit::Millisecond);
+            let day = SECONDS_IN_DAY * MILLISECONDS;
+            let days = (days.start() / day..=days.end() / day).prop_map(move |x| x * day);
+            primitive(data_type, days, length, null_density)
+        }
+        Time32(unit) => {
+            let end = (SECONDS_IN_DAY * time_unit_multiple(unit)) as i32;
+            primitive(data_type, 0..end, length, null_density)
+        }
+        Time64(unit) => {
+            let end = SECONDS_IN_DAY * time_unit_multiple(unit);
+            primitive(data_type, 0..end, length, null_density)
+        }
+        Timestamp(unit, _) => primitive(data_type, timestamps(unit), length, null_density),
+        Interval(IntervalUnit::DayTime) => {
+            let values = any::<(i32, i32)>().prop_map(|(days, ms)| days_ms::new(days, ms));
+            primitive(data_type, values, length, null_density)
+        }
+        Interval(IntervalUnit::MonthDayNano) => {
+            let values = any::<(i32, i32, i64)>()
+                .prop_map(|(months, days, ns)| months_days_ns::new(months, days, ns));
+            primitive(data_type, values, length, null_density)
+        }
+        Decimal(precision, _) => {
+            let bound = 10i128.pow(precision as u32) - 1;
+            primitive(data_type, -bound..=bound, length, null_density)
+        }
+        Utf8 => utf8::<i32>(length, null_density),
+        LargeUtf8 => utf8::<i64>(length, null_density),
+        Binary => binary::<i32>(length, null_density),
+        LargeBinary => binary::<i64>(length, null_density),
+        FixedSizeBinary(size) => (
+            vec(any::<u8>(), length * size),
+            validity(length, null_density),
+        )
+            .prop_map(move |(values, validity)| {
+                Box::new(FixedSizeBinaryArray::from_data(
+                    data_type.clone(),
+                    values.into(),
+                    validity,
+                )) as Box<dyn Array>
+            })
+            .boxed(),
+        List(field) => list::<i32>(&data_type, &field, length, null_density),
+        LargeList(field) => list::<i64>(&data_type, &field, length, null_density),
+        FixedSizeList(field, size) => (
+            array_of_length(
+                field.data_type(),
+                length * size,
+                density(&field, null_density),
+            ),
+            validity(length, null_density),
+        )
+            .prop_map(move |(values, validity)| {
+                Box::new(FixedSizeListArray::from_data(
+                    data_type.clone(),
+                    values.into(),
+                    validity,
+                )) as Box<dyn Array>
+            })
+            .boxed(),
+        Struct(fields) => (
+            fields
+                .iter()
+                .map(|field| {
+                    array_of_length(field.data_type(), length, density(field, null_density))
+                })
+                .collect::<Vec<_>>(),
+            validity(length, null_density),
+        )
+            .prop_map(move |(values, validity)| {
+                let values = values.into_iter().map(Arc::from).collect();
+                Box::new(StructArray::from_data(data_type.clone(), values, validity))
+                    as Box<dyn Array>
+            })
+            .boxed(),
+        Dictionary(key_type, values, _) => match_integer_type!(key_type, |$T| {
+            dictionary::<$T>(values.as_ref(), length, null_density)
+        }),
+        other => panic!(
+            "Arbitrary arrays of data type {:?} are not supported",
+            other
+        ),
+    }
+}
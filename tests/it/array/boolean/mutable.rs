@@ -1,4 +1,4 @@
-use arrow2::array::{MutableArray, MutableBooleanArray};
+use arrow2::array::{BooleanArray, MutableArray, MutableBooleanArray};
 use arrow2::bitmap::MutableBitmap;
 use arrow2::datatypes::DataType;
 use arrow2::error::Result;
@@ -97,3 +97,18 @@ fn extend_trusted_len_values() {
     );
     assert_eq!(a.values(), &MutableBitmap::from([false, true, false]));
 }
+
+#[test]
+fn extend_from_array() -> Result<()> {
+    let array = BooleanArray::from([Some(true), None, Some(false), Some(true)]).slice(1, 3);
+
+    let mut a = MutableBooleanArray::from([Some(false)]);
+    a.extend_from_array(&array, 1, 2)?;
+    assert!(a.validity().is_none());
+    a.extend_from_array(&array, 0, 1)?;
+    assert_eq!(
+        a,
+        MutableBooleanArray::from([Some(false), Some(false), Some(true), None])
+    );
+    Ok(())
+}
@@ -12,8 +12,14 @@ mod struct_;
 mod union;
 mod utf8;
 
-use arrow2::array::{clone, new_empty_array, new_null_array, Array, PrimitiveArray};
+use std::sync::Arc;
+
+use arrow2::array::{
+    clone, deep_clone_compact, new_empty_array, new_null_array, Array, ListArray, PrimitiveArray,
+    Utf8Array,
+};
 use arrow2::bitmap::Bitmap;
+use arrow2::buffer::Buffer;
 use arrow2::datatypes::{DataType, Field, UnionMode};
 
 #[test]
@@ -103,3 +109,55 @@ fn test_with_validity() {
 struct A {
     array: std::sync::Arc<dyn Array>,
 }
+
+#[test]
+fn deep_clone_compact_sliced() {
+    let values = Utf8Array::<i32>::from_slice(["aa", "bbb", "c", "dddd"]);
+    let offsets = Buffer::from_slice([0i32, 2, 4]);
+    let data_type = ListArray::<i32>::default_datatype(DataType::Utf8);
+    let list = ListArray::<i32>::from_data(
+        data_type,
+        offsets,
+        Arc::new(values),
+        Some(Bitmap::from([false, true])),
+    );
+
+    let sliced = list.slice(1, 1);
+    let result = deep_clone_compact(&sliced);
+    assert_eq!(result.as_ref(), &sliced as &dyn Array);
+
+    let result = result.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 2]);
+    let values = result
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8Array<i32>>()
+        .unwrap();
+    assert_eq!(values.values().as_slice(), b"cdddd");
+}
+
+#[test]
+fn try_slice() {
+    let array = PrimitiveArray::<i32>::from([Some(1), None, Some(3)]);
+    assert_eq!(array.try_slice(1, 2).unwrap(), array.slice(1, 2));
+    assert_eq!(array.try_slice(3, 0).unwrap(), array.slice(3, 0));
+    assert!(array.try_slice(2, 2).is_err());
+    assert!(array.try_slice(4, 0).is_err());
+    assert!(array.try_slice(1, usize::MAX).is_err());
+
+    let array = Utf8Array::<i32>::from_slice(["a", "bb"]);
+    assert_eq!(array.try_slice(1, 1).unwrap(), array.slice(1, 1));
+    let error = array.try_slice(1, 2).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid argument error: The range 1..3 is out of bounds for an array of length 2"
+    );
+
+    let array: &dyn Array = &array;
+    assert_eq!(array.try_slice(0, 1).unwrap(), array.slice(0, 1));
+    assert!(array.try_slice(usize::MAX, 1).is_err());
+
+    let array = new_null_array(DataType::Null, 2);
+    assert_eq!(array.try_slice(1, 1).unwrap().len(), 1);
+    assert!(array.try_slice(1, 2).is_err());
+}
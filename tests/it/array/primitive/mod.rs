@@ -53,6 +53,33 @@ fn empty() {
     assert_eq!(array.validity(), None);
 }
 
+#[test]
+fn aligned_chunks() {
+    let array = Int32Array::from_vec((0..100).collect()).slice(3, 90);
+    let (prefix, middle, suffix) = array.aligned_chunks::<4>();
+
+    assert!(prefix.len() < 4);
+    assert!(suffix.len() < 4);
+    assert_eq!(middle.as_ptr() as usize % 16, 0);
+    let values = prefix
+        .iter()
+        .chain(middle.iter().flatten())
+        .chain(suffix.iter())
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(values, (3..93).collect::<Vec<_>>());
+
+    // lanes whose size is not a power of two have no prefix
+    let (prefix, middle, suffix) = array.aligned_chunks::<3>();
+    assert!(prefix.is_empty());
+    assert_eq!(middle.len(), 30);
+    assert!(suffix.is_empty());
+
+    let array = Int32Array::new_empty(DataType::Int32);
+    let (prefix, middle, suffix) = array.aligned_chunks::<4>();
+    assert!(prefix.is_empty() && middle.is_empty() && suffix.is_empty());
+}
+
 #[test]
 fn from() {
     let data = vec![Some(1), None, Some(10)];
@@ -231,3 +231,34 @@ fn wrong_data_type() {
     let values = vec![1u8];
     MutablePrimitiveArray::from_data(DataType::Utf8, values, None);
 }
+
+#[test]
+fn extend_from_range() {
+    let array = PrimitiveArray::<i32>::from([Some(1), None, Some(3), Some(4)]).slice(1, 3);
+
+    let mut a = MutablePrimitiveArray::<i32>::from_slice([0]);
+    a.extend_from_range(&array, 1, 2);
+    assert!(a.validity().is_none());
+    a.extend_from_range(&array, 0, 2);
+    let a: PrimitiveArray<i32> = a.into();
+    assert_eq!(
+        a,
+        PrimitiveArray::<i32>::from([Some(0), Some(3), Some(4), None, Some(3)])
+    );
+}
+
+#[test]
+fn extend_from_array() -> Result<()> {
+    let array = PrimitiveArray::<i32>::from([Some(1), None, Some(3)]);
+
+    let mut a = MutablePrimitiveArray::<i32>::new();
+    a.extend_from_array(&array, 1, 2)?;
+    assert_eq!(a, MutablePrimitiveArray::from([None, Some(3)]));
+
+    // out of bounds
+    assert!(a.extend_from_array(&array, 2, 2).is_err());
+    // different data type
+    let array = array.to(DataType::Date32);
+    assert!(a.extend_from_array(&array, 0, 1).is_err());
+    Ok(())
+}
@@ -80,3 +80,17 @@ fn test_extend_trusted_len() {
         Some(&Bitmap::from_u8_slice(&[0b00011011], 5))
     );
 }
+
+#[test]
+fn extend_from_range() {
+    let array = Utf8Array::<i32>::from([Some("a"), None, Some("bb"), Some("ccc")]).slice(1, 3);
+
+    let mut a = MutableUtf8Array::<i32>::from_iter([Some("x")]);
+    a.extend_from_range(&array, 1, 2);
+    a.extend_from_range(&array, 0, 1);
+    let a: Utf8Array<i32> = a.into();
+    assert_eq!(
+        a,
+        Utf8Array::<i32>::from([Some("x"), Some("bb"), Some("ccc"), None])
+    );
+}
@@ -72,6 +72,39 @@ fn count_zeros_1() {
     assert_eq!(count_zeros(input, 10, 90), 60);
 }
 
+#[test]
+fn pack_unpack() {
+    let values = (0..21).map(|i| i % 3 == 0).collect::<Vec<_>>();
+    let packed = pack_bools(&values);
+    assert_eq!(packed, vec![0b01001001, 0b10010010, 0b00000100]);
+
+    let mut unpacked = vec![false; values.len()];
+    unpack_bits(&packed, 0, &mut unpacked);
+    assert_eq!(unpacked, values);
+
+    let mut unpacked = vec![false; 17];
+    unpack_bits(&packed, 3, &mut unpacked);
+    assert_eq!(unpacked, values[3..20]);
+}
+
+proptest! {
+    /// Asserts that `Bitmap::to_bools` equals the bits of the bitmap
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn to_bools(bitmap in bitmap_strategy()) {
+        assert_eq!(bitmap.to_bools(), bitmap.iter().collect::<Vec<_>>());
+    }
+
+    /// Asserts that `pack_bools` equals packing the bits one by one
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn pack(values in prop::collection::vec(any::<bool>(), 0..100)) {
+        let mut expected = vec![0u8; bytes_for(values.len())];
+        values.iter().enumerate().for_each(|(i, value)| set_bit(&mut expected, i, *value));
+        assert_eq!(pack_bools(&values), expected);
+    }
+}
+
 proptest! {
     /// Asserts that `Bitmap::null_count` equals the number of unset bits
     #[test]
@@ -0,0 +1,40 @@
+use arrow2::buffer::{Buffer, ChunkedBuffer};
+
+#[test]
+fn push_and_extend() {
+    let mut buffer = ChunkedBuffer::<i64>::with_segment_capacity(3);
+    assert!(buffer.is_empty());
+    buffer.push(1);
+    buffer.extend_from_slice(&[2, 3, 4, 5, 6, 7]);
+    buffer.extend([8]);
+
+    assert_eq!(buffer.len(), 8);
+    let segments = buffer.segments().collect::<Vec<_>>();
+    assert_eq!(segments, vec![&[1, 2, 3][..], &[4, 5, 6], &[7, 8]]);
+    assert_eq!(buffer.get(4), Some(5));
+    assert_eq!(buffer.get(8), None);
+    assert_eq!(
+        buffer.iter().copied().collect::<Vec<_>>(),
+        (1..=8).collect::<Vec<_>>()
+    );
+
+    let buffer: Buffer<i64> = buffer.into();
+    assert_eq!(buffer.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn empty() {
+    let buffer = ChunkedBuffer::<u8>::new();
+    assert_eq!(buffer.segments().count(), 0);
+    assert!(buffer.into_buffer().is_empty());
+}
+
+#[test]
+fn from_iter() {
+    let buffer = (0..10_000u32).collect::<ChunkedBuffer<u32>>();
+    assert_eq!(buffer.len(), 10_000);
+    assert_eq!(
+        buffer.into_buffer(),
+        (0..10_000u32).collect::<Buffer<u32>>()
+    );
+}
@@ -74,3 +74,28 @@ fn from_vec() {
     assert_eq!(buffer.len(), 3);
     assert_eq!(buffer.as_slice(), &[0, 1, 2]);
 }
+
+#[test]
+fn from_owner() {
+    let owner = std::sync::Arc::new(vec![1u32, 2, 3, 4]);
+    // Vec<u32> does not implement `AsRef<[u8]>`
+    struct Owner(std::sync::Arc<Vec<u32>>);
+    impl AsRef<[u8]> for Owner {
+        fn as_ref(&self) -> &[u8] {
+            bytemuck::cast_slice(self.0.as_slice())
+        }
+    }
+    let bytes = std::sync::Arc::new(Owner(owner.clone()));
+
+    let buffer = Buffer::<u32>::from_owner(bytes.clone(), 4, 2).unwrap();
+    assert_eq!(buffer.as_slice(), &[2, 3]);
+    // the buffer points to the memory of the owner
+    assert_eq!(buffer.as_slice().as_ptr(), owner[1..].as_ptr());
+    assert_eq!(buffer.clone().slice(1, 1).as_slice(), &[3]);
+
+    // out of bounds
+    assert!(Buffer::<u32>::from_owner(bytes.clone(), 12, 2).is_none());
+    // not aligned
+    assert!(Buffer::<u32>::from_owner(bytes.clone(), 1, 1).is_none());
+    assert!(Buffer::<u32>::from_owner(bytes, 16, 0).unwrap().is_empty());
+}
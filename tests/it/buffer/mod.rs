@@ -1 +1,3 @@
+mod chunked;
 mod immutable;
+mod mutable;
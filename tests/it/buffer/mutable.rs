@@ -0,0 +1,43 @@
+use arrow2::buffer::{Buffer, MutableBuffer};
+
+#[test]
+fn out_of_order() {
+    let mut buffer = MutableBuffer::<i64>::with_capacity(6);
+    assert!(buffer.is_empty());
+    buffer.push(1);
+    buffer.extend_zeros(5);
+    assert_eq!(buffer.as_slice(), &[1, 0, 0, 0, 0, 0]);
+
+    buffer.set(5, 6);
+    buffer.write_slice_at(2, &[3, 4]);
+    buffer.write_slice_at(6, &[]);
+    assert_eq!(buffer.len(), 6);
+
+    let buffer: Buffer<i64> = buffer.into();
+    assert_eq!(buffer.as_slice(), &[1, 0, 3, 4, 0, 6]);
+}
+
+#[test]
+fn from_vec() {
+    let mut buffer = MutableBuffer::from(vec![1u8, 2]);
+    buffer.extend([3]);
+    buffer.extend_from_slice(&[4]);
+    assert_eq!(buffer.into_vec(), vec![1, 2, 3, 4]);
+    assert_eq!((0..3).collect::<MutableBuffer<u8>>().as_slice(), &[0, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn set_out_of_bounds() {
+    let mut buffer = MutableBuffer::<i32>::new();
+    buffer.extend_zeros(2);
+    buffer.set(2, 1);
+}
+
+#[test]
+#[should_panic]
+fn write_slice_out_of_bounds() {
+    let mut buffer = MutableBuffer::<i32>::new();
+    buffer.extend_zeros(2);
+    buffer.write_slice_at(1, &[1, 2]);
+}
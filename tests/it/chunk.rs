@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+
+fn struct_type() -> DataType {
+    DataType::Struct(vec![
+        Field::new("b", DataType::Int32, false),
+        Field::new("c", DataType::Utf8, true),
+    ])
+}
+
+fn struct_array() -> StructArray {
+    StructArray::from_data(
+        struct_type(),
+        vec![
+            Arc::new(Int32Array::from_slice([1, 2, 3])),
+            Arc::new(Utf8Array::<i32>::from([Some("x"), None, Some("z")])),
+        ],
+        Some([true, true, false].into()),
+    )
+}
+
+fn data() -> (Schema, Chunk<Arc<dyn Array>>) {
+    let list_type = DataType::List(Box::new(Field::new("item", struct_type(), true)));
+    let list = ListArray::<i32>::from_data(
+        list_type.clone(),
+        vec![0, 2, 3].into(),
+        Arc::new(struct_array()),
+        Some([true, false].into()),
+    );
+    let schema = Schema::from(vec![
+        Field::new("a", struct_type(), true),
+        Field::new("l", list_type, true),
+        Field::new("d", DataType::Int64, false),
+    ]);
+    let chunk = Chunk::new(vec![
+        Arc::new(struct_array().slice(0, 2)) as Arc<dyn Array>,
+        Arc::new(list),
+        Arc::new(Int64Array::from_slice([1, 2])),
+    ]);
+    (schema, chunk)
+}
+
+#[test]
+fn field_at_path() {
+    let (schema, _) = data();
+
+    assert_eq!(schema.field_at_path("d").unwrap(), schema.fields[2]);
+    assert_eq!(
+        schema.field_at_path("a.b").unwrap(),
+        Field::new("b", DataType::Int32, true)
+    );
+    assert_eq!(
+        schema.field_at_path("l.c").unwrap(),
+        Field::new(
+            "c",
+            DataType::List(Box::new(Field::new("c", DataType::Utf8, true))),
+            true
+        )
+    );
+    assert!(schema.field_at_path("a.e").is_err());
+    assert!(schema.field_at_path("d.e").is_err());
+    assert!(schema.field_at_path("e").is_err());
+}
+
+#[test]
+fn projection_from_paths() {
+    let (schema, _) = data();
+
+    assert_eq!(
+        schema
+            .projection_from_paths(&["l.b", "a.c", "a.b"])
+            .unwrap(),
+        vec![0, 1]
+    );
+    assert!(schema.projection_from_paths(&["a.e"]).is_err());
+}
+
+#[test]
+fn column_at_path_struct() {
+    let (schema, chunk) = data();
+
+    let result = chunk.column_at_path(&schema, "a.c").unwrap();
+    let expected = Utf8Array::<i32>::from([Some("x"), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let result = chunk.column_at_path(&schema, "d").unwrap();
+    assert_eq!(result.as_ref(), chunk.columns()[2].as_ref());
+}
+
+#[test]
+fn column_at_path_struct_nulls() {
+    let schema = Schema::from(vec![Field::new("a", struct_type(), true)]);
+    let chunk = Chunk::new(vec![Arc::new(struct_array()) as Arc<dyn Array>]);
+
+    let result = chunk.column_at_path(&schema, "a.b").unwrap();
+    let expected = Int32Array::from([Some(1), Some(2), None]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn column_at_path_list() {
+    let (schema, chunk) = data();
+
+    let result = chunk.column_at_path(&schema, "l.b").unwrap();
+    let expected = ListArray::<i32>::from_data(
+        schema.field_at_path("l.b").unwrap().data_type().clone(),
+        vec![0, 2, 3].into(),
+        Arc::new(Int32Array::from([Some(1), Some(2), None])),
+        Some([true, false].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(chunk.column_at_path(&schema, "l.e").is_err());
+}
+
+#[cfg(feature = "compute_concatenate")]
+#[test]
+fn concat() -> arrow2::error::Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let chunk1 = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("x"), None])),
+    ]);
+    let chunk2 = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([3])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["z"])),
+    ]);
+
+    let result = Chunk::concat(&[chunk1.clone(), chunk2], &schema)?;
+    let expected = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("x"), None, Some("z")])),
+    ]);
+    assert_eq!(result, expected);
+
+    let result = Chunk::<Arc<dyn Array>>::concat::<Arc<dyn Array>>(&[], &schema)?;
+    assert_eq!(result.arrays().len(), 2);
+    assert!(result.is_empty());
+    assert_eq!(result.arrays()[1].data_type(), &DataType::Utf8);
+    Ok(())
+}
+
+#[cfg(feature = "compute_concatenate")]
+#[test]
+fn concat_mismatch() {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let valid = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["x"])),
+    ]);
+
+    let columns = Chunk::new(vec![Arc::new(Int32Array::from_slice([1])) as Arc<dyn Array>]);
+    let error = Chunk::concat(&[valid.clone(), columns], &schema).unwrap_err();
+    assert!(error.to_string().contains("Chunk 1 has 1 columns"));
+
+    let data_type = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i64>::from_slice(["x"])),
+    ]);
+    let error = Chunk::concat(&[data_type, valid.clone()], &schema).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Column \"b\" of chunk 0 has data type"));
+
+    let nulls = Chunk::new(vec![
+        Arc::new(Int32Array::from([None])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_slice(["x"])),
+    ]);
+    let error = Chunk::concat(&[valid, nulls], &schema).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Column \"a\" of chunk 1 has nulls"));
+}
+
+#[test]
+fn slice() {
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([Some("a"), None, Some("c")])),
+    ]);
+    let expected = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([2, 3])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([None, Some("c")])),
+    ]);
+    assert_eq!(chunk.slice(1, 2), expected);
+    assert_eq!(chunk.try_slice(1, 2).unwrap(), expected);
+    assert!(chunk.try_slice(2, 2).is_err());
+    assert!(chunk.try_slice(usize::MAX, 2).is_err());
+
+    let empty = Chunk::<Arc<dyn Array>>::new(vec![]);
+    assert!(empty.try_slice(0, 0).unwrap().is_empty());
+    assert!(empty.try_slice(0, 1).is_err());
+}
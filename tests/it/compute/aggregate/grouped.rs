@@ -0,0 +1,51 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+use arrow2::datatypes::DataType;
+
+fn data() -> (Int64Array, UInt32Array) {
+    let values = Int64Array::from([Some(1), Some(-2), None, Some(4), Some(5), Some(6)]);
+    let groups = UInt32Array::from([Some(0), Some(0), Some(2), Some(0), None, Some(3)]);
+    (values, groups)
+}
+
+#[test]
+fn sum() {
+    let (values, groups) = data();
+    let result = grouped_sum(&values, &groups, 4).unwrap();
+    assert_eq!(result, Int64Array::from([Some(3), None, None, Some(6)]));
+}
+
+#[test]
+fn min_max() {
+    let (values, groups) = data();
+    let result = grouped_min(&values, &groups, 4).unwrap();
+    assert_eq!(result, Int64Array::from([Some(-2), None, None, Some(6)]));
+
+    let result = grouped_max(&values, &groups, 4).unwrap();
+    assert_eq!(result, Int64Array::from([Some(4), None, None, Some(6)]));
+}
+
+#[test]
+fn count() {
+    let (values, groups) = data();
+    let result = grouped_count(&values, &groups, 4).unwrap();
+    assert_eq!(result, UInt64Array::from_slice([3, 0, 0, 1]));
+}
+
+#[test]
+fn logical_type() {
+    let values = Int32Array::from_slice([1, 2]).to(DataType::Date32);
+    let groups = UInt32Array::from_slice([1, 1]);
+    let result = grouped_max(&values, &groups, 2).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from([None, Some(2)]).to(DataType::Date32)
+    );
+}
+
+#[test]
+fn errors() {
+    let (values, groups) = data();
+    assert!(grouped_sum(&values, &groups, 3).is_err());
+    assert!(grouped_count(&values, &groups.slice(0, 2), 4).is_err());
+}
@@ -0,0 +1,137 @@
+use arrow2::array::*;
+use arrow2::compute::aggregate::*;
+use arrow2::datatypes::{DataType, TimeUnit};
+
+fn list(values: Vec<Option<Vec<Option<i32>>>>) -> ListArray<i32> {
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(values).unwrap();
+    array.into()
+}
+
+fn data() -> ListArray<i32> {
+    list(vec![
+        Some(vec![Some(1), Some(-2), Some(3)]),
+        None,
+        Some(vec![]),
+        Some(vec![None, Some(5), None]),
+        Some(vec![None]),
+    ])
+}
+
+#[test]
+fn sum() {
+    let result = list_sum_primitive::<i32, i32>(&data()).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from([Some(2), None, None, Some(5), None])
+    );
+}
+
+#[test]
+fn min_max() {
+    let array = data();
+    let result = list_min_primitive::<i32, i32>(&array).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from([Some(-2), None, None, Some(5), None])
+    );
+
+    let result = list_max_primitive::<i32, i32>(&array).unwrap();
+    assert_eq!(
+        result,
+        Int32Array::from([Some(3), None, None, Some(5), None])
+    );
+}
+
+#[test]
+fn mean() {
+    let result = list_mean_primitive::<i32, i32>(&data()).unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from([Some(2.0 / 3.0), None, None, Some(5.0), None])
+    );
+}
+
+#[test]
+fn sliced() {
+    let array = data().slice(3, 2);
+    let result = list_sum_primitive::<i32, i32>(&array).unwrap();
+    assert_eq!(result, Int32Array::from([Some(5), None]));
+}
+
+#[test]
+fn unaligned_validity() {
+    // sublists longer than a chunk of bits whose validity does not start at a byte boundary
+    let values = (0..200)
+        .map(|x| if x % 3 == 0 { None } else { Some(x) })
+        .collect::<Int32Array>()
+        .slice(3, 197);
+    let offsets = vec![0, 7, 150, 197];
+    let array = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        offsets.clone().into(),
+        std::sync::Arc::new(values.clone()),
+        None,
+    );
+
+    let sublists = offsets.windows(2).map(|window| {
+        values
+            .iter()
+            .skip(window[0] as usize)
+            .take((window[1] - window[0]) as usize)
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>()
+    });
+    let (sum, (min, max)): (Vec<_>, (Vec<_>, Vec<_>)) = sublists
+        .map(|x| {
+            let sum = x.iter().sum::<i32>();
+            (
+                Some(sum),
+                (x.iter().min().copied(), x.iter().max().copied()),
+            )
+        })
+        .unzip();
+
+    let result = list_sum_primitive::<i32, i32>(&array).unwrap();
+    assert_eq!(result, Int32Array::from(sum));
+    let result = list_min_primitive::<i32, i32>(&array).unwrap();
+    assert_eq!(result, Int32Array::from(min));
+    let result = list_max_primitive::<i32, i32>(&array).unwrap();
+    assert_eq!(result, Int32Array::from(max));
+}
+
+#[test]
+fn wrong_values() {
+    assert!(list_sum_primitive::<i32, i64>(&data()).is_err());
+}
+
+#[test]
+fn dyn_() {
+    let array = data();
+    let result = list_sum(&array).unwrap();
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from([Some(2), None, None, Some(5), None]) as &dyn Array
+    );
+
+    let result = list_mean(&array).unwrap();
+    assert_eq!(result.data_type(), &DataType::Float64);
+}
+
+#[test]
+fn dyn_logical_type() {
+    let values = Int64Array::from_slice([1, 2, 3]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let array = ListArray::<i64>::from_data(
+        ListArray::<i64>::default_datatype(values.data_type().clone()),
+        vec![0, 2, 3].into(),
+        std::sync::Arc::new(values),
+        None,
+    );
+    let result = list_max(&array).unwrap();
+    let expected = Int64Array::from_slice([2, 3]).to(DataType::Timestamp(TimeUnit::Second, None));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    assert!(list_mean(&array).is_err());
+    assert!(list_min(&Int32Array::from_slice([1])).is_err());
+}
@@ -1,7 +1,10 @@
 use arrow2::compute::aggregate::{
-    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_primitive,
-    min_string,
+    max_binary, max_boolean, max_primitive, max_string, min_binary, min_boolean, min_max,
+    min_max_binary, min_max_boolean, min_max_chunked, min_max_primitive, min_max_primitive_chunked,
+    min_max_string, min_primitive, min_string,
 };
+use arrow2::error::Result;
+use arrow2::scalar::{BooleanScalar, PrimitiveScalar, Scalar, Utf8Scalar};
 use arrow2::{array::*, datatypes::DataType};
 
 #[test]
@@ -186,3 +189,110 @@ fn test_binary_min_max_1() {
     assert_eq!(Some("a".as_bytes()), min_binary(&a));
     assert_eq!(Some("b".as_bytes()), max_binary(&a));
 }
+
+#[test]
+fn min_max_primitive_fused() {
+    // larger than the number of lanes, with nulls and an offset
+    let a = Int32Array::from(
+        (0..100)
+            .map(|x| if x % 7 == 0 { None } else { Some(50 - x) })
+            .collect::<Vec<_>>(),
+    );
+    let a = a.slice(3, 90);
+    assert_eq!(
+        min_max_primitive(&a),
+        min_primitive(&a).zip(max_primitive(&a))
+    );
+    assert_eq!(min_max_primitive(&a), Some((-42, 47)));
+
+    // same semantics as `min_primitive` and `max_primitive` for NaNs
+    let a = Float64Array::from_slice([1.0, f64::NAN, -1.0]);
+    assert_eq!(
+        min_max_primitive(&a),
+        min_primitive(&a).zip(max_primitive(&a))
+    );
+
+    let a = Int32Array::from([None, None]);
+    assert_eq!(min_max_primitive(&a), None);
+}
+
+#[test]
+fn min_max_primitive_chunks() {
+    let a = Int32Array::from([Some(5), None, Some(3)]);
+    let b = Int32Array::from([None, None]);
+    let c = Int32Array::from_slice([7, 4]);
+    assert_eq!(min_max_primitive_chunked(&[&a, &b, &c]), Some((3, 7)));
+    assert_eq!(min_max_primitive_chunked(&[&b]), None);
+}
+
+#[test]
+fn min_max_non_primitive() {
+    let a = BooleanArray::from([Some(true), None, Some(true)]);
+    assert_eq!(min_max_boolean(&a), Some((true, true)));
+    let a = BooleanArray::from_slice([false, true]);
+    assert_eq!(min_max_boolean(&a), Some((false, true)));
+    let a = BooleanArray::from([None, Some(false)]);
+    assert_eq!(min_max_boolean(&a), Some((false, false)));
+
+    let a = Utf8Array::<i32>::from([Some("b"), None, Some("a"), Some("c")]);
+    assert_eq!(min_max_string(&a), Some(("a", "c")));
+
+    let a = BinaryArray::<i32>::from([Some(b"b"), None]);
+    assert_eq!(min_max_binary(&a), Some((b"b".as_ref(), b"b".as_ref())));
+}
+
+#[test]
+fn min_max_dyn() -> Result<()> {
+    let a = Int64Array::from([Some(5), None, Some(-3)]).to(DataType::Date64);
+    let (min, max) = min_max(&a)?;
+    assert_eq!(
+        min.as_ref(),
+        &PrimitiveScalar::<i64>::new(DataType::Date64, Some(-3)) as &dyn Scalar
+    );
+    assert_eq!(
+        max.as_ref(),
+        &PrimitiveScalar::<i64>::new(DataType::Date64, Some(5)) as &dyn Scalar
+    );
+
+    let a = Utf8Array::<i32>::from([Some("b"), None]);
+    let b = Utf8Array::<i32>::from([Some("c"), Some("a")]);
+    let (min, max) = min_max_chunked(&[&a, &b])?;
+    assert_eq!(
+        min.as_ref(),
+        &Utf8Scalar::<i32>::new(Some("a")) as &dyn Scalar
+    );
+    assert_eq!(
+        max.as_ref(),
+        &Utf8Scalar::<i32>::new(Some("c")) as &dyn Scalar
+    );
+
+    let a = BooleanArray::from([None, None]);
+    let (min, max) = min_max(&a)?;
+    assert_eq!(min.as_ref(), &BooleanScalar::new(None) as &dyn Scalar);
+    assert_eq!(max.as_ref(), &BooleanScalar::new(None) as &dyn Scalar);
+
+    assert!(min_max_chunked(&[]).is_err());
+    assert!(min_max_chunked(&[&a, &b]).is_err());
+    assert!(min_max(&NullArray::from_data(DataType::Null, 1)).is_err());
+    Ok(())
+}
+
+#[test]
+fn min_max_primitive_sliced_validity() {
+    let a: Int32Array = (0..100)
+        .map(|i| if i % 7 == 0 { None } else { Some(50 - i) })
+        .collect();
+    let a = a.slice(3, 87);
+    assert_eq!(Some(-39), min_primitive(&a));
+    assert_eq!(Some(47), max_primitive(&a));
+    assert_eq!(Some((-39, 47)), min_max_primitive(&a));
+
+    // a validity without nulls
+    let a = Int32Array::from_vec((0..100).map(|i| 50 - i).collect())
+        .with_validity(Some(vec![true; 100].into()));
+    assert!(a.validity().is_some());
+    let a = a.slice(3, 87);
+    assert_eq!(Some(-39), min_primitive(&a));
+    assert_eq!(Some(47), max_primitive(&a));
+    assert_eq!(Some((-39, 47)), min_max_primitive(&a));
+}
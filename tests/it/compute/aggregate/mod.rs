@@ -1,3 +1,5 @@
+mod grouped;
+mod list;
 mod memory;
 mod min_max;
 mod sum;
@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::aggregate::{sum, sum_primitive};
+use arrow2::compute::aggregate::{compensated_sum, compensated_sum_primitive, sum, sum_primitive};
 use arrow2::compute::arithmetics;
 use arrow2::datatypes::DataType;
 use arrow2::scalar::{PrimitiveScalar, Scalar};
@@ -52,3 +52,45 @@ fn test_primitive_array_sum_large_64() {
         sum_primitive(&c)
     );
 }
+
+#[test]
+fn sum_sliced_validity() {
+    let a: Int32Array = (0..100)
+        .map(|i| if i % 5 == 0 { None } else { Some(i) })
+        .collect();
+    let expected = (3..90).filter(|i| i % 5 != 0).sum::<i32>();
+    assert_eq!(Some(expected), sum_primitive(&a.slice(3, 87)));
+
+    // a validity without nulls
+    let a = Int32Array::from_vec((0..100).collect()).with_validity(Some(vec![true; 100].into()));
+    assert!(a.validity().is_some());
+    assert_eq!(Some((3..90).sum::<i32>()), sum_primitive(&a.slice(3, 87)));
+}
+
+#[test]
+fn compensated_sum_f64() {
+    // naive summation loses the small values
+    let mut values = vec![1.0e16, 1.0, -1.0e16];
+    values.resize(102, 1.0);
+    let a = Float64Array::from_vec(values);
+    assert_eq!(Some(100.0), compensated_sum_primitive(&a));
+
+    let a = Float64Array::from([Some(1.0e16), None, Some(1.0), Some(-1.0e16)]);
+    assert_eq!(Some(1.0), compensated_sum_primitive(&a));
+
+    let a = Float64Array::from([None, None]);
+    assert_eq!(None, compensated_sum_primitive(&a));
+}
+
+#[test]
+fn compensated_sum_dyn() {
+    let a = Float32Array::from_vec(vec![0.1; 1_000_000]);
+    let result = compensated_sum(&a).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<PrimitiveScalar<f32>>()
+        .unwrap();
+    assert!((result.value().unwrap() - 100_000.0).abs() < 0.1);
+
+    assert!(compensated_sum(&Int32Array::from_slice([1])).is_err());
+}
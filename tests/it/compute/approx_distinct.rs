@@ -0,0 +1,84 @@
+use arrow2::array::*;
+use arrow2::compute::approx_distinct::*;
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+
+fn assert_close(estimate: u64, expected: u64) {
+    let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+    assert!(error < 0.05, "{} is not close to {}", estimate, expected);
+}
+
+#[test]
+fn exact_for_small_cardinalities() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(2), Some(1), Some(3)]);
+    assert_eq!(approx_distinct(&array)?, 3);
+
+    let array = Utf8Array::<i32>::from([Some("a"), Some("b"), None, Some("a")]);
+    assert_eq!(approx_distinct(&array)?, 2);
+
+    let array = BooleanArray::from_slice([true, true, false]);
+    assert_eq!(approx_distinct(&array)?, 2);
+
+    let array = Float64Array::from([Some(1.0), Some(1.0), Some(-0.0), None]);
+    assert_eq!(approx_distinct(&array)?, 2);
+
+    let array = Int64Array::new_empty(DataType::Int64);
+    assert_eq!(approx_distinct(&array)?, 0);
+    Ok(())
+}
+
+#[test]
+fn large_cardinalities() -> Result<()> {
+    let array = Int64Array::from_vec((0..100000).map(|x| x % 50000).collect());
+    assert_close(approx_distinct(&array)?, 50000);
+
+    let array = Utf8Array::<i64>::from_slice((0..20000).map(|x| x.to_string()).collect::<Vec<_>>());
+    assert_close(approx_distinct(&array)?, 20000);
+    Ok(())
+}
+
+#[test]
+fn merge() -> Result<()> {
+    let mut sketch = HyperLogLog::new(14)?;
+    let mut expected = HyperLogLog::new(14)?;
+    for chunk in 0..4 {
+        let array = UInt32Array::from_vec((0..10000).map(|x| x + chunk * 5000).collect());
+        let mut partial = HyperLogLog::new(14)?;
+        partial.update(&array)?;
+        sketch.merge(&partial)?;
+        expected.update(&array)?;
+    }
+    assert_eq!(sketch, expected);
+    assert_close(sketch.count(), 25000);
+
+    assert!(sketch.merge(&HyperLogLog::default()).is_err());
+    Ok(())
+}
+
+#[test]
+fn registers() -> Result<()> {
+    let mut sketch = HyperLogLog::new(4)?;
+    sketch.update(&Int32Array::from_slice([1, 2, 3]))?;
+    assert_eq!(sketch.registers().len(), 16);
+
+    let other = HyperLogLog::try_from_registers(sketch.registers().to_vec())?;
+    assert_eq!(other, sketch);
+    assert_eq!(other.precision(), 4);
+
+    assert!(HyperLogLog::try_from_registers(vec![0; 15]).is_err());
+    assert!(HyperLogLog::try_from_registers(vec![0; 8]).is_err());
+    assert!(HyperLogLog::try_from_registers(vec![100; 16]).is_err());
+    assert!(HyperLogLog::new(3).is_err());
+    assert!(HyperLogLog::new(19).is_err());
+    Ok(())
+}
+
+#[test]
+fn unsupported() {
+    assert!(can_approx_distinct(&DataType::Float32));
+    assert!(can_approx_distinct(&DataType::Utf8));
+    assert!(!can_approx_distinct(&DataType::Null));
+
+    let array = NullArray::from_data(DataType::Null, 2);
+    assert!(approx_distinct(&array).is_err());
+}
@@ -1,5 +1,6 @@
 mod basic;
 mod decimal;
+mod promote;
 mod time;
 
 use arrow2::array::*;
@@ -60,6 +61,7 @@ fn consistency() {
         Duration(TimeUnit::Microsecond),
         Duration(TimeUnit::Nanosecond),
         Interval(IntervalUnit::MonthDayNano),
+        Decimal(5, 2),
     ];
 
     let cases = datatypes.clone().into_iter().zip(datatypes.into_iter());
@@ -82,6 +84,54 @@ fn consistency() {
         if can_rem(&lhs, &rhs) {
             rem(lhs_a.as_ref(), rhs_a.as_ref());
         }
+        // the fallible kernels error exactly when they are not supported
+        let can_checked = can_checked_arithmetics(&lhs, &rhs);
+        assert_eq!(
+            checked_add(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_checked
+        );
+        assert_eq!(
+            checked_sub(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_checked
+        );
+        assert_eq!(
+            checked_mul(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_checked
+        );
+        assert_eq!(
+            checked_div(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_checked
+        );
+        assert_eq!(
+            saturating_add(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_checked
+        );
+        assert_eq!(
+            saturating_sub(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_checked
+        );
+        assert_eq!(
+            saturating_mul(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_checked
+        );
+
+        let can_integer = can_integer_arithmetics(&lhs, &rhs);
+        assert_eq!(
+            checked_rem(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_integer
+        );
+        assert_eq!(
+            overflowing_add(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_integer
+        );
+        assert_eq!(
+            overflowing_sub(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_integer
+        );
+        assert_eq!(
+            overflowing_mul(lhs_a.as_ref(), rhs_a.as_ref()).is_ok(),
+            can_integer
+        );
     });
 }
 
@@ -106,3 +156,91 @@ fn test_neg_dict() {
     );
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn test_checked_add() {
+    let a = Int8Array::from([Some(100), None, Some(1)]);
+    let b = Int8Array::from([Some(100), Some(1), Some(1)]);
+    let result = checked_add(&a, &b).unwrap();
+    let expected = Int8Array::from([None, None, Some(2)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_checked_div() {
+    let a = Int8Array::from_slice([-128, 1, 6]);
+    let b = Int8Array::from_slice([-1, 0, 3]);
+    let result = checked_div(&a, &b).unwrap();
+    let expected = Int8Array::from([None, None, Some(2)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_saturating_sub() {
+    let a = UInt8Array::from([Some(1), None, Some(5)]);
+    let b = UInt8Array::from([Some(2), Some(1), Some(3)]);
+    let result = saturating_sub(&a, &b).unwrap();
+    let expected = UInt8Array::from([Some(0), None, Some(2)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_overflowing_mul() {
+    let a = Int8Array::from_slice([64, 2]);
+    let b = Int8Array::from_slice([2, 2]);
+    let (result, overflow) = overflowing_mul(&a, &b).unwrap();
+    let expected = Int8Array::from_slice([-128, 4]);
+    assert_eq!(expected, result.as_ref());
+    assert_eq!(overflow, [true, false].into());
+}
+
+#[test]
+fn test_add_decimal() {
+    let a = Int128Array::from([Some(1111111), None]).to(Decimal(7, 2));
+    let b = Int128Array::from([Some(11111111), Some(1)]).to(Decimal(8, 3));
+    let result = add(&a, &b);
+    let expected = Int128Array::from([Some(22222221), None]).to(Decimal(8, 3));
+    assert_eq!(expected, result.as_ref());
+
+    let result = add(&a, &a);
+    let expected = Int128Array::from([Some(2222222), None]).to(Decimal(7, 2));
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn test_checked_add_decimal() {
+    let a = Int128Array::from([Some(99999), None, Some(1)]).to(Decimal(5, 2));
+    let b = Int128Array::from([Some(1), Some(1), Some(1)]).to(Decimal(5, 2));
+    assert!(can_checked_arithmetics(a.data_type(), b.data_type()));
+    let result = checked_add(&a, &b).unwrap();
+    let expected = Int128Array::from([None, None, Some(2)]).to(Decimal(5, 2));
+    assert_eq!(expected, result.as_ref());
+
+    assert!(!can_checked_arithmetics(&Decimal(5, 2), &Decimal(5, 3)));
+    let c = Int128Array::from([Some(1), Some(1), Some(1)]).to(Decimal(5, 3));
+    assert!(checked_add(&a, &c).is_err());
+}
+
+#[test]
+fn test_integer_arithmetics_unsupported() {
+    let a = Int8Array::from_slice([1]);
+    let b = Int16Array::from_slice([1]);
+    assert!(!can_checked_arithmetics(a.data_type(), b.data_type()));
+    assert!(checked_add(&a, &b).is_err());
+    assert!(saturating_sub(&a, &b).is_err());
+    assert!(overflowing_mul(&a, &b).is_err());
+
+    let a = Float32Array::from_slice([1.0]);
+    assert!(!can_integer_arithmetics(a.data_type(), a.data_type()));
+    assert!(checked_rem(&a, &a).is_err());
+    assert!(overflowing_add(&a, &a).is_err());
+}
+
+#[test]
+fn test_saturating_mul_decimal() {
+    let a = Int128Array::from([Some(50000), Some(-50000), Some(150)]).to(Decimal(5, 2));
+    let b = Int128Array::from([Some(300), Some(300), Some(200)]).to(Decimal(5, 2));
+    let result = saturating_mul(&a, &b).unwrap();
+    let expected = Int128Array::from([Some(99999), Some(-99999), Some(300)]).to(Decimal(5, 2));
+    assert_eq!(expected, result.as_ref());
+}
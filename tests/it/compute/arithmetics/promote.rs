@@ -0,0 +1,80 @@
+use arrow2::array::*;
+use arrow2::compute::arithmetics::promote::*;
+use arrow2::datatypes::DataType::{self, *};
+use arrow2::error::Result;
+
+#[test]
+fn supertypes() {
+    let cases = [
+        (Int8, Int32, Some(Int32)),
+        (UInt16, UInt8, Some(UInt16)),
+        (Int8, UInt16, Some(Int32)),
+        (UInt32, Int64, Some(Int64)),
+        (UInt64, Int8, Some(Int64)),
+        (Float32, Int16, Some(Float32)),
+        (Float32, UInt32, Some(Float64)),
+        (Float32, Float64, Some(Float64)),
+        (Int64, Float64, Some(Float64)),
+        (Decimal(5, 2), Int32, Some(Decimal(12, 2))),
+        (Decimal(5, 2), Decimal(10, 4), Some(Decimal(10, 4))),
+        (Decimal(5, 2), Float32, Some(Float64)),
+        (Utf8, Int32, None),
+        (Duration(arrow2::datatypes::TimeUnit::Second), Int64, None),
+    ];
+    for (lhs, rhs, expected) in cases {
+        assert_eq!(
+            numeric_supertype(&lhs, &rhs),
+            expected,
+            "{:?} {:?}",
+            lhs,
+            rhs
+        );
+        assert_eq!(
+            numeric_supertype(&rhs, &lhs),
+            expected,
+            "{:?} {:?}",
+            rhs,
+            lhs
+        );
+    }
+}
+
+#[test]
+fn add_mixed() -> Result<()> {
+    let a = Int8Array::from([Some(1), None, Some(-3)]);
+    let b = UInt16Array::from([Some(1000), Some(1), Some(2)]);
+    let result = add(&a, &b)?;
+    let expected = Int32Array::from([Some(1001), None, Some(-1)]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn mul_float() -> Result<()> {
+    let a = Int32Array::from_slice([2, 3]);
+    let b = Float32Array::from_slice([0.5, 1.5]);
+    let result = mul(&a, &b)?;
+    let expected = Float64Array::from_slice([1.0, 4.5]);
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn sub_decimal() -> Result<()> {
+    let a = PrimitiveArray::<i128>::from_slice([150, 200]).to(DataType::Decimal(5, 2));
+    let b = Int8Array::from_slice([1, 3]);
+    let result = sub(&a, &b)?;
+    let expected = PrimitiveArray::<i128>::from_slice([50, -100]).to(DataType::Decimal(5, 2));
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let a = Int8Array::from_slice([1]);
+    assert!(add(&a, &Utf8Array::<i32>::from_slice(["a"])).is_err());
+    assert!(add(&a, &Int8Array::from_slice([1, 2])).is_err());
+
+    let decimal = PrimitiveArray::<i128>::from_slice([1]).to(DataType::Decimal(5, 2));
+    assert!(rem(&a, &decimal).is_err());
+}
@@ -0,0 +1,113 @@
+use arrow2::array::*;
+use arrow2::compute::between::*;
+use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::error::Result;
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+const OPTIONS: [BetweenOptions; 4] = [
+    BetweenOptions {
+        low_inclusive: true,
+        high_inclusive: true,
+    },
+    BetweenOptions {
+        low_inclusive: true,
+        high_inclusive: false,
+    },
+    BetweenOptions {
+        low_inclusive: false,
+        high_inclusive: true,
+    },
+    BetweenOptions {
+        low_inclusive: false,
+        high_inclusive: false,
+    },
+];
+
+#[test]
+fn primitive_scalar() {
+    let array = Int32Array::from([Some(1), Some(2), None, Some(3), Some(4)]);
+    let expected = [
+        [Some(false), Some(true), None, Some(true), Some(true)],
+        [Some(false), Some(true), None, Some(true), Some(false)],
+        [Some(false), Some(false), None, Some(true), Some(true)],
+        [Some(false), Some(false), None, Some(true), Some(false)],
+    ];
+    for (options, expected) in OPTIONS.into_iter().zip(expected) {
+        let result = between_scalar(&array, 2, 4, options);
+        assert_eq!(result, BooleanArray::from(expected), "{:?}", options);
+    }
+
+    let array = Float64Array::from_slice([f64::NAN, 0.5, 1.0]).slice(1, 2);
+    let result = between_scalar(&array, 0.0, 1.0, BetweenOptions::default());
+    assert_eq!(result, BooleanArray::from_slice([true, true]));
+    let array = Float64Array::from_slice([f64::NAN]);
+    let result = between_scalar(&array, 0.0, 1.0, BetweenOptions::default());
+    assert_eq!(result, BooleanArray::from_slice([false]));
+}
+
+#[test]
+fn primitive_arrays() -> Result<()> {
+    let array = Int64Array::from([Some(1), Some(5), None, Some(3)]);
+    let low = Int64Array::from([Some(0), Some(5), Some(0), None]);
+    let high = Int64Array::from_slice([1, 10, 10, 10]);
+
+    let result = between(&array, &low, &high, BetweenOptions::default())?;
+    let expected = BooleanArray::from([Some(true), Some(true), None, None]);
+    assert_eq!(result, expected);
+
+    let result = between(&array, &low, &high, OPTIONS[3])?;
+    let expected = BooleanArray::from([Some(false), Some(false), None, None]);
+    assert_eq!(result, expected);
+
+    assert!(between(&array, &low.slice(0, 3), &high, OPTIONS[0]).is_err());
+    Ok(())
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("apple"), Some("b"), None, Some("m"), Some("z")]);
+    let result = between_utf8_scalar(&array, "b", "m", BetweenOptions::default());
+    let expected = BooleanArray::from([Some(false), Some(true), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = between_utf8_scalar(&array, "b", "m", OPTIONS[3]);
+    let expected = BooleanArray::from([Some(false), Some(false), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    let low = Utf8Array::<i32>::from_slice(["a", "c", "a", "a", "a"]);
+    let high = Utf8Array::<i32>::from([Some("b"), Some("d"), Some("z"), None, Some("z")]);
+    let result = between_utf8(&array, &low, &high, BetweenOptions::default())?;
+    let expected = BooleanArray::from([Some(true), Some(false), None, None, Some(true)]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn dyn_scalar() -> Result<()> {
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    let array = Int64Array::from([Some(1), None, Some(3)]).to(data_type.clone());
+    let low = PrimitiveScalar::<i64>::new(data_type.clone(), Some(2));
+    let high = PrimitiveScalar::<i64>::new(data_type.clone(), Some(3));
+    let result = between_scalar_dyn(&array, &low, &high, BetweenOptions::default())?;
+    assert_eq!(result, BooleanArray::from([Some(false), None, Some(true)]));
+
+    let null = PrimitiveScalar::<i64>::new(data_type, None);
+    let result = between_scalar_dyn(&array, &low, &null, BetweenOptions::default())?;
+    assert_eq!(result, BooleanArray::new_null(DataType::Boolean, 3));
+
+    // bounds of a different type
+    let low = PrimitiveScalar::<i64>::from(Some(2));
+    assert!(between_scalar_dyn(&array, &low, &low, BetweenOptions::default()).is_err());
+
+    let array = Utf8Array::<i64>::from_slice(["a", "c"]);
+    let low = Utf8Scalar::<i64>::from(Some("b"));
+    let high = Utf8Scalar::<i64>::from(Some("d"));
+    let result = between_scalar_dyn(&array, &low, &high, BetweenOptions::default())?;
+    assert_eq!(result, BooleanArray::from_slice([false, true]));
+
+    assert!(can_between(&DataType::Date32));
+    assert!(!can_between(&DataType::Boolean));
+    let array = BooleanArray::from_slice([true]);
+    assert!(between_scalar_dyn(&array, &low, &high, BetweenOptions::default()).is_err());
+    Ok(())
+}
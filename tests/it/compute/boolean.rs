@@ -66,6 +66,28 @@ fn array_or_validity() {
     assert_eq!(c, expected);
 }
 
+#[test]
+fn array_xor() {
+    let a = BooleanArray::from(&[Some(false), Some(false), Some(true), Some(true), None]);
+    let b = BooleanArray::from(&[Some(false), Some(true), Some(false), Some(true), Some(true)]);
+    let c = xor(&a, &b).unwrap();
+
+    let expected = BooleanArray::from(&[Some(false), Some(true), Some(true), Some(false), None]);
+
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn array_xor_sliced() {
+    let a = BooleanArray::from_slice([true; 16]).slice(3, 9);
+    let b = BooleanArray::from(&[Some(true), None, Some(false)].repeat(4)).slice(1, 9);
+    let c = xor(&a, &b).unwrap();
+
+    let expected = BooleanArray::from(&[None, Some(true), Some(false)].repeat(3));
+
+    assert_eq!(c, expected);
+}
+
 #[test]
 fn array_not() {
     let a = BooleanArray::from_slice(vec![false, true]);
@@ -420,6 +442,20 @@ fn array_or_scalar_validity() {
     assert_eq!(real, expected);
 }
 
+#[test]
+fn array_xor_scalar() {
+    let array = BooleanArray::from(&[Some(false), Some(true), None]);
+
+    let real = xor_scalar(&array, &BooleanScalar::new(Some(true)));
+    assert_eq!(real, BooleanArray::from(&[Some(true), Some(false), None]));
+
+    let real = xor_scalar(&array, &BooleanScalar::new(Some(false)));
+    assert_eq!(real, array);
+
+    let real = xor_scalar(&array, &BooleanScalar::new(None));
+    assert_eq!(real, BooleanArray::from(&[None, None, None]));
+}
+
 #[test]
 fn test_any_all() {
     let array = BooleanArray::from(&[None, Some(false), Some(true)]);
@@ -446,4 +482,9 @@ fn test_any_all() {
     let array = BooleanArray::from(&[Some(false)]);
     assert!(!any(&array));
     assert!(!all(&array));
+    // values of null slots are ignored
+    let array =
+        BooleanArray::from_slice([true; 3]).with_validity(Some([false, true, false].into()));
+    assert!(any(&array));
+    assert!(!any(&array.slice(2, 1)));
 }
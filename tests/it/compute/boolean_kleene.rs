@@ -84,6 +84,54 @@ fn or_generic() {
     assert_eq!(c, expected);
 }
 
+#[test]
+fn xor_generic() {
+    let lhs = BooleanArray::from(&[
+        None,
+        None,
+        None,
+        Some(false),
+        Some(false),
+        Some(false),
+        Some(true),
+        Some(true),
+        Some(true),
+    ]);
+    let rhs = BooleanArray::from(&[
+        None,
+        Some(false),
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+    ]);
+    let c = xor(&lhs, &rhs).unwrap();
+
+    let expected = BooleanArray::from(&[
+        None,
+        None,
+        None,
+        None,
+        Some(false),
+        Some(true),
+        None,
+        Some(true),
+        Some(false),
+    ]);
+
+    assert_eq!(c, expected);
+}
+
+#[test]
+fn not_generic() {
+    let array = BooleanArray::from(&[None, Some(false), Some(true)]);
+    let expected = BooleanArray::from(&[None, Some(true), Some(false)]);
+    assert_eq!(not(&array), expected);
+}
+
 #[test]
 fn or_right_nulls() {
     let a = BooleanArray::from_slice(&[false, false, false, true, true, true]);
@@ -214,3 +262,48 @@ fn array_or_none() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn array_xor_scalar() {
+    let array = BooleanArray::from(&[Some(true), Some(false), None]);
+
+    let result = xor_scalar(&array, &BooleanScalar::new(Some(false)));
+    assert_eq!(result, array);
+
+    let result = xor_scalar(&array, &BooleanScalar::new(None));
+    assert_eq!(result, BooleanArray::from(&[None, None, None]));
+}
+
+#[test]
+fn any_all() {
+    let array = BooleanArray::from(&[Some(true), None, Some(false)]);
+    assert_eq!(any(&array), Some(true));
+    assert_eq!(all(&array), Some(false));
+
+    let array = BooleanArray::from(&[None, Some(false)]);
+    assert_eq!(any(&array), None);
+    assert_eq!(all(&array), Some(false));
+
+    let array = BooleanArray::from(&[None, Some(true)]);
+    assert_eq!(any(&array), Some(true));
+    assert_eq!(all(&array), None);
+
+    let array = BooleanArray::from_slice([true, true]);
+    assert_eq!(any(&array), Some(true));
+    assert_eq!(all(&array), Some(true));
+
+    let array = BooleanArray::from_slice([false; 10]);
+    assert_eq!(any(&array), Some(false));
+    assert_eq!(all(&array), Some(false));
+
+    let array = BooleanArray::from_slice([] as [bool; 0]);
+    assert_eq!(any(&array), Some(false));
+    assert_eq!(all(&array), Some(true));
+
+    // values of null slots are ignored
+    let array = BooleanArray::from_slice([true, false, true])
+        .with_validity(Some([false, false, true].into()))
+        .slice(0, 2);
+    assert_eq!(any(&array), None);
+    assert_eq!(all(&array), None);
+}
@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
 use arrow2::array::*;
-use arrow2::compute::cast::{can_cast_types, cast, CastOptions};
+use arrow2::compute::cast::{
+    can_cast_types, cast, try_binary_to_fixed_size_binary, try_list_to_fixed_size_list, CastOptions,
+};
 use arrow2::datatypes::*;
 use arrow2::types::NativeType;
 
@@ -351,6 +355,74 @@ fn decimal_to_integer() {
     assert_eq!(c, &expected)
 }
 
+#[test]
+fn utf8_to_decimal() {
+    let array = Utf8Array::<i32>::from([
+        Some("1.05"),
+        Some("-0.5"),
+        Some("+12"),
+        Some("1.005"),
+        Some("1.050"),
+        Some("1000"),
+        Some(" 1.2"),
+        Some("a"),
+        None,
+    ]);
+    let b = cast(&array, &DataType::Decimal(5, 2), CastOptions::default()).unwrap();
+
+    let expected = Int128Array::from(&[
+        Some(105),
+        Some(-50),
+        Some(1200),
+        None,
+        Some(105),
+        None,
+        None,
+        None,
+        None,
+    ])
+    .to(DataType::Decimal(5, 2));
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn utf8_to_decimal_lenient() {
+    let array = Utf8Array::<i64>::from_slice(["1.005", "-1.004", " 1.2 ", "999.995", "1e2"]);
+    let b = cast(
+        &array,
+        &DataType::Decimal(5, 2),
+        CastOptions {
+            partial: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let expected = Int128Array::from(&[Some(101), Some(-100), Some(120), None, None])
+        .to(DataType::Decimal(5, 2));
+    assert_eq!(expected, b.as_ref());
+}
+
+#[test]
+fn decimal_to_utf8() {
+    let array = Int128Array::from(&[Some(-1234), Some(5), Some(-5), None, Some(100)])
+        .to(DataType::Decimal(5, 2));
+    let b = cast(&array, &DataType::Utf8, CastOptions::default()).unwrap();
+
+    let expected = Utf8Array::<i32>::from([
+        Some("-12.34"),
+        Some("0.05"),
+        Some("-0.05"),
+        None,
+        Some("1.00"),
+    ]);
+    assert_eq!(expected, b.as_ref());
+
+    let array = Int128Array::from_slice([-12]).to(DataType::Decimal(2, 0));
+    let b = cast(&array, &DataType::LargeUtf8, CastOptions::default()).unwrap();
+    assert_eq!(Utf8Array::<i64>::from_slice(["-12"]), b.as_ref());
+}
+
 #[test]
 fn utf8_to_i32_partial() {
     let array = Utf8Array::<i32>::from_slice(&["5", "6", "seven", "8aa", "9.1aa"]);
@@ -664,6 +736,60 @@ fn list_to_list() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn list_to_fixed_size_list() {
+    let data = vec![
+        Some(vec![Some(1i32), Some(2)]),
+        None,
+        Some(vec![Some(4), None, Some(6)]),
+        Some(vec![None, Some(8)]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let to_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::UInt16, true)), 2);
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    // lists of a different size are null
+    let values = UInt16Array::from([Some(1), Some(2), None, None, None, None, None, Some(8)]);
+    let expected = FixedSizeListArray::from_data(
+        to_type.clone(),
+        Arc::new(values),
+        Some([true, false, false, true].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+
+    assert!(try_list_to_fixed_size_list(&array, &to_type, CastOptions::default()).is_err());
+    let array = array.slice(3, 1);
+    let result = try_list_to_fixed_size_list(&array, &to_type, CastOptions::default()).unwrap();
+    let values = UInt16Array::from([None, Some(8)]);
+    let expected = FixedSizeListArray::from_data(to_type, Arc::new(values), None);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn binary_to_fixed_size_binary() {
+    let array = BinaryArray::<i64>::from([Some(b"ab".as_ref()), None, Some(b"abc"), Some(b"cd")]);
+
+    let to_type = DataType::FixedSizeBinary(2);
+    assert!(can_cast_types(array.data_type(), &to_type));
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    // values of a different size are null
+    let expected = FixedSizeBinaryArray::from_data(
+        to_type,
+        b"ab\0\0\0\0cd".to_vec().into(),
+        Some([true, false, false, true].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+
+    assert!(try_binary_to_fixed_size_binary(&array, 2).is_err());
+    let result = try_binary_to_fixed_size_binary(&array.slice(3, 1), 2).unwrap();
+    assert_eq!(result.value(0), b"cd");
+}
+
 #[test]
 fn timestamp_with_tz_to_utf8() {
     let tz = "-02:00".to_string();
@@ -712,6 +838,62 @@ fn naive_timestamp_to_utf8() {
     assert_eq!(expected, result.as_ref());
 }
 
+#[test]
+fn utf8_to_timestamp_ms_with_tz() {
+    let tz = "-02:00".to_string();
+    let array = Utf8Array::<i32>::from([Some("1996-12-19T16:39:57.123-02:00"), None]);
+    let expected = Int64Array::from(&[Some(851020797123), None])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, Some(tz)));
+
+    let result = cast(&array, expected.data_type(), CastOptions::default()).expect("cast failed");
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn epoch_to_timestamp() {
+    let options = CastOptions {
+        epoch_unit: Some(TimeUnit::Second),
+        ..Default::default()
+    };
+    let expected = Int64Array::from(&[Some(851020797000), None])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+
+    let array = Int64Array::from(&[Some(851020797), None]);
+    let result = cast(&array, expected.data_type(), options).expect("cast failed");
+    assert_eq!(expected, result.as_ref());
+
+    let array = Utf8Array::<i32>::from([Some("851020797"), Some("a")]);
+    let result = cast(&array, expected.data_type(), options).expect("cast failed");
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn timestamp_to_epoch() {
+    let options = CastOptions {
+        epoch_unit: Some(TimeUnit::Second),
+        ..Default::default()
+    };
+    let array = Int64Array::from(&[Some(851020797123), None])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let expected = Int64Array::from(&[Some(851020797), None]);
+
+    let result = cast(&array, &DataType::Int64, options).expect("cast failed");
+    assert_eq!(&expected as &dyn Array, result.as_ref());
+}
+
+#[test]
+fn date_to_utf8() {
+    let expected = Utf8Array::<i32>::from([Some("1970-01-01"), None, Some("2021-01-30")]);
+
+    let array = Int32Array::from(&[Some(0), None, Some(18657)]).to(DataType::Date32);
+    let result = cast(&array, expected.data_type(), CastOptions::default()).expect("cast failed");
+    assert_eq!(expected, result.as_ref());
+
+    let array = Int64Array::from(&[Some(0), None, Some(1611964800000)]).to(DataType::Date64);
+    let result = cast(&array, expected.data_type(), CastOptions::default()).expect("cast failed");
+    assert_eq!(expected, result.as_ref());
+}
+
 #[test]
 fn null_array_from_and_to_others() {
     macro_rules! typed_test {
@@ -750,6 +932,175 @@ fn null_array_from_and_to_others() {
     typed_test!(Float64Array, Float64);
 }
 
+fn entries_type(key: &str, value: &str) -> DataType {
+    DataType::Struct(vec![
+        Field::new(key, DataType::Utf8, false),
+        Field::new(value, DataType::Int32, true),
+    ])
+}
+
+fn map_array() -> MapArray {
+    let data_type = DataType::Map(
+        Box::new(Field::new("entries", entries_type("key", "value"), false)),
+        false,
+    );
+    let entries = StructArray::from_data(
+        entries_type("key", "value"),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+            Arc::new(Int32Array::from(&[Some(1), None, Some(3)])),
+        ],
+        None,
+    );
+    MapArray::from_data(
+        data_type,
+        vec![0, 2, 2, 3].into(),
+        Arc::new(entries),
+        Some([true, false, true].into()),
+    )
+}
+
+#[test]
+fn map_to_list_and_back() {
+    let map = map_array();
+    let list_type = DataType::List(Box::new(Field::new("item", entries_type("k", "v"), true)));
+    assert!(can_cast_types(map.data_type(), &list_type));
+
+    let list = cast(&map, &list_type, CastOptions::default()).unwrap();
+    let list = list.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(list.data_type(), &list_type);
+    assert_eq!(list.offsets().as_slice(), map.offsets().as_slice());
+    assert_eq!(list.validity(), map.validity());
+    let values = list
+        .values()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(
+        values.values(),
+        map.field()
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap()
+            .values()
+    );
+
+    assert!(can_cast_types(&list_type, map.data_type()));
+    let result = cast(list, map.data_type(), CastOptions::default()).unwrap();
+    assert_eq!(result.as_ref(), &map as &dyn Array);
+}
+
+#[test]
+fn list_of_non_struct_to_map() {
+    let list_type = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+    let map_type = map_array().data_type().clone();
+    assert!(!can_cast_types(&list_type, &map_type));
+
+    let list = ListArray::<i32>::new_null(list_type, 2);
+    assert!(cast(&list, &map_type, CastOptions::default()).is_err());
+}
+
+#[test]
+fn struct_to_struct() {
+    let array = StructArray::from_data(
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]),
+        vec![
+            Arc::new(Int32Array::from_slice([1, 2])),
+            Arc::new(Utf8Array::<i32>::from_slice(["x", "y"])),
+        ],
+        Some([true, false].into()),
+    );
+    let to_type = DataType::Struct(vec![
+        Field::new("c", DataType::Int64, true),
+        Field::new("d", DataType::LargeUtf8, true),
+    ]);
+    assert!(can_cast_types(array.data_type(), &to_type));
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+    let expected = StructArray::from_data(
+        to_type,
+        vec![
+            Arc::new(Int64Array::from_slice([1, 2])),
+            Arc::new(Utf8Array::<i64>::from_slice(["x", "y"])),
+        ],
+        Some([true, false].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    let to_type = DataType::Struct(vec![Field::new("c", DataType::Int64, true)]);
+    assert!(!can_cast_types(array.data_type(), &to_type));
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+}
+
+#[test]
+fn struct_to_map() {
+    let array = StructArray::from_data(
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int8, true),
+            Field::new("b", DataType::Int16, true),
+        ]),
+        vec![
+            Arc::new(Int8Array::from(&[Some(1), Some(2), None])),
+            Arc::new(Int16Array::from(&[Some(3), None, Some(4)])),
+        ],
+        Some([true, true, false].into()),
+    );
+    let to_type = DataType::Map(
+        Box::new(Field::new("entries", entries_type("key", "value"), false)),
+        false,
+    );
+    assert!(can_cast_types(array.data_type(), &to_type));
+
+    let result = cast(&array, &to_type, CastOptions::default()).unwrap();
+
+    let entries = StructArray::from_data(
+        entries_type("key", "value"),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "a", "b", "a", "b"])),
+            Arc::new(Int32Array::from(&[
+                Some(1),
+                Some(3),
+                Some(2),
+                None,
+                None,
+                Some(4),
+            ])),
+        ],
+        None,
+    );
+    let expected = MapArray::from_data(
+        to_type,
+        vec![0, 2, 4, 6].into(),
+        Arc::new(entries),
+        Some([true, true, false].into()),
+    );
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn struct_to_map_non_utf8_keys() {
+    let array = StructArray::new_null(
+        DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+        1,
+    );
+    let to_type = DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Int32, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        )),
+        false,
+    );
+    assert!(!can_cast_types(array.data_type(), &to_type));
+    assert!(cast(&array, &to_type, CastOptions::default()).is_err());
+}
+
 /*
 #[test]
 fn dict_to_dict_bad_index_value_primitive() {
@@ -52,6 +52,10 @@ fn consistency() {
         if can_lt_eq(&d1) {
             lt_eq(array.as_ref(), array.as_ref());
         }
+        if can_is_distinct_from(&d1) {
+            is_distinct_from(array.as_ref(), array.as_ref());
+            is_not_distinct_from(array.as_ref(), array.as_ref());
+        }
     });
 
     // array <> scalar
@@ -64,9 +68,156 @@ fn consistency() {
         if can_lt_eq(&d1) {
             lt_eq(array.as_ref(), array.as_ref());
         }
+        if can_is_distinct_from(&d1) {
+            is_distinct_from_scalar(array.as_ref(), scalar.as_ref());
+            is_not_distinct_from_scalar(array.as_ref(), scalar.as_ref());
+        }
     });
 }
 
+#[test]
+fn is_distinct_from_dyn() {
+    use arrow2::compute::comparison::*;
+
+    let lhs = Int32Array::from([Some(1), None, None, Some(2), Some(3)]);
+    let rhs = Int32Array::from([Some(1), None, Some(2), None, Some(4)]);
+
+    let result = is_not_distinct_from(&lhs, &rhs);
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([true, true, false, false, false])
+    );
+    let result = is_distinct_from(&lhs, &rhs);
+    assert_eq!(
+        result,
+        BooleanArray::from_slice([false, false, true, true, true])
+    );
+
+    // sliced
+    let result = is_not_distinct_from(&lhs.slice(1, 3), &rhs.slice(1, 3));
+    assert_eq!(result, BooleanArray::from_slice([true, false, false]));
+
+    let lhs = Utf8Array::<i32>::from([Some("a"), None, Some("b")]);
+    let rhs = Utf8Array::<i32>::from([Some("a"), None, None]);
+    let result = is_not_distinct_from(&lhs, &rhs);
+    assert_eq!(result, BooleanArray::from_slice([true, true, false]));
+
+    let array = NullArray::from_data(Null, 2);
+    let result = is_not_distinct_from(&array, &array);
+    assert_eq!(result, BooleanArray::from_slice([true, true]));
+}
+
+#[test]
+fn is_distinct_from_scalar_dyn() {
+    use arrow2::compute::comparison::*;
+    use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+    let array = Int64Array::from([Some(1), None, Some(2)]);
+
+    let scalar = PrimitiveScalar::<i64>::from(Some(1));
+    let result = is_not_distinct_from_scalar(&array, &scalar);
+    assert_eq!(result, BooleanArray::from_slice([true, false, false]));
+    let result = is_distinct_from_scalar(&array, &scalar);
+    assert_eq!(result, BooleanArray::from_slice([false, true, true]));
+
+    // comparing against a null scalar is `is_null`
+    let scalar = PrimitiveScalar::<i64>::from(None);
+    let result = is_not_distinct_from_scalar(&array, &scalar);
+    assert_eq!(result, BooleanArray::from_slice([false, true, false]));
+    let result = is_distinct_from_scalar(&array, &scalar);
+    assert_eq!(result, BooleanArray::from_slice([true, false, true]));
+
+    let array = Utf8Array::<i32>::from_slice(["a", "b"]);
+    let scalar = Utf8Scalar::<i32>::new(None::<&str>);
+    let result = is_not_distinct_from_scalar(&array, &scalar);
+    assert_eq!(result, BooleanArray::from_slice([false, false]));
+}
+
+#[test]
+fn scalar_dictionary_dyn() {
+    use arrow2::compute::comparison::*;
+    use arrow2::scalar::Utf8Scalar;
+
+    let values = std::sync::Arc::new(Utf8Array::<i32>::from([Some("a"), Some("b"), None]));
+    let keys = Int32Array::from([Some(1), None, Some(0), Some(2), Some(1)]);
+    let array = DictionaryArray::<i32>::from_data(keys, values);
+
+    let scalar = Utf8Scalar::<i32>::from(Some("b"));
+    let result = eq_scalar(&array, &scalar);
+    let expected = BooleanArray::from([Some(true), None, Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = gt_scalar(&array, &Utf8Scalar::<i32>::from(Some("a")));
+    let expected = BooleanArray::from([Some(true), None, Some(false), None, Some(true)]);
+    assert_eq!(result, expected);
+
+    let result = is_distinct_from_scalar(&array, &scalar);
+    let expected = BooleanArray::from_slice([false, true, true, true, false]);
+    assert_eq!(result, expected);
+
+    let result = is_not_distinct_from_scalar(&array, &scalar);
+    let expected = BooleanArray::from_slice([true, false, false, false, true]);
+    assert_eq!(result, expected);
+
+    let result = eq_scalar(&array, &Utf8Scalar::<i32>::from(None::<&str>));
+    assert_eq!(result, BooleanArray::new_null(Boolean, 5));
+}
+
+#[test]
+fn dictionary_dyn() {
+    use arrow2::compute::comparison::*;
+    use arrow2::datatypes::IntegerType;
+
+    let dict_type = Dictionary(IntegerType::Int32, Box::new(Utf8), false);
+    assert!(can_eq(&dict_type));
+    assert!(can_lt(&dict_type));
+    assert!(can_is_distinct_from(&dict_type));
+    let dict_type = Dictionary(
+        IntegerType::Int32,
+        Box::new(Interval(IntervalUnit::DayTime)),
+        false,
+    );
+    assert!(can_eq(&dict_type));
+    assert!(!can_lt(&dict_type));
+    assert!(!can_eq(&Dictionary(
+        IntegerType::Int32,
+        Box::new(Null),
+        false
+    )));
+
+    // dictionaries whose keys refer to different values are compared via their values
+    let values = std::sync::Arc::new(Utf8Array::<i32>::from_slice(["a", "b"]));
+    let keys = Int32Array::from([Some(1), None, Some(0), Some(0)]);
+    let lhs = DictionaryArray::<i32>::from_data(keys, values);
+    let values = std::sync::Arc::new(Utf8Array::<i32>::from_slice(["b", "c", "a"]));
+    let keys = Int32Array::from([Some(0), Some(1), Some(1), Some(2)]);
+    let rhs = DictionaryArray::<i32>::from_data(keys, values);
+
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    assert_eq!(eq(&lhs, &rhs), expected);
+    let expected = BooleanArray::from([Some(false), None, Some(true), Some(false)]);
+    assert_eq!(lt(&lhs, &rhs), expected);
+    let expected = BooleanArray::from_slice([false, true, true, false]);
+    assert_eq!(is_distinct_from(&lhs, &rhs), expected);
+}
+
+#[test]
+fn scalar_temporal_dyn() {
+    use arrow2::compute::comparison::*;
+    use arrow2::scalar::PrimitiveScalar;
+
+    let array = Int32Array::from([Some(1), None, Some(3)]).to(Date32);
+    let scalar = PrimitiveScalar::<i32>::new(Date32, Some(1));
+    let result = lt_eq_scalar(&array, &scalar);
+    assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+
+    let data_type = Timestamp(TimeUnit::Millisecond, None);
+    let array = Int64Array::from_slice([1, 2, 3]).to(data_type.clone());
+    let scalar = PrimitiveScalar::<i64>::new(data_type, Some(2));
+    let result = neq_scalar(&array, &scalar);
+    assert_eq!(result, BooleanArray::from_slice([true, false, true]));
+}
+
 // disable wrapping inside literal vectors used for test data and assertions
 #[rustfmt::skip::macros(vec)]
 #[cfg(test)]
@@ -198,6 +349,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn is_distinct_from_nulls() {
+        cmp_bool_options!(
+            is_distinct_from,
+            &[
+                None,
+                None,
+                None,
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(true)
+            ],
+            &[
+                None,
+                Some(false),
+                Some(true),
+                None,
+                Some(false),
+                Some(true),
+                Some(false)
+            ],
+            &[
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(true)
+            ]
+        );
+        cmp_bool_options!(
+            is_not_distinct_from,
+            &[None, None, Some(false), Some(true)],
+            &[None, Some(true), Some(false), Some(false)],
+            &[Some(true), Some(false), Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn is_distinct_from_scalar_nulls() {
+        let a = BooleanArray::from([None, Some(true), Some(false)]);
+        let c = is_not_distinct_from_scalar(&a, true);
+        assert_eq!(BooleanArray::from_slice([false, true, false]), c);
+        let c = is_distinct_from_scalar(&a, false);
+        assert_eq!(BooleanArray::from_slice([true, true, false]), c);
+    }
+
     #[test]
     fn eq_nulls() {
         cmp_bool_options!(
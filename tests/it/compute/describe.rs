@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::compute::describe::{describe, describe_fields};
+use arrow2::datatypes::DataType;
+use arrow2::error::Result;
+
+#[test]
+fn basics() -> Result<()> {
+    let a = Int32Array::from([Some(1), None, Some(3), Some(3)]);
+    let b = Utf8Array::<i32>::from([Some("x"), Some("y"), None, Some("x")]);
+    let c = Float64Array::from_slice([0.5, 1.5, 0.5, 2.0]);
+    let d = Int64Array::from([Some(0), Some(86_400_000), None, None]).to(DataType::Date64);
+    let e = NullArray::from_data(DataType::Null, 4);
+    let columns = Chunk::new(vec![&a as &dyn Array, &b, &c, &d, &e]);
+
+    let result = describe(&["a", "b", "c", "d", "e"], &columns, 1)?;
+
+    let fields = describe_fields();
+    assert_eq!(result.arrays().len(), fields.len());
+    for (field, array) in fields.iter().zip(result.arrays()) {
+        assert_eq!(field.data_type(), array.data_type());
+    }
+
+    let expected: Vec<Arc<dyn Array>> = vec![
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c", "d", "e"])),
+        Arc::new(Utf8Array::<i32>::from_slice([
+            "Int32", "Utf8", "Float64", "Date64", "Null",
+        ])),
+        Arc::new(Float64Array::from_slice([0.25, 0.25, 0.0, 0.5, 1.0])),
+        Arc::new(UInt64Array::from([
+            Some(2),
+            Some(2),
+            Some(3),
+            Some(2),
+            None,
+        ])),
+        Arc::new(Utf8Array::<i32>::from([
+            Some("1"),
+            Some("x"),
+            Some("0.5"),
+            Some("1970-01-01"),
+            None,
+        ])),
+        Arc::new(Utf8Array::<i32>::from([
+            Some("3"),
+            Some("y"),
+            Some("2"),
+            Some("1970-01-02"),
+            None,
+        ])),
+        Arc::new(Float64Array::from([
+            Some(7.0 / 3.0),
+            None,
+            Some(1.125),
+            None,
+            None,
+        ])),
+    ];
+    for (i, expected) in expected.iter().enumerate() {
+        assert_eq!(&result.arrays()[i], expected, "column {}", i);
+    }
+
+    let mut top_values = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    top_values.try_extend(vec![None, Some(vec![Some("x")]), None, None, None])?;
+    let top_values: ListArray<i32> = top_values.into();
+    assert_eq!(result.arrays()[7].as_ref(), &top_values as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn top_values_ties() -> Result<()> {
+    let a = Utf8Array::<i64>::from_slice(["b", "a", "c", "b", "a", "d"]);
+    let columns = Chunk::new(vec![&a as &dyn Array]);
+
+    let result = describe(&["a"], &columns, 3)?;
+
+    let mut top_values = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    top_values.try_extend(vec![Some(vec![Some("a"), Some("b"), Some("c")])])?;
+    let top_values: ListArray<i32> = top_values.into();
+    assert_eq!(result.arrays()[7].as_ref(), &top_values as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn empty_and_errors() -> Result<()> {
+    let a = Int32Array::from_slice([]);
+    let columns = Chunk::new(vec![&a as &dyn Array]);
+
+    let result = describe(&["a"], &columns, 3)?;
+    assert!(result.arrays()[2].is_null(0));
+    assert!(result.arrays()[4].is_null(0));
+    assert!(result.arrays()[6].is_null(0));
+
+    assert!(describe(&["a", "b"], &columns, 3).is_err());
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::fill_null::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::scalar::*;
+
+#[test]
+fn fill_null_primitive() {
+    let array = Int32Array::from([None, Some(1), None, Some(3), None]).to(DataType::Date32);
+    let value = PrimitiveScalar::new(DataType::Date32, Some(0));
+    let result = fill_null(&array, &value).unwrap();
+    let expected = Int32Array::from_slice([0, 1, 0, 3, 0]).to(DataType::Date32);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn fill_null_utf8() {
+    let array = Utf8Array::<i64>::from([Some("a"), None, Some("c")]);
+    let result = fill_null(&array, &Utf8Scalar::<i64>::new(Some("b"))).unwrap();
+    let expected = Utf8Array::<i64>::from_slice(["a", "b", "c"]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn fill_null_boolean_sliced() {
+    let array = BooleanArray::from([Some(false), None, Some(true), None]).slice(1, 3);
+    let result = fill_null(&array, &BooleanScalar::new(Some(false))).unwrap();
+    let expected = BooleanArray::from_slice([false, true, false]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn fill_null_struct() {
+    let fields = vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+    let array = StructArray::from_data(
+        DataType::Struct(fields.clone()),
+        vec![
+            Arc::new(Int32Array::from_slice([1, 2])),
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])),
+        ],
+        Some([false, true].into()),
+    );
+    let value = StructScalar::new(
+        DataType::Struct(fields.clone()),
+        Some(vec![
+            Arc::new(PrimitiveScalar::<i32>::new(DataType::Int32, None)),
+            Arc::new(Utf8Scalar::<i32>::new(Some("z"))),
+        ]),
+    );
+    let result = fill_null(&array, &value).unwrap();
+
+    let expected = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(Int32Array::from([None, Some(2)])),
+            Arc::new(Utf8Array::<i32>::from_slice(["z", "b"])),
+        ],
+        None,
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn fill_null_dictionary() {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("b")]).unwrap();
+    let array: DictionaryArray<i32> = array.into();
+
+    let value = DictionaryScalar::<i32>::new(
+        array.data_type().clone(),
+        Some(Arc::new(Utf8Scalar::<i32>::new(Some("c")))),
+    );
+    let result = fill_null(&array, &value).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+    let values = (0..result.len())
+        .map(|i| result.value(i))
+        .map(|x| {
+            x.as_any()
+                .downcast_ref::<Utf8Scalar<i32>>()
+                .unwrap()
+                .clone()
+        })
+        .map(|x| x.value().unwrap().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec!["a", "c", "b"]);
+}
+
+#[test]
+fn fill_null_with_null() {
+    let array = Int32Array::from([None, Some(1)]);
+    let value = PrimitiveScalar::<i32>::new(DataType::Int32, None);
+    let result = fill_null(&array, &value).unwrap();
+    assert_eq!(array, result.as_ref());
+}
+
+#[test]
+fn fill_null_invalid() {
+    let array = Int32Array::from([None, Some(1)]);
+    let value = PrimitiveScalar::new(DataType::Int64, Some(1i64));
+    assert!(fill_null(&array, &value).is_err());
+}
+
+#[test]
+fn forward_fill_primitive() {
+    let array = Int32Array::from([None, Some(1), None, None, Some(4), None]);
+    let result = forward_fill(&array);
+    let expected = Int32Array::from([None, Some(1), Some(1), Some(1), Some(4), Some(4)]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn backward_fill_utf8() {
+    let array = Utf8Array::<i32>::from([None, Some("a"), None, None, Some("d"), None]);
+    let result = backward_fill(&array);
+    let expected =
+        Utf8Array::<i32>::from([Some("a"), Some("a"), Some("d"), Some("d"), Some("d"), None]);
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn fill_without_nulls() {
+    let array = Float64Array::from_slice([1.0, 2.0]);
+    assert_eq!(array, forward_fill(&array).as_ref());
+    assert_eq!(array, backward_fill(&array).as_ref());
+}
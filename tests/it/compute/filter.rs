@@ -193,3 +193,92 @@ fn list_array() {
     assert_eq!(&make_array(expected), &result);
 }
 */
+
+#[test]
+fn filter_into_appends() {
+    let mut out = MutablePrimitiveArray::<i64>::new();
+    let array = Int64Array::from([Some(1), None, Some(3), Some(4)]);
+    let mask = BooleanArray::from([Some(true), Some(true), None, Some(true)]);
+    filter_into(&array, &mask, &mut out).unwrap();
+    let array = Int64Array::from_slice([5, 6]);
+    filter_into(&array, &BooleanArray::from_slice([false, true]), &mut out).unwrap();
+
+    let out: Int64Array = out.into();
+    assert_eq!(out, Int64Array::from([Some(1), None, Some(4), Some(6)]));
+}
+
+#[test]
+fn filter_into_equals_filter() {
+    let array = BinaryArray::<i64>::from([Some(b"a".as_ref()), None, Some(b"c")]).slice(1, 2);
+    let mask = BooleanArray::from_slice([true, true]);
+
+    let mut out = MutableBinaryArray::<i64>::new();
+    filter_into(&array, &mask, &mut out).unwrap();
+    let out: BinaryArray<i64> = out.into();
+    assert_eq!(&out as &dyn Array, filter(&array, &mask).unwrap().as_ref());
+}
+
+#[test]
+fn filter_into_errors() {
+    let array = Int32Array::from_slice([1, 2]);
+    let mut out = MutablePrimitiveArray::<i32>::new();
+    assert!(filter_into(&array, &BooleanArray::from_slice([true]), &mut out).is_err());
+
+    let mut out = MutablePrimitiveArray::<i64>::new();
+    let mask = BooleanArray::from_slice([true, false]);
+    assert!(filter_into(&array, &mask, &mut out).is_err());
+}
+
+#[test]
+fn dictionary_shares_values() {
+    let mut array = MutableDictionaryArray::<i8, MutableUtf8Array<i32>>::new();
+    array
+        .try_extend([Some("hello"), None, Some("world"), Some("!")])
+        .unwrap();
+    let array: DictionaryArray<i8> = array.into();
+    let mask = BooleanArray::from_slice([false, true, true, false]);
+
+    let expected = Int8Array::from([None, Some(1)]);
+    let result = filter(&array, &mask).unwrap();
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert!(std::sync::Arc::ptr_eq(result.values(), array.values()));
+    assert_eq!(result.keys(), &expected);
+
+    let result = build_filter(&mask).unwrap()(&array);
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i8>>()
+        .unwrap();
+    assert!(std::sync::Arc::ptr_eq(result.values(), array.values()));
+    assert_eq!(result.keys(), &expected);
+}
+
+#[test]
+fn primitive_runs_across_chunks() {
+    // runs of different lengths that start, end and span 64-bit chunk boundaries
+    let mask = (0..300)
+        .map(|i| matches!(i % 97, 0..=70) || i % 5 == 0)
+        .collect::<Vec<_>>();
+    let values = (0..300)
+        .map(|i| if i % 7 == 0 { None } else { Some(i) })
+        .collect::<Vec<_>>();
+
+    for offset in [0, 3, 64, 70] {
+        let length = 300 - offset - 5;
+        let array = Int32Array::from(&values).slice(offset, length);
+        let filter_array = BooleanArray::from_slice(&mask).slice(offset, length);
+
+        let result = filter(&array, &filter_array).unwrap();
+
+        let expected = values[offset..offset + length]
+            .iter()
+            .zip(mask[offset..offset + length].iter())
+            .filter(|(_, is_selected)| **is_selected)
+            .map(|(value, _)| *value)
+            .collect::<Vec<_>>();
+        assert_eq!(result.as_ref(), &Int32Array::from(expected) as &dyn Array);
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::find_runs::find_runs;
+use arrow2::datatypes::{DataType, Field};
+
+#[test]
+fn primitive() {
+    let array = Int64Array::from([Some(1), Some(1), Some(2), None, Some(2), Some(2)]);
+    let (ends, values) = find_runs(&array);
+    assert_eq!(ends, vec![2, 3, 4, 6]);
+    let expected = Int64Array::from([Some(1), Some(2), None, Some(2)]);
+    assert_eq!(values.as_ref(), &expected as &dyn Array);
+}
+
+#[test]
+fn empty() {
+    let array = Utf8Array::<i32>::new_empty(DataType::Utf8);
+    let (ends, values) = find_runs(&array);
+    assert!(ends.is_empty());
+    assert_eq!(values.len(), 0);
+}
+
+#[test]
+fn constant() {
+    let array = Utf8Array::<i32>::from_slice(["a", "a", "a"]);
+    let (ends, values) = find_runs(&array);
+    assert_eq!(ends, vec![3]);
+    assert_eq!(
+        values.as_ref(),
+        &Utf8Array::<i32>::from_slice(["a"]) as &dyn Array
+    );
+}
+
+#[test]
+fn float_nan() {
+    let array = Float64Array::from_slice([f64::NAN, f64::NAN, 1.0]);
+    let (ends, _) = find_runs(&array);
+    assert_eq!(ends, vec![2, 3]);
+}
+
+#[test]
+fn sliced() {
+    let array = Int32Array::from_slice([1, 2, 2, 3]).slice(1, 3);
+    let (ends, values) = find_runs(&array);
+    assert_eq!(ends, vec![2, 3]);
+    assert_eq!(
+        values.as_ref(),
+        &Int32Array::from_slice([2, 3]) as &dyn Array
+    );
+}
+
+#[test]
+fn nested() {
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let values: Arc<dyn Array> = Arc::new(Int32Array::from_slice([1, 1, 2]));
+    let array = StructArray::from_data(DataType::Struct(fields), vec![values], None);
+    let (ends, values) = find_runs(&array);
+    assert_eq!(ends, vec![2, 3]);
+    assert_eq!(values.len(), 2);
+}
@@ -0,0 +1,46 @@
+use arrow2::array::*;
+use arrow2::compute::float::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn classify_f32() {
+    let array = Float32Array::from([
+        Some(1.0),
+        Some(f32::NAN),
+        Some(f32::INFINITY),
+        Some(f32::NEG_INFINITY),
+        None,
+    ]);
+
+    let result = is_nan(&array).unwrap();
+    let expected = BooleanArray::from([Some(false), Some(true), Some(false), Some(false), None]);
+    assert_eq!(result, expected);
+
+    let result = is_finite(&array).unwrap();
+    let expected = BooleanArray::from([Some(true), Some(false), Some(false), Some(false), None]);
+    assert_eq!(result, expected);
+
+    let result = is_infinite(&array).unwrap();
+    let expected = BooleanArray::from([Some(false), Some(false), Some(true), Some(true), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn classify_f64_sliced() {
+    let array = Float64Array::from([Some(f64::NAN), Some(2.0), None, Some(f64::INFINITY)]);
+    let array = array.slice(1, 3);
+
+    let result = is_nan(&array).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(false), None, Some(false)]));
+
+    let result = is_infinite(&array).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(false), None, Some(true)]));
+}
+
+#[test]
+fn classify_invalid() {
+    let array = Int32Array::from_slice([1]);
+    assert!(is_nan(&array).is_err());
+    assert!(!can_classify(&DataType::Int32));
+    assert!(can_classify(&DataType::Float64));
+}
@@ -0,0 +1,67 @@
+use arrow2::array::*;
+use arrow2::compute::histogram::*;
+
+#[test]
+fn bin_edges() {
+    let array = Float64Array::from([
+        Some(-1.0),
+        Some(0.0),
+        Some(0.5),
+        Some(1.0),
+        None,
+        Some(2.0),
+        Some(f64::NAN),
+        Some(2.5),
+    ]);
+    let result = bin(&array, &[0.0, 1.0, 2.0]).unwrap();
+    let expected = UInt32Array::from([None, Some(0), Some(0), Some(1), None, Some(1), None, None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn bin_sliced() {
+    let array = Int64Array::from_slice([1, 2, 3, 4]).slice(2, 2);
+    let result = bin(&array, &[0, 3, 10]).unwrap();
+    assert_eq!(result, UInt32Array::from_slice([1, 1]));
+}
+
+#[test]
+fn bin_invalid_edges() {
+    let array = Int32Array::from_slice([1]);
+    assert!(bin(&array, &[1]).is_err());
+    assert!(bin(&array, &[1, 1]).is_err());
+    assert!(bin(&array, &[2, 1]).is_err());
+    let array = Float32Array::from_slice([1.0]);
+    assert!(bin(&array, &[0.0, f32::NAN]).is_err());
+}
+
+#[test]
+fn uniform() {
+    let array = Int32Array::from([
+        Some(0),
+        Some(9),
+        Some(10),
+        None,
+        Some(99),
+        Some(100),
+        Some(101),
+    ]);
+    let result = bin_uniform(&array, 0.0, 100.0, 10).unwrap();
+    let expected = UInt32Array::from([Some(0), Some(0), Some(1), None, Some(9), Some(9), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn uniform_invalid() {
+    let array = Int32Array::from_slice([1]);
+    assert!(bin_uniform(&array, 0.0, 1.0, 0).is_err());
+    assert!(bin_uniform(&array, 1.0, 1.0, 1).is_err());
+    assert!(bin_uniform(&array, f64::NAN, 1.0, 1).is_err());
+}
+
+#[test]
+fn counts() {
+    let array = Int32Array::from([Some(1), Some(2), None, Some(7), Some(20)]);
+    let buckets = bin(&array, &[0, 5, 10]).unwrap();
+    assert_eq!(histogram(&buckets, 2), UInt64Array::from_slice([2, 1]));
+}
@@ -0,0 +1,83 @@
+use arrow2::array::*;
+use arrow2::compute::json_extract::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn utf8() {
+    let array = Utf8Array::<i32>::from([
+        Some(r#"{"a": {"b": "x"}}"#),
+        Some(r#"{"a": {"b": 1.5}}"#),
+        Some(r#"{"a": {"b": {"c": true}}}"#),
+        Some(r#"{"a": {"b": null}}"#),
+        Some(r#"{"a": 1}"#),
+        Some("not json"),
+        None,
+    ]);
+    let result = json_extract_utf8(&array, "$.a.b").unwrap();
+    let expected = Utf8Array::<i32>::from([
+        Some("x"),
+        Some("1.5"),
+        Some(r#"{"c":true}"#),
+        None,
+        None,
+        None,
+        None,
+    ]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn f64() {
+    let array = Utf8Array::<i64>::from([
+        Some(r#"{"a": [1, 2.5]}"#),
+        Some(r#"{"a": ["1", 2]}"#),
+        Some(r#"{"a": []}"#),
+        None,
+    ]);
+    let result = json_extract_f64(&array, "$.a[1]").unwrap();
+    assert_eq!(
+        result,
+        Float64Array::from([Some(2.5), Some(2.0), None, None])
+    );
+    let result = json_extract_f64(&array, "$.a[0]").unwrap();
+    assert_eq!(result, Float64Array::from([Some(1.0), None, None, None]));
+}
+
+#[test]
+fn bool() {
+    let array = Utf8Array::<i32>::from([
+        Some(r#"{"a b": true}"#),
+        Some(r#"{"a b": "true"}"#),
+        Some(r#"{"a b": false}"#),
+    ]);
+    let result = json_extract_bool(&array, r#"$["a b"]"#).unwrap();
+    assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+}
+
+#[test]
+fn root() {
+    let array = Utf8Array::<i32>::from([Some("1"), Some("[1]")]);
+    let result = json_extract_utf8(&array, "$").unwrap();
+    assert_eq!(result, array);
+}
+
+#[test]
+fn dyn_() {
+    let array = Utf8Array::<i32>::from([Some(r#"{"a": 1}"#), None]);
+    let result = json_extract(&array, "$.a", &DataType::Float64).unwrap();
+    assert_eq!(
+        result.as_any().downcast_ref::<Float64Array>().unwrap(),
+        &Float64Array::from([Some(1.0), None])
+    );
+
+    assert!(json_extract(&array, "$.a", &DataType::Int32).is_err());
+    assert!(json_extract(&Int32Array::from_slice([1]), "$", &DataType::Utf8).is_err());
+}
+
+#[test]
+fn invalid_path() {
+    let array = Utf8Array::<i32>::from([Some("{}")]);
+    for path in ["a", "$.", "$..a", "$[", "$[a]", "$a"] {
+        assert!(json_extract_utf8(&array, path).is_err(), "{}", path);
+    }
+}
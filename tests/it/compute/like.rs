@@ -44,3 +44,77 @@ fn test_nlike_binary_scalar() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_like_utf8_scalar_fast_paths() -> Result<()> {
+    let array =
+        Utf8Array::<i32>::from([Some("Arrow"), None, Some("Parquet"), Some("arrow\nflight")]);
+    let cases = [
+        // equality, prefix, suffix, containment, regex
+        ("Arrow", [Some(true), None, Some(false), Some(false)]),
+        ("Ar%", [Some(true), None, Some(false), Some(false)]),
+        ("%et", [Some(false), None, Some(true), Some(false)]),
+        ("%rr%", [Some(true), None, Some(false), Some(true)]),
+        ("%%", [Some(true), None, Some(true), Some(true)]),
+        ("_rr%", [Some(true), None, Some(false), Some(true)]),
+        ("%w_f%", [Some(false), None, Some(false), Some(true)]),
+    ];
+    for (pattern, expected) in cases {
+        let result = like_utf8_scalar(&array, pattern)?;
+        assert_eq!(result, BooleanArray::from(expected), "{}", pattern);
+        let result = nlike_utf8_scalar(&array, pattern)?;
+        let expected = expected.map(|x| x.map(|x| !x));
+        assert_eq!(result, BooleanArray::from(expected), "{}", pattern);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_like_utf8_escapes_regex() -> Result<()> {
+    let array = Utf8Array::<i64>::from_slice(["a.c", "abc", "a(c"]);
+    let result = like_utf8_scalar(&array, "a._")?;
+    assert_eq!(result, BooleanArray::from_slice([true, false, false]));
+    let result = like_utf8_scalar(&array, "a(%")?;
+    assert_eq!(result, BooleanArray::from_slice([false, false, true]));
+
+    let patterns = Utf8Array::<i64>::from_slice(["a._", "a._", "a(_"]);
+    let result = like_utf8(&array, &patterns)?;
+    assert_eq!(result, BooleanArray::from_slice([true, false, true]));
+    Ok(())
+}
+
+#[test]
+fn test_ilike_utf8() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("Arrow"), None, Some("PARQUET"), Some("ÀRROW")]);
+
+    let result = ilike_utf8_scalar(&array, "arrow")?;
+    let expected = BooleanArray::from([Some(true), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = ilike_utf8_scalar(&array, "%qu%")?;
+    let expected = BooleanArray::from([Some(false), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let result = nilike_utf8_scalar(&array, "àr%")?;
+    let expected = BooleanArray::from([Some(true), None, Some(true), Some(false)]);
+    assert_eq!(result, expected);
+
+    let patterns = Utf8Array::<i32>::from_slice(["a%", "a%", "%T", "_RROW"]);
+    let result = ilike_utf8(&array, &patterns)?;
+    let expected = BooleanArray::from([Some(true), None, Some(true), Some(true)]);
+    assert_eq!(result, expected);
+    let result = nilike_utf8(&array, &patterns)?;
+    let expected = BooleanArray::from([Some(false), None, Some(false), Some(false)]);
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn test_like_binary_scalar_fast_paths() -> Result<()> {
+    let array = BinaryArray::<i32>::from_slice(["Arrow", "Parquet"]);
+    let result = like_binary_scalar(&array, b"%rr%")?;
+    assert_eq!(result, BooleanArray::from_slice([true, false]));
+    let result = nlike_binary_scalar(&array, b"Arrow")?;
+    assert_eq!(result, BooleanArray::from_slice([false, true]));
+    Ok(())
+}
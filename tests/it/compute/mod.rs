@@ -1,7 +1,11 @@
 #[cfg(feature = "compute_aggregate")]
 mod aggregate;
+#[cfg(feature = "compute_approx_distinct")]
+mod approx_distinct;
 #[cfg(feature = "compute_arithmetics")]
 mod arithmetics;
+#[cfg(feature = "compute_between")]
+mod between;
 #[cfg(feature = "compute_bitwise")]
 mod bitwise;
 #[cfg(feature = "compute_boolean")]
@@ -16,12 +20,24 @@ mod comparison;
 mod concatenate;
 #[cfg(feature = "compute_contains")]
 mod contains;
+#[cfg(feature = "compute_describe")]
+mod describe;
+#[cfg(feature = "compute_fill_null")]
+mod fill_null;
 #[cfg(feature = "compute_filter")]
 mod filter;
+#[cfg(feature = "compute_find_runs")]
+mod find_runs;
+#[cfg(feature = "compute_float")]
+mod float;
 #[cfg(feature = "compute_hash")]
 mod hash;
+#[cfg(feature = "compute_histogram")]
+mod histogram;
 #[cfg(feature = "compute_if_then_else")]
 mod if_then_else;
+#[cfg(feature = "compute_json_extract")]
+mod json_extract;
 #[cfg(feature = "compute_length")]
 mod length;
 #[cfg(feature = "compute_like")]
@@ -34,16 +50,30 @@ mod lower;
 mod merge_sort;
 #[cfg(feature = "compute_partition")]
 mod partition;
+#[cfg(feature = "compute_rechunk")]
+mod rechunk;
 #[cfg(feature = "compute_regex_match")]
 mod regex_match;
+#[cfg(feature = "compute_registry")]
+mod registry;
+#[cfg(feature = "compute_selection")]
+mod selection;
 #[cfg(feature = "compute_sort")]
 mod sort;
+#[cfg(feature = "compute_split")]
+mod split;
+#[cfg(feature = "compute_string")]
+mod string;
+#[cfg(feature = "compute_string_distance")]
+mod string_distance;
 #[cfg(feature = "compute_substring")]
 mod substring;
 #[cfg(feature = "compute_take")]
 mod take;
 #[cfg(feature = "compute_temporal")]
 mod temporal;
+#[cfg(feature = "compute_tokenize")]
+mod tokenize;
 #[cfg(feature = "compute_upper")]
 mod upper;
 #[cfg(feature = "compute_window")]
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::compute::rechunk::Rechunk;
+use arrow2::error::{ArrowError, Result};
+
+fn chunk(values: &[i32]) -> Result<Chunk<Arc<dyn Array>>> {
+    Ok(Chunk::new(vec![
+        Arc::new(Int32Array::from_slice(values)) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from_iter_values(
+            values.iter().map(|x| x.to_string()),
+        )),
+    ]))
+}
+
+#[test]
+fn coalesce_and_split() -> Result<()> {
+    let chunks = vec![
+        chunk(&[1]),
+        chunk(&[]),
+        chunk(&[2, 3]),
+        chunk(&[4, 5, 6, 7, 8, 9, 10]),
+        chunk(&[11]),
+    ];
+
+    let result = Rechunk::new(chunks.into_iter(), 3).collect::<Result<Vec<_>>>()?;
+
+    let expected = vec![
+        chunk(&[1, 2, 3])?,
+        chunk(&[4, 5, 6])?,
+        chunk(&[7, 8, 9])?,
+        chunk(&[10, 11])?,
+    ];
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn empty() -> Result<()> {
+    let chunks = vec![chunk(&[]), chunk(&[])];
+    assert_eq!(Rechunk::new(chunks.into_iter(), 3).count(), 0);
+    Ok(())
+}
+
+#[test]
+fn errors_are_forwarded() {
+    let chunks = vec![
+        chunk(&[1]),
+        Err(ArrowError::OutOfSpec("".to_string())),
+        chunk(&[2, 3]),
+    ];
+
+    let mut iter = Rechunk::new(chunks.into_iter(), 2);
+    assert!(iter.next().unwrap().is_err());
+    assert_eq!(iter.next().unwrap().unwrap(), chunk(&[1, 2]).unwrap());
+    assert_eq!(iter.next().unwrap().unwrap(), chunk(&[3]).unwrap());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn different_types() {
+    let chunks = vec![
+        chunk(&[1]),
+        Ok(Chunk::new(vec![
+            Arc::new(Int64Array::from_slice([2])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(["2"])),
+        ])),
+    ];
+    let mut iter = Rechunk::new(chunks.into_iter(), 2);
+    assert!(iter.next().unwrap().is_err());
+}
@@ -55,3 +55,19 @@ fn test_like_scalar() {
         vec![true, false, false, false],
     )
 }
+
+#[test]
+fn test_compiled() {
+    let regex = Regex::new("^ar").unwrap();
+    let lhs = Utf8Array::<i64>::from([Some("arrow"), None, Some("parquet")]);
+    let result = regex_match_compiled(&lhs, &regex);
+    assert_eq!(result, BooleanArray::from([Some(true), None, Some(false)]));
+}
+
+#[test]
+fn test_invalid_pattern() {
+    let lhs = Utf8Array::<i32>::from_slice(["arrow"]);
+    assert!(regex_match_scalar(&lhs, "(").is_err());
+    let error = regex_match(&lhs, &Utf8Array::<i32>::from_slice(["("])).unwrap_err();
+    assert!(error.to_string().contains("Unable to compile regex"));
+}
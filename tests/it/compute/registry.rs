@@ -0,0 +1,113 @@
+use arrow2::array::*;
+use arrow2::compute::registry::Registry;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+
+fn extension_type() -> DataType {
+    DataType::Extension("money".to_string(), Box::new(DataType::Int64), None)
+}
+
+fn is_money(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Extension(name, _, _) if name == "money")
+}
+
+#[test]
+fn builtin() -> Result<()> {
+    let registry = Registry::builtin();
+    let lhs = Int32Array::from([Some(1), None, Some(3)]);
+    let rhs = Int32Array::from_slice([1, 2, 2]);
+
+    let result = registry.binary("add", &lhs, &rhs)?;
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from([Some(2), None, Some(5)]) as &dyn Array
+    );
+
+    let result = registry.binary("gt", &lhs, &rhs)?;
+    assert_eq!(
+        result.as_ref(),
+        &BooleanArray::from([Some(false), None, Some(true)]) as &dyn Array
+    );
+
+    let result = registry.unary("not", &BooleanArray::from_slice([true, false]))?;
+    assert_eq!(
+        result.as_ref(),
+        &BooleanArray::from_slice([false, true]) as &dyn Array
+    );
+
+    let result = registry.unary("length", &Utf8Array::<i32>::from_slice(["a", "bc"]))?;
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from_slice([1, 2]) as &dyn Array
+    );
+    Ok(())
+}
+
+#[test]
+fn not_registered() {
+    let registry = Registry::builtin();
+    let lhs = Int32Array::from_slice([1]);
+    let rhs = Utf8Array::<i32>::from_slice(["a"]);
+
+    assert!(!registry.can_binary("add", lhs.data_type(), rhs.data_type()));
+    assert!(registry.binary("add", &lhs, &rhs).is_err());
+    assert!(registry.unary("unknown", &lhs).is_err());
+}
+
+#[test]
+fn different_lengths() {
+    let registry = Registry::builtin();
+    let lhs = Int32Array::from_slice([1, 2]);
+    let rhs = Int32Array::from_slice([1]);
+    assert!(registry.binary("add", &lhs, &rhs).is_err());
+}
+
+#[test]
+fn extension() -> Result<()> {
+    let mut registry = Registry::builtin();
+    registry.register_binary(
+        "add",
+        |lhs, rhs| is_money(lhs) && is_money(rhs),
+        |lhs, rhs| {
+            let lhs = lhs.as_any().downcast_ref::<Int64Array>().unwrap();
+            let rhs = rhs.as_any().downcast_ref::<Int64Array>().unwrap();
+            let result = lhs
+                .values()
+                .iter()
+                .zip(rhs.values().iter())
+                .map(|(lhs, rhs)| lhs + rhs)
+                .collect::<Vec<_>>();
+            Ok(Box::new(Int64Array::from_vec(result).to(extension_type())))
+        },
+    );
+
+    let lhs = Int64Array::from_slice([1, 2]).to(extension_type());
+    let rhs = Int64Array::from_slice([10, 20]).to(extension_type());
+    let result = registry.binary("add", &lhs, &rhs)?;
+    let expected = Int64Array::from_slice([11, 22]).to(extension_type());
+    assert_eq!(result.as_ref(), &expected as &dyn Array);
+
+    // builtin kernels remain registered
+    let lhs = Int64Array::from_slice([1]);
+    assert!(registry.binary("add", &lhs, &lhs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn override_builtin() -> Result<()> {
+    let mut registry = Registry::builtin();
+    registry.register_unary(
+        "length",
+        |data_type| {
+            data_type == &DataType::List(Box::new(Field::new("item", DataType::Int32, true)))
+        },
+        |array| Ok(Box::new(Int32Array::from_slice(vec![0; array.len()]))),
+    );
+    let array = ListArray::<i32>::new_null(ListArray::<i32>::default_datatype(DataType::Int32), 2);
+    let result = registry.unary("length", &array)?;
+    assert_eq!(
+        result.as_ref(),
+        &Int32Array::from_slice([0, 0]) as &dyn Array
+    );
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use arrow2::array::*;
+use arrow2::bitmap::Bitmap;
+use arrow2::compute::selection::*;
+use arrow2::error::Result;
+
+#[test]
+fn representation_by_selectivity() {
+    // clustered rows are runs
+    let mask = BooleanArray::from_iter((0..1000).map(|i| Some((100..300).contains(&i))));
+    let selection = boolean_to_selection(&mask);
+    assert_eq!(
+        selection,
+        Selection::Runs {
+            runs: vec![(100, 200)],
+            length: 1000
+        }
+    );
+
+    // few rows are indices
+    let mask = BooleanArray::from_iter((0..1000).map(|i| Some(i % 100 == 1)));
+    let selection = boolean_to_selection(&mask);
+    assert!(matches!(selection, Selection::Indices { .. }));
+    assert_eq!(selection.selected(), 10);
+
+    // scattered rows are a mask
+    let mask = BooleanArray::from_iter((0..1000).map(|i| Some(i % 3 == 0)));
+    let selection = boolean_to_selection(&mask);
+    assert!(matches!(selection, Selection::Mask(_)));
+    assert_eq!(selection.selected(), 334);
+
+    let mask = BooleanArray::from_slice([false, false]);
+    assert_eq!(
+        boolean_to_selection(&mask),
+        Selection::Runs {
+            runs: vec![],
+            length: 2
+        }
+    );
+}
+
+#[test]
+fn nulls_are_not_selected() {
+    let mask = BooleanArray::from([Some(true), None, Some(false), Some(true)]);
+    let selection = boolean_to_selection(&mask);
+    assert_eq!(selection.to_runs(), vec![(0, 1), (3, 1)]);
+    assert_eq!(
+        selection_to_boolean(&selection),
+        BooleanArray::from_slice([true, false, false, true])
+    );
+}
+
+#[test]
+fn round_trips() -> Result<()> {
+    let values = (0..300).map(|i| i % 7 < 2 || i > 250).collect::<Vec<_>>();
+    let expected = values
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| **x)
+        .map(|(i, _)| i as u32)
+        .collect::<Vec<_>>();
+
+    let mask = Bitmap::from(values.as_slice());
+    let representations = vec![
+        Selection::Mask(mask.clone()),
+        Selection::Indices {
+            indices: expected.clone().into(),
+            length: 300,
+        },
+        Selection::Runs {
+            runs: Selection::Mask(mask.clone()).to_runs(),
+            length: 300,
+        },
+    ];
+    for selection in representations {
+        assert_eq!(selection.len(), 300);
+        assert_eq!(selection.selected(), expected.len());
+        assert_eq!(selection.to_mask(), mask);
+        assert_eq!(selection.to_indices()?.as_slice(), expected.as_slice());
+        assert_eq!(
+            selection_to_indices(&selection)?,
+            UInt32Array::from_slice(&expected)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn sliced_mask() {
+    let mask = BooleanArray::from_slice([true, false, true, true, false, true]).slice(1, 4);
+    assert_eq!(boolean_to_selection(&mask).to_runs(), vec![(1, 2)]);
+}
+
+#[test]
+fn from_indices() -> Result<()> {
+    let indices = UInt32Array::from_slice([2, 3, 4]);
+    assert_eq!(
+        indices_to_selection(&indices, 10)?,
+        Selection::Runs {
+            runs: vec![(2, 3)],
+            length: 10
+        }
+    );
+
+    assert!(indices_to_selection(&UInt32Array::from_slice([3, 2]), 10).is_err());
+    assert!(indices_to_selection(&UInt32Array::from_slice([2, 10]), 10).is_err());
+    assert!(indices_to_selection(&UInt32Array::from([Some(1), None]), 10).is_err());
+    Ok(())
+}
+
+#[test]
+fn compose() -> Result<()> {
+    // rows 1, 3, 5, 7 and 9
+    let first = boolean_to_selection(&BooleanArray::from_iter((0..10).map(|i| Some(i % 2 == 1))));
+    // the second and third of the selected rows, i.e. rows 3 and 5
+    let second = boolean_to_selection(&BooleanArray::from_slice([false, true, true, false, false]));
+
+    let result = first.compose(&second)?;
+    assert_eq!(result.len(), 10);
+    assert_eq!(result.to_indices()?.as_slice(), &[3, 5]);
+
+    assert!(second.compose(&first).is_err());
+    Ok(())
+}
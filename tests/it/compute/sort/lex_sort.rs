@@ -1,5 +1,5 @@
 use arrow2::array::*;
-use arrow2::compute::sort::{lexsort, SortColumn, SortOptions};
+use arrow2::compute::sort::{lexsort, lexsort_to_indices, SortColumn, SortOptions};
 
 fn test_lex_sort_arrays(input: Vec<SortColumn>, expected: Vec<Box<dyn Array>>) {
     let sorted = lexsort::<i32>(&input, None).unwrap();
@@ -73,138 +73,81 @@ fn test_lex_sort_mixed_types2() {
     test_lex_sort_arrays(input, expected);
 }
 
-/*
-    // test sort with nulls first
-    let input = vec![
-        SortColumn {
-            values: Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-                None,
-                Some(-1),
-                Some(2),
-                None,
-            ])) as ArrayRef,
-            options: Some(SortOptions {
-                descending: true,
-                nulls_first: true,
-            }),
-        },
-        SortColumn {
-            values: Arc::new(StringArray::from(vec![
-                Some("foo"),
-                Some("world"),
-                Some("hello"),
-                None,
-            ])) as ArrayRef,
-            options: Some(SortOptions {
-                descending: true,
-                nulls_first: true,
-            }),
-        },
-    ];
+fn sort_column(values: &dyn Array, descending: bool, nulls_first: bool) -> SortColumn<'_> {
+    SortColumn {
+        values,
+        options: Some(SortOptions {
+            descending,
+            nulls_first,
+        }),
+    }
+}
+
+#[test]
+fn test_lex_sort_nulls_first() {
+    let c1 = Int64Array::from([None, Some(-1), Some(2), None]);
+    let c2 = Utf8Array::<i32>::from([Some("foo"), Some("world"), Some("hello"), None]);
+    let input = vec![sort_column(&c1, true, true), sort_column(&c2, true, true)];
     let expected = vec![
-        Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-            None,
-            None,
-            Some(2),
-            Some(-1),
-        ])) as ArrayRef,
-        Arc::new(StringArray::from(vec![
+        Box::new(Int64Array::from([None, None, Some(2), Some(-1)])) as Box<dyn Array>,
+        Box::new(Utf8Array::<i32>::from([
             None,
             Some("foo"),
             Some("hello"),
             Some("world"),
-        ])) as ArrayRef,
+        ])),
     ];
     test_lex_sort_arrays(input, expected);
+}
 
-    // test sort with nulls last
-    let input = vec![
-        SortColumn {
-            values: Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-                None,
-                Some(-1),
-                Some(2),
-                None,
-            ])) as ArrayRef,
-            options: Some(SortOptions {
-                descending: true,
-                nulls_first: false,
-            }),
-        },
-        SortColumn {
-            values: Arc::new(StringArray::from(vec![
-                Some("foo"),
-                Some("world"),
-                Some("hello"),
-                None,
-            ])) as ArrayRef,
-            options: Some(SortOptions {
-                descending: true,
-                nulls_first: false,
-            }),
-        },
-    ];
+#[test]
+fn test_lex_sort_nulls_last() {
+    let c1 = Int64Array::from([None, Some(-1), Some(2), None]);
+    let c2 = Utf8Array::<i32>::from([Some("foo"), Some("world"), Some("hello"), None]);
+    let input = vec![sort_column(&c1, true, false), sort_column(&c2, true, false)];
     let expected = vec![
-        Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-            Some(2),
-            Some(-1),
-            None,
-            None,
-        ])) as ArrayRef,
-        Arc::new(StringArray::from(vec![
+        Box::new(Int64Array::from([Some(2), Some(-1), None, None])) as Box<dyn Array>,
+        Box::new(Utf8Array::<i32>::from([
             Some("hello"),
             Some("world"),
             Some("foo"),
             None,
-        ])) as ArrayRef,
+        ])),
     ];
     test_lex_sort_arrays(input, expected);
+}
 
-    // test sort with opposite options
-    let input = vec![
-        SortColumn {
-            values: Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-                None,
-                Some(-1),
-                Some(2),
-                Some(-1),
-                None,
-            ])) as ArrayRef,
-            options: Some(SortOptions {
-                descending: false,
-                nulls_first: false,
-            }),
-        },
-        SortColumn {
-            values: Arc::new(StringArray::from(vec![
-                Some("foo"),
-                Some("bar"),
-                Some("world"),
-                Some("hello"),
-                None,
-            ])) as ArrayRef,
-            options: Some(SortOptions {
-                descending: true,
-                nulls_first: true,
-            }),
-        },
-    ];
+#[test]
+fn test_lex_sort_opposite_options() {
+    let c1 = Int64Array::from([None, Some(-1), Some(2), Some(-1), None]);
+    let c2 = Utf8Array::<i32>::from([Some("foo"), Some("bar"), Some("world"), Some("hello"), None]);
+    let input = vec![sort_column(&c1, false, false), sort_column(&c2, true, true)];
     let expected = vec![
-        Arc::new(PrimitiveArray::<Int64Type>::from(vec![
-            Some(-1),
-            Some(-1),
-            Some(2),
-            None,
-            None,
-        ])) as ArrayRef,
-        Arc::new(StringArray::from(vec![
+        Box::new(Int64Array::from([Some(-1), Some(-1), Some(2), None, None])) as Box<dyn Array>,
+        Box::new(Utf8Array::<i32>::from([
             Some("hello"),
             Some("bar"),
             Some("world"),
             None,
             Some("foo"),
-        ])) as ArrayRef,
+        ])),
     ];
     test_lex_sort_arrays(input, expected);
 }
-*/
+
+#[test]
+fn test_lex_sort_to_indices_is_stable() {
+    let c1 = Int32Array::from_slice([1, 0, 1, 0, 1]);
+    let c2 = Int32Array::from_slice([5, 5, 5, 5, 4]);
+    let input = vec![sort_column(&c1, false, true), sort_column(&c2, false, true)];
+
+    let indices = lexsort_to_indices::<u32>(&input, None).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([1, 3, 4, 0, 2]));
+
+    // a limit that is not smaller than the number of rows is the same as no limit
+    let indices = lexsort_to_indices::<u32>(&input, Some(5)).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([1, 3, 4, 0, 2]));
+
+    let indices = lexsort_to_indices::<u32>(&input, Some(3)).unwrap();
+    assert_eq!(indices, UInt32Array::from_slice([1, 3, 4]));
+}
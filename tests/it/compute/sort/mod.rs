@@ -1,4 +1,5 @@
 mod lex_sort;
+mod sorted;
 
 use arrow2::array::*;
 use arrow2::compute::sort::*;
@@ -139,6 +140,60 @@ fn boolean() {
     );
 }
 
+#[test]
+fn boolean_with_limit() {
+    let array = BooleanArray::from(&[None, Some(false), Some(true), Some(true), Some(false), None]);
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, Some(3)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([1, 4, 2]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, Some(3)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([5, 0, 2]));
+
+    let indices = sort_to_indices::<i32>(&array, &options, Some(10)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([5, 0, 2, 3, 1, 4]));
+}
+
+#[test]
+fn list_with_limit() {
+    let data = vec![
+        None,
+        Some(vec![Some(4), None, Some(2)]),
+        Some(vec![Some(2), Some(3), Some(4)]),
+        Some(vec![Some(1)]),
+        None,
+        Some(vec![Some(2), Some(3), Some(4)]),
+        Some(vec![Some(3), Some(3), None]),
+    ];
+    let mut array = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    array.try_extend(data).unwrap();
+    let array: ListArray<i32> = array.into();
+
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, None).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([3, 2, 5, 6, 1, 0, 4]));
+    let indices = sort_to_indices::<i32>(&array, &options, Some(3)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([3, 2, 5]));
+
+    let options = SortOptions {
+        descending: true,
+        nulls_first: true,
+    };
+    let indices = sort_to_indices::<i32>(&array, &options, Some(4)).unwrap();
+    assert_eq!(indices, Int32Array::from_slice([0, 4, 1, 6]));
+}
+
 #[test]
 #[ignore] // improve equality for NaN values. These are right but the equality fails
 fn test_nans() {
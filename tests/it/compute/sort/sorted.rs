@@ -0,0 +1,69 @@
+use arrow2::array::*;
+use arrow2::compute::sort::*;
+
+#[test]
+fn sorted_options() {
+    let array = Int32Array::from([None, Some(1), Some(2), Some(2)]);
+    let options = |descending, nulls_first| SortOptions {
+        descending,
+        nulls_first,
+    };
+    assert!(is_sorted(&array, &options(false, true)).unwrap());
+    assert!(!is_sorted(&array, &options(false, false)).unwrap());
+    assert!(!is_sorted(&array, &options(true, true)).unwrap());
+
+    let array = Utf8Array::<i32>::from([Some("c"), Some("b"), None]);
+    assert!(is_sorted(&array, &options(true, false)).unwrap());
+}
+
+#[test]
+fn sorted_trivial() {
+    let array = Int32Array::from_slice([1]);
+    assert!(is_sorted(&array, &SortOptions::default()).unwrap());
+    let array = Int32Array::from_slice([2, 1]).slice(1, 1);
+    assert!(is_sorted(&array, &SortOptions::default()).unwrap());
+}
+
+#[test]
+fn lexsorted() {
+    let a = Int32Array::from_slice([1, 1, 2]);
+    let b = Utf8Array::<i32>::from_slice(["b", "a", "a"]);
+    let columns = |descending| {
+        vec![
+            SortColumn {
+                values: &a,
+                options: None,
+            },
+            SortColumn {
+                values: &b,
+                options: Some(SortOptions {
+                    descending,
+                    nulls_first: true,
+                }),
+            },
+        ]
+    };
+    assert!(is_lexsorted(&columns(true)).unwrap());
+    assert!(!is_lexsorted(&columns(false)).unwrap());
+    assert!(is_lexsorted(&[]).is_err());
+}
+
+#[test]
+fn monotonic() {
+    let check = |values: &[Option<f64>], expected| {
+        let array = Float64Array::from(values);
+        assert_eq!(monotonicity(&array).unwrap(), expected);
+    };
+    check(&[], Monotonicity::Constant);
+    check(&[None, None], Monotonicity::Constant);
+    check(&[Some(1.0), None, Some(1.0)], Monotonicity::Constant);
+    check(
+        &[Some(1.0), None, Some(1.0), Some(2.0)],
+        Monotonicity::Increasing,
+    );
+    check(&[Some(2.0), Some(1.0), None], Monotonicity::Decreasing);
+    check(
+        &[Some(1.0), Some(2.0), Some(1.0)],
+        Monotonicity::NotMonotonic,
+    );
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::split::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn basics() {
+    let array = Utf8Array::<i32>::from([Some("a b"), None, Some("c"), Some("d  e")]);
+    let result = split(&array, " ").unwrap();
+
+    let expected = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Utf8),
+        vec![0, 2, 2, 3, 6].into(),
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c", "d", "", "e"])),
+        Some([true, false, true, true].into()),
+    );
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn large() {
+    let array = Utf8Array::<i64>::from_slice(["a::b"]);
+    let result = split(&array, "::").unwrap();
+    assert_eq!(
+        result.data_type(),
+        &ListArray::<i64>::default_datatype(DataType::LargeUtf8)
+    );
+    assert_eq!(
+        result.values().as_ref(),
+        &Utf8Array::<i64>::from_slice(["a", "b"]) as &dyn Array
+    );
+}
+
+#[test]
+fn n() {
+    let array = Utf8Array::<i32>::from_slice(["a,b,c", "a"]);
+    let result = split_n(&array, ",", 2).unwrap();
+    assert_eq!(result.offsets().as_slice(), &[0, 2, 3]);
+    assert_eq!(
+        result.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b,c", "a"]) as &dyn Array
+    );
+
+    let result = rsplit_n(&array, ",", 2).unwrap();
+    assert_eq!(
+        result.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["c", "a,b", "a"]) as &dyn Array
+    );
+
+    assert!(split_n(&array, ",", 0).is_err());
+}
+
+#[test]
+fn empty_delimiter() {
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(split(&array, "").is_err());
+}
+
+#[test]
+fn dyn_() {
+    let array = Utf8Array::<i32>::from_slice(["a,b"]);
+    let result = split_dyn(&array, ",").unwrap();
+    assert_eq!(result.len(), 1);
+    assert!(split_dyn(&Int32Array::from_slice([1]), ",").is_err());
+}
@@ -0,0 +1,75 @@
+use arrow2::array::*;
+use arrow2::compute::string::*;
+
+#[test]
+fn lower_upper() {
+    let array = Utf8Array::<i32>::from([Some("aBc"), None, Some(""), Some("D")]);
+    let expected = Utf8Array::<i32>::from([Some("abc"), None, Some(""), Some("d")]);
+    assert_eq!(lower(&array), expected);
+    let expected = Utf8Array::<i32>::from([Some("ABC"), None, Some(""), Some("D")]);
+    assert_eq!(upper(&array), expected);
+
+    let array = Utf8Array::<i64>::from([Some("ÀbΣ"), Some("Straße"), None, Some("aB")]);
+    let expected = Utf8Array::<i64>::from([Some("àbς"), Some("straße"), None, Some("ab")]);
+    assert_eq!(lower(&array), expected);
+    let expected = Utf8Array::<i64>::from([Some("ÀBΣ"), Some("STRASSE"), None, Some("AB")]);
+    assert_eq!(upper(&array), expected);
+}
+
+#[test]
+fn lower_upper_sliced() {
+    let array = Utf8Array::<i32>::from([Some("aA"), Some("bB"), None, Some("cC")]).slice(1, 3);
+    let expected = Utf8Array::<i32>::from([Some("bb"), None, Some("cc")]);
+    assert_eq!(lower(&array), expected);
+
+    let array = Utf8Array::<i32>::from([Some("é"), Some("bB"), None, Some("çC")]).slice(1, 3);
+    let expected = Utf8Array::<i32>::from([Some("BB"), None, Some("ÇC")]);
+    assert_eq!(upper(&array), expected);
+}
+
+#[test]
+fn length_chars() {
+    let array = Utf8Array::<i32>::from([Some("abc"), None, Some("")]).slice(0, 3);
+    assert_eq!(length(&array), Int32Array::from([Some(3), None, Some(0)]));
+
+    let array = Utf8Array::<i64>::from([Some("ação"), None, Some("ab")]);
+    assert_eq!(length(&array), Int64Array::from([Some(4), None, Some(2)]));
+}
+
+#[test]
+fn substring_chars() {
+    let array = Utf8Array::<i32>::from([Some("hello"), None, Some("ação"), Some("")]);
+
+    let result = substring(&array, 1, Some(2));
+    let expected = Utf8Array::<i32>::from([Some("el"), None, Some("çã"), Some("")]);
+    assert_eq!(result, expected);
+
+    let result = substring(&array, -2, None);
+    let expected = Utf8Array::<i32>::from([Some("lo"), None, Some("ão"), Some("")]);
+    assert_eq!(result, expected);
+
+    let result = substring(&array, -10, Some(1));
+    let expected = Utf8Array::<i32>::from([Some("h"), None, Some("a"), Some("")]);
+    assert_eq!(result, expected);
+
+    let result = substring(&array, 10, None);
+    let expected = Utf8Array::<i32>::from([Some(""), None, Some(""), Some("")]);
+    assert_eq!(result, expected);
+
+    let result = substring(&array, 0, Some(u64::MAX));
+    assert_eq!(result, array);
+}
+
+#[test]
+fn trims() {
+    let array = Utf8Array::<i32>::from([Some(" a b "), None, Some("\t c"), Some("  ")]);
+
+    let expected = Utf8Array::<i32>::from([Some("a b"), None, Some("c"), Some("")]);
+    assert_eq!(trim(&array), expected);
+
+    let expected = Utf8Array::<i32>::from([Some("a b "), None, Some("c"), Some("")]);
+    assert_eq!(trim_start(&array), expected);
+
+    let expected = Utf8Array::<i32>::from([Some(" a b"), None, Some("\t c"), Some("")]);
+    assert_eq!(trim_end(&array), expected);
+}
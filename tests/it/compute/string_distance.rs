@@ -0,0 +1,51 @@
+use arrow2::array::*;
+use arrow2::compute::string_distance::*;
+
+#[test]
+fn levenshtein_arrays() {
+    let lhs = Utf8Array::<i32>::from([Some("kitten"), Some(""), Some("flaw"), Some("ãrrow"), None]);
+    let rhs = Utf8Array::<i32>::from([
+        Some("sitting"),
+        Some("abc"),
+        Some("lawn"),
+        Some("arrow"),
+        Some("a"),
+    ]);
+    let result = levenshtein(&lhs, &rhs).unwrap();
+    let expected = UInt32Array::from([Some(3), Some(3), Some(2), Some(1), None]);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn levenshtein_different_lengths() {
+    let lhs = Utf8Array::<i32>::from_slice(["a"]);
+    let rhs = Utf8Array::<i32>::from_slice(["a", "b"]);
+    assert!(levenshtein(&lhs, &rhs).is_err());
+}
+
+#[test]
+fn levenshtein_with_scalar() {
+    let array = Utf8Array::<i64>::from([Some("arrow"), Some("arrow2"), None]);
+    let result = levenshtein_scalar(&array, "arrow");
+    assert_eq!(result, UInt32Array::from([Some(0), Some(1), None]));
+}
+
+#[test]
+fn jaro_winkler_arrays() {
+    let lhs = Utf8Array::<i32>::from([Some("dixon"), Some("abc"), Some(""), None]);
+    let rhs = Utf8Array::<i32>::from([Some("dicksonx"), Some("xyz"), Some(""), Some("a")]);
+    let result = jaro_winkler(&lhs, &rhs).unwrap();
+    assert!((result.value(0) - 0.8133).abs() < 1e-4);
+    assert_eq!(result.value(1), 0.0);
+    assert_eq!(result.value(2), 1.0);
+    assert!(result.is_null(3));
+}
+
+#[test]
+fn jaro_winkler_with_scalar() {
+    let array = Utf8Array::<i32>::from([Some("marhta"), Some("martha"), None]);
+    let result = jaro_winkler_scalar(&array, "martha");
+    assert!((result.value(0) - 0.9611).abs() < 1e-4);
+    assert_eq!(result.value(1), 1.0);
+    assert!(result.is_null(2));
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use arrow2::compute::take::{can_take, take};
+use arrow2::compute::take::{can_take, compact_dictionary, take, take_into};
 use arrow2::datatypes::{DataType, Field, IntervalUnit};
 use arrow2::error::Result;
 use arrow2::{array::*, bitmap::MutableBitmap, types::NativeType};
@@ -313,3 +313,137 @@ fn test_nested() {
 
     assert_eq!(expected, result.as_ref());
 }
+
+#[test]
+fn fixed_size_list_with_nulls() {
+    let values = Int32Array::from_slice([1, 2, 3, 4, 5, 6]);
+    let data_type = FixedSizeListArray::default_datatype(DataType::Int32, 2);
+    let array = FixedSizeListArray::from_data(
+        data_type.clone(),
+        Arc::new(values),
+        Some([true, false, true].into()),
+    );
+
+    let indices = Int32Array::from([Some(2), None, Some(1), Some(0)]);
+    let result = take(&array, &indices).unwrap();
+
+    let expected_values =
+        Int32Array::from([Some(5), Some(6), None, None, None, None, Some(1), Some(2)]);
+    let expected = FixedSizeListArray::from_data(
+        data_type,
+        Arc::new(expected_values),
+        Some([true, false, false, true].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn list_of_struct_with_out_of_bounds_null_index() {
+    let fields = vec![Field::new("a", DataType::Int32, true)];
+    let values = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>],
+        None,
+    );
+    let data_type = ListArray::<i32>::default_datatype(values.data_type().clone());
+    let array = ListArray::<i32>::from_data(
+        data_type.clone(),
+        Buffer::from_slice([0, 2, 3]),
+        Arc::new(values.clone()),
+        None,
+    );
+
+    // the value of a null index is irrelevant, even when out of bounds
+    let indices = Int32Array::from_data(
+        DataType::Int32,
+        Buffer::from_slice([1, 100]),
+        Some([true, false].into()),
+    );
+    let result = take(&array, &indices).unwrap();
+
+    let expected = ListArray::<i32>::from_data(
+        data_type,
+        Buffer::from_slice([0, 1, 1]),
+        Arc::new(values.slice(2, 1)),
+        Some([true, false].into()),
+    );
+    assert_eq!(expected, result.as_ref());
+}
+
+#[test]
+fn take_into_appends() -> Result<()> {
+    let mut out = MutableUtf8Array::<i32>::new();
+    let values = Utf8Array::<i32>::from([Some("a"), None, Some("c")]);
+    take_into(
+        &values,
+        &Int32Array::from([Some(2), Some(1), None]),
+        &mut out,
+    )?;
+    let values = Utf8Array::<i32>::from_slice(["d", "e"]);
+    take_into(&values, &Int32Array::from_slice([1, 1, 0]), &mut out)?;
+
+    let out: Utf8Array<i32> = out.into();
+    let expected = Utf8Array::<i32>::from([Some("c"), None, None, Some("e"), Some("e"), Some("d")]);
+    assert_eq!(out, expected);
+    Ok(())
+}
+
+#[test]
+fn take_into_equals_take() -> Result<()> {
+    let values = BooleanArray::from([Some(true), None, Some(false)]);
+    let indices = Int32Array::from([Some(2), None, Some(1), Some(0)]);
+
+    let mut out = MutableBooleanArray::new();
+    take_into(&values, &indices, &mut out)?;
+    let out: BooleanArray = out.into();
+    assert_eq!(&out as &dyn Array, take(&values, &indices)?.as_ref());
+    Ok(())
+}
+
+#[test]
+fn take_into_wrong_type() {
+    let values = Int32Array::from_slice([1, 2]).to(DataType::Date32);
+    let indices = Int32Array::from_slice([0]);
+
+    let mut out = MutablePrimitiveArray::<i32>::new();
+    assert!(take_into(&values, &indices, &mut out).is_err());
+}
+
+#[test]
+fn dictionary_shares_values() -> Result<()> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("b"), Some("a")])?;
+    let array: DictionaryArray<i32> = array.into();
+
+    let indices = UInt32Array::from([Some(3), None, Some(1)]);
+    let result = take(&array, &indices)?;
+    let result = result
+        .as_any()
+        .downcast_ref::<DictionaryArray<i32>>()
+        .unwrap();
+
+    assert!(Arc::ptr_eq(result.values(), array.values()));
+    assert_eq!(result.keys(), &Int32Array::from([Some(0), None, None]));
+    Ok(())
+}
+
+#[test]
+fn compact_dictionary_drops_unused_values() -> Result<()> {
+    let mut array = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), Some("b"), Some("c"), None, Some("c")])?;
+    let array: DictionaryArray<u8> = array.into();
+    let array = array.slice(2, 3);
+
+    let result = compact_dictionary(&array)?;
+    assert_eq!(
+        result.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["c"]) as &dyn Array
+    );
+    assert_eq!(result.keys(), &UInt8Array::from([Some(0), None, Some(0)]));
+    assert_eq!(result.data_type(), array.data_type());
+
+    // all values are used: nothing to compact
+    let result = compact_dictionary(&result)?;
+    assert_eq!(result.values().len(), 1);
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::compute::tokenize::*;
+use arrow2::datatypes::DataType;
+
+#[test]
+fn basics() {
+    let array = Utf8Array::<i32>::from([Some("a b a"), None, Some(""), Some("b, c")]);
+    let result = tokenize::<i32, i32>(&array, r"\w+").unwrap();
+
+    let tokens = DictionaryArray::<i32>::from_data(
+        Int32Array::from_slice([0, 1, 0, 1, 2]),
+        Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+    );
+    let expected = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(tokens.data_type().clone()),
+        vec![0, 3, 3, 3, 5].into(),
+        Arc::new(tokens),
+        Some([true, false, true, true].into()),
+    );
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn large() {
+    let array = Utf8Array::<i64>::from_slice(["x=1;y=2"]);
+    let result = tokenize::<i64, u8>(&array, r"[a-z]=\d").unwrap();
+    assert_eq!(
+        result.data_type(),
+        &ListArray::<i64>::default_datatype(DataType::Dictionary(
+            u8::KEY_TYPE,
+            Box::new(DataType::LargeUtf8),
+            false
+        ))
+    );
+    let tokens = result
+        .values()
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    assert_eq!(
+        tokens.values().as_ref(),
+        &Utf8Array::<i64>::from_slice(["x=1", "y=2"]) as &dyn Array
+    );
+}
+
+#[test]
+fn key_overflow() {
+    let values = (0..300).map(|x| x.to_string()).collect::<Vec<_>>();
+    let array = Utf8Array::<i32>::from_slice([values.join(" ")]);
+    assert!(tokenize::<i32, u8>(&array, r"\d+").is_err());
+}
+
+#[test]
+fn invalid_regex() {
+    let array = Utf8Array::<i32>::from_slice(["a"]);
+    assert!(tokenize::<i32, i32>(&array, "(").is_err());
+}
+
+#[test]
+fn dyn_() {
+    let array = Utf8Array::<i32>::from_slice(["a b"]);
+    let result = tokenize_dyn(&array, r"\w+").unwrap();
+    assert_eq!(result.len(), 1);
+
+    let array = Int32Array::from_slice([1]);
+    assert!(tokenize_dyn(&array, r"\w+").is_err());
+}
@@ -0,0 +1,55 @@
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::util::generate::*;
+
+#[test]
+fn sequential_values() {
+    let array = sequential(-1i32, 4);
+    assert_eq!(array, Int32Array::from_slice([-1, 0, 1, 2]));
+
+    let array = sequential(0.5f64, 2);
+    assert_eq!(array, Float64Array::from_slice([0.5, 1.5]));
+}
+
+#[test]
+fn zipf_is_deterministic() {
+    let array = zipf_dictionary::<i16>(1000, 10, 1.0, 0.1, 1);
+    assert_eq!(array, zipf_dictionary::<i16>(1000, 10, 1.0, 0.1, 1));
+    assert_eq!(array.len(), 1000);
+    assert_eq!(array.values().len(), 10);
+    assert!(array.null_count() > 0);
+}
+
+#[test]
+fn zipf_frequencies() {
+    let array = zipf_dictionary::<u8>(10_000, 5, 2.0, 0.0, 0);
+    assert_eq!(array.null_count(), 0);
+
+    let mut counts = [0usize; 5];
+    array
+        .keys()
+        .values()
+        .iter()
+        .for_each(|key| counts[*key as usize] += 1);
+    // the first value is the most frequent, about 4 times more than the second one
+    assert!(counts.windows(2).all(|x| x[0] >= x[1]));
+    assert!(counts[0] > 3 * counts[1]);
+}
+
+#[test]
+fn timestamps_in_range() {
+    let array = timestamps(
+        100,
+        -10,
+        10,
+        TimeUnit::Millisecond,
+        Some("+01:00".to_string()),
+        0.0,
+        0,
+    );
+    assert_eq!(
+        array.data_type(),
+        &DataType::Timestamp(TimeUnit::Millisecond, Some("+01:00".to_string()))
+    );
+    assert!(array.values().iter().all(|x| (-10..10).contains(x)));
+}
@@ -113,3 +113,160 @@ fn snappy() -> Result<()> {
 fn deflate() -> Result<()> {
     roundtrip(Some(write::Compression::Deflate))
 }
+
+fn nested_schema() -> Schema {
+    Schema::from(vec![
+        Field::new("a", DataType::Float32, true),
+        Field::new("b", DataType::Float64, true),
+        Field::new("c", DataType::FixedSizeBinary(2), false),
+        Field::new("d", DataType::FixedSizeBinary(2), true),
+        Field::new(
+            "e",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            true,
+        ),
+        Field::new(
+            "f",
+            DataType::List(Box::new(Field::new("item", DataType::Utf8, false))),
+            false,
+        ),
+    ])
+}
+
+fn nested_data() -> Chunk<Arc<dyn Array>> {
+    let mut e = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    e.try_extend(vec![
+        Some(vec![Some(1), None]),
+        None,
+        Some(vec![]),
+        Some(vec![Some(3)]),
+    ])
+    .unwrap();
+    let e: ListArray<i32> = e.into();
+
+    let mut f = MutableListArray::<i32, MutableUtf8Array<i32>>::new();
+    f.try_extend(vec![
+        Some(vec![Some("a")]),
+        Some(vec![]),
+        Some(vec![Some("b"), Some("c")]),
+        Some(vec![]),
+    ])
+    .unwrap();
+    let f: ListArray<i32> = f.into();
+
+    let columns = vec![
+        Arc::new(Float32Array::from([Some(1.5), None, Some(-1.0), None])) as Arc<dyn Array>,
+        Arc::new(Float64Array::from([None, Some(2.5), None, Some(0.0)])) as Arc<dyn Array>,
+        Arc::new(FixedSizeBinaryArray::from_data(
+            DataType::FixedSizeBinary(2),
+            b"aabbccdd".to_vec().into(),
+            None,
+        )) as Arc<dyn Array>,
+        Arc::new(FixedSizeBinaryArray::from_data(
+            DataType::FixedSizeBinary(2),
+            b"aabbccdd".to_vec().into(),
+            Some([true, false, false, true].into()),
+        )) as Arc<dyn Array>,
+        Arc::new(e) as Arc<dyn Array>,
+        Arc::new(f) as Arc<dyn Array>,
+    ];
+
+    Chunk::try_new(columns).unwrap()
+}
+
+#[test]
+fn nested_roundtrip() -> Result<()> {
+    let expected = nested_data();
+    let expected_schema = nested_schema();
+
+    let data = write_avro(&expected, &expected_schema, None)?;
+
+    let (result, read_schema) = read_avro(&data)?;
+
+    // avro named types (e.g. fixed) are read with extra metadata
+    for (f1, f2) in read_schema.fields.iter().zip(expected_schema.fields.iter()) {
+        assert_eq!(f1.data_type(), f2.data_type());
+        assert_eq!(f1.is_nullable, f2.is_nullable);
+    }
+    for (c1, c2) in result.columns().iter().zip(expected.columns().iter()) {
+        assert_eq!(c1.as_ref(), c2.as_ref());
+    }
+    Ok(())
+}
+
+#[test]
+fn writer() -> Result<()> {
+    let expected = data();
+    let expected_schema = schema();
+
+    let mut writer = write::Writer::try_new(vec![], &expected_schema, None)?;
+    writer.write(&expected)?;
+    writer.write(&expected)?;
+    let file = writer.into_inner();
+
+    let (result, read_schema) = read_avro(&file)?;
+    assert_eq!(expected_schema, read_schema);
+    assert_eq!(result, expected);
+
+    // the file has two blocks with the same rows
+    let mut reader = file.as_slice();
+    let (avro_schema, schema, codec, file_marker) =
+        arrow2::io::avro::read::read_metadata(&mut reader)?;
+    let reader = arrow2::io::avro::read::Reader::new(
+        arrow2::io::avro::read::Decompressor::new(
+            arrow2::io::avro::read::BlockStreamIterator::new(&mut reader, file_marker),
+            codec,
+        ),
+        avro_schema,
+        schema.fields,
+    );
+    assert_eq!(
+        reader.collect::<Result<Vec<_>>>()?,
+        vec![expected.clone(), expected]
+    );
+    Ok(())
+}
+
+#[test]
+fn writer_errors() -> Result<()> {
+    let mut writer = write::Writer::try_new(vec![], &schema(), None)?;
+
+    let columns = Chunk::new(data().columns()[1..].to_vec());
+    assert!(writer.write(&columns).is_err());
+
+    let mut columns = data().columns().to_vec();
+    columns[0] = Arc::new(Int32Array::from_slice([1, 2]));
+    assert!(writer.write(&Chunk::new(columns)).is_err());
+
+    let unsupported = Schema::from(vec![Field::new("a", DataType::UInt8, false)]);
+    assert!(write::Writer::try_new(vec![], &unsupported, None).is_err());
+    Ok(())
+}
+
+#[test]
+fn struct_schema() -> Result<()> {
+    let data_type = DataType::Struct(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, false),
+    ]);
+    assert!(write::can_serialize(&data_type));
+    assert!(!write::can_serialize(&DataType::Struct(vec![Field::new(
+        "a",
+        DataType::UInt8,
+        false
+    )])));
+
+    let schema = Schema::from(vec![Field::new("s", data_type.clone(), true)]);
+    let array = StructArray::from_data(
+        data_type,
+        vec![
+            Arc::new(Int32Array::from([Some(1), None])),
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])),
+        ],
+        Some([true, false].into()),
+    );
+
+    let mut writer = write::Writer::try_new(vec![], &schema, None)?;
+    writer.write(&Chunk::new(vec![Arc::new(array) as Arc<dyn Array>]))?;
+    Ok(())
+}
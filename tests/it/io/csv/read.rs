@@ -89,6 +89,151 @@ fn infer_ints() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn infer_with_options() -> Result<()> {
+    let file = Cursor::new(
+        "a,b,c,d,e,f\n\
+        yes,1.5,01/02/2021 10:00,01/02/2021,1,x\n\
+        no,-10.25,,02/02/2021,,y\n\
+        ,1,03/02/2021 11:30,,2,z",
+    );
+    let mut reader = ReaderBuilder::new().from_reader(file);
+
+    let mut options = InferOptions {
+        datetime_formats: vec!["%d/%m/%Y %H:%M".to_string(), "%d/%m/%Y".to_string()],
+        true_values: vec!["yes".to_string()],
+        false_values: vec!["no".to_string()],
+        decimals: true,
+        ..Default::default()
+    };
+    options.overrides.insert("e".to_string(), DataType::Float32);
+
+    let (fields, _) = infer_schema_with_options(&mut reader, None, true, &options)?;
+
+    assert_eq!(
+        fields,
+        vec![
+            Field::new("a", DataType::Boolean, true),
+            Field::new("b", DataType::Decimal(21, 2), true),
+            Field::new("c", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+            Field::new("d", DataType::Date32, true),
+            Field::new("e", DataType::Float32, true),
+            Field::new("f", DataType::Utf8, true),
+        ]
+    );
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+    let chunk = deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        None,
+        0,
+        |rows, column, data_type, line_number| {
+            deserialize_column_with(rows, column, data_type, line_number, &options)
+        },
+    )?;
+
+    let expected = BooleanArray::from([Some(true), Some(false), None]);
+    assert_eq!(chunk.arrays()[0].as_ref(), &expected as &dyn Array);
+    let expected =
+        Int128Array::from([Some(150), Some(-1025), Some(100)]).to(DataType::Decimal(21, 2));
+    assert_eq!(chunk.arrays()[1].as_ref(), &expected as &dyn Array);
+    let expected = Int64Array::from([Some(1612173600000), None, Some(1612351800000)])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    assert_eq!(chunk.arrays()[2].as_ref(), &expected as &dyn Array);
+    let expected = Int32Array::from([Some(18659), Some(18660), None]).to(DataType::Date32);
+    assert_eq!(chunk.arrays()[3].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn infer_merges() -> Result<()> {
+    let file = Cursor::new("1.5,1.5,,true\n10.25,1e3,,\n,2,,false");
+    let mut reader = ReaderBuilder::new().from_reader(file);
+
+    let options = InferOptions {
+        decimals: true,
+        ..Default::default()
+    };
+    let (fields, _) = infer_schema_with_options(&mut reader, Some(10), false, &options)?;
+
+    assert_eq!(
+        fields,
+        vec![
+            Field::new("column_1", DataType::Decimal(4, 2), true),
+            Field::new("column_2", DataType::Float64, true),
+            Field::new("column_3", DataType::Null, true),
+            Field::new("column_4", DataType::Boolean, true),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn deserialize_projection() -> Result<()> {
+    let file = Cursor::new("a,b,c,d\n1,x,2.5,true\n2,y,,false");
+    let mut reader = ReaderBuilder::new().from_reader(file);
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer)?;
+
+    let mut rows = vec![ByteRecord::default(); 10];
+    let rows_read = read_rows(&mut reader, 0, &mut rows)?;
+
+    let chunk = deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        Some(&[3, 0]),
+        0,
+        deserialize_column,
+    )?;
+    assert_eq!(chunk.arrays().len(), 2);
+    let expected = BooleanArray::from_slice([true, false]);
+    assert_eq!(chunk.arrays()[0].as_ref(), &expected as &dyn Array);
+    let expected = Int64Array::from_slice([1, 2]);
+    assert_eq!(chunk.arrays()[1].as_ref(), &expected as &dyn Array);
+
+    let result = deserialize_batch(
+        &rows[..rows_read],
+        &fields,
+        Some(&[1, 4]),
+        0,
+        deserialize_column,
+    );
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[cfg(feature = "io_csv_read_parallel")]
+#[test]
+fn read_parallel_chunks() -> Result<()> {
+    let data = (0..100)
+        .map(|i| format!("{},{}\n", i, i * 2))
+        .collect::<String>();
+    let data = format!("a,b\n{}", data);
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer)?;
+
+    let chunks = read_parallel(&mut reader, &fields, Some(&[1]), 7)?;
+    assert_eq!(chunks.len(), 15);
+    assert!(chunks.iter().take(14).all(|chunk| chunk.len() == 7));
+    assert_eq!(chunks[14].len(), 2);
+
+    let values = chunks
+        .iter()
+        .flat_map(|chunk| {
+            let array = chunk.arrays()[0]
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap();
+            array.values().iter().copied().collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(values, (0..100).map(|i| i * 2).collect::<Vec<_>>());
+
+    assert!(read_parallel(&mut reader, &fields, None, 0).is_err());
+    Ok(())
+}
+
 fn test_deserialize(input: &str, data_type: DataType) -> Result<Arc<dyn Array>> {
     let reader = std::io::Cursor::new(input);
     let mut reader = ReaderBuilder::new().has_headers(false).from_reader(reader);
@@ -154,8 +299,7 @@ fn decimal_only_scale() -> Result<()> {
 #[test]
 fn decimal_only_integer() -> Result<()> {
     let result = test_deserialize("1,\n1.0,\n1.1,\n10.0,\n", DataType::Decimal(1, 0))?;
-    let expected =
-        Int128Array::from(&[Some(1), Some(1), None, Some(10)]).to(DataType::Decimal(1, 0));
+    let expected = Int128Array::from(&[Some(1), Some(1), None, None]).to(DataType::Decimal(1, 0));
     assert_eq!(expected, result.as_ref());
     Ok(())
 }
@@ -218,6 +362,39 @@ fn deserialize_timestamp() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn read_in_chunks() -> Result<()> {
+    let data = "a,b\n1,x\n2,y\n3,z\n4,w\n5,v\n";
+    let mut reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer)?;
+
+    let chunks = read_chunks(reader, fields, Some(vec![0]), 2).collect::<Result<Vec<_>>>()?;
+
+    let lengths = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![2, 2, 1]);
+    assert_eq!(chunks[0].arrays().len(), 1);
+    let a = chunks[2].arrays()[0]
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a, &Int64Array::from_slice([5]));
+    Ok(())
+}
+
+#[test]
+fn read_in_chunks_error() -> Result<()> {
+    // the fourth row has more fields than the others
+    let data = "a\n1\n2\n3,4\n5\n";
+    let reader = ReaderBuilder::new().from_reader(Cursor::new(data));
+    let fields = vec![Field::new("a", DataType::Int64, true)];
+
+    let mut chunks = read_chunks(reader, fields, None, 2);
+    assert_eq!(chunks.next().unwrap()?.len(), 2);
+    assert!(chunks.next().unwrap().is_err());
+    assert!(chunks.next().is_none());
+    Ok(())
+}
+
 proptest! {
     #[test]
     #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
@@ -1,4 +1,5 @@
 use futures::io::Cursor;
+use futures::TryStreamExt;
 
 use arrow2::array::*;
 use arrow2::error::Result;
@@ -48,3 +49,24 @@ async fn read() -> Result<()> {
     assert_eq!("Aberdeen, Aberdeen City, UK", city.value(13));
     Ok(())
 }
+
+#[tokio::test]
+async fn read_in_chunks() -> Result<()> {
+    let data = "a,b\n1,x\n2,y\n3,z\n4,w\n5,v\n";
+    let mut reader = AsyncReaderBuilder::new().create_reader(Cursor::new(data.as_bytes()));
+    let (fields, _) = infer_schema(&mut reader, None, true, &infer).await?;
+
+    let chunks = read_chunks(reader, fields, Some(vec![0]), 2)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let lengths = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![2, 2, 1]);
+    assert_eq!(chunks[0].arrays().len(), 1);
+    let a = chunks[2].arrays()[0]
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(a, &Int64Array::from_slice([5]));
+    Ok(())
+}
@@ -219,6 +219,14 @@ fn data_array(column: usize) -> (Chunk<Arc<dyn Array>>, Vec<&'static str>) {
                 ],
             )
         }
+        18 => {
+            let array = Int128Array::from(&[Some(-1234), Some(5), None, Some(100)])
+                .to(DataType::Decimal(5, 2));
+            (
+                Arc::new(array) as Arc<dyn Array>,
+                vec!["-12.34", "0.05", "\"\"", "1.00"],
+            )
+        }
         _ => todo!(),
     };
 
@@ -260,6 +268,50 @@ fn write_each() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn write_null() -> Result<()> {
+    let keys = Int8Array::from([Some(1), None, Some(0)]);
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b"]));
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>,
+        Arc::new(DictionaryArray::<i8>::from_data(keys, values)),
+        Arc::new(NullArray::from_data(DataType::Null, 3)),
+    ]);
+
+    let write = Cursor::new(Vec::<u8>::new());
+    let mut writer = WriterBuilder::new().from_writer(write);
+    let options = SerializeOptions {
+        null: "NULL".to_string(),
+        ..Default::default()
+    };
+    write_chunk(&mut writer, &chunk, &options)?;
+
+    let buffer = writer.into_inner().unwrap().into_inner();
+    assert_eq!(
+        "1,b,\nNULL,NULL,\n3,a,\n".to_string(),
+        String::from_utf8(buffer).unwrap(),
+    );
+    Ok(())
+}
+
+#[test]
+fn write_unsupported() {
+    let array = Int32Array::from_slice([1]);
+    let array = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int32),
+        vec![0, 1].into(),
+        Arc::new(array),
+        None,
+    );
+    let options = SerializeOptions::default();
+    assert!(new_serializer(&array, &options).is_err());
+}
+
+#[test]
+fn write_decimal() -> Result<()> {
+    write_single(18)
+}
+
 #[test]
 #[cfg(feature = "chrono-tz")]
 fn write_tz_timezone() -> Result<()> {
@@ -0,0 +1,171 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::feather::{read, write};
+
+fn round_trip(schema: Schema, chunk: Chunk<Arc<dyn Array>>) -> Result<()> {
+    let mut file = Cursor::new(vec![]);
+    write::write(&mut file, &schema, &chunk)?;
+
+    let metadata = read::read_metadata(&mut file)?;
+    assert_eq!(metadata.schema, schema);
+    assert_eq!(metadata.num_rows, chunk.len());
+    assert_eq!(metadata.description, None);
+
+    let read = read::read(&mut file, &metadata, None)?;
+    assert_eq!(read, chunk);
+    Ok(())
+}
+
+fn round_trip_array(array: Arc<dyn Array>) -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    round_trip(schema, Chunk::new(vec![array]))
+}
+
+#[test]
+fn primitive() -> Result<()> {
+    round_trip_array(Arc::new(Int8Array::from([Some(1), None, Some(-3)])))?;
+    round_trip_array(Arc::new(Int16Array::from([Some(1), None, Some(-3)])))?;
+    round_trip_array(Arc::new(Int32Array::from([Some(1), None, Some(-3)])))?;
+    round_trip_array(Arc::new(Int64Array::from([Some(1), None, Some(-3)])))?;
+    round_trip_array(Arc::new(UInt8Array::from([Some(1), None, Some(3)])))?;
+    round_trip_array(Arc::new(UInt16Array::from([Some(1), None, Some(3)])))?;
+    round_trip_array(Arc::new(UInt32Array::from([Some(1), None, Some(3)])))?;
+    round_trip_array(Arc::new(UInt64Array::from([Some(1), None, Some(3)])))?;
+    round_trip_array(Arc::new(Float32Array::from([Some(1.5), None, Some(-3.0)])))?;
+    round_trip_array(Arc::new(Float64Array::from_slice([1.5, 2.0, -3.0])))
+}
+
+#[test]
+fn boolean() -> Result<()> {
+    round_trip_array(Arc::new(BooleanArray::from([
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+    ])))?;
+    round_trip_array(Arc::new(BooleanArray::from_slice([true; 20]).slice(3, 11)))
+}
+
+#[test]
+fn utf8_and_binary() -> Result<()> {
+    let data = [Some("a"), None, Some(""), Some("ççç"), Some("hello")];
+    round_trip_array(Arc::new(Utf8Array::<i32>::from(data)))?;
+    round_trip_array(Arc::new(Utf8Array::<i64>::from(data)))?;
+    round_trip_array(Arc::new(BinaryArray::<i32>::from(
+        data.map(|x| x.map(|x| x.as_bytes())),
+    )))?;
+    round_trip_array(Arc::new(BinaryArray::<i64>::from(
+        data.map(|x| x.map(|x| x.as_bytes())),
+    )))
+}
+
+#[test]
+fn sliced() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("aa"), None, Some("bbb"), Some("c"), None]);
+    round_trip_array(Arc::new(array.slice(1, 3)))?;
+    round_trip_array(Arc::new(array.slice(2, 2)))?;
+
+    let array = Int32Array::from([
+        Some(1),
+        None,
+        Some(3),
+        Some(4),
+        None,
+        Some(6),
+        None,
+        None,
+        Some(9),
+    ]);
+    round_trip_array(Arc::new(array.slice(3, 6)))
+}
+
+#[test]
+fn temporal() -> Result<()> {
+    let data = [Some(1), None, Some(3)];
+    round_trip_array(Arc::new(Int32Array::from(data).to(DataType::Date32)))?;
+    round_trip_array(Arc::new(
+        Int32Array::from(data).to(DataType::Time32(TimeUnit::Millisecond)),
+    ))?;
+    let data = [Some(1i64), None, Some(3)];
+    round_trip_array(Arc::new(
+        Int64Array::from(data).to(DataType::Time64(TimeUnit::Nanosecond)),
+    ))?;
+    round_trip_array(Arc::new(
+        Int64Array::from(data).to(DataType::Timestamp(TimeUnit::Second, None)),
+    ))?;
+    round_trip_array(Arc::new(Int64Array::from(data).to(DataType::Timestamp(
+        TimeUnit::Microsecond,
+        Some("+01:00".to_string()),
+    ))))
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"]));
+    let keys = Int8Array::from([Some(2), None, Some(0), Some(2)]);
+    round_trip_array(Arc::new(DictionaryArray::<i8>::from_data(keys, values)))?;
+
+    let values = Arc::new(Int64Array::from_slice([10, 20]));
+    let keys = UInt16Array::from_slice([1, 1, 0]);
+    round_trip_array(Arc::new(DictionaryArray::<u16>::from_data(keys, values)))
+}
+
+#[test]
+fn many_columns_and_projection() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Boolean, true),
+    ]);
+    let a = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let b = Arc::new(Utf8Array::<i32>::from([Some("a"), None, Some("c")])) as Arc<dyn Array>;
+    let c = Arc::new(BooleanArray::from_slice([true, false, true])) as Arc<dyn Array>;
+    let chunk = Chunk::new(vec![a.clone(), b, c.clone()]);
+    round_trip(schema.clone(), chunk.clone())?;
+
+    let mut file = Cursor::new(vec![]);
+    write::write(&mut file, &schema, &chunk)?;
+    let metadata = read::read_metadata(&mut file)?;
+    let read = read::read(&mut file, &metadata, Some(&[2, 0]))?;
+    assert_eq!(read, Chunk::new(vec![c, a]));
+
+    assert!(read::read(&mut file, &metadata, Some(&[3])).is_err());
+    Ok(())
+}
+
+#[test]
+fn empty() -> Result<()> {
+    round_trip(Schema::from(vec![]), Chunk::new(vec![]))?;
+    round_trip_array(Arc::new(Utf8Array::<i32>::new_empty(DataType::Utf8)))
+}
+
+#[test]
+fn unsupported() {
+    let array =
+        Arc::new(Int128Array::from_slice([1]).to(DataType::Decimal(5, 2))) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let mut file = Cursor::new(vec![]);
+    assert!(write::write(&mut file, &schema, &Chunk::new(vec![array])).is_err());
+}
+
+#[test]
+fn invalid() -> Result<()> {
+    assert!(read::read_metadata(&mut Cursor::new(b"FEA1")).is_err());
+    assert!(read::read_metadata(&mut Cursor::new(b"ARROW1\0\0\0\0\0\0\0\0")).is_err());
+
+    let array = Arc::new(Int32Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)]);
+    let mut file = vec![];
+    write::write(&mut file, &schema, &Chunk::new(vec![array]))?;
+
+    // truncating the metadata
+    let length = file.len();
+    file[length - 8..length - 4].copy_from_slice(&(length as u32).to_le_bytes());
+    assert!(read::read_metadata(&mut Cursor::new(file)).is_err());
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::flight::*;
+use arrow2::io::ipc::write::{default_ipc_fields, WriteOptions};
+
+fn dictionary(values: &[Option<&str>]) -> Arc<dyn Array> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend(values.iter().copied()).unwrap();
+    let array: DictionaryArray<i32> = array.into();
+    Arc::new(array)
+}
+
+fn chunks() -> Vec<Chunk<Arc<dyn Array>>> {
+    vec![
+        Chunk::new(vec![
+            Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>,
+            dictionary(&[Some("a"), Some("b")]),
+        ]),
+        Chunk::new(vec![
+            Arc::new(Int32Array::from([Some(3), Some(4), None])) as Arc<dyn Array>,
+            dictionary(&[Some("a"), Some("b"), Some("c")]),
+        ]),
+    ]
+}
+
+fn schema() -> Schema {
+    let chunk = &chunks()[0];
+    Schema::from(vec![
+        Field::new("int", chunk.arrays()[0].data_type().clone(), true),
+        Field::new("dict", chunk.arrays()[1].data_type().clone(), true),
+    ])
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let schema = schema();
+    let fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions { compression: None };
+
+    let mut encoder = FlightEncoder::new(fields, options);
+    let mut messages = vec![encoder.encode_schema(&schema)];
+    for (i, chunk) in chunks().iter().enumerate() {
+        messages.extend(encoder.encode(chunk, vec![i as u8])?);
+    }
+    // schema, dictionary, batch, delta dictionary, batch
+    assert_eq!(messages.len(), 5);
+
+    let mut decoder = FlightDecoder::try_new(&messages[0])?;
+    assert_eq!(decoder.schema(), &schema);
+    let mut result = vec![];
+    for message in &messages[1..] {
+        if let Some((chunk, app_metadata)) = decoder.decode(message)? {
+            result.push((chunk, app_metadata));
+        }
+    }
+
+    let expected = chunks()
+        .into_iter()
+        .enumerate()
+        .map(|(i, chunk)| (chunk, vec![i as u8]))
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+    Ok(())
+}
+
+#[test]
+fn serialize_deserialize() -> Result<()> {
+    let schema = schema();
+    let fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions { compression: None };
+    let chunk = chunks().pop().unwrap();
+
+    let (schema_from_flight, ipc_schema) =
+        deserialize_schemas(&serialize_schema(&schema, &fields).data_header)?;
+    assert_eq!(schema_from_flight, schema);
+
+    let (dictionaries, batch) = serialize_batch(&chunk, &fields, &options);
+    let mut dictionaries_read = Default::default();
+    for dictionary in &dictionaries {
+        assert!(deserialize_message(
+            dictionary,
+            &schema.fields,
+            &ipc_schema,
+            &mut dictionaries_read
+        )?
+        .is_none());
+    }
+    let result = deserialize_batch(&batch, &schema.fields, &ipc_schema, &dictionaries_read)?;
+    assert_eq!(result, chunk);
+
+    // a record batch is not a dictionary batch
+    assert!(
+        deserialize_dictionary(&batch, &schema.fields, &ipc_schema, &mut dictionaries_read)
+            .is_err()
+    );
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use std::fs::File;
+use std::io::{Cursor, Read};
+
+use arrow2::error::Result;
+use arrow2::io::ipc::convert::{file_to_stream, stream_to_file};
+use arrow2::io::ipc::read::{read_file_metadata, read_stream_metadata, FileReader, StreamReader};
+
+use crate::io::ipc::common::read_gzip_json;
+
+fn read_integration_file(version: &str, file_name: &str, extension: &str) -> Vec<u8> {
+    let testdata = crate::test_util::arrow_test_data();
+    let mut file = File::open(format!(
+        "{}/arrow-ipc-stream/integration/{}/{}.{}",
+        testdata, version, file_name, extension
+    ))
+    .unwrap();
+    let mut data = vec![];
+    file.read_to_end(&mut data).unwrap();
+    data
+}
+
+fn test_stream_to_file(version: &str, file_name: &str) -> Result<()> {
+    let stream = read_integration_file(version, file_name, "stream");
+
+    let mut file = vec![];
+    stream_to_file(&mut Cursor::new(stream), &mut file)?;
+
+    let mut reader = Cursor::new(file);
+    let metadata = read_file_metadata(&mut reader)?;
+    let schema = metadata.schema.clone();
+    let ipc_fields = metadata.ipc_schema.fields.clone();
+    let batches = FileReader::new(reader, metadata, None).collect::<Result<Vec<_>>>()?;
+
+    let (expected_schema, expected_ipc_fields, expected_batches) =
+        read_gzip_json(version, file_name)?;
+
+    assert_eq!(schema, expected_schema);
+    assert_eq!(ipc_fields, expected_ipc_fields);
+    assert_eq!(batches, expected_batches);
+    Ok(())
+}
+
+fn test_file_to_stream(version: &str, file_name: &str) -> Result<()> {
+    let file = read_integration_file(version, file_name, "arrow_file");
+
+    let mut stream = vec![];
+    file_to_stream(&mut Cursor::new(file), &mut stream)?;
+
+    let mut reader = Cursor::new(stream);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let schema = metadata.schema.clone();
+    let ipc_fields = metadata.ipc_schema.fields.clone();
+    let batches = StreamReader::new(reader, metadata)
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let (expected_schema, expected_ipc_fields, expected_batches) =
+        read_gzip_json(version, file_name)?;
+
+    assert_eq!(schema, expected_schema);
+    assert_eq!(ipc_fields, expected_ipc_fields);
+    assert_eq!(batches, expected_batches);
+    Ok(())
+}
+
+#[test]
+fn stream_to_file_primitive() -> Result<()> {
+    test_stream_to_file("1.0.0-littleendian", "generated_primitive")
+}
+
+#[test]
+fn stream_to_file_dictionary() -> Result<()> {
+    test_stream_to_file("1.0.0-littleendian", "generated_dictionary")
+}
+
+#[test]
+fn stream_to_file_nested() -> Result<()> {
+    test_stream_to_file("1.0.0-littleendian", "generated_nested")
+}
+
+#[test]
+fn file_to_stream_primitive() -> Result<()> {
+    test_file_to_stream("1.0.0-littleendian", "generated_primitive")
+}
+
+#[test]
+fn file_to_stream_dictionary() -> Result<()> {
+    test_file_to_stream("1.0.0-littleendian", "generated_dictionary")
+}
+
+#[test]
+fn file_to_stream_nested() -> Result<()> {
+    test_file_to_stream("1.0.0-littleendian", "generated_nested")
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let stream = read_integration_file("1.0.0-littleendian", "generated_dictionary", "stream");
+
+    let mut file = vec![];
+    stream_to_file(&mut Cursor::new(stream), &mut file)?;
+    let mut result = vec![];
+    file_to_stream(&mut Cursor::new(file), &mut result)?;
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let batches = StreamReader::new(reader, metadata)
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+
+    let (_, _, expected_batches) = read_gzip_json("1.0.0-littleendian", "generated_dictionary")?;
+    assert_eq!(batches, expected_batches);
+    Ok(())
+}
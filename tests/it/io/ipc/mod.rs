@@ -1,4 +1,5 @@
 mod common;
+mod convert;
 mod read;
 mod write;
 
@@ -6,3 +7,6 @@ pub use common::read_gzip_json;
 
 #[cfg(feature = "io_ipc_write_async")]
 mod write_async;
+
+#[cfg(feature = "io_ipc_read_async")]
+mod read_stream_async;
@@ -2,6 +2,7 @@ use std::fs::File;
 
 use arrow2::error::Result;
 use arrow2::io::ipc::read::*;
+use arrow2::io::range::{FnRangeReader, RangeReader, ReadableFile};
 
 use super::super::common::read_gzip_json;
 
@@ -182,3 +183,111 @@ fn read_projected() -> Result<()> {
     test_projection("1.0.0-littleendian", "generated_dictionary", 2)?;
     test_projection("1.0.0-littleendian", "generated_nested", 0)
 }
+
+#[test]
+fn read_range_reader() -> Result<()> {
+    let testdata = crate::test_util::arrow_test_data();
+    let mut file = File::open(format!(
+        "{}/arrow-ipc-stream/integration/1.0.0-littleendian/generated_primitive.arrow_file",
+        testdata
+    ))?;
+    let (schema, _, batches) = read_gzip_json("1.0.0-littleendian", "generated_primitive")?;
+
+    // a source that only supports reading ranges, e.g. an object store
+    let length = file.metadata()?.len();
+    let mut requests = 0;
+    let source = FnRangeReader::new(length, |offset, buf: &mut [u8]| {
+        requests += 1;
+        file.read_range(offset, buf)
+    });
+    let mut reader = ReadableFile::new(source);
+
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, None);
+    assert_eq!(&schema, reader.schema());
+
+    batches.iter().zip(reader).try_for_each(|(lhs, rhs)| {
+        assert_eq!(lhs, &rhs?);
+        Result::Ok(())
+    })?;
+    assert!(requests > 0);
+    Ok(())
+}
+
+/// The bytes of a file, aligned to 64 bits as a memory-mapped file would be.
+struct Aligned(Vec<u64>, usize);
+
+impl AsRef<[u8]> for Aligned {
+    fn as_ref(&self) -> &[u8] {
+        &bytemuck::cast_slice(&self.0)[..self.1]
+    }
+}
+
+fn test_mmap(version: &str, file_name: &str) -> Result<()> {
+    let testdata = crate::test_util::arrow_test_data();
+    let bytes = std::fs::read(format!(
+        "{}/arrow-ipc-stream/integration/{}/{}.arrow_file",
+        testdata, version, file_name
+    ))?;
+    let mut aligned = vec![0u64; (bytes.len() + 7) / 8];
+    bytemuck::cast_slice_mut(&mut aligned)[..bytes.len()].copy_from_slice(&bytes);
+    let data = std::sync::Arc::new(Aligned(aligned, bytes.len()));
+
+    let (schema, _, batches) = read_gzip_json(version, file_name)?;
+
+    let metadata = mmap::mmap_file_metadata(&data)?;
+    assert_eq!(&schema, &metadata.schema);
+    assert_eq!(metadata.num_batches(), batches.len());
+
+    for (block, expected) in batches.iter().enumerate() {
+        let chunk = mmap::mmap_batch(&data, &metadata, None, block)?;
+        assert_eq!(expected, &chunk);
+    }
+    assert!(mmap::mmap_batch(&data, &metadata, None, batches.len()).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_mmap() -> Result<()> {
+    test_mmap("1.0.0-littleendian", "generated_primitive")?;
+    test_mmap("1.0.0-littleendian", "generated_dictionary")?;
+    test_mmap("1.0.0-littleendian", "generated_nested")?;
+    test_mmap("1.0.0-bigendian", "generated_primitive")
+}
+
+#[test]
+fn read_mmap_zero_copy() -> Result<()> {
+    use arrow2::array::{Array, PrimitiveArray};
+
+    let testdata = crate::test_util::arrow_test_data();
+    let bytes = std::fs::read(format!(
+        "{}/arrow-ipc-stream/integration/1.0.0-littleendian/generated_primitive.arrow_file",
+        testdata
+    ))?;
+    let mut aligned = vec![0u64; (bytes.len() + 7) / 8];
+    bytemuck::cast_slice_mut(&mut aligned)[..bytes.len()].copy_from_slice(&bytes);
+    let data = std::sync::Arc::new(Aligned(aligned, bytes.len()));
+    let range = {
+        let bytes: &[u8] = (*data).as_ref();
+        bytes.as_ptr_range()
+    };
+
+    let metadata = mmap::mmap_file_metadata(&data)?;
+    let chunk = mmap::mmap_batch(&data, &metadata, None, 0)?;
+    let (index, field) = metadata
+        .schema
+        .fields
+        .iter()
+        .enumerate()
+        .find(|(_, field)| field.data_type() == &arrow2::datatypes::DataType::Int32)
+        .unwrap();
+    let array = chunk.arrays()[index]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i32>>()
+        .unwrap();
+    assert!(!array.is_empty(), "{:?}", field);
+    // the values point to the memory of the file
+    let ptr = array.values().as_slice().as_ptr() as *const u8;
+    assert!(range.contains(&ptr));
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use futures::StreamExt;
+use tokio::fs::File;
+use tokio_util::compat::*;
+
+use arrow2::error::Result;
+use arrow2::io::ipc::read::stream_async::*;
+
+use crate::io::ipc::common::read_gzip_json;
+
+async fn test_file(version: &str, file_name: &str) -> Result<()> {
+    let testdata = crate::test_util::arrow_test_data();
+    let mut file = File::open(format!(
+        "{}/arrow-ipc-stream/integration/{}/{}.stream",
+        testdata, version, file_name
+    ))
+    .await?
+    .compat();
+
+    let metadata = read_stream_metadata_async(&mut file).await?;
+    let reader = AsyncStreamReader::new(file, metadata);
+
+    // read expected JSON output
+    let (schema, ipc_fields, batches) = read_gzip_json(version, file_name)?;
+
+    assert_eq!(&schema, &reader.metadata().schema);
+    assert_eq!(&ipc_fields, &reader.metadata().ipc_schema.fields);
+
+    let result = reader
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, batches);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_async() -> Result<()> {
+    test_file("1.0.0-littleendian", "generated_primitive").await
+}
+
+#[tokio::test]
+async fn read_async_dictionary() -> Result<()> {
+    test_file("1.0.0-littleendian", "generated_dictionary").await
+}
+
+#[tokio::test]
+async fn read_async_nested() -> Result<()> {
+    test_file("1.0.0-littleendian", "generated_nested").await
+}
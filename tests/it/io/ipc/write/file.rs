@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{Field, Schema};
+use arrow2::datatypes::{DataType, Field, Metadata, Schema, TimeUnit};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::{read_file_metadata, FileReader};
 use arrow2::io::ipc::{write::*, IpcField};
@@ -349,6 +349,47 @@ fn write_sliced_utf8() -> Result<()> {
     round_trip(columns, schema, None, Some(Compression::ZSTD))
 }
 
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_lz4() -> Result<()> {
+    // compressible values and values too small to benefit from compression, that are written
+    // uncompressed
+    let compressible = Arc::new(Int64Array::from_vec(vec![1; 1000])) as Arc<dyn Array>;
+    let small = Utf8Array::<i32>::from([Some("a"), None]).slice(0, 1);
+    let schema = Schema::from(vec![
+        Field::new("a", compressible.data_type().clone(), false),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let columns = Chunk::try_new(vec![
+        compressible.slice(0, 1).into(),
+        Arc::new(small) as Arc<dyn Array>,
+    ])?;
+    round_trip(columns, schema, None, Some(Compression::LZ4))?;
+
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        compressible.data_type().clone(),
+        false,
+    )]);
+    let columns = Chunk::try_new(vec![compressible])?;
+    round_trip(columns, schema, None, Some(Compression::LZ4))
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_compressed_empty() -> Result<()> {
+    let array = Arc::new(Utf8Array::<i32>::new_empty(DataType::Utf8)) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![array])?;
+    round_trip(
+        columns.clone(),
+        schema.clone(),
+        None,
+        Some(Compression::LZ4),
+    )?;
+    round_trip(columns, schema, None, Some(Compression::ZSTD))
+}
+
 #[test]
 fn write_sliced_list() -> Result<()> {
     let data = vec![
@@ -365,3 +406,109 @@ fn write_sliced_list() -> Result<()> {
     let columns = Chunk::try_new(vec![array])?;
     round_trip(columns, schema, None, None)
 }
+
+#[test]
+fn write_custom_metadata() -> Result<()> {
+    let metadata = |key: &str, value: &str| {
+        let mut metadata = Metadata::new();
+        metadata.insert(key.to_string(), value.to_string());
+        metadata
+    };
+
+    let extension = DataType::Extension(
+        "date16".to_string(),
+        Box::new(DataType::UInt16),
+        Some("days".to_string()),
+    );
+    let a = UInt16Array::from_slice([1, 2]).to(extension.clone());
+
+    let item = Field::new("item", DataType::Int32, true).with_metadata(metadata("child", "1"));
+    let list = DataType::List(Box::new(item));
+    let mut b = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new_from(
+        MutablePrimitiveArray::new(),
+        list.clone(),
+        0,
+    );
+    b.try_extend(vec![Some(vec![Some(1)]), None])?;
+
+    let schema = Schema::from(vec![
+        Field::new("a", extension, false).with_metadata(metadata("unit", "day")),
+        Field::new("b", list, true).with_metadata(metadata("producer", "arrow2")),
+    ])
+    .with_metadata(metadata("version", "1"));
+    let columns = Chunk::try_new(vec![Arc::new(a) as Arc<dyn Array>, b.into_arc()])?;
+    round_trip(columns, schema, None, None)
+}
+
+#[test]
+fn read_timestamp_time_unit() -> Result<()> {
+    let array =
+        Int64Array::from([Some(1), None, Some(3)]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let schema = Schema::from(vec![Field::new("a", array.data_type().clone(), true)]);
+    let columns = Chunk::try_new(vec![Arc::new(array) as Arc<dyn Array>])?;
+    let data = write_(&[columns], &schema, None, None)?;
+
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let mut reader = FileReader::new(reader, metadata, None);
+    reader.set_timestamp_time_unit(TimeUnit::Millisecond);
+
+    let data_type = DataType::Timestamp(TimeUnit::Millisecond, None);
+    assert_eq!(reader.schema().fields[0].data_type(), &data_type);
+    let expected = Int64Array::from([Some(1_000), None, Some(3_000)]).to(data_type);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        batches,
+        vec![Chunk::try_new(vec![Arc::new(expected) as Arc<dyn Array>])?]
+    );
+    Ok(())
+}
+
+fn dictionary(keys: &[i32], values: &[&str]) -> Chunk<Arc<dyn Array>> {
+    let array = DictionaryArray::<i32>::from_data(
+        Int32Array::from_slice(keys),
+        Arc::new(Utf8Array::<i32>::from_slice(values)),
+    );
+    Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+}
+
+#[test]
+fn write_dictionary_delta() -> Result<()> {
+    let batches = vec![
+        dictionary(&[0, 1], &["a", "b"]),
+        // the dictionary grows: only "c" is written, as a delta
+        dictionary(&[2, 0], &["a", "b", "c"]),
+        dictionary(&[1], &["a", "b", "c"]),
+    ];
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        batches[0].arrays()[0].data_type().clone(),
+        true,
+    )]);
+
+    let data = write_(&batches, &schema, None, None)?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_file_metadata(&mut reader)?;
+    let reader = FileReader::new(reader, metadata, None);
+
+    let result = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, batches);
+    Ok(())
+}
+
+#[test]
+fn write_dictionary_replacement() -> Result<()> {
+    let batches = vec![
+        dictionary(&[0, 1], &["a", "b"]),
+        dictionary(&[0, 1], &["b", "a"]),
+    ];
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        batches[0].arrays()[0].data_type().clone(),
+        true,
+    )]);
+
+    // files do not support replacing dictionaries
+    assert!(write_(&batches, &schema, None, None).is_err());
+    Ok(())
+}
@@ -1,13 +1,13 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
-use arrow2::array::Array;
+use arrow2::array::*;
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::Schema;
+use arrow2::datatypes::{Field, Schema};
 use arrow2::error::Result;
 use arrow2::io::ipc::read::read_stream_metadata;
 use arrow2::io::ipc::read::StreamReader;
-use arrow2::io::ipc::write::{StreamWriter, WriteOptions};
+use arrow2::io::ipc::write::{Compression, StreamWriter, WriteOptions};
 use arrow2::io::ipc::IpcField;
 
 use crate::io::ipc::common::read_arrow_stream;
@@ -17,10 +17,11 @@ fn write_(
     schema: &Schema,
     ipc_fields: Option<Vec<IpcField>>,
     batches: &[Chunk<Arc<dyn Array>>],
+    compression: Option<Compression>,
 ) -> Vec<u8> {
     let mut result = vec![];
 
-    let options = WriteOptions { compression: None };
+    let options = WriteOptions { compression };
     let mut writer = StreamWriter::new(&mut result, options);
     writer.start(schema, ipc_fields).unwrap();
     for batch in batches {
@@ -31,9 +32,13 @@ fn write_(
 }
 
 fn test_file(version: &str, file_name: &str) {
+    test_file_compressed(version, file_name, None)
+}
+
+fn test_file_compressed(version: &str, file_name: &str, compression: Option<Compression>) {
     let (schema, ipc_fields, batches) = read_arrow_stream(version, file_name);
 
-    let result = write_(&schema, Some(ipc_fields), &batches);
+    let result = write_(&schema, Some(ipc_fields), &batches, compression);
 
     let mut reader = Cursor::new(result);
     let metadata = read_stream_metadata(&mut reader).unwrap();
@@ -147,3 +152,55 @@ fn write_100_custom_metadata() {
 fn write_100_decimal() {
     test_file("1.0.0-littleendian", "generated_decimal");
 }
+
+#[test]
+#[cfg_attr(miri, ignore)] // compression uses FFI, which miri does not support
+fn write_100_compressed_primitive() {
+    test_file_compressed(
+        "1.0.0-littleendian",
+        "generated_primitive",
+        Some(Compression::LZ4),
+    );
+    test_file_compressed(
+        "1.0.0-littleendian",
+        "generated_primitive",
+        Some(Compression::ZSTD),
+    );
+}
+
+#[test]
+fn write_dictionary_delta_and_replacement() -> Result<()> {
+    let dictionary = |keys: &[i32], values: &[&str]| {
+        let array = DictionaryArray::<i32>::from_data(
+            Int32Array::from_slice(keys),
+            Arc::new(Utf8Array::<i32>::from_slice(values)),
+        );
+        Chunk::new(vec![Arc::new(array) as Arc<dyn Array>])
+    };
+    let batches = vec![
+        dictionary(&[0, 1], &["a", "b"]),
+        // a delta
+        dictionary(&[2, 0], &["a", "b", "c"]),
+        // a replacement
+        dictionary(&[0, 1], &["c", "a"]),
+        // a delta of the replacement
+        dictionary(&[2, 1], &["c", "a", "d"]),
+    ];
+    let schema = Schema::from(vec![Field::new(
+        "a",
+        batches[0].arrays()[0].data_type().clone(),
+        true,
+    )]);
+
+    let result = write_(&schema, None, &batches, None);
+
+    let mut reader = Cursor::new(result);
+    let metadata = read_stream_metadata(&mut reader)?;
+    let reader = StreamReader::new(reader, metadata);
+
+    let result = reader
+        .map(|x| x.map(|x| x.unwrap()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, batches);
+    Ok(())
+}
@@ -1,4 +1,5 @@
 mod read;
+mod schema;
 mod write;
 
 use std::io::Cursor;
@@ -226,13 +227,17 @@ fn case_struct() -> (String, Vec<Field>, Vec<Box<dyn Array>>) {
 
     // build expected output
     let d = Utf8Array::<i32>::from(&vec![Some("text"), None, Some("text"), None]);
-    let c = StructArray::from_data(DataType::Struct(vec![d_field]), vec![Arc::new(d)], None);
+    let c = StructArray::from_data(
+        DataType::Struct(vec![d_field]),
+        vec![Arc::new(d)],
+        Some(Bitmap::from([true, false, true, false])),
+    );
 
     let b = BooleanArray::from(vec![Some(true), Some(false), Some(true), None]);
     let expected = StructArray::from_data(
         DataType::Struct(vec![Field::new("b", DataType::Boolean, true), c_field]),
         vec![Arc::new(b), Arc::new(c)],
-        None,
+        Some(Bitmap::from([true, true, true, false])),
     );
 
     (data, fields, vec![Box::new(expected) as Box<dyn Array>])
@@ -268,7 +273,11 @@ fn case_nested_list() -> (String, Vec<Field>, Vec<Box<dyn Array>>) {
         None,
     ]);
 
-    let c = StructArray::from_data(DataType::Struct(vec![d_field]), vec![Arc::new(d)], None);
+    let c = StructArray::from_data(
+        DataType::Struct(vec![d_field]),
+        vec![Arc::new(d)],
+        Some(Bitmap::from([true, true, false, true, true, true])),
+    );
 
     let b = BooleanArray::from(vec![
         Some(true),
@@ -197,7 +197,7 @@ fn read_json() -> Result<()> {
 
     let data_type = read::infer_rows(&values)?;
 
-    let result = read::deserialize_json(&values, data_type);
+    let result = read::deserialize_json(&values, data_type)?;
 
     let expected = StructArray::from_data(
         DataType::Struct(vec![Field::new("a", DataType::Int64, true)]),
@@ -209,3 +209,71 @@ fn read_json() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn read_fixed_size_list_and_decimal() -> Result<()> {
+    let data = r#"{"a": [1, 2], "b": 1.25}
+    {"a": [3], "b": "-2.5"}
+    {"a": null, "b": null}"#
+        .to_string();
+    let item = Field::new("item", DataType::Int32, true);
+    let fields = vec![
+        Field::new("a", DataType::FixedSizeList(Box::new(item), 2), true),
+        Field::new("b", DataType::Decimal(5, 2), true),
+    ];
+
+    let batch = read_batch(data, &fields)?;
+
+    let expected = FixedSizeListArray::from_data(
+        fields[0].data_type().clone(),
+        Arc::new(Int32Array::from([Some(1), Some(2), None, None, None, None])),
+        Some(Bitmap::from([true, false, false])),
+    );
+    assert_eq!(batch.arrays()[0].as_ref(), &expected as &dyn Array);
+
+    let expected = Int128Array::from([Some(125), Some(-250), None]).to(DataType::Decimal(5, 2));
+    assert_eq!(batch.arrays()[1].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn read_unsupported() {
+    let data = r#"{"a": 1}"#.to_string();
+    let fields = vec![Field::new(
+        "a",
+        DataType::Interval(IntervalUnit::DayTime),
+        true,
+    )];
+    assert!(read_batch(data, &fields).is_err());
+}
+
+#[test]
+fn read_in_chunks() -> Result<()> {
+    let data = "{\"a\": 1, \"b\": \"x\"}\n\n{\"a\": 2}\n{\"a\": null, \"b\": \"z\"}\n";
+    let fields = vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ];
+
+    let chunks =
+        read::read_chunks(Cursor::new(data), fields.clone(), 2).collect::<Result<Vec<_>>>()?;
+
+    let lengths = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+    assert_eq!(lengths, vec![2, 1]);
+    let expected = Int64Array::from([None]);
+    assert_eq!(chunks[1].arrays()[0].as_ref(), &expected as &dyn Array);
+    let expected = Utf8Array::<i32>::from([Some("x"), None]);
+    assert_eq!(chunks[0].arrays()[1].as_ref(), &expected as &dyn Array);
+
+    // exactly one chunk
+    let chunks =
+        read::read_chunks(Cursor::new(data), fields.clone(), 3).collect::<Result<Vec<_>>>()?;
+    assert_eq!(chunks.len(), 1);
+
+    // the iterator stops after an error
+    let data = "{\"a\": 1}\nnot json\n{\"a\": 2}\n";
+    let mut chunks = read::read_chunks(Cursor::new(data), fields, 2);
+    assert!(chunks.next().unwrap().is_err());
+    assert!(chunks.next().is_none());
+    Ok(())
+}
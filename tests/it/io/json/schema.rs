@@ -0,0 +1,219 @@
+use serde_json::json;
+
+use arrow2::datatypes::*;
+use arrow2::error::Result;
+use arrow2::io::json::schema::*;
+
+fn round_trip(schema: Schema) -> Result<()> {
+    let json = serialize_schema(&schema);
+    let result = deserialize_schema(&json)?;
+    assert_eq!(result, schema);
+    // through a string, as stored in e.g. a config file
+    let result = deserialize_schema(&serde_json::from_str(&json.to_string())?)?;
+    assert_eq!(result, schema);
+    Ok(())
+}
+
+#[test]
+fn primitives() -> Result<()> {
+    let data_types = vec![
+        DataType::Null,
+        DataType::Boolean,
+        DataType::Int8,
+        DataType::UInt64,
+        DataType::Float16,
+        DataType::Float64,
+        DataType::Utf8,
+        DataType::LargeBinary,
+        DataType::FixedSizeBinary(3),
+        DataType::Decimal(10, 2),
+        DataType::Date32,
+        DataType::Date64,
+        DataType::Time32(TimeUnit::Millisecond),
+        DataType::Time64(TimeUnit::Nanosecond),
+        DataType::Timestamp(TimeUnit::Second, None),
+        DataType::Timestamp(TimeUnit::Microsecond, Some("+01:00".to_string())),
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        DataType::Duration(TimeUnit::Millisecond),
+    ];
+    let fields = data_types
+        .into_iter()
+        .enumerate()
+        .map(|(i, data_type)| Field::new(format!("c{}", i), data_type, i % 2 == 0))
+        .collect::<Vec<_>>();
+    round_trip(Schema::from(fields))
+}
+
+#[test]
+fn nested() -> Result<()> {
+    let item = Field::new("item", DataType::Int32, true);
+    let entries = DataType::Struct(vec![
+        Field::new("key", DataType::Utf8, false),
+        Field::new("value", DataType::Int32, true),
+    ]);
+    let fields = vec![
+        Field::new("list", DataType::List(Box::new(item.clone())), true),
+        Field::new("large", DataType::LargeList(Box::new(item.clone())), true),
+        Field::new("fixed", DataType::FixedSizeList(Box::new(item), 2), true),
+        Field::new(
+            "struct",
+            DataType::Struct(vec![
+                Field::new("a", DataType::Boolean, true),
+                Field::new("b", DataType::Float32, false),
+            ]),
+            false,
+        ),
+        Field::new(
+            "map",
+            DataType::Map(Box::new(Field::new("entries", entries, false)), true),
+            true,
+        ),
+        Field::new(
+            "sparse",
+            DataType::Union(
+                vec![
+                    Field::new("a", DataType::Int32, true),
+                    Field::new("b", DataType::Utf8, true),
+                ],
+                None,
+                UnionMode::Sparse,
+            ),
+            true,
+        ),
+        Field::new(
+            "dense",
+            DataType::Union(
+                vec![
+                    Field::new("a", DataType::Int32, true),
+                    Field::new("b", DataType::Utf8, true),
+                ],
+                Some(vec![5, 7]),
+                UnionMode::Dense,
+            ),
+            true,
+        ),
+    ];
+    round_trip(Schema::from(fields))
+}
+
+#[test]
+fn dictionary_extension_and_metadata() -> Result<()> {
+    let mut metadata = Metadata::new();
+    metadata.insert("key".to_string(), "value".to_string());
+    let extension = DataType::Extension(
+        "uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(16)),
+        Some("{}".to_string()),
+    );
+    let fields = vec![
+        Field::new(
+            "dict",
+            DataType::Dictionary(IntegerType::Int16, Box::new(DataType::Utf8), true),
+            true,
+        )
+        .with_metadata(metadata.clone()),
+        Field::new(
+            "nested_dict",
+            DataType::List(Box::new(Field::new(
+                "item",
+                DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Int64), false),
+                true,
+            ))),
+            true,
+        ),
+        Field::new("extension", extension.clone(), false),
+        Field::new(
+            "dict_extension",
+            DataType::Dictionary(IntegerType::Int32, Box::new(extension), false),
+            false,
+        ),
+    ];
+    round_trip(Schema::from(fields).with_metadata(metadata))
+}
+
+#[test]
+fn canonical() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new(
+            "a",
+            DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+            true,
+        ),
+        Field::new(
+            "b",
+            DataType::List(Box::new(Field::new(
+                "item",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".to_string())),
+                true,
+            ))),
+            false,
+        ),
+    ]);
+    let expected = json!({
+        "fields": [
+            {
+                "name": "a",
+                "nullable": true,
+                "type": {"name": "utf8"},
+                "children": [],
+                "dictionary": {
+                    "id": 0,
+                    "indexType": {"name": "int", "bitWidth": 32, "isSigned": true},
+                    "isOrdered": false
+                }
+            },
+            {
+                "name": "b",
+                "nullable": false,
+                "type": {"name": "list"},
+                "children": [
+                    {
+                        "name": "item",
+                        "nullable": true,
+                        "type": {"name": "timestamp", "unit": "MILLISECOND", "timezone": "UTC"},
+                        "children": []
+                    }
+                ]
+            }
+        ]
+    });
+    assert_eq!(serialize_schema(&schema), expected);
+    assert_eq!(deserialize_schema(&expected)?, schema);
+    Ok(())
+}
+
+#[test]
+fn data_type() -> Result<()> {
+    let data_type = DataType::Time64(TimeUnit::Microsecond);
+    let json = serialize_data_type(&data_type);
+    assert_eq!(
+        json,
+        json!({"name": "time", "bitWidth": 64, "unit": "MICROSECOND"})
+    );
+    assert_eq!(deserialize_data_type(&json, vec![])?, data_type);
+
+    let field = Field::new("item", DataType::Utf8, true);
+    let data_type = DataType::List(Box::new(field.clone()));
+    let json = serialize_data_type(&data_type);
+    assert_eq!(deserialize_data_type(&json, vec![field])?, data_type);
+    Ok(())
+}
+
+#[test]
+fn invalid() {
+    assert!(deserialize_schema(&json!({"fields": 1})).is_err());
+    assert!(deserialize_field(&json!({"name": "a", "nullable": true})).is_err());
+    assert!(deserialize_field(&json!({
+        "name": "a",
+        "nullable": true,
+        "type": {"name": "list"},
+        "children": []
+    }))
+    .is_err());
+    assert!(deserialize_data_type(
+        &json!({"name": "int", "bitWidth": 7, "isSigned": true}),
+        vec![]
+    )
+    .is_err());
+    assert!(deserialize_data_type(&json!({"name": "unknown"}), vec![]).is_err());
+}
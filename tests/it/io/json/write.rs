@@ -4,7 +4,7 @@ use arrow2::{
     array::*,
     bitmap::Bitmap,
     buffer::Buffer,
-    datatypes::{DataType, Field},
+    datatypes::{DataType, Field, TimeUnit},
     error::Result,
 };
 
@@ -305,3 +305,158 @@ fn write_escaped_utf8() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn write_temporal() -> Result<()> {
+    let date32 = Int32Array::from([Some(18628), None]).to(DataType::Date32);
+    let time32 = Int32Array::from([Some(3661), None]).to(DataType::Time32(TimeUnit::Second));
+    let naive = Int64Array::from([Some(1_609_495_200_500), None])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let tz = Int64Array::from([Some(1_609_495_200), None]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+02:00".to_string()),
+    ));
+    let duration = Int64Array::from([Some(10), None]).to(DataType::Duration(TimeUnit::Second));
+
+    let batch = Chunk::try_new(vec![&date32 as &dyn Array, &time32, &naive, &tz, &duration])?;
+
+    let buf = write_batch(
+        batch,
+        vec!["d", "t", "ts", "tz", "du"]
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect(),
+        json_write::LineDelimited::default(),
+    )?;
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"d":"2021-01-01","t":"01:01:01","ts":"2021-01-01T10:00:00.500","tz":"2021-01-01T12:00:00+02:00","du":10}
+{"d":null,"t":null,"ts":null,"tz":null,"du":null}
+"#
+    );
+    Ok(())
+}
+
+#[test]
+fn write_decimal() -> Result<()> {
+    let a = Int128Array::from([Some(-1234), Some(5), None]).to(DataType::Decimal(5, 2));
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array])?;
+
+    let buf = write_batch(
+        batch,
+        vec!["c1".to_string()],
+        json_write::JsonArray::default(),
+    )?;
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"[{"c1":"-12.34"},{"c1":"0.05"},{"c1":null}]"#
+    );
+    Ok(())
+}
+
+#[test]
+fn write_dictionary() -> Result<()> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "b"]));
+    let keys = Int32Array::from([Some(1), None, Some(0), Some(1)]);
+    let a = DictionaryArray::<i32>::from_data(keys, values);
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array])?;
+
+    let buf = write_batch(
+        batch,
+        vec!["c1".to_string()],
+        json_write::LineDelimited::default(),
+    )?;
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"c1":"b"}
+{"c1":null}
+{"c1":"a"}
+{"c1":"b"}
+"#
+    );
+    Ok(())
+}
+
+#[test]
+fn write_fixed_size_list() -> Result<()> {
+    let values = Arc::new(Int32Array::from([
+        Some(1),
+        None,
+        Some(3),
+        Some(4),
+        Some(5),
+        Some(6),
+    ]));
+    let a = FixedSizeListArray::from_data(
+        FixedSizeListArray::default_datatype(DataType::Int32, 2),
+        values,
+        Some(Bitmap::from([true, false, true])),
+    );
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array])?;
+
+    let buf = write_batch(
+        batch,
+        vec!["c1".to_string()],
+        json_write::LineDelimited::default(),
+    )?;
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"c1":[1,null]}
+{"c1":null}
+{"c1":[5,6]}
+"#
+    );
+    Ok(())
+}
+
+#[test]
+fn write_sliced_list() -> Result<()> {
+    let data = vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![Some(3)]),
+        None,
+        Some(vec![Some(4), None]),
+    ];
+    let mut a = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    a.try_extend(data)?;
+    let a: ListArray<i32> = a.into();
+    let a = a.slice(1, 3);
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array])?;
+
+    let buf = write_batch(
+        batch,
+        vec!["c1".to_string()],
+        json_write::LineDelimited::default(),
+    )?;
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        r#"{"c1":[3]}
+{"c1":null}
+{"c1":[4,null]}
+"#
+    );
+    Ok(())
+}
+
+#[test]
+fn write_unsupported() {
+    let a = BinaryArray::<i32>::from_slice([b"a"]);
+
+    let batch = Chunk::try_new(vec![&a as &dyn Array]).unwrap();
+
+    assert!(write_batch(
+        batch,
+        vec!["c1".to_string()],
+        json_write::LineDelimited::default(),
+    )
+    .is_err());
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use arrow2::error::Result;
+use arrow2::io::json_integration::{read, write, ArrowJson};
+
+use super::ipc::read_gzip_json;
+
+fn round_trip(version: &str, file_name: &str) -> Result<()> {
+    let (schema, ipc_fields, batches) = read_gzip_json(version, file_name)?;
+
+    let names = schema.fields.iter().map(|f| &f.name).collect::<Vec<_>>();
+    let arrow_json = ArrowJson {
+        schema: write::serialize_schema(&schema, &ipc_fields),
+        batches: batches
+            .iter()
+            .map(|batch| write::serialize_chunk(batch, &names))
+            .collect(),
+        dictionaries: batches
+            .first()
+            .map(|batch| write::serialize_dictionaries(batch, &ipc_fields)),
+    };
+    let json = serde_json::to_string(&arrow_json)?;
+
+    let arrow_json: ArrowJson = serde_json::from_str(&json)?;
+    let (result_schema, result_ipc_fields) =
+        read::deserialize_schema(&serde_json::to_value(arrow_json.schema)?)?;
+    assert_eq!(result_schema, schema);
+    assert_eq!(result_ipc_fields, ipc_fields);
+
+    let dictionaries = arrow_json
+        .dictionaries
+        .unwrap_or_default()
+        .into_iter()
+        .map(|dictionary| (dictionary.id, dictionary))
+        .collect::<HashMap<_, _>>();
+    let result = arrow_json
+        .batches
+        .iter()
+        .map(|batch| read::deserialize_chunk(&schema, &ipc_fields, batch, &dictionaries))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(result, batches);
+    Ok(())
+}
+
+#[test]
+fn round_trip_primitive() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_primitive")
+}
+
+#[test]
+fn round_trip_datetime() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_datetime")
+}
+
+#[test]
+fn round_trip_interval() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_interval")
+}
+
+#[test]
+fn round_trip_decimal() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_decimal")
+}
+
+#[test]
+fn round_trip_nested() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_nested")
+}
+
+#[test]
+fn round_trip_map() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_map")
+}
+
+#[test]
+fn round_trip_union() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_union")
+}
+
+#[test]
+fn round_trip_dictionary() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_dictionary")
+}
+
+#[test]
+fn round_trip_nested_dictionary() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_nested_dictionary")
+}
+
+#[test]
+fn round_trip_extension() -> Result<()> {
+    round_trip("1.0.0-littleendian", "generated_extension")
+}
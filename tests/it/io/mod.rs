@@ -1,18 +1,35 @@
 #[cfg(feature = "io_print")]
 mod print;
 
+mod range;
+
 #[cfg(feature = "io_json")]
 mod json;
 
 #[cfg(feature = "io_ipc")]
 mod ipc;
 
+#[cfg(feature = "io_flight")]
+mod flight;
+
+#[cfg(feature = "io_json_integration")]
+mod json_integration;
+
 #[cfg(feature = "io_parquet")]
 mod parquet;
 
 #[cfg(feature = "io_avro")]
 mod avro;
 
+#[cfg(feature = "io_feather")]
+mod feather;
+
+#[cfg(feature = "io_serde")]
+mod serde;
+
+#[cfg(feature = "io_odbc")]
+mod odbc;
+
 #[cfg(any(
     feature = "io_csv_read",
     feature = "io_csv_write",
@@ -0,0 +1,73 @@
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field, TimeUnit};
+use arrow2::error::Result;
+use arrow2::io::odbc::{read, write};
+
+/// Serializes `array` to an ODBC buffer and deserializes it back, without a data source.
+fn round_trip(array: Box<dyn Array>, is_nullable: bool) -> Result<()> {
+    let field = Field::new("a", array.data_type().clone(), is_nullable);
+
+    let descriptions = write::infer_descriptions(&[field.clone()])?;
+    let mut buffer = write::buffer_from_description(descriptions, array.len());
+    buffer.set_num_rows(array.len());
+    write::serialize(array.as_ref(), &mut buffer.column_mut(0))?;
+
+    let result = read::deserialize(buffer.column(0), field.data_type);
+    assert_eq!(result.as_ref(), array.as_ref());
+    Ok(())
+}
+
+#[test]
+fn primitive() -> Result<()> {
+    round_trip(Box::new(Int32Array::from_slice([1, 2, 3])), false)?;
+    round_trip(Box::new(Int64Array::from([Some(1), None, Some(3)])), true)?;
+    round_trip(Box::new(Float64Array::from([Some(1.5), None])), true)
+}
+
+#[test]
+fn boolean() -> Result<()> {
+    round_trip(Box::new(BooleanArray::from_slice([true, false])), false)?;
+    round_trip(Box::new(BooleanArray::from([Some(true), None])), true)
+}
+
+#[test]
+fn utf8() -> Result<()> {
+    round_trip(
+        Box::new(Utf8Array::<i32>::from([Some("ab"), None, Some("abc")])),
+        true,
+    )
+}
+
+#[test]
+fn binary() -> Result<()> {
+    round_trip(
+        Box::new(BinaryArray::<i32>::from([
+            Some(b"ab".as_ref()),
+            None,
+            Some(b""),
+        ])),
+        true,
+    )
+}
+
+#[test]
+fn date_and_timestamp() -> Result<()> {
+    let array = Int32Array::from([Some(18_000), None]).to(DataType::Date32);
+    round_trip(Box::new(array), true)?;
+
+    let array = Int64Array::from([Some(1_600_000_000_123_456), None])
+        .to(DataType::Timestamp(TimeUnit::Microsecond, None));
+    round_trip(Box::new(array), true)
+}
+
+#[test]
+fn decimal() -> Result<()> {
+    let array = Int128Array::from([Some(-1234), None, Some(5)]).to(DataType::Decimal(5, 2));
+    round_trip(Box::new(array), true)
+}
+
+#[test]
+fn nulls_to_non_nullable() {
+    let array = Int32Array::from([Some(1), None]);
+    assert!(round_trip(Box::new(array), false).is_err());
+}
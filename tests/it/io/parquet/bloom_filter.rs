@@ -0,0 +1,157 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::array::{Array, BooleanArray, Int32Array, Utf8Array};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::Result;
+use arrow2::io::parquet::bloom_filter::*;
+use arrow2::io::parquet::read::{read_metadata, RecordReader};
+use arrow2::io::parquet::write::{Compression, Encoding, FileSink, Version, WriteOptions};
+use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+#[test]
+fn insert_and_check() {
+    let mut bitset = vec![0; 32];
+    let hash = hash_native(1i32);
+    assert!(!is_in_set(&bitset, hash));
+    insert(&mut bitset, hash);
+    assert!(is_in_set(&bitset, hash));
+}
+
+#[test]
+fn hash_integers_as_physical_type() {
+    // integers up to 32 bits are plain-encoded as parquet's Int32
+    let mut bitset = vec![0; 64];
+    let array = arrow2::array::UInt8Array::from_slice([1, 2]);
+    insert_array(&array, &mut bitset).unwrap();
+    assert!(is_in_set(&bitset, hash_native(1i32)));
+    assert!(is_in_set(&bitset, hash_native(2i32)));
+}
+
+#[test]
+fn might_contain_primitive() -> Result<()> {
+    let array = Int32Array::from([Some(1), None, Some(3)]);
+    let mut bitset = vec![0; optimal_num_bytes(array.len(), 0.01)];
+    insert_array(&array, &mut bitset)?;
+
+    assert!(might_contain(
+        &bitset,
+        &PrimitiveScalar::<i32>::from(Some(1))
+    )?);
+    assert!(might_contain(
+        &bitset,
+        &PrimitiveScalar::<i32>::from(Some(3))
+    )?);
+    assert!(!might_contain(
+        &bitset,
+        &PrimitiveScalar::<i32>::from(Some(2))
+    )?);
+    assert!(!might_contain(
+        &bitset,
+        &PrimitiveScalar::<i32>::from(None)
+    )?);
+    Ok(())
+}
+
+#[test]
+fn might_contain_utf8() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("hello"), Some("world")]);
+    let mut bitset = vec![0; optimal_num_bytes(array.len(), 0.01)];
+    insert_array(&array, &mut bitset)?;
+
+    assert!(might_contain(
+        &bitset,
+        &Utf8Scalar::<i32>::new(Some("hello"))
+    )?);
+    assert!(!might_contain(
+        &bitset,
+        &Utf8Scalar::<i32>::new(Some("arrow"))
+    )?);
+    Ok(())
+}
+
+#[test]
+fn optimal_num_bytes_bounds() {
+    assert_eq!(optimal_num_bytes(0, 0.01), 32);
+    assert!(optimal_num_bytes(1_000_000, 0.01).is_power_of_two());
+}
+
+fn write_with_bloom_filters(bloom_filters: BloomFilterOptions) -> Result<Vec<u8>> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Boolean, true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let mut sink = FileSink::try_new_with_bloom_filters(
+        Cursor::new(vec![]),
+        schema,
+        vec![Encoding::Plain; 3],
+        options,
+        2,
+        None,
+        bloom_filters,
+    )?;
+    for (a, b) in [([1, 2], ["a", "b"]), ([3, 4], ["c", "d"])] {
+        sink.write(Chunk::new(vec![
+            Arc::new(Int32Array::from_slice(a)) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(b)),
+            Arc::new(BooleanArray::from_slice([true, false])),
+        ]))?;
+    }
+    let (size, data) = sink.close()?;
+    let data = data.into_inner();
+    assert_eq!(size, data.len() as u64);
+    Ok(data)
+}
+
+#[test]
+fn write_and_read() -> Result<()> {
+    let data = write_with_bloom_filters(BloomFilterOptions {
+        fields: vec![1],
+        fpp: 0.01,
+    })?;
+    let mut reader = Cursor::new(data);
+    let metadata = read_metadata(&mut reader)?;
+    assert_eq!(metadata.row_groups.len(), 2);
+
+    let mut bitset = vec![];
+    for (row_group, (present, absent)) in metadata.row_groups.iter().zip([("a", "c"), ("d", "b")]) {
+        read(&row_group.columns()[1], &mut reader, &mut bitset)?;
+        assert!(might_contain(
+            &bitset,
+            &Utf8Scalar::<i32>::new(Some(present))
+        )?);
+        assert!(!might_contain(
+            &bitset,
+            &Utf8Scalar::<i32>::new(Some(absent))
+        )?);
+
+        // columns without bloom filters
+        read(&row_group.columns()[0], &mut reader, &mut bitset)?;
+        assert!(bitset.is_empty());
+    }
+
+    // the file is still readable
+    reader.set_position(0);
+    let reader = RecordReader::try_new(reader, None, None, None, None)?;
+    let rows = reader
+        .map(|chunk| chunk.map(|chunk| chunk.len()))
+        .sum::<Result<usize>>()?;
+    assert_eq!(rows, 4);
+    Ok(())
+}
+
+#[test]
+fn write_unsupported() {
+    for fields in [vec![2], vec![3]] {
+        assert!(write_with_bloom_filters(BloomFilterOptions { fields, fpp: 0.01 }).is_err());
+    }
+    let fields = vec![0];
+    assert!(write_with_bloom_filters(BloomFilterOptions { fields, fpp: 1.0 }).is_err());
+}
@@ -0,0 +1,183 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::error::{ArrowError, Result};
+use arrow2::io::parquet::encryption::*;
+use arrow2::io::parquet::read::RecordReader;
+
+use super::integration_write;
+
+const FOOTER_KEY: &[u8] = b"0123456789012345";
+const COLUMN_KEY: &[u8] = b"1234567890123450";
+
+fn data() -> (Schema, Vec<Chunk<Arc<dyn Array>>>) {
+    let mut dictionary = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    dictionary
+        .try_extend([Some("a"), None, Some("b"), Some("a")])
+        .unwrap();
+    let dictionary: DictionaryArray<i32> = dictionary.into();
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", dictionary.data_type().clone(), true),
+    ]);
+    let batch = Chunk::new(vec![
+        Arc::new(Int32Array::from([Some(1), None, Some(3), Some(4)])) as Arc<dyn Array>,
+        Arc::new(Utf8Array::<i32>::from([
+            Some("x"),
+            Some("y"),
+            None,
+            Some("z"),
+        ])),
+        Arc::new(dictionary),
+    ]);
+    (schema, vec![batch.clone(), batch])
+}
+
+fn key_retriever() -> KeyRetriever {
+    Arc::new(|key_metadata: &[u8]| match key_metadata {
+        b"footer" => Ok(FOOTER_KEY.to_vec()),
+        b"column" => Ok(COLUMN_KEY.to_vec()),
+        _ => Err(ArrowError::InvalidArgumentError("unknown key".to_string())),
+    })
+}
+
+fn encryption_options(algorithm: Algorithm) -> EncryptionOptions {
+    EncryptionOptions {
+        algorithm,
+        footer_key: FOOTER_KEY.to_vec(),
+        footer_key_metadata: Some(b"footer".to_vec()),
+        column_keys: vec![ColumnKey {
+            path: vec!["b".to_string()],
+            key: COLUMN_KEY.to_vec(),
+            key_metadata: Some(b"column".to_vec()),
+        }],
+        aad_prefix: None,
+        store_aad_prefix: true,
+    }
+}
+
+fn write_encrypted(options: &EncryptionOptions) -> Result<Vec<u8>> {
+    let (schema, batches) = data();
+    let data = integration_write(&schema, &batches)?;
+
+    let mut encrypted = vec![];
+    let size = encrypt(&mut Cursor::new(data), &mut encrypted, options)?;
+    assert_eq!(size, encrypted.len() as u64);
+    Ok(encrypted)
+}
+
+fn read_decrypted(data: &[u8], options: &DecryptionOptions) -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+    let reader =
+        RecordReader::try_new_decrypted(Cursor::new(data), options, None, None, None, None)?;
+    reader.collect()
+}
+
+fn round_trip(algorithm: Algorithm) -> Result<()> {
+    let encrypted = write_encrypted(&encryption_options(algorithm))?;
+    assert_eq!(&encrypted[..4], b"PARE");
+    assert_eq!(&encrypted[encrypted.len() - 4..], b"PARE");
+
+    let options = DecryptionOptions {
+        key_retriever: key_retriever(),
+        aad_prefix: None,
+    };
+    let batches = read_decrypted(&encrypted, &options)?;
+    assert_eq!(batches, data().1);
+    Ok(())
+}
+
+#[test]
+fn round_trip_gcm() -> Result<()> {
+    round_trip(Algorithm::AesGcmV1)
+}
+
+#[test]
+fn round_trip_gcm_ctr() -> Result<()> {
+    round_trip(Algorithm::AesGcmCtrV1)
+}
+
+#[test]
+fn plaintext() -> Result<()> {
+    let (schema, batches) = data();
+    let data = integration_write(&schema, &batches)?;
+
+    let options = DecryptionOptions {
+        key_retriever: key_retriever(),
+        aad_prefix: None,
+    };
+    assert_eq!(read_decrypted(&data, &options)?, batches);
+    Ok(())
+}
+
+#[test]
+fn wrong_key() -> Result<()> {
+    let encrypted = write_encrypted(&encryption_options(Algorithm::AesGcmV1))?;
+
+    // the footer key is right but the column key is wrong
+    let options = DecryptionOptions {
+        key_retriever: Arc::new(|_: &[u8]| Ok(FOOTER_KEY.to_vec())),
+        aad_prefix: None,
+    };
+    assert!(read_decrypted(&encrypted, &options).is_err());
+
+    let options = DecryptionOptions {
+        key_retriever: Arc::new(|_: &[u8]| Ok(COLUMN_KEY.to_vec())),
+        aad_prefix: None,
+    };
+    assert!(read_decrypted(&encrypted, &options).is_err());
+    Ok(())
+}
+
+#[test]
+fn missing_key() -> Result<()> {
+    let encrypted = write_encrypted(&encryption_options(Algorithm::AesGcmV1))?;
+
+    let options = DecryptionOptions {
+        key_retriever: Arc::new(|_: &[u8]| {
+            Err(ArrowError::InvalidArgumentError("no key".to_string()))
+        }),
+        aad_prefix: None,
+    };
+    assert!(read_decrypted(&encrypted, &options).is_err());
+    Ok(())
+}
+
+#[test]
+fn supplied_aad_prefix() -> Result<()> {
+    let mut options = encryption_options(Algorithm::AesGcmV1);
+    options.aad_prefix = Some(b"file.parquet".to_vec());
+    options.store_aad_prefix = false;
+    let encrypted = write_encrypted(&options)?;
+
+    let mut options = DecryptionOptions {
+        key_retriever: key_retriever(),
+        aad_prefix: None,
+    };
+    assert!(read_decrypted(&encrypted, &options).is_err());
+
+    options.aad_prefix = Some(b"other.parquet".to_vec());
+    assert!(read_decrypted(&encrypted, &options).is_err());
+
+    options.aad_prefix = Some(b"file.parquet".to_vec());
+    assert_eq!(read_decrypted(&encrypted, &options)?, data().1);
+    Ok(())
+}
+
+#[test]
+fn unknown_column_key() {
+    let mut options = encryption_options(Algorithm::AesGcmV1);
+    options.column_keys[0].path = vec!["d".to_string()];
+    assert!(write_encrypted(&options).is_err());
+}
+
+#[test]
+fn read_encrypted_without_keys() -> Result<()> {
+    let encrypted = write_encrypted(&encryption_options(Algorithm::AesGcmV1))?;
+    assert!(RecordReader::try_new(Cursor::new(encrypted), None, None, None, None).is_err());
+    Ok(())
+}
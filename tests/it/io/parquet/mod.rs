@@ -9,6 +9,9 @@ use arrow2::{
 
 use crate::io::ipc::read_gzip_json;
 
+mod bloom_filter;
+#[cfg(feature = "io_parquet_encryption")]
+mod encryption;
 mod read;
 mod write;
 
@@ -372,7 +375,13 @@ pub fn pyarrow_nullable_statistics(column: usize) -> Option<Box<dyn Statistics>>
             min_value: Some(0),
             max_value: Some(9),
         }),
-        6 => return None,
+        6 => Box::new(PrimitiveStatistics::<i32> {
+            data_type: DataType::Int32,
+            distinct_count: None,
+            null_count: Some(1),
+            min_value: Some(10),
+            max_value: Some(200),
+        }),
         // Decimal statistics
         7 => Box::new(PrimitiveStatistics::<i128> {
             distinct_count: None,
@@ -597,19 +606,76 @@ pub fn pyarrow_struct(column: usize) -> Box<dyn Array> {
                 None,
             ))
         }
+        2 => {
+            let struct_ = pyarrow_struct(0);
+            let validity =
+                Bitmap::from([true, false, true, true, true, true, false, true, true, true]);
+            Box::new(StructArray::from_data(
+                DataType::Struct(fields),
+                struct_
+                    .as_any()
+                    .downcast_ref::<StructArray>()
+                    .unwrap()
+                    .values()
+                    .to_vec(),
+                Some(validity),
+            ))
+        }
+        3 => {
+            // [[a, None], None, [], [b], [c, d, e], None, [], [None], [f], [g]]
+            let string = [
+                Some("a"),
+                None,
+                Some("b"),
+                None,
+                Some("d"),
+                Some("e"),
+                None,
+                Some("f"),
+                Some("g"),
+            ];
+            let boolean = [
+                Some(true),
+                None,
+                None,
+                Some(false),
+                Some(true),
+                Some(false),
+                None,
+                None,
+                Some(true),
+            ];
+            let validity = Bitmap::from([true, false, true, true, true, true, false, true, true]);
+            let values = vec![
+                Arc::new(Utf8Array::<i32>::from(string)) as Arc<dyn Array>,
+                Arc::new(BooleanArray::from(boolean)) as Arc<dyn Array>,
+            ];
+            let struct_ = StructArray::from_data(DataType::Struct(fields), values, Some(validity));
+
+            let validity =
+                Bitmap::from([true, false, true, true, true, false, true, true, true, true]);
+            Box::new(ListArray::<i32>::from_data(
+                ListArray::<i32>::default_datatype(struct_.data_type().clone()),
+                Buffer::from_slice([0, 2, 2, 2, 3, 6, 6, 6, 7, 8, 9]),
+                Arc::new(struct_),
+                Some(validity),
+            ))
+        }
         _ => todo!(),
     }
 }
 
 pub fn pyarrow_struct_statistics(column: usize) -> Option<Box<dyn Statistics>> {
+    // the statistics are of the parquet column with the same index, whose values are the same
+    // in columns 0 and 2 and in columns 1 and 3.
     match column {
-        0 => Some(Box::new(Utf8Statistics {
+        0 | 2 => Some(Box::new(Utf8Statistics {
             distinct_count: None,
             null_count: Some(1),
             min_value: Some("".to_string()),
             max_value: Some("def".to_string()),
         })),
-        1 => Some(Box::new(BooleanStatistics {
+        1 | 3 => Some(Box::new(BooleanStatistics {
             distinct_count: None,
             null_count: Some(1),
             min_value: Some(false),
@@ -730,3 +796,73 @@ fn arrow_type() -> Result<()> {
     assert_eq!(new_batches, vec![batch]);
     Ok(())
 }
+
+#[test]
+fn roundtrip_metadata() -> Result<()> {
+    let metadata = Metadata::from([("key".to_string(), "value".to_string())]);
+    let extension = DataType::Extension(
+        "uuid".to_string(),
+        Box::new(DataType::FixedSizeBinary(2)),
+        Some("v1".to_string()),
+    );
+    let list = DataType::List(Box::new(
+        Field::new("item", DataType::Int32, true).with_metadata(metadata.clone()),
+    ));
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true).with_metadata(metadata.clone()),
+        Field::new("b", extension.clone(), true),
+        Field::new("c", list.clone(), true),
+    ])
+    .with_metadata(metadata);
+
+    let a = Int32Array::from([Some(1), None]);
+    let b = FixedSizeBinaryArray::from_data(extension, vec![1, 2, 3, 4].into(), None);
+    let c = ListArray::<i32>::from_data(
+        list,
+        vec![0, 1, 1].into(),
+        Arc::new(Int32Array::from_slice([1])),
+        None,
+    );
+    let batch = Chunk::try_new(vec![
+        Arc::new(a) as Arc<dyn Array>,
+        Arc::new(b),
+        Arc::new(c),
+    ])?;
+
+    let data = integration_write(&schema, &[batch.clone()])?;
+    let (read_schema, read_batches) = integration_read(&data)?;
+
+    assert_eq!(read_schema.as_ref(), &schema);
+    assert_eq!(read_batches, vec![batch]);
+    Ok(())
+}
+
+#[test]
+fn key_value_metadata() -> Result<()> {
+    let schema = Schema::from(vec![Field::new("a", DataType::Int32, true)])
+        .with_metadata(Metadata::from([("a".to_string(), "1".to_string())]));
+    let other = KeyValue {
+        key: "b".to_string(),
+        value: Some("2".to_string()),
+    };
+
+    // the schema of a previous file is replaced
+    let previous = schema_to_key_value_metadata(&Schema::default(), None);
+    let key_value_metadata =
+        schema_to_key_value_metadata(&schema, Some([previous, vec![other]].concat()));
+    assert_eq!(
+        key_value_metadata
+            .iter()
+            .map(|kv| kv.key.as_str())
+            .collect::<Vec<_>>(),
+        vec!["b", "a", "ARROW:schema"]
+    );
+
+    let read_schema = schema::read_schema_from_metadata(&Some(key_value_metadata))?.unwrap();
+    let expected = schema.with_metadata(Metadata::from([
+        ("a".to_string(), "1".to_string()),
+        ("b".to_string(), "2".to_string()),
+    ]));
+    assert_eq!(read_schema, expected);
+    Ok(())
+}
@@ -363,6 +363,26 @@ fn v1_struct_struct_optional() -> Result<()> {
     test_pyarrow_integration(1, 1, "struct", false, false, None)
 }
 
+#[test]
+fn v1_struct_nullable_optional() -> Result<()> {
+    test_pyarrow_integration(2, 1, "struct", false, false, None)
+}
+
+#[test]
+fn v2_struct_nullable_optional() -> Result<()> {
+    test_pyarrow_integration(2, 2, "struct", false, false, None)
+}
+
+#[test]
+fn v1_list_struct_optional() -> Result<()> {
+    test_pyarrow_integration(3, 1, "struct", false, false, None)
+}
+
+#[test]
+fn v2_list_struct_optional() -> Result<()> {
+    test_pyarrow_integration(3, 2, "struct", false, false, None)
+}
+
 #[test]
 fn all_types() -> Result<()> {
     let path = "testing/parquet-testing/data/alltypes_plain.parquet";
@@ -399,3 +419,537 @@ fn all_types() -> Result<()> {
 
     Ok(())
 }
+
+fn all_types_int96(time_unit: Option<TimeUnit>) -> Result<()> {
+    let path = "testing/parquet-testing/data/alltypes_plain.parquet";
+    let reader = std::fs::File::open(path)?;
+
+    let mut reader = RecordReader::try_new(reader, None, None, None, None)?;
+    if let Some(time_unit) = time_unit {
+        reader.set_int96_time_unit(time_unit);
+    }
+    let time_unit = time_unit.unwrap_or(TimeUnit::Nanosecond);
+    let data_type = DataType::Timestamp(time_unit, None);
+    assert_eq!(reader.schema().fields[10].data_type(), &data_type);
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+
+    // the timestamp_col (INT96) in seconds
+    let seconds = [
+        1235865600i64,
+        1235865660,
+        1238544000,
+        1238544060,
+        1233446400,
+        1233446460,
+        1230768000,
+        1230768060,
+    ];
+    let factor = match time_unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    };
+    let expected = Int64Array::from_vec(seconds.iter().map(|x| x * factor).collect()).to(data_type);
+    assert_eq!(batches[0].columns()[10].as_ref(), &expected as &dyn Array);
+    Ok(())
+}
+
+#[test]
+fn all_types_int96_ns() -> Result<()> {
+    all_types_int96(None)
+}
+
+#[test]
+fn all_types_int96_us() -> Result<()> {
+    all_types_int96(Some(TimeUnit::Microsecond))
+}
+
+#[test]
+fn prune_row_groups_by_statistics() -> Result<()> {
+    use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let batches = vec![
+        Chunk::try_new(vec![
+            Arc::new(Int32Array::from(&[Some(1), None, Some(3)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+        ])?,
+        Chunk::try_new(vec![
+            Arc::new(Int32Array::from_slice([10, 20, 30])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from_slice(["x", "y", "z"])),
+        ])?,
+    ];
+    let data = integration_write(&schema, &batches)?;
+    let metadata = read_metadata(&mut Cursor::new(data))?;
+
+    let int = |op, value| {
+        Predicate::new(
+            0,
+            op,
+            Box::new(PrimitiveScalar::<i32>::new(DataType::Int32, value)),
+        )
+    };
+    let utf8 = |op, value| Predicate::new(1, op, Box::new(Utf8Scalar::<i32>::new(value)));
+
+    let prune = |predicates: &[Predicate]| prune_row_groups(&metadata, None, predicates);
+
+    assert_eq!(prune(&[])?, vec![0, 1]);
+    assert_eq!(prune(&[int(Operator::Eq, Some(2))])?, vec![0]);
+    assert_eq!(prune(&[int(Operator::Eq, Some(5))])?, Vec::<usize>::new());
+    assert_eq!(prune(&[int(Operator::Gt, Some(3))])?, vec![1]);
+    assert_eq!(prune(&[int(Operator::GtEq, Some(3))])?, vec![0, 1]);
+    assert_eq!(prune(&[int(Operator::Lt, Some(10))])?, vec![0]);
+    assert_eq!(prune(&[int(Operator::Eq, None)])?, Vec::<usize>::new());
+    assert_eq!(prune(&[int(Operator::IsNull, None)])?, vec![0]);
+    assert_eq!(prune(&[utf8(Operator::GtEq, Some("d"))])?, vec![1]);
+    assert_eq!(
+        prune(&[int(Operator::Lt, Some(10)), utf8(Operator::Eq, Some("y"))])?,
+        Vec::<usize>::new()
+    );
+
+    // the column of the predicate refers to the projected schema
+    let predicate = Predicate::new(0, Operator::Eq, Box::new(Utf8Scalar::<i32>::new(Some("a"))));
+    assert_eq!(
+        prune_row_groups(&metadata, Some(&[1]), &[predicate])?,
+        vec![0]
+    );
+
+    // mismatching types error
+    let predicate = Predicate::new(0, Operator::Eq, Box::new(Utf8Scalar::<i32>::new(Some("a"))));
+    assert!(prune(&[predicate]).is_err());
+    Ok(())
+}
+
+#[test]
+fn prune_row_groups_after_nested_field() -> Result<()> {
+    use arrow2::io::parquet::write::{Compression, Encoding, FileSink, Version, WriteOptions};
+    use arrow2::scalar::{PrimitiveScalar, Utf8Scalar};
+
+    // a map has two parquet columns (its keys and values), so that the column chunks of the
+    // row groups are not at the indices of the fields
+    let entries = StructArray::from_data(
+        DataType::Struct(vec![
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", DataType::Int32, true),
+        ]),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+            Arc::new(Int32Array::from_slice([100, 200, 300])),
+        ],
+        None,
+    );
+    let field = Field::new("entries", entries.data_type().clone(), false);
+    let map = Arc::new(MapArray::from_data(
+        DataType::Map(Box::new(field), false),
+        vec![0, 1, 2, 3].into(),
+        Arc::new(entries),
+        None,
+    )) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("m", map.data_type().clone(), false),
+        Field::new("a", DataType::Int32, false),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+    let mut sink = FileSink::try_new(
+        Cursor::new(vec![]),
+        schema,
+        vec![Encoding::Plain; 2],
+        options,
+        3,
+        None,
+    )?;
+    for values in [[1, 2, 3], [10, 20, 30]] {
+        sink.write(Chunk::new(vec![
+            map.clone(),
+            Arc::new(Int32Array::from_slice(values)),
+        ]))?;
+    }
+    let (_, data) = sink.close()?;
+    let metadata = read_metadata(&mut Cursor::new(data.into_inner()))?;
+
+    let value = Box::new(PrimitiveScalar::<i32>::new(DataType::Int32, Some(2)));
+    let predicate = Predicate::new(1, Operator::Eq, value);
+    assert_eq!(prune_row_groups(&metadata, None, &[predicate])?, vec![0]);
+
+    // predicates on nested fields do not prune
+    let value = Box::new(Utf8Scalar::<i32>::new(Some("z")));
+    let predicate = Predicate::new(0, Operator::Eq, value);
+    assert_eq!(prune_row_groups(&metadata, None, &[predicate])?, vec![0, 1]);
+    Ok(())
+}
+
+#[test]
+fn select_rows_from_pages() -> Result<()> {
+    use arrow2::io::parquet::read::indexes::{select_rows, PageLocation};
+
+    let locations = [0, 10, 25, 30]
+        .iter()
+        .enumerate()
+        .map(|(i, first_row_index)| PageLocation {
+            offset: 4 + i as i64 * 100,
+            compressed_page_size: 100,
+            first_row_index: *first_row_index,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        select_rows(&locations, &[true, false, true, true], 50)?,
+        vec![0..10, 25..50]
+    );
+    assert_eq!(
+        select_rows(&locations, &[false, true, false, false], 50)?,
+        vec![10..25]
+    );
+    assert_eq!(
+        select_rows(&locations, &[false; 4], 50)?,
+        Vec::<std::ops::Range<usize>>::new()
+    );
+    assert!(select_rows(&locations, &[true], 50).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_fields_by_name() -> Result<()> {
+    let a = Arc::new(Int32Array::from_slice([1, 2])) as Arc<dyn Array>;
+    let b = Arc::new(Utf8Array::<i32>::from_slice(["a", "b"])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), false),
+        Field::new("b", b.data_type().clone(), false),
+    ]);
+    let data = integration_write(&schema, &[Chunk::new(vec![a.clone(), b.clone()])])?;
+
+    // the fields are read by name, in the order of the requested schema
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, None, None, None)?;
+    let requested = Schema::from(vec![schema.fields[1].clone(), schema.fields[0].clone()]);
+    reader.set_schema(requested, true)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![Chunk::new(vec![b, a.clone()])]);
+
+    // a field that does not exist is never matched
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, None, None, None)?;
+    let requested = Schema::from(vec![Field::new("c", a.data_type().clone(), false)]);
+    assert!(reader.set_schema(requested.clone(), true).is_err());
+    assert!(reader.set_schema(requested, false).is_err());
+    Ok(())
+}
+
+#[test]
+fn read_fields_by_name_strict_nullability() -> Result<()> {
+    let a = Arc::new(Int32Array::from([Some(1), None])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", a.data_type().clone(), true)]);
+    let data = integration_write(&schema, &[Chunk::new(vec![a.clone()])])?;
+
+    // a non-nullable field can only be read from a nullable column unless the match is strict
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, None, None, None)?;
+    let requested = Schema::from(vec![Field::new("a", a.data_type().clone(), false)]);
+    assert!(reader.set_schema(requested.clone(), true).is_err());
+    reader.set_schema(requested, false)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![Chunk::new(vec![a])]);
+    Ok(())
+}
+
+#[test]
+fn read_utf8_as_large_utf8() -> Result<()> {
+    let a = Arc::new(Utf8Array::<i32>::from([Some("a"), None, Some("bb")])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", a.data_type().clone(), true)]);
+    let data = integration_write(&schema, &[Chunk::new(vec![a])])?;
+
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, None, None, None)?;
+    // small columns are read with `i32` offsets
+    assert_eq!(reader.schema().fields[0].data_type(), &DataType::Utf8);
+    let requested = Schema::from(vec![Field::new("a", DataType::LargeUtf8, true)]);
+    reader.set_schema(requested, true)?;
+
+    let expected = Utf8Array::<i64>::from([Some("a"), None, Some("bb")]);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        batches,
+        vec![Chunk::new(vec![Arc::new(expected) as Arc<dyn Array>])]
+    );
+    Ok(())
+}
+
+#[test]
+fn read_timestamp_time_unit() -> Result<()> {
+    let a = Arc::new(
+        Int64Array::from([Some(1_001), None, Some(3)])
+            .to(DataType::Timestamp(TimeUnit::Millisecond, None)),
+    ) as Arc<dyn Array>;
+    let schema = Schema::from(vec![Field::new("a", a.data_type().clone(), true)]);
+    let data = integration_write(&schema, &[Chunk::new(vec![a])])?;
+
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, None, None, None)?;
+    reader.set_timestamp_time_unit(TimeUnit::Microsecond);
+    let data_type = DataType::Timestamp(TimeUnit::Microsecond, None);
+    assert_eq!(reader.schema().fields[0].data_type(), &data_type);
+    let expected = Int64Array::from([Some(1_001_000), None, Some(3_000)]).to(data_type);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        batches,
+        vec![Chunk::new(vec![Arc::new(expected) as Arc<dyn Array>])]
+    );
+
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, None, None, None)?;
+    reader.set_timestamp_time_unit(TimeUnit::Second);
+    let data_type = DataType::Timestamp(TimeUnit::Second, None);
+    let expected = Int64Array::from([Some(1), None, Some(0)]).to(data_type);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        batches,
+        vec![Chunk::new(vec![Arc::new(expected) as Arc<dyn Array>])]
+    );
+    Ok(())
+}
+
+#[test]
+fn read_with_decoder() -> Result<()> {
+    let a = Arc::new(Int64Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let b = Arc::new(
+        Int64Array::from([Some(1_001), None, Some(3)])
+            .to(DataType::Timestamp(TimeUnit::Millisecond, None)),
+    ) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+    ]);
+    let chunk = Chunk::new(vec![a, b]);
+    let data = integration_write(&schema, &[chunk.clone(), chunk])?;
+
+    // decodes each column in its own thread
+    let decoder: Decoder = Arc::new(|tasks| {
+        tasks
+            .into_iter()
+            .map(std::thread::spawn)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, Some(5), None, None)?;
+    reader.set_decoder(decoder);
+    reader.set_timestamp_time_unit(TimeUnit::Second);
+
+    let a = Int64Array::from([Some(1), None, Some(3)]);
+    let b =
+        Int64Array::from([Some(1), None, Some(0)]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let expected = vec![
+        Chunk::new(vec![
+            Arc::new(a.clone()) as Arc<dyn Array>,
+            Arc::new(b.clone()) as Arc<dyn Array>,
+        ]),
+        Chunk::new(vec![
+            Arc::new(a.slice(0, 2)) as Arc<dyn Array>,
+            Arc::new(b.slice(0, 2)) as Arc<dyn Array>,
+        ]),
+    ];
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, expected);
+    Ok(())
+}
+
+#[test]
+fn read_dictionaries() -> Result<()> {
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "bb"]));
+    let keys = PrimitiveArray::<u8>::from([Some(1), None, Some(1), Some(0)]);
+    let a = Arc::new(DictionaryArray::<u8>::from_data(keys, values)) as Arc<dyn Array>;
+    let values = Arc::new(Int64Array::from_slice([10, 20]));
+    let keys = PrimitiveArray::<i8>::from_slice([0, 0, 1, 0]);
+    let b = Arc::new(DictionaryArray::<i8>::from_data(keys, values)) as Arc<dyn Array>;
+    let c = Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c", "d"])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), false),
+        Field::new("c", c.data_type().clone(), false),
+    ]);
+    let data = integration_write(&schema, &[Chunk::new(vec![a, b, c.clone()])])?;
+
+    // read the values of the dictionaries, and then the dictionaries themselves
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, None, None, None)?;
+    reader.set_schema(
+        Schema::from(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Int64, false),
+            Field::new("c", DataType::Utf8, false),
+        ]),
+        true,
+    )?;
+    reader.set_read_dictionaries(true);
+
+    let dictionary =
+        |values: DataType| DataType::Dictionary(IntegerType::Int32, Box::new(values), false);
+    let fields = &reader.schema().fields;
+    assert_eq!(fields[0].data_type(), &dictionary(DataType::Utf8));
+    assert_eq!(fields[1].data_type(), &dictionary(DataType::Int64));
+    // columns that are not dictionary-encoded are read as usual
+    assert_eq!(fields[2].data_type(), &DataType::Utf8);
+
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a", "bb"]));
+    let keys = PrimitiveArray::<i32>::from([Some(1), None, Some(1), Some(0)]);
+    let a = DictionaryArray::<i32>::from_data(keys, values);
+    let values = Arc::new(Int64Array::from_slice([10, 20]));
+    let keys = PrimitiveArray::<i32>::from_slice([0, 0, 1, 0]);
+    let b = DictionaryArray::<i32>::from_data(keys, values);
+    let expected = Chunk::new(vec![Arc::new(a) as Arc<dyn Array>, Arc::new(b), c]);
+
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![expected]);
+    Ok(())
+}
+
+#[test]
+fn read_in_chunks() -> Result<()> {
+    let a = Int64Array::from([Some(1), None, Some(3), Some(4), None]);
+    let b = Utf8Array::<i32>::from([Some("a"), Some("bb"), None, Some("c"), Some("d")]);
+    let mut c = MutableListArray::<i32, MutablePrimitiveArray<i32>>::new();
+    c.try_extend(vec![
+        Some(vec![Some(1), Some(2)]),
+        None,
+        Some(vec![]),
+        Some(vec![None]),
+        Some(vec![Some(3)]),
+    ])?;
+    let c: ListArray<i32> = c.into();
+    let chunk = Chunk::new(vec![
+        Arc::new(a.clone()) as Arc<dyn Array>,
+        Arc::new(b.clone()),
+        Arc::new(c.clone()),
+    ]);
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+        Field::new("c", c.data_type().clone(), true),
+    ]);
+    let data = integration_write(&schema, &[chunk.clone(), chunk])?;
+
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, Some(7), None, None)?;
+    reader.set_chunk_size(2);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+
+    let expected = [(0, 2), (2, 2), (4, 1), (0, 2)]
+        .iter()
+        .map(|&(offset, length)| {
+            Chunk::new(vec![
+                Arc::new(a.slice(offset, length)) as Arc<dyn Array>,
+                Arc::new(b.slice(offset, length)),
+                Arc::new(c.slice(offset, length)),
+            ])
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(batches, expected);
+    Ok(())
+}
+
+#[test]
+fn read_with_row_filter() -> Result<()> {
+    use arrow2::io::parquet::write::{Compression, Encoding, FileSink, Version, WriteOptions};
+
+    let a = Int64Array::from_slice((0..12).collect::<Vec<_>>());
+    let b = Utf8Array::<i32>::from_iter((0..12).map(|x| (x % 5 != 0).then(|| x.to_string())));
+    let mut c = MutableListArray::<i32, MutablePrimitiveArray<i64>>::new();
+    c.try_extend((0..12).map(|x| Some(vec![Some(x), None])))?;
+    let c: ListArray<i32> = c.into();
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), false),
+        Field::new("b", b.data_type().clone(), true),
+        Field::new("c", c.data_type().clone(), true),
+    ]);
+
+    // two row groups of 6 rows, each with two pages per column
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let mut sink = FileSink::try_new(
+        std::io::Cursor::new(vec![]),
+        schema,
+        vec![Encoding::Plain; 3],
+        options,
+        6,
+        None,
+    )?;
+    for offset in (0..12).step_by(3) {
+        sink.write(Chunk::new(vec![
+            Arc::new(a.slice(offset, 3)) as Arc<dyn Array>,
+            Arc::new(b.slice(offset, 3)),
+            Arc::new(c.slice(offset, 3)),
+        ]))?;
+    }
+    let (_, data) = sink.close()?;
+    let data = data.into_inner();
+
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, None, None, None)?;
+    let filter: RowFilter = Arc::new(|chunk: &Chunk<Arc<dyn Array>>| {
+        let a = chunk.arrays()[0]
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        Ok(a.values().iter().map(|x| [1, 2, 8].contains(x)).collect())
+    });
+    assert!(reader.set_row_filter(vec![3], filter.clone()).is_err());
+    reader.set_row_filter(vec![0], filter)?;
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+
+    let expected = [vec![1, 2], vec![8]]
+        .iter()
+        .map(|rows| {
+            let take = |array: &dyn Array| {
+                let mut growable = arrow2::array::growable::make_growable(&[array], false, 3);
+                rows.iter().for_each(|row| growable.extend(0, *row, 1));
+                growable.as_arc()
+            };
+            Chunk::new(vec![take(&a), take(&b), take(&c)])
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(batches, expected);
+    Ok(())
+}
+
+#[test]
+fn read_type_overrides() -> Result<()> {
+    let a = Arc::new(Int64Array::from([Some(1_000), None, Some(3)])) as Arc<dyn Array>;
+    let b = Arc::new(Utf8Array::<i32>::from_slice(["x", "y", "z"])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("created_at", a.data_type().clone(), true),
+        Field::new("id", b.data_type().clone(), false),
+    ]);
+    let data = integration_write(&schema, &[Chunk::new(vec![a, b.clone()])])?;
+
+    let timestamp = DataType::Timestamp(TimeUnit::Millisecond, None);
+    let overrides = [
+        TypeOverride::Pattern("*_at".to_string(), timestamp.clone()),
+        TypeOverride::Name("id".to_string(), DataType::LargeBinary),
+    ];
+    let metadata = read_metadata(&mut std::io::Cursor::new(&data))?;
+    let expected = get_schema_with_overrides(&metadata, &overrides)?;
+    assert_eq!(expected.fields[0].data_type(), &timestamp);
+    assert_eq!(expected.fields[1].data_type(), &DataType::LargeBinary);
+
+    let mut reader = RecordReader::try_new(std::io::Cursor::new(&data), None, None, None, None)?;
+    reader.set_type_overrides(&overrides[..1])?;
+    assert_eq!(reader.schema().fields[0].data_type(), &timestamp);
+
+    let expected = Int64Array::from([Some(1_000), None, Some(3)]).to(timestamp);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        batches,
+        vec![Chunk::new(vec![Arc::new(expected) as Arc<dyn Array>, b])]
+    );
+
+    // a date cannot be read from a `BYTE_ARRAY`
+    let overrides = [TypeOverride::Name("id".to_string(), DataType::Date64)];
+    assert!(get_schema_with_overrides(&metadata, &overrides).is_err());
+    Ok(())
+}
@@ -2,6 +2,7 @@ use std::io::Cursor;
 
 use arrow2::error::Result;
 use arrow2::io::parquet::write::*;
+use arrow2::types::days_ms;
 
 use super::*;
 
@@ -352,6 +353,54 @@ fn utf8_optional_v2_delta() -> Result<()> {
     )
 }
 
+#[test]
+fn int64_optional_v1_delta() -> Result<()> {
+    round_trip(
+        0,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::DeltaBinaryPacked,
+    )
+}
+
+#[test]
+fn int64_required_v2_delta() -> Result<()> {
+    round_trip(
+        0,
+        false,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::DeltaBinaryPacked,
+    )
+}
+
+#[test]
+fn utf8_optional_v1_delta_byte_array() -> Result<()> {
+    round_trip(
+        2,
+        true,
+        false,
+        Version::V1,
+        Compression::Uncompressed,
+        Encoding::DeltaByteArray,
+    )
+}
+
+#[test]
+fn utf8_required_v2_delta_byte_array() -> Result<()> {
+    round_trip(
+        2,
+        false,
+        false,
+        Version::V2,
+        Compression::Uncompressed,
+        Encoding::DeltaByteArray,
+    )
+}
+
 #[test]
 fn i32_optional_v2_dict() -> Result<()> {
     round_trip(
@@ -520,3 +569,578 @@ fn decimal_26_required_v2() -> Result<()> {
         Encoding::Plain,
     )
 }
+
+fn decimal_negative_statistics(precision: usize) -> Result<()> {
+    let array: Arc<dyn Array> = Arc::new(
+        PrimitiveArray::<i128>::from([Some(-256), Some(-1), None, Some(0), Some(3)])
+            .to(DataType::Decimal(precision, 2)),
+    );
+
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![Encoding::Plain])?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        to_parquet_schema(&schema)?,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+
+    let (result, statistics) = read_column(&mut Cursor::new(data), 0, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+
+    let expected: Box<dyn Statistics> = Box::new(PrimitiveStatistics::<i128> {
+        data_type: DataType::Decimal(precision, 2),
+        distinct_count: None,
+        null_count: Some(1),
+        min_value: Some(-256),
+        max_value: Some(3),
+    });
+    assert_eq!(Some(expected), statistics);
+    Ok(())
+}
+
+fn write_statistics(array: Arc<dyn Array>) -> Result<Option<Box<dyn Statistics>>> {
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![Encoding::Plain])?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        to_parquet_schema(&schema)?,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+
+    let (result, statistics) = read_column(&mut Cursor::new(data), 0, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+    Ok(statistics)
+}
+
+#[test]
+fn float_nan_statistics() -> Result<()> {
+    let array = Float64Array::from([Some(1.0), Some(f64::NAN), None, Some(-1.0)]);
+    let statistics = write_statistics(Arc::new(array.clone()))?;
+
+    let statistics = statistics.unwrap();
+    let statistics = statistics
+        .as_any()
+        .downcast_ref::<PrimitiveStatistics<f64>>();
+    let expected = PrimitiveStatistics::<f64> {
+        data_type: DataType::Float64,
+        distinct_count: None,
+        null_count: Some(1),
+        min_value: Some(-1.0),
+        max_value: Some(1.0),
+    };
+    assert_eq!(Some(&expected), statistics);
+    Ok(())
+}
+
+#[test]
+fn interval_statistics() -> Result<()> {
+    let array = PrimitiveArray::<days_ms>::from([Some(days_ms::new(1, 2)), None])
+        .to(DataType::Interval(IntervalUnit::DayTime));
+    let statistics = write_statistics(Arc::new(array))?;
+
+    let statistics = statistics.unwrap();
+    let statistics = statistics.as_any().downcast_ref::<FixedLenStatistics>();
+    let expected = FixedLenStatistics {
+        data_type: DataType::Interval(IntervalUnit::DayTime),
+        distinct_count: None,
+        null_count: Some(1),
+        min_value: None,
+        max_value: None,
+    };
+    assert_eq!(Some(&expected), statistics);
+    Ok(())
+}
+
+#[test]
+fn boolean_statistics() -> Result<()> {
+    let array = BooleanArray::from([Some(true), None, Some(true)]);
+    let statistics = write_statistics(Arc::new(array))?;
+
+    let statistics = statistics.unwrap();
+    let statistics = statistics.as_any().downcast_ref::<BooleanStatistics>();
+    let expected = BooleanStatistics {
+        distinct_count: Some(1),
+        null_count: Some(1),
+        min_value: Some(true),
+        max_value: Some(true),
+    };
+    assert_eq!(Some(&expected), statistics);
+    Ok(())
+}
+
+#[test]
+fn decimal_9_negative_statistics() -> Result<()> {
+    decimal_negative_statistics(9)
+}
+
+#[test]
+fn decimal_18_negative_statistics() -> Result<()> {
+    decimal_negative_statistics(18)
+}
+
+#[test]
+fn decimal_26_negative_statistics() -> Result<()> {
+    decimal_negative_statistics(26)
+}
+
+#[test]
+fn utf8_dict_optional_v1() -> Result<()> {
+    let keys = PrimitiveArray::<i32>::from([Some(0), Some(1), None, Some(1)]);
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["b", "aa"]));
+    let array: Arc<dyn Array> = Arc::new(DictionaryArray::<i32>::from_data(keys, values));
+
+    let schema = Schema::from(vec![Field::new("a1", array.data_type().clone(), true)]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V1,
+    };
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+    let row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options,
+        vec![Encoding::RleDictionary],
+    )?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        to_parquet_schema(&schema)?,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+
+    let (result, statistics) = read_column(&mut Cursor::new(data), 0, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+
+    // the statistics are derived from the dictionary's values
+    let expected: Box<dyn Statistics> = Box::new(Utf8Statistics {
+        distinct_count: None,
+        null_count: Some(1),
+        min_value: Some("aa".to_string()),
+        max_value: Some("b".to_string()),
+    });
+    assert_eq!(Some(expected), statistics);
+    Ok(())
+}
+
+fn round_trip_array(array: Arc<dyn Array>, version: Version) -> Result<()> {
+    let field = Field::new("a1", array.data_type().clone(), true);
+    let schema = Schema::from(vec![field]);
+
+    let options = WriteOptions {
+        write_statistics: false,
+        compression: Compression::Uncompressed,
+        version,
+    };
+
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let iter = vec![Chunk::try_new(vec![array.clone()])];
+
+    let row_groups =
+        RowGroupIterator::try_new(iter.into_iter(), &schema, options, vec![Encoding::Plain])?;
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+
+    let data = writer.into_inner();
+
+    let (result, _) = read_column(&mut Cursor::new(data), 0, 0)?;
+    assert_eq!(array.as_ref(), result.as_ref());
+    Ok(())
+}
+
+// [[[1, None], []], None, [None, [2]], [], [[3, 4]]]
+fn list_list_int64() -> ListArray<i32> {
+    let values = Arc::new(Int64Array::from([Some(1), None, Some(2), Some(3), Some(4)]));
+    let inner = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Int64),
+        vec![0, 2, 2, 2, 3, 5].into(),
+        values,
+        Some([true, true, false, true, true].into()),
+    );
+    ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(inner.data_type().clone()),
+        vec![0, 2, 2, 4, 4, 5].into(),
+        Arc::new(inner),
+        Some([true, false, true, true, true].into()),
+    )
+}
+
+#[test]
+fn list_list_int64_optional_v1() -> Result<()> {
+    round_trip_array(Arc::new(list_list_int64()), Version::V1)
+}
+
+#[test]
+fn list_list_int64_optional_v2() -> Result<()> {
+    round_trip_array(Arc::new(list_list_int64()), Version::V2)
+}
+
+#[test]
+fn list_list_int64_optional_sliced() -> Result<()> {
+    let array = list_list_int64();
+    // [[None, [2]], [], [[3, 4]]]
+    let array = array.slice(2, 3);
+    round_trip_array(Arc::new(array), Version::V2)
+}
+
+#[test]
+fn list_list_utf8_optional() -> Result<()> {
+    // [[["a"], None], None, [[]]]
+    let values = Arc::new(Utf8Array::<i32>::from_slice(["a"]));
+    let inner = ListArray::<i32>::from_data(
+        ListArray::<i32>::default_datatype(DataType::Utf8),
+        vec![0, 1, 1, 1].into(),
+        values,
+        Some([true, false, true].into()),
+    );
+    let array = ListArray::<i64>::from_data(
+        ListArray::<i64>::default_datatype(inner.data_type().clone()),
+        vec![0, 2, 2, 3].into(),
+        Arc::new(inner),
+        Some([true, false, true].into()),
+    );
+    round_trip_array(Arc::new(array), Version::V1)
+}
+
+// [{"a": 1, "b": None}, None, {}, {"c": 3}]
+fn map_utf8_int32() -> MapArray {
+    let fields = vec![
+        Field::new("keys", DataType::Utf8, false),
+        Field::new("values", DataType::Int32, true),
+    ];
+    let entries = StructArray::from_data(
+        DataType::Struct(fields),
+        vec![
+            Arc::new(Utf8Array::<i32>::from_slice(["a", "b", "c"])),
+            Arc::new(Int32Array::from([Some(1), None, Some(3)])),
+        ],
+        None,
+    );
+    let field = Field::new("entries", entries.data_type().clone(), false);
+    MapArray::from_data(
+        DataType::Map(Box::new(field), false),
+        vec![0, 2, 2, 2, 3].into(),
+        Arc::new(entries),
+        Some([true, false, true, true].into()),
+    )
+}
+
+#[test]
+fn map_optional_v1() -> Result<()> {
+    round_trip_array(Arc::new(map_utf8_int32()), Version::V1)
+}
+
+#[test]
+fn map_optional_v2() -> Result<()> {
+    round_trip_array(Arc::new(map_utf8_int32()), Version::V2)
+}
+
+#[test]
+fn map_optional_sliced() -> Result<()> {
+    // [None, {}, {"c": 3}]
+    let array = map_utf8_int32().slice(1, 3);
+    round_trip_array(Arc::new(array), Version::V1)
+}
+
+#[test]
+fn per_column_compression_and_encoding() -> Result<()> {
+    let a = Arc::new(Int64Array::from_slice([1, 2, 3])) as Arc<dyn Array>;
+    let b = Arc::new(Utf8Array::<i32>::from_slice(["aa", "bb", "cc"])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let iter = vec![Chunk::try_new(vec![a.clone(), b.clone()])];
+    let mut row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::Plain],
+    )?;
+    row_groups.set_encoding(0, Encoding::DeltaBinaryPacked)?;
+    row_groups.set_compression("b", Compression::Snappy)?;
+    row_groups.set_encoding("b", Encoding::DeltaLengthByteArray)?;
+    assert!(row_groups
+        .set_compression("c", Compression::Snappy)
+        .is_err());
+    assert!(row_groups.set_encoding(2, Encoding::Plain).is_err());
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+
+    let (result, _) = read_column(&mut Cursor::new(&data), 0, 0)?;
+    assert_eq!(a.as_ref(), result.as_ref());
+    let (result, _) = read_column(&mut Cursor::new(&data), 0, 1)?;
+    assert_eq!(b.as_ref(), result.as_ref());
+    Ok(())
+}
+
+#[test]
+fn file_sink() -> Result<()> {
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let chunk = |a: [Option<i64>; 2], b: [Option<&str>; 2]| {
+        Chunk::new(vec![
+            Arc::new(Int64Array::from(a)) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from(b)) as Arc<dyn Array>,
+        ])
+    };
+
+    let mut sink = FileSink::try_new(
+        Cursor::new(vec![]),
+        schema,
+        vec![Encoding::Plain, Encoding::Plain],
+        options,
+        4,
+        None,
+    )?;
+    sink.write(chunk([Some(1), None], [Some("a"), Some("b")]))?;
+    sink.write(chunk([Some(3), Some(4)], [None, Some("d")]))?;
+    sink.write(chunk([Some(5), Some(6)], [Some("e"), None]))?;
+    let (_, writer) = sink.close()?;
+    let data = writer.into_inner();
+
+    // the first two chunks are written to the first row group
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    assert_eq!(reader.metadata().row_groups.len(), 2);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    let expected = vec![
+        Chunk::new(vec![
+            Arc::new(Int64Array::from([Some(1), None, Some(3), Some(4)])) as Arc<dyn Array>,
+            Arc::new(Utf8Array::<i32>::from([
+                Some("a"),
+                Some("b"),
+                None,
+                Some("d"),
+            ])),
+        ]),
+        chunk([Some(5), Some(6)], [Some("e"), None]),
+    ];
+    assert_eq!(batches, expected);
+    Ok(())
+}
+
+#[test]
+fn partitioned() -> Result<()> {
+    use arrow2::io::parquet::write::partitioned::PartitionedWriter;
+
+    let root = std::env::temp_dir().join("arrow2_parquet_partitioned");
+    let _ = std::fs::remove_dir_all(&root);
+
+    let mut keys = MutableDictionaryArray::<u8, MutableUtf8Array<i32>>::new();
+    keys.try_extend([Some("a/b"), None, Some("a/b"), Some("c")])?;
+    let keys: DictionaryArray<u8> = keys.into();
+
+    let schema = Schema::from(vec![
+        Field::new("year", DataType::Int32, true),
+        Field::new("key", keys.data_type().clone(), true),
+        Field::new("value", DataType::Int64, true),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let chunk = Chunk::new(vec![
+        Arc::new(Int32Array::from_slice([2021, 2021, 2021, 2022])) as Arc<dyn Array>,
+        Arc::new(keys),
+        Arc::new(Int64Array::from([Some(1), Some(2), None, Some(4)])),
+    ]);
+
+    let mut writer = PartitionedWriter::try_new(
+        &root,
+        schema,
+        vec!["year".into(), 1.into()],
+        vec![Encoding::Plain; 3],
+        options,
+        1024,
+    )?;
+    assert_eq!(writer.schema().fields.len(), 1);
+    writer.write(&chunk)?;
+    let files = writer
+        .close()?
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+
+    let expected = vec![
+        "year=2021/key=__HIVE_DEFAULT_PARTITION__/part-0.parquet",
+        "year=2021/key=a%2Fb/part-0.parquet",
+        "year=2022/key=c/part-0.parquet",
+    ];
+    assert_eq!(
+        files,
+        expected
+            .iter()
+            .map(std::path::PathBuf::from)
+            .collect::<Vec<_>>()
+    );
+
+    let read = |path: &str| -> Result<Vec<Chunk<Arc<dyn Array>>>> {
+        let file = std::fs::File::open(root.join(path))?;
+        RecordReader::try_new(file, None, None, None, None)?.collect()
+    };
+    let values = |values: &[Option<i64>]| {
+        vec![Chunk::new(vec![
+            Arc::new(Int64Array::from(values)) as Arc<dyn Array>
+        ])]
+    };
+    assert_eq!(read(expected[0])?, values(&[Some(2)]));
+    assert_eq!(read(expected[1])?, values(&[Some(1), None]));
+    assert_eq!(read(expected[2])?, values(&[Some(4)]));
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[test]
+fn partitioned_invalid() {
+    use arrow2::io::parquet::write::partitioned::PartitionedWriter;
+
+    let schema = Schema::from(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new(
+            "b",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            true,
+        ),
+    ]);
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let new = |partitions: Vec<FieldRef>| {
+        PartitionedWriter::try_new(
+            std::env::temp_dir(),
+            schema.clone(),
+            partitions,
+            vec![Encoding::Plain; 2],
+            options,
+            1024,
+        )
+    };
+    assert!(new(vec![]).is_err());
+    assert!(new(vec!["c".into()]).is_err());
+    assert!(new(vec![1.into()]).is_err());
+    assert!(new(vec![0.into()]).is_ok());
+}
+
+#[test]
+fn encoder() -> Result<()> {
+    let a = Arc::new(Int64Array::from([Some(1), None, Some(3)])) as Arc<dyn Array>;
+    let b = Arc::new(Utf8Array::<i32>::from([Some("aa"), Some("bb"), None])) as Arc<dyn Array>;
+    let schema = Schema::from(vec![
+        Field::new("a", a.data_type().clone(), true),
+        Field::new("b", b.data_type().clone(), true),
+    ]);
+
+    let options = WriteOptions {
+        write_statistics: true,
+        compression: Compression::Uncompressed,
+        version: Version::V2,
+    };
+    let parquet_schema = to_parquet_schema(&schema)?;
+
+    let chunk = Chunk::try_new(vec![a, b])?;
+    let iter = vec![Ok(chunk.clone()), Ok(chunk.clone())];
+    let mut row_groups = RowGroupIterator::try_new(
+        iter.into_iter(),
+        &schema,
+        options,
+        vec![Encoding::Plain, Encoding::DeltaLengthByteArray],
+    )?;
+    // encode each column on its own thread
+    row_groups.set_encoder(Arc::new(|tasks: Vec<EncodeTask>| {
+        tasks
+            .into_iter()
+            .map(std::thread::spawn)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    }));
+
+    let mut writer = Cursor::new(vec![]);
+    write_file(
+        &mut writer,
+        row_groups,
+        &schema,
+        parquet_schema,
+        options,
+        None,
+    )?;
+    let data = writer.into_inner();
+
+    let reader = RecordReader::try_new(Cursor::new(data), None, None, None, None)?;
+    assert_eq!(reader.metadata().row_groups.len(), 2);
+    let batches = reader.collect::<Result<Vec<_>>>()?;
+    assert_eq!(batches, vec![chunk.clone(), chunk]);
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use arrow2::io::range::*;
+
+fn read_all<R: RangeReader>(reader: R) -> std::io::Result<Vec<u8>> {
+    let mut file = ReadableFile::new(reader);
+    let mut result = vec![];
+    file.read_to_end(&mut result)?;
+    Ok(result)
+}
+
+#[test]
+fn slice() -> std::io::Result<()> {
+    let data = b"0123456789";
+    assert_eq!(read_all(data.as_ref())?, data);
+    assert_eq!(read_all(data.to_vec())?, data);
+    Ok(())
+}
+
+#[test]
+fn seek() -> std::io::Result<()> {
+    let mut file = ReadableFile::new(b"0123456789".as_ref());
+
+    let mut buf = [0; 3];
+    assert_eq!(file.seek(SeekFrom::End(-4))?, 6);
+    file.read_exact(&mut buf)?;
+    assert_eq!(&buf, b"678");
+
+    assert_eq!(file.seek(SeekFrom::Current(-5))?, 4);
+    file.read_exact(&mut buf)?;
+    assert_eq!(&buf, b"456");
+
+    assert_eq!(file.seek(SeekFrom::Start(1))?, 1);
+    file.read_exact(&mut buf)?;
+    assert_eq!(&buf, b"123");
+    assert_eq!(file.position(), 4);
+
+    // past the end yields no bytes
+    file.seek(SeekFrom::End(2))?;
+    assert_eq!(file.read(&mut buf)?, 0);
+
+    assert!(file.seek(SeekFrom::Current(-20)).is_err());
+    Ok(())
+}
+
+#[test]
+fn closure() -> std::io::Result<()> {
+    let data = b"0123456789".to_vec();
+    let mut ranges = vec![];
+    let source = FnRangeReader::new(data.len() as u64, |offset, buf: &mut [u8]| {
+        ranges.push((offset, buf.len()));
+        buf.copy_from_slice(&data[offset as usize..offset as usize + buf.len()]);
+        Ok(())
+    });
+    let mut file = ReadableFile::new(source);
+
+    let mut buf = [0; 4];
+    file.seek(SeekFrom::Start(2))?;
+    file.read_exact(&mut buf)?;
+    assert_eq!(&buf, b"2345");
+    file.seek(SeekFrom::End(-2))?;
+    assert_eq!(file.read(&mut buf)?, 2);
+    assert_eq!(&buf[..2], b"89");
+
+    // only the requested ranges were read
+    assert_eq!(ranges, vec![(2, 4), (8, 2)]);
+    Ok(())
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut data = b"0123".as_ref();
+    let mut buf = [0; 3];
+    assert!(data.read_range(2, &mut buf).is_err());
+
+    let mut source = FnRangeReader::new(4, |_, _: &mut [u8]| Ok(()));
+    assert!(source.read_range(2, &mut buf).is_err());
+}
+
+#[test]
+fn file() -> std::io::Result<()> {
+    let path = std::env::temp_dir().join("arrow2_range_reader.bin");
+    std::fs::File::create(&path)?.write_all(b"0123456789")?;
+
+    let mut file = std::fs::File::open(&path)?;
+    assert_eq!(file.length()?, 10);
+    let mut buf = [0; 3];
+    file.read_range(5, &mut buf)?;
+    assert_eq!(&buf, b"567");
+    assert_eq!(read_all(file)?, b"0123456789");
+
+    std::fs::remove_file(&path)
+}
@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+
+use serde_derive::{Deserialize, Serialize};
+
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+use arrow2::io::serde::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Inner {
+    a: i64,
+    b: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Kind {
+    Small,
+    Large,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Row {
+    id: u32,
+    name: String,
+    score: Option<f64>,
+    tags: Vec<String>,
+    inner: Option<Inner>,
+    kind: Kind,
+    data: Vec<u8>,
+}
+
+fn inner_data_type() -> DataType {
+    DataType::Struct(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Boolean, true),
+    ])
+}
+
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("id", DataType::UInt32, false),
+        Field::new("name", DataType::Utf8, false),
+        Field::new("score", DataType::Float64, true),
+        Field::new(
+            "tags",
+            DataType::List(Box::new(Field::new("item", DataType::Utf8, false))),
+            false,
+        ),
+        Field::new("inner", inner_data_type(), true),
+        Field::new("kind", DataType::Utf8, false),
+        Field::new("data", DataType::Binary, false),
+    ]
+}
+
+fn rows() -> Vec<Row> {
+    vec![
+        Row {
+            id: 1,
+            name: "a".to_string(),
+            score: Some(0.5),
+            tags: vec!["x".to_string(), "y".to_string()],
+            inner: Some(Inner { a: -1, b: None }),
+            kind: Kind::Small,
+            data: vec![1, 2],
+        },
+        Row {
+            id: 2,
+            name: "b".to_string(),
+            score: None,
+            tags: vec![],
+            inner: None,
+            kind: Kind::Large,
+            data: vec![],
+        },
+    ]
+}
+
+#[test]
+fn roundtrip() -> Result<()> {
+    let fields = fields();
+    let rows = rows();
+
+    let chunk = serialize(&rows, &fields)?;
+    assert_eq!(chunk.len(), 2);
+
+    let expected = UInt32Array::from_slice([1, 2]);
+    assert_eq!(chunk.arrays()[0].as_ref(), &expected as &dyn Array);
+    let expected = Float64Array::from([Some(0.5), None]);
+    assert_eq!(chunk.arrays()[2].as_ref(), &expected as &dyn Array);
+    let expected = Utf8Array::<i32>::from_slice(["Small", "Large"]);
+    assert_eq!(chunk.arrays()[5].as_ref(), &expected as &dyn Array);
+    let expected = BinaryArray::<i32>::from_slice([&[1u8, 2][..], &[]]);
+    assert_eq!(chunk.arrays()[6].as_ref(), &expected as &dyn Array);
+    assert_eq!(chunk.arrays()[4].null_count(), 1);
+
+    let result: Vec<Row> = deserialize(&chunk, &fields)?;
+    assert_eq!(result, rows);
+    Ok(())
+}
+
+#[test]
+fn missing_fields_are_null() -> Result<()> {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Partial {
+        id: u32,
+        score: Option<f64>,
+    }
+
+    let fields = vec![
+        Field::new("id", DataType::UInt32, false),
+        Field::new("score", DataType::Float64, true),
+        Field::new("other", DataType::Utf8, true),
+    ];
+    let rows = vec![Partial { id: 1, score: None }];
+
+    let chunk = serialize(&rows, &fields)?;
+    assert_eq!(chunk.arrays()[2].null_count(), 1);
+
+    // the extra column is ignored
+    let result: Vec<Partial> = deserialize(&chunk, &fields)?;
+    assert_eq!(result, rows);
+    Ok(())
+}
+
+#[test]
+fn maps() -> Result<()> {
+    let fields = vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Int32, true),
+    ];
+    let rows = vec![
+        BTreeMap::from([("a".to_string(), 1), ("b".to_string(), 2)]),
+        BTreeMap::from([("a".to_string(), 3)]),
+    ];
+
+    let chunk = serialize(&rows, &fields)?;
+    let expected = Int32Array::from([Some(2), None]);
+    assert_eq!(chunk.arrays()[1].as_ref(), &expected as &dyn Array);
+
+    let result: Vec<BTreeMap<String, Option<i32>>> = deserialize(&chunk, &fields)?;
+    assert_eq!(result[1].get("b"), Some(&None));
+    Ok(())
+}
+
+#[test]
+fn non_nullable() {
+    #[derive(Serialize)]
+    struct Nullable {
+        id: Option<u32>,
+    }
+
+    let fields = vec![Field::new("id", DataType::UInt32, false)];
+    assert!(serialize(&[Nullable { id: None }], &fields).is_err());
+}
+
+#[test]
+fn invalid_values() {
+    // not a struct
+    assert!(serialize(&[1i32], &[Field::new("a", DataType::Int32, false)]).is_err());
+    // out of range
+    assert!(to_array(&[300i32], &DataType::UInt8).is_err());
+    // wrong type
+    assert!(to_array(&["a"], &DataType::Int32).is_err());
+    // wrong size
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int8, false)), 2);
+    assert!(to_array(&[vec![1i8]], &data_type).is_err());
+}
+
+#[test]
+fn fixed_size_list() -> Result<()> {
+    let data_type = DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int16, true)), 2);
+    let values = vec![Some([1i16, 2]), None];
+
+    let array = to_array(&values, &data_type)?;
+    assert_eq!(array.data_type(), &data_type);
+    assert_eq!(array.null_count(), 1);
+
+    let result: Vec<Option<[i16; 2]>> = from_array(array.as_ref())?;
+    assert_eq!(result, values);
+    Ok(())
+}
+
+type Nested = Option<Vec<Option<Vec<Option<i32>>>>>;
+
+#[test]
+fn nested_lists() -> Result<()> {
+    let data_type = DataType::LargeList(Box::new(Field::new(
+        "item",
+        DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+        true,
+    )));
+    let values = vec![
+        Some(vec![Some(vec![Some(1), None]), None]),
+        None,
+        Some(vec![]),
+    ];
+
+    let array = to_array(&values, &data_type)?;
+    assert_eq!(array.data_type(), &data_type);
+
+    let result: Vec<Nested> = from_array(array.as_ref())?;
+    assert_eq!(result, values);
+    Ok(())
+}
+
+#[test]
+fn sliced_list() -> Result<()> {
+    let data_type = DataType::List(Box::new(Field::new("item", DataType::Int32, false)));
+    let array = to_array(&[vec![1, 2], vec![3], vec![4, 5]], &data_type)?;
+    let array = array.slice(1, 2);
+
+    let result: Vec<Vec<i32>> = from_array(array.as_ref())?;
+    assert_eq!(result, vec![vec![3], vec![4, 5]]);
+    Ok(())
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    let mut array = MutableDictionaryArray::<i32, MutableUtf8Array<i32>>::new();
+    array.try_extend([Some("a"), None, Some("b"), Some("a")])?;
+    let array: DictionaryArray<i32> = array.into();
+
+    let result: Vec<Option<String>> = from_array(&array)?;
+    assert_eq!(
+        result,
+        vec![
+            Some("a".to_string()),
+            None,
+            Some("b".to_string()),
+            Some("a".to_string())
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn enums() -> Result<()> {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Event {
+        Click { x: i32, y: i32 },
+        Key(String),
+    }
+
+    let data_type = DataType::Struct(vec![
+        Field::new(
+            "Click",
+            DataType::Struct(vec![
+                Field::new("x", DataType::Int32, false),
+                Field::new("y", DataType::Int32, false),
+            ]),
+            true,
+        ),
+        Field::new("Key", DataType::Utf8, true),
+    ]);
+    let values = vec![Event::Click { x: 1, y: 2 }, Event::Key("a".to_string())];
+
+    let array = to_array(&values, &data_type)?;
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(array.values()[0].null_count(), 1);
+    assert!(array.values()[0].is_valid(0));
+    assert!(array.values()[1].is_valid(1));
+
+    // a variant is read from a struct with a single field
+    let data_type = DataType::Struct(vec![Field::new("Key", DataType::Utf8, false)]);
+    let values = vec![Event::Key("a".to_string())];
+    let array = to_array(&values, &data_type)?;
+    let result: Vec<Event> = from_array(array.as_ref())?;
+    assert_eq!(result, values);
+    Ok(())
+}
@@ -1,10 +1,18 @@
 mod array;
 mod bitmap;
 mod buffer;
+mod chunk;
 mod ffi;
+mod rows;
 mod scalar;
 mod temporal_conversions;
 
+#[cfg(feature = "proptest")]
+mod strategies;
+
+#[cfg(feature = "benchmarks")]
+mod generate;
+
 mod io;
 mod test_util;
 
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use arrow2::array::*;
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field};
+use arrow2::error::Result;
+use arrow2::impl_arrow_row;
+use arrow2::rows::{ArrowField, ArrowRow};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Row {
+    a: i32,
+    b: Option<f64>,
+    c: bool,
+    d: Option<bool>,
+    e: String,
+    f: Option<String>,
+    g: Vec<u8>,
+    h: Option<u16>,
+}
+
+impl_arrow_row!(Row {
+    a: i32,
+    b: Option<f64>,
+    c: bool,
+    d: Option<bool>,
+    e: String,
+    f: Option<String>,
+    g: Vec<u8>,
+    h: Option<u16>,
+});
+
+fn rows() -> Vec<Row> {
+    vec![
+        Row {
+            a: 1,
+            b: Some(0.5),
+            c: true,
+            d: None,
+            e: "a".to_string(),
+            f: None,
+            g: b"x".to_vec(),
+            h: Some(1),
+        },
+        Row {
+            a: -1,
+            b: None,
+            c: false,
+            d: Some(false),
+            e: "".to_string(),
+            f: Some("f".to_string()),
+            g: vec![],
+            h: None,
+        },
+    ]
+}
+
+#[test]
+fn fields() {
+    let fields = Row::fields();
+    assert_eq!(fields[0], Field::new("a", DataType::Int32, false));
+    assert_eq!(fields[1], Field::new("b", DataType::Float64, true));
+    assert_eq!(fields[6], Field::new("g", DataType::Binary, false));
+    assert_eq!(fields.len(), 8);
+}
+
+#[test]
+fn to_chunk() {
+    let chunk = Row::to_chunk(&rows());
+
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(
+        chunk.arrays()[0].as_ref(),
+        &Int32Array::from_slice([1, -1]) as &dyn Array
+    );
+    assert_eq!(
+        chunk.arrays()[1].as_ref(),
+        &Float64Array::from([Some(0.5), None]) as &dyn Array
+    );
+    assert_eq!(
+        chunk.arrays()[5].as_ref(),
+        &Utf8Array::<i32>::from([None, Some("f")]) as &dyn Array
+    );
+    for (field, array) in Row::fields().iter().zip(chunk.arrays()) {
+        assert_eq!(field.data_type(), array.data_type());
+    }
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let rows = rows();
+    let chunk = Row::to_chunk(&rows);
+    assert_eq!(Row::from_chunk(&chunk)?, rows);
+
+    let chunk = Row::to_chunk(&[]);
+    assert_eq!(Row::from_chunk(&chunk)?, vec![]);
+    Ok(())
+}
+
+#[test]
+fn from_chunk_errors() {
+    let chunk = Row::to_chunk(&rows());
+
+    // wrong number of columns
+    let arrays = chunk.arrays()[..7].to_vec();
+    assert!(Row::from_chunk(&Chunk::new(arrays)).is_err());
+
+    // wrong data type
+    let mut arrays = chunk.arrays().to_vec();
+    arrays[0] = Arc::new(Int64Array::from_slice([1, 2]));
+    assert!(Row::from_chunk(&Chunk::new(arrays)).is_err());
+
+    // nulls in a non-nullable column
+    let mut arrays = chunk.arrays().to_vec();
+    arrays[0] = Arc::new(Int32Array::from([Some(1), None]));
+    assert!(Row::from_chunk(&Chunk::new(arrays)).is_err());
+}
+
+#[test]
+fn field_from_array() -> Result<()> {
+    let array = Utf8Array::<i32>::from([Some("a"), None]);
+    assert_eq!(
+        Option::<String>::from_array(&array)?,
+        vec![Some("a".to_string()), None]
+    );
+    assert!(String::from_array(&array).is_err());
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use proptest::prelude::*;
+
+use arrow2::array::Array;
+use arrow2::datatypes::{DataType, Field, IntegerType, IntervalUnit, TimeUnit};
+use arrow2::util::strategies::{array_strategy, chunk_strategy};
+
+fn data_types() -> Vec<DataType> {
+    vec![
+        DataType::Null,
+        DataType::Boolean,
+        DataType::Int8,
+        DataType::UInt64,
+        DataType::Float64,
+        DataType::Date64,
+        DataType::Time32(TimeUnit::Millisecond),
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".to_string())),
+        DataType::Interval(IntervalUnit::MonthDayNano),
+        DataType::Decimal(5, 2),
+        DataType::Utf8,
+        DataType::LargeBinary,
+        DataType::FixedSizeBinary(3),
+        DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+        DataType::FixedSizeList(Box::new(Field::new("item", DataType::Utf8, false)), 2),
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int16, true),
+            Field::new("b", DataType::LargeUtf8, false),
+        ]),
+        DataType::Dictionary(IntegerType::Int8, Box::new(DataType::Utf8), false),
+    ]
+}
+
+fn data_type_strategy() -> impl Strategy<Value = DataType> {
+    prop::sample::select(data_types())
+}
+
+proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn array_of_data_type(
+        (data_type, array) in data_type_strategy()
+            .prop_flat_map(|data_type| (Just(data_type.clone()), array_strategy(data_type, 0..20, 0.5)))
+    ) {
+        prop_assert_eq!(array.data_type(), &data_type);
+        prop_assert!(array.len() < 20);
+        let sliced = array.slice(0, array.len());
+        prop_assert_eq!(array.as_ref(), sliced.as_ref());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn decimals_fit_in_precision(array in array_strategy(DataType::Decimal(3, 1), 10, 0.0)) {
+        let array = array.as_any().downcast_ref::<arrow2::array::PrimitiveArray<i128>>().unwrap();
+        prop_assert_eq!(array.null_count(), 0);
+        prop_assert!(array.values().iter().all(|x| x.abs() < 1000));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // miri and proptest do not work well :(
+    fn chunk_of_fields(chunk in chunk_strategy(
+        &[
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ],
+        5..10,
+        1.0,
+    )) {
+        prop_assert!((5..10).contains(&chunk.len()));
+        // fields that are not nullable have no nulls
+        prop_assert_eq!(chunk.arrays()[0].null_count(), 0);
+        prop_assert_eq!(chunk.arrays()[1].null_count(), chunk.len());
+    }
+}
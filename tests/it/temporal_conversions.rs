@@ -1,5 +1,6 @@
 use arrow2::array::*;
-use arrow2::datatypes::TimeUnit;
+use arrow2::datatypes::{DataType, TimeUnit};
+use arrow2::error::ArrowError;
 use arrow2::temporal_conversions;
 use arrow2::types::months_days_ns;
 
@@ -139,3 +140,45 @@ fn add_interval_timezone() {
     let r = temporal_conversions::timestamp_to_datetime(r, timeunit, &timezone);
     assert_eq!("2020-10-29 01:00:00 WET", format!("{}", r));
 }
+
+#[test]
+fn timestamp_to_time_unit() {
+    let array = Int64Array::from([Some(1_001), None, Some(-1)]).to(DataType::Timestamp(
+        TimeUnit::Millisecond,
+        Some("+01:00".to_string()),
+    ));
+
+    let result = temporal_conversions::timestamp_to_time_unit(&array, TimeUnit::Second).unwrap();
+    let expected = Int64Array::from([Some(1), None, Some(0)]).to(DataType::Timestamp(
+        TimeUnit::Second,
+        Some("+01:00".to_string()),
+    ));
+    assert_eq!(result, expected);
+
+    let result =
+        temporal_conversions::timestamp_to_time_unit(&array, TimeUnit::Nanosecond).unwrap();
+    let expected = Int64Array::from([Some(1_001_000_000), None, Some(-1_000_000)]).to(
+        DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".to_string())),
+    );
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn timestamp_to_time_unit_overflow() {
+    let array =
+        Int64Array::from_slice([i64::MAX / 1000]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let result = temporal_conversions::timestamp_to_time_unit(&array, TimeUnit::Nanosecond);
+    assert!(matches!(result, Err(ArrowError::Overflow)));
+
+    // the values of null slots are ignored
+    let array = Int64Array::from_data(
+        DataType::Timestamp(TimeUnit::Second, None),
+        vec![i64::MAX].into(),
+        Some([false].into()),
+    );
+    let result = temporal_conversions::timestamp_to_time_unit(&array, TimeUnit::Nanosecond);
+    assert!(result.is_ok());
+
+    let array = Int64Array::from_slice([1]);
+    assert!(temporal_conversions::timestamp_to_time_unit(&array, TimeUnit::Second).is_err());
+}